@@ -1,7 +1,24 @@
-use code_gen::gen_code;
+use code_gen::{gen_code, gen_code_with_generics, gen_law_tests};
 
 fn main() {
-    match gen_code() {
+    // `--generics=Left,Right,...` names the type parameters of the `Or` types.
+    let generics = std::env::args().find_map(|arg| {
+        arg.strip_prefix("--generics=").map(|names| {
+            names
+                .split(',')
+                .map(|name| name.trim().to_string())
+                .collect::<Vec<_>>()
+        })
+    });
+    // `--laws` generates the property-based tests instead of the `Or` types.
+    let result = if std::env::args().any(|arg| arg == "--laws") {
+        gen_law_tests()
+    } else if let Some(names) = generics {
+        gen_code_with_generics(&names)
+    } else {
+        gen_code()
+    };
+    match result {
         Ok(_) => (),
         Err(e) => println!("{}", e),
     }
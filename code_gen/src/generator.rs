@@ -5,39 +5,121 @@
 //! ## Generate code
 //!
 //! ```bash
-//! # Output code to `or/src/enums.rs`
+//! # Output code to `or-rs/src/enums.rs`, `or-rs/src/vec.rs` and `or-rs/src/integrations/*.rs`
 //! cargo run --bin code_gen
+//!
+//! # Output the property-based tests of the laws of the `Or` types to `tests/tests/laws_test.rs`
+//! cargo run --bin code_gen -- --laws
+//!
+//! # Name the type parameters `Or2<Left, Right>` instead of `Or2<T1, T2>`, given a name for each
+//! # of `T1` ... `T9`. the variants are still named `T1` ... `T9`.
+//! cargo run --bin code_gen -- --generics=Left,Right,Third,Fourth,Fifth,Sixth,Seventh,Eighth,Ninth
 //! ```
 
-use std::{fs::File, os::unix::prelude::FileExt};
+use std::fs;
+
+mod generics;
+mod integrations;
+mod laws;
+mod vec;
 
 const GEN_COUNT: usize = 9;
-const OUT_PUT_DIR: &'static str = "../or/src/enums.rs";
+const OUT_PUT_DIR: &str = "../or-rs/src";
+const LAWS_OUT_PUT_PATH: &str = "../tests/tests/laws_test.rs";
 
 pub fn gen_code() -> Result<(), String> {
-    let data = gen_code_string(GEN_COUNT);
-    let file = match File::create(OUT_PUT_DIR) {
-        Ok(file) => file,
-        Err(e) => {
-            return Err(format!("failed to create a file: {}", e));
-        }
-    };
+    for (path, data) in gen_files(GEN_COUNT, true) {
+        write_code(&format!("{}/{}", OUT_PUT_DIR, path), data)?;
+    }
+    Ok(())
+}
 
-    match file.write_all_at(data.as_bytes(), 0) {
-        Ok(_) => format!("writing to {} has been done", OUT_PUT_DIR),
-        Err(e) => format!("failed to create a file: {}", e),
-    };
+/// Same as `gen_code`, but the type parameters `T1` ... `T{GEN_COUNT}` are named by `names`.
+pub fn gen_code_with_generics(names: &[String]) -> Result<(), String> {
+    for (path, data) in gen_files_with_generics(GEN_COUNT, true, names)? {
+        write_code(&format!("{}/{}", OUT_PUT_DIR, path), data)?;
+    }
     Ok(())
 }
 
-fn gen_code_string(gen_count: usize) -> String {
+/// Writes the property-based tests of the laws of `Or2` ... `Or{GEN_COUNT}`, which depend on
+/// `proptest`, to the `tests` crate.
+pub fn gen_law_tests() -> Result<(), String> {
+    write_code(LAWS_OUT_PUT_PATH, gen_law_tests_string(GEN_COUNT))
+}
+
+/// Returns the (unformatted) code of the property-based tests of the laws of `Or2` ...
+/// `Or{gen_count}`.
+pub fn gen_law_tests_string(gen_count: usize) -> String {
+    laws::gen_code_string(gen_count)
+}
+
+/// Returns pairs of the path relative to `or-rs/src` and the (unformatted) code of each
+/// generated file, with `Or2` ... `Or{gen_count}`.
+///
+/// Without `inner_attributes`, the module docs and the inner attributes are omitted,
+/// since they are not allowed in a file `include!`d by the `codegen` feature of `or-rs`.
+pub fn gen_files(gen_count: usize, inner_attributes: bool) -> Vec<(String, String)> {
+    let mut files = vec![
+        ("enums.rs".to_string(), gen_code_string(gen_count)),
+        ("vec.rs".to_string(), vec::gen_code_string(gen_count)),
+    ];
+    for (name, data) in integrations::gen_code_strings(gen_count) {
+        files.push((format!("integrations/{}.rs", name), data));
+    }
+
+    if !inner_attributes {
+        for (_, data) in files.iter_mut() {
+            *data = strip_inner_attributes(data);
+        }
+    }
+    files
+}
+
+/// Same as `gen_files`, but the type parameters `T1` ... `T{gen_count}` are renamed to `names`,
+/// like `Or2<Left, Right>`. The variants keep their names `T1` ... `T{gen_count}`.
+pub fn gen_files_with_generics(
+    gen_count: usize,
+    inner_attributes: bool,
+    names: &[String],
+) -> Result<Vec<(String, String)>, String> {
+    gen_files(gen_count, inner_attributes)
+        .into_iter()
+        .map(|(path, data)| Ok((path, generics::rename_generics(&data, names, gen_count)?)))
+        .collect()
+}
+
+// removes the lines of `//! ...` and `#![...]`, which only appear at the top of the files.
+fn strip_inner_attributes(code: &str) -> String {
+    code.lines()
+        .filter(|line| {
+            let line = line.trim_start();
+            !line.starts_with("//!") && !line.starts_with("#![")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn write_code(path: &str, data: String) -> Result<(), String> {
+    match fs::write(path, data) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(format!("failed to write to {}: {}", path, e)),
+    }
+}
+
+/// Returns the (unformatted) code of `enums.rs` with `Or2` ... `Or{gen_count}`.
+pub fn gen_code_string(gen_count: usize) -> String {
     let common = format!(
         "
 {}
+{}
+{}
 {}
     ",
         gen_module_top_doc_comment(),
+        gen_module_attributes(),
         gen_import_stmts(),
+        FORWARDING_MACRO,
     );
     let repeating = (2..=gen_count)
         .into_iter()
@@ -47,33 +129,975 @@ fn gen_code_string(gen_count: usize) -> String {
 {}
 {}
 {}
+{}
+{}
+{}
+{}
+{}
+{}
 ",
                 gen_enum_decl(i),
                 gen_impl_block(i),
-                gen_impl_block_with_trait_bound(i)
+                gen_impl_forwarding(i),
+                gen_impl_variant_sizes(i),
+                gen_impl_compact(i),
+                gen_impl_result(i),
+                gen_impl_option(i),
+                gen_impl_when(i),
+                gen_impl_concat(i, gen_count)
             )
         })
         .collect::<Vec<_>>()
         .join("");
 
-    format!("{} {}", common, repeating)
+    format!("{} {} {}", common, repeating, gen_as_dyn_impls(gen_count))
 }
 
-fn gen_module_top_doc_comment() -> String {
+// the impls which only forward to the value of the active variant are the same for every
+// arity, so they are written once as a macro and invoked for each `Or` type, rather than
+// generated as code for each of them. this keeps `enums.rs` small, which is parsed by every
+// downstream build.
+//
+// rustfmt does not format the body of `macro_rules!`, so it is formatted here.
+const FORWARDING_MACRO: &str = r##"
+// implements the traits and the methods which only forward to the value of the active variant,
+// along with the types named after the `Or` type, like `Or2Items` and `Or2Discriminant`.
+// each variant is given as `T1(t1, e1, I1, i1, E1, 0, T1)`, with the names of its payload, its
+// parse error, its iterator type and value, its collection type, its position in the tuple and
+// its type parameter, which is named apart from the variant so that it can be renamed.
+macro_rules! impl_forwarding {
+    // the formatting traits, so that the value can be formatted like `{:x}` if every payload can.
+    (@fmt $or:ident $trait:ident { $($t:ident($x:ident, $g:ident)),+ }) => {
+        /// Formats the value of the active variant.
+        impl<$($g),+> fmt::$trait for $or<$($g),+>
+        where
+            $($g: fmt::$trait),+
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                match self {
+                    $(Self::$t($x) => fmt::$trait::fmt($x, f),)+
+                }
+            }
+        }
+    };
+    // the impls bounding every payload type by the associated type of the first one, like
+    // `T2: Index<I, Output = T1::Output>`, for which the first variant is taken apart.
+    (@index $or:ident { $t1:ident($x1:ident, $g1:ident) $(, $t:ident($x:ident, $g:ident))+ }) => {
+        #[doc = concat!("Indexes into the value of the active variant, so an `", stringify!($or), "` of collections supporting")]
+        /// the same indexing, like `Vec<u8>` and `[u8; 32]`, stays indexable.
+        impl<I, $g1, $($g),+> Index<I> for $or<$g1, $($g),+>
+        where
+            $g1: Index<I>,
+            $($g: Index<I, Output = $g1::Output>),+
+        {
+            type Output = $g1::Output;
+
+            fn index(&self, index: I) -> &Self::Output {
+                match self {
+                    Self::$t1($x1) => $x1.index(index),
+                    $(Self::$t($x) => $x.index(index),)+
+                }
+            }
+        }
+
+        impl<I, $g1, $($g),+> IndexMut<I> for $or<$g1, $($g),+>
+        where
+            $g1: IndexMut<I>,
+            $($g: IndexMut<I, Output = $g1::Output>),+
+        {
+            fn index_mut(&mut self, index: I) -> &mut Self::Output {
+                match self {
+                    Self::$t1($x1) => $x1.index_mut(index),
+                    $(Self::$t($x) => $x.index_mut(index),)+
+                }
+            }
+        }
+    };
+    // the target type is taken from the first type, like the output type of `Index`.
+    (@deref $or:ident { $t1:ident($x1:ident, $g1:ident) $(, $t:ident($x:ident, $g:ident))+ }) => {
+        #[doc = concat!("Dereferences the value of the active variant, so an `", stringify!($or), "` of pointers to the same")]
+        /// target, like `Box<str>` and `String`, can call the methods of the target directly.
+        ///
+        /// As with `either::Either`, a method called on a value whose `T1` is not inferred yet,
+        #[doc = concat!("like `", stringify!($or), "::T2(x).boxed()`, needs the types given, since the method is looked up")]
+        /// on the target too.
+        impl<$g1, $($g),+> Deref for $or<$g1, $($g),+>
+        where
+            $g1: Deref,
+            $($g: Deref<Target = $g1::Target>),+
+        {
+            type Target = $g1::Target;
+
+            fn deref(&self) -> &Self::Target {
+                match self {
+                    Self::$t1($x1) => $x1.deref(),
+                    $(Self::$t($x) => $x.deref(),)+
+                }
+            }
+        }
+
+        impl<$g1, $($g),+> DerefMut for $or<$g1, $($g),+>
+        where
+            $g1: DerefMut,
+            $($g: DerefMut<Target = $g1::Target>),+
+        {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                match self {
+                    Self::$t1($x1) => $x1.deref_mut(),
+                    $(Self::$t($x) => $x.deref_mut(),)+
+                }
+            }
+        }
+    };
+    (@iterator $or:ident { $t1:ident($x1:ident, $g1:ident) $(, $t:ident($x:ident, $g:ident))+ }) => {
+        /// Iterates the iterator of the active variant, so the branches returning different iterators
+        /// of the same item, like `std::iter::once(x)` and `vec.into_iter()`, can be iterated directly.
+        ///
+        #[doc = concat!("The inherent `fold` and `try_fold` of `", stringify!($or), "` take precedence over the ones of `Iterator`,")]
+        /// which are called as `Iterator::fold(iter, init, f)` instead.
+        impl<$g1, $($g),+> Iterator for $or<$g1, $($g),+>
+        where
+            $g1: Iterator,
+            $($g: Iterator<Item = $g1::Item>),+
+        {
+            type Item = $g1::Item;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                match self {
+                    Self::$t1($x1) => $x1.next(),
+                    $(Self::$t($x) => $x.next(),)+
+                }
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                match self {
+                    Self::$t1($x1) => $x1.size_hint(),
+                    $(Self::$t($x) => $x.size_hint(),)+
+                }
+            }
+
+            fn nth(&mut self, n: usize) -> Option<Self::Item> {
+                match self {
+                    Self::$t1($x1) => $x1.nth(n),
+                    $(Self::$t($x) => $x.nth(n),)+
+                }
+            }
+
+            fn count(self) -> usize {
+                match self {
+                    Self::$t1($x1) => $x1.count(),
+                    $(Self::$t($x) => $x.count(),)+
+                }
+            }
+
+            fn last(self) -> Option<Self::Item> {
+                match self {
+                    Self::$t1($x1) => $x1.last(),
+                    $(Self::$t($x) => $x.last(),)+
+                }
+            }
+
+            fn fold<B, F>(self, init: B, f: F) -> B
+            where
+                F: FnMut(B, Self::Item) -> B,
+            {
+                match self {
+                    Self::$t1($x1) => $x1.fold(init, f),
+                    $(Self::$t($x) => $x.fold(init, f),)+
+                }
+            }
+        }
+
+        impl<$g1, $($g),+> DoubleEndedIterator for $or<$g1, $($g),+>
+        where
+            $g1: DoubleEndedIterator,
+            $($g: DoubleEndedIterator<Item = $g1::Item>),+
+        {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                match self {
+                    Self::$t1($x1) => $x1.next_back(),
+                    $(Self::$t($x) => $x.next_back(),)+
+                }
+            }
+
+            fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+                match self {
+                    Self::$t1($x1) => $x1.nth_back(n),
+                    $(Self::$t($x) => $x.nth_back(n),)+
+                }
+            }
+
+            fn rfold<B, F>(self, init: B, f: F) -> B
+            where
+                F: FnMut(B, Self::Item) -> B,
+            {
+                match self {
+                    Self::$t1($x1) => $x1.rfold(init, f),
+                    $(Self::$t($x) => $x.rfold(init, f),)+
+                }
+            }
+        }
+
+        impl<$g1, $($g),+> ExactSizeIterator for $or<$g1, $($g),+>
+        where
+            $g1: ExactSizeIterator,
+            $($g: ExactSizeIterator<Item = $g1::Item>),+
+        {
+            fn len(&self) -> usize {
+                match self {
+                    Self::$t1($x1) => $x1.len(),
+                    $(Self::$t($x) => $x.len(),)+
+                }
+            }
+        }
+
+        impl<$g1, $($g),+> FusedIterator for $or<$g1, $($g),+>
+        where
+            $g1: FusedIterator,
+            $($g: FusedIterator<Item = $g1::Item>),+
+        {
+        }
+    };
+    ($or:ident, $items:ident, $discriminant:ident { $($t:ident($x:ident, $e:ident, $it:ident, $i:ident, $ext:ident, $n:tt, $g:ident)),+ }) => {
+        #[doc = concat!("Extension to `", stringify!($or), "` to check if the enum's type matches a arbitrary type.")]
+        /// Currently, these functions depend on the rustc intrinsics, and the constraints
+        /// of the intrinsics require that the type must satisfy `'static'`.
+        impl<$($g),+> $or<$($g),+>
+        where
+            $($g: 'static),+
+        {
+            pub fn is_type<T: 'static>(&self) -> bool {
+                match self {
+                    $(Self::$t(_) => TypeId::of::<T>() == TypeId::of::<$g>(),)+
+                }
+            }
+
+            #[doc = concat!("Lifts a dynamically-typed value into `", stringify!($or), "` by downcasting it to its types in order.")]
+            /// If the value is none of these types, it is handed back as is.
+            #[cfg(feature = "alloc")]
+            pub fn from_any(value: Box<dyn Any>) -> Result<Self, Box<dyn Any>> {
+                $(
+                    let value = match value.downcast::<$g>() {
+                        Ok($x) => return Ok(Self::$t(*$x)),
+                        Err(value) => value,
+                    };
+                )+
+                Err(value)
+            }
+
+            /// Converts the value of the active variant into a dynamically-typed value,
+            /// which can be downcast back to the payload type, or lifted again with `from_any`.
+            #[cfg(feature = "alloc")]
+            pub fn into_any(self) -> Box<dyn Any> {
+                match self {
+                    $(Self::$t($x) => Box::new($x),)+
+                }
+            }
+
+            /// Borrows the value of the active variant as a dynamically-typed value.
+            pub fn as_any(&self) -> &dyn Any {
+                match self {
+                    $(Self::$t($x) => $x,)+
+                }
+            }
+
+            /// Moves the value of the active variant out if it is of type `T`, or hands back the
+            /// enum as is, so that a payload can be extracted by its type in generic code.
+            ///
+            /// `TryFrom<Self>` can not be implemented for the payload types instead, since they
+            /// are not local to this crate, and they can be the same type.
+            pub fn downcast<T: 'static>(self) -> Result<T, Self> {
+                match self {
+                    $(Self::$t($x) => {
+                        let mut value = Some($x);
+                        if let Some(t) = (&mut value as &mut dyn Any).downcast_mut::<Option<T>>() {
+                            // `value` is `Some` here, and `T` is the type of it.
+                            return Ok(t.take().unwrap());
+                        }
+                        Err(Self::$t(value.unwrap()))
+                    })+
+                }
+            }
+        }
+
+        #[doc = concat!("Parses a string into `", stringify!($or), "` by trying its types in order.")]
+        /// The first successful parse wins, and if every type fails to parse,
+        /// the errors of all the types are returned in a `ParseOrError`.
+        impl<$($g),+> FromStr for $or<$($g),+>
+        where
+            $($g: FromStr),+
+        {
+            type Err = ParseOrError<($($g::Err),+)>;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $(
+                    let $e = match $g::from_str(s) {
+                        Ok($x) => return Ok(Self::$t($x)),
+                        Err($e) => $e,
+                    };
+                )+
+                Err(ParseOrError::new(($($e),+)))
+            }
+        }
+
+        impl<$($ext),+> fmt::Debug for ParseOrError<($($ext),+)>
+        where
+            $($ext: fmt::Debug),+
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let ($($e),+) = self.errors();
+                f.debug_tuple("ParseOrError")$(.field($e))+.finish()
+            }
+        }
+
+        /// Lists the errors of all the types, in order.
+        impl<$($ext),+> fmt::Display for ParseOrError<($($ext),+)>
+        where
+            $($ext: fmt::Display),+
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let ($($e),+) = self.errors();
+                f.write_str("no type could parse the string")?;
+                for (i, e) in [$($e as &dyn fmt::Display),+].iter().enumerate() {
+                    f.write_str(if i == 0 { ": " } else { ", " })?;
+                    e.fmt(f)?;
+                }
+                Ok(())
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl<$($ext),+> Error for ParseOrError<($($ext),+)>
+        where
+            $($ext: Error),+
+        {
+        }
+
+        impl_forwarding!(@index $or { $($t($x, $g)),+ });
+        impl_forwarding!(@deref $or { $($t($x, $g)),+ });
+        impl_forwarding!(@iterator $or { $($t($x, $g)),+ });
+
+        impl_forwarding!(@fmt $or Display { $($t($x, $g)),+ });
+        impl_forwarding!(@fmt $or LowerHex { $($t($x, $g)),+ });
+        impl_forwarding!(@fmt $or UpperHex { $($t($x, $g)),+ });
+        impl_forwarding!(@fmt $or Octal { $($t($x, $g)),+ });
+        impl_forwarding!(@fmt $or Binary { $($t($x, $g)),+ });
+        impl_forwarding!(@fmt $or LowerExp { $($t($x, $g)),+ });
+        impl_forwarding!(@fmt $or UpperExp { $($t($x, $g)),+ });
+        impl_forwarding!(@fmt $or Pointer { $($t($x, $g)),+ });
+
+        #[doc = concat!("Forwards to the error of the active variant, so an `", stringify!($or), "` of errors is itself an error")]
+        /// with the same message and source.
+        #[cfg(feature = "std")]
+        impl<$($g),+> Error for $or<$($g),+>
+        where
+            $($g: Error),+
+        {
+            fn source(&self) -> Option<&(dyn Error + 'static)> {
+                match self {
+                    $(Self::$t($x) => $x.source(),)+
+                }
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl<$($g),+> $or<$($g),+>
+        where
+            $($g: Error + Send + Sync + 'static),+
+        {
+            /// Converts the error of the active variant into a boxed error.
+            ///
+            /// The error is boxed as is rather than wrapped in the enum, so it can be recovered
+            /// with `downcast` on the boxed error.
+            pub fn into_boxed_error(self) -> Box<dyn Error + Send + Sync> {
+                match self {
+                    $(Self::$t($x) => Box::new($x),)+
+                }
+            }
+        }
+
+        /// Writes to the writer of the active variant, so a formatting target chosen at runtime
+        /// can be written to uniformly.
+        impl<$($g),+> fmt::Write for $or<$($g),+>
+        where
+            $($g: fmt::Write),+
+        {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                match self {
+                    $(Self::$t($x) => $x.write_str(s),)+
+                }
+            }
+
+            fn write_char(&mut self, c: char) -> fmt::Result {
+                match self {
+                    $(Self::$t($x) => $x.write_char(c),)+
+                }
+            }
+
+            fn write_fmt(&mut self, args: fmt::Arguments<'_>) -> fmt::Result {
+                match self {
+                    $(Self::$t($x) => $x.write_fmt(args),)+
+                }
+            }
+        }
+
+        /// Borrows the value of the active variant as `U`, like `str`, `[u8]` or `Path`, so an
+        /// `Or` of path-like values can be passed to `File::open` directly.
+        ///
+        #[doc = concat!("The inherent `as_ref` and `as_mut` of `", stringify!($or), "` take precedence over the ones")]
+        /// of `AsRef` and `AsMut` in a method call, which are called as `AsRef::<U>::as_ref(&x)`.
+        impl<U: ?Sized, $($g),+> AsRef<U> for $or<$($g),+>
+        where
+            $($g: AsRef<U>),+
+        {
+            fn as_ref(&self) -> &U {
+                match self {
+                    $(Self::$t($x) => $x.as_ref(),)+
+                }
+            }
+        }
+
+        impl<U: ?Sized, $($g),+> AsMut<U> for $or<$($g),+>
+        where
+            $($g: AsMut<U>),+
+        {
+            fn as_mut(&mut self) -> &mut U {
+                match self {
+                    $(Self::$t($x) => $x.as_mut(),)+
+                }
+            }
+        }
+
+        /// Reads from the reader of the active variant, like a `File` or a `Cursor` chosen at
+        /// runtime.
+        #[cfg(feature = "std")]
+        impl<$($g),+> io::Read for $or<$($g),+>
+        where
+            $($g: io::Read),+
+        {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                match self {
+                    $(Self::$t($x) => $x.read(buf),)+
+                }
+            }
+
+            fn read_vectored(&mut self, bufs: &mut [io::IoSliceMut<'_>]) -> io::Result<usize> {
+                match self {
+                    $(Self::$t($x) => $x.read_vectored(bufs),)+
+                }
+            }
+
+            fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
+                match self {
+                    $(Self::$t($x) => $x.read_to_end(buf),)+
+                }
+            }
+
+            fn read_to_string(&mut self, buf: &mut String) -> io::Result<usize> {
+                match self {
+                    $(Self::$t($x) => $x.read_to_string(buf),)+
+                }
+            }
+
+            fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+                match self {
+                    $(Self::$t($x) => $x.read_exact(buf),)+
+                }
+            }
+        }
+
+        /// Reads from the buffered reader of the active variant.
+        #[cfg(feature = "std")]
+        impl<$($g),+> io::BufRead for $or<$($g),+>
+        where
+            $($g: io::BufRead),+
+        {
+            fn fill_buf(&mut self) -> io::Result<&[u8]> {
+                match self {
+                    $(Self::$t($x) => $x.fill_buf(),)+
+                }
+            }
+
+            fn consume(&mut self, amt: usize) {
+                match self {
+                    $(Self::$t($x) => $x.consume(amt),)+
+                }
+            }
+
+            fn read_until(&mut self, byte: u8, buf: &mut Vec<u8>) -> io::Result<usize> {
+                match self {
+                    $(Self::$t($x) => $x.read_until(byte, buf),)+
+                }
+            }
+
+            fn read_line(&mut self, buf: &mut String) -> io::Result<usize> {
+                match self {
+                    $(Self::$t($x) => $x.read_line(buf),)+
+                }
+            }
+        }
+
+        /// Writes to the writer of the active variant, like a `File` or a `Vec<u8>` chosen at
+        /// runtime.
+        #[cfg(feature = "std")]
+        impl<$($g),+> io::Write for $or<$($g),+>
+        where
+            $($g: io::Write),+
+        {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                match self {
+                    $(Self::$t($x) => $x.write(buf),)+
+                }
+            }
+
+            fn write_vectored(&mut self, bufs: &[io::IoSlice<'_>]) -> io::Result<usize> {
+                match self {
+                    $(Self::$t($x) => $x.write_vectored(bufs),)+
+                }
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                match self {
+                    $(Self::$t($x) => $x.flush(),)+
+                }
+            }
+
+            fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+                match self {
+                    $(Self::$t($x) => $x.write_all(buf),)+
+                }
+            }
+
+            fn write_fmt(&mut self, args: fmt::Arguments<'_>) -> io::Result<()> {
+                match self {
+                    $(Self::$t($x) => $x.write_fmt(args),)+
+                }
+            }
+        }
+
+        /// Seeks the stream of the active variant.
+        #[cfg(feature = "std")]
+        impl<$($g),+> io::Seek for $or<$($g),+>
+        where
+            $($g: io::Seek),+
+        {
+            fn seek(&mut self, pos: io::SeekFrom) -> io::Result<u64> {
+                match self {
+                    $(Self::$t($x) => $x.seek(pos),)+
+                }
+            }
+
+            fn rewind(&mut self) -> io::Result<()> {
+                match self {
+                    $(Self::$t($x) => $x.rewind(),)+
+                }
+            }
+
+            fn stream_position(&mut self) -> io::Result<u64> {
+                match self {
+                    $(Self::$t($x) => $x.stream_position(),)+
+                }
+            }
+        }
+
+        #[doc = concat!("See [`", stringify!($or), "::from_any`].")]
+        #[cfg(feature = "alloc")]
+        impl<$($g),+> TryFrom<Box<dyn Any>> for $or<$($g),+>
+        where
+            $($g: 'static),+
+        {
+            type Error = Box<dyn Any>;
+
+            fn try_from(value: Box<dyn Any>) -> Result<Self, Self::Error> {
+                Self::from_any(value)
+            }
+        }
+
+        #[doc = concat!("Partitions `", stringify!($or), "` values into a tuple of collections, where the payload of each variant")]
+        /// is appended to the collection at the same position, like `(Vec<T1>, Vec<T2>, ...)`.
+        impl<$($g),+, $($ext),+> Extend<$or<$($g),+>> for ($($ext),+)
+        where
+            $($ext: Extend<$g>),+
+        {
+            fn extend<I: IntoIterator<Item = $or<$($g),+>>>(&mut self, iter: I) {
+                for value in iter {
+                    match value {
+                        $($or::$t($x) => self.$n.extend(Some($x)),)+
+                    }
+                }
+            }
+        }
+
+        #[doc = concat!("An iterator over the items of an `", stringify!($or), "` of collections, each yielded as an `", stringify!($or), "`")]
+        #[doc = concat!("of the variant the collection is in. Created by [`", stringify!($or), "::iter_items`] and [`", stringify!($or), "::into_items`].")]
+        pub struct $items<$($it),+> {
+            inner: $or<$($it),+>,
+        }
+
+        impl<$($it),+> Iterator for $items<$($it),+>
+        where
+            $($it: Iterator),+
+        {
+            type Item = $or<$($it::Item),+>;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                match &mut self.inner {
+                    $($or::$t($i) => $i.next().map($or::$t),)+
+                }
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                match &self.inner {
+                    $($or::$t($i) => $i.size_hint(),)+
+                }
+            }
+        }
+
+        impl<$($g),+> $or<$($g),+> {
+            /// Returns an iterator over the items of the collection in the active variant, each
+            #[doc = concat!("borrowed and wrapped in the variant, like `", stringify!($or), "<&A, &B, ...>` for `", stringify!($or), "<Vec<A>, Vec<B>, ...>`.")]
+            pub fn iter_items<'a>(&'a self) -> $items<$(<&'a $g as IntoIterator>::IntoIter),+>
+            where
+                $(&'a $g: IntoIterator),+
+            {
+                self.as_ref().into_items()
+            }
+
+            /// Converts the collection in the active variant into an iterator over its items, each
+            /// wrapped in the variant.
+            pub fn into_items(self) -> $items<$($g::IntoIter),+>
+            where
+                $($g: IntoIterator),+
+            {
+                $items {
+                    inner: match self {
+                        $(Self::$t($x) => $or::$t($x.into_iter()),)+
+                    },
+                }
+            }
+        }
+
+        #[doc = concat!("The variant of an `", stringify!($or), "`, without its payload. Returned by [`", stringify!($or), "::discriminant`].")]
+        ///
+        /// This can be stored in sets or maps, or switched on, without touching the payloads.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub enum $discriminant {
+            $($t),+
+        }
+
+        impl $discriminant {
+            /// Returns an iterator over all the variants, in the order of their positions.
+            pub fn iter_all() -> impl DoubleEndedIterator<Item = Self> + ExactSizeIterator {
+                [$(Self::$t),+].into_iter()
+            }
+        }
+
+        impl<$($g),+> $or<$($g),+> {
+            /// Returns the variant of the enum, without its payload.
+            #[must_use]
+            pub fn discriminant(&self) -> $discriminant {
+                match self {
+                    $(Self::$t(_) => $discriminant::$t,)+
+                }
+            }
+
+            /// Returns the name of the active variant, like `"T1"`.
+            #[must_use]
+            pub const fn variant_name(&self) -> &'static str {
+                match self {
+                    $(Self::$t(_) => stringify!($t),)+
+                }
+            }
+
+            /// Returns the name of the payload type of the active variant, like `"i32"`, as
+            /// given by `core::any::type_name`.
+            ///
+            /// The name is meant for diagnostics like logs and error messages, and its exact
+            /// form may change between compiler versions.
+            #[must_use]
+            pub fn type_name(&self) -> &'static str {
+                match self {
+                    $(Self::$t(_) => type_name::<$g>(),)+
+                }
+            }
+        }
+
+        impl<$($g),+> $or<$($g),+> {
+            /// Converts the value of the active variant into `U`, which all the variants can be
+            /// converted into, collapsing the enum into a single type.
+            pub fn unify<U>(self) -> U
+            where
+                $($g: Into<U>),+
+            {
+                match self {
+                    $(Self::$t($x) => $x.into(),)+
+                }
+            }
+
+            /// Tries to convert the value of the active variant into `U`, like narrowing
+            /// numeric variants into a single type. On failure, the error of the conversion is
+            /// returned in the variant of the value.
+            pub fn try_unify<U>(self) -> Result<U, $or<$(<$g as TryInto<U>>::Error),+>>
+            where
+                $($g: TryInto<U>),+
+            {
+                match self {
+                    $(Self::$t($x) => $x.try_into().map_err($or::$t),)+
+                }
+            }
+        }
+
+        impl<$($g),+> $or<$($g),+> {
+            /// Wraps the value into the first variants of the `Or` type concatenating `Self`
+            /// and `R`.
+            pub fn concat_left<R>(self) -> <Self as Concat<R>>::Output
+            where
+                Self: Concat<R>,
+            {
+                Concat::left(self)
+            }
+
+            /// Wraps the value into the last variants of the `Or` type concatenating `L`
+            /// and `Self`.
+            pub fn concat_right<L>(self) -> <L as Concat<Self>>::Output
+            where
+                L: Concat<Self>,
+            {
+                L::right(self)
+            }
+        }
+
+        impl<$($g),+> $or<$($g),+> {
+            /// Maps the value of the active variant by `p`, which implements `PolyMap` for each
+            /// of the payload types.
+            pub fn map_poly<P>(self, p: P) -> $or<$(<P as PolyMap<$g>>::Output),+>
+            where
+                $(P: PolyMap<$g>,)+
+            {
+                match self {
+                    $(Self::$t($x) => $or::$t(p.call($x)),)+
+                }
+            }
+        }
+
+        impl<$($g),+> $or<$($g),+> {
+            /// Projects a pinned reference to the enum into a pinned reference to the value of
+            /// the active variant.
+            pub fn as_pin_ref(self: Pin<&Self>) -> $or<$(Pin<&$g>),+> {
+                // SAFETY: the value is pinned as long as the enum is, since it is never moved
+                // out of the enum, and the enum has no `Drop` impl nor a manual `Unpin` impl.
+                unsafe {
+                    match self.get_ref() {
+                        $(Self::$t($x) => $or::$t(Pin::new_unchecked($x)),)+
+                    }
+                }
+            }
+
+            /// Projects a pinned mutable reference to the enum into a pinned mutable reference
+            /// to the value of the active variant.
+            pub fn as_pin_mut(self: Pin<&mut Self>) -> $or<$(Pin<&mut $g>),+> {
+                // SAFETY: the value is pinned as long as the enum is, since it is never moved
+                // out of the enum, and the variant can not be changed through the pinned
+                // reference.
+                unsafe {
+                    match self.get_unchecked_mut() {
+                        $(Self::$t($x) => $or::$t(Pin::new_unchecked($x)),)+
+                    }
+                }
+            }
+        }
+
+        /// Polls the future of the active variant, so the branches calling different async
+        /// functions of the same output can be awaited directly.
+        impl<O, $($g),+> Future for $or<$($g),+>
+        where
+            $($g: Future<Output = O>),+
+        {
+            type Output = O;
+
+            fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<O> {
+                match self.as_pin_mut() {
+                    $($or::$t($x) => $x.poll(cx),)+
+                }
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl<$($g),+> $or<$($g),+> {
+            /// Boxes the value of the active variant, which shrinks the enum into the size of
+            /// a pointer and a tag, regardless of the size of the payloads.
+            #[must_use]
+            pub fn boxed(self) -> $or<$(Box<$g>),+> {
+                match self {
+                    $(Self::$t($x) => $or::$t(Box::new($x)),)+
+                }
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl<$($g),+> $or<$(Box<$g>),+> {
+            /// Moves the value of the active variant out of its box. The reverse of `boxed`.
+            #[must_use]
+            pub fn unboxed(self) -> $or<$($g),+> {
+                match self {
+                    $(Self::$t($x) => $or::$t(*$x),)+
+                }
+            }
+        }
+
+        // the lifetimes elided here are distinct, so the references need not share one.
+        impl<$($g: ?Sized),+> $or<$(&mut $g),+> {
+            /// Reborrows the mutable reference of the active variant for a shorter lifetime,
+            /// so that the enum can be passed down to functions repeatedly, like a plain
+            /// `&mut` is implicitly reborrowed.
+            #[must_use]
+            pub fn reborrow(&mut self) -> $or<$(&mut $g),+> {
+                match self {
+                    $(Self::$t($x) => $or::$t(&mut **$x),)+
+                }
+            }
+        }
+
+        #[doc = concat!("Extension to `", stringify!($or), "` of `Cow` values, such as zero-copy parse results.")]
+        #[cfg(feature = "alloc")]
+        impl<'a, $($g),+> $or<$(Cow<'a, $g>),+>
+        where
+            $($g: ToOwned + ?Sized),+
+        {
+            /// Converts the borrowed value of the active variant into the owned one, so that
+            /// the enum is detached from the borrowed data.
+            #[must_use]
+            pub fn into_owned(self) -> $or<$($g::Owned),+> {
+                match self {
+                    $(Self::$t($x) => $or::$t($x.into_owned()),)+
+                }
+            }
+
+            /// Returns a mutable reference to the owned value of the active variant,
+            /// cloning the borrowed value into the owned one if necessary.
+            pub fn to_mut(&mut self) -> $or<$(&mut $g::Owned),+> {
+                match self {
+                    $(Self::$t($x) => $or::$t($x.to_mut()),)+
+                }
+            }
+        }
+    };
+}
+"##;
+
+// gen
+// ```
+// impl_forwarding!(Or2, Or2Items, Or2Discriminant {
+//     T1(t1, e1, I1, i1, E1, 0, T1),
+//     T2(t2, e2, I2, i2, E2, 1, T2)
+// });
+// ```
+// the invocation is indented here, since rustfmt does not format it either.
+fn gen_impl_forwarding(idx: usize) -> String {
+    let variants = (1..=idx)
+        .into_iter()
+        .map(|i| format!("    T{i}(t{i}, e{i}, I{i}, i{i}, E{i}, {}, T{i})", i - 1, i = i))
+        .collect::<Vec<_>>()
+        .join(",\n");
+
+    format!(
+        "
+impl_forwarding!({or}, {or}Items, {or}Discriminant {{
+{variants}
+}});
+",
+        or = gen_enum_name(idx),
+        variants = variants
+    )
+}
+
+// gen
+// ```
+// #[doc(hidden)]
+// #[macro_export]
+// macro_rules! __as_dyn_impls {
+//     ($($args:tt)+) => {
+//         $crate::__as_dyn_impl!(Or2 { T1 T2 } $($args)+);
+//         $crate::__as_dyn_impl!(Or3 { T1 T2 T3 } $($args)+);
+//     };
+// }
+// ```
+// `as_dyn!` in `macros.rs` implements its trait for each of the generated `Or` types.
+fn gen_as_dyn_impls(gen_count: usize) -> String {
+    let invocations = (2..=gen_count)
+        .into_iter()
+        .map(|i| {
+            let variants = (1..=i)
+                .into_iter()
+                .map(|j| format!("T{}", j))
+                .collect::<Vec<_>>()
+                .join(" ");
+            format!(
+                "        $crate::__as_dyn_impl!({} {{ {} }} $($args)+);",
+                gen_enum_name(i),
+                variants
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
     format!(
         "
+// lists the `Or` types for `as_dyn!`, which implements its local trait for each of them.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __as_dyn_impls {{
+    ($($args:tt)+) => {{
+{}
+    }};
+}}
+",
+        invocations
+    )
+}
+
+fn gen_module_top_doc_comment() -> String {
+    "
 //! A concrete implementation of the type Or that represents values of multiple types.
 //! 
 //! Different enum types `OrX` (where X is the number of types the enum can contain) are provided
 //! depending on the number of types it can contain.
 //! 
 //! The implementation of these enums includes several basic function such as `is_tx`
-//! for assertion and `as_tx` for cast, and also have some util functions, like `map`, `fold`.\n"
-    )
+//! for assertion and `as_tx` for cast, and also have some util functions, like `map`, `fold`.\n".to_string()
+}
+
+// the generated signatures are inherently wide for the larger enums.
+fn gen_module_attributes() -> String {
+    "#![allow(clippy::type_complexity)]\n".to_string()
 }
 
 fn gen_import_stmts() -> String {
-    format!("use std::any::TypeId;")
+    "use core::any::{type_name, Any, TypeId};
+use core::convert::Infallible;
+use core::fmt;
+use core::future::Future;
+use core::iter::FusedIterator;
+use core::mem::{align_of, size_of};
+use core::ops::{Deref, DerefMut, Index, IndexMut};
+use core::pin::Pin;
+use core::str::FromStr;
+use core::task::{Context, Poll};
+
+#[cfg(feature = \"alloc\")]
+use alloc::borrow::{Cow, ToOwned};
+#[cfg(feature = \"alloc\")]
+use alloc::boxed::Box;
+#[cfg(feature = \"std\")]
+use std::error::Error;
+#[cfg(feature = \"std\")]
+use std::io;
+
+use crate::concat::Concat;
+use crate::from_str::ParseOrError;
+use crate::poly::PolyMap;
+use crate::when::When;"
+        .to_string()
 }
 
 // gen
@@ -87,99 +1111,841 @@ fn gen_impl_block(idx: usize) -> String {
 
 impl <{}> {} <{}> {{
     {}
-    {}
-    {}
-    {}
 }}
     ",
         gen_enum_generics(idx),
         gen_enum_name(idx),
         gen_enum_generics(idx),
-        gen_method_is_tx(idx),
-        gen_method_as_tx(idx),
-        gen_method_map_tx(idx),
-        gen_method_fold(idx)
+        [
+            gen_method_is_tx(idx),
+            gen_method_as_tx(idx),
+            gen_method_unwrap_tx(idx),
+            gen_method_as_ref(idx),
+            gen_method_map_tx(idx),
+            gen_method_map_tx_async(idx),
+            gen_method_fold(idx),
+            gen_method_try_fold(idx),
+            gen_method_fold_async(idx),
+            gen_method_when_tx(idx),
+            gen_method_into_options(idx),
+            gen_method_as_options(idx),
+            gen_method_try_from_options(idx),
+        ]
+        .join("")
     }
 }
 
 // gen
 // ```
-// impl<T1, T2> Or2<T1, T2>
-// where
-//     T1: 'static,
-//     T2: 'static,
-// {
-// }
+// pub fn as_ref(&self) -> Or3<&T1, &T2, &T3> { ... }
+// pub fn as_mut(&mut self) -> Or3<&mut T1, &mut T2, &mut T3> { ... }
 // ```
-fn gen_impl_block_with_trait_bound(idx: usize) -> String {
-    fn gen_impl_block_with_trait_bound_comment(map_idx: usize) -> String {
-        format!(
-            "
-/// Extension to `Or{}` to check if the enum's type matches a arbitrary type.
-/// Currently, these functions depend on the rustc intrinsics, and the constraints
-/// of the intrinsics require that the type must satisfy `'static'`.",
-            map_idx,
-        )
+fn gen_method_as_ref(idx: usize) -> String {
+    // gen `&T1, &T2, &T3` with prefix = "&"
+    fn gen_ref_generics(g_idx: usize, prefix: &str) -> String {
+        (1..=g_idx)
+            .into_iter()
+            .map(|i| format!("{}T{}", prefix, i))
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    // gen
+    // ```
+    // Self::T1(t1) => Or3::T1(t1),
+    // Self::T2(t2) => Or3::T2(t2),
+    // Self::T3(t3) => Or3::T3(t3),
+    // ```
+    fn gen_as_ref_match_arms(g_idx: usize) -> String {
+        (1..=g_idx)
+            .into_iter()
+            .map(|i| format!("Self::T{}(t{}) => {}::T{}(t{}),", i, i, gen_enum_name(g_idx), i, i))
+            .collect::<Vec<_>>()
+            .join("")
     }
 
-    fn gen_trait_bound_params(g_idx: usize, trait_bound_str: String) -> String {
+    format!(
+        "
+/// Converts from `&{or}<T1, ...>` to `{or}<&T1, ...>`.
+#[must_use]
+pub fn as_ref(&self) -> {or}<{refs}> {{
+    match self {{
+        {arms}
+    }}
+}}
+
+/// Converts from `&mut {or}<T1, ...>` to `{or}<&mut T1, ...>`.
+#[must_use]
+pub fn as_mut(&mut self) -> {or}<{muts}> {{
+    match self {{
+        {arms}
+    }}
+}}
+        ",
+        or = gen_enum_name(idx),
+        refs = gen_ref_generics(idx, "&"),
+        muts = gen_ref_generics(idx, "&mut "),
+        arms = gen_as_ref_match_arms(idx),
+    )
+}
+
+// gen
+// ```
+// pub fn into_options(self) -> (Option<T1>, Option<T2>, Option<T3>) {
+//     match self {
+//         Self::T1(t1) => (Some(t1), None, None),
+//         ...
+//     }
+// }
+// ```
+fn gen_method_into_options(idx: usize) -> String {
+    // gen
+    // ```
+    // Self::T1(t1) => (Some(t1), None, None),
+    // Self::T2(t2) => (None, Some(t2), None),
+    // Self::T3(t3) => (None, None, Some(t3)),
+    // ```
+    fn gen_into_options_match_arms(g_idx: usize) -> String {
         (1..=g_idx)
             .into_iter()
-            .map(|i| format!("T{}: {}", i, trait_bound_str))
+            .map(|i| {
+                format!(
+                    "Self::T{}(t{}) => ({}),",
+                    i,
+                    i,
+                    gen_options_tuple_values(g_idx, i, &format!("Some(t{})", i))
+                )
+            })
             .collect::<Vec<_>>()
-            .join(",\n")
+            .join("")
     }
 
-    format! {"
-{}
-impl <{}> {} <{}>
-where
-    {}
-{{   
-    {}
+    format!(
+        "
+/// Converts the enum into a tuple of Options, where only the slot of the active variant is `Some`.
+#[must_use]
+pub fn into_options(self) -> ({}) {{
+    match self {{
+        {}
+    }}
+}}
+        ",
+        gen_options_tuple_type(idx),
+        gen_into_options_match_arms(idx)
+    )
+}
+
+// gen
+// ```
+// pub fn as_options(&self) -> (Option<&T1>, Option<&T2>, Option<&T3>) {
+//     self.as_ref().into_options()
+// }
+// ```
+fn gen_method_as_options(idx: usize) -> String {
+    // gen `Option<&T1>, Option<&T2>, Option<&T3>`
+    fn gen_ref_options_tuple_type(g_idx: usize) -> String {
+        (1..=g_idx)
+            .into_iter()
+            .map(|i| format!("Option<&T{}>", i))
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    format!(
+        "
+/// Borrows the enum as a tuple of Options, where only the slot of the active variant is `Some`.
+#[must_use]
+pub fn as_options(&self) -> ({}) {{
+    self.as_ref().into_options()
+}}
+        ",
+        gen_ref_options_tuple_type(idx)
+    )
+}
+
+// gen
+// ```
+// pub fn try_from_options(
+//     options: (Option<T1>, Option<T2>, Option<T3>),
+// ) -> Result<Self, (Option<T1>, Option<T2>, Option<T3>)> {
+//     match options {
+//         (Some(t1), None, None) => Ok(Self::T1(t1)),
+//         ...
+//         options => Err(options),
+//     }
+// }
+// ```
+fn gen_method_try_from_options(idx: usize) -> String {
+    // gen
+    // ```
+    // (Some(t1), None, None) => Ok(Self::T1(t1)),
+    // (None, Some(t2), None) => Ok(Self::T2(t2)),
+    // (None, None, Some(t3)) => Ok(Self::T3(t3)),
+    // ```
+    fn gen_try_from_options_match_arms(g_idx: usize) -> String {
+        (1..=g_idx)
+            .into_iter()
+            .map(|i| {
+                format!(
+                    "({}) => Ok(Self::T{}(t{})),",
+                    gen_options_tuple_values(g_idx, i, &format!("Some(t{})", i)),
+                    i,
+                    i
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("")
+    }
+
+    format!(
+        "
+/// Builds the enum from a tuple of Options, which succeeds only when exactly one of them is `Some`.
+/// Otherwise the tuple is handed back as is.
+pub fn try_from_options(options: ({})) -> Result<Self, ({})> {{
+    match options {{
+        {}
+        options => Err(options),
+    }}
+}}
+        ",
+        gen_options_tuple_type(idx),
+        gen_options_tuple_type(idx),
+        gen_try_from_options_match_arms(idx)
+    )
+}
+
+// gen
+// ```
+// pub fn when_t1<R, F>(self, f: F) -> When<R, Or3<Infallible, T2, T3>>
+// where
+//     F: FnOnce(T1) -> R,
+// {
+//     ...
+// }
+// ...
+// ```
+fn gen_method_when_tx(idx: usize) -> String {
+    fn gen_method_when_tx_comment(when_idx: usize) -> String {
+        format!(
+            "
+/// Handles the T{} value of the enum using a provided function, and leaves the other
+/// variants to the subsequent `when_tx` calls.",
+            when_idx,
+        )
+    }
+
+    // gen
+    // ```
+    // Self::T1(t1) => When::handled(f(t1)),
+    // Self::T2(t2) => When::pending(Or3::T2(t2)),
+    // Self::T3(t3) => When::pending(Or3::T3(t3)),
+    // ```
+    fn gen_when_match_arms(g_idx: usize, when_idx: usize) -> String {
+        (1..=g_idx)
+            .into_iter()
+            .map(|i| {
+                if i == when_idx {
+                    format!("Self::T{}(t{}) => When::handled(f(t{})),", i, i, i)
+                } else {
+                    format!(
+                        "Self::T{}(t{}) => When::pending({}::T{}(t{})),",
+                        i,
+                        i,
+                        gen_enum_name(g_idx),
+                        i,
+                        i
+                    )
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("")
+    }
+
+    let closure = |x: usize| {
+        format!(
+            "
+{}
+pub fn when_t{}<R, F>(self, f: F) -> When<R, {}<{}>>
+where
+    F: FnOnce(T{}) -> R,
+{{
+    match self {{
+        {}
+    }}
+}}
+",
+            gen_method_when_tx_comment(x),
+            x,
+            gen_enum_name(idx),
+            gen_handled_generic_type(idx, &[x]),
+            x,
+            gen_when_match_arms(idx, x)
+        )
+    };
+
+    (1..=idx)
+        .into_iter()
+        .map(closure)
+        .collect::<Vec<_>>()
+        .join("")
+}
+
+// gen
+// ```
+// impl<T1, T2, T3, T4, T5> Concat<Or3<T3, T4, T5>> for Or2<T1, T2> {
+//     type Output = Or5<T1, T2, T3, T4, T5>;
+//     fn left(lhs: Self) -> Self::Output { ... }
+//     fn right(rhs: Or3<T3, T4, T5>) -> Self::Output { ... }
+// }
+// ...
+// ```
+// for every `Or` type on the right, whose concatenation is at most `Or{gen_count}`.
+fn gen_impl_concat(idx: usize, gen_count: usize) -> String {
+    let gen_impl = |rhs_idx: usize| {
+        let count = idx + rhs_idx;
+        let generics = |range: std::ops::RangeInclusive<usize>| {
+            range
+                .map(|i| format!("T{}", i))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let arms = |or: String, offset: usize, len: usize| {
+            (1..=len)
+                .map(|i| {
+                    format!(
+                        "{}::T{}(x) => {}::T{}(x),",
+                        or,
+                        i,
+                        gen_enum_name(count),
+                        i + offset
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+        format!(
+            "
+impl<{all}> Concat<{rhs}<{rhs_generics}>> for {lhs}<{lhs_generics}> {{
+    type Output = {out}<{all}>;
+
+    fn left(lhs: Self) -> Self::Output {{
+        match lhs {{
+            {left_arms}
+        }}
+    }}
+
+    fn right(rhs: {rhs}<{rhs_generics}>) -> Self::Output {{
+        match rhs {{
+            {right_arms}
+        }}
+    }}
+}}
+",
+            all = generics(1..=count),
+            lhs = gen_enum_name(idx),
+            lhs_generics = generics(1..=idx),
+            rhs = gen_enum_name(rhs_idx),
+            rhs_generics = generics(idx + 1..=count),
+            out = gen_enum_name(count),
+            left_arms = arms(gen_enum_name(idx), 0, idx),
+            right_arms = arms(gen_enum_name(rhs_idx), idx, rhs_idx),
+        )
+    };
+
+    (2..=gen_count.saturating_sub(idx))
+        .map(gen_impl)
+        .collect::<Vec<_>>()
+        .join("")
+}
+
+// gen
+// ```
+// impl<R, T1, T2, T3> When<R, Or3<T1, T2, T3>> {
+//     pub fn when_t1<F>(self, f: F) -> When<R, Or3<Infallible, T2, T3>> { ... }
+//     ...
+// }
+//
+// impl<R> When<R, Or3<Infallible, Infallible, Infallible>> {
+//     pub fn finish(self) -> R { ... }
+// }
+// ```
+fn gen_impl_when(idx: usize) -> String {
+    let gen_when_tx = |x: usize| {
+        format!(
+            "
+/// Handles the T{} value of the pending `Or{}` using a provided function,
+/// unless a previous `when_tx` has already handled the value.
+pub fn when_t{}<F>(self, f: F) -> When<R, {}<{}>>
+where
+    F: FnOnce(T{}) -> R,
+{{
+    match self.into_state() {{
+        Ok(result) => When::handled(result),
+        Err(or) => or.when_t{}(f),
+    }}
+}}
+",
+            x,
+            idx,
+            x,
+            gen_enum_name(idx),
+            gen_handled_generic_type(idx, &[x]),
+            x,
+            x
+        )
+    };
+
+    // gen
+    // ```
+    // Or3::T1(never) => match never {},
+    // Or3::T2(never) => match never {},
+    // Or3::T3(never) => match never {},
+    // ```
+    let finish_match_arms = (1..=idx)
+        .into_iter()
+        .map(|i| format!("{}::T{}(never) => match never {{}},", gen_enum_name(idx), i))
+        .collect::<Vec<_>>()
+        .join("");
+
+    format!(
+        "
+impl <R, {}> When<R, {}<{}>> {{
+    {}
+}}
+
+impl <R> When<R, {}<{}>> {{
+    /// Finishes the chain and returns the result. This is available only when every
+    /// variant of `Or{}` has been handled.
+    pub fn finish(self) -> R {{
+        match self.into_state() {{
+            Ok(result) => result,
+            Err(or) => match or {{
+                {}
+            }},
+        }}
+    }}
+}}
+    ",
+        gen_enum_generics(idx),
+        gen_enum_name(idx),
+        gen_enum_generics(idx),
+        (1..=idx)
+            .into_iter()
+            .map(gen_when_tx)
+            .collect::<Vec<_>>()
+            .join(""),
+        gen_enum_name(idx),
+        gen_handled_generic_type(idx, &(1..=idx).collect::<Vec<_>>()),
+        idx,
+        finish_match_arms
+    )
+}
+
+// gen
+// ```
+// impl<T1, T2> Or2<T1, T2> {
+//     pub const fn variant_sizes() -> [usize; 2] { ... }
+//     pub const fn variant_aligns() -> [usize; 2] { ... }
+//     pub const fn max_variant_size() -> usize { ... }
+// }
+// ```
+fn gen_impl_variant_sizes(idx: usize) -> String {
+    fn gen_array(idx: usize, f: &str) -> String {
+        (1..=idx)
+            .into_iter()
+            .map(|i| format!("{}::<T{}>()", f, i))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    let index_arms = (1..=idx)
+        .into_iter()
+        .map(|i| format!("Self::T{}(_) => {},", i, i - 1))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        "
+impl<{generics}> {or}<{generics}> {{
+    /// The number of the variants, which is the size of the arrays returned by
+    /// [`{or}::variant_sizes`] and friends, indexed by [`{or}::variant_index`].
+    pub const VARIANT_COUNT: usize = {idx};
+
+    /// Returns the 0-based position of the active variant, like `0` for `T1`.
+    ///
+    /// This can index a table of handlers or counters, with `VARIANT_COUNT` entries.
+    #[must_use]
+    pub const fn variant_index(&self) -> usize {{
+        match self {{
+            {index_arms}
+        }}
+    }}
+
+    /// Returns the sizes of the payload types, in the order of the variants.
+    ///
+    /// Comparing them with the size of the enum tells whether one oversized variant bloats
+    /// the whole enum, in which case it can be shrunk by [`{or}::boxed`].
+    pub const fn variant_sizes() -> [usize; {idx}] {{
+        [{sizes}]
+    }}
+
+    /// Returns the alignments of the payload types, in the order of the variants.
+    pub const fn variant_aligns() -> [usize; {idx}] {{
+        [{aligns}]
+    }}
+
+    /// Returns the size of the largest payload type, which the size of the enum is at least.
+    pub const fn max_variant_size() -> usize {{
+        let sizes = Self::variant_sizes();
+        let mut max = 0;
+        let mut i = 0;
+        while i < sizes.len() {{
+            if sizes[i] > max {{
+                max = sizes[i];
+            }}
+            i += 1;
+        }}
+        max
+    }}
+}}
+",
+        or = gen_enum_name(idx),
+        generics = gen_enum_generics(idx),
+        idx = idx,
+        sizes = gen_array(idx, "size_of"),
+        aligns = gen_array(idx, "align_of"),
+        index_arms = index_arms,
+    )
+}
+
+// gen
+// ```
+// impl<T1, T2> Or3<T1, T2, Infallible> {
+//     pub fn compact_t3(self) -> Or2<T1, T2> {
+//         ...
+//     }
+// }
+// ```
+// for each position of the enum.
+fn gen_impl_compact(idx: usize) -> String {
+    fn gen_impl_compact_comment(g_idx: usize, compact_idx: usize) -> String {
+        format!(
+            "
+/// Removes the uninhabited `T{}` position from `Or{}`, which is useful to simplify
+/// an `Or` type once some of its branches become impossible.",
+            compact_idx, g_idx,
+        )
+    }
+
+    // gen `T1, T2` for `Or3<T1, T2, Infallible>` with compact_idx = 3
+    fn gen_remaining_generics(g_idx: usize, compact_idx: usize) -> String {
+        (1..=g_idx)
+            .into_iter()
+            .filter(|i| *i != compact_idx)
+            .map(|i| format!("T{}", i))
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    // gen `T1, T2, Infallible` for `Or3<T1, T2, Infallible>` with compact_idx = 3
+    fn gen_compacted_generics(g_idx: usize, compact_idx: usize) -> String {
+        (1..=g_idx)
+            .into_iter()
+            .map(|i| {
+                if i == compact_idx {
+                    "Infallible".to_string()
+                } else {
+                    format!("T{}", i)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    // gen
+    // ```
+    // Self::T1(t1) => Or2::T1(t1),
+    // Self::T2(t2) => Or2::T2(t2),
+    // Self::T3(never) => match never {},
+    // ```
+    fn gen_compact_match_arms(g_idx: usize, compact_idx: usize) -> String {
+        (1..=g_idx)
+            .into_iter()
+            .map(|i| {
+                if i == compact_idx {
+                    format!("Self::T{}(never) => match never {{}},", i)
+                } else if g_idx == 2 {
+                    format!("Self::T{}(t{}) => t{},", i, i, i)
+                } else {
+                    let new_idx = if i < compact_idx { i } else { i - 1 };
+                    format!(
+                        "Self::T{}(t{}) => {}::T{}(t{}),",
+                        i,
+                        i,
+                        gen_enum_name(g_idx - 1),
+                        new_idx,
+                        i
+                    )
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("")
+    }
+
+    let closure = |x: usize| {
+        // `Or2` is compacted into the remaining type itself.
+        let compacted_type = if idx == 2 {
+            gen_remaining_generics(idx, x)
+        } else {
+            format!(
+                "{}<{}>",
+                gen_enum_name(idx - 1),
+                gen_remaining_generics(idx, x)
+            )
+        };
+
+        format!(
+            "
+impl <{}> {} <{}> {{
+    {}
+    #[must_use]
+    pub fn compact_t{}(self) -> {} {{
+        match self {{
+            {}
+        }}
+    }}
+}}
+",
+            gen_remaining_generics(idx, x),
+            gen_enum_name(idx),
+            gen_compacted_generics(idx, x),
+            gen_impl_compact_comment(idx, x),
+            x,
+            compacted_type,
+            gen_compact_match_arms(idx, x)
+        )
+    };
+
+    (1..=idx)
+        .into_iter()
+        .map(closure)
+        .collect::<Vec<_>>()
+        .join("")
+}
+
+// gen
+// ```
+// impl<T1, T2, T3> Or3<T1, T2, T3> {
+//     pub fn into_result(self) -> Result<T1, Or2<T2, T3>> { ... }
+//     pub fn try_into_result<E>(self) -> Result<T1, E> { ... }
+// }
+// impl<T1, T2, T3> From<Result<T1, Or2<T2, T3>>> for Or3<T1, T2, T3> { ... }
+// ```
+// `Or2` uses `T2` itself as the error, instead of an `Or` of the remaining types.
+fn gen_impl_result(idx: usize) -> String {
+    let rest_type = if idx == 2 {
+        "T2".to_string()
+    } else {
+        format!(
+            "{}<{}>",
+            gen_enum_name(idx - 1),
+            (2..=idx)
+                .into_iter()
+                .map(|i| format!("T{}", i))
+                .collect::<Vec<_>>()
+                .join(",")
+        )
+    };
+
+    // gen `Self::T2(t2) => Err(Or2::T1(t2)),` or `Self::T2(t2) => Err(t2),` for `Or2`
+    let into_arms = (2..=idx)
+        .into_iter()
+        .map(|i| {
+            if idx == 2 {
+                format!("Self::T{}(t{}) => Err(t{}),", i, i, i)
+            } else {
+                format!(
+                    "Self::T{}(t{}) => Err({}::T{}(t{})),",
+                    i,
+                    i,
+                    gen_enum_name(idx - 1),
+                    i - 1,
+                    i
+                )
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("");
+
+    // gen `Err(Or2::T1(t2)) => Self::T2(t2),` or `Err(t2) => Self::T2(t2),` for `Or2`
+    let from_arms = (2..=idx)
+        .into_iter()
+        .map(|i| {
+            if idx == 2 {
+                format!("Err(t{}) => Self::T{}(t{}),", i, i, i)
+            } else {
+                format!(
+                    "Err({}::T{}(t{})) => Self::T{}(t{}),",
+                    gen_enum_name(idx - 1),
+                    i - 1,
+                    i,
+                    i,
+                    i
+                )
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("");
+
+    // gen `Self::T2(t2) => Err(t2.into()),`
+    let try_into_arms = (2..=idx)
+        .into_iter()
+        .map(|i| format!("Self::T{}(t{}) => Err(t{}.into()),", i, i, i))
+        .collect::<Vec<_>>()
+        .join("");
+
+    // gen `T2: Into<E>, T3: Into<E>`
+    let into_bounds = (2..=idx)
+        .into_iter()
+        .map(|i| format!("T{}: Into<E>", i))
+        .collect::<Vec<_>>()
+        .join(",");
+
+    format!(
+        "
+impl <{generics}> {or} <{generics}> {{
+    /// Converts the enum into a `Result`, treating `T1` as the ok value and the others as the
+    /// error. The conversion is lossless, and can be reverted by `From`.
+    pub fn into_result(self) -> Result<T1, {rest}> {{
+        match self {{
+            Self::T1(t1) => Ok(t1),
+            {into_arms}
+        }}
+    }}
+
+    /// Converts the enum into a `Result`, treating `T1` as the ok value and converting the
+    /// others into the error type `E`.
+    pub fn try_into_result<E>(self) -> Result<T1, E>
+    where
+        {into_bounds}
+    {{
+        match self {{
+            Self::T1(t1) => Ok(t1),
+            {try_into_arms}
+        }}
+    }}
+}}
+
+/// Converts the ok value into `T1` and the error into the remaining positions, which reverts
+/// `into_result`.
+impl <{generics}> From<Result<T1, {rest}>> for {or} <{generics}> {{
+    fn from(result: Result<T1, {rest}>) -> Self {{
+        match result {{
+            Ok(t1) => Self::T1(t1),
+            {from_arms}
+        }}
+    }}
 }}
-    ",
-    gen_impl_block_with_trait_bound_comment(idx),
-    gen_enum_generics(idx),
-    gen_enum_name(idx),
-    gen_enum_generics(idx),
-    gen_trait_bound_params(idx, "'static".to_string()),
-    gen_method_is(idx)
-    }
+",
+        generics = gen_enum_generics(idx),
+        or = gen_enum_name(idx),
+        rest = rest_type,
+        into_arms = into_arms,
+        into_bounds = into_bounds,
+        try_into_arms = try_into_arms,
+        from_arms = from_arms,
+    )
 }
 
 // gen
 // ```
-// pub fn is<T: 'static>(&self) -> bool {
-//     match self {
-//         Self::T1(_) => TypeId::of::<T>() == TypeId::of::<T1>(),
-//         Self::T2(_) => TypeId::of::<T>() == TypeId::of::<T2>(),
-//     }
+// impl<T1, T2> Or3<T1, T2, ()> {
+//     pub fn into_option(self) -> Option<Or2<T1, T2>> { ... }
 // }
+// impl<T1, T2> From<Option<Or2<T1, T2>>> for Or3<T1, T2, ()> { ... }
 // ```
-fn gen_method_is(idx: usize) -> String {
-    fn gen_is_match_arm(g_idx: usize) -> String {
-        (1..=g_idx)
-            .into_iter()
-            .map(|i| {
+// `Or2` uses `T1` itself as the value, like `Or2<T, ()>` and `Option<T>`.
+fn gen_impl_option(idx: usize) -> String {
+    let remaining_generics = (1..idx)
+        .into_iter()
+        .map(|i| format!("T{}", i))
+        .collect::<Vec<_>>()
+        .join(",");
+    let some_type = if idx == 2 {
+        "T1".to_string()
+    } else {
+        format!("{}<{}>", gen_enum_name(idx - 1), remaining_generics)
+    };
+
+    // gen `Self::T1(t1) => Some(Or2::T1(t1)),` or `Self::T1(t1) => Some(t1),` for `Or2`
+    let into_arms = (1..idx)
+        .into_iter()
+        .map(|i| {
+            if idx == 2 {
+                format!("Self::T{}(t{}) => Some(t{}),", i, i, i)
+            } else {
                 format!(
-                    "Self::T{}(_) => TypeId::of::<T>() == TypeId::of::<T{}>()",
-                    i, i
+                    "Self::T{}(t{}) => Some({}::T{}(t{})),",
+                    i,
+                    i,
+                    gen_enum_name(idx - 1),
+                    i,
+                    i
                 )
-            })
-            .collect::<Vec<_>>()
-            .join(",\n")
-    }
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("");
+
+    // gen `Some(Or2::T1(t1)) => Self::T1(t1),` or `Some(t1) => Self::T1(t1),` for `Or2`
+    let from_arms = (1..idx)
+        .into_iter()
+        .map(|i| {
+            if idx == 2 {
+                format!("Some(t{}) => Self::T{}(t{}),", i, i, i)
+            } else {
+                format!(
+                    "Some({}::T{}(t{})) => Self::T{}(t{}),",
+                    gen_enum_name(idx - 1),
+                    i,
+                    i,
+                    i,
+                    i
+                )
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("");
 
     format!(
         "
-pub fn is_type<T: 'static>(&self) -> bool {{
-    match self {{
-        {}
+impl <{generics}> {or} <{generics}, ()> {{
+    /// Converts the enum into an `Option`, which is `None` for the `()` in the last position.
+    /// The conversion is lossless, and can be reverted by `From`.
+    pub fn into_option(self) -> Option<{some}> {{
+        match self {{
+            {into_arms}
+            Self::T{idx}(()) => None,
+        }}
     }}
 }}
-        ",
-        gen_is_match_arm(idx)
+
+/// Converts `None` into the `()` in the last position, which reverts `into_option`.
+impl <{generics}> From<Option<{some}>> for {or} <{generics}, ()> {{
+    fn from(option: Option<{some}>) -> Self {{
+        match option {{
+            {from_arms}
+            None => Self::T{idx}(()),
+        }}
+    }}
+}}
+",
+        generics = remaining_generics,
+        or = gen_enum_name(idx),
+        some = some_type,
+        idx = idx,
+        into_arms = into_arms,
+        from_arms = from_arms,
     )
 }
 
@@ -204,11 +1970,9 @@ fn gen_method_is_tx(idx: usize) -> String {
         format!(
             "
 {}
+#[must_use]
 pub fn is_t{}(&self) -> bool {{
-    match self {{
-        Self::T{}(_) => true,
-        _ => false,
-    }}
+    matches!(self, Self::T{}(_))
 }}
         ",
             gen_method_is_tx_comment(x),
@@ -219,7 +1983,7 @@ pub fn is_t{}(&self) -> bool {{
 
     (1..=idx)
         .into_iter()
-        .map(|i| closure(i))
+        .map(closure)
         .collect::<Vec<_>>()
         .join("")
 }
@@ -286,7 +2050,7 @@ fn gen_method_fold(idx: usize) -> String {
     // F1, F2, F3
     // ```
     fn gen_fold_generics_arg(g_idx: usize) -> String {
-        (0..=g_idx)
+        (1..=g_idx)
             .into_iter()
             .map(|i| format!("F{}", i))
             .collect::<Vec<_>>()
@@ -296,6 +2060,8 @@ fn gen_method_fold(idx: usize) -> String {
     format!(
         "
 {}
+#[must_use]
+#[allow(clippy::too_many_arguments)]
 pub fn fold<T, {}>(self, {}) -> T
 where
         {}
@@ -313,10 +2079,152 @@ where
     )
 }
 
+// gen
+// ```
+// pub fn try_fold<T, E, F1, F2, F3>(self, f1: F1, f2: F2, f3: F3) -> Result<T, E>
+// where
+//     F1: FnOnce(T1) -> Result<T, E>,
+//     F2: FnOnce(T2) -> Result<T, E>,
+//     F3: FnOnce(T3) -> Result<T, E>,
+// {
+// }
+// ```
+fn gen_method_try_fold(idx: usize) -> String {
+    // gen `F1: FnOnce(T1) -> Result<T, E>, ...`
+    let where_clause = (1..=idx)
+        .into_iter()
+        .map(|i| format!("F{}: FnOnce(T{}) -> Result<T, E>", i, i))
+        .collect::<Vec<_>>()
+        .join(",");
+    let generics = (1..=idx)
+        .into_iter()
+        .map(|i| format!("F{}", i))
+        .collect::<Vec<_>>()
+        .join(",");
+    let args = (1..=idx)
+        .into_iter()
+        .map(|i| format!("f{}: F{}", i, i))
+        .collect::<Vec<_>>()
+        .join(",");
+    let match_arms = (1..=idx)
+        .into_iter()
+        .map(|i| format!("Self::T{}(t{}) => f{}(t{})", i, i, i, i))
+        .collect::<Vec<_>>()
+        .join(",");
+
+    format!(
+        "
+/// Consolidates the `Or{}` enum into a single value of type `T` by applying provided
+/// fallible functions, returning the error of the applied one, so that the functions can use `?`.
+#[allow(clippy::too_many_arguments)]
+pub fn try_fold<T, E, {}>(self, {}) -> Result<T, E>
+where
+        {}
+{{
+    match self {{
+        {}
+    }}
+}}
+    ",
+        idx, generics, args, where_clause, match_arms
+    )
+}
+
+// gen
+// ```
+// pub async fn fold_async<T, F1, Fut1, F2, Fut2>(self, f1: F1, f2: F2) -> T
+// where
+//     F1: FnOnce(T1) -> Fut1,
+//     Fut1: Future<Output = T>,
+//     F2: FnOnce(T2) -> Fut2,
+//     Fut2: Future<Output = T>,
+// {
+// }
+// ```
+fn gen_method_fold_async(idx: usize) -> String {
+    fn gen_method_fold_async_comment(g_idx: usize) -> String {
+        format!(
+            "
+/// Consolidates the `Or{}` enum into a single value of type `T`,
+/// by applying provided async functions and awaiting the result.",
+            g_idx,
+        )
+    }
+
+    // gen `F1, Fut1, F2, Fut2, F3, Fut3`
+    fn gen_fold_async_generics_arg(g_idx: usize) -> String {
+        (1..=g_idx)
+            .into_iter()
+            .map(|i| format!("F{}, Fut{}", i, i))
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    // gen `f1: F1, f2: F2, f3: F3`
+    fn gen_fold_async_args(g_idx: usize) -> String {
+        (1..=g_idx)
+            .into_iter()
+            .map(|i| format!("f{}: F{}", i, i))
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    // gen
+    // ```
+    // F1: FnOnce(T1) -> Fut1,
+    // Fut1: Future<Output = T>,
+    // ...
+    // ```
+    fn gen_fold_async_where(g_idx: usize) -> String {
+        (1..=g_idx)
+            .into_iter()
+            .map(|i| format!("F{}: FnOnce(T{}) -> Fut{}, Fut{}: Future<Output = T>", i, i, i, i))
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    // gen
+    // ```
+    // Self::T1(t1) => f1(t1).await,
+    // Self::T2(t2) => f2(t2).await,
+    // ```
+    fn gen_fold_async_match_arms(g_idx: usize) -> String {
+        (1..=g_idx)
+            .into_iter()
+            .map(|i| format!("Self::T{}(t{}) => f{}(t{}).await", i, i, i, i))
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    format!(
+        "
+{}
+#[allow(clippy::too_many_arguments)]
+pub async fn fold_async<T, {}>(self, {}) -> T
+where
+        {}
+{{
+    match self {{
+        {}
+    }}
+}}
+    ",
+        gen_method_fold_async_comment(idx),
+        gen_fold_async_generics_arg(idx),
+        gen_fold_async_args(idx),
+        gen_fold_async_where(idx),
+        gen_fold_async_match_arms(idx)
+    )
+}
+
 // gen
 // ```
 // pub fn as_t1(self) -> Option<T1> {
 // }
+// pub fn as_t1_ref(&self) -> Option<&T1> {
+// }
+// pub fn as_t1_mut(&mut self) -> Option<&mut T1> {
+// }
 // pub fn as_t2(self) -> Option<T2> {
 // }
 // ...
@@ -334,25 +2242,94 @@ fn gen_method_as_tx(idx: usize) -> String {
         format!(
             "
 {}
-pub fn as_t{}(self) -> Option<T{}>{{
+#[must_use]
+pub fn as_t{x}(self) -> Option<T{x}>{{
+    match self {{
+        Self::T{x}(t{x}) => Some(t{x}),
+        _ => None,
+    }}
+}}
+
+/// Returns a reference to the T{x} value, if it is of type T{x}, without consuming the enum.
+#[must_use]
+pub fn as_t{x}_ref(&self) -> Option<&T{x}>{{
+    match self {{
+        Self::T{x}(t{x}) => Some(t{x}),
+        _ => None,
+    }}
+}}
+
+/// Returns a mutable reference to the T{x} value, if it is of type T{x}.
+#[must_use]
+pub fn as_t{x}_mut(&mut self) -> Option<&mut T{x}>{{
     match self {{
-        Self::T{}(t{}) => Some(t{}),
+        Self::T{x}(t{x}) => Some(t{x}),
         _ => None,
     }}
 }}
         ",
             gen_method_as_tx_comment(x),
-            x,
-            x,
-            x,
-            x,
-            x
+            x = x
+        )
+    };
+
+    (1..=idx)
+        .into_iter()
+        .map(closure)
+        .collect::<Vec<_>>()
+        .join("")
+}
+
+// gen
+// ```
+// #[track_caller]
+// pub fn unwrap_t1(self) -> T1 { ... }
+// #[track_caller]
+// pub fn expect_t1(self, msg: &str) -> T1 { ... }
+// ...
+// ```
+// `#[track_caller]` makes the panics point at the caller, not at this file.
+fn gen_method_unwrap_tx(idx: usize) -> String {
+    let closure = |x: usize| {
+        format!(
+            "
+/// Returns the T{x} value, consuming the enum.
+///
+/// # Panics
+///
+/// Panics if the enum is not of type T{x}, naming the variant it is of.
+#[track_caller]
+pub fn unwrap_t{x}(self) -> T{x} {{
+    match self {{
+        Self::T{x}(t{x}) => t{x},
+        _ => panic!(
+            \"called `{or}::unwrap_t{x}()` on a `{{:?}}` value\",
+            self.discriminant()
+        ),
+    }}
+}}
+
+/// Returns the T{x} value, consuming the enum.
+///
+/// # Panics
+///
+/// Panics with `msg` if the enum is not of type T{x}.
+#[track_caller]
+pub fn expect_t{x}(self, msg: &str) -> T{x} {{
+    match self {{
+        Self::T{x}(t{x}) => t{x},
+        _ => panic!(\"{{}}\", msg),
+    }}
+}}
+        ",
+            or = gen_enum_name(idx),
+            x = x
         )
     };
 
     (1..=idx)
         .into_iter()
-        .map(|i| closure(i))
+        .map(closure)
         .collect::<Vec<_>>()
         .join("")
 }
@@ -410,6 +2387,7 @@ fn gen_method_map_tx(idx: usize) -> String {
         format!(
             "
 {}
+#[must_use = \"this returns the mapped enum, without modifying the original\"]
 pub fn map_t{}<F, B>(self, f: F) -> {}<{}>
 where
     F: FnOnce(T{}) -> B,
@@ -428,13 +2406,73 @@ where
         )
     };
 
-    let res = (1..=idx)
+    (1..=idx)
         .into_iter()
-        .map(|i| closure(i))
+        .map(closure)
         .collect::<Vec<_>>()
-        .join("");
+        .join("")
+}
+
+// gen
+// ```
+// pub async fn map_t1_async<F, Fut, B>(self, f: F) -> Or3<B, T2, T3>
+// where
+//     F: FnOnce(T1) -> Fut,
+//     Fut: Future<Output = B>,
+// {
+//     ...
+// }
+// ...
+// ```
+fn gen_method_map_tx_async(idx: usize) -> String {
+    // gen
+    // ```
+    // Self::T1(t1) => Or3::T1(f(t1).await),
+    // Self::T2(t2) => Or3::T2(t2),
+    // Self::T3(t3) => Or3::T3(t3),
+    // ```
+    fn gen_map_async_match_arms(g_idx: usize, map_idx: usize) -> String {
+        (1..=g_idx)
+            .into_iter()
+            .map(|i| {
+                let value = if i == map_idx {
+                    format!("f(t{}).await", i)
+                } else {
+                    format!("t{}", i)
+                };
+                format!("Self::T{}(t{}) => {}::T{}({}),", i, i, gen_enum_name(g_idx), i, value)
+            })
+            .collect::<Vec<_>>()
+            .join("")
+    }
+
+    let closure = |x: usize| {
+        format!(
+            "
+/// Transforms the T{x} value of the enum using a provided async function and awaits it,
+/// maintaining other types as is.
+pub async fn map_t{x}_async<F, Fut, B>(self, f: F) -> {or}<{generics}>
+where
+    F: FnOnce(T{x}) -> Fut,
+    Fut: Future<Output = B>,
+{{
+    match self {{
+        {arms}
+    }}
+}}
+",
+            x = x,
+            or = gen_enum_name(idx),
+            generics = gen_rewrited_generic_type(gen_enum_generics(idx), x, "B".to_string()),
+            arms = gen_map_async_match_arms(idx, x)
+        )
+    };
 
-    res
+    (1..=idx)
+        .into_iter()
+        .map(closure)
+        .collect::<Vec<_>>()
+        .join("")
 }
 
 // gen
@@ -457,6 +2495,10 @@ fn gen_enum_decl(idx: usize) -> String {
     format!(
         "
 {}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = \"serde\", derive(serde::Serialize, serde::Deserialize), serde(untagged))]
+#[cfg_attr(feature = \"repr-c\", repr(C))]
+#[cfg_attr(feature = \"repr-u8\", repr(u8))]
 pub enum {} <{}> {{
    {} 
 }}
@@ -480,7 +2522,7 @@ fn gen_enum_generics(idx: usize) -> String {
         .map(|i| format!("T{}", i))
         .collect::<Vec<_>>()
         .join(",");
-    format!("{}", enum_generics)
+    enum_generics.to_string()
 }
 
 // gen
@@ -506,7 +2548,72 @@ fn gen_enum_field(idx: usize) -> String {
     s
 }
 
+// gen
+// ```
+// T1: 'static,
+// T2: 'static,
+// T3: 'static
+// ```
+fn gen_trait_bound_params(idx: usize, trait_bound_str: String) -> String {
+    (1..=idx)
+        .into_iter()
+        .map(|i| format!("T{}: {}", i, trait_bound_str))
+        .collect::<Vec<_>>()
+        .join(",\n")
+}
+
+// gen `Option<T1>, Option<T2>, Option<T3>` with idx = 3
+fn gen_options_tuple_type(idx: usize) -> String {
+    (1..=idx)
+        .into_iter()
+        .map(|i| format!("Option<T{}>", i))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+// gen `None, Some(t2), None` with idx = 3, some_idx = 2 and some_value = "Some(t2)"
+fn gen_options_tuple_values(idx: usize, some_idx: usize, some_value: &str) -> String {
+    (1..=idx)
+        .into_iter()
+        .map(|i| {
+            if i == some_idx {
+                some_value.to_string()
+            } else {
+                "None".to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+// gen `Infallible, T2, Infallible` with idx = 3 and handled_idxs = [1, 3]
+fn gen_handled_generic_type(idx: usize, handled_idxs: &[usize]) -> String {
+    (1..=idx)
+        .into_iter()
+        .map(|i| {
+            if handled_idxs.contains(&i) {
+                "Infallible".to_string()
+            } else {
+                format!("T{}", i)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
 // "T1, T2, T3", B, 1 -> T1, B, T3
 fn gen_rewrited_generic_type(input_typ: String, g_idx: usize, rewrited_type_str: String) -> String {
-    input_typ.replace(format!("T{}", g_idx).as_str(), &rewrited_type_str)
+    // replaced as a whole, so that `T1` does not match the head of `T10`.
+    let target = format!("T{}", g_idx);
+    input_typ
+        .split(',')
+        .map(|typ| {
+            if typ.trim() == target {
+                rewrited_type_str.as_str()
+            } else {
+                typ
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
 }
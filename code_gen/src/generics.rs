@@ -0,0 +1,116 @@
+//! Renaming of the type parameters of the generated `Or` types, like `Or2<Left, Right>`
+//! instead of `Or2<T1, T2>`, which reads better in the rustdoc signatures.
+//!
+//! The code is generated with `T1` ... `Tn` as usual, and each of them is renamed afterwards,
+//! unless it names a variant. The variants keep their names, since `Or2::T1(x)` and the methods
+//! like `as_t1` are named after them. A `Tn` names a variant when it follows `::`, like
+//! `Self::T1`, or when it is followed by `(`, like `T1(T1)` in the enum declaration.
+
+/// Renames `T1` ... `Tn` used as type parameters to `names[0]` ... `names[n - 1]`, including
+/// the ones in the doc comments.
+///
+/// Fails if there are fewer names than `gen_count`, if a name is not a valid identifier or
+/// given more than once, or if it is already used in the code, like `F` in `map_t1<F, B>`.
+/// A name used only after `::`, like `Left` in `Either::Left`, does not collide.
+pub fn rename_generics(code: &str, names: &[String], gen_count: usize) -> Result<String, String> {
+    validate_names(code, names, gen_count)?;
+
+    let mut renamed = String::with_capacity(code.len());
+    for line in code.lines() {
+        // the variants listed for `as_dyn!`, which also names its type parameters after them.
+        if line.contains("__as_dyn_impl!") {
+            renamed.push_str(line);
+        } else {
+            renamed.push_str(&rename_line(line, names, gen_count));
+        }
+        renamed.push('\n');
+    }
+    Ok(renamed)
+}
+
+fn validate_names(code: &str, names: &[String], gen_count: usize) -> Result<(), String> {
+    if names.len() < gen_count {
+        return Err(format!(
+            "{} names are given for the type parameters, but `Or{}` needs {}.",
+            names.len(),
+            gen_count,
+            gen_count
+        ));
+    }
+    let idents = idents(code);
+    for (i, name) in names.iter().take(gen_count).enumerate() {
+        if !is_ident(name) {
+            return Err(format!("`{}` is not a valid type parameter name.", name));
+        }
+        if names[..i].contains(name) {
+            return Err(format!("`{}` is given more than once.", name));
+        }
+        if idents.iter().any(|ident| ident == name) {
+            return Err(format!(
+                "`{}` is already used in the generated code, and can not name a type parameter.",
+                name
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn rename_line(line: &str, names: &[String], gen_count: usize) -> String {
+    let mut renamed = String::with_capacity(line.len());
+    let mut rest = line;
+    while let Some(start) = rest.find(|c: char| is_ident_char(c)) {
+        let (before, word) = rest.split_at(start);
+        let len = word.find(|c: char| !is_ident_char(c)).unwrap_or(word.len());
+        let (word, after) = word.split_at(len);
+        renamed.push_str(before);
+
+        let preceded_by_path = renamed.trim_end().ends_with("::");
+        let followed_by_paren = after.trim_start().starts_with('(');
+        match type_parameter_index(word, gen_count) {
+            Some(index) if !preceded_by_path && !followed_by_paren => {
+                renamed.push_str(&names[index - 1])
+            }
+            _ => renamed.push_str(word),
+        }
+        rest = after;
+    }
+    renamed.push_str(rest);
+    renamed
+}
+
+// get 3 from `T3`, if it is one of `T1` ... `T{gen_count}`.
+fn type_parameter_index(word: &str, gen_count: usize) -> Option<usize> {
+    let index = word.strip_prefix('T')?.parse::<usize>().ok()?;
+    if (1..=gen_count).contains(&index) && !word.starts_with("T0") {
+        Some(index)
+    } else {
+        None
+    }
+}
+
+// the idents which are not a segment of a path after `::`, like `Left` in `Either::Left(t1)`,
+// which do not collide with a type parameter of the same name.
+fn idents(code: &str) -> Vec<&str> {
+    let mut idents = Vec::new();
+    let mut rest = code;
+    while let Some(start) = rest.find(is_ident_char) {
+        let preceded_by_path = rest[..start].trim_end().ends_with("::");
+        let word = &rest[start..];
+        let len = word.find(|c: char| !is_ident_char(c)).unwrap_or(word.len());
+        if !preceded_by_path {
+            idents.push(&word[..len]);
+        }
+        rest = &word[len..];
+    }
+    idents
+}
+
+fn is_ident(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(is_ident_char)
+        && name != "_"
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
@@ -0,0 +1,1722 @@
+//! Code generation of the impls for third-party crates.
+//!
+//! Each integration is written into its own module under `or-rs/src/integrations`,
+//! which is compiled only when the corresponding cargo feature of `or-rs` is enabled.
+
+use crate::{gen_enum_generics, gen_enum_name, gen_trait_bound_params};
+
+// returns pairs of the module name and the generated code of each integration.
+pub(crate) fn gen_code_strings(gen_count: usize) -> Vec<(&'static str, String)> {
+    vec![
+        (
+            "embedded_hal",
+            gen_integration_code_string(
+                gen_count,
+                "`embedded-hal` drivers of the `Or` types, enabled by the `embedded-hal` feature.
+//!
+//! The I2C, SPI and serial traits are forwarded to the driver of the active variant, so the
+//! choice between peripherals made at init time can be stored without a `dyn` trait object.
+//! Every variant must share the same error type.",
+                "use embedded_hal::i2c::{self, AddressMode, I2c};
+use embedded_hal::spi::{self, SpiBus, SpiDevice};
+use embedded_hal_nb::nb;
+use embedded_hal_nb::serial;",
+                |i| [gen_impl_i2c(i), gen_impl_spi(i), gen_impl_serial(i)].join(""),
+            ),
+        ),
+        (
+            "pyo3",
+            gen_integration_code_string(
+                gen_count,
+                "`pyo3` conversions of the `Or` types, enabled by the `pyo3` feature.",
+                "use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;",
+                |i| [gen_impl_into_py(i), gen_impl_from_py_object(i)].join(""),
+            ),
+        ),
+        (
+            "async_graphql",
+            gen_integration_code_string(
+                gen_count,
+                "`async-graphql` union output of the `Or` types, enabled by the `async-graphql` feature.",
+                "use std::borrow::Cow;
+
+use ::async_graphql::indexmap::IndexSet;
+use ::async_graphql::parser::types::Field;
+use ::async_graphql::registry::{MetaType, MetaTypeId, Registry};
+use ::async_graphql::resolver_utils::{resolve_container, ContainerType, Fields};
+use ::async_graphql::{
+    Context, ContextSelectionSet, ObjectType, OutputType, Positioned, ServerResult, UnionType,
+    Value,
+};",
+                |i| {
+                    [
+                        gen_impl_output_type(i),
+                        gen_impl_container_type(i),
+                        gen_impl_union_type(i),
+                    ]
+                    .join("")
+                },
+            ),
+        ),
+        (
+            "borsh",
+            gen_integration_code_string(
+                gen_count,
+                "`borsh` serialization of the `Or` types, enabled by the `borsh` feature.
+//!
+//! A value is encoded as the (0-based) `u8` index of its variant followed by the payload.",
+                "use borsh::io::{Error, ErrorKind, Read, Result, Write};
+use borsh::{BorshDeserialize, BorshSerialize};",
+                |i| [gen_impl_borsh_serialize(i), gen_impl_borsh_deserialize(i)].join(""),
+            ),
+        ),
+        (
+            "either",
+            gen_integration_code_string(
+                gen_count,
+                "`either` conversions of the `Or` types, enabled by the `either` feature.
+//!
+//! `Or2<L, R>` converts into `Either<L, R>` and back, and is folded like `Either` with
+//! `fold_either`. The larger `Or` types convert into an `Either` whose right side is an `Or` of
+//! the remaining types, like `Either<T1, Or2<T2, T3>>` for `Or3<T1, T2, T3>`.",
+                "use either::Either;",
+                gen_impl_either,
+            ),
+        ),
+        (
+            "frunk",
+            gen_integration_code_string(
+                gen_count,
+                "`frunk` conversions of the `Or` types, enabled by the `frunk` feature.
+//!
+//! `Or3<T1, T2, T3>` converts into `Coprod!(T1, T2, T3)` and back, keeping the position of
+//! the value.",
+                "use frunk_core::coproduct::{CNil, Coproduct};",
+                gen_impl_coproduct,
+            ),
+        ),
+        (
+            "futures",
+            gen_integration_code_string(
+                gen_count,
+                "`futures` streams of the `Or` types, enabled by the `futures` feature.",
+                "use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::stream::{FusedStream, Stream};",
+                gen_impl_stream,
+            ),
+        ),
+        (
+            "anyhow",
+            gen_integration_code_string(
+                gen_count,
+                "`anyhow` conversions of the `Or` types, enabled by the `anyhow` feature.",
+                "use std::error::Error as StdError;
+use std::fmt::Display;",
+                gen_impl_anyhow,
+            ),
+        ),
+        (
+            "miette",
+            gen_integration_code_string(
+                gen_count,
+                "`miette` diagnostics of the `Or` types, enabled by the `miette` feature.",
+                "use std::fmt::Display;
+
+use miette::{Diagnostic, LabeledSpan, Severity, SourceCode};",
+                gen_impl_diagnostic,
+            ),
+        ),
+        (
+            "rocket",
+            gen_integration_code_string(
+                gen_count,
+                "`rocket` responses of the `Or` types, enabled by the `rocket` feature.",
+                "use rocket::request::Request;
+use rocket::response::{self, Responder};",
+                gen_impl_responder,
+            ),
+        ),
+        (
+            "serde_tagged",
+            gen_integration_code_string(
+                gen_count,
+                "`serde` impls of the tagged representation of the `Or` types, enabled by the `serde`
+//! feature.
+//!
+//! Each `Or` type is serialized and deserialized through a private mirror enum, which derives
+//! the adjacently tagged representation.",
+                "use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::tagged::Tagged;",
+                gen_impl_tagged,
+            ),
+        ),
+        (
+            "tokio",
+            gen_integration_code_string(
+                gen_count,
+                "`tokio` I/O of the `Or` types, enabled by the `tokio` feature.
+//!
+//! The I/O traits are forwarded to the I/O object of the active variant, so a connection
+//! chosen at runtime, like a `TcpStream` or a `UnixStream`, can be held as a single type.",
+                "use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use tokio::io::{AsyncBufRead, AsyncRead, AsyncSeek, AsyncWrite, ReadBuf};",
+                |i| {
+                    [
+                        gen_impl_async_read(i),
+                        gen_impl_async_buf_read(i),
+                        gen_impl_async_write(i),
+                        gen_impl_async_seek(i),
+                    ]
+                    .join("")
+                },
+            ),
+        ),
+        (
+            "tower",
+            gen_integration_code_string(
+                gen_count,
+                "`tower` services of the `Or` types, enabled by the `tower` feature.",
+                "use core::task::{Context, Poll};
+
+use tower_service::Service;",
+                gen_impl_service,
+            ),
+        ),
+    ]
+}
+
+fn gen_integration_code_string(
+    gen_count: usize,
+    top_doc: &str,
+    import_stmts: &str,
+    gen_repeating: fn(usize) -> String,
+) -> String {
+    let repeating = (2..=gen_count)
+        .into_iter()
+        .map(gen_repeating)
+        .collect::<Vec<_>>()
+        .join("");
+
+    format!(
+        "
+//! {}
+
+{}
+
+use crate::enums::*;
+{}",
+        top_doc, import_stmts, repeating
+    )
+}
+
+// gen `e1, e2, e3` with idx = 3
+fn gen_error_args(idx: usize) -> String {
+    (1..=idx)
+        .into_iter()
+        .map(|i| format!("e{}", i))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+// gen
+// ```
+// impl<T1, T2> IntoPy<PyObject> for Or2<T1, T2>
+// where
+//     T1: IntoPy<PyObject>,
+//     T2: IntoPy<PyObject>,
+// {
+//     ...
+// }
+// ```
+fn gen_impl_into_py(idx: usize) -> String {
+    // gen
+    // ```
+    // Self::T1(t1) => t1.into_py(py),
+    // Self::T2(t2) => t2.into_py(py),
+    // ```
+    let match_arms = (1..=idx)
+        .into_iter()
+        .map(|i| format!("Self::T{}(t{}) => t{}.into_py(py),", i, i, i))
+        .collect::<Vec<_>>()
+        .join("");
+
+    format!(
+        "
+/// Converts the value of the active variant into a Python object.
+impl <{generics}> IntoPy<PyObject> for {or} <{generics}>
+where
+    {bounds}
+{{
+    fn into_py(self, py: Python<'_>) -> PyObject {{
+        match self {{
+            {arms}
+        }}
+    }}
+}}
+    ",
+        generics = gen_enum_generics(idx),
+        or = gen_enum_name(idx),
+        bounds = gen_trait_bound_params(idx, "IntoPy<PyObject>".to_string()),
+        arms = match_arms,
+    )
+}
+
+// gen
+// ```
+// impl<'py, T1, T2> FromPyObject<'py> for Or2<T1, T2>
+// where
+//     T1: FromPyObject<'py>,
+//     T2: FromPyObject<'py>,
+// {
+//     ...
+// }
+// ```
+fn gen_impl_from_py_object(idx: usize) -> String {
+    // gen
+    // ```
+    // let e1 = match ob.extract::<T1>() {
+    //     Ok(t1) => return Ok(Self::T1(t1)),
+    //     Err(e1) => e1,
+    // };
+    // ...
+    // ```
+    let attempts = (1..=idx)
+        .into_iter()
+        .map(|i| {
+            format!(
+                "let e{} = match ob.extract::<T{}>() {{
+    Ok(t{}) => return Ok(Self::T{}(t{})),
+    Err(e{}) => e{},
+}};",
+                i, i, i, i, i, i, i
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    // gen `T1: {}, T2: {}, T3: {}`
+    let error_format = (1..=idx)
+        .into_iter()
+        .map(|i| format!("T{}: {{}}", i))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!(
+        "
+/// Extracts `T1` ... `T{idx}` from a Python object in order, and the first successful
+/// extraction wins. If every type fails, a `TypeError` describing all the failures is raised.
+impl <'py, {generics}> FromPyObject<'py> for {or} <{generics}>
+where
+    {bounds}
+{{
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {{
+        {attempts}
+        Err(PyTypeError::new_err(format!(
+            \"failed to extract {or} ({error_format})\",
+            {args}
+        )))
+    }}
+}}
+    ",
+        idx = idx,
+        generics = gen_enum_generics(idx),
+        or = gen_enum_name(idx),
+        bounds = gen_trait_bound_params(idx, "FromPyObject<'py>".to_string()),
+        attempts = attempts,
+        error_format = error_format,
+        args = gen_error_args(idx),
+    )
+}
+
+// gen
+// ```
+// impl<T1, T2> OutputType for Or2<T1, T2>
+// where
+//     T1: ObjectType,
+//     T2: ObjectType,
+// {
+//     ...
+// }
+// ```
+fn gen_impl_output_type(idx: usize) -> String {
+    // gen `T1::type_name(), T2::type_name(), T3::type_name()`
+    let type_names = (1..=idx)
+        .into_iter()
+        .map(|i| format!("T{}::type_name()", i))
+        .collect::<Vec<_>>()
+        .join(",");
+
+    // gen
+    // ```
+    // Self::T1(t1) => t1.introspection_type_name(),
+    // Self::T2(t2) => t2.introspection_type_name(),
+    // ```
+    let introspection_match_arms = (1..=idx)
+        .into_iter()
+        .map(|i| format!("Self::T{}(t{}) => t{}.introspection_type_name(),", i, i, i))
+        .collect::<Vec<_>>()
+        .join("");
+
+    // gen
+    // ```
+    // T1::create_type_info(registry);
+    // T2::create_type_info(registry);
+    // ```
+    let create_type_infos = (1..=idx)
+        .into_iter()
+        .map(|i| format!("T{}::create_type_info(registry);", i))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    // gen
+    // ```
+    // possible_types.insert(T1::type_name().into_owned());
+    // possible_types.insert(T2::type_name().into_owned());
+    // ```
+    let possible_types = (1..=idx)
+        .into_iter()
+        .map(|i| format!("possible_types.insert(T{}::type_name().into_owned());", i))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        "
+/// Outputs `{or}` as a GraphQL union of the object types `T1` ... `T{idx}`.
+///
+/// The union is named after its members joined with `Or`, e.g. `UserOrPost` for
+/// `Or2<User, Post>`.
+impl <{generics}> OutputType for {or} <{generics}>
+where
+    {bounds}
+{{
+    fn type_name() -> Cow<'static, str> {{
+        Cow::Owned([{type_names}].join(\"Or\"))
+    }}
+
+    fn introspection_type_name(&self) -> Cow<'static, str> {{
+        match self {{
+            {introspection_arms}
+        }}
+    }}
+
+    fn create_type_info(registry: &mut Registry) -> String {{
+        registry.create_output_type::<Self, _>(MetaTypeId::Union, |registry| {{
+            {create_type_infos}
+
+            let mut possible_types = IndexSet::new();
+            {possible_types}
+
+            MetaType::Union {{
+                name: <Self as OutputType>::type_name().into_owned(),
+                description: None,
+                possible_types,
+                visible: None,
+                inaccessible: false,
+                tags: Vec::new(),
+                rust_typename: Some(std::any::type_name::<Self>()),
+                directive_invocations: Vec::new(),
+            }}
+        }})
+    }}
+
+    async fn resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        _field: &Positioned<Field>,
+    ) -> ServerResult<Value> {{
+        resolve_container(ctx, self).await
+    }}
+}}
+    ",
+        or = gen_enum_name(idx),
+        idx = idx,
+        generics = gen_enum_generics(idx),
+        bounds = gen_trait_bound_params(idx, "ObjectType".to_string()),
+        type_names = type_names,
+        introspection_arms = introspection_match_arms,
+        create_type_infos = create_type_infos,
+        possible_types = possible_types,
+    )
+}
+
+// gen
+// ```
+// impl<T1, T2> ContainerType for Or2<T1, T2>
+// where
+//     T1: ObjectType,
+//     T2: ObjectType,
+// {
+//     ...
+// }
+// ```
+fn gen_impl_container_type(idx: usize) -> String {
+    // gen
+    // ```
+    // Self::T1(t1) => t1.collect_all_fields(ctx, fields),
+    // Self::T2(t2) => t2.collect_all_fields(ctx, fields),
+    // ```
+    let match_arms = (1..=idx)
+        .into_iter()
+        .map(|i| format!("Self::T{}(t{}) => t{}.collect_all_fields(ctx, fields),", i, i, i))
+        .collect::<Vec<_>>()
+        .join("");
+
+    format!(
+        "
+/// Collects the fields of the active variant, so the selection set is resolved against it.
+impl <{generics}> ContainerType for {or} <{generics}>
+where
+    {bounds}
+{{
+    async fn resolve_field(&self, _ctx: &Context<'_>) -> ServerResult<Option<Value>> {{
+        Ok(None)
+    }}
+
+    fn collect_all_fields<'a>(
+        &'a self,
+        ctx: &ContextSelectionSet<'a>,
+        fields: &mut Fields<'a>,
+    ) -> ServerResult<()> {{
+        match self {{
+            {arms}
+        }}
+    }}
+}}
+    ",
+        generics = gen_enum_generics(idx),
+        or = gen_enum_name(idx),
+        bounds = gen_trait_bound_params(idx, "ObjectType".to_string()),
+        arms = match_arms,
+    )
+}
+
+// gen `impl<T1, T2> UnionType for Or2<T1, T2> where T1: ObjectType, T2: ObjectType {}`
+fn gen_impl_union_type(idx: usize) -> String {
+    format!(
+        "
+impl <{generics}> UnionType for {or} <{generics}>
+where
+    {bounds}
+{{}}
+    ",
+        generics = gen_enum_generics(idx),
+        or = gen_enum_name(idx),
+        bounds = gen_trait_bound_params(idx, "ObjectType".to_string()),
+    )
+}
+
+// gen
+// ```
+// impl<T1, T2> BorshSerialize for Or2<T1, T2>
+// where
+//     T1: BorshSerialize,
+//     T2: BorshSerialize,
+// {
+//     ...
+// }
+// ```
+fn gen_impl_borsh_serialize(idx: usize) -> String {
+    // gen
+    // ```
+    // Self::T1(t1) => {
+    //     0u8.serialize(writer)?;
+    //     t1.serialize(writer)
+    // }
+    // ...
+    // ```
+    let match_arms = (1..=idx)
+        .into_iter()
+        .map(|i| {
+            format!(
+                "Self::T{}(t{}) => {{
+    {}u8.serialize(writer)?;
+    t{}.serialize(writer)
+}}",
+                i,
+                i,
+                i - 1,
+                i
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        "
+impl <{generics}> BorshSerialize for {or} <{generics}>
+where
+    {bounds}
+{{
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {{
+        match self {{
+            {arms}
+        }}
+    }}
+}}
+    ",
+        generics = gen_enum_generics(idx),
+        or = gen_enum_name(idx),
+        bounds = gen_trait_bound_params(idx, "BorshSerialize".to_string()),
+        arms = match_arms,
+    )
+}
+
+// gen
+// ```
+// impl<T1, T2> BorshDeserialize for Or2<T1, T2>
+// where
+//     T1: BorshDeserialize,
+//     T2: BorshDeserialize,
+// {
+//     ...
+// }
+// ```
+fn gen_impl_borsh_deserialize(idx: usize) -> String {
+    // gen
+    // ```
+    // 0 => Ok(Self::T1(T1::deserialize_reader(reader)?)),
+    // 1 => Ok(Self::T2(T2::deserialize_reader(reader)?)),
+    // ```
+    let match_arms = (1..=idx)
+        .into_iter()
+        .map(|i| format!("{} => Ok(Self::T{}(T{}::deserialize_reader(reader)?)),", i - 1, i, i))
+        .collect::<Vec<_>>()
+        .join("");
+
+    format!(
+        "
+/// Fails with `ErrorKind::InvalidData` if the variant tag is out of range.
+impl <{generics}> BorshDeserialize for {or} <{generics}>
+where
+    {bounds}
+{{
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {{
+        match u8::deserialize_reader(reader)? {{
+            {arms}
+            tag => Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(\"invalid {or} variant tag: {{}}\", tag),
+            )),
+        }}
+    }}
+}}
+    ",
+        generics = gen_enum_generics(idx),
+        or = gen_enum_name(idx),
+        bounds = gen_trait_bound_params(idx, "BorshDeserialize".to_string()),
+        arms = match_arms,
+    )
+}
+
+// gen
+// ```
+// impl<T1, T2> Or2<T1, T2>
+// where
+//     T1: StdError + Send + Sync + 'static,
+//     T2: StdError + Send + Sync + 'static,
+// {
+//     pub fn into_anyhow(self) -> anyhow::Error { ... }
+//     pub fn context<C>(self, context: C) -> anyhow::Error { ... }
+// }
+// ```
+fn gen_impl_anyhow(idx: usize) -> String {
+    // gen
+    // ```
+    // Self::T1(t1) => anyhow::Error::new(t1),
+    // Self::T2(t2) => anyhow::Error::new(t2),
+    // ```
+    let match_arms = (1..=idx)
+        .into_iter()
+        .map(|i| format!("Self::T{}(t{}) => anyhow::Error::new(t{}),", i, i, i))
+        .collect::<Vec<_>>()
+        .join("");
+
+    format!(
+        "
+impl <{generics}> {or} <{generics}>
+where
+    {bounds}
+{{
+    /// Converts the error of the active variant into an `anyhow::Error`.
+    ///
+    /// The error is moved as is rather than wrapped, so it can be recovered with
+    /// `anyhow::Error::downcast` and its `source` chain is kept.
+    ///
+    /// The `?` operator converts `{or}` into an `anyhow::Error` as well, by the blanket `From`
+    /// impl of `anyhow` for every error type, which is why `{or}` can not have its own. That
+    /// keeps the enum as the error, so it is downcast to `{or}` rather than to the variant.
+    pub fn into_anyhow(self) -> anyhow::Error {{
+        match self {{
+            {arms}
+        }}
+    }}
+
+    /// Converts the error of the active variant into an `anyhow::Error` with the given context,
+    /// the same as `anyhow::Error::context`.
+    pub fn context<C>(self, context: C) -> anyhow::Error
+    where
+        C: Display + Send + Sync + 'static,
+    {{
+        self.into_anyhow().context(context)
+    }}
+}}
+    ",
+        generics = gen_enum_generics(idx),
+        or = gen_enum_name(idx),
+        bounds = gen_trait_bound_params(idx, "StdError + Send + Sync + 'static".to_string()),
+        arms = match_arms,
+    )
+}
+
+// gen
+// ```
+// impl<T1, T2> Diagnostic for Or2<T1, T2>
+// where
+//     T1: Diagnostic,
+//     T2: Diagnostic,
+// {
+//     ...
+// }
+// ```
+fn gen_impl_diagnostic(idx: usize) -> String {
+    // gen
+    // ```
+    // Self::T1(t1) => t1.code(),
+    // Self::T2(t2) => t2.code(),
+    // ```
+    fn gen_match_arms(g_idx: usize, method: &str) -> String {
+        (1..=g_idx)
+            .into_iter()
+            .map(|i| format!("Self::T{}(t{}) => t{}.{}(),", i, i, i, method))
+            .collect::<Vec<_>>()
+            .join("")
+    }
+
+    format!(
+        "
+/// Forwards every method to the diagnostic of the active variant, so the report looks
+/// the same as the one of the variant itself.
+impl <{generics}> Diagnostic for {or} <{generics}>
+where
+    {bounds}
+{{
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {{
+        match self {{ {code} }}
+    }}
+
+    fn severity(&self) -> Option<Severity> {{
+        match self {{ {severity} }}
+    }}
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {{
+        match self {{ {help} }}
+    }}
+
+    fn url<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {{
+        match self {{ {url} }}
+    }}
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {{
+        match self {{ {source_code} }}
+    }}
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {{
+        match self {{ {labels} }}
+    }}
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {{
+        match self {{ {related} }}
+    }}
+
+    fn diagnostic_source(&self) -> Option<&dyn Diagnostic> {{
+        match self {{ {diagnostic_source} }}
+    }}
+}}
+    ",
+        generics = gen_enum_generics(idx),
+        or = gen_enum_name(idx),
+        bounds = gen_trait_bound_params(idx, "Diagnostic".to_string()),
+        code = gen_match_arms(idx, "code"),
+        severity = gen_match_arms(idx, "severity"),
+        help = gen_match_arms(idx, "help"),
+        url = gen_match_arms(idx, "url"),
+        source_code = gen_match_arms(idx, "source_code"),
+        labels = gen_match_arms(idx, "labels"),
+        related = gen_match_arms(idx, "related"),
+        diagnostic_source = gen_match_arms(idx, "diagnostic_source"),
+    )
+}
+
+// gen `T2: Service<Req, Response = T1::Response, Error = T1::Error>, ...` with idx = 3
+fn gen_same_service_bounds(idx: usize) -> String {
+    (2..=idx)
+        .into_iter()
+        .map(|i| {
+            format!(
+                "T{}: Service<Req, Response = T1::Response, Error = T1::Error>,",
+                i
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// gen
+// ```
+// impl<Req, T1, T2> Service<Req> for Or2<T1, T2>
+// where
+//     T1: Service<Req>,
+//     T2: Service<Req, Response = T1::Response, Error = T1::Error>,
+// {
+//     ...
+// }
+// ```
+fn gen_impl_service(idx: usize) -> String {
+    // gen
+    // ```
+    // Self::T1(t1) => t1.poll_ready(cx),
+    // Self::T2(t2) => t2.poll_ready(cx),
+    // ```
+    let poll_ready_arms = (1..=idx)
+        .into_iter()
+        .map(|i| format!("Self::T{}(t{}) => t{}.poll_ready(cx),", i, i, i))
+        .collect::<Vec<_>>()
+        .join("");
+
+    // gen
+    // ```
+    // Self::T1(t1) => Or2::T1(t1.call(req)),
+    // Self::T2(t2) => Or2::T2(t2.call(req)),
+    // ```
+    let call_arms = (1..=idx)
+        .into_iter()
+        .map(|i| {
+            format!(
+                "Self::T{}(t{}) => {}::T{}(t{}.call(req)),",
+                i,
+                i,
+                gen_enum_name(idx),
+                i,
+                i
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("");
+
+    // gen `T1::Future, T2::Future`
+    let futures = (1..=idx)
+        .into_iter()
+        .map(|i| format!("T{}::Future", i))
+        .collect::<Vec<_>>()
+        .join(",");
+
+    format!(
+        "
+/// Forwards to the service of the active variant, so services sharing the same request,
+/// response and error types can be chosen at runtime and still handed out as a single type.
+impl <Req, {generics}> Service<Req> for {or} <{generics}>
+where
+    T1: Service<Req>,
+    {bounds}
+{{
+    type Response = T1::Response;
+    type Error = T1::Error;
+    type Future = {or}<{futures}>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {{
+        match self {{
+            {poll_ready_arms}
+        }}
+    }}
+
+    fn call(&mut self, req: Req) -> Self::Future {{
+        match self {{
+            {call_arms}
+        }}
+    }}
+}}
+    ",
+        generics = gen_enum_generics(idx),
+        or = gen_enum_name(idx),
+        bounds = gen_same_service_bounds(idx),
+        futures = futures,
+        poll_ready_arms = poll_ready_arms,
+        call_arms = call_arms,
+    )
+}
+
+// gen
+// ```
+// impl<'r, 'o: 'r, T1, T2> Responder<'r, 'o> for Or2<T1, T2>
+// where
+//     T1: Responder<'r, 'o>,
+//     T2: Responder<'r, 'o>,
+// {
+//     ...
+// }
+// ```
+fn gen_impl_responder(idx: usize) -> String {
+    // gen
+    // ```
+    // Self::T1(t1) => t1.respond_to(req),
+    // Self::T2(t2) => t2.respond_to(req),
+    // ```
+    let match_arms = (1..=idx)
+        .into_iter()
+        .map(|i| format!("Self::T{}(t{}) => t{}.respond_to(req),", i, i, i))
+        .collect::<Vec<_>>()
+        .join("");
+
+    format!(
+        "
+/// Responds with the responder of the active variant, so a handler can return
+/// different kinds of responses.
+impl <'r, 'o: 'r, {generics}> Responder<'r, 'o> for {or} <{generics}>
+where
+    {bounds}
+{{
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'o> {{
+        match self {{
+            {arms}
+        }}
+    }}
+}}
+    ",
+        generics = gen_enum_generics(idx),
+        or = gen_enum_name(idx),
+        bounds = gen_trait_bound_params(idx, "Responder<'r, 'o>".to_string()),
+        arms = match_arms,
+    )
+}
+
+// gen `T1: I2c<A>, T2: I2c<A, Error = T1::Error>,` with idx = 2, bound = "I2c" and args = "A"
+fn gen_same_error_bounds(idx: usize, bound: &str, args: &str) -> String {
+    (1..=idx)
+        .into_iter()
+        .map(|i| match (i, args) {
+            (1, "") => format!("T1: {},", bound),
+            (1, _) => format!("T1: {}<{}>,", bound, args),
+            (_, "") => format!("T{}: {}<Error = T1::Error>,", i, bound),
+            _ => format!("T{}: {}<{}, Error = T1::Error>,", i, bound, args),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// gen
+// ```
+// fn flush(&mut self) -> Result<(), Self::Error> {
+//     match self {
+//         Self::T1(t1) => t1.flush(),
+//         Self::T2(t2) => t2.flush(),
+//     }
+// }
+// ```
+// with signature = "fn flush(&mut self) -> Result<(), Self::Error>" and call = "flush()"
+fn gen_forward_method(idx: usize, signature: &str, call: &str) -> String {
+    let match_arms = (1..=idx)
+        .into_iter()
+        .map(|i| format!("Self::T{}(t{}) => t{}.{},", i, i, i, call))
+        .collect::<Vec<_>>()
+        .join("");
+
+    format!(
+        "
+    {} {{
+        match self {{
+            {}
+        }}
+    }}
+",
+        signature, match_arms
+    )
+}
+
+// gen
+// ```
+// impl<T1, T2> i2c::ErrorType for Or2<T1, T2> { ... }
+//
+// impl<A, T1, T2> I2c<A> for Or2<T1, T2> { ... }
+// ```
+fn gen_impl_i2c(idx: usize) -> String {
+    format!(
+        "
+impl <{generics}> i2c::ErrorType for {or} <{generics}>
+where
+    {error_bounds}
+{{
+    type Error = T1::Error;
+}}
+
+/// Forwards to the I2C driver of the active variant.
+impl <A: AddressMode, {generics}> I2c<A> for {or} <{generics}>
+where
+    {bounds}
+{{
+    {read}
+    {write}
+    {write_read}
+    {transaction}
+}}
+    ",
+        generics = gen_enum_generics(idx),
+        or = gen_enum_name(idx),
+        error_bounds = gen_same_error_bounds(idx, "i2c::ErrorType", ""),
+        bounds = gen_same_error_bounds(idx, "I2c", "A"),
+        read = gen_forward_method(
+            idx,
+            "fn read(&mut self, address: A, read: &mut [u8]) -> Result<(), Self::Error>",
+            "read(address, read)"
+        ),
+        write = gen_forward_method(
+            idx,
+            "fn write(&mut self, address: A, write: &[u8]) -> Result<(), Self::Error>",
+            "write(address, write)"
+        ),
+        write_read = gen_forward_method(
+            idx,
+            "fn write_read(&mut self, address: A, write: &[u8], read: &mut [u8]) -> Result<(), Self::Error>",
+            "write_read(address, write, read)"
+        ),
+        transaction = gen_forward_method(
+            idx,
+            "fn transaction(&mut self, address: A, operations: &mut [i2c::Operation<'_>]) -> Result<(), Self::Error>",
+            "transaction(address, operations)"
+        ),
+    )
+}
+
+// gen
+// ```
+// impl<T1, T2> spi::ErrorType for Or2<T1, T2> { ... }
+//
+// impl<Word, T1, T2> SpiBus<Word> for Or2<T1, T2> { ... }
+//
+// impl<Word, T1, T2> SpiDevice<Word> for Or2<T1, T2> { ... }
+// ```
+fn gen_impl_spi(idx: usize) -> String {
+    format!(
+        "
+impl <{generics}> spi::ErrorType for {or} <{generics}>
+where
+    {error_bounds}
+{{
+    type Error = T1::Error;
+}}
+
+/// Forwards to the SPI bus of the active variant.
+impl <Word: Copy + 'static, {generics}> SpiBus<Word> for {or} <{generics}>
+where
+    {bus_bounds}
+{{
+    {read}
+    {write}
+    {transfer}
+    {transfer_in_place}
+    {flush}
+}}
+
+/// Forwards to the SPI device of the active variant.
+impl <Word: Copy + 'static, {generics}> SpiDevice<Word> for {or} <{generics}>
+where
+    {device_bounds}
+{{
+    {transaction}
+    {read}
+    {write}
+    {transfer}
+    {transfer_in_place}
+}}
+    ",
+        generics = gen_enum_generics(idx),
+        or = gen_enum_name(idx),
+        error_bounds = gen_same_error_bounds(idx, "spi::ErrorType", ""),
+        bus_bounds = gen_same_error_bounds(idx, "SpiBus", "Word"),
+        device_bounds = gen_same_error_bounds(idx, "SpiDevice", "Word"),
+        read = gen_forward_method(
+            idx,
+            "fn read(&mut self, words: &mut [Word]) -> Result<(), Self::Error>",
+            "read(words)"
+        ),
+        write = gen_forward_method(
+            idx,
+            "fn write(&mut self, words: &[Word]) -> Result<(), Self::Error>",
+            "write(words)"
+        ),
+        transfer = gen_forward_method(
+            idx,
+            "fn transfer(&mut self, read: &mut [Word], write: &[Word]) -> Result<(), Self::Error>",
+            "transfer(read, write)"
+        ),
+        transfer_in_place = gen_forward_method(
+            idx,
+            "fn transfer_in_place(&mut self, words: &mut [Word]) -> Result<(), Self::Error>",
+            "transfer_in_place(words)"
+        ),
+        flush = gen_forward_method(idx, "fn flush(&mut self) -> Result<(), Self::Error>", "flush()"),
+        transaction = gen_forward_method(
+            idx,
+            "fn transaction(&mut self, operations: &mut [spi::Operation<'_, Word>]) -> Result<(), Self::Error>",
+            "transaction(operations)"
+        ),
+    )
+}
+
+// gen
+// ```
+// impl<T1, T2> serial::ErrorType for Or2<T1, T2> { ... }
+//
+// impl<Word, T1, T2> serial::Read<Word> for Or2<T1, T2> { ... }
+//
+// impl<Word, T1, T2> serial::Write<Word> for Or2<T1, T2> { ... }
+// ```
+fn gen_impl_serial(idx: usize) -> String {
+    format!(
+        "
+impl <{generics}> serial::ErrorType for {or} <{generics}>
+where
+    {error_bounds}
+{{
+    type Error = T1::Error;
+}}
+
+/// Forwards to the serial reader of the active variant.
+impl <Word: Copy, {generics}> serial::Read<Word> for {or} <{generics}>
+where
+    {read_bounds}
+{{
+    {read}
+}}
+
+/// Forwards to the serial writer of the active variant.
+impl <Word: Copy, {generics}> serial::Write<Word> for {or} <{generics}>
+where
+    {write_bounds}
+{{
+    {write}
+    {flush}
+}}
+    ",
+        generics = gen_enum_generics(idx),
+        or = gen_enum_name(idx),
+        error_bounds = gen_same_error_bounds(idx, "serial::ErrorType", ""),
+        read_bounds = gen_same_error_bounds(idx, "serial::Read", "Word"),
+        write_bounds = gen_same_error_bounds(idx, "serial::Write", "Word"),
+        read = gen_forward_method(idx, "fn read(&mut self) -> nb::Result<Word, Self::Error>", "read()"),
+        write = gen_forward_method(
+            idx,
+            "fn write(&mut self, word: Word) -> nb::Result<(), Self::Error>",
+            "write(word)"
+        ),
+        flush = gen_forward_method(idx, "fn flush(&mut self) -> nb::Result<(), Self::Error>", "flush()"),
+    )
+}
+
+// gen
+// ```
+// #[derive(Serialize)]
+// #[serde(tag = "variant", content = "value", rename = "Or2")]
+// enum Or2Ref<'a, T1, T2> { ... }
+//
+// #[derive(Deserialize)]
+// #[serde(tag = "variant", content = "value", rename = "Or2")]
+// enum Or2Owned<T1, T2> { ... }
+//
+// impl<T1, T2> Serialize for Tagged<Or2<T1, T2>> { ... }
+// impl<'de, T1, T2> Deserialize<'de> for Tagged<Or2<T1, T2>> { ... }
+// ```
+fn gen_impl_tagged(idx: usize) -> String {
+    // gen `T1(&'a T1), T2(&'a T2)`
+    let ref_variants = (1..=idx)
+        .into_iter()
+        .map(|i| format!("T{}(&'a T{})", i, i))
+        .collect::<Vec<_>>()
+        .join(",");
+
+    // gen `T1(T1), T2(T2)`
+    let owned_variants = (1..=idx)
+        .into_iter()
+        .map(|i| format!("T{}(T{})", i, i))
+        .collect::<Vec<_>>()
+        .join(",");
+
+    // gen
+    // ```
+    // Or2::T1(t1) => Or2Ref::T1(t1),
+    // Or2::T2(t2) => Or2Ref::T2(t2),
+    // ```
+    let ref_match_arms = (1..=idx)
+        .into_iter()
+        .map(|i| {
+            format!(
+                "{or}::T{i}(t{i}) => {or}Ref::T{i}(t{i}),",
+                or = gen_enum_name(idx),
+                i = i
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("");
+
+    // gen
+    // ```
+    // Or2Owned::T1(t1) => Or2::T1(t1),
+    // Or2Owned::T2(t2) => Or2::T2(t2),
+    // ```
+    let owned_match_arms = (1..=idx)
+        .into_iter()
+        .map(|i| {
+            format!(
+                "{or}Owned::T{i}(t{i}) => {or}::T{i}(t{i}),",
+                or = gen_enum_name(idx),
+                i = i
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("");
+
+    format!(
+        "
+#[derive(Serialize)]
+#[serde(tag = \"variant\", content = \"value\", rename = \"{or}\")]
+enum {or}Ref<'a, {generics}> {{
+    {ref_variants}
+}}
+
+#[derive(Deserialize)]
+#[serde(tag = \"variant\", content = \"value\", rename = \"{or}\")]
+enum {or}Owned<{generics}> {{
+    {owned_variants}
+}}
+
+/// Serializes the name of the active variant as `variant`, along with its value as `value`.
+impl <{generics}> Serialize for Tagged<{or} <{generics}>>
+where
+    {ser_bounds}
+{{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{
+        match &self.0 {{
+            {ref_arms}
+        }}
+        .serialize(serializer)
+    }}
+}}
+
+/// Deserializes the value into the variant named by `variant`.
+impl <'de, {generics}> Deserialize<'de> for Tagged<{or} <{generics}>>
+where
+    {de_bounds}
+{{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{
+        let value = match {or}Owned::deserialize(deserializer)? {{
+            {owned_arms}
+        }};
+        Ok(Tagged(value))
+    }}
+}}
+    ",
+        or = gen_enum_name(idx),
+        generics = gen_enum_generics(idx),
+        ref_variants = ref_variants,
+        owned_variants = owned_variants,
+        ser_bounds = gen_trait_bound_params(idx, "Serialize".to_string()),
+        de_bounds = gen_trait_bound_params(idx, "Deserialize<'de>".to_string()),
+        ref_arms = ref_match_arms,
+        owned_arms = owned_match_arms,
+    )
+}
+
+// gen
+// ```
+// impl<T1, T2, T3> Or3<T1, T2, T3> {
+//     pub fn into_either(self) -> Either<T1, Or2<T2, T3>> { ... }
+// }
+// impl<T1, T2, T3> From<Either<T1, Or2<T2, T3>>> for Or3<T1, T2, T3> { ... }
+// impl<T1, T2, T3> From<Or3<T1, T2, T3>> for Either<T1, Or2<T2, T3>> { ... }
+// ```
+fn gen_impl_either(idx: usize) -> String {
+    let right_type = if idx == 2 {
+        "T2".to_string()
+    } else {
+        format!(
+            "{}<{}>",
+            gen_enum_name(idx - 1),
+            (2..=idx)
+                .into_iter()
+                .map(|i| format!("T{}", i))
+                .collect::<Vec<_>>()
+                .join(",")
+        )
+    };
+
+    // gen `t2` or `Or2::T1(t2)`, the right side for the value of `Ti`.
+    let right_value = |i: usize| {
+        if idx == 2 {
+            format!("t{}", i)
+        } else {
+            format!("{}::T{}(t{})", gen_enum_name(idx - 1), i - 1, i)
+        }
+    };
+
+    // gen `Self::T2(t2) => Either::Right(Or2::T1(t2)),`
+    let into_arms = (2..=idx)
+        .into_iter()
+        .map(|i| format!("Self::T{}(t{}) => Either::Right({}),", i, i, right_value(i)))
+        .collect::<Vec<_>>()
+        .join("");
+
+    // gen `Either::Right(Or2::T1(t2)) => Self::T2(t2),`
+    let from_arms = (2..=idx)
+        .into_iter()
+        .map(|i| format!("Either::Right({}) => Self::T{}(t{}),", right_value(i), i, i))
+        .collect::<Vec<_>>()
+        .join("");
+
+    // `Or2` is folded the same way as `Either`, with the closures of the left and the right.
+    let fold_either = if idx == 2 {
+        "
+    /// Applies `f` to the value of `T1` or `g` to the value of `T2`, the same as
+    /// `Either::either` on the converted value.
+    pub fn fold_either<F, G, B>(self, f: F, g: G) -> B
+    where
+        F: FnOnce(T1) -> B,
+        G: FnOnce(T2) -> B,
+    {
+        self.into_either().either(f, g)
+    }
+"
+    } else {
+        ""
+    };
+
+    format!(
+        "
+impl <{generics}> {or} <{generics}> {{
+    /// Converts the enum into an `Either`, with `T1` on the left and the others on the right.
+    #[allow(clippy::type_complexity)]
+    pub fn into_either(self) -> Either<T1, {right}> {{
+        match self {{
+            Self::T1(t1) => Either::Left(t1),
+            {into_arms}
+        }}
+    }}
+{fold_either}
+}}
+
+/// Converts the left value into `T1` and the right value into the remaining positions.
+impl <{generics}> From<Either<T1, {right}>> for {or} <{generics}> {{
+    fn from(either: Either<T1, {right}>) -> Self {{
+        match either {{
+            Either::Left(t1) => Self::T1(t1),
+            {from_arms}
+        }}
+    }}
+}}
+
+/// Converts `T1` into the left value and the others into the right value.
+impl <{generics}> From<{or} <{generics}>> for Either<T1, {right}> {{
+    fn from(or: {or} <{generics}>) -> Self {{
+        or.into_either()
+    }}
+}}
+    ",
+        generics = gen_enum_generics(idx),
+        or = gen_enum_name(idx),
+        right = right_type,
+        fold_either = fold_either,
+        into_arms = into_arms,
+        from_arms = from_arms,
+    )
+}
+
+// gen
+// ```
+// impl<T1, T2> Or2<T1, T2> {
+//     pub fn into_coproduct(self) -> Coproduct<T1, Coproduct<T2, CNil>> { ... }
+// }
+// impl<T1, T2> From<Coproduct<T1, Coproduct<T2, CNil>>> for Or2<T1, T2> { ... }
+// impl<T1, T2> From<Or2<T1, T2>> for Coproduct<T1, Coproduct<T2, CNil>> { ... }
+// ```
+fn gen_impl_coproduct(idx: usize) -> String {
+    // gen `Coproduct<T1, Coproduct<T2, CNil>>`
+    let coproduct_type = (1..=idx)
+        .into_iter()
+        .rev()
+        .fold("CNil".to_string(), |tail, i| {
+            format!("Coproduct<T{}, {}>", i, tail)
+        });
+
+    // gen `Coproduct::Inr(Coproduct::Inl(t2))`, the coproduct holding the value of `Ti`.
+    let coproduct_value = |i: usize, inner: String| {
+        (1..i).into_iter().fold(inner, |value, _| format!("Coproduct::Inr({})", value))
+    };
+
+    // gen `Self::T2(t2) => Coproduct::Inr(Coproduct::Inl(t2)),`
+    let into_arms = (1..=idx)
+        .into_iter()
+        .map(|i| {
+            format!(
+                "Self::T{}(t{}) => {},",
+                i,
+                i,
+                coproduct_value(i, format!("Coproduct::Inl(t{})", i))
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("");
+
+    // gen `Coproduct::Inr(Coproduct::Inl(t2)) => Self::T2(t2),`, followed by the arm of `CNil`.
+    let from_arms = (1..=idx)
+        .into_iter()
+        .map(|i| {
+            format!(
+                "{} => Self::T{}(t{}),",
+                coproduct_value(i, format!("Coproduct::Inl(t{})", i)),
+                i,
+                i
+            )
+        })
+        .chain(std::iter::once(format!(
+            "{} => match cnil {{}},",
+            coproduct_value(idx + 1, "cnil".to_string())
+        )))
+        .collect::<Vec<_>>()
+        .join("");
+
+    format!(
+        "
+impl <{generics}> {or} <{generics}> {{
+    /// Converts the enum into the `Coproduct` of its types, which is `Coprod!({types})`.
+    #[allow(clippy::type_complexity)]
+    pub fn into_coproduct(self) -> {coproduct} {{
+        match self {{
+            {into_arms}
+        }}
+    }}
+}}
+
+/// Converts the value of a `Coproduct` into the variant at the same position.
+impl <{generics}> From<{coproduct}> for {or} <{generics}> {{
+    fn from(coproduct: {coproduct}) -> Self {{
+        match coproduct {{
+            {from_arms}
+        }}
+    }}
+}}
+
+/// Converts the value of the active variant into the `Coproduct` at the same position.
+impl <{generics}> From<{or} <{generics}>> for {coproduct} {{
+    fn from(or: {or} <{generics}>) -> Self {{
+        or.into_coproduct()
+    }}
+}}
+    ",
+        generics = gen_enum_generics(idx),
+        or = gen_enum_name(idx),
+        types = (1..=idx)
+            .into_iter()
+            .map(|i| format!("T{}", i))
+            .collect::<Vec<_>>()
+            .join(", "),
+        coproduct = coproduct_type,
+        into_arms = into_arms,
+        from_arms = from_arms,
+    )
+}
+
+// gen
+// ```
+// impl<T1, T2> Stream for Or2<T1, T2>
+// where
+//     T1: Stream,
+//     T2: Stream<Item = T1::Item>,
+// {
+//     ...
+// }
+// ```
+// along with `FusedStream` bounded likewise.
+fn gen_impl_stream(idx: usize) -> String {
+    fn gen_same_item_bounds(idx: usize, bound: &str) -> String {
+        (1..=idx)
+            .into_iter()
+            .map(|i| match i {
+                1 => format!("T1: {},", bound),
+                _ => format!("T{}: {}<Item = T1::Item>,", i, bound),
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    // gen `Or2::T1(t1) => t1.poll_next(cx),`
+    let poll_next_arms = (1..=idx)
+        .into_iter()
+        .map(|i| format!("{}::T{}(t{}) => t{}.poll_next(cx),", gen_enum_name(idx), i, i, i))
+        .collect::<Vec<_>>()
+        .join("");
+
+    // gen `Self::T1(t1) => t1.size_hint(),`
+    let size_hint_arms = (1..=idx)
+        .into_iter()
+        .map(|i| format!("Self::T{}(t{}) => t{}.size_hint(),", i, i, i))
+        .collect::<Vec<_>>()
+        .join("");
+
+    // gen `Self::T1(t1) => t1.is_terminated(),`
+    let is_terminated_arms = (1..=idx)
+        .into_iter()
+        .map(|i| format!("Self::T{}(t{}) => t{}.is_terminated(),", i, i, i))
+        .collect::<Vec<_>>()
+        .join("");
+
+    format!(
+        "
+/// Polls the stream of the active variant, so the branches returning different streams of the
+/// same item can be consumed as a single stream.
+impl <{generics}> Stream for {or} <{generics}>
+where
+    {stream_bounds}
+{{
+    type Item = T1::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {{
+        match self.as_pin_mut() {{
+            {poll_next_arms}
+        }}
+    }}
+
+    fn size_hint(&self) -> (usize, Option<usize>) {{
+        match self {{
+            {size_hint_arms}
+        }}
+    }}
+}}
+
+impl <{generics}> FusedStream for {or} <{generics}>
+where
+    {fused_bounds}
+{{
+    fn is_terminated(&self) -> bool {{
+        match self {{
+            {is_terminated_arms}
+        }}
+    }}
+}}
+    ",
+        generics = gen_enum_generics(idx),
+        or = gen_enum_name(idx),
+        stream_bounds = gen_same_item_bounds(idx, "Stream"),
+        fused_bounds = gen_same_item_bounds(idx, "FusedStream"),
+        poll_next_arms = poll_next_arms,
+        size_hint_arms = size_hint_arms,
+        is_terminated_arms = is_terminated_arms,
+    )
+}
+
+// gen
+// ```
+// Or2::T1(t1) => t1.poll_read(cx, buf),
+// Or2::T2(t2) => t2.poll_read(cx, buf),
+// ```
+// for the pinned payloads projected by `as_pin_mut`.
+fn gen_pinned_match_arms(idx: usize, call: &str) -> String {
+    (1..=idx)
+        .into_iter()
+        .map(|i| format!("{}::T{}(t{}) => t{}.{},", gen_enum_name(idx), i, i, i, call))
+        .collect::<Vec<_>>()
+        .join("")
+}
+
+// gen
+// ```
+// impl<T1, T2> AsyncRead for Or2<T1, T2>
+// where
+//     T1: AsyncRead,
+//     T2: AsyncRead,
+// {
+//     ...
+// }
+// ```
+fn gen_impl_async_read(idx: usize) -> String {
+    format!(
+        "
+/// Reads from the I/O object of the active variant.
+impl <{generics}> AsyncRead for {or} <{generics}>
+where
+    {bounds}
+{{
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {{
+        match self.as_pin_mut() {{
+            {arms}
+        }}
+    }}
+}}
+    ",
+        generics = gen_enum_generics(idx),
+        or = gen_enum_name(idx),
+        bounds = gen_trait_bound_params(idx, "AsyncRead".to_string()),
+        arms = gen_pinned_match_arms(idx, "poll_read(cx, buf)"),
+    )
+}
+
+// gen
+// ```
+// impl<T1, T2> AsyncBufRead for Or2<T1, T2>
+// where
+//     T1: AsyncBufRead,
+//     T2: AsyncBufRead,
+// {
+//     ...
+// }
+// ```
+fn gen_impl_async_buf_read(idx: usize) -> String {
+    format!(
+        "
+/// Reads from the buffered I/O object of the active variant.
+impl <{generics}> AsyncBufRead for {or} <{generics}>
+where
+    {bounds}
+{{
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {{
+        match self.as_pin_mut() {{
+            {fill_buf_arms}
+        }}
+    }}
+
+    fn consume(self: Pin<&mut Self>, amt: usize) {{
+        match self.as_pin_mut() {{
+            {consume_arms}
+        }}
+    }}
+}}
+    ",
+        generics = gen_enum_generics(idx),
+        or = gen_enum_name(idx),
+        bounds = gen_trait_bound_params(idx, "AsyncBufRead".to_string()),
+        fill_buf_arms = gen_pinned_match_arms(idx, "poll_fill_buf(cx)"),
+        consume_arms = gen_pinned_match_arms(idx, "consume(amt)"),
+    )
+}
+
+// gen
+// ```
+// impl<T1, T2> AsyncWrite for Or2<T1, T2>
+// where
+//     T1: AsyncWrite,
+//     T2: AsyncWrite,
+// {
+//     ...
+// }
+// ```
+fn gen_impl_async_write(idx: usize) -> String {
+    // gen `Self::T1(t1) => t1.is_write_vectored(),`
+    let is_write_vectored_arms = (1..=idx)
+        .into_iter()
+        .map(|i| format!("Self::T{}(t{}) => t{}.is_write_vectored(),", i, i, i))
+        .collect::<Vec<_>>()
+        .join("");
+
+    format!(
+        "
+/// Writes to the I/O object of the active variant.
+impl <{generics}> AsyncWrite for {or} <{generics}>
+where
+    {bounds}
+{{
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {{
+        match self.as_pin_mut() {{
+            {write_arms}
+        }}
+    }}
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {{
+        match self.as_pin_mut() {{
+            {flush_arms}
+        }}
+    }}
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {{
+        match self.as_pin_mut() {{
+            {shutdown_arms}
+        }}
+    }}
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[io::IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {{
+        match self.as_pin_mut() {{
+            {write_vectored_arms}
+        }}
+    }}
+
+    fn is_write_vectored(&self) -> bool {{
+        match self {{
+            {is_write_vectored_arms}
+        }}
+    }}
+}}
+    ",
+        generics = gen_enum_generics(idx),
+        or = gen_enum_name(idx),
+        bounds = gen_trait_bound_params(idx, "AsyncWrite".to_string()),
+        write_arms = gen_pinned_match_arms(idx, "poll_write(cx, buf)"),
+        flush_arms = gen_pinned_match_arms(idx, "poll_flush(cx)"),
+        shutdown_arms = gen_pinned_match_arms(idx, "poll_shutdown(cx)"),
+        write_vectored_arms = gen_pinned_match_arms(idx, "poll_write_vectored(cx, bufs)"),
+        is_write_vectored_arms = is_write_vectored_arms,
+    )
+}
+
+// gen
+// ```
+// impl<T1, T2> AsyncSeek for Or2<T1, T2>
+// where
+//     T1: AsyncSeek,
+//     T2: AsyncSeek,
+// {
+//     ...
+// }
+// ```
+fn gen_impl_async_seek(idx: usize) -> String {
+    format!(
+        "
+/// Seeks the I/O object of the active variant.
+impl <{generics}> AsyncSeek for {or} <{generics}>
+where
+    {bounds}
+{{
+    fn start_seek(self: Pin<&mut Self>, position: io::SeekFrom) -> io::Result<()> {{
+        match self.as_pin_mut() {{
+            {start_seek_arms}
+        }}
+    }}
+
+    fn poll_complete(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<u64>> {{
+        match self.as_pin_mut() {{
+            {poll_complete_arms}
+        }}
+    }}
+}}
+    ",
+        generics = gen_enum_generics(idx),
+        or = gen_enum_name(idx),
+        bounds = gen_trait_bound_params(idx, "AsyncSeek".to_string()),
+        start_seek_arms = gen_pinned_match_arms(idx, "start_seek(position)"),
+        poll_complete_arms = gen_pinned_match_arms(idx, "poll_complete(cx)"),
+    )
+}
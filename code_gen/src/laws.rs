@@ -0,0 +1,264 @@
+//! Code generation of the property-based tests of the laws which the `Or` types must obey,
+//! written to `tests/tests/laws_test.rs` with `cargo run --bin code_gen -- --laws`.
+//!
+//! Every variant of the tested `Or` types has the same type `i32`, so that the same functions
+//! can be mapped over any of them, and the values are built by `orN(k, x)` from the index of
+//! the variant, since the `Or` types are not `Clone` in general.
+
+use crate::gen_enum_name;
+
+pub(crate) fn gen_code_string(gen_count: usize) -> String {
+    let repeating = (2..=gen_count)
+        .into_iter()
+        .map(|i| {
+            [
+                gen_constructor(i),
+                // rustfmt does not format the inside of `proptest!`, so it is indented here.
+                format!(
+                    "
+proptest! {{
+{}
+}}
+",
+                    indent(
+                        &[
+                            gen_law_map_identity(i),
+                            gen_law_map_composition(i),
+                            gen_law_fold_map(i),
+                            gen_law_as_is(i),
+                        ]
+                        .join("\n")
+                    ),
+                ),
+            ]
+            .join("")
+        })
+        .collect::<Vec<_>>()
+        .join("");
+
+    format!(
+        "
+//! Property-based tests of the laws of the `Or` types, generated by `code_gen`.
+//! Do not edit this file by hand, run `cargo run --bin code_gen -- --laws` instead.
+
+use or_rs::enums::*;
+use proptest::prelude::*;
+
+fn f(x: i32) -> i32 {{
+    x.wrapping_mul(3)
+}}
+
+fn g(x: i32) -> i32 {{
+    x.wrapping_add(7)
+}}
+{}",
+        repeating
+    )
+}
+
+// gen `or3`
+fn gen_constructor_name(idx: usize) -> String {
+    format!("or{}", idx)
+}
+
+// gen `Or3<i32, i32, i32>`
+fn gen_i32_type(idx: usize) -> String {
+    format!("{}<{}>", gen_enum_name(idx), vec!["i32"; idx].join(", "))
+}
+
+// gen
+// ```
+// fn or3(k: usize, x: i32) -> Or3<i32, i32, i32> {
+//     match k {
+//         1 => Or3::T1(x),
+//         2 => Or3::T2(x),
+//         _ => Or3::T3(x),
+//     }
+// }
+// ```
+fn gen_constructor(idx: usize) -> String {
+    let arms = (1..=idx)
+        .into_iter()
+        .map(|i| {
+            let pat = if i == idx {
+                "_".to_string()
+            } else {
+                i.to_string()
+            };
+            format!("{} => {}::T{}(x),", pat, gen_enum_name(idx), i)
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        "
+fn {}(k: usize, x: i32) -> {} {{
+    match k {{
+        {}
+    }}
+}}
+",
+        gen_constructor_name(idx),
+        gen_i32_type(idx),
+        arms
+    )
+}
+
+// gen `fn or3_map_identity(k in 1..=3usize, x: i32) {`, the head of a law.
+fn gen_law_head(idx: usize, law: &str) -> String {
+    format!(
+        "#[test]
+fn {}_{}(k in 1..={}usize, x: i32) {{",
+        gen_constructor_name(idx),
+        law,
+        idx
+    )
+}
+
+// gen
+// ```
+// #[test]
+// fn or3_map_identity(k in 1..=3usize, x: i32) {
+//     prop_assert_eq!(or3(k, x).map_t1(|x| x).into_options(), or3(k, x).into_options());
+//     ...
+// }
+// ```
+fn gen_law_map_identity(idx: usize) -> String {
+    let or = gen_constructor_name(idx);
+    let asserts = (1..=idx)
+        .into_iter()
+        .map(|i| {
+            format!(
+                "prop_assert_eq!({or}(k, x).map_t{i}(|x| x).into_options(), {or}(k, x).into_options());",
+                or = or,
+                i = i
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        "{}\n{}\n}}\n",
+        gen_law_head(idx, "map_identity"),
+        indent(&asserts)
+    )
+}
+
+// gen
+// ```
+// #[test]
+// fn or3_map_composition(k in 1..=3usize, x: i32) {
+//     prop_assert_eq!(
+//         or3(k, x).map_t1(f).map_t1(g).into_options(),
+//         or3(k, x).map_t1(|x| g(f(x))).into_options()
+//     );
+//     ...
+// }
+// ```
+fn gen_law_map_composition(idx: usize) -> String {
+    let or = gen_constructor_name(idx);
+    let asserts = (1..=idx)
+        .into_iter()
+        .map(|i| {
+            format!(
+                "prop_assert_eq!(
+    {or}(k, x).map_t{i}(f).map_t{i}(g).into_options(),
+    {or}(k, x).map_t{i}(|x| g(f(x))).into_options()
+);",
+                or = or,
+                i = i
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        "{}\n{}\n}}\n",
+        gen_law_head(idx, "map_composition"),
+        indent(&asserts)
+    )
+}
+
+// gen
+// ```
+// #[test]
+// fn or3_fold_map(k in 1..=3usize, x: i32) {
+//     prop_assert_eq!(
+//         or3(k, x).map_t1(f).fold(|x| x, |x| x, |x| x),
+//         or3(k, x).fold(f, |x| x, |x| x)
+//     );
+//     ...
+// }
+// ```
+fn gen_law_fold_map(idx: usize) -> String {
+    let or = gen_constructor_name(idx);
+    let identities = vec!["|x| x"; idx].join(", ");
+    let asserts = (1..=idx)
+        .into_iter()
+        .map(|i| {
+            let fs = (1..=idx)
+                .into_iter()
+                .map(|j| if i == j { "f" } else { "|x| x" })
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!(
+                "prop_assert_eq!(
+    {or}(k, x).map_t{i}(f).fold({identities}),
+    {or}(k, x).fold({fs})
+);",
+                or = or,
+                i = i,
+                identities = identities,
+                fs = fs
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        "{}\n{}\n}}\n",
+        gen_law_head(idx, "fold_map"),
+        indent(&asserts)
+    )
+}
+
+// gen
+// ```
+// #[test]
+// fn or3_as_is(k in 1..=3usize, x: i32) {
+//     prop_assert_eq!(or3(k, x).is_t1(), k == 1);
+//     prop_assert_eq!(or3(k, x).as_t1(), if k == 1 { Some(x) } else { None });
+//     ...
+// }
+// ```
+fn gen_law_as_is(idx: usize) -> String {
+    let or = gen_constructor_name(idx);
+    let asserts = (1..=idx)
+        .into_iter()
+        .map(|i| {
+            format!(
+                "prop_assert_eq!({or}(k, x).is_t{i}(), k == {i});
+prop_assert_eq!({or}(k, x).as_t{i}(), if k == {i} {{ Some(x) }} else {{ None }});",
+                or = or,
+                i = i
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!("{}\n{}\n}}\n", gen_law_head(idx, "as_is"), indent(&asserts))
+}
+
+// indents each line by 4 spaces, except the empty ones.
+fn indent(code: &str) -> String {
+    code.lines()
+        .map(|line| {
+            if line.is_empty() {
+                String::new()
+            } else {
+                format!("    {}", line)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
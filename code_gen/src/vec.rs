@@ -0,0 +1,348 @@
+//! Code generation of `OrVec` types, the struct-of-arrays containers of `Or` values.
+
+use crate::{gen_enum_generics, gen_enum_name};
+
+pub(crate) fn gen_code_string(gen_count: usize) -> String {
+    let common = format!(
+        "
+{}
+{}
+    ",
+        gen_module_top_doc_comment(),
+        gen_import_stmts(),
+    );
+    let repeating = (2..=gen_count)
+        .into_iter()
+        .map(|i| {
+            [
+                gen_vec_decl(i),
+                gen_vec_impl_block(i),
+                gen_vec_trait_impls(i),
+                gen_iter_decl(i),
+            ]
+            .join("")
+        })
+        .collect::<Vec<_>>()
+        .join("");
+
+    format!("{} {}", common, repeating)
+}
+
+fn gen_module_top_doc_comment() -> String {
+    "
+//! Struct-of-arrays containers of the `Or` types.
+//!
+//! `OrVecX` stores the payloads of each variant in a separate `Vec`, plus the order in which
+//! the values have been pushed. Compared to `Vec<OrX<...>>`, no space is wasted on padding
+//! the payloads to the size of the largest variant, which matters when storing a huge number
+//! of small heterogeneous values.\n"
+        .to_string()
+}
+
+fn gen_import_stmts() -> String {
+    "use alloc::vec::Vec;
+use core::iter::FromIterator;
+use core::slice;
+
+use crate::enums::*;"
+        .to_string()
+}
+
+// gen `OrVec3` with idx = 3
+fn gen_vec_name(idx: usize) -> String {
+    format!("OrVec{}", idx)
+}
+
+// gen `OrVec3Iter` with idx = 3
+fn gen_iter_name(idx: usize) -> String {
+    format!("OrVec{}Iter", idx)
+}
+
+// gen `&'a T1, &'a T2, &'a T3` with idx = 3
+fn gen_ref_generics(idx: usize) -> String {
+    (1..=idx)
+        .into_iter()
+        .map(|i| format!("&'a T{}", i))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+// gen
+// ```
+// pub struct OrVec3<T1, T2, T3> {
+//     t1: Vec<T1>,
+//     t2: Vec<T2>,
+//     t3: Vec<T3>,
+//     order: Vec<u8>,
+// }
+// ```
+fn gen_vec_decl(idx: usize) -> String {
+    let fields = (1..=idx)
+        .into_iter()
+        .map(|i| format!("t{}: Vec<T{}>,", i, i))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        "
+/// `{}` is a struct-of-arrays container of `{}` values.
+pub struct {} <{}> {{
+    {}
+    // the (0-based) variant of each value, in the order of insertion.
+    order: Vec<u8>,
+}}
+    ",
+        gen_vec_name(idx),
+        gen_enum_name(idx),
+        gen_vec_name(idx),
+        gen_enum_generics(idx),
+        fields
+    )
+}
+
+// gen
+// ```
+// impl<T1, T2, T3> OrVec3<T1, T2, T3> {
+//     pub fn new() -> Self { ... }
+//     pub fn push(&mut self, value: Or3<T1, T2, T3>) { ... }
+//     ...
+// }
+// ```
+fn gen_vec_impl_block(idx: usize) -> String {
+    // gen `t1: Vec::new(), t2: Vec::new(), t3: Vec::new(),`
+    let new_fields = (1..=idx)
+        .into_iter()
+        .map(|i| format!("t{}: Vec::new(),", i))
+        .collect::<Vec<_>>()
+        .join("");
+
+    // gen
+    // ```
+    // Or3::T1(t1) => {
+    //     self.t1.push(t1);
+    //     self.order.push(0);
+    // }
+    // ...
+    // ```
+    let push_match_arms = (1..=idx)
+        .into_iter()
+        .map(|i| {
+            format!(
+                "{}::T{}(t{}) => {{
+    self.t{}.push(t{});
+    self.order.push({});
+}}",
+                gen_enum_name(idx),
+                i,
+                i,
+                i,
+                i,
+                i - 1
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    // gen `t1: self.t1.iter(), t2: self.t2.iter(), t3: self.t3.iter(),`
+    let iter_fields = (1..=idx)
+        .into_iter()
+        .map(|i| format!("t{}: self.t{}.iter(),", i, i))
+        .collect::<Vec<_>>()
+        .join("");
+
+    // gen
+    // ```
+    // /// Returns the T1 values in the container, in the order of insertion.
+    // pub fn as_t1_slice(&self) -> &[T1] {
+    //     &self.t1
+    // }
+    // ...
+    // ```
+    let slice_methods = (1..=idx)
+        .into_iter()
+        .map(|i| {
+            format!(
+                "
+/// Returns the T{} values in the container, in the order of insertion.
+pub fn as_t{}_slice(&self) -> &[T{}] {{
+    &self.t{}
+}}
+",
+                i, i, i, i
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("");
+
+    format!(
+        "
+impl <{}> {} <{}> {{
+    /// Creates an empty container.
+    pub fn new() -> Self {{
+        Self {{
+            {}
+            order: Vec::new(),
+        }}
+    }}
+
+    /// Appends a value to the back of the container.
+    pub fn push(&mut self, value: {}<{}>) {{
+        match value {{
+            {}
+        }}
+    }}
+
+    /// Returns the number of values in the container.
+    pub fn len(&self) -> usize {{
+        self.order.len()
+    }}
+
+    /// Returns true if the container contains no values.
+    pub fn is_empty(&self) -> bool {{
+        self.order.is_empty()
+    }}
+
+    /// Returns an iterator over the values in the order of insertion, each borrowed as `{}`.
+    pub fn iter(&self) -> {}<'_, {}> {{
+        {} {{
+            {}
+            order: self.order.iter(),
+        }}
+    }}
+
+    {}
+}}
+    ",
+        gen_enum_generics(idx),
+        gen_vec_name(idx),
+        gen_enum_generics(idx),
+        new_fields,
+        gen_enum_name(idx),
+        gen_enum_generics(idx),
+        push_match_arms,
+        gen_enum_name(idx),
+        gen_iter_name(idx),
+        gen_enum_generics(idx),
+        gen_iter_name(idx),
+        iter_fields,
+        slice_methods
+    )
+}
+
+// gen `Default`, `FromIterator`, `Extend` and `IntoIterator` impls for `OrVec3`.
+fn gen_vec_trait_impls(idx: usize) -> String {
+    format!(
+        "
+impl <{generics}> Default for {vec} <{generics}> {{
+    fn default() -> Self {{
+        Self::new()
+    }}
+}}
+
+impl <{generics}> Extend<{or}<{generics}>> for {vec} <{generics}> {{
+    fn extend<I: IntoIterator<Item = {or}<{generics}>>>(&mut self, iter: I) {{
+        for value in iter {{
+            self.push(value);
+        }}
+    }}
+}}
+
+impl <{generics}> FromIterator<{or}<{generics}>> for {vec} <{generics}> {{
+    fn from_iter<I: IntoIterator<Item = {or}<{generics}>>>(iter: I) -> Self {{
+        let mut vec = Self::new();
+        vec.extend(iter);
+        vec
+    }}
+}}
+
+impl <'a, {generics}> IntoIterator for &'a {vec} <{generics}> {{
+    type Item = {or}<{ref_generics}>;
+    type IntoIter = {iter}<'a, {generics}>;
+
+    fn into_iter(self) -> Self::IntoIter {{
+        self.iter()
+    }}
+}}
+    ",
+        generics = gen_enum_generics(idx),
+        ref_generics = gen_ref_generics(idx),
+        vec = gen_vec_name(idx),
+        iter = gen_iter_name(idx),
+        or = gen_enum_name(idx),
+    )
+}
+
+// gen
+// ```
+// pub struct OrVec3Iter<'a, T1, T2, T3> {
+//     t1: slice::Iter<'a, T1>,
+//     ...
+//     order: slice::Iter<'a, u8>,
+// }
+//
+// impl<'a, T1, T2, T3> Iterator for OrVec3Iter<'a, T1, T2, T3> {
+//     ...
+// }
+// ```
+fn gen_iter_decl(idx: usize) -> String {
+    let fields = (1..=idx)
+        .into_iter()
+        .map(|i| format!("t{}: slice::Iter<'a, T{}>,", i, i))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    // gen
+    // ```
+    // 0 => Or3::T1(self.t1.next()?),
+    // 1 => Or3::T2(self.t2.next()?),
+    // 2 => Or3::T3(self.t3.next()?),
+    // ```
+    let next_match_arms = (1..=idx)
+        .into_iter()
+        .map(|i| {
+            format!(
+                "{} => {}::T{}(self.t{}.next()?),",
+                i - 1,
+                gen_enum_name(idx),
+                i,
+                i
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("");
+
+    format!(
+        "
+/// An iterator over the values of [`{vec}`], created by [`{vec}::iter`].
+pub struct {iter} <'a, {generics}> {{
+    {fields}
+    order: slice::Iter<'a, u8>,
+}}
+
+impl <'a, {generics}> Iterator for {iter} <'a, {generics}> {{
+    type Item = {or}<{ref_generics}>;
+
+    fn next(&mut self) -> Option<Self::Item> {{
+        let value = match self.order.next()? {{
+            {arms}
+            _ => unreachable!(\"invalid variant index\"),
+        }};
+        Some(value)
+    }}
+
+    fn size_hint(&self) -> (usize, Option<usize>) {{
+        self.order.size_hint()
+    }}
+}}
+
+impl <'a, {generics}> ExactSizeIterator for {iter} <'a, {generics}> {{}}
+    ",
+        vec = gen_vec_name(idx),
+        iter = gen_iter_name(idx),
+        generics = gen_enum_generics(idx),
+        ref_generics = gen_ref_generics(idx),
+        or = gen_enum_name(idx),
+        fields = fields,
+        arms = next_match_arms,
+    )
+}
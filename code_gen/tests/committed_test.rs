@@ -0,0 +1,39 @@
+use code_gen::{gen_files, gen_law_tests_string};
+
+// the arity of the files committed by `cargo run --bin code_gen`.
+const GEN_COUNT: usize = 9;
+
+// formats the code, so that the committed files formatted by rustfmt compare equal to the
+// generated code.
+fn format_code(code: &str) -> String {
+    let file = syn::parse_file(code).expect("the code should be valid Rust");
+    prettyplease::unparse(&file)
+}
+
+fn read_committed(path: &str) -> String {
+    let path = format!("{}/../{}", env!("CARGO_MANIFEST_DIR"), path);
+    std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("failed to read {}: {}", path, e))
+}
+
+// the `codegen` feature of `or-rs` generates the files again at build time, so the committed
+// files must be what the generator gives, or the two modes would drift apart.
+#[test]
+fn test_committed_files_are_generated() {
+    for (path, data) in gen_files(GEN_COUNT, true) {
+        let committed = read_committed(&format!("or-rs/src/{}", path));
+        assert!(
+            format_code(&committed) == format_code(&data),
+            "or-rs/src/{} differs from the generated code, run `cargo run --bin code_gen`",
+            path
+        );
+    }
+}
+
+#[test]
+fn test_committed_law_tests_are_generated() {
+    let committed = read_committed("tests/tests/laws_test.rs");
+    assert!(
+        format_code(&committed) == format_code(&gen_law_tests_string(GEN_COUNT)),
+        "tests/tests/laws_test.rs differs from the generated code, run `cargo run --bin code_gen -- --laws`"
+    );
+}
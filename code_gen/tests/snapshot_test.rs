@@ -0,0 +1,59 @@
+use code_gen::{gen_code_string, gen_files_with_generics};
+
+// formats the generated code, so that the snapshots are readable.
+fn format_code(code: String) -> String {
+    let file = syn::parse_file(&code).expect("the generated code should be valid Rust");
+    prettyplease::unparse(&file)
+}
+
+#[test]
+fn test_gen_code_string_2() {
+    insta::assert_snapshot!(format_code(gen_code_string(2)));
+}
+
+#[test]
+fn test_gen_code_string_3() {
+    insta::assert_snapshot!(format_code(gen_code_string(3)));
+}
+
+#[test]
+fn test_gen_code_string_4() {
+    insta::assert_snapshot!(format_code(gen_code_string(4)));
+}
+
+#[test]
+fn test_gen_code_string_high_arity() {
+    let enums = format_code(gen_code_string(16));
+
+    assert!(enums.contains("pub enum Or16<"));
+    // `T1` is rewritten as a whole, not as the head of `T10` ... `T16`.
+    assert!(enums.contains(
+        "pub fn map_t1<F, B>(self, f: F) -> Or10<B, T2, T3, T4, T5, T6, T7, T8, T9, T10>"
+    ));
+}
+
+#[test]
+fn test_gen_files_with_generics() {
+    let names = vec!["Left".to_string(), "Right".to_string()];
+    let files = gen_files_with_generics(2, true, &names).unwrap();
+    let (_, enums) = files.iter().find(|(path, _)| path == "enums.rs").unwrap();
+    let enums = format_code(enums.clone());
+
+    // the type parameters are renamed, while the variants keep their names.
+    assert!(enums.contains("pub enum Or2<Left, Right> {\n    T1(Left),\n    T2(Right),\n}"));
+    assert!(enums.contains("pub fn as_t1(self) -> Option<Left> {"));
+    assert!(enums.contains("Self::T1(t1) => Some(t1),"));
+    assert!(enums.contains("T1(t1, e1, I1, i1, E1, 0, Left)"));
+    assert!(!enums.contains("Option<T1>"));
+}
+
+#[test]
+fn test_gen_files_with_generics_error() {
+    let names = |names: &[&str]| names.iter().map(|name| name.to_string()).collect::<Vec<_>>();
+
+    // `F` is the type parameter of the closure of `map_t1<F, B>`.
+    assert!(gen_files_with_generics(2, true, &names(&["F", "Right"])).is_err());
+    assert!(gen_files_with_generics(2, true, &names(&["Left", "Left"])).is_err());
+    assert!(gen_files_with_generics(2, true, &names(&["Left", "1st"])).is_err());
+    assert!(gen_files_with_generics(3, true, &names(&["Left", "Right"])).is_err());
+}
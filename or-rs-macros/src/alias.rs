@@ -0,0 +1,162 @@
+//! Implementation of the `or_alias!` macro, which generates a named wrapper enum of an `Or` type.
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Attribute, Ident, Token, Type, Visibility};
+
+// `number: i64` in `Or2<number: i64, text: String>`
+struct AliasVariant {
+    name: Ident,
+    typ: Type,
+}
+
+impl Parse for AliasVariant {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let typ = input.parse()?;
+        Ok(Self { name, typ })
+    }
+}
+
+// `pub Value = Or2<number: i64, text: String>` with the leading attributes.
+pub(crate) struct Alias {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    name: Ident,
+    or: Ident,
+    variants: Punctuated<AliasVariant, Token![,]>,
+}
+
+impl Parse for Alias {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        let name = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let or: Ident = input.parse()?;
+        input.parse::<Token![<]>()?;
+        let mut variants = Punctuated::new();
+        while !input.peek(Token![>]) {
+            variants.push_value(input.parse()?);
+            if input.peek(Token![>]) {
+                break;
+            }
+            variants.push_punct(input.parse()?);
+        }
+        input.parse::<Token![>]>()?;
+
+        if or != format!("Or{}", variants.len()) {
+            return Err(syn::Error::new(
+                or.span(),
+                format!(
+                    "`{}` does not match the number of the variants, expected `Or{}`.",
+                    or,
+                    variants.len()
+                ),
+            ));
+        }
+
+        Ok(Self {
+            attrs,
+            vis,
+            name,
+            or,
+            variants,
+        })
+    }
+}
+
+impl Alias {
+    pub(crate) fn expand(self) -> TokenStream {
+        let Alias {
+            attrs,
+            vis,
+            name,
+            or,
+            variants,
+        } = self;
+
+        let names = variants.iter().map(|v| &v.name).collect::<Vec<_>>();
+        let types = variants.iter().map(|v| &v.typ).collect::<Vec<_>>();
+        let variant_names = names
+            .iter()
+            .map(|name| Ident::new(&to_camel_case(&name.to_string()), name.span()))
+            .collect::<Vec<_>>();
+        let or_variants = (1..=variants.len())
+            .map(|i| Ident::new(&format!("T{}", i), Span::call_site()))
+            .collect::<Vec<_>>();
+        let or_type = quote! { ::or_rs::enums::#or<#(#types),*> };
+
+        let accessors = names
+            .iter()
+            .zip(&variant_names)
+            .zip(&types)
+            .map(|((name, variant), typ)| {
+                let is = format_ident!("is_{}", name);
+                let as_ = format_ident!("as_{}", name);
+                let is_doc = format!("Returns true if the enum is of the `{}` variant.", variant);
+                let as_doc = format!(
+                    "Converts the enum to an Option containing the `{}` value, if it is of the `{}` variant.",
+                    variant, variant
+                );
+                quote! {
+                    #[doc = #is_doc]
+                    pub fn #is(&self) -> bool {
+                        ::core::matches!(self, Self::#variant(_))
+                    }
+
+                    #[doc = #as_doc]
+                    pub fn #as_(self) -> ::core::option::Option<#typ> {
+                        match self {
+                            Self::#variant(value) => ::core::option::Option::Some(value),
+                            _ => ::core::option::Option::None,
+                        }
+                    }
+                }
+            });
+
+        quote! {
+            #(#attrs)*
+            #vis enum #name {
+                #(#variant_names(#types)),*
+            }
+
+            impl #name {
+                #(#accessors)*
+            }
+
+            impl ::core::convert::From<#or_type> for #name {
+                fn from(value: #or_type) -> Self {
+                    match value {
+                        #(::or_rs::enums::#or::#or_variants(value) => Self::#variant_names(value)),*
+                    }
+                }
+            }
+
+            impl ::core::convert::From<#name> for #or_type {
+                fn from(value: #name) -> Self {
+                    match value {
+                        #(#name::#variant_names(value) => Self::#or_variants(value)),*
+                    }
+                }
+            }
+        }
+    }
+}
+
+// `raw_bytes` to `RawBytes`
+fn to_camel_case(name: &str) -> String {
+    name.split('_')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
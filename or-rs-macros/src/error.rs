@@ -2,6 +2,7 @@
 
 use core::fmt;
 use proc_macro::Span;
+use proc_macro2::{Span as Span2, TokenStream as TokenStream2};
 use quote::ToTokens;
 use std::{borrow::Borrow, fmt::Display, path::PathBuf};
 use syn::spanned::Spanned;
@@ -25,6 +26,8 @@ pub(crate) struct Error {
     message: String,
     span: Span,
     source_code: String,
+    // actionable suggestions, rendered as `help:` lines following the message.
+    helps: Vec<String>,
 }
 
 impl Error {
@@ -43,6 +46,7 @@ impl Error {
                 message: message,
                 span: span,
                 source_code: source_code,
+                helps: vec![],
             }
         }
 
@@ -51,13 +55,37 @@ impl Error {
             let span = err_tok.span().unwrap();
             Self {
                 filepath: PathBuf::new(),
-                message: message,
-                span: span,
+                message,
+                span,
                 source_code: "".to_string(),
+                helps: vec![],
             }
         }
     }
 
+    // adds a suggestion of how to fix the error, like `help: add a type parameter`.
+    pub(crate) fn with_help(mut self, help: impl Into<String>) -> Self {
+        self.helps.push(help.into());
+        self
+    }
+
+    // the message followed by the `help:` lines. `compile_error!` can not attach notes to
+    // the error like the compiler does, so they are written into the message.
+    fn message_with_helps(&self) -> String {
+        let mut message = self.message.clone();
+        for help in &self.helps {
+            message.push_str("\nhelp: ");
+            message.push_str(help);
+        }
+        message
+    }
+
+    // `compile_error!` with the message, pointing at the span of the error. the location
+    // is rendered by the compiler, so the message is used as is.
+    pub(crate) fn to_compile_error(&self) -> TokenStream2 {
+        syn::Error::new(Span2::from(self.span), self.message_with_helps()).to_compile_error()
+    }
+
     #[cfg(feature = "macro_error_debugging")]
     fn render_location(
         formatter: &mut fmt::Formatter,
@@ -125,7 +153,7 @@ impl Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         Self::render_location(
             f,
-            &self.message,
+            &self.message_with_helps(),
             &self.filepath,
             &self.source_code,
             &self.span,
@@ -139,6 +167,6 @@ impl Display for Error {
         write!(f, "
         {error}\n
         To see the more detailed cause of the error, You can use the `macro_error_debugging` feature in `or-rs-macros`.
-        ", error = self.message)
+        ", error = self.message_with_helps())
     }
 }
@@ -6,7 +6,9 @@
 #[allow(unused_extern_crates)]
 extern crate proc_macro;
 
+mod alias;
 mod error;
+mod options;
 mod parser;
 use proc_macro::TokenStream;
 
@@ -53,8 +55,347 @@ use proc_macro::TokenStream;
 /// };
 /// ```
 ///
+/// Arms guarded by `#[cfg(...)]` are supported. The variants are assigned in the order
+/// of the arms in the source regardless of the cfg, so the `Or` type stays the same for
+/// every cfg combination, and the variant of a disabled arm is just never constructed.
+///
+/// ```rust
+/// #![feature(proc_macro_hygiene)]
+///
+/// use or_rs_macros::or_gen;
+/// use or_rs::enums::Or3;
+///
+/// #[or_gen]
+/// let s: Or3<i32, f32, String> = match 42 {
+///     1 => 22,
+///     #[cfg(target_os = "linux")]
+///     10 => 3.2,
+///     _ => "hello".to_string(),
+/// };
+/// ```
+///
+/// Only the value at the end of each branch is wrapped, so a branch can contain closures,
+/// blocks and other `match` expressions. A branch without such a value is wrapped as `()`,
+/// while a branch ending with `return`, `break`, `continue` or `panic!()` is kept as is and
+/// takes no variant. An `if` without `else` produces `()` when the condition is false, so it can
+/// be annotated as `Or2<T, ()>`, which converts into an `Option<T>` by `into_option`.
+///
+/// A branch can be mapped to a specific variant by `#[variant(Tn)]` (or `#[or_gen::variant(Tn)]`)
+/// on the match arm or on the value at the end of the branch, which is useful when two branches
+/// produce the same type. Such a branch does not take a position of the automatic assignment.
+///
+/// ```rust
+/// #![feature(proc_macro_hygiene, stmt_expr_attributes)]
+///
+/// use or_rs_macros::or_gen;
+/// use or_rs::enums::Or2;
+///
+/// #[or_gen]
+/// let s: Or2<i32, String> = match 42 {
+///     1 => 22,
+///     2 => "two".to_string(),
+///     #[variant(T2)]
+///     _ => "hello".to_string(),
+/// };
+///
+/// #[or_gen]
+/// let s: Or2<i32, String> = if true {
+///     #[variant(T2)]
+///     "hello".to_string()
+/// } else {
+///     22
+/// };
+/// ```
+///
+/// A branch producing the `Or` type by itself, like `Or3::T2(x)`, is passed through as is,
+/// instead of being wrapped again. The macro can not know the type of other expressions, so
+/// a call of a helper function returning the `Or` type needs `#[passthrough]` (or
+/// `#[or_gen::passthrough]`). Such a branch still takes its position, so the following
+/// branches are wrapped into the same variants as if it were a plain value.
+///
+/// ```rust
+/// #![feature(proc_macro_hygiene)]
+///
+/// use or_rs_macros::or_gen;
+/// use or_rs::enums::Or3;
+///
+/// fn number(s: &str) -> Or3<i32, f32, String> {
+///     match s.parse() {
+///         Ok(n) => Or3::T1(n),
+///         Err(_) => Or3::T2(0.0),
+///     }
+/// }
+///
+/// #[or_gen]
+/// let s: Or3<i32, f32, String> = match "42" {
+///     "zero" => 0,
+///     #[passthrough]
+///     n if n.starts_with(char::is_numeric) => number(n),
+///     _ => Or3::T3("hello".to_string()),
+/// };
+/// ```
+///
+/// ## Example: branches returning references
+///
+/// The annotated type is used as is, so references with lifetimes and generic parameters
+/// of the enclosing function can be used in the `Or` type.
+///
+/// ```rust
+/// #![feature(proc_macro_hygiene)]
+///
+/// use or_rs_macros::or_gen;
+/// use or_rs::enums::Or2;
+///
+/// fn key<'a>(name: &'a String, bytes: &'a Vec<u8>) -> Or2<&'a str, &'a [u8]> {
+///     #[or_gen]
+///     let x: Or2<&'a str, &'a [u8]> = if bytes.is_empty() {
+///         name
+///     } else {
+///         bytes
+///     };
+///     x
+/// }
+/// ```
+///
+/// ## Example: `Or` type inside `Option` or `Result`
+///
+/// The `Or` type can also be annotated as the first type argument of an `Option` or a
+/// `Result`. In this case, only the payloads of `Some(...)` or `Ok(...)` are wrapped into
+/// the variants, while `None` and `Err(...)` are kept as is and take no variant.
+///
+/// ```rust
+/// #![feature(proc_macro_hygiene)]
+///
+/// use or_rs_macros::or_gen;
+/// use or_rs::enums::Or2;
+///
+/// #[or_gen]
+/// let s: Result<Or2<i32, String>, String> = match 42 {
+///     1  => Ok(22),
+///     10 => Err("not found".to_string()),
+///     _  => Ok("hello".to_string()),
+/// };
+/// ```
+///
+/// ## Example: `Or` type inside a tuple
+///
+/// When a tuple is annotated and initialized with a tuple expression, each element which is
+/// an `if` or `match` expression is rewritten with the type at the same position of the
+/// annotation, while the other elements are kept as is.
+///
+/// ```rust
+/// #![feature(proc_macro_hygiene)]
+///
+/// use or_rs_macros::or_gen;
+/// use or_rs::enums::Or2;
+///
+/// let n = 3;
+/// #[or_gen]
+/// let (x, count): (Or2<i32, String>, usize) = (
+///     if n > 2 { 42 } else { "small".to_string() },
+///     n,
+/// );
+/// assert_eq!(x.as_t1(), Some(42));
+/// assert_eq!(count, 3);
+/// ```
+///
+/// ## Example: usage on expression
+///
+/// The attribute can also be placed on the `if` or `match` expression itself, which
+/// additionally requires `#![feature(stmt_expr_attributes)]`. In this case, the `Or` type
+/// is referred as `or_rs::enums::OrX` after the number of the branches, and its generics
+/// are inferred from the context.
+///
+/// ```rust
+/// #![feature(proc_macro_hygiene, stmt_expr_attributes)]
+///
+/// use or_rs_macros::or_gen;
+/// use or_rs::enums::Or3;
+///
+/// let s: Or3<i32, f32, String> = #[or_gen]
+/// match 42 {
+///     1  => 22,
+///     10 => 3.2,
+///     _  => "hello".to_string(),
+/// };
+/// ```
+///
+/// ## Example: `Or` type given in the attribute
+///
+/// The `Or` type can be given as the first argument of the attribute instead of the
+/// annotation of the binding, which can then be left unannotated or be `_`. This also
+/// works on an expression, where the generics are not inferred in this case.
+///
+/// ```rust
+/// #![feature(proc_macro_hygiene, stmt_expr_attributes)]
+///
+/// use or_rs_macros::or_gen;
+/// use or_rs::enums::{Or2, Or3};
+///
+/// #[or_gen(Or3<i32, f32, String>)]
+/// let s = match 42 {
+///     1  => 22,
+///     10 => 3.2,
+///     _  => "hello".to_string(),
+/// };
+///
+/// let len = #[or_gen(Or2<i32, &str>, coerce)]
+/// if true {
+///     3u8
+/// } else {
+///     "hello"
+/// };
+/// ```
+///
+/// The `if` or `match` expression can be wrapped in parentheses. When the `Or` type is given
+/// in the attribute, it is the type of the branches, so the expression can also be wrapped in
+/// method calls, casts or `?`, like `match v { ... }.to_string()`.
+///
+/// With `#![feature(try_blocks)]`, it can also be at the tail of a try block, like
+/// `let x: Result<Or2<i32, f64>, E> = try { if c { a? } else { b } };`. The branches are then
+/// wrapped into the `Or` type inside the `Result` or the `Option`, since the try block wraps
+/// its value in `Ok` or `Some` itself.
+///
+/// ## Example: branches of unnameable types
+///
+/// When the branches return values whose types can not be written, like closures or chains
+/// of iterator adapters, a trait object can be given in the attribute instead of the `Or`
+/// type. Each branch value is then boxed, and the `Or` type is named after the number of the
+/// branches with `Box<dyn ...>` variants, like `Or2<Box<dyn Iterator<Item = u32>>, ...>`.
+///
+/// ```rust
+/// #![feature(proc_macro_hygiene)]
+///
+/// use or_rs_macros::or_gen;
+///
+/// let evens = true;
+/// #[or_gen(dyn Iterator<Item = u32>)]
+/// let it = if evens {
+///     (0..5).filter(|x| x % 2 == 0)
+/// } else {
+///     (0..5).filter(|x| x % 2 == 1).map(|x| x * 10)
+/// };
+/// // all the variants are of the same type, so they can be folded into a single one.
+/// let it = it.fold(|it| it, |it| it);
+/// assert_eq!(it.collect::<Vec<_>>(), vec![0, 2, 4]);
+/// ```
+///
+/// ## Options
+///
+/// The behavior of the macro can be changed by the arguments of the attribute, which
+/// can follow the `Or` type given in the attribute.
+///
+/// * `coerce`: wraps each branch value in `Into::into`, so that a branch can return a value
+///   convertible into the type of the variant, like `&str` for `String`.
+/// * `boxed`: wraps each branch value in `Box::new`, for the `Or` type annotated with boxed
+///   types like `Or2<Box<T1>, Box<T2>>`. This keeps the `Or` value small even when some
+///   branches return large values, which is cheaper to move around afterwards.
+///   It can be combined with `coerce`, in which case the value is converted before boxed.
+/// * `flatten`: when a branch ends with another `if` or `match` expression, distributes its
+///   branches over the variants of the `Or` type, rather than treating it as a single value.
+///   Branches of a `match` arm are always distributed in this way.
+/// * `trace`: emits a `tracing::debug!` event when a branch is taken at runtime, recording
+///   the variant, its index and the type name of the value. The crate using this option
+///   must depend on `tracing`.
+/// * `allow_unused_variants`: accepts an `Or` type with more variants than the branches use,
+///   like the variants reserved for branches added later. Without it, the unused variants
+///   are reported as an error.
+/// * `crate = path`: the path to `or_rs` used by the expansion, which is `::or_rs` by default.
+///   This is needed when the crate depends on `or_rs` under another name, or uses it through
+///   a re-export of another crate, like `#[or_gen(crate = my_lib::or_rs)]`.
+///
+/// ```rust
+/// #![feature(proc_macro_hygiene)]
+///
+/// use or_rs_macros::or_gen;
+/// use or_rs::enums::{Or2, Or3};
+///
+/// #[or_gen(coerce)]
+/// let s: Or2<i64, String> = if true {
+///     3
+/// } else {
+///     "hello"
+/// };
+///
+/// #[or_gen(boxed)]
+/// let b: Or2<Box<[u8; 1024]>, Box<i32>> = if true {
+///     [0; 1024]
+/// } else {
+///     3
+/// };
+///
+/// #[or_gen(flatten)]
+/// let f: Or3<i32, String, f32> = if true {
+///     if false {
+///         3
+///     } else {
+///         "hello".to_string()
+///     }
+/// } else {
+///     3.0
+/// };
+///
+/// #[or_gen(allow_unused_variants)]
+/// let r: Or3<i32, String, f32> = if true {
+///     3
+/// } else {
+///     "hello".to_string()
+/// };
+/// ```
+///
+/// ```compile_fail
+/// #![feature(proc_macro_hygiene)]
+///
+/// use or_rs_macros::or_gen;
+/// use or_rs::enums::Or3;
+///
+/// // `T3` is not used by any branch.
+/// #[or_gen]
+/// let r: Or3<i32, String, f32> = if true {
+///     3
+/// } else {
+///     "hello".to_string()
+/// };
+/// ```
+///
 ///
 #[proc_macro_attribute]
-pub fn or_gen(_attr: TokenStream, item: TokenStream) -> TokenStream {
-    parser::MacroParser::parse(item)
+pub fn or_gen(attr: TokenStream, item: TokenStream) -> TokenStream {
+    parser::MacroParser::parse(attr, item)
+}
+
+/// A macro that generates a named enum wrapping a concrete [Or](../or/trait.Or.html) type,
+/// giving the `Or`-based internals a presentable public API.
+///
+/// The variants are given as `name: Type` pairs, in the order of the `Or` type. Each of
+/// them becomes a variant named in CamelCase, along with the `is_name` and `as_name`
+/// accessors corresponding to `is_tx` and `as_tx`. The conversions from and to the `Or`
+/// type are generated as `From` impls, and the attributes are passed through to the enum.
+///
+/// ## Example
+///
+/// ```rust
+/// use or_rs_macros::or_alias;
+/// use or_rs::enums::Or3;
+///
+/// or_alias! {
+///     /// A value of a config entry.
+///     #[derive(Debug, PartialEq)]
+///     pub Value = Or3<number: i64, text: String, flag: bool>
+/// }
+///
+/// let or: Or3<i64, String, bool> = Or3::T1(42);
+/// let value = Value::from(or);
+/// assert_eq!(value, Value::Number(42));
+/// assert!(value.is_number());
+/// assert_eq!(value.as_number(), Some(42));
+///
+/// let or: Or3<i64, String, bool> = Value::Flag(true).into();
+/// assert!(or.is_t3());
+/// ```
+#[proc_macro]
+pub fn or_alias(item: TokenStream) -> TokenStream {
+    syn::parse_macro_input!(item as alias::Alias)
+        .expand()
+        .into()
 }
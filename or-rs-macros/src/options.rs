@@ -0,0 +1,149 @@
+//! Options of the `or_gen` macro, given as the arguments of the attribute.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, ToTokens};
+use syn::parse::{Parse, ParseStream, Parser};
+use syn::punctuated::Punctuated;
+use syn::{Path, Token, Type};
+
+use crate::error::{Error, Result};
+
+// options given as `#[or_gen(coerce, ...)]`, optionally with the `Or` type like
+// `#[or_gen(Or2<i32, f32>, coerce)]`, or a trait object like `#[or_gen(dyn Iterator<Item = i32>)]`.
+// the path to `or_rs` is given like `#[or_gen(crate = my_crate::or_rs)]`.
+#[derive(Clone, Default)]
+pub(crate) struct MacroOptions {
+    // the `Or` type given in the attribute, used instead of the annotation of the binding.
+    pub(crate) typ: Option<Type>,
+    // the trait object given in the attribute, like `dyn Iterator<Item = i32>`. each branch
+    // value is boxed into `Box<dyn ...>`, so that the types of the values need not be named.
+    pub(crate) dyn_trait: Option<Type>,
+    // wrap each branch value in `Into::into` before constructing the variant.
+    pub(crate) coerce: bool,
+    // wrap each branch value in `Box::new`, for the `Or` of boxed types.
+    pub(crate) boxed: bool,
+    // distribute the branches of a nested `if` or `match` at the tail of a branch
+    // over the variants, instead of treating it as a single value.
+    pub(crate) flatten: bool,
+    // emit `tracing::debug!` recording the variant taken at runtime.
+    pub(crate) trace: bool,
+    // accept an `Or` type with more variants than the branches use.
+    pub(crate) allow_unused_variants: bool,
+    // the path to `or_rs` in the expansion, for a crate which depends on it under another
+    // name or through a re-export. `::or_rs` if not given.
+    pub(crate) krate: Option<Path>,
+}
+
+// an argument of the attribute, which is `crate = path`, or a type like the `Or` type or
+// an option, which is parsed as a type as well.
+enum Arg {
+    Crate(Path),
+    Type(Type),
+}
+
+impl Parse for Arg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(Token![crate]) && input.peek2(Token![=]) {
+            input.parse::<Token![crate]>()?;
+            input.parse::<Token![=]>()?;
+            return Path::parse_mod_style(input).map(Arg::Crate);
+        }
+        input.parse().map(Arg::Type)
+    }
+}
+
+impl MacroOptions {
+    pub(crate) fn parse(attr: TokenStream) -> Result<Self> {
+        let attr = TokenStream2::from(attr);
+        let args = match Punctuated::<Arg, Token![,]>::parse_terminated.parse2(attr.clone()) {
+            Ok(args) => args,
+            Err(error) => return Err(Error::new(&attr, error.to_string())),
+        };
+
+        let mut options = MacroOptions::default();
+        for arg in args {
+            let arg = match arg {
+                Arg::Crate(path) if options.krate.is_some() => {
+                    return Err(Error::new(
+                        &path,
+                        "The path to `or_rs` is given more than once.".to_string(),
+                    ))
+                }
+                Arg::Crate(path) => {
+                    options.krate = Some(path);
+                    continue;
+                }
+                Arg::Type(arg) => arg,
+            };
+            // an option is a lowercase identifier, and anything else is the `Or` type.
+            let ident = match Self::option_ident(&arg) {
+                Some(ident) => ident,
+                None if matches!(arg, Type::TraitObject(_)) => {
+                    if options.dyn_trait.is_some() {
+                        return Err(Error::new(
+                            &arg,
+                            "The trait object is given more than once.".to_string(),
+                        ));
+                    }
+                    options.dyn_trait = Some(arg);
+                    continue;
+                }
+                None if options.typ.is_none() => {
+                    options.typ = Some(arg);
+                    continue;
+                }
+                None => {
+                    return Err(Error::new(
+                        &arg,
+                        "The `Or` type is given more than once.".to_string(),
+                    ))
+                }
+            };
+            match ident.as_str() {
+                "coerce" => options.coerce = true,
+                "boxed" => options.boxed = true,
+                "flatten" => options.flatten = true,
+                "trace" => options.trace = true,
+                "allow_unused_variants" => options.allow_unused_variants = true,
+                _ => {
+                    return Err(Error::new(
+                        &arg,
+                        format!("Unknown option `{}` found.", ident),
+                    )
+                    .with_help("`coerce`, `boxed`, `flatten`, `trace`, `allow_unused_variants` and `crate = path` are supported."))
+                }
+            }
+        }
+
+        if let (Some(typ), Some(_)) = (&options.typ, &options.dyn_trait) {
+            return Err(Error::new(
+                typ,
+                "The `Or` type can not be given along with a trait object, which names the `Or` type of boxed trait objects.".to_string(),
+            ));
+        }
+
+        Ok(options)
+    }
+
+    // the path to `or_rs` in the expansion, like `::or_rs` in `::or_rs::enums::Or2`.
+    pub(crate) fn krate(&self) -> TokenStream2 {
+        match &self.krate {
+            Some(path) => path.to_token_stream(),
+            None => quote! { ::or_rs },
+        }
+    }
+
+    // get `coerce` from the argument parsed as a type.
+    fn option_ident(arg: &Type) -> Option<String> {
+        let ident = match arg {
+            Type::Path(ptype) if ptype.qself.is_none() => ptype.path.get_ident()?.to_string(),
+            _ => return None,
+        };
+        if ident.starts_with(|c: char| c.is_ascii_lowercase()) {
+            Some(ident)
+        } else {
+            None
+        }
+    }
+}
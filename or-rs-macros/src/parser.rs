@@ -1,18 +1,48 @@
-use core::panic;
 use proc_macro::TokenStream;
 use proc_macro2::Span as Span2;
 use proc_macro2::TokenStream as TokenStream2;
-use quote::quote;
+use quote::{quote, ToTokens};
+use syn::parse::{ParseStream, Parser};
+use syn::spanned::Spanned;
 use syn::Arm;
 use syn::ExprMatch;
-use syn::{Block, Expr, ExprIf, Ident, LocalInit, Pat, PathArguments, Stmt, Type};
+use syn::{
+    Attribute, Block, Expr, ExprIf, ExprMacro, ExprTryBlock, GenericArgument, Ident, Local,
+    LocalInit, Macro, Pat, PathArguments, Stmt, Token, Type, TypeTuple,
+};
 
 use crate::error::{Error, Result};
+use crate::options::MacroOptions;
 
 // private macro parser
 pub(crate) struct MacroParser {
     depth: usize,
-    typ: Type,
+    // `None` when the attribute is placed on the expression, where the type is not annotated.
+    typ: Option<Type>,
+    // the number of the variants, used to name the `Or` type when `typ` is `None`.
+    variant_count: usize,
+    // set when the `Or` type is annotated inside an `Option` or a `Result`.
+    nested: Option<Nested>,
+    // the variant given by `#[variant(Tn)]` or `#[passthrough]` on the arm being parsed,
+    // taken by its value.
+    variant: Option<Variant>,
+    options: MacroOptions,
+}
+
+// the type wrapping the `Or` type in the annotation, like `Option<Or2<i32, f32>>`.
+#[derive(Clone, Copy)]
+enum Nested {
+    Option,
+    Result,
+}
+
+// the variant of a branch given by its attribute.
+#[derive(Clone, Copy)]
+enum Variant {
+    // `#[variant(Tn)]`
+    Index(usize),
+    // `#[passthrough]`, where the value is already of the annotated type.
+    Passthrough,
 }
 
 impl MacroParser {
@@ -22,7 +52,11 @@ impl MacroParser {
 
         MacroParser {
             depth: 0,
-            typ: return_type,
+            typ: Some(return_type),
+            variant_count: 0,
+            nested: None,
+            variant: None,
+            options: MacroOptions::default(),
         }
     }
 
@@ -34,27 +68,144 @@ impl MacroParser {
 
 impl MacroParser {
     // parser's entry point
-    pub(crate) fn parse(input: TokenStream) -> TokenStream {
-        let local = match syn::parse2::<Stmt>(input.clone().into()) {
-            Ok(Stmt::Local(local)) => local,
-            Err(error) => panic!("{}", error),
-            _ => panic!("expected Stmt::Local, but not"),
+    // on failure, the original tokens are emitted along with a `compile_error!`, rather than
+    // nothing, so that IDEs can still analyze the rest of the code while the statement is edited.
+    pub(crate) fn parse(attr: TokenStream, input: TokenStream) -> TokenStream {
+        let tok = match syn::parse2::<Stmt>(input.clone().into()) {
+            Ok(Stmt::Local(local)) => {
+                let original = local.to_token_stream();
+                MacroOptions::parse(attr)
+                    .and_then(|options| Self::parse_local(local, options))
+                    .unwrap_or_else(|error| Self::with_error(error, original))
+            }
+            Ok(Stmt::Expr(expr, None)) => Self::parse_expr_position_or_error(attr, expr),
+            // an expression in a function argument is passed with the trailing comma,
+            // which can not be parsed as a statement. the comma must not be emitted back.
+            Err(error) => match Self::parse_expr_with_comma.parse(input.clone()) {
+                Ok(expr) => Self::parse_expr_position_or_error(attr, expr),
+                Err(_) => {
+                    let original = TokenStream2::from(input);
+                    Self::with_error(Error::new(&original, error.to_string()), original)
+                }
+            },
+            Ok(stmt) => Self::with_error(
+                Error::new(
+                    &stmt,
+                    "Unsupported statement found. `let` statements and `if` or `match` expressions are supported.".to_string(),
+                ),
+                stmt.to_token_stream(),
+            ),
         };
+
+        TokenStream::from(tok)
+    }
+
+    fn parse_expr_position_or_error(attr: TokenStream, expr: Expr) -> TokenStream2 {
+        let original = expr.to_token_stream();
+        MacroOptions::parse(attr)
+            .and_then(|options| Self::parse_expr_position(expr, options))
+            .unwrap_or_else(|error| {
+                // keep it a single expression.
+                let tok = Self::with_error(error, original);
+                quote! { { #tok } }
+            })
+    }
+
+    // emit `compile_error!` followed by the original tokens.
+    fn with_error(error: Error, original: TokenStream2) -> TokenStream2 {
+        let error = error.to_compile_error();
+        quote! { #error #original }
+    }
+
+    // parse `if true { ... } else { ... },`
+    fn parse_expr_with_comma(input: ParseStream) -> syn::Result<Expr> {
+        let expr = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+        Ok(expr)
+    }
+
+    // parse `let x: Or2<i32, f32> = if true { ... } else { ... };`
+    // with `#[or_gen(Or2<i32, f32>)]`, the binding is left as is, and can be unannotated.
+    fn parse_local(local: Local, options: MacroOptions) -> Result<TokenStream2> {
+        let attrs = local.attrs;
         let let_tok = local.let_token;
-        let (pat_tok, typ) = match Self::parse_pat_and_ret_type(local.pat) {
-            Ok((pat_tok, typ)) => (pat_tok, typ),
-            Err(e) => panic!("{}", e),
+        if options.dyn_trait.is_some() {
+            // the `Or` type is named after the number of the branches, like on an expression.
+            let pat_tok = local.pat;
+            let local_init = match local.init {
+                None => return Err(Self::missing_init_error(&let_tok)),
+                Some(local_init) => local_init,
+            };
+            let eq_tok = local_init.eq_token;
+            let expr_tok = Self::parse_expr_position(*local_init.expr, options)?;
+            let semi_tok = local.semi_token;
+            return Ok(quote! { #(#attrs)* #let_tok #pat_tok #eq_tok #expr_tok #semi_tok });
+        }
+        let (pat_tok, typ) = match options.typ.clone() {
+            Some(typ) => (local.pat.to_token_stream(), typ),
+            None => Self::parse_pat_and_ret_type(local.pat)?,
         };
-        let mut parser = MacroParser { depth: 0, typ: typ };
-        let local_tok = match local.init {
-            None => unreachable!(),
-            Some(local_init) => parser.parse_local_init(local_init),
+        let local_tok = match (local.init, typ) {
+            (None, _) => return Err(Self::missing_init_error(&let_tok)),
+            (Some(local_init), Type::Tuple(tuple_type)) if options.typ.is_none() => {
+                Self::parse_local_init_tuple(local_init, tuple_type, options)?
+            }
+            (Some(local_init), typ) => {
+                let (typ, nested) = Self::parse_nested_type(typ);
+                let mut parser = MacroParser {
+                    depth: 0,
+                    typ: Some(typ),
+                    variant_count: 0,
+                    nested,
+                    variant: None,
+                    options,
+                };
+                parser.parse_local_init(local_init)?
+            }
         };
+        let semi_tok = local.semi_token;
 
-        match local_tok {
-            Ok(local_tok) => TokenStream::from(quote! { #let_tok #pat_tok #local_tok ;}),
-            Err(e) => panic!("{}", e),
+        Ok(quote! { #(#attrs)* #let_tok #pat_tok #local_tok #semi_tok })
+    }
+
+    // `let x: Or2<i32, f32>;` has no expression to generate the branches of.
+    fn missing_init_error(let_tok: &Token![let]) -> Error {
+        Error::new(
+            let_tok,
+            "The `let` statement has no initializer. the binding needs an `if` or `match` expression assigned.".to_string(),
+        )
+        .with_help("assign the expression like `let x: Or2<i32, f32> = if true { ... } else { ... };`.")
+    }
+
+    // parse `if true { ... } else { ... }` with the attribute placed on the expression itself.
+    // the `Or` type is named after the number of the variants, and its generics are inferred,
+    // unless it is given in the attribute.
+    fn parse_expr_position(expr: Expr, options: MacroOptions) -> Result<TokenStream2> {
+        if let Some(typ) = options.typ.clone() {
+            let (typ, nested) = Self::parse_nested_type(typ);
+            let mut parser = MacroParser {
+                depth: 0,
+                typ: Some(typ),
+                variant_count: 0,
+                nested,
+                variant: None,
+                options,
+            };
+            return parser.parse_branches(expr);
         }
+        let mut parser = MacroParser {
+            depth: 0,
+            typ: None,
+            variant_count: 0,
+            nested: None,
+            variant: None,
+            options,
+        };
+        // the first pass only counts the variants.
+        parser.parse_expr_at_first(expr.clone())?;
+        parser.depth = 0;
+
+        parser.parse_expr_at_first(expr)
     }
 
     // parse `x: Or2<i32, f32>` in `let x: Or2<i32, f32> = if true { ... } else { ... }`
@@ -66,186 +217,765 @@ impl MacroParser {
                 Ok((quote! { #ident_tok : #typ_tok }, typ_tok))
             }
             _ => Err(
-                Error::new(&pat, "Fail to parse `let` binding.\nif you use macro you need type annotation using the Or type.".to_string())
+                Error::new(&pat, "Fail to parse `let` binding. the `Or` type is not annotated.".to_string())
+                    .with_help("annotate the binding like `let x: Or2<i32, f32> = ...`, or give the type in the attribute like `#[or_gen(Or2<i32, f32>)]`.")
             ),
         }
     }
 
+    // get `Or2<i32, f32>` and `Nested::Option` from `Option<Or2<i32, f32>>`, and the same
+    // for `Result<Or2<i32, f32>, E>`. other types are returned as is.
+    fn parse_nested_type(typ: Type) -> (Type, Option<Nested>) {
+        let nested = match &typ {
+            Type::Path(ptype) if ptype.qself.is_none() => {
+                ptype.path.segments.last().and_then(|seg| {
+                    let nested = match seg.ident.to_string().as_str() {
+                        "Option" => Nested::Option,
+                        "Result" => Nested::Result,
+                        _ => return None,
+                    };
+                    match &seg.arguments {
+                        PathArguments::AngleBracketed(args) => match args.args.first() {
+                            Some(GenericArgument::Type(inner)) => Some((inner.clone(), nested)),
+                            _ => None,
+                        },
+                        _ => None,
+                    }
+                })
+            }
+            _ => None,
+        };
+        match nested {
+            Some((inner, nested)) => (inner, Some(nested)),
+            None => (typ, None),
+        }
+    }
+
     // parse `= if true { ... } else { ... }` in `let x: Or2<i32, f32> = if true { ... } else { ... }`
     fn parse_local_init(&mut self, local_init: LocalInit) -> Result<TokenStream2> {
-        let expr_tok = self.parse_expr_at_first(local_init.expr.as_ref().clone())?;
+        let eq_tok = local_init.eq_token;
+        let expr_tok = self.parse_branches(local_init.expr.as_ref().clone())?;
+
+        Ok(quote! { #eq_tok #expr_tok })
+    }
+
+    // parse `= (if true { ... } else { ... }, n)` in
+    // `let (x, n): (Or2<i32, f32>, usize) = (if true { ... } else { ... }, n)`.
+    // each element which is an `if` or `match` expression is rewritten with the type at the
+    // same position in the annotation, and the other elements are left as is.
+    fn parse_local_init_tuple(
+        local_init: LocalInit,
+        tuple_type: TypeTuple,
+        options: MacroOptions,
+    ) -> Result<TokenStream2> {
+        let eq_tok = local_init.eq_token;
+        let expr_tuple = match *local_init.expr {
+            Expr::Tuple(expr_tuple) if expr_tuple.elems.len() == tuple_type.elems.len() => {
+                expr_tuple
+            }
+            expr => return Err(Error::new(
+                &expr,
+                "The tuple annotated on the binding needs a tuple expression of the same length, like `(if true { ... } else { ... }, n)`.".to_string(),
+            )),
+        };
+        if !expr_tuple.elems.iter().any(Self::is_branching) {
+            return Err(Error::new(
+                &expr_tuple,
+                "Unsupported expression found. the tuple needs an `if` or `match` expression as one of its elements.".to_string(),
+            ));
+        }
+
+        let mut elems = Vec::new();
+        for (expr, typ) in expr_tuple.elems.into_iter().zip(tuple_type.elems) {
+            if !Self::is_branching(&expr) {
+                elems.push(expr.to_token_stream());
+                continue;
+            }
+            let (typ, nested) = Self::parse_nested_type(typ);
+            let mut parser = MacroParser {
+                depth: 0,
+                typ: Some(typ),
+                variant_count: 0,
+                nested,
+                variant: None,
+                options: options.clone(),
+            };
+            elems.push(parser.parse_branches(expr)?);
+        }
+
+        let attrs = expr_tuple.attrs;
+        let mut tok = quote! { #(#attrs)* };
+        // the trailing comma keeps a tuple of a single element a tuple.
+        expr_tuple
+            .paren_token
+            .surround(&mut tok, |tok| tok.extend(quote! { #(#elems,)* }));
+
+        Ok(quote! { #eq_tok #tok })
+    }
 
-        Ok(quote! { = #expr_tok })
+    // true if the expression is an `if` or `match` expression, possibly wrapped in parentheses.
+    fn is_branching(expr: &Expr) -> bool {
+        match expr {
+            Expr::If(_) | Expr::Match(_) => true,
+            Expr::Paren(paren) => Self::is_branching(&paren.expr),
+            _ => false,
+        }
+    }
+
+    // parse the `if` or `match` expression wrapped into the annotated `Or` type, and check
+    // that the branches fit in its variants, and use all of them unless `allow_unused_variants`
+    // is given.
+    fn parse_branches(&mut self, expr: Expr) -> Result<TokenStream2> {
+        let expr_tok = self.parse_expr_at_first(expr)?;
+        let (typ, arity) = match self
+            .typ
+            .as_ref()
+            .and_then(|typ| Some((typ, Self::arity(typ)?)))
+        {
+            Some(found) => found,
+            None => return Ok(expr_tok),
+        };
+        if self.variant_count > arity {
+            return Err(Error::new(
+                typ,
+                format!(
+                    "The annotation declares `Or{}`, but the branches need {} variants.",
+                    arity, self.variant_count
+                ),
+            )
+            .with_help(format!("add a type parameter for each extra branch, like `Or{}<...>`, or end a branch with a diverging expression like `return` or `panic!(...)`, which takes no variant.", self.variant_count)));
+        }
+        if self.variant_count < arity && !self.options.allow_unused_variants {
+            return Err(Error::new(
+                typ,
+                format!(
+                    "The annotation declares `Or{}`, but the branches use only {} of its variants.",
+                    arity, self.variant_count
+                ),
+            )
+            .with_help("remove the unused type parameters, or give `#[or_gen(allow_unused_variants)]` to keep them for other branches."));
+        }
+        Ok(expr_tok)
+    }
+
+    // get 3 from `Or3<i32, f32, String>`. `None` for other types like aliases of `Or` types,
+    // whose number of the variants is unknown here.
+    fn arity(typ: &Type) -> Option<usize> {
+        let ident = match typ {
+            Type::Path(ptype) if ptype.qself.is_none() => &ptype.path.segments.last()?.ident,
+            _ => return None,
+        };
+        ident.to_string().strip_prefix("Or")?.parse().ok()
     }
 
     // parse `if true { ... } else { ... }` in `let x: Or2<i32, f32> = if true { ... } else { ... }`
-    // unlike `parse_expr`, this function parses `if` or `match` expression only.
+    // unlike `parse_expr`, this function parses `if` or `match` expression only, which can be
+    // wrapped in parentheses. it can also be wrapped in method calls, casts or `?`, like
+    // `match v { ... }.into()`, unless the `Or` type is annotated on the binding, where it is
+    // not the type of the branches.
     fn parse_expr_at_first(&mut self, expr: Expr) -> Result<TokenStream2> {
         let expr_tok = match expr {
             Expr::If(expr_if) => self.parse_expr_if(expr_if),
             Expr::Match(expr_match) => self.parse_expr_match(expr_match),
+            Expr::TryBlock(try_block) => self.parse_try_block(try_block),
+            Expr::Paren(paren) => {
+                let attrs = paren.attrs;
+                let inner = self.parse_expr_at_first(*paren.expr)?;
+                let mut tok = quote! { #(#attrs)* };
+                paren.paren_token.surround(&mut tok, |tok| tok.extend(inner));
+                Ok(tok)
+            }
+            Expr::MethodCall(_) | Expr::Cast(_) | Expr::Try(_) if self.is_annotated_on_binding() => {
+                Err(Error::new(
+                    &expr,
+                    "The `Or` type annotated on the binding is not the type of the `if` or `match` expression wrapped in a method call, a cast or `?`."
+                        .to_string(),
+                )
+                .with_help("give the `Or` type of the branches in the attribute instead, like `#[or_gen(Or2<i32, f32>)]`."))
+            }
+            Expr::MethodCall(call) => {
+                let attrs = call.attrs;
+                let receiver = self.parse_expr_at_first(*call.receiver)?;
+                let dot_tok = call.dot_token;
+                let method = call.method;
+                let turbofish = call.turbofish;
+                let args = call.args;
+                let mut tok = quote! { #(#attrs)* #receiver #dot_tok #method #turbofish };
+                call.paren_token.surround(&mut tok, |tok| args.to_tokens(tok));
+                Ok(tok)
+            }
+            Expr::Cast(cast) => {
+                let attrs = cast.attrs;
+                let inner = self.parse_expr_at_first(*cast.expr)?;
+                let as_tok = cast.as_token;
+                let ty = cast.ty;
+                Ok(quote! { #(#attrs)* #inner #as_tok #ty })
+            }
+            Expr::Try(expr_try) => {
+                let attrs = expr_try.attrs;
+                let inner = self.parse_expr_at_first(*expr_try.expr)?;
+                let question_tok = expr_try.question_token;
+                Ok(quote! { #(#attrs)* #inner #question_tok })
+            }
             _ => Err(Error::new(
                 &expr,
-                format!("Unsupported expression found.`if` or `match` expressions are supported."),
+                "Unsupported expression found.`if` or `match` expressions are supported."
+                    .to_string(),
             )),
         }?;
 
         Ok(quote! { #expr_tok })
     }
 
+    // parse `try { ...; if true { ... } else { ... } }`. the value at the tail is wrapped in
+    // `Ok` or `Some` by the try block itself, so the branches are wrapped into the `Or` type
+    // inside the `Result` or the `Option`, rather than the `Result` or the `Option` itself.
+    fn parse_try_block(&mut self, try_block: ExprTryBlock) -> Result<TokenStream2> {
+        if self.typ.is_some() && self.nested.is_none() {
+            return Err(Error::new(
+                &try_block,
+                "The try block needs the `Or` type inside a `Result` or an `Option`, like `Result<Or2<i32, f32>, E>`.".to_string(),
+            ));
+        }
+        let mut stmts = try_block.block.stmts;
+        let tail = match stmts.pop() {
+            Some(Stmt::Expr(tail, None)) => tail,
+            _ => {
+                return Err(Error::new(
+                    try_block.try_token,
+                    "The try block needs an `if` or `match` expression at its tail.".to_string(),
+                ))
+            }
+        };
+        let nested = self.nested.take();
+        let tail_tok = self.parse_expr_at_first(tail);
+        self.nested = nested;
+        let tail_tok = tail_tok?;
+
+        let attrs = try_block.attrs;
+        let try_tok = try_block.try_token;
+        let mut tok = quote! { #(#attrs)* #try_tok };
+        try_block
+            .block
+            .brace_token
+            .surround(&mut tok, |tok| tok.extend(quote! { #(#stmts)* #tail_tok }));
+        Ok(tok)
+    }
+
+    // true if the `Or` type is annotated on the `let` binding, rather than given in the attribute.
+    fn is_annotated_on_binding(&self) -> bool {
+        self.typ.is_some() && self.options.typ.is_none()
+    }
+
     // parse `if true { ... } else { ... }` in `let x: Or2<i32, f32> = if true { ... } else { ... }`
     fn parse_expr(&mut self, expr: Expr) -> Result<TokenStream2> {
         let expr_tok = match expr {
             Expr::If(expr_if) => self.parse_expr_if(expr_if),
             Expr::Match(expr_match) => self.parse_expr_match(expr_match),
-            Expr::Lit(expr_lit) => {
-                let rewrited = self.rewrite_method_name(quote!(#expr_lit))?;
-                Ok(quote!(#rewrited))
-            }
-            Expr::MethodCall(expr_method_call) => {
-                let rewrited = self.rewrite_method_name(quote!(#expr_method_call))?;
-                Ok(quote!(#rewrited))
+            // a block body of a match arm is rewritten at its tail, like a branch of `if`.
+            // a labeled block is wrapped as a whole, since it can break with a value.
+            Expr::Block(block) if block.label.is_none() => {
+                let attrs = block.attrs;
+                let then_tok = self.parse_then(block.block)?;
+                Ok(quote! { #(#attrs)* #then_tok })
             }
-            _ => Err(Error::new(
-                &expr,
-                format!("Unsupported expression found.`if` or `match` expressions are supported."),
-            )),
+            // any other expression is a branch value, like a literal, a variable of
+            // a generic type, or a function call.
+            _ => self.parse_value(expr),
         }?;
 
         Ok(quote! { #expr_tok })
     }
 
     fn parse_expr_match(&mut self, expr_match: ExprMatch) -> Result<TokenStream2> {
+        let scrutinee = expr_match.expr;
         let arms_tok: TokenStream2 = expr_match
             .arms
             .into_iter()
-            .map(|arm| -> Result<TokenStream2> {
-                self.depth += 1;
-                self.parse_match_arm(arm)
-            })
+            .map(|arm| -> Result<TokenStream2> { self.parse_match_arm(arm) })
             .collect::<Vec<_>>()
             .into_iter()
             .collect::<Result<Vec<_>>>()?
             .into_iter()
             .collect();
 
-        Ok(quote! {
-            match 33 {
-                #arms_tok
-            };
-        })
+        // the tokens written by the user are emitted with their original spans, including
+        // the keywords and the braces, so that IDEs and diagnostics point at the user's code.
+        let match_tok = expr_match.match_token;
+        let mut tok = quote! { #match_tok #scrutinee };
+        expr_match
+            .brace_token
+            .surround(&mut tok, |tok| tok.extend(arms_tok));
+
+        Ok(tok)
     }
 
     fn parse_match_arm(&mut self, arm: Arm) -> Result<TokenStream2> {
+        // the attributes and the guard are kept as is. with `#[cfg(...)]`, the variant is still
+        // assigned by the position of the arm in the source, so the `Or` type is the same
+        // for every cfg combination.
+        let (attrs, variant) = Self::take_variant_attribute(arm.attrs)?;
+        if variant.is_some() && matches!(arm.body.as_ref(), Expr::If(_) | Expr::Match(_)) {
+            return Err(Error::new(
+                &arm.body,
+                "`#[variant(...)]` and `#[passthrough]` are supported on an arm whose body is a single value."
+                    .to_string(),
+            ));
+        }
+        self.variant = variant;
         let pat_tok = arm.pat;
+        let guard_tok = arm.guard.map(|(if_tok, cond)| quote! { #if_tok #cond });
+        let fat_arrow_tok = arm.fat_arrow_token;
+        // the comma is optional after a block, but the rewritten body is no longer a block.
+        let comma_tok = arm.comma.unwrap_or_default();
         let expr_tok = self.parse_expr(arm.body.as_ref().clone())?;
         Ok(quote! {
-                #pat_tok => #expr_tok,
+                #(#attrs)*
+                #pat_tok #guard_tok #fat_arrow_tok #expr_tok #comma_tok
         })
     }
 
     fn parse_expr_if(&mut self, expr_if: ExprIf) -> Result<TokenStream2> {
+        let if_tok = expr_if.if_token;
         let then_tok = self.parse_then(expr_if.then_branch)?;
         let cond = expr_if.cond.as_ref().clone();
         let cond_tok = quote! { #cond };
 
         let cur_if = quote! {
-            if #cond_tok #then_tok
+            #if_tok #cond_tok #then_tok
         };
 
         match expr_if.else_branch {
-            Some(else_branch) => {
-                match else_branch.1.as_ref().clone() {
+            Some((else_tok, else_branch)) => {
+                match else_branch.as_ref().clone() {
                     // else
                     Expr::Block(block) => {
                         let then = self.parse_then(block.block)?;
-                        Ok(quote! { #cur_if else { #then } })
+                        Ok(quote! { #cur_if #else_tok #then })
                     }
                     // else-if
                     Expr::If(_expr_if) => {
                         let _if = self.parse_expr_if(_expr_if)?;
-                        Ok(quote! { #cur_if else #_if })
+                        Ok(quote! { #cur_if #else_tok #_if })
                     }
                     _ => Err(Error::new(
-                        &else_branch.1,
+                        &else_branch,
                         "expected else or elseif".to_string(),
                     )),
                 }
             }
-            _ => Ok(cur_if),
+            // an `if` without `else` produces `()` when the condition is false.
+            None => {
+                let unit_tok = self.rewrite_value(quote! { () })?;
+                Ok(quote! { #cur_if else { #unit_tok } })
+            }
         }
     }
 
+    // parse `{ ... }` of a branch, keeping the original braces.
     fn parse_then(&mut self, then_branch: Block) -> Result<TokenStream2> {
-        self.depth += 1;
-        let stmts = then_branch.stmts;
-        self.parse_stmts(stmts)
+        let stmts = self.parse_stmts(then_branch.stmts)?;
+        let mut tok = TokenStream2::new();
+        then_branch
+            .brace_token
+            .surround(&mut tok, |tok| tok.extend(stmts));
+
+        Ok(tok)
     }
 
-    fn parse_stmts(&mut self, stmts: Vec<Stmt>) -> Result<TokenStream2> {
-        let (before, last) = stmts.split_at(stmts.len() - 1);
+    // only the tail expression of the branch is rewritten. the statements before it are kept
+    // as is, including closures, blocks and `match` expressions which have tails of their own.
+    fn parse_stmts(&mut self, mut stmts: Vec<Stmt>) -> Result<TokenStream2> {
+        let tail = match stmts.pop() {
+            Some(Stmt::Expr(expr, None)) => Some(expr),
+            // a macro call at the end without a semicolon, like `vec![]`.
+            Some(Stmt::Macro(stmt)) if stmt.semi_token.is_none() => Some(Expr::Macro(ExprMacro {
+                attrs: stmt.attrs,
+                mac: stmt.mac,
+            })),
+            Some(stmt) => {
+                stmts.push(stmt);
+                None
+            }
+            None => None,
+        };
 
-        let before_tok = quote! { #(#before)* };
-        let last = quote! { #(#last)* };
-        let rewrited_stmt = self.rewrite_method_name(last)?;
+        let before_tok = quote! { #(#stmts)* };
+        let rewrited_stmt = match tail {
+            // with `flatten`, the branches of the tail `if` or `match` become variants by themselves.
+            Some(expr @ (Expr::If(_) | Expr::Match(_))) if self.options.flatten => {
+                self.parse_expr(expr)?
+            }
+            Some(expr) => self.parse_value(expr)?,
+            // a branch ending with `return ...;` or `panic!(...);` produces no value.
+            None if stmts.last().map_or(false, Self::is_diverging_stmt) => TokenStream2::new(),
+            // a branch without the tail expression, like `{ f(); }`, produces `()`.
+            None => self.rewrite_value(quote! { () })?,
+        };
         let stmts = quote! {
-            // then-block
+            #before_tok
+            // return as `Or`
+            #rewrited_stmt
+        };
+
+        Ok(stmts)
+    }
+
+    // wraps a branch value, unless it diverges like `return x` or `panic!()`, in which case
+    // it is kept as is and takes no variant.
+    fn parse_value(&mut self, expr: Expr) -> Result<TokenStream2> {
+        if Self::is_diverging(&expr) {
+            self.variant = None;
+            return Ok(quote! { #expr });
+        }
+        self.rewrite_value(quote! { #expr })
+    }
+
+    fn is_diverging_stmt(stmt: &Stmt) -> bool {
+        match stmt {
+            Stmt::Expr(expr, _) => Self::is_diverging(expr),
+            Stmt::Macro(stmt) => Self::is_diverging_macro(&stmt.mac),
+            _ => false,
+        }
+    }
+
+    fn is_diverging(expr: &Expr) -> bool {
+        match expr {
+            Expr::Return(_) | Expr::Break(_) | Expr::Continue(_) => true,
+            Expr::Macro(expr) => Self::is_diverging_macro(&expr.mac),
+            _ => false,
+        }
+    }
+
+    // `panic!()`, `unreachable!()`, `todo!()` or `unimplemented!()`, with or without the path.
+    fn is_diverging_macro(mac: &Macro) -> bool {
+        mac.path.segments.last().map_or(false, |seg| {
+            matches!(
+                seg.ident.to_string().as_str(),
+                "panic" | "unreachable" | "todo" | "unimplemented"
+            )
+        })
+    }
+
+    // wraps a branch value into the next variant, like `rewrite_method_name`.
+    // the outer attributes of the value, like `#[allow(...)]`, can not be placed inside
+    // the variant constructor, so they are moved onto a `let` binding of the value.
+    fn rewrite_value(&mut self, value: TokenStream2) -> Result<TokenStream2> {
+        let (attrs, value) = match Self::parse_outer_attributes.parse2(value.clone()) {
+            Ok(parsed) => parsed,
+            Err(error) => return Err(Error::new(&value, error.to_string())),
+        };
+        let (attrs, variant) = Self::take_variant_attribute(attrs)?;
+        // the attributes are moved onto the `let` binding of the value, where `#[cfg(...)]`
+        // would remove the binding and leave the value undefined.
+        if let Some(attr) = attrs
+            .iter()
+            .find(|attr| attr.path().is_ident("cfg") || attr.path().is_ident("cfg_attr"))
+        {
+            return Err(Error::new(
+                attr,
+                "`#[cfg(...)]` and `#[cfg_attr(...)]` are not supported on a branch value."
+                    .to_string(),
+            )
+            .with_help(
+                "put the attribute on the `match` arm instead, which keeps the variants of the other arms.",
+            ));
+        }
+        if variant.is_some() {
+            self.variant = variant;
+        }
+        // the whole value is passed through, even inside an `Option` or a `Result`.
+        if matches!(self.variant, Some(Variant::Passthrough)) {
+            self.variant = None;
+            self.skip_position();
+            return Ok(Self::with_attributes(attrs, value));
+        }
+        if let Some(nested) = self.nested {
+            return self.rewrite_nested_value(nested, attrs, value);
+        }
+        self.rewrite_value_with_attributes(attrs, value)
+    }
+
+    fn rewrite_value_with_attributes(
+        &mut self,
+        attrs: Vec<Attribute>,
+        value: TokenStream2,
+    ) -> Result<TokenStream2> {
+        // a value like `Or3::T2(x)` is already of the `Or` type, and is not wrapped again.
+        if let Some(count) = Self::parse_or_constructor(&value) {
+            self.variant = None;
+            self.variant_count = self.variant_count.max(count);
+            self.skip_position();
+            return Ok(Self::with_attributes(attrs, value));
+        }
+        let value = Self::with_attributes(attrs, value);
+        self.rewrite_method_name(value)
+    }
+
+    // a value passed through still takes its position, so that the following branches are
+    // wrapped into the same variants as if it were a plain value.
+    fn skip_position(&mut self) {
+        self.depth += 1;
+        self.variant_count = self.variant_count.max(self.depth);
+    }
+
+    fn with_attributes(attrs: Vec<Attribute>, value: TokenStream2) -> TokenStream2 {
+        if attrs.is_empty() {
+            return value;
+        }
+        quote! {
             {
-                #before_tok
-                // return as `Or`
-                #rewrited_stmt
+                #(#attrs)*
+                let value = #value;
+                value
             }
+        }
+    }
+
+    // get `3` from a variant constructor like `Or3::T2(x)` or `or_rs::enums::Or3::<i32, f32, u8>::T2(x)`.
+    // the type of other expressions, like a call of a function returning `Or3`, can not be
+    // known by the macro, so such a branch needs `#[passthrough]`.
+    fn parse_or_constructor(value: &TokenStream2) -> Option<usize> {
+        let path = match syn::parse2::<Expr>(value.clone()).ok()? {
+            Expr::Call(call) if call.args.len() == 1 => match *call.func {
+                Expr::Path(path) if path.qself.is_none() => path.path,
+                _ => return None,
+            },
+            _ => return None,
         };
+        let mut segments = path.segments.iter().rev();
+        let variant = segments.next()?.ident.to_string();
+        let or = segments.next()?.ident.to_string();
+        let index = variant.strip_prefix('T')?.parse::<usize>().ok()?;
+        let count = or.strip_prefix("Or")?.parse::<usize>().ok()?;
+        if 0 < index && index <= count {
+            Some(count)
+        } else {
+            None
+        }
+    }
 
-        Ok(stmts)
+    // wraps the payload of `Some(...)` or `Ok(...)` into the next variant, and keeps `None`
+    // and `Err(...)` as is, for the `Or` type annotated inside an `Option` or a `Result`.
+    fn rewrite_nested_value(
+        &mut self,
+        nested: Nested,
+        attrs: Vec<Attribute>,
+        value: TokenStream2,
+    ) -> Result<TokenStream2> {
+        let (wrapper, passthrough, expected) = match nested {
+            Nested::Option => ("Some", "None", "`Some(...)` or `None`"),
+            Nested::Result => ("Ok", "Err", "`Ok(...)` or `Err(...)`"),
+        };
+        let expr = match syn::parse2::<Expr>(value.clone()) {
+            Ok(expr) => expr,
+            Err(_) => {
+                return Err(Error::new(
+                    &value,
+                    format!("Unsupported branch value found. {} is expected.", expected),
+                ))
+            }
+        };
+        // `#[variant(...)]` is for the payload, and ignored on `None` and `Err(...)`.
+        let variant = self.variant.take();
+        match &expr {
+            Expr::Call(call) if call.args.len() == 1 && Self::is_path_to(&call.func, wrapper) => {
+                let func = &call.func;
+                let payload = call.args.to_token_stream();
+                self.variant = variant;
+                let rewrited = self.rewrite_value_with_attributes(vec![], payload)?;
+                Ok(quote! { #(#attrs)* #func(#rewrited) })
+            }
+            Expr::Path(_) if Self::is_path_to(&expr, passthrough) => {
+                Ok(quote! { #(#attrs)* #expr })
+            }
+            Expr::Call(call) if Self::is_path_to(&call.func, passthrough) => {
+                Ok(quote! { #(#attrs)* #expr })
+            }
+            _ => Err(Error::new(
+                &expr,
+                format!("Unsupported branch value found. {} is expected.", expected),
+            )),
+        }
+    }
+
+    // returns true if `expr` is a path ending with `name`, like `Some` or `Option::Some`.
+    fn is_path_to(expr: &Expr, name: &str) -> bool {
+        match expr {
+            Expr::Path(path) => path
+                .path
+                .segments
+                .last()
+                .map_or(false, |seg| seg.ident == name),
+            _ => false,
+        }
+    }
+
+    // removes `#[variant(T2)]` or `#[or_gen::variant(T2)]` from the attributes, and returns
+    // the rest and the variant. the same for `#[passthrough]` or `#[or_gen::passthrough]`.
+    fn take_variant_attribute(attrs: Vec<Attribute>) -> Result<(Vec<Attribute>, Option<Variant>)> {
+        let mut variant = None;
+        let mut rest = vec![];
+        for attr in attrs {
+            if Self::is_attribute(&attr, "passthrough") {
+                if attr.meta.require_path_only().is_err() {
+                    return Err(Error::new(
+                        &attr,
+                        "`#[passthrough]` takes no arguments.".to_string(),
+                    ));
+                }
+                variant = Some(Variant::Passthrough);
+                continue;
+            }
+            if !Self::is_attribute(&attr, "variant") {
+                rest.push(attr);
+                continue;
+            }
+            let index = attr
+                .parse_args::<Ident>()
+                .ok()
+                .and_then(|ident| ident.to_string().strip_prefix('T')?.parse::<usize>().ok())
+                .filter(|index| *index > 0);
+            match index {
+                Some(index) => variant = Some(Variant::Index(index)),
+                None => {
+                    return Err(Error::new(
+                        &attr,
+                        "Fail to parse `#[variant(...)]`. a variant like `T2` is expected."
+                            .to_string(),
+                    )
+                    .with_help("the variants are named `T1`, `T2`, ... after the positions of the type parameters of the `Or` type."))
+                }
+            }
+        }
+        Ok((rest, variant))
+    }
+
+    // returns true if the attribute is `#[name]` or `#[or_gen::name]`.
+    fn is_attribute(attr: &Attribute, name: &str) -> bool {
+        let path = attr.path();
+        path.is_ident(name)
+            || (path.segments.len() == 2
+                && path.segments[0].ident == "or_gen"
+                && path.segments[1].ident == name)
+    }
+
+    // parse `#[allow(...)] value` into the attributes and the rest.
+    fn parse_outer_attributes(input: ParseStream) -> syn::Result<(Vec<Attribute>, TokenStream2)> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let rest = input.parse()?;
+        Ok((attrs, rest))
     }
 
     // get `Or3::Or3<i32, i32, f32>`
+    // each call wraps a branch value into the next variant, or the one given by `#[variant(...)]`,
+    // which does not take a position of the automatic assignment.
     fn rewrite_method_name(&mut self, wraped_expr: TokenStream2) -> Result<TokenStream2> {
-        let typ_tok = self.parse_enum_type()?;
-        let method_name: Ident =
-            Ident::new(format!("T{}", self.depth).as_str(), Span2::call_site());
-        let or_type_name = self.get_or_type_name()?;
+        let index = match self.variant.take() {
+            Some(Variant::Index(index)) => index,
+            _ => {
+                self.depth += 1;
+                self.depth
+            }
+        };
+        // counted in the first pass of `parse_expr_position`, and unchanged in the second.
+        self.variant_count = self.variant_count.max(index);
+        let or_type_path = self.parse_or_type_path()?;
+        // the variant is spanned to the value, so that a type mismatch is reported there.
+        let span = wraped_expr.span();
+        let method_name: Ident = Ident::new(format!("T{}", index).as_str(), span);
+        let wraped_expr = if self.options.coerce {
+            quote! { ::core::convert::Into::into(#wraped_expr) }
+        } else {
+            wraped_expr
+        };
+        let wraped_expr = if self.options.boxed || self.options.dyn_trait.is_some() {
+            let krate = self.options.krate();
+            quote! { #krate::__private::Box::new(#wraped_expr) }
+        } else {
+            wraped_expr
+        };
+        if self.options.trace {
+            let variant = method_name.to_string();
+            // `core::any::type_name_of_val` needs Rust 1.76, later than the MSRV.
+            return Ok(quote! {
+                {
+                    fn type_name_of<T>(_: &T) -> &'static str {
+                        ::core::any::type_name::<T>()
+                    }
+                    let value = #wraped_expr;
+                    ::tracing::debug!(
+                        variant = #variant,
+                        index = #index,
+                        type_name = type_name_of(&value),
+                        "or_gen branch taken",
+                    );
+                    #or_type_path::#method_name(value)
+                }
+            });
+        }
         Ok(quote! {
-            #or_type_name::#typ_tok::#method_name(#wraped_expr)
+            #or_type_path::#method_name(#wraped_expr)
         })
     }
 
-    // get `<i32, i32, f32>`
-    fn parse_enum_type(&mut self) -> Result<TokenStream2> {
-        let angle_bracket_tok = match &(self.typ) {
-            Type::Path(ptype) => match ptype.path.segments.first().cloned() {
-                Some(seg) => match seg.arguments {
-                    PathArguments::AngleBracketed(args) => Ok(args),
-                    _ => Err(Error::new(
-                        &seg.arguments,
-                        "Fail to parse type declaration".to_string(),
-                    )),
-                },
-                None => Err(Error::new(
-                    &ptype.path.segments,
+    // get `Or3::<i32, i32, f32>` from the annotated type, or `::or_rs::enums::Or3`
+    // if the type is not annotated, with `::or_rs` replaced by the path given as `crate = ...`. with a trait object given in the attribute, the generics
+    // are given as `Box<dyn ...>`, to which the boxed values are coerced.
+    fn parse_or_type_path(&self) -> Result<TokenStream2> {
+        match &self.typ {
+            Some(typ) => Self::parse_annotated_type_path(typ),
+            None => {
+                let or_type_name = Ident::new(
+                    format!("Or{}", self.variant_count).as_str(),
+                    Span2::call_site(),
+                );
+                let krate = self.options.krate();
+                match &self.options.dyn_trait {
+                    Some(dyn_trait) => {
+                        let generics = vec![dyn_trait; self.variant_count];
+                        Ok(
+                            quote!(#krate::enums::#or_type_name::<#(#krate::__private::Box<#generics>),*>),
+                        )
+                    }
+                    None => Ok(quote!(#krate::enums::#or_type_name)),
+                }
+            }
+        }
+    }
+
+    // get `or_rs::enums::Or3::<T, i32, Vec<T>>` from `or_rs::enums::Or3<T, i32, Vec<T>>`.
+    // the generic arguments are kept as tokens, so that they can refer to type parameters
+    // and lifetimes of the enclosing function.
+    fn parse_annotated_type_path(typ: &Type) -> Result<TokenStream2> {
+        let mut path = match typ {
+            Type::Path(ptype) if ptype.qself.is_none() => ptype.path.clone(),
+            _ => {
+                return Err(Error::new(
+                    typ,
                     "Fail to parse type declaration".to_string(),
-                )),
-            },
+                ))
+            }
+        };
+        let last_seg = match path.segments.last_mut() {
+            Some(seg) => seg,
+            None => {
+                return Err(Error::new(
+                    &path.segments,
+                    "Fail to parse type declaration".to_string(),
+                ))
+            }
+        };
+        match std::mem::replace(&mut last_seg.arguments, PathArguments::None) {
+            PathArguments::AngleBracketed(mut args) => {
+                args.colon2_token = None;
+                Ok(quote!(#path::#args))
+            }
+            // pointing at the type, since `PathArguments::None` has no tokens to point at.
             _ => Err(Error::new(
-                &self.typ,
+                typ,
                 "Fail to parse type declaration".to_string(),
             )),
-        }?;
-        Ok(quote!(#angle_bracket_tok))
-    }
-
-    // get `Or3` from Or3<i32,f32,String>
-    fn get_or_type_name(&self) -> Result<TokenStream2> {
-        let ty = &self.typ;
-        let str = quote!(#ty).to_string();
-        //
-        let idx = str
-            .find("<")
-            .unwrap_or_else(|| panic!("fail parse, expect token `,`. str: {}", str));
-        let substr = &str[0..idx];
-        Ok(substr
-            .parse()
-            .unwrap_or_else(|e| panic!("fail parse, expect token `,`. str: {}, error: {}", str, e)))
+        }
     }
 }
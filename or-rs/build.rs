@@ -0,0 +1,37 @@
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    #[cfg(feature = "codegen")]
+    codegen::generate();
+}
+
+// generates the `Or` types into `OUT_DIR`, which are `include!`d instead of the committed files.
+#[cfg(feature = "codegen")]
+mod codegen {
+    use std::{env, fs, path::Path};
+
+    pub(crate) fn generate() {
+        let out_dir = env::var("OUT_DIR").unwrap();
+
+        for (path, data) in code_gen::gen_files(arity(), false) {
+            let path = Path::new(&out_dir).join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(&path, data).unwrap();
+        }
+    }
+
+    // the largest arity enabled by the `arity-X` features.
+    fn arity() -> usize {
+        if cfg!(feature = "high-arity") {
+            16
+        } else if cfg!(feature = "arity-9") {
+            9
+        } else if cfg!(feature = "arity-6") {
+            6
+        } else if cfg!(feature = "arity-4") {
+            4
+        } else {
+            9
+        }
+    }
+}
@@ -0,0 +1,39 @@
+//! Concatenation of `Or` types, like `Or2<A, B>` and `Or3<C, D, E>` into `Or5<A, B, C, D, E>`.
+//!
+//! This merges the sums defined independently, like the errors of two libraries, into a single
+//! `Or` type at an API boundary. The values of either side are wrapped into the concatenated
+//! type by `concat_left` or `concat_right`, keeping their variants in order.
+//!
+//! ```rust
+//! use or_rs::concat::Concatenated;
+//! use or_rs::enums::{Or2, Or3};
+//!
+//! type IoErrors = Or2<std::io::Error, std::fmt::Error>;
+//! type ParseErrors = Or3<std::num::ParseIntError, std::num::ParseFloatError, std::str::ParseBoolError>;
+//! type Errors = Concatenated<IoErrors, ParseErrors>;
+//!
+//! let e: ParseErrors = Or3::T2("x".parse::<f64>().unwrap_err());
+//! let e: Errors = e.concat_right::<IoErrors>();
+//! assert!(e.is_t4());
+//!
+//! let e: IoErrors = Or2::T2(std::fmt::Error);
+//! let e: Errors = e.concat_left::<ParseErrors>();
+//! assert!(e.is_t2());
+//! ```
+
+/// Concatenation of `Self` and `Rhs`, implemented for the pairs of `Or` types whose
+/// concatenation is within the largest arity generated.
+pub trait Concat<Rhs> {
+    /// The `Or` type with the variants of `Self` followed by those of `Rhs`.
+    type Output;
+
+    /// Wraps the value into the first variants of the concatenated type.
+    fn left(lhs: Self) -> Self::Output;
+
+    /// Wraps the value into the last variants of the concatenated type.
+    fn right(rhs: Rhs) -> Self::Output;
+}
+
+/// The `Or` type concatenating `L` and `R`, like `Or5<A, B, C, D, E>` for `Or2<A, B>` and
+/// `Or3<C, D, E>`.
+pub type Concatenated<L, R> = <L as Concat<R>>::Output;
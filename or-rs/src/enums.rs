@@ -6,9 +6,866 @@
 //! The implementation of these enums includes several basic function such as `is_tx`
 //! for assertion and `as_tx` for cast, and also have some util functions, like `map`, `fold`.
 
-use std::any::TypeId;
+#![allow(clippy::type_complexity)]
+
+use core::any::{type_name, Any, TypeId};
+use core::convert::Infallible;
+use core::fmt;
+use core::future::Future;
+use core::iter::FusedIterator;
+use core::mem::{align_of, size_of};
+use core::ops::{Deref, DerefMut, Index, IndexMut};
+use core::pin::Pin;
+use core::str::FromStr;
+use core::task::{Context, Poll};
+
+#[cfg(feature = "alloc")]
+use alloc::borrow::{Cow, ToOwned};
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "std")]
+use std::error::Error;
+#[cfg(feature = "std")]
+use std::io;
+
+use crate::concat::Concat;
+use crate::from_str::ParseOrError;
+use crate::poly::PolyMap;
+use crate::when::When;
+
+// implements the traits and the methods which only forward to the value of the active variant,
+// along with the types named after the `Or` type, like `Or2Items` and `Or2Discriminant`.
+// each variant is given as `T1(t1, e1, I1, i1, E1, 0, T1)`, with the names of its payload, its
+// parse error, its iterator type and value, its collection type, its position in the tuple and
+// its type parameter, which is named apart from the variant so that it can be renamed.
+macro_rules! impl_forwarding {
+    // the formatting traits, so that the value can be formatted like `{:x}` if every payload can.
+    (@fmt $or:ident $trait:ident { $($t:ident($x:ident, $g:ident)),+ }) => {
+        /// Formats the value of the active variant.
+        impl<$($g),+> fmt::$trait for $or<$($g),+>
+        where
+            $($g: fmt::$trait),+
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                match self {
+                    $(Self::$t($x) => fmt::$trait::fmt($x, f),)+
+                }
+            }
+        }
+    };
+    // the impls bounding every payload type by the associated type of the first one, like
+    // `T2: Index<I, Output = T1::Output>`, for which the first variant is taken apart.
+    (@index $or:ident { $t1:ident($x1:ident, $g1:ident) $(, $t:ident($x:ident, $g:ident))+ }) => {
+        #[doc = concat!("Indexes into the value of the active variant, so an `", stringify!($or), "` of collections supporting")]
+        /// the same indexing, like `Vec<u8>` and `[u8; 32]`, stays indexable.
+        impl<I, $g1, $($g),+> Index<I> for $or<$g1, $($g),+>
+        where
+            $g1: Index<I>,
+            $($g: Index<I, Output = $g1::Output>),+
+        {
+            type Output = $g1::Output;
+
+            fn index(&self, index: I) -> &Self::Output {
+                match self {
+                    Self::$t1($x1) => $x1.index(index),
+                    $(Self::$t($x) => $x.index(index),)+
+                }
+            }
+        }
+
+        impl<I, $g1, $($g),+> IndexMut<I> for $or<$g1, $($g),+>
+        where
+            $g1: IndexMut<I>,
+            $($g: IndexMut<I, Output = $g1::Output>),+
+        {
+            fn index_mut(&mut self, index: I) -> &mut Self::Output {
+                match self {
+                    Self::$t1($x1) => $x1.index_mut(index),
+                    $(Self::$t($x) => $x.index_mut(index),)+
+                }
+            }
+        }
+    };
+    // the target type is taken from the first type, like the output type of `Index`.
+    (@deref $or:ident { $t1:ident($x1:ident, $g1:ident) $(, $t:ident($x:ident, $g:ident))+ }) => {
+        #[doc = concat!("Dereferences the value of the active variant, so an `", stringify!($or), "` of pointers to the same")]
+        /// target, like `Box<str>` and `String`, can call the methods of the target directly.
+        ///
+        /// As with `either::Either`, a method called on a value whose `T1` is not inferred yet,
+        #[doc = concat!("like `", stringify!($or), "::T2(x).boxed()`, needs the types given, since the method is looked up")]
+        /// on the target too.
+        impl<$g1, $($g),+> Deref for $or<$g1, $($g),+>
+        where
+            $g1: Deref,
+            $($g: Deref<Target = $g1::Target>),+
+        {
+            type Target = $g1::Target;
+
+            fn deref(&self) -> &Self::Target {
+                match self {
+                    Self::$t1($x1) => $x1.deref(),
+                    $(Self::$t($x) => $x.deref(),)+
+                }
+            }
+        }
+
+        impl<$g1, $($g),+> DerefMut for $or<$g1, $($g),+>
+        where
+            $g1: DerefMut,
+            $($g: DerefMut<Target = $g1::Target>),+
+        {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                match self {
+                    Self::$t1($x1) => $x1.deref_mut(),
+                    $(Self::$t($x) => $x.deref_mut(),)+
+                }
+            }
+        }
+    };
+    (@iterator $or:ident { $t1:ident($x1:ident, $g1:ident) $(, $t:ident($x:ident, $g:ident))+ }) => {
+        /// Iterates the iterator of the active variant, so the branches returning different iterators
+        /// of the same item, like `std::iter::once(x)` and `vec.into_iter()`, can be iterated directly.
+        ///
+        #[doc = concat!("The inherent `fold` and `try_fold` of `", stringify!($or), "` take precedence over the ones of `Iterator`,")]
+        /// which are called as `Iterator::fold(iter, init, f)` instead.
+        impl<$g1, $($g),+> Iterator for $or<$g1, $($g),+>
+        where
+            $g1: Iterator,
+            $($g: Iterator<Item = $g1::Item>),+
+        {
+            type Item = $g1::Item;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                match self {
+                    Self::$t1($x1) => $x1.next(),
+                    $(Self::$t($x) => $x.next(),)+
+                }
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                match self {
+                    Self::$t1($x1) => $x1.size_hint(),
+                    $(Self::$t($x) => $x.size_hint(),)+
+                }
+            }
+
+            fn nth(&mut self, n: usize) -> Option<Self::Item> {
+                match self {
+                    Self::$t1($x1) => $x1.nth(n),
+                    $(Self::$t($x) => $x.nth(n),)+
+                }
+            }
+
+            fn count(self) -> usize {
+                match self {
+                    Self::$t1($x1) => $x1.count(),
+                    $(Self::$t($x) => $x.count(),)+
+                }
+            }
+
+            fn last(self) -> Option<Self::Item> {
+                match self {
+                    Self::$t1($x1) => $x1.last(),
+                    $(Self::$t($x) => $x.last(),)+
+                }
+            }
+
+            fn fold<B, F>(self, init: B, f: F) -> B
+            where
+                F: FnMut(B, Self::Item) -> B,
+            {
+                match self {
+                    Self::$t1($x1) => $x1.fold(init, f),
+                    $(Self::$t($x) => $x.fold(init, f),)+
+                }
+            }
+        }
+
+        impl<$g1, $($g),+> DoubleEndedIterator for $or<$g1, $($g),+>
+        where
+            $g1: DoubleEndedIterator,
+            $($g: DoubleEndedIterator<Item = $g1::Item>),+
+        {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                match self {
+                    Self::$t1($x1) => $x1.next_back(),
+                    $(Self::$t($x) => $x.next_back(),)+
+                }
+            }
+
+            fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+                match self {
+                    Self::$t1($x1) => $x1.nth_back(n),
+                    $(Self::$t($x) => $x.nth_back(n),)+
+                }
+            }
+
+            fn rfold<B, F>(self, init: B, f: F) -> B
+            where
+                F: FnMut(B, Self::Item) -> B,
+            {
+                match self {
+                    Self::$t1($x1) => $x1.rfold(init, f),
+                    $(Self::$t($x) => $x.rfold(init, f),)+
+                }
+            }
+        }
+
+        impl<$g1, $($g),+> ExactSizeIterator for $or<$g1, $($g),+>
+        where
+            $g1: ExactSizeIterator,
+            $($g: ExactSizeIterator<Item = $g1::Item>),+
+        {
+            fn len(&self) -> usize {
+                match self {
+                    Self::$t1($x1) => $x1.len(),
+                    $(Self::$t($x) => $x.len(),)+
+                }
+            }
+        }
+
+        impl<$g1, $($g),+> FusedIterator for $or<$g1, $($g),+>
+        where
+            $g1: FusedIterator,
+            $($g: FusedIterator<Item = $g1::Item>),+
+        {
+        }
+    };
+    ($or:ident, $items:ident, $discriminant:ident { $($t:ident($x:ident, $e:ident, $it:ident, $i:ident, $ext:ident, $n:tt, $g:ident)),+ }) => {
+        #[doc = concat!("Extension to `", stringify!($or), "` to check if the enum's type matches a arbitrary type.")]
+        /// Currently, these functions depend on the rustc intrinsics, and the constraints
+        /// of the intrinsics require that the type must satisfy `'static'`.
+        impl<$($g),+> $or<$($g),+>
+        where
+            $($g: 'static),+
+        {
+            pub fn is_type<T: 'static>(&self) -> bool {
+                match self {
+                    $(Self::$t(_) => TypeId::of::<T>() == TypeId::of::<$g>(),)+
+                }
+            }
+
+            #[doc = concat!("Lifts a dynamically-typed value into `", stringify!($or), "` by downcasting it to its types in order.")]
+            /// If the value is none of these types, it is handed back as is.
+            #[cfg(feature = "alloc")]
+            pub fn from_any(value: Box<dyn Any>) -> Result<Self, Box<dyn Any>> {
+                $(
+                    let value = match value.downcast::<$g>() {
+                        Ok($x) => return Ok(Self::$t(*$x)),
+                        Err(value) => value,
+                    };
+                )+
+                Err(value)
+            }
+
+            /// Converts the value of the active variant into a dynamically-typed value,
+            /// which can be downcast back to the payload type, or lifted again with `from_any`.
+            #[cfg(feature = "alloc")]
+            pub fn into_any(self) -> Box<dyn Any> {
+                match self {
+                    $(Self::$t($x) => Box::new($x),)+
+                }
+            }
+
+            /// Borrows the value of the active variant as a dynamically-typed value.
+            pub fn as_any(&self) -> &dyn Any {
+                match self {
+                    $(Self::$t($x) => $x,)+
+                }
+            }
+
+            /// Moves the value of the active variant out if it is of type `T`, or hands back the
+            /// enum as is, so that a payload can be extracted by its type in generic code.
+            ///
+            /// `TryFrom<Self>` can not be implemented for the payload types instead, since they
+            /// are not local to this crate, and they can be the same type.
+            pub fn downcast<T: 'static>(self) -> Result<T, Self> {
+                match self {
+                    $(Self::$t($x) => {
+                        let mut value = Some($x);
+                        if let Some(t) = (&mut value as &mut dyn Any).downcast_mut::<Option<T>>() {
+                            // `value` is `Some` here, and `T` is the type of it.
+                            return Ok(t.take().unwrap());
+                        }
+                        Err(Self::$t(value.unwrap()))
+                    })+
+                }
+            }
+        }
+
+        #[doc = concat!("Parses a string into `", stringify!($or), "` by trying its types in order.")]
+        /// The first successful parse wins, and if every type fails to parse,
+        /// the errors of all the types are returned in a `ParseOrError`.
+        impl<$($g),+> FromStr for $or<$($g),+>
+        where
+            $($g: FromStr),+
+        {
+            type Err = ParseOrError<($($g::Err),+)>;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $(
+                    let $e = match $g::from_str(s) {
+                        Ok($x) => return Ok(Self::$t($x)),
+                        Err($e) => $e,
+                    };
+                )+
+                Err(ParseOrError::new(($($e),+)))
+            }
+        }
+
+        impl<$($ext),+> fmt::Debug for ParseOrError<($($ext),+)>
+        where
+            $($ext: fmt::Debug),+
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let ($($e),+) = self.errors();
+                f.debug_tuple("ParseOrError")$(.field($e))+.finish()
+            }
+        }
+
+        /// Lists the errors of all the types, in order.
+        impl<$($ext),+> fmt::Display for ParseOrError<($($ext),+)>
+        where
+            $($ext: fmt::Display),+
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let ($($e),+) = self.errors();
+                f.write_str("no type could parse the string")?;
+                for (i, e) in [$($e as &dyn fmt::Display),+].iter().enumerate() {
+                    f.write_str(if i == 0 { ": " } else { ", " })?;
+                    e.fmt(f)?;
+                }
+                Ok(())
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl<$($ext),+> Error for ParseOrError<($($ext),+)>
+        where
+            $($ext: Error),+
+        {
+        }
+
+        impl_forwarding!(@index $or { $($t($x, $g)),+ });
+        impl_forwarding!(@deref $or { $($t($x, $g)),+ });
+        impl_forwarding!(@iterator $or { $($t($x, $g)),+ });
+
+        impl_forwarding!(@fmt $or Display { $($t($x, $g)),+ });
+        impl_forwarding!(@fmt $or LowerHex { $($t($x, $g)),+ });
+        impl_forwarding!(@fmt $or UpperHex { $($t($x, $g)),+ });
+        impl_forwarding!(@fmt $or Octal { $($t($x, $g)),+ });
+        impl_forwarding!(@fmt $or Binary { $($t($x, $g)),+ });
+        impl_forwarding!(@fmt $or LowerExp { $($t($x, $g)),+ });
+        impl_forwarding!(@fmt $or UpperExp { $($t($x, $g)),+ });
+        impl_forwarding!(@fmt $or Pointer { $($t($x, $g)),+ });
+
+        #[doc = concat!("Forwards to the error of the active variant, so an `", stringify!($or), "` of errors is itself an error")]
+        /// with the same message and source.
+        #[cfg(feature = "std")]
+        impl<$($g),+> Error for $or<$($g),+>
+        where
+            $($g: Error),+
+        {
+            fn source(&self) -> Option<&(dyn Error + 'static)> {
+                match self {
+                    $(Self::$t($x) => $x.source(),)+
+                }
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl<$($g),+> $or<$($g),+>
+        where
+            $($g: Error + Send + Sync + 'static),+
+        {
+            /// Converts the error of the active variant into a boxed error.
+            ///
+            /// The error is boxed as is rather than wrapped in the enum, so it can be recovered
+            /// with `downcast` on the boxed error.
+            pub fn into_boxed_error(self) -> Box<dyn Error + Send + Sync> {
+                match self {
+                    $(Self::$t($x) => Box::new($x),)+
+                }
+            }
+        }
+
+        /// Writes to the writer of the active variant, so a formatting target chosen at runtime
+        /// can be written to uniformly.
+        impl<$($g),+> fmt::Write for $or<$($g),+>
+        where
+            $($g: fmt::Write),+
+        {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                match self {
+                    $(Self::$t($x) => $x.write_str(s),)+
+                }
+            }
+
+            fn write_char(&mut self, c: char) -> fmt::Result {
+                match self {
+                    $(Self::$t($x) => $x.write_char(c),)+
+                }
+            }
+
+            fn write_fmt(&mut self, args: fmt::Arguments<'_>) -> fmt::Result {
+                match self {
+                    $(Self::$t($x) => $x.write_fmt(args),)+
+                }
+            }
+        }
+
+        /// Borrows the value of the active variant as `U`, like `str`, `[u8]` or `Path`, so an
+        /// `Or` of path-like values can be passed to `File::open` directly.
+        ///
+        #[doc = concat!("The inherent `as_ref` and `as_mut` of `", stringify!($or), "` take precedence over the ones")]
+        /// of `AsRef` and `AsMut` in a method call, which are called as `AsRef::<U>::as_ref(&x)`.
+        impl<U: ?Sized, $($g),+> AsRef<U> for $or<$($g),+>
+        where
+            $($g: AsRef<U>),+
+        {
+            fn as_ref(&self) -> &U {
+                match self {
+                    $(Self::$t($x) => $x.as_ref(),)+
+                }
+            }
+        }
+
+        impl<U: ?Sized, $($g),+> AsMut<U> for $or<$($g),+>
+        where
+            $($g: AsMut<U>),+
+        {
+            fn as_mut(&mut self) -> &mut U {
+                match self {
+                    $(Self::$t($x) => $x.as_mut(),)+
+                }
+            }
+        }
+
+        /// Reads from the reader of the active variant, like a `File` or a `Cursor` chosen at
+        /// runtime.
+        #[cfg(feature = "std")]
+        impl<$($g),+> io::Read for $or<$($g),+>
+        where
+            $($g: io::Read),+
+        {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                match self {
+                    $(Self::$t($x) => $x.read(buf),)+
+                }
+            }
+
+            fn read_vectored(&mut self, bufs: &mut [io::IoSliceMut<'_>]) -> io::Result<usize> {
+                match self {
+                    $(Self::$t($x) => $x.read_vectored(bufs),)+
+                }
+            }
+
+            fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
+                match self {
+                    $(Self::$t($x) => $x.read_to_end(buf),)+
+                }
+            }
+
+            fn read_to_string(&mut self, buf: &mut String) -> io::Result<usize> {
+                match self {
+                    $(Self::$t($x) => $x.read_to_string(buf),)+
+                }
+            }
+
+            fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+                match self {
+                    $(Self::$t($x) => $x.read_exact(buf),)+
+                }
+            }
+        }
+
+        /// Reads from the buffered reader of the active variant.
+        #[cfg(feature = "std")]
+        impl<$($g),+> io::BufRead for $or<$($g),+>
+        where
+            $($g: io::BufRead),+
+        {
+            fn fill_buf(&mut self) -> io::Result<&[u8]> {
+                match self {
+                    $(Self::$t($x) => $x.fill_buf(),)+
+                }
+            }
+
+            fn consume(&mut self, amt: usize) {
+                match self {
+                    $(Self::$t($x) => $x.consume(amt),)+
+                }
+            }
+
+            fn read_until(&mut self, byte: u8, buf: &mut Vec<u8>) -> io::Result<usize> {
+                match self {
+                    $(Self::$t($x) => $x.read_until(byte, buf),)+
+                }
+            }
+
+            fn read_line(&mut self, buf: &mut String) -> io::Result<usize> {
+                match self {
+                    $(Self::$t($x) => $x.read_line(buf),)+
+                }
+            }
+        }
+
+        /// Writes to the writer of the active variant, like a `File` or a `Vec<u8>` chosen at
+        /// runtime.
+        #[cfg(feature = "std")]
+        impl<$($g),+> io::Write for $or<$($g),+>
+        where
+            $($g: io::Write),+
+        {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                match self {
+                    $(Self::$t($x) => $x.write(buf),)+
+                }
+            }
+
+            fn write_vectored(&mut self, bufs: &[io::IoSlice<'_>]) -> io::Result<usize> {
+                match self {
+                    $(Self::$t($x) => $x.write_vectored(bufs),)+
+                }
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                match self {
+                    $(Self::$t($x) => $x.flush(),)+
+                }
+            }
+
+            fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+                match self {
+                    $(Self::$t($x) => $x.write_all(buf),)+
+                }
+            }
+
+            fn write_fmt(&mut self, args: fmt::Arguments<'_>) -> io::Result<()> {
+                match self {
+                    $(Self::$t($x) => $x.write_fmt(args),)+
+                }
+            }
+        }
+
+        /// Seeks the stream of the active variant.
+        #[cfg(feature = "std")]
+        impl<$($g),+> io::Seek for $or<$($g),+>
+        where
+            $($g: io::Seek),+
+        {
+            fn seek(&mut self, pos: io::SeekFrom) -> io::Result<u64> {
+                match self {
+                    $(Self::$t($x) => $x.seek(pos),)+
+                }
+            }
+
+            fn rewind(&mut self) -> io::Result<()> {
+                match self {
+                    $(Self::$t($x) => $x.rewind(),)+
+                }
+            }
+
+            fn stream_position(&mut self) -> io::Result<u64> {
+                match self {
+                    $(Self::$t($x) => $x.stream_position(),)+
+                }
+            }
+        }
+
+        #[doc = concat!("See [`", stringify!($or), "::from_any`].")]
+        #[cfg(feature = "alloc")]
+        impl<$($g),+> TryFrom<Box<dyn Any>> for $or<$($g),+>
+        where
+            $($g: 'static),+
+        {
+            type Error = Box<dyn Any>;
+
+            fn try_from(value: Box<dyn Any>) -> Result<Self, Self::Error> {
+                Self::from_any(value)
+            }
+        }
+
+        #[doc = concat!("Partitions `", stringify!($or), "` values into a tuple of collections, where the payload of each variant")]
+        /// is appended to the collection at the same position, like `(Vec<T1>, Vec<T2>, ...)`.
+        impl<$($g),+, $($ext),+> Extend<$or<$($g),+>> for ($($ext),+)
+        where
+            $($ext: Extend<$g>),+
+        {
+            fn extend<I: IntoIterator<Item = $or<$($g),+>>>(&mut self, iter: I) {
+                for value in iter {
+                    match value {
+                        $($or::$t($x) => self.$n.extend(Some($x)),)+
+                    }
+                }
+            }
+        }
+
+        #[doc = concat!("An iterator over the items of an `", stringify!($or), "` of collections, each yielded as an `", stringify!($or), "`")]
+        #[doc = concat!("of the variant the collection is in. Created by [`", stringify!($or), "::iter_items`] and [`", stringify!($or), "::into_items`].")]
+        pub struct $items<$($it),+> {
+            inner: $or<$($it),+>,
+        }
+
+        impl<$($it),+> Iterator for $items<$($it),+>
+        where
+            $($it: Iterator),+
+        {
+            type Item = $or<$($it::Item),+>;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                match &mut self.inner {
+                    $($or::$t($i) => $i.next().map($or::$t),)+
+                }
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                match &self.inner {
+                    $($or::$t($i) => $i.size_hint(),)+
+                }
+            }
+        }
+
+        impl<$($g),+> $or<$($g),+> {
+            /// Returns an iterator over the items of the collection in the active variant, each
+            #[doc = concat!("borrowed and wrapped in the variant, like `", stringify!($or), "<&A, &B, ...>` for `", stringify!($or), "<Vec<A>, Vec<B>, ...>`.")]
+            pub fn iter_items<'a>(&'a self) -> $items<$(<&'a $g as IntoIterator>::IntoIter),+>
+            where
+                $(&'a $g: IntoIterator),+
+            {
+                self.as_ref().into_items()
+            }
+
+            /// Converts the collection in the active variant into an iterator over its items, each
+            /// wrapped in the variant.
+            pub fn into_items(self) -> $items<$($g::IntoIter),+>
+            where
+                $($g: IntoIterator),+
+            {
+                $items {
+                    inner: match self {
+                        $(Self::$t($x) => $or::$t($x.into_iter()),)+
+                    },
+                }
+            }
+        }
+
+        #[doc = concat!("The variant of an `", stringify!($or), "`, without its payload. Returned by [`", stringify!($or), "::discriminant`].")]
+        ///
+        /// This can be stored in sets or maps, or switched on, without touching the payloads.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub enum $discriminant {
+            $($t),+
+        }
+
+        impl $discriminant {
+            /// Returns an iterator over all the variants, in the order of their positions.
+            pub fn iter_all() -> impl DoubleEndedIterator<Item = Self> + ExactSizeIterator {
+                [$(Self::$t),+].into_iter()
+            }
+        }
+
+        impl<$($g),+> $or<$($g),+> {
+            /// Returns the variant of the enum, without its payload.
+            #[must_use]
+            pub fn discriminant(&self) -> $discriminant {
+                match self {
+                    $(Self::$t(_) => $discriminant::$t,)+
+                }
+            }
+
+            /// Returns the name of the active variant, like `"T1"`.
+            #[must_use]
+            pub const fn variant_name(&self) -> &'static str {
+                match self {
+                    $(Self::$t(_) => stringify!($t),)+
+                }
+            }
+
+            /// Returns the name of the payload type of the active variant, like `"i32"`, as
+            /// given by `core::any::type_name`.
+            ///
+            /// The name is meant for diagnostics like logs and error messages, and its exact
+            /// form may change between compiler versions.
+            #[must_use]
+            pub fn type_name(&self) -> &'static str {
+                match self {
+                    $(Self::$t(_) => type_name::<$g>(),)+
+                }
+            }
+        }
+
+        impl<$($g),+> $or<$($g),+> {
+            /// Converts the value of the active variant into `U`, which all the variants can be
+            /// converted into, collapsing the enum into a single type.
+            pub fn unify<U>(self) -> U
+            where
+                $($g: Into<U>),+
+            {
+                match self {
+                    $(Self::$t($x) => $x.into(),)+
+                }
+            }
+
+            /// Tries to convert the value of the active variant into `U`, like narrowing
+            /// numeric variants into a single type. On failure, the error of the conversion is
+            /// returned in the variant of the value.
+            pub fn try_unify<U>(self) -> Result<U, $or<$(<$g as TryInto<U>>::Error),+>>
+            where
+                $($g: TryInto<U>),+
+            {
+                match self {
+                    $(Self::$t($x) => $x.try_into().map_err($or::$t),)+
+                }
+            }
+        }
+
+        impl<$($g),+> $or<$($g),+> {
+            /// Wraps the value into the first variants of the `Or` type concatenating `Self`
+            /// and `R`.
+            pub fn concat_left<R>(self) -> <Self as Concat<R>>::Output
+            where
+                Self: Concat<R>,
+            {
+                Concat::left(self)
+            }
+
+            /// Wraps the value into the last variants of the `Or` type concatenating `L`
+            /// and `Self`.
+            pub fn concat_right<L>(self) -> <L as Concat<Self>>::Output
+            where
+                L: Concat<Self>,
+            {
+                L::right(self)
+            }
+        }
+
+        impl<$($g),+> $or<$($g),+> {
+            /// Maps the value of the active variant by `p`, which implements `PolyMap` for each
+            /// of the payload types.
+            pub fn map_poly<P>(self, p: P) -> $or<$(<P as PolyMap<$g>>::Output),+>
+            where
+                $(P: PolyMap<$g>,)+
+            {
+                match self {
+                    $(Self::$t($x) => $or::$t(p.call($x)),)+
+                }
+            }
+        }
+
+        impl<$($g),+> $or<$($g),+> {
+            /// Projects a pinned reference to the enum into a pinned reference to the value of
+            /// the active variant.
+            pub fn as_pin_ref(self: Pin<&Self>) -> $or<$(Pin<&$g>),+> {
+                // SAFETY: the value is pinned as long as the enum is, since it is never moved
+                // out of the enum, and the enum has no `Drop` impl nor a manual `Unpin` impl.
+                unsafe {
+                    match self.get_ref() {
+                        $(Self::$t($x) => $or::$t(Pin::new_unchecked($x)),)+
+                    }
+                }
+            }
+
+            /// Projects a pinned mutable reference to the enum into a pinned mutable reference
+            /// to the value of the active variant.
+            pub fn as_pin_mut(self: Pin<&mut Self>) -> $or<$(Pin<&mut $g>),+> {
+                // SAFETY: the value is pinned as long as the enum is, since it is never moved
+                // out of the enum, and the variant can not be changed through the pinned
+                // reference.
+                unsafe {
+                    match self.get_unchecked_mut() {
+                        $(Self::$t($x) => $or::$t(Pin::new_unchecked($x)),)+
+                    }
+                }
+            }
+        }
+
+        /// Polls the future of the active variant, so the branches calling different async
+        /// functions of the same output can be awaited directly.
+        impl<O, $($g),+> Future for $or<$($g),+>
+        where
+            $($g: Future<Output = O>),+
+        {
+            type Output = O;
+
+            fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<O> {
+                match self.as_pin_mut() {
+                    $($or::$t($x) => $x.poll(cx),)+
+                }
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl<$($g),+> $or<$($g),+> {
+            /// Boxes the value of the active variant, which shrinks the enum into the size of
+            /// a pointer and a tag, regardless of the size of the payloads.
+            #[must_use]
+            pub fn boxed(self) -> $or<$(Box<$g>),+> {
+                match self {
+                    $(Self::$t($x) => $or::$t(Box::new($x)),)+
+                }
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl<$($g),+> $or<$(Box<$g>),+> {
+            /// Moves the value of the active variant out of its box. The reverse of `boxed`.
+            #[must_use]
+            pub fn unboxed(self) -> $or<$($g),+> {
+                match self {
+                    $(Self::$t($x) => $or::$t(*$x),)+
+                }
+            }
+        }
+
+        // the lifetimes elided here are distinct, so the references need not share one.
+        impl<$($g: ?Sized),+> $or<$(&mut $g),+> {
+            /// Reborrows the mutable reference of the active variant for a shorter lifetime,
+            /// so that the enum can be passed down to functions repeatedly, like a plain
+            /// `&mut` is implicitly reborrowed.
+            #[must_use]
+            pub fn reborrow(&mut self) -> $or<$(&mut $g),+> {
+                match self {
+                    $(Self::$t($x) => $or::$t(&mut **$x),)+
+                }
+            }
+        }
+
+        #[doc = concat!("Extension to `", stringify!($or), "` of `Cow` values, such as zero-copy parse results.")]
+        #[cfg(feature = "alloc")]
+        impl<'a, $($g),+> $or<$(Cow<'a, $g>),+>
+        where
+            $($g: ToOwned + ?Sized),+
+        {
+            /// Converts the borrowed value of the active variant into the owned one, so that
+            /// the enum is detached from the borrowed data.
+            #[must_use]
+            pub fn into_owned(self) -> $or<$($g::Owned),+> {
+                match self {
+                    $(Self::$t($x) => $or::$t($x.into_owned()),)+
+                }
+            }
+
+            /// Returns a mutable reference to the owned value of the active variant,
+            /// cloning the borrowed value into the owned one if necessary.
+            pub fn to_mut(&mut self) -> $or<$(&mut $g::Owned),+> {
+                match self {
+                    $(Self::$t($x) => $or::$t($x.to_mut()),)+
+                }
+            }
+        }
+    };
+}
 
 /// `Or2` is an enum representing a value that can be either of 2 types, T1 ... T2.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(untagged)
+)]
+#[cfg_attr(feature = "repr-c", repr(C))]
+#[cfg_attr(feature = "repr-u8", repr(u8))]
 pub enum Or2<T1, T2> {
     T1(T1),
     T2(T2),
@@ -16,22 +873,19 @@ pub enum Or2<T1, T2> {
 
 impl<T1, T2> Or2<T1, T2> {
     /// Returns true if the enum is of type T1.
+    #[must_use]
     pub fn is_t1(&self) -> bool {
-        match self {
-            Self::T1(_) => true,
-            _ => false,
-        }
+        matches!(self, Self::T1(_))
     }
 
     /// Returns true if the enum is of type T2.
+    #[must_use]
     pub fn is_t2(&self) -> bool {
-        match self {
-            Self::T2(_) => true,
-            _ => false,
-        }
+        matches!(self, Self::T2(_))
     }
 
     /// Converts the enum to an Option containing the T1 value, if it is of type T1.
+    #[must_use]
     pub fn as_t1(self) -> Option<T1> {
         match self {
             Self::T1(t1) => Some(t1),
@@ -39,641 +893,649 @@ impl<T1, T2> Or2<T1, T2> {
         }
     }
 
-    /// Converts the enum to an Option containing the T2 value, if it is of type T2.
-    pub fn as_t2(self) -> Option<T2> {
+    /// Returns a reference to the T1 value, if it is of type T1, without consuming the enum.
+    #[must_use]
+    pub fn as_t1_ref(&self) -> Option<&T1> {
         match self {
-            Self::T2(t2) => Some(t2),
+            Self::T1(t1) => Some(t1),
             _ => None,
         }
     }
 
-    /// Transforms the T1 value of the enum using a provided function,
-    /// maintaining other types as is.
-    pub fn map_t1<F, B>(self, f: F) -> Or2<B, T2>
-    where
-        F: FnOnce(T1) -> B,
-    {
+    /// Returns a mutable reference to the T1 value, if it is of type T1.
+    #[must_use]
+    pub fn as_t1_mut(&mut self) -> Option<&mut T1> {
         match self {
-            Self::T1(t1) => Or2::<B, T2>::T1(f(t1)),
-            Self::T2(t2) => Or2::<B, T2>::T2(t2),
+            Self::T1(t1) => Some(t1),
+            _ => None,
         }
     }
 
-    /// Transforms the T2 value of the enum using a provided function,
-    /// maintaining other types as is.
-    pub fn map_t2<F, B>(self, f: F) -> Or2<T1, B>
-    where
-        F: FnOnce(T2) -> B,
-    {
+    /// Converts the enum to an Option containing the T2 value, if it is of type T2.
+    #[must_use]
+    pub fn as_t2(self) -> Option<T2> {
         match self {
-            Self::T1(t1) => Or2::<T1, B>::T1(t1),
-            Self::T2(t2) => Or2::<T1, B>::T2(f(t2)),
+            Self::T2(t2) => Some(t2),
+            _ => None,
         }
     }
 
-    /// Consolidates the `Or2` enum into a single value of type `T`,
-    /// by applying provided functions.
-    pub fn fold<T, F0, F1, F2>(self, f1: F1, f2: F2) -> T
-    where
-        F1: FnOnce(T1) -> T,
-        F2: FnOnce(T2) -> T,
-    {
+    /// Returns a reference to the T2 value, if it is of type T2, without consuming the enum.
+    #[must_use]
+    pub fn as_t2_ref(&self) -> Option<&T2> {
         match self {
-            Self::T1(t1) => f1(t1),
-            Self::T2(t2) => f2(t2),
+            Self::T2(t2) => Some(t2),
+            _ => None,
         }
     }
-}
 
-/// Extension to `Or2` to check if the enum's type matches a arbitrary type.
-/// Currently, these functions depend on the rustc intrinsics, and the constraints
-/// of the intrinsics require that the type must satisfy `'static'`.
-impl<T1, T2> Or2<T1, T2>
-where
-    T1: 'static,
-    T2: 'static,
-{
-    pub fn is_type<T: 'static>(&self) -> bool {
+    /// Returns a mutable reference to the T2 value, if it is of type T2.
+    #[must_use]
+    pub fn as_t2_mut(&mut self) -> Option<&mut T2> {
         match self {
-            Self::T1(_) => TypeId::of::<T>() == TypeId::of::<T1>(),
-            Self::T2(_) => TypeId::of::<T>() == TypeId::of::<T2>(),
+            Self::T2(t2) => Some(t2),
+            _ => None,
         }
     }
-}
-
-/// `Or3` is an enum representing a value that can be either of 3 types, T1 ... T3.
-#[derive(Clone, Copy)]
-pub enum Or3<T1, T2, T3> {
-    T1(T1),
-    T2(T2),
-    T3(T3),
-}
 
-impl<T1, T2, T3> Or3<T1, T2, T3> {
-    /// Returns true if the enum is of type T1.
-    pub fn is_t1(&self) -> bool {
+    /// Returns the T1 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum is not of type T1, naming the variant it is of.
+    #[track_caller]
+    pub fn unwrap_t1(self) -> T1 {
         match self {
-            Self::T1(_) => true,
-            _ => false,
+            Self::T1(t1) => t1,
+            _ => panic!(
+                "called `Or2::unwrap_t1()` on a `{:?}` value",
+                self.discriminant()
+            ),
         }
     }
 
-    /// Returns true if the enum is of type T2.
-    pub fn is_t2(&self) -> bool {
+    /// Returns the T1 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the enum is not of type T1.
+    #[track_caller]
+    pub fn expect_t1(self, msg: &str) -> T1 {
         match self {
-            Self::T2(_) => true,
-            _ => false,
+            Self::T1(t1) => t1,
+            _ => panic!("{}", msg),
         }
     }
 
-    /// Returns true if the enum is of type T3.
-    pub fn is_t3(&self) -> bool {
+    /// Returns the T2 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum is not of type T2, naming the variant it is of.
+    #[track_caller]
+    pub fn unwrap_t2(self) -> T2 {
         match self {
-            Self::T3(_) => true,
-            _ => false,
+            Self::T2(t2) => t2,
+            _ => panic!(
+                "called `Or2::unwrap_t2()` on a `{:?}` value",
+                self.discriminant()
+            ),
         }
     }
 
-    /// Converts the enum to an Option containing the T1 value, if it is of type T1.
-    pub fn as_t1(self) -> Option<T1> {
+    /// Returns the T2 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the enum is not of type T2.
+    #[track_caller]
+    pub fn expect_t2(self, msg: &str) -> T2 {
         match self {
-            Self::T1(t1) => Some(t1),
-            _ => None,
+            Self::T2(t2) => t2,
+            _ => panic!("{}", msg),
         }
     }
 
-    /// Converts the enum to an Option containing the T2 value, if it is of type T2.
-    pub fn as_t2(self) -> Option<T2> {
+    /// Converts from `&Or2<T1, ...>` to `Or2<&T1, ...>`.
+    #[must_use]
+    pub fn as_ref(&self) -> Or2<&T1, &T2> {
         match self {
-            Self::T2(t2) => Some(t2),
-            _ => None,
+            Self::T1(t1) => Or2::T1(t1),
+            Self::T2(t2) => Or2::T2(t2),
         }
     }
 
-    /// Converts the enum to an Option containing the T3 value, if it is of type T3.
-    pub fn as_t3(self) -> Option<T3> {
+    /// Converts from `&mut Or2<T1, ...>` to `Or2<&mut T1, ...>`.
+    #[must_use]
+    pub fn as_mut(&mut self) -> Or2<&mut T1, &mut T2> {
         match self {
-            Self::T3(t3) => Some(t3),
-            _ => None,
+            Self::T1(t1) => Or2::T1(t1),
+            Self::T2(t2) => Or2::T2(t2),
         }
     }
 
     /// Transforms the T1 value of the enum using a provided function,
     /// maintaining other types as is.
-    pub fn map_t1<F, B>(self, f: F) -> Or3<B, T2, T3>
+    #[must_use = "this returns the mapped enum, without modifying the original"]
+    pub fn map_t1<F, B>(self, f: F) -> Or2<B, T2>
     where
         F: FnOnce(T1) -> B,
     {
         match self {
-            Self::T1(t1) => Or3::<B, T2, T3>::T1(f(t1)),
-            Self::T2(t2) => Or3::<B, T2, T3>::T2(t2),
-            Self::T3(t3) => Or3::<B, T2, T3>::T3(t3),
+            Self::T1(t1) => Or2::<B, T2>::T1(f(t1)),
+            Self::T2(t2) => Or2::<B, T2>::T2(t2),
         }
     }
 
     /// Transforms the T2 value of the enum using a provided function,
     /// maintaining other types as is.
-    pub fn map_t2<F, B>(self, f: F) -> Or3<T1, B, T3>
+    #[must_use = "this returns the mapped enum, without modifying the original"]
+    pub fn map_t2<F, B>(self, f: F) -> Or2<T1, B>
     where
         F: FnOnce(T2) -> B,
     {
         match self {
-            Self::T1(t1) => Or3::<T1, B, T3>::T1(t1),
-            Self::T2(t2) => Or3::<T1, B, T3>::T2(f(t2)),
-            Self::T3(t3) => Or3::<T1, B, T3>::T3(t3),
+            Self::T1(t1) => Or2::<T1, B>::T1(t1),
+            Self::T2(t2) => Or2::<T1, B>::T2(f(t2)),
         }
     }
 
-    /// Transforms the T3 value of the enum using a provided function,
+    /// Transforms the T1 value of the enum using a provided async function and awaits it,
     /// maintaining other types as is.
-    pub fn map_t3<F, B>(self, f: F) -> Or3<T1, T2, B>
+    pub async fn map_t1_async<F, Fut, B>(self, f: F) -> Or2<B, T2>
     where
-        F: FnOnce(T3) -> B,
+        F: FnOnce(T1) -> Fut,
+        Fut: Future<Output = B>,
     {
         match self {
-            Self::T1(t1) => Or3::<T1, T2, B>::T1(t1),
-            Self::T2(t2) => Or3::<T1, T2, B>::T2(t2),
-            Self::T3(t3) => Or3::<T1, T2, B>::T3(f(t3)),
+            Self::T1(t1) => Or2::T1(f(t1).await),
+            Self::T2(t2) => Or2::T2(t2),
         }
     }
 
-    /// Consolidates the `Or3` enum into a single value of type `T`,
+    /// Transforms the T2 value of the enum using a provided async function and awaits it,
+    /// maintaining other types as is.
+    pub async fn map_t2_async<F, Fut, B>(self, f: F) -> Or2<T1, B>
+    where
+        F: FnOnce(T2) -> Fut,
+        Fut: Future<Output = B>,
+    {
+        match self {
+            Self::T1(t1) => Or2::T1(t1),
+            Self::T2(t2) => Or2::T2(f(t2).await),
+        }
+    }
+
+    /// Consolidates the `Or2` enum into a single value of type `T`,
     /// by applying provided functions.
-    pub fn fold<T, F0, F1, F2, F3>(self, f1: F1, f2: F2, f3: F3) -> T
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn fold<T, F1, F2>(self, f1: F1, f2: F2) -> T
     where
         F1: FnOnce(T1) -> T,
         F2: FnOnce(T2) -> T,
-        F3: FnOnce(T3) -> T,
     {
         match self {
             Self::T1(t1) => f1(t1),
             Self::T2(t2) => f2(t2),
-            Self::T3(t3) => f3(t3),
         }
     }
-}
 
-/// Extension to `Or3` to check if the enum's type matches a arbitrary type.
-/// Currently, these functions depend on the rustc intrinsics, and the constraints
-/// of the intrinsics require that the type must satisfy `'static'`.
-impl<T1, T2, T3> Or3<T1, T2, T3>
-where
-    T1: 'static,
-    T2: 'static,
-    T3: 'static,
-{
-    pub fn is_type<T: 'static>(&self) -> bool {
+    /// Consolidates the `Or2` enum into a single value of type `T` by applying provided
+    /// fallible functions, returning the error of the applied one, so that the functions can use `?`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_fold<T, E, F1, F2>(self, f1: F1, f2: F2) -> Result<T, E>
+    where
+        F1: FnOnce(T1) -> Result<T, E>,
+        F2: FnOnce(T2) -> Result<T, E>,
+    {
         match self {
-            Self::T1(_) => TypeId::of::<T>() == TypeId::of::<T1>(),
-            Self::T2(_) => TypeId::of::<T>() == TypeId::of::<T2>(),
-            Self::T3(_) => TypeId::of::<T>() == TypeId::of::<T3>(),
+            Self::T1(t1) => f1(t1),
+            Self::T2(t2) => f2(t2),
         }
     }
-}
-
-/// `Or4` is an enum representing a value that can be either of 4 types, T1 ... T4.
-pub enum Or4<T1, T2, T3, T4> {
-    T1(T1),
-    T2(T2),
-    T3(T3),
-    T4(T4),
-}
 
-impl<T1, T2, T3, T4> Or4<T1, T2, T3, T4> {
-    /// Returns true if the enum is of type T1.
-    pub fn is_t1(&self) -> bool {
+    /// Consolidates the `Or2` enum into a single value of type `T`,
+    /// by applying provided async functions and awaiting the result.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn fold_async<T, F1, Fut1, F2, Fut2>(self, f1: F1, f2: F2) -> T
+    where
+        F1: FnOnce(T1) -> Fut1,
+        Fut1: Future<Output = T>,
+        F2: FnOnce(T2) -> Fut2,
+        Fut2: Future<Output = T>,
+    {
         match self {
-            Self::T1(_) => true,
-            _ => false,
+            Self::T1(t1) => f1(t1).await,
+            Self::T2(t2) => f2(t2).await,
         }
     }
 
-    /// Returns true if the enum is of type T2.
-    pub fn is_t2(&self) -> bool {
+    /// Handles the T1 value of the enum using a provided function, and leaves the other
+    /// variants to the subsequent `when_tx` calls.
+    pub fn when_t1<R, F>(self, f: F) -> When<R, Or2<Infallible, T2>>
+    where
+        F: FnOnce(T1) -> R,
+    {
         match self {
-            Self::T2(_) => true,
-            _ => false,
+            Self::T1(t1) => When::handled(f(t1)),
+            Self::T2(t2) => When::pending(Or2::T2(t2)),
         }
     }
 
-    /// Returns true if the enum is of type T3.
-    pub fn is_t3(&self) -> bool {
+    /// Handles the T2 value of the enum using a provided function, and leaves the other
+    /// variants to the subsequent `when_tx` calls.
+    pub fn when_t2<R, F>(self, f: F) -> When<R, Or2<T1, Infallible>>
+    where
+        F: FnOnce(T2) -> R,
+    {
         match self {
-            Self::T3(_) => true,
-            _ => false,
+            Self::T1(t1) => When::pending(Or2::T1(t1)),
+            Self::T2(t2) => When::handled(f(t2)),
         }
     }
 
-    /// Returns true if the enum is of type T4.
-    pub fn is_t4(&self) -> bool {
+    /// Converts the enum into a tuple of Options, where only the slot of the active variant is `Some`.
+    #[must_use]
+    pub fn into_options(self) -> (Option<T1>, Option<T2>) {
         match self {
-            Self::T4(_) => true,
-            _ => false,
+            Self::T1(t1) => (Some(t1), None),
+            Self::T2(t2) => (None, Some(t2)),
         }
     }
 
-    /// Converts the enum to an Option containing the T1 value, if it is of type T1.
-    pub fn as_t1(self) -> Option<T1> {
-        match self {
-            Self::T1(t1) => Some(t1),
-            _ => None,
+    /// Borrows the enum as a tuple of Options, where only the slot of the active variant is `Some`.
+    #[must_use]
+    pub fn as_options(&self) -> (Option<&T1>, Option<&T2>) {
+        self.as_ref().into_options()
+    }
+
+    /// Builds the enum from a tuple of Options, which succeeds only when exactly one of them is `Some`.
+    /// Otherwise the tuple is handed back as is.
+    pub fn try_from_options(
+        options: (Option<T1>, Option<T2>),
+    ) -> Result<Self, (Option<T1>, Option<T2>)> {
+        match options {
+            (Some(t1), None) => Ok(Self::T1(t1)),
+            (None, Some(t2)) => Ok(Self::T2(t2)),
+            options => Err(options),
         }
     }
+}
 
-    /// Converts the enum to an Option containing the T2 value, if it is of type T2.
-    pub fn as_t2(self) -> Option<T2> {
+impl_forwarding!(Or2, Or2Items, Or2Discriminant {
+    T1(t1, e1, I1, i1, E1, 0, T1),
+    T2(t2, e2, I2, i2, E2, 1, T2)
+});
+
+impl<T1, T2> Or2<T1, T2> {
+    /// The number of the variants, which is the size of the arrays returned by
+    /// [`Or2::variant_sizes`] and friends, indexed by [`Or2::variant_index`].
+    pub const VARIANT_COUNT: usize = 2;
+
+    /// Returns the 0-based position of the active variant, like `0` for `T1`.
+    ///
+    /// This can index a table of handlers or counters, with `VARIANT_COUNT` entries.
+    #[must_use]
+    pub const fn variant_index(&self) -> usize {
         match self {
-            Self::T2(t2) => Some(t2),
-            _ => None,
+            Self::T1(_) => 0,
+            Self::T2(_) => 1,
         }
     }
 
-    /// Converts the enum to an Option containing the T3 value, if it is of type T3.
-    pub fn as_t3(self) -> Option<T3> {
+    /// Returns the sizes of the payload types, in the order of the variants.
+    ///
+    /// Comparing them with the size of the enum tells whether one oversized variant bloats
+    /// the whole enum, in which case it can be shrunk by [`Or2::boxed`].
+    pub const fn variant_sizes() -> [usize; 2] {
+        [size_of::<T1>(), size_of::<T2>()]
+    }
+
+    /// Returns the alignments of the payload types, in the order of the variants.
+    pub const fn variant_aligns() -> [usize; 2] {
+        [align_of::<T1>(), align_of::<T2>()]
+    }
+
+    /// Returns the size of the largest payload type, which the size of the enum is at least.
+    pub const fn max_variant_size() -> usize {
+        let sizes = Self::variant_sizes();
+        let mut max = 0;
+        let mut i = 0;
+        while i < sizes.len() {
+            if sizes[i] > max {
+                max = sizes[i];
+            }
+            i += 1;
+        }
+        max
+    }
+}
+
+impl<T2> Or2<Infallible, T2> {
+    /// Removes the uninhabited `T1` position from `Or2`, which is useful to simplify
+    /// an `Or` type once some of its branches become impossible.
+    #[must_use]
+    pub fn compact_t1(self) -> T2 {
         match self {
-            Self::T3(t3) => Some(t3),
-            _ => None,
+            Self::T1(never) => match never {},
+            Self::T2(t2) => t2,
         }
     }
+}
 
-    /// Converts the enum to an Option containing the T4 value, if it is of type T4.
-    pub fn as_t4(self) -> Option<T4> {
+impl<T1> Or2<T1, Infallible> {
+    /// Removes the uninhabited `T2` position from `Or2`, which is useful to simplify
+    /// an `Or` type once some of its branches become impossible.
+    #[must_use]
+    pub fn compact_t2(self) -> T1 {
         match self {
-            Self::T4(t4) => Some(t4),
-            _ => None,
+            Self::T1(t1) => t1,
+            Self::T2(never) => match never {},
         }
     }
+}
 
-    /// Transforms the T1 value of the enum using a provided function,
-    /// maintaining other types as is.
-    pub fn map_t1<F, B>(self, f: F) -> Or4<B, T2, T3, T4>
-    where
-        F: FnOnce(T1) -> B,
-    {
+impl<T1, T2> Or2<T1, T2> {
+    /// Converts the enum into a `Result`, treating `T1` as the ok value and the others as the
+    /// error. The conversion is lossless, and can be reverted by `From`.
+    pub fn into_result(self) -> Result<T1, T2> {
         match self {
-            Self::T1(t1) => Or4::<B, T2, T3, T4>::T1(f(t1)),
-            Self::T2(t2) => Or4::<B, T2, T3, T4>::T2(t2),
-            Self::T3(t3) => Or4::<B, T2, T3, T4>::T3(t3),
-            Self::T4(t4) => Or4::<B, T2, T3, T4>::T4(t4),
+            Self::T1(t1) => Ok(t1),
+            Self::T2(t2) => Err(t2),
         }
     }
 
-    /// Transforms the T2 value of the enum using a provided function,
-    /// maintaining other types as is.
-    pub fn map_t2<F, B>(self, f: F) -> Or4<T1, B, T3, T4>
+    /// Converts the enum into a `Result`, treating `T1` as the ok value and converting the
+    /// others into the error type `E`.
+    pub fn try_into_result<E>(self) -> Result<T1, E>
     where
-        F: FnOnce(T2) -> B,
+        T2: Into<E>,
     {
         match self {
-            Self::T1(t1) => Or4::<T1, B, T3, T4>::T1(t1),
-            Self::T2(t2) => Or4::<T1, B, T3, T4>::T2(f(t2)),
-            Self::T3(t3) => Or4::<T1, B, T3, T4>::T3(t3),
-            Self::T4(t4) => Or4::<T1, B, T3, T4>::T4(t4),
+            Self::T1(t1) => Ok(t1),
+            Self::T2(t2) => Err(t2.into()),
         }
     }
+}
 
-    /// Transforms the T3 value of the enum using a provided function,
-    /// maintaining other types as is.
-    pub fn map_t3<F, B>(self, f: F) -> Or4<T1, T2, B, T4>
-    where
-        F: FnOnce(T3) -> B,
-    {
+/// Converts the ok value into `T1` and the error into the remaining positions, which reverts
+/// `into_result`.
+impl<T1, T2> From<Result<T1, T2>> for Or2<T1, T2> {
+    fn from(result: Result<T1, T2>) -> Self {
+        match result {
+            Ok(t1) => Self::T1(t1),
+            Err(t2) => Self::T2(t2),
+        }
+    }
+}
+
+impl<T1> Or2<T1, ()> {
+    /// Converts the enum into an `Option`, which is `None` for the `()` in the last position.
+    /// The conversion is lossless, and can be reverted by `From`.
+    pub fn into_option(self) -> Option<T1> {
         match self {
-            Self::T1(t1) => Or4::<T1, T2, B, T4>::T1(t1),
-            Self::T2(t2) => Or4::<T1, T2, B, T4>::T2(t2),
-            Self::T3(t3) => Or4::<T1, T2, B, T4>::T3(f(t3)),
-            Self::T4(t4) => Or4::<T1, T2, B, T4>::T4(t4),
+            Self::T1(t1) => Some(t1),
+            Self::T2(()) => None,
         }
     }
+}
 
-    /// Transforms the T4 value of the enum using a provided function,
-    /// maintaining other types as is.
-    pub fn map_t4<F, B>(self, f: F) -> Or4<T1, T2, T3, B>
+/// Converts `None` into the `()` in the last position, which reverts `into_option`.
+impl<T1> From<Option<T1>> for Or2<T1, ()> {
+    fn from(option: Option<T1>) -> Self {
+        match option {
+            Some(t1) => Self::T1(t1),
+            None => Self::T2(()),
+        }
+    }
+}
+
+impl<R, T1, T2> When<R, Or2<T1, T2>> {
+    /// Handles the T1 value of the pending `Or2` using a provided function,
+    /// unless a previous `when_tx` has already handled the value.
+    pub fn when_t1<F>(self, f: F) -> When<R, Or2<Infallible, T2>>
     where
-        F: FnOnce(T4) -> B,
+        F: FnOnce(T1) -> R,
     {
-        match self {
-            Self::T1(t1) => Or4::<T1, T2, T3, B>::T1(t1),
-            Self::T2(t2) => Or4::<T1, T2, T3, B>::T2(t2),
-            Self::T3(t3) => Or4::<T1, T2, T3, B>::T3(t3),
-            Self::T4(t4) => Or4::<T1, T2, T3, B>::T4(f(t4)),
+        match self.into_state() {
+            Ok(result) => When::handled(result),
+            Err(or) => or.when_t1(f),
         }
     }
 
-    /// Consolidates the `Or4` enum into a single value of type `T`,
-    /// by applying provided functions.
-    pub fn fold<T, F0, F1, F2, F3, F4>(self, f1: F1, f2: F2, f3: F3, f4: F4) -> T
+    /// Handles the T2 value of the pending `Or2` using a provided function,
+    /// unless a previous `when_tx` has already handled the value.
+    pub fn when_t2<F>(self, f: F) -> When<R, Or2<T1, Infallible>>
     where
-        F1: FnOnce(T1) -> T,
-        F2: FnOnce(T2) -> T,
-        F3: FnOnce(T3) -> T,
-        F4: FnOnce(T4) -> T,
+        F: FnOnce(T2) -> R,
     {
-        match self {
-            Self::T1(t1) => f1(t1),
-            Self::T2(t2) => f2(t2),
-            Self::T3(t3) => f3(t3),
-            Self::T4(t4) => f4(t4),
+        match self.into_state() {
+            Ok(result) => When::handled(result),
+            Err(or) => or.when_t2(f),
         }
     }
 }
 
-/// Extension to `Or4` to check if the enum's type matches a arbitrary type.
-/// Currently, these functions depend on the rustc intrinsics, and the constraints
-/// of the intrinsics require that the type must satisfy `'static'`.
-impl<T1, T2, T3, T4> Or4<T1, T2, T3, T4>
-where
-    T1: 'static,
-    T2: 'static,
-    T3: 'static,
-    T4: 'static,
-{
-    pub fn is_type<T: 'static>(&self) -> bool {
-        match self {
-            Self::T1(_) => TypeId::of::<T>() == TypeId::of::<T1>(),
-            Self::T2(_) => TypeId::of::<T>() == TypeId::of::<T2>(),
-            Self::T3(_) => TypeId::of::<T>() == TypeId::of::<T3>(),
-            Self::T4(_) => TypeId::of::<T>() == TypeId::of::<T4>(),
+impl<R> When<R, Or2<Infallible, Infallible>> {
+    /// Finishes the chain and returns the result. This is available only when every
+    /// variant of `Or2` has been handled.
+    pub fn finish(self) -> R {
+        match self.into_state() {
+            Ok(result) => result,
+            Err(or) => match or {
+                Or2::T1(never) => match never {},
+                Or2::T2(never) => match never {},
+            },
         }
     }
 }
 
-/// `Or5` is an enum representing a value that can be either of 5 types, T1 ... T5.
-pub enum Or5<T1, T2, T3, T4, T5> {
-    T1(T1),
-    T2(T2),
-    T3(T3),
-    T4(T4),
-    T5(T5),
-}
+impl<T1, T2, T3, T4> Concat<Or2<T3, T4>> for Or2<T1, T2> {
+    type Output = Or4<T1, T2, T3, T4>;
 
-impl<T1, T2, T3, T4, T5> Or5<T1, T2, T3, T4, T5> {
-    /// Returns true if the enum is of type T1.
-    pub fn is_t1(&self) -> bool {
-        match self {
-            Self::T1(_) => true,
-            _ => false,
+    fn left(lhs: Self) -> Self::Output {
+        match lhs {
+            Or2::T1(x) => Or4::T1(x),
+            Or2::T2(x) => Or4::T2(x),
         }
     }
 
-    /// Returns true if the enum is of type T2.
-    pub fn is_t2(&self) -> bool {
-        match self {
-            Self::T2(_) => true,
-            _ => false,
+    fn right(rhs: Or2<T3, T4>) -> Self::Output {
+        match rhs {
+            Or2::T1(x) => Or4::T3(x),
+            Or2::T2(x) => Or4::T4(x),
         }
     }
+}
 
-    /// Returns true if the enum is of type T3.
-    pub fn is_t3(&self) -> bool {
-        match self {
-            Self::T3(_) => true,
-            _ => false,
-        }
-    }
+impl<T1, T2, T3, T4, T5> Concat<Or3<T3, T4, T5>> for Or2<T1, T2> {
+    type Output = Or5<T1, T2, T3, T4, T5>;
 
-    /// Returns true if the enum is of type T4.
-    pub fn is_t4(&self) -> bool {
-        match self {
-            Self::T4(_) => true,
-            _ => false,
+    fn left(lhs: Self) -> Self::Output {
+        match lhs {
+            Or2::T1(x) => Or5::T1(x),
+            Or2::T2(x) => Or5::T2(x),
         }
     }
 
-    /// Returns true if the enum is of type T5.
-    pub fn is_t5(&self) -> bool {
-        match self {
-            Self::T5(_) => true,
-            _ => false,
+    fn right(rhs: Or3<T3, T4, T5>) -> Self::Output {
+        match rhs {
+            Or3::T1(x) => Or5::T3(x),
+            Or3::T2(x) => Or5::T4(x),
+            Or3::T3(x) => Or5::T5(x),
         }
     }
+}
 
-    /// Converts the enum to an Option containing the T1 value, if it is of type T1.
-    pub fn as_t1(self) -> Option<T1> {
-        match self {
-            Self::T1(t1) => Some(t1),
-            _ => None,
-        }
-    }
+impl<T1, T2, T3, T4, T5, T6> Concat<Or4<T3, T4, T5, T6>> for Or2<T1, T2> {
+    type Output = Or6<T1, T2, T3, T4, T5, T6>;
 
-    /// Converts the enum to an Option containing the T2 value, if it is of type T2.
-    pub fn as_t2(self) -> Option<T2> {
-        match self {
-            Self::T2(t2) => Some(t2),
-            _ => None,
+    fn left(lhs: Self) -> Self::Output {
+        match lhs {
+            Or2::T1(x) => Or6::T1(x),
+            Or2::T2(x) => Or6::T2(x),
         }
     }
 
-    /// Converts the enum to an Option containing the T3 value, if it is of type T3.
-    pub fn as_t3(self) -> Option<T3> {
-        match self {
-            Self::T3(t3) => Some(t3),
-            _ => None,
+    fn right(rhs: Or4<T3, T4, T5, T6>) -> Self::Output {
+        match rhs {
+            Or4::T1(x) => Or6::T3(x),
+            Or4::T2(x) => Or6::T4(x),
+            Or4::T3(x) => Or6::T5(x),
+            Or4::T4(x) => Or6::T6(x),
         }
     }
+}
 
-    /// Converts the enum to an Option containing the T4 value, if it is of type T4.
-    pub fn as_t4(self) -> Option<T4> {
-        match self {
-            Self::T4(t4) => Some(t4),
-            _ => None,
-        }
-    }
+impl<T1, T2, T3, T4, T5, T6, T7> Concat<Or5<T3, T4, T5, T6, T7>> for Or2<T1, T2> {
+    type Output = Or7<T1, T2, T3, T4, T5, T6, T7>;
 
-    /// Converts the enum to an Option containing the T5 value, if it is of type T5.
-    pub fn as_t5(self) -> Option<T5> {
-        match self {
-            Self::T5(t5) => Some(t5),
-            _ => None,
+    fn left(lhs: Self) -> Self::Output {
+        match lhs {
+            Or2::T1(x) => Or7::T1(x),
+            Or2::T2(x) => Or7::T2(x),
         }
     }
 
-    /// Transforms the T1 value of the enum using a provided function,
-    /// maintaining other types as is.
-    pub fn map_t1<F, B>(self, f: F) -> Or5<B, T2, T3, T4, T5>
-    where
-        F: FnOnce(T1) -> B,
-    {
-        match self {
-            Self::T1(t1) => Or5::<B, T2, T3, T4, T5>::T1(f(t1)),
-            Self::T2(t2) => Or5::<B, T2, T3, T4, T5>::T2(t2),
-            Self::T3(t3) => Or5::<B, T2, T3, T4, T5>::T3(t3),
-            Self::T4(t4) => Or5::<B, T2, T3, T4, T5>::T4(t4),
-            Self::T5(t5) => Or5::<B, T2, T3, T4, T5>::T5(t5),
+    fn right(rhs: Or5<T3, T4, T5, T6, T7>) -> Self::Output {
+        match rhs {
+            Or5::T1(x) => Or7::T3(x),
+            Or5::T2(x) => Or7::T4(x),
+            Or5::T3(x) => Or7::T5(x),
+            Or5::T4(x) => Or7::T6(x),
+            Or5::T5(x) => Or7::T7(x),
         }
     }
+}
 
-    /// Transforms the T2 value of the enum using a provided function,
-    /// maintaining other types as is.
-    pub fn map_t2<F, B>(self, f: F) -> Or5<T1, B, T3, T4, T5>
-    where
-        F: FnOnce(T2) -> B,
-    {
-        match self {
-            Self::T1(t1) => Or5::<T1, B, T3, T4, T5>::T1(t1),
-            Self::T2(t2) => Or5::<T1, B, T3, T4, T5>::T2(f(t2)),
-            Self::T3(t3) => Or5::<T1, B, T3, T4, T5>::T3(t3),
-            Self::T4(t4) => Or5::<T1, B, T3, T4, T5>::T4(t4),
-            Self::T5(t5) => Or5::<T1, B, T3, T4, T5>::T5(t5),
-        }
-    }
+impl<T1, T2, T3, T4, T5, T6, T7, T8> Concat<Or6<T3, T4, T5, T6, T7, T8>> for Or2<T1, T2> {
+    type Output = Or8<T1, T2, T3, T4, T5, T6, T7, T8>;
 
-    /// Transforms the T3 value of the enum using a provided function,
-    /// maintaining other types as is.
-    pub fn map_t3<F, B>(self, f: F) -> Or5<T1, T2, B, T4, T5>
-    where
-        F: FnOnce(T3) -> B,
-    {
-        match self {
-            Self::T1(t1) => Or5::<T1, T2, B, T4, T5>::T1(t1),
-            Self::T2(t2) => Or5::<T1, T2, B, T4, T5>::T2(t2),
-            Self::T3(t3) => Or5::<T1, T2, B, T4, T5>::T3(f(t3)),
-            Self::T4(t4) => Or5::<T1, T2, B, T4, T5>::T4(t4),
-            Self::T5(t5) => Or5::<T1, T2, B, T4, T5>::T5(t5),
+    fn left(lhs: Self) -> Self::Output {
+        match lhs {
+            Or2::T1(x) => Or8::T1(x),
+            Or2::T2(x) => Or8::T2(x),
         }
     }
 
-    /// Transforms the T4 value of the enum using a provided function,
-    /// maintaining other types as is.
-    pub fn map_t4<F, B>(self, f: F) -> Or5<T1, T2, T3, B, T5>
-    where
-        F: FnOnce(T4) -> B,
-    {
-        match self {
-            Self::T1(t1) => Or5::<T1, T2, T3, B, T5>::T1(t1),
-            Self::T2(t2) => Or5::<T1, T2, T3, B, T5>::T2(t2),
-            Self::T3(t3) => Or5::<T1, T2, T3, B, T5>::T3(t3),
-            Self::T4(t4) => Or5::<T1, T2, T3, B, T5>::T4(f(t4)),
-            Self::T5(t5) => Or5::<T1, T2, T3, B, T5>::T5(t5),
+    fn right(rhs: Or6<T3, T4, T5, T6, T7, T8>) -> Self::Output {
+        match rhs {
+            Or6::T1(x) => Or8::T3(x),
+            Or6::T2(x) => Or8::T4(x),
+            Or6::T3(x) => Or8::T5(x),
+            Or6::T4(x) => Or8::T6(x),
+            Or6::T5(x) => Or8::T7(x),
+            Or6::T6(x) => Or8::T8(x),
         }
     }
+}
 
-    /// Transforms the T5 value of the enum using a provided function,
-    /// maintaining other types as is.
-    pub fn map_t5<F, B>(self, f: F) -> Or5<T1, T2, T3, T4, B>
-    where
-        F: FnOnce(T5) -> B,
-    {
-        match self {
-            Self::T1(t1) => Or5::<T1, T2, T3, T4, B>::T1(t1),
-            Self::T2(t2) => Or5::<T1, T2, T3, T4, B>::T2(t2),
-            Self::T3(t3) => Or5::<T1, T2, T3, T4, B>::T3(t3),
-            Self::T4(t4) => Or5::<T1, T2, T3, T4, B>::T4(t4),
-            Self::T5(t5) => Or5::<T1, T2, T3, T4, B>::T5(f(t5)),
-        }
-    }
+impl<T1, T2, T3, T4, T5, T6, T7, T8, T9> Concat<Or7<T3, T4, T5, T6, T7, T8, T9>> for Or2<T1, T2> {
+    type Output = Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>;
 
-    /// Consolidates the `Or5` enum into a single value of type `T`,
-    /// by applying provided functions.
-    pub fn fold<T, F0, F1, F2, F3, F4, F5>(self, f1: F1, f2: F2, f3: F3, f4: F4, f5: F5) -> T
-    where
-        F1: FnOnce(T1) -> T,
-        F2: FnOnce(T2) -> T,
-        F3: FnOnce(T3) -> T,
-        F4: FnOnce(T4) -> T,
-        F5: FnOnce(T5) -> T,
-    {
-        match self {
-            Self::T1(t1) => f1(t1),
-            Self::T2(t2) => f2(t2),
-            Self::T3(t3) => f3(t3),
-            Self::T4(t4) => f4(t4),
-            Self::T5(t5) => f5(t5),
+    fn left(lhs: Self) -> Self::Output {
+        match lhs {
+            Or2::T1(x) => Or9::T1(x),
+            Or2::T2(x) => Or9::T2(x),
         }
     }
-}
 
-/// Extension to `Or5` to check if the enum's type matches a arbitrary type.
-/// Currently, these functions depend on the rustc intrinsics, and the constraints
-/// of the intrinsics require that the type must satisfy `'static'`.
-impl<T1, T2, T3, T4, T5> Or5<T1, T2, T3, T4, T5>
-where
-    T1: 'static,
-    T2: 'static,
-    T3: 'static,
-    T4: 'static,
-    T5: 'static,
-{
-    pub fn is_type<T: 'static>(&self) -> bool {
-        match self {
-            Self::T1(_) => TypeId::of::<T>() == TypeId::of::<T1>(),
-            Self::T2(_) => TypeId::of::<T>() == TypeId::of::<T2>(),
-            Self::T3(_) => TypeId::of::<T>() == TypeId::of::<T3>(),
-            Self::T4(_) => TypeId::of::<T>() == TypeId::of::<T4>(),
-            Self::T5(_) => TypeId::of::<T>() == TypeId::of::<T5>(),
+    fn right(rhs: Or7<T3, T4, T5, T6, T7, T8, T9>) -> Self::Output {
+        match rhs {
+            Or7::T1(x) => Or9::T3(x),
+            Or7::T2(x) => Or9::T4(x),
+            Or7::T3(x) => Or9::T5(x),
+            Or7::T4(x) => Or9::T6(x),
+            Or7::T5(x) => Or9::T7(x),
+            Or7::T6(x) => Or9::T8(x),
+            Or7::T7(x) => Or9::T9(x),
         }
     }
 }
 
-/// `Or6` is an enum representing a value that can be either of 6 types, T1 ... T6.
-pub enum Or6<T1, T2, T3, T4, T5, T6> {
+/// `Or3` is an enum representing a value that can be either of 3 types, T1 ... T3.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(untagged)
+)]
+#[cfg_attr(feature = "repr-c", repr(C))]
+#[cfg_attr(feature = "repr-u8", repr(u8))]
+pub enum Or3<T1, T2, T3> {
     T1(T1),
     T2(T2),
     T3(T3),
-    T4(T4),
-    T5(T5),
-    T6(T6),
 }
 
-impl<T1, T2, T3, T4, T5, T6> Or6<T1, T2, T3, T4, T5, T6> {
+impl<T1, T2, T3> Or3<T1, T2, T3> {
     /// Returns true if the enum is of type T1.
+    #[must_use]
     pub fn is_t1(&self) -> bool {
-        match self {
-            Self::T1(_) => true,
-            _ => false,
-        }
+        matches!(self, Self::T1(_))
     }
 
     /// Returns true if the enum is of type T2.
+    #[must_use]
     pub fn is_t2(&self) -> bool {
-        match self {
-            Self::T2(_) => true,
-            _ => false,
-        }
+        matches!(self, Self::T2(_))
     }
 
     /// Returns true if the enum is of type T3.
+    #[must_use]
     pub fn is_t3(&self) -> bool {
+        matches!(self, Self::T3(_))
+    }
+
+    /// Converts the enum to an Option containing the T1 value, if it is of type T1.
+    #[must_use]
+    pub fn as_t1(self) -> Option<T1> {
         match self {
-            Self::T3(_) => true,
-            _ => false,
+            Self::T1(t1) => Some(t1),
+            _ => None,
         }
     }
 
-    /// Returns true if the enum is of type T4.
-    pub fn is_t4(&self) -> bool {
+    /// Returns a reference to the T1 value, if it is of type T1, without consuming the enum.
+    #[must_use]
+    pub fn as_t1_ref(&self) -> Option<&T1> {
         match self {
-            Self::T4(_) => true,
-            _ => false,
+            Self::T1(t1) => Some(t1),
+            _ => None,
         }
     }
 
-    /// Returns true if the enum is of type T5.
-    pub fn is_t5(&self) -> bool {
+    /// Returns a mutable reference to the T1 value, if it is of type T1.
+    #[must_use]
+    pub fn as_t1_mut(&mut self) -> Option<&mut T1> {
         match self {
-            Self::T5(_) => true,
-            _ => false,
+            Self::T1(t1) => Some(t1),
+            _ => None,
         }
     }
 
-    /// Returns true if the enum is of type T6.
-    pub fn is_t6(&self) -> bool {
+    /// Converts the enum to an Option containing the T2 value, if it is of type T2.
+    #[must_use]
+    pub fn as_t2(self) -> Option<T2> {
         match self {
-            Self::T6(_) => true,
-            _ => false,
+            Self::T2(t2) => Some(t2),
+            _ => None,
         }
     }
 
-    /// Converts the enum to an Option containing the T1 value, if it is of type T1.
-    pub fn as_t1(self) -> Option<T1> {
+    /// Returns a reference to the T2 value, if it is of type T2, without consuming the enum.
+    #[must_use]
+    pub fn as_t2_ref(&self) -> Option<&T2> {
         match self {
-            Self::T1(t1) => Some(t1),
+            Self::T2(t2) => Some(t2),
             _ => None,
         }
     }
 
-    /// Converts the enum to an Option containing the T2 value, if it is of type T2.
-    pub fn as_t2(self) -> Option<T2> {
+    /// Returns a mutable reference to the T2 value, if it is of type T2.
+    #[must_use]
+    pub fn as_t2_mut(&mut self) -> Option<&mut T2> {
         match self {
             Self::T2(t2) => Some(t2),
             _ => None,
@@ -681,6 +1543,7 @@ impl<T1, T2, T3, T4, T5, T6> Or6<T1, T2, T3, T4, T5, T6> {
     }
 
     /// Converts the enum to an Option containing the T3 value, if it is of type T3.
+    #[must_use]
     pub fn as_t3(self) -> Option<T3> {
         match self {
             Self::T3(t3) => Some(t3),
@@ -688,560 +1551,688 @@ impl<T1, T2, T3, T4, T5, T6> Or6<T1, T2, T3, T4, T5, T6> {
         }
     }
 
-    /// Converts the enum to an Option containing the T4 value, if it is of type T4.
-    pub fn as_t4(self) -> Option<T4> {
+    /// Returns a reference to the T3 value, if it is of type T3, without consuming the enum.
+    #[must_use]
+    pub fn as_t3_ref(&self) -> Option<&T3> {
         match self {
-            Self::T4(t4) => Some(t4),
+            Self::T3(t3) => Some(t3),
             _ => None,
         }
     }
 
-    /// Converts the enum to an Option containing the T5 value, if it is of type T5.
-    pub fn as_t5(self) -> Option<T5> {
+    /// Returns a mutable reference to the T3 value, if it is of type T3.
+    #[must_use]
+    pub fn as_t3_mut(&mut self) -> Option<&mut T3> {
         match self {
-            Self::T5(t5) => Some(t5),
+            Self::T3(t3) => Some(t3),
             _ => None,
         }
     }
 
-    /// Converts the enum to an Option containing the T6 value, if it is of type T6.
-    pub fn as_t6(self) -> Option<T6> {
+    /// Returns the T1 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum is not of type T1, naming the variant it is of.
+    #[track_caller]
+    pub fn unwrap_t1(self) -> T1 {
         match self {
-            Self::T6(t6) => Some(t6),
-            _ => None,
+            Self::T1(t1) => t1,
+            _ => panic!(
+                "called `Or3::unwrap_t1()` on a `{:?}` value",
+                self.discriminant()
+            ),
+        }
+    }
+
+    /// Returns the T1 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the enum is not of type T1.
+    #[track_caller]
+    pub fn expect_t1(self, msg: &str) -> T1 {
+        match self {
+            Self::T1(t1) => t1,
+            _ => panic!("{}", msg),
+        }
+    }
+
+    /// Returns the T2 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum is not of type T2, naming the variant it is of.
+    #[track_caller]
+    pub fn unwrap_t2(self) -> T2 {
+        match self {
+            Self::T2(t2) => t2,
+            _ => panic!(
+                "called `Or3::unwrap_t2()` on a `{:?}` value",
+                self.discriminant()
+            ),
+        }
+    }
+
+    /// Returns the T2 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the enum is not of type T2.
+    #[track_caller]
+    pub fn expect_t2(self, msg: &str) -> T2 {
+        match self {
+            Self::T2(t2) => t2,
+            _ => panic!("{}", msg),
+        }
+    }
+
+    /// Returns the T3 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum is not of type T3, naming the variant it is of.
+    #[track_caller]
+    pub fn unwrap_t3(self) -> T3 {
+        match self {
+            Self::T3(t3) => t3,
+            _ => panic!(
+                "called `Or3::unwrap_t3()` on a `{:?}` value",
+                self.discriminant()
+            ),
+        }
+    }
+
+    /// Returns the T3 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the enum is not of type T3.
+    #[track_caller]
+    pub fn expect_t3(self, msg: &str) -> T3 {
+        match self {
+            Self::T3(t3) => t3,
+            _ => panic!("{}", msg),
+        }
+    }
+
+    /// Converts from `&Or3<T1, ...>` to `Or3<&T1, ...>`.
+    #[must_use]
+    pub fn as_ref(&self) -> Or3<&T1, &T2, &T3> {
+        match self {
+            Self::T1(t1) => Or3::T1(t1),
+            Self::T2(t2) => Or3::T2(t2),
+            Self::T3(t3) => Or3::T3(t3),
+        }
+    }
+
+    /// Converts from `&mut Or3<T1, ...>` to `Or3<&mut T1, ...>`.
+    #[must_use]
+    pub fn as_mut(&mut self) -> Or3<&mut T1, &mut T2, &mut T3> {
+        match self {
+            Self::T1(t1) => Or3::T1(t1),
+            Self::T2(t2) => Or3::T2(t2),
+            Self::T3(t3) => Or3::T3(t3),
         }
     }
 
     /// Transforms the T1 value of the enum using a provided function,
     /// maintaining other types as is.
-    pub fn map_t1<F, B>(self, f: F) -> Or6<B, T2, T3, T4, T5, T6>
+    #[must_use = "this returns the mapped enum, without modifying the original"]
+    pub fn map_t1<F, B>(self, f: F) -> Or3<B, T2, T3>
     where
         F: FnOnce(T1) -> B,
     {
         match self {
-            Self::T1(t1) => Or6::<B, T2, T3, T4, T5, T6>::T1(f(t1)),
-            Self::T2(t2) => Or6::<B, T2, T3, T4, T5, T6>::T2(t2),
-            Self::T3(t3) => Or6::<B, T2, T3, T4, T5, T6>::T3(t3),
-            Self::T4(t4) => Or6::<B, T2, T3, T4, T5, T6>::T4(t4),
-            Self::T5(t5) => Or6::<B, T2, T3, T4, T5, T6>::T5(t5),
-            Self::T6(t6) => Or6::<B, T2, T3, T4, T5, T6>::T6(t6),
+            Self::T1(t1) => Or3::<B, T2, T3>::T1(f(t1)),
+            Self::T2(t2) => Or3::<B, T2, T3>::T2(t2),
+            Self::T3(t3) => Or3::<B, T2, T3>::T3(t3),
         }
     }
 
     /// Transforms the T2 value of the enum using a provided function,
     /// maintaining other types as is.
-    pub fn map_t2<F, B>(self, f: F) -> Or6<T1, B, T3, T4, T5, T6>
+    #[must_use = "this returns the mapped enum, without modifying the original"]
+    pub fn map_t2<F, B>(self, f: F) -> Or3<T1, B, T3>
     where
         F: FnOnce(T2) -> B,
     {
         match self {
-            Self::T1(t1) => Or6::<T1, B, T3, T4, T5, T6>::T1(t1),
-            Self::T2(t2) => Or6::<T1, B, T3, T4, T5, T6>::T2(f(t2)),
-            Self::T3(t3) => Or6::<T1, B, T3, T4, T5, T6>::T3(t3),
-            Self::T4(t4) => Or6::<T1, B, T3, T4, T5, T6>::T4(t4),
-            Self::T5(t5) => Or6::<T1, B, T3, T4, T5, T6>::T5(t5),
-            Self::T6(t6) => Or6::<T1, B, T3, T4, T5, T6>::T6(t6),
+            Self::T1(t1) => Or3::<T1, B, T3>::T1(t1),
+            Self::T2(t2) => Or3::<T1, B, T3>::T2(f(t2)),
+            Self::T3(t3) => Or3::<T1, B, T3>::T3(t3),
         }
     }
 
     /// Transforms the T3 value of the enum using a provided function,
     /// maintaining other types as is.
-    pub fn map_t3<F, B>(self, f: F) -> Or6<T1, T2, B, T4, T5, T6>
+    #[must_use = "this returns the mapped enum, without modifying the original"]
+    pub fn map_t3<F, B>(self, f: F) -> Or3<T1, T2, B>
     where
         F: FnOnce(T3) -> B,
     {
         match self {
-            Self::T1(t1) => Or6::<T1, T2, B, T4, T5, T6>::T1(t1),
-            Self::T2(t2) => Or6::<T1, T2, B, T4, T5, T6>::T2(t2),
-            Self::T3(t3) => Or6::<T1, T2, B, T4, T5, T6>::T3(f(t3)),
-            Self::T4(t4) => Or6::<T1, T2, B, T4, T5, T6>::T4(t4),
-            Self::T5(t5) => Or6::<T1, T2, B, T4, T5, T6>::T5(t5),
-            Self::T6(t6) => Or6::<T1, T2, B, T4, T5, T6>::T6(t6),
+            Self::T1(t1) => Or3::<T1, T2, B>::T1(t1),
+            Self::T2(t2) => Or3::<T1, T2, B>::T2(t2),
+            Self::T3(t3) => Or3::<T1, T2, B>::T3(f(t3)),
         }
     }
 
-    /// Transforms the T4 value of the enum using a provided function,
+    /// Transforms the T1 value of the enum using a provided async function and awaits it,
     /// maintaining other types as is.
-    pub fn map_t4<F, B>(self, f: F) -> Or6<T1, T2, T3, B, T5, T6>
+    pub async fn map_t1_async<F, Fut, B>(self, f: F) -> Or3<B, T2, T3>
     where
-        F: FnOnce(T4) -> B,
+        F: FnOnce(T1) -> Fut,
+        Fut: Future<Output = B>,
     {
         match self {
-            Self::T1(t1) => Or6::<T1, T2, T3, B, T5, T6>::T1(t1),
-            Self::T2(t2) => Or6::<T1, T2, T3, B, T5, T6>::T2(t2),
-            Self::T3(t3) => Or6::<T1, T2, T3, B, T5, T6>::T3(t3),
-            Self::T4(t4) => Or6::<T1, T2, T3, B, T5, T6>::T4(f(t4)),
-            Self::T5(t5) => Or6::<T1, T2, T3, B, T5, T6>::T5(t5),
-            Self::T6(t6) => Or6::<T1, T2, T3, B, T5, T6>::T6(t6),
+            Self::T1(t1) => Or3::T1(f(t1).await),
+            Self::T2(t2) => Or3::T2(t2),
+            Self::T3(t3) => Or3::T3(t3),
         }
     }
 
-    /// Transforms the T5 value of the enum using a provided function,
+    /// Transforms the T2 value of the enum using a provided async function and awaits it,
     /// maintaining other types as is.
-    pub fn map_t5<F, B>(self, f: F) -> Or6<T1, T2, T3, T4, B, T6>
+    pub async fn map_t2_async<F, Fut, B>(self, f: F) -> Or3<T1, B, T3>
     where
-        F: FnOnce(T5) -> B,
+        F: FnOnce(T2) -> Fut,
+        Fut: Future<Output = B>,
     {
         match self {
-            Self::T1(t1) => Or6::<T1, T2, T3, T4, B, T6>::T1(t1),
-            Self::T2(t2) => Or6::<T1, T2, T3, T4, B, T6>::T2(t2),
-            Self::T3(t3) => Or6::<T1, T2, T3, T4, B, T6>::T3(t3),
-            Self::T4(t4) => Or6::<T1, T2, T3, T4, B, T6>::T4(t4),
-            Self::T5(t5) => Or6::<T1, T2, T3, T4, B, T6>::T5(f(t5)),
-            Self::T6(t6) => Or6::<T1, T2, T3, T4, B, T6>::T6(t6),
+            Self::T1(t1) => Or3::T1(t1),
+            Self::T2(t2) => Or3::T2(f(t2).await),
+            Self::T3(t3) => Or3::T3(t3),
         }
     }
 
-    /// Transforms the T6 value of the enum using a provided function,
+    /// Transforms the T3 value of the enum using a provided async function and awaits it,
     /// maintaining other types as is.
-    pub fn map_t6<F, B>(self, f: F) -> Or6<T1, T2, T3, T4, T5, B>
+    pub async fn map_t3_async<F, Fut, B>(self, f: F) -> Or3<T1, T2, B>
     where
-        F: FnOnce(T6) -> B,
+        F: FnOnce(T3) -> Fut,
+        Fut: Future<Output = B>,
     {
         match self {
-            Self::T1(t1) => Or6::<T1, T2, T3, T4, T5, B>::T1(t1),
-            Self::T2(t2) => Or6::<T1, T2, T3, T4, T5, B>::T2(t2),
-            Self::T3(t3) => Or6::<T1, T2, T3, T4, T5, B>::T3(t3),
-            Self::T4(t4) => Or6::<T1, T2, T3, T4, T5, B>::T4(t4),
-            Self::T5(t5) => Or6::<T1, T2, T3, T4, T5, B>::T5(t5),
-            Self::T6(t6) => Or6::<T1, T2, T3, T4, T5, B>::T6(f(t6)),
+            Self::T1(t1) => Or3::T1(t1),
+            Self::T2(t2) => Or3::T2(t2),
+            Self::T3(t3) => Or3::T3(f(t3).await),
         }
     }
 
-    /// Consolidates the `Or6` enum into a single value of type `T`,
+    /// Consolidates the `Or3` enum into a single value of type `T`,
     /// by applying provided functions.
-    pub fn fold<T, F0, F1, F2, F3, F4, F5, F6>(
-        self,
-        f1: F1,
-        f2: F2,
-        f3: F3,
-        f4: F4,
-        f5: F5,
-        f6: F6,
-    ) -> T
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn fold<T, F1, F2, F3>(self, f1: F1, f2: F2, f3: F3) -> T
     where
         F1: FnOnce(T1) -> T,
         F2: FnOnce(T2) -> T,
         F3: FnOnce(T3) -> T,
-        F4: FnOnce(T4) -> T,
-        F5: FnOnce(T5) -> T,
-        F6: FnOnce(T6) -> T,
     {
         match self {
             Self::T1(t1) => f1(t1),
             Self::T2(t2) => f2(t2),
             Self::T3(t3) => f3(t3),
-            Self::T4(t4) => f4(t4),
-            Self::T5(t5) => f5(t5),
-            Self::T6(t6) => f6(t6),
         }
     }
-}
 
-/// Extension to `Or6` to check if the enum's type matches a arbitrary type.
-/// Currently, these functions depend on the rustc intrinsics, and the constraints
-/// of the intrinsics require that the type must satisfy `'static'`.
-impl<T1, T2, T3, T4, T5, T6> Or6<T1, T2, T3, T4, T5, T6>
-where
-    T1: 'static,
-    T2: 'static,
-    T3: 'static,
-    T4: 'static,
-    T5: 'static,
-    T6: 'static,
-{
-    pub fn is_type<T: 'static>(&self) -> bool {
+    /// Consolidates the `Or3` enum into a single value of type `T` by applying provided
+    /// fallible functions, returning the error of the applied one, so that the functions can use `?`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_fold<T, E, F1, F2, F3>(self, f1: F1, f2: F2, f3: F3) -> Result<T, E>
+    where
+        F1: FnOnce(T1) -> Result<T, E>,
+        F2: FnOnce(T2) -> Result<T, E>,
+        F3: FnOnce(T3) -> Result<T, E>,
+    {
         match self {
-            Self::T1(_) => TypeId::of::<T>() == TypeId::of::<T1>(),
-            Self::T2(_) => TypeId::of::<T>() == TypeId::of::<T2>(),
-            Self::T3(_) => TypeId::of::<T>() == TypeId::of::<T3>(),
-            Self::T4(_) => TypeId::of::<T>() == TypeId::of::<T4>(),
-            Self::T5(_) => TypeId::of::<T>() == TypeId::of::<T5>(),
-            Self::T6(_) => TypeId::of::<T>() == TypeId::of::<T6>(),
+            Self::T1(t1) => f1(t1),
+            Self::T2(t2) => f2(t2),
+            Self::T3(t3) => f3(t3),
         }
     }
-}
-
-/// `Or7` is an enum representing a value that can be either of 7 types, T1 ... T7.
-pub enum Or7<T1, T2, T3, T4, T5, T6, T7> {
-    T1(T1),
-    T2(T2),
-    T3(T3),
-    T4(T4),
-    T5(T5),
-    T6(T6),
-    T7(T7),
-}
 
-impl<T1, T2, T3, T4, T5, T6, T7> Or7<T1, T2, T3, T4, T5, T6, T7> {
-    /// Returns true if the enum is of type T1.
-    pub fn is_t1(&self) -> bool {
+    /// Consolidates the `Or3` enum into a single value of type `T`,
+    /// by applying provided async functions and awaiting the result.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn fold_async<T, F1, Fut1, F2, Fut2, F3, Fut3>(self, f1: F1, f2: F2, f3: F3) -> T
+    where
+        F1: FnOnce(T1) -> Fut1,
+        Fut1: Future<Output = T>,
+        F2: FnOnce(T2) -> Fut2,
+        Fut2: Future<Output = T>,
+        F3: FnOnce(T3) -> Fut3,
+        Fut3: Future<Output = T>,
+    {
         match self {
-            Self::T1(_) => true,
-            _ => false,
+            Self::T1(t1) => f1(t1).await,
+            Self::T2(t2) => f2(t2).await,
+            Self::T3(t3) => f3(t3).await,
         }
     }
 
-    /// Returns true if the enum is of type T2.
-    pub fn is_t2(&self) -> bool {
+    /// Handles the T1 value of the enum using a provided function, and leaves the other
+    /// variants to the subsequent `when_tx` calls.
+    pub fn when_t1<R, F>(self, f: F) -> When<R, Or3<Infallible, T2, T3>>
+    where
+        F: FnOnce(T1) -> R,
+    {
         match self {
-            Self::T2(_) => true,
-            _ => false,
+            Self::T1(t1) => When::handled(f(t1)),
+            Self::T2(t2) => When::pending(Or3::T2(t2)),
+            Self::T3(t3) => When::pending(Or3::T3(t3)),
         }
     }
 
-    /// Returns true if the enum is of type T3.
-    pub fn is_t3(&self) -> bool {
+    /// Handles the T2 value of the enum using a provided function, and leaves the other
+    /// variants to the subsequent `when_tx` calls.
+    pub fn when_t2<R, F>(self, f: F) -> When<R, Or3<T1, Infallible, T3>>
+    where
+        F: FnOnce(T2) -> R,
+    {
         match self {
-            Self::T3(_) => true,
-            _ => false,
+            Self::T1(t1) => When::pending(Or3::T1(t1)),
+            Self::T2(t2) => When::handled(f(t2)),
+            Self::T3(t3) => When::pending(Or3::T3(t3)),
         }
     }
 
-    /// Returns true if the enum is of type T4.
-    pub fn is_t4(&self) -> bool {
+    /// Handles the T3 value of the enum using a provided function, and leaves the other
+    /// variants to the subsequent `when_tx` calls.
+    pub fn when_t3<R, F>(self, f: F) -> When<R, Or3<T1, T2, Infallible>>
+    where
+        F: FnOnce(T3) -> R,
+    {
         match self {
-            Self::T4(_) => true,
-            _ => false,
+            Self::T1(t1) => When::pending(Or3::T1(t1)),
+            Self::T2(t2) => When::pending(Or3::T2(t2)),
+            Self::T3(t3) => When::handled(f(t3)),
         }
     }
 
-    /// Returns true if the enum is of type T5.
-    pub fn is_t5(&self) -> bool {
+    /// Converts the enum into a tuple of Options, where only the slot of the active variant is `Some`.
+    #[must_use]
+    pub fn into_options(self) -> (Option<T1>, Option<T2>, Option<T3>) {
         match self {
-            Self::T5(_) => true,
-            _ => false,
+            Self::T1(t1) => (Some(t1), None, None),
+            Self::T2(t2) => (None, Some(t2), None),
+            Self::T3(t3) => (None, None, Some(t3)),
         }
     }
 
-    /// Returns true if the enum is of type T6.
-    pub fn is_t6(&self) -> bool {
-        match self {
-            Self::T6(_) => true,
-            _ => false,
-        }
+    /// Borrows the enum as a tuple of Options, where only the slot of the active variant is `Some`.
+    #[must_use]
+    pub fn as_options(&self) -> (Option<&T1>, Option<&T2>, Option<&T3>) {
+        self.as_ref().into_options()
     }
 
-    /// Returns true if the enum is of type T7.
-    pub fn is_t7(&self) -> bool {
-        match self {
-            Self::T7(_) => true,
-            _ => false,
+    /// Builds the enum from a tuple of Options, which succeeds only when exactly one of them is `Some`.
+    /// Otherwise the tuple is handed back as is.
+    pub fn try_from_options(
+        options: (Option<T1>, Option<T2>, Option<T3>),
+    ) -> Result<Self, (Option<T1>, Option<T2>, Option<T3>)> {
+        match options {
+            (Some(t1), None, None) => Ok(Self::T1(t1)),
+            (None, Some(t2), None) => Ok(Self::T2(t2)),
+            (None, None, Some(t3)) => Ok(Self::T3(t3)),
+            options => Err(options),
         }
     }
+}
 
-    /// Converts the enum to an Option containing the T1 value, if it is of type T1.
-    pub fn as_t1(self) -> Option<T1> {
+impl_forwarding!(Or3, Or3Items, Or3Discriminant {
+    T1(t1, e1, I1, i1, E1, 0, T1),
+    T2(t2, e2, I2, i2, E2, 1, T2),
+    T3(t3, e3, I3, i3, E3, 2, T3)
+});
+
+impl<T1, T2, T3> Or3<T1, T2, T3> {
+    /// The number of the variants, which is the size of the arrays returned by
+    /// [`Or3::variant_sizes`] and friends, indexed by [`Or3::variant_index`].
+    pub const VARIANT_COUNT: usize = 3;
+
+    /// Returns the 0-based position of the active variant, like `0` for `T1`.
+    ///
+    /// This can index a table of handlers or counters, with `VARIANT_COUNT` entries.
+    #[must_use]
+    pub const fn variant_index(&self) -> usize {
         match self {
-            Self::T1(t1) => Some(t1),
-            _ => None,
+            Self::T1(_) => 0,
+            Self::T2(_) => 1,
+            Self::T3(_) => 2,
         }
     }
 
-    /// Converts the enum to an Option containing the T2 value, if it is of type T2.
-    pub fn as_t2(self) -> Option<T2> {
-        match self {
-            Self::T2(t2) => Some(t2),
-            _ => None,
-        }
+    /// Returns the sizes of the payload types, in the order of the variants.
+    ///
+    /// Comparing them with the size of the enum tells whether one oversized variant bloats
+    /// the whole enum, in which case it can be shrunk by [`Or3::boxed`].
+    pub const fn variant_sizes() -> [usize; 3] {
+        [size_of::<T1>(), size_of::<T2>(), size_of::<T3>()]
     }
 
-    /// Converts the enum to an Option containing the T3 value, if it is of type T3.
-    pub fn as_t3(self) -> Option<T3> {
-        match self {
-            Self::T3(t3) => Some(t3),
-            _ => None,
-        }
+    /// Returns the alignments of the payload types, in the order of the variants.
+    pub const fn variant_aligns() -> [usize; 3] {
+        [align_of::<T1>(), align_of::<T2>(), align_of::<T3>()]
     }
 
-    /// Converts the enum to an Option containing the T4 value, if it is of type T4.
-    pub fn as_t4(self) -> Option<T4> {
-        match self {
-            Self::T4(t4) => Some(t4),
-            _ => None,
+    /// Returns the size of the largest payload type, which the size of the enum is at least.
+    pub const fn max_variant_size() -> usize {
+        let sizes = Self::variant_sizes();
+        let mut max = 0;
+        let mut i = 0;
+        while i < sizes.len() {
+            if sizes[i] > max {
+                max = sizes[i];
+            }
+            i += 1;
         }
+        max
     }
+}
 
-    /// Converts the enum to an Option containing the T5 value, if it is of type T5.
-    pub fn as_t5(self) -> Option<T5> {
+impl<T2, T3> Or3<Infallible, T2, T3> {
+    /// Removes the uninhabited `T1` position from `Or3`, which is useful to simplify
+    /// an `Or` type once some of its branches become impossible.
+    #[must_use]
+    pub fn compact_t1(self) -> Or2<T2, T3> {
         match self {
-            Self::T5(t5) => Some(t5),
-            _ => None,
+            Self::T1(never) => match never {},
+            Self::T2(t2) => Or2::T1(t2),
+            Self::T3(t3) => Or2::T2(t3),
         }
     }
+}
 
-    /// Converts the enum to an Option containing the T6 value, if it is of type T6.
-    pub fn as_t6(self) -> Option<T6> {
+impl<T1, T3> Or3<T1, Infallible, T3> {
+    /// Removes the uninhabited `T2` position from `Or3`, which is useful to simplify
+    /// an `Or` type once some of its branches become impossible.
+    #[must_use]
+    pub fn compact_t2(self) -> Or2<T1, T3> {
         match self {
-            Self::T6(t6) => Some(t6),
-            _ => None,
+            Self::T1(t1) => Or2::T1(t1),
+            Self::T2(never) => match never {},
+            Self::T3(t3) => Or2::T2(t3),
         }
     }
+}
 
-    /// Converts the enum to an Option containing the T7 value, if it is of type T7.
-    pub fn as_t7(self) -> Option<T7> {
+impl<T1, T2> Or3<T1, T2, Infallible> {
+    /// Removes the uninhabited `T3` position from `Or3`, which is useful to simplify
+    /// an `Or` type once some of its branches become impossible.
+    #[must_use]
+    pub fn compact_t3(self) -> Or2<T1, T2> {
         match self {
-            Self::T7(t7) => Some(t7),
-            _ => None,
+            Self::T1(t1) => Or2::T1(t1),
+            Self::T2(t2) => Or2::T2(t2),
+            Self::T3(never) => match never {},
         }
     }
+}
 
-    /// Transforms the T1 value of the enum using a provided function,
-    /// maintaining other types as is.
-    pub fn map_t1<F, B>(self, f: F) -> Or7<B, T2, T3, T4, T5, T6, T7>
-    where
-        F: FnOnce(T1) -> B,
-    {
+impl<T1, T2, T3> Or3<T1, T2, T3> {
+    /// Converts the enum into a `Result`, treating `T1` as the ok value and the others as the
+    /// error. The conversion is lossless, and can be reverted by `From`.
+    pub fn into_result(self) -> Result<T1, Or2<T2, T3>> {
         match self {
-            Self::T1(t1) => Or7::<B, T2, T3, T4, T5, T6, T7>::T1(f(t1)),
-            Self::T2(t2) => Or7::<B, T2, T3, T4, T5, T6, T7>::T2(t2),
-            Self::T3(t3) => Or7::<B, T2, T3, T4, T5, T6, T7>::T3(t3),
-            Self::T4(t4) => Or7::<B, T2, T3, T4, T5, T6, T7>::T4(t4),
-            Self::T5(t5) => Or7::<B, T2, T3, T4, T5, T6, T7>::T5(t5),
-            Self::T6(t6) => Or7::<B, T2, T3, T4, T5, T6, T7>::T6(t6),
-            Self::T7(t7) => Or7::<B, T2, T3, T4, T5, T6, T7>::T7(t7),
+            Self::T1(t1) => Ok(t1),
+            Self::T2(t2) => Err(Or2::T1(t2)),
+            Self::T3(t3) => Err(Or2::T2(t3)),
         }
     }
 
-    /// Transforms the T2 value of the enum using a provided function,
-    /// maintaining other types as is.
-    pub fn map_t2<F, B>(self, f: F) -> Or7<T1, B, T3, T4, T5, T6, T7>
+    /// Converts the enum into a `Result`, treating `T1` as the ok value and converting the
+    /// others into the error type `E`.
+    pub fn try_into_result<E>(self) -> Result<T1, E>
     where
-        F: FnOnce(T2) -> B,
+        T2: Into<E>,
+        T3: Into<E>,
     {
         match self {
-            Self::T1(t1) => Or7::<T1, B, T3, T4, T5, T6, T7>::T1(t1),
-            Self::T2(t2) => Or7::<T1, B, T3, T4, T5, T6, T7>::T2(f(t2)),
-            Self::T3(t3) => Or7::<T1, B, T3, T4, T5, T6, T7>::T3(t3),
-            Self::T4(t4) => Or7::<T1, B, T3, T4, T5, T6, T7>::T4(t4),
-            Self::T5(t5) => Or7::<T1, B, T3, T4, T5, T6, T7>::T5(t5),
-            Self::T6(t6) => Or7::<T1, B, T3, T4, T5, T6, T7>::T6(t6),
-            Self::T7(t7) => Or7::<T1, B, T3, T4, T5, T6, T7>::T7(t7),
+            Self::T1(t1) => Ok(t1),
+            Self::T2(t2) => Err(t2.into()),
+            Self::T3(t3) => Err(t3.into()),
         }
     }
+}
 
-    /// Transforms the T3 value of the enum using a provided function,
-    /// maintaining other types as is.
-    pub fn map_t3<F, B>(self, f: F) -> Or7<T1, T2, B, T4, T5, T6, T7>
-    where
-        F: FnOnce(T3) -> B,
-    {
-        match self {
-            Self::T1(t1) => Or7::<T1, T2, B, T4, T5, T6, T7>::T1(t1),
-            Self::T2(t2) => Or7::<T1, T2, B, T4, T5, T6, T7>::T2(t2),
-            Self::T3(t3) => Or7::<T1, T2, B, T4, T5, T6, T7>::T3(f(t3)),
-            Self::T4(t4) => Or7::<T1, T2, B, T4, T5, T6, T7>::T4(t4),
-            Self::T5(t5) => Or7::<T1, T2, B, T4, T5, T6, T7>::T5(t5),
-            Self::T6(t6) => Or7::<T1, T2, B, T4, T5, T6, T7>::T6(t6),
-            Self::T7(t7) => Or7::<T1, T2, B, T4, T5, T6, T7>::T7(t7),
+/// Converts the ok value into `T1` and the error into the remaining positions, which reverts
+/// `into_result`.
+impl<T1, T2, T3> From<Result<T1, Or2<T2, T3>>> for Or3<T1, T2, T3> {
+    fn from(result: Result<T1, Or2<T2, T3>>) -> Self {
+        match result {
+            Ok(t1) => Self::T1(t1),
+            Err(Or2::T1(t2)) => Self::T2(t2),
+            Err(Or2::T2(t3)) => Self::T3(t3),
         }
     }
+}
 
-    /// Transforms the T4 value of the enum using a provided function,
-    /// maintaining other types as is.
-    pub fn map_t4<F, B>(self, f: F) -> Or7<T1, T2, T3, B, T5, T6, T7>
-    where
-        F: FnOnce(T4) -> B,
-    {
+impl<T1, T2> Or3<T1, T2, ()> {
+    /// Converts the enum into an `Option`, which is `None` for the `()` in the last position.
+    /// The conversion is lossless, and can be reverted by `From`.
+    pub fn into_option(self) -> Option<Or2<T1, T2>> {
         match self {
-            Self::T1(t1) => Or7::<T1, T2, T3, B, T5, T6, T7>::T1(t1),
-            Self::T2(t2) => Or7::<T1, T2, T3, B, T5, T6, T7>::T2(t2),
-            Self::T3(t3) => Or7::<T1, T2, T3, B, T5, T6, T7>::T3(t3),
-            Self::T4(t4) => Or7::<T1, T2, T3, B, T5, T6, T7>::T4(f(t4)),
-            Self::T5(t5) => Or7::<T1, T2, T3, B, T5, T6, T7>::T5(t5),
-            Self::T6(t6) => Or7::<T1, T2, T3, B, T5, T6, T7>::T6(t6),
-            Self::T7(t7) => Or7::<T1, T2, T3, B, T5, T6, T7>::T7(t7),
+            Self::T1(t1) => Some(Or2::T1(t1)),
+            Self::T2(t2) => Some(Or2::T2(t2)),
+            Self::T3(()) => None,
         }
     }
+}
 
-    /// Transforms the T5 value of the enum using a provided function,
-    /// maintaining other types as is.
-    pub fn map_t5<F, B>(self, f: F) -> Or7<T1, T2, T3, T4, B, T6, T7>
-    where
-        F: FnOnce(T5) -> B,
-    {
-        match self {
-            Self::T1(t1) => Or7::<T1, T2, T3, T4, B, T6, T7>::T1(t1),
-            Self::T2(t2) => Or7::<T1, T2, T3, T4, B, T6, T7>::T2(t2),
-            Self::T3(t3) => Or7::<T1, T2, T3, T4, B, T6, T7>::T3(t3),
-            Self::T4(t4) => Or7::<T1, T2, T3, T4, B, T6, T7>::T4(t4),
-            Self::T5(t5) => Or7::<T1, T2, T3, T4, B, T6, T7>::T5(f(t5)),
-            Self::T6(t6) => Or7::<T1, T2, T3, T4, B, T6, T7>::T6(t6),
-            Self::T7(t7) => Or7::<T1, T2, T3, T4, B, T6, T7>::T7(t7),
+/// Converts `None` into the `()` in the last position, which reverts `into_option`.
+impl<T1, T2> From<Option<Or2<T1, T2>>> for Or3<T1, T2, ()> {
+    fn from(option: Option<Or2<T1, T2>>) -> Self {
+        match option {
+            Some(Or2::T1(t1)) => Self::T1(t1),
+            Some(Or2::T2(t2)) => Self::T2(t2),
+            None => Self::T3(()),
         }
     }
+}
 
-    /// Transforms the T6 value of the enum using a provided function,
-    /// maintaining other types as is.
-    pub fn map_t6<F, B>(self, f: F) -> Or7<T1, T2, T3, T4, T5, B, T7>
+impl<R, T1, T2, T3> When<R, Or3<T1, T2, T3>> {
+    /// Handles the T1 value of the pending `Or3` using a provided function,
+    /// unless a previous `when_tx` has already handled the value.
+    pub fn when_t1<F>(self, f: F) -> When<R, Or3<Infallible, T2, T3>>
     where
-        F: FnOnce(T6) -> B,
+        F: FnOnce(T1) -> R,
     {
-        match self {
-            Self::T1(t1) => Or7::<T1, T2, T3, T4, T5, B, T7>::T1(t1),
-            Self::T2(t2) => Or7::<T1, T2, T3, T4, T5, B, T7>::T2(t2),
-            Self::T3(t3) => Or7::<T1, T2, T3, T4, T5, B, T7>::T3(t3),
-            Self::T4(t4) => Or7::<T1, T2, T3, T4, T5, B, T7>::T4(t4),
-            Self::T5(t5) => Or7::<T1, T2, T3, T4, T5, B, T7>::T5(t5),
-            Self::T6(t6) => Or7::<T1, T2, T3, T4, T5, B, T7>::T6(f(t6)),
-            Self::T7(t7) => Or7::<T1, T2, T3, T4, T5, B, T7>::T7(t7),
+        match self.into_state() {
+            Ok(result) => When::handled(result),
+            Err(or) => or.when_t1(f),
         }
     }
 
-    /// Transforms the T7 value of the enum using a provided function,
-    /// maintaining other types as is.
-    pub fn map_t7<F, B>(self, f: F) -> Or7<T1, T2, T3, T4, T5, T6, B>
+    /// Handles the T2 value of the pending `Or3` using a provided function,
+    /// unless a previous `when_tx` has already handled the value.
+    pub fn when_t2<F>(self, f: F) -> When<R, Or3<T1, Infallible, T3>>
     where
-        F: FnOnce(T7) -> B,
+        F: FnOnce(T2) -> R,
     {
-        match self {
-            Self::T1(t1) => Or7::<T1, T2, T3, T4, T5, T6, B>::T1(t1),
-            Self::T2(t2) => Or7::<T1, T2, T3, T4, T5, T6, B>::T2(t2),
-            Self::T3(t3) => Or7::<T1, T2, T3, T4, T5, T6, B>::T3(t3),
-            Self::T4(t4) => Or7::<T1, T2, T3, T4, T5, T6, B>::T4(t4),
-            Self::T5(t5) => Or7::<T1, T2, T3, T4, T5, T6, B>::T5(t5),
-            Self::T6(t6) => Or7::<T1, T2, T3, T4, T5, T6, B>::T6(t6),
-            Self::T7(t7) => Or7::<T1, T2, T3, T4, T5, T6, B>::T7(f(t7)),
+        match self.into_state() {
+            Ok(result) => When::handled(result),
+            Err(or) => or.when_t2(f),
         }
     }
 
-    /// Consolidates the `Or7` enum into a single value of type `T`,
-    /// by applying provided functions.
-    pub fn fold<T, F0, F1, F2, F3, F4, F5, F6, F7>(
-        self,
-        f1: F1,
-        f2: F2,
-        f3: F3,
-        f4: F4,
-        f5: F5,
-        f6: F6,
-        f7: F7,
-    ) -> T
+    /// Handles the T3 value of the pending `Or3` using a provided function,
+    /// unless a previous `when_tx` has already handled the value.
+    pub fn when_t3<F>(self, f: F) -> When<R, Or3<T1, T2, Infallible>>
     where
-        F1: FnOnce(T1) -> T,
-        F2: FnOnce(T2) -> T,
-        F3: FnOnce(T3) -> T,
-        F4: FnOnce(T4) -> T,
-        F5: FnOnce(T5) -> T,
-        F6: FnOnce(T6) -> T,
-        F7: FnOnce(T7) -> T,
+        F: FnOnce(T3) -> R,
     {
-        match self {
-            Self::T1(t1) => f1(t1),
-            Self::T2(t2) => f2(t2),
-            Self::T3(t3) => f3(t3),
-            Self::T4(t4) => f4(t4),
-            Self::T5(t5) => f5(t5),
-            Self::T6(t6) => f6(t6),
-            Self::T7(t7) => f7(t7),
+        match self.into_state() {
+            Ok(result) => When::handled(result),
+            Err(or) => or.when_t3(f),
         }
     }
 }
 
-/// Extension to `Or7` to check if the enum's type matches a arbitrary type.
-/// Currently, these functions depend on the rustc intrinsics, and the constraints
-/// of the intrinsics require that the type must satisfy `'static'`.
-impl<T1, T2, T3, T4, T5, T6, T7> Or7<T1, T2, T3, T4, T5, T6, T7>
-where
-    T1: 'static,
-    T2: 'static,
-    T3: 'static,
-    T4: 'static,
-    T5: 'static,
-    T6: 'static,
-    T7: 'static,
-{
-    pub fn is_type<T: 'static>(&self) -> bool {
-        match self {
-            Self::T1(_) => TypeId::of::<T>() == TypeId::of::<T1>(),
-            Self::T2(_) => TypeId::of::<T>() == TypeId::of::<T2>(),
-            Self::T3(_) => TypeId::of::<T>() == TypeId::of::<T3>(),
-            Self::T4(_) => TypeId::of::<T>() == TypeId::of::<T4>(),
-            Self::T5(_) => TypeId::of::<T>() == TypeId::of::<T5>(),
-            Self::T6(_) => TypeId::of::<T>() == TypeId::of::<T6>(),
-            Self::T7(_) => TypeId::of::<T>() == TypeId::of::<T7>(),
+impl<R> When<R, Or3<Infallible, Infallible, Infallible>> {
+    /// Finishes the chain and returns the result. This is available only when every
+    /// variant of `Or3` has been handled.
+    pub fn finish(self) -> R {
+        match self.into_state() {
+            Ok(result) => result,
+            Err(or) => match or {
+                Or3::T1(never) => match never {},
+                Or3::T2(never) => match never {},
+                Or3::T3(never) => match never {},
+            },
         }
     }
 }
 
-/// `Or8` is an enum representing a value that can be either of 8 types, T1 ... T8.
-pub enum Or8<T1, T2, T3, T4, T5, T6, T7, T8> {
-    T1(T1),
-    T2(T2),
-    T3(T3),
-    T4(T4),
-    T5(T5),
-    T6(T6),
-    T7(T7),
-    T8(T8),
-}
+impl<T1, T2, T3, T4, T5> Concat<Or2<T4, T5>> for Or3<T1, T2, T3> {
+    type Output = Or5<T1, T2, T3, T4, T5>;
 
-impl<T1, T2, T3, T4, T5, T6, T7, T8> Or8<T1, T2, T3, T4, T5, T6, T7, T8> {
-    /// Returns true if the enum is of type T1.
-    pub fn is_t1(&self) -> bool {
-        match self {
-            Self::T1(_) => true,
-            _ => false,
+    fn left(lhs: Self) -> Self::Output {
+        match lhs {
+            Or3::T1(x) => Or5::T1(x),
+            Or3::T2(x) => Or5::T2(x),
+            Or3::T3(x) => Or5::T3(x),
         }
     }
 
-    /// Returns true if the enum is of type T2.
-    pub fn is_t2(&self) -> bool {
-        match self {
-            Self::T2(_) => true,
-            _ => false,
+    fn right(rhs: Or2<T4, T5>) -> Self::Output {
+        match rhs {
+            Or2::T1(x) => Or5::T4(x),
+            Or2::T2(x) => Or5::T5(x),
         }
     }
+}
 
-    /// Returns true if the enum is of type T3.
-    pub fn is_t3(&self) -> bool {
-        match self {
-            Self::T3(_) => true,
-            _ => false,
+impl<T1, T2, T3, T4, T5, T6> Concat<Or3<T4, T5, T6>> for Or3<T1, T2, T3> {
+    type Output = Or6<T1, T2, T3, T4, T5, T6>;
+
+    fn left(lhs: Self) -> Self::Output {
+        match lhs {
+            Or3::T1(x) => Or6::T1(x),
+            Or3::T2(x) => Or6::T2(x),
+            Or3::T3(x) => Or6::T3(x),
         }
     }
 
-    /// Returns true if the enum is of type T4.
-    pub fn is_t4(&self) -> bool {
-        match self {
-            Self::T4(_) => true,
-            _ => false,
+    fn right(rhs: Or3<T4, T5, T6>) -> Self::Output {
+        match rhs {
+            Or3::T1(x) => Or6::T4(x),
+            Or3::T2(x) => Or6::T5(x),
+            Or3::T3(x) => Or6::T6(x),
         }
     }
+}
 
-    /// Returns true if the enum is of type T5.
-    pub fn is_t5(&self) -> bool {
-        match self {
-            Self::T5(_) => true,
-            _ => false,
+impl<T1, T2, T3, T4, T5, T6, T7> Concat<Or4<T4, T5, T6, T7>> for Or3<T1, T2, T3> {
+    type Output = Or7<T1, T2, T3, T4, T5, T6, T7>;
+
+    fn left(lhs: Self) -> Self::Output {
+        match lhs {
+            Or3::T1(x) => Or7::T1(x),
+            Or3::T2(x) => Or7::T2(x),
+            Or3::T3(x) => Or7::T3(x),
         }
     }
 
-    /// Returns true if the enum is of type T6.
-    pub fn is_t6(&self) -> bool {
-        match self {
-            Self::T6(_) => true,
-            _ => false,
+    fn right(rhs: Or4<T4, T5, T6, T7>) -> Self::Output {
+        match rhs {
+            Or4::T1(x) => Or7::T4(x),
+            Or4::T2(x) => Or7::T5(x),
+            Or4::T3(x) => Or7::T6(x),
+            Or4::T4(x) => Or7::T7(x),
         }
     }
+}
 
-    /// Returns true if the enum is of type T7.
-    pub fn is_t7(&self) -> bool {
-        match self {
-            Self::T7(_) => true,
-            _ => false,
+impl<T1, T2, T3, T4, T5, T6, T7, T8> Concat<Or5<T4, T5, T6, T7, T8>> for Or3<T1, T2, T3> {
+    type Output = Or8<T1, T2, T3, T4, T5, T6, T7, T8>;
+
+    fn left(lhs: Self) -> Self::Output {
+        match lhs {
+            Or3::T1(x) => Or8::T1(x),
+            Or3::T2(x) => Or8::T2(x),
+            Or3::T3(x) => Or8::T3(x),
         }
     }
 
-    /// Returns true if the enum is of type T8.
-    pub fn is_t8(&self) -> bool {
-        match self {
-            Self::T8(_) => true,
-            _ => false,
+    fn right(rhs: Or5<T4, T5, T6, T7, T8>) -> Self::Output {
+        match rhs {
+            Or5::T1(x) => Or8::T4(x),
+            Or5::T2(x) => Or8::T5(x),
+            Or5::T3(x) => Or8::T6(x),
+            Or5::T4(x) => Or8::T7(x),
+            Or5::T5(x) => Or8::T8(x),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7, T8, T9> Concat<Or6<T4, T5, T6, T7, T8, T9>> for Or3<T1, T2, T3> {
+    type Output = Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>;
+
+    fn left(lhs: Self) -> Self::Output {
+        match lhs {
+            Or3::T1(x) => Or9::T1(x),
+            Or3::T2(x) => Or9::T2(x),
+            Or3::T3(x) => Or9::T3(x),
+        }
+    }
+
+    fn right(rhs: Or6<T4, T5, T6, T7, T8, T9>) -> Self::Output {
+        match rhs {
+            Or6::T1(x) => Or9::T4(x),
+            Or6::T2(x) => Or9::T5(x),
+            Or6::T3(x) => Or9::T6(x),
+            Or6::T4(x) => Or9::T7(x),
+            Or6::T5(x) => Or9::T8(x),
+            Or6::T6(x) => Or9::T9(x),
         }
     }
+}
+
+/// `Or4` is an enum representing a value that can be either of 4 types, T1 ... T4.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(untagged)
+)]
+#[cfg_attr(feature = "repr-c", repr(C))]
+#[cfg_attr(feature = "repr-u8", repr(u8))]
+pub enum Or4<T1, T2, T3, T4> {
+    T1(T1),
+    T2(T2),
+    T3(T3),
+    T4(T4),
+}
+
+impl<T1, T2, T3, T4> Or4<T1, T2, T3, T4> {
+    /// Returns true if the enum is of type T1.
+    #[must_use]
+    pub fn is_t1(&self) -> bool {
+        matches!(self, Self::T1(_))
+    }
+
+    /// Returns true if the enum is of type T2.
+    #[must_use]
+    pub fn is_t2(&self) -> bool {
+        matches!(self, Self::T2(_))
+    }
+
+    /// Returns true if the enum is of type T3.
+    #[must_use]
+    pub fn is_t3(&self) -> bool {
+        matches!(self, Self::T3(_))
+    }
+
+    /// Returns true if the enum is of type T4.
+    #[must_use]
+    pub fn is_t4(&self) -> bool {
+        matches!(self, Self::T4(_))
+    }
 
     /// Converts the enum to an Option containing the T1 value, if it is of type T1.
+    #[must_use]
     pub fn as_t1(self) -> Option<T1> {
         match self {
             Self::T1(t1) => Some(t1),
@@ -1249,7 +2240,26 @@ impl<T1, T2, T3, T4, T5, T6, T7, T8> Or8<T1, T2, T3, T4, T5, T6, T7, T8> {
         }
     }
 
+    /// Returns a reference to the T1 value, if it is of type T1, without consuming the enum.
+    #[must_use]
+    pub fn as_t1_ref(&self) -> Option<&T1> {
+        match self {
+            Self::T1(t1) => Some(t1),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the T1 value, if it is of type T1.
+    #[must_use]
+    pub fn as_t1_mut(&mut self) -> Option<&mut T1> {
+        match self {
+            Self::T1(t1) => Some(t1),
+            _ => None,
+        }
+    }
+
     /// Converts the enum to an Option containing the T2 value, if it is of type T2.
+    #[must_use]
     pub fn as_t2(self) -> Option<T2> {
         match self {
             Self::T2(t2) => Some(t2),
@@ -1257,7 +2267,26 @@ impl<T1, T2, T3, T4, T5, T6, T7, T8> Or8<T1, T2, T3, T4, T5, T6, T7, T8> {
         }
     }
 
+    /// Returns a reference to the T2 value, if it is of type T2, without consuming the enum.
+    #[must_use]
+    pub fn as_t2_ref(&self) -> Option<&T2> {
+        match self {
+            Self::T2(t2) => Some(t2),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the T2 value, if it is of type T2.
+    #[must_use]
+    pub fn as_t2_mut(&mut self) -> Option<&mut T2> {
+        match self {
+            Self::T2(t2) => Some(t2),
+            _ => None,
+        }
+    }
+
     /// Converts the enum to an Option containing the T3 value, if it is of type T3.
+    #[must_use]
     pub fn as_t3(self) -> Option<T3> {
         match self {
             Self::T3(t3) => Some(t3),
@@ -1265,7 +2294,26 @@ impl<T1, T2, T3, T4, T5, T6, T7, T8> Or8<T1, T2, T3, T4, T5, T6, T7, T8> {
         }
     }
 
+    /// Returns a reference to the T3 value, if it is of type T3, without consuming the enum.
+    #[must_use]
+    pub fn as_t3_ref(&self) -> Option<&T3> {
+        match self {
+            Self::T3(t3) => Some(t3),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the T3 value, if it is of type T3.
+    #[must_use]
+    pub fn as_t3_mut(&mut self) -> Option<&mut T3> {
+        match self {
+            Self::T3(t3) => Some(t3),
+            _ => None,
+        }
+    }
+
     /// Converts the enum to an Option containing the T4 value, if it is of type T4.
+    #[must_use]
     pub fn as_t4(self) -> Option<T4> {
         match self {
             Self::T4(t4) => Some(t4),
@@ -1273,640 +2321,8137 @@ impl<T1, T2, T3, T4, T5, T6, T7, T8> Or8<T1, T2, T3, T4, T5, T6, T7, T8> {
         }
     }
 
-    /// Converts the enum to an Option containing the T5 value, if it is of type T5.
-    pub fn as_t5(self) -> Option<T5> {
+    /// Returns a reference to the T4 value, if it is of type T4, without consuming the enum.
+    #[must_use]
+    pub fn as_t4_ref(&self) -> Option<&T4> {
         match self {
-            Self::T5(t5) => Some(t5),
+            Self::T4(t4) => Some(t4),
             _ => None,
         }
     }
 
-    /// Converts the enum to an Option containing the T6 value, if it is of type T6.
-    pub fn as_t6(self) -> Option<T6> {
+    /// Returns a mutable reference to the T4 value, if it is of type T4.
+    #[must_use]
+    pub fn as_t4_mut(&mut self) -> Option<&mut T4> {
         match self {
-            Self::T6(t6) => Some(t6),
+            Self::T4(t4) => Some(t4),
             _ => None,
         }
     }
 
-    /// Converts the enum to an Option containing the T7 value, if it is of type T7.
-    pub fn as_t7(self) -> Option<T7> {
+    /// Returns the T1 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum is not of type T1, naming the variant it is of.
+    #[track_caller]
+    pub fn unwrap_t1(self) -> T1 {
         match self {
-            Self::T7(t7) => Some(t7),
-            _ => None,
+            Self::T1(t1) => t1,
+            _ => panic!(
+                "called `Or4::unwrap_t1()` on a `{:?}` value",
+                self.discriminant()
+            ),
         }
     }
 
-    /// Converts the enum to an Option containing the T8 value, if it is of type T8.
-    pub fn as_t8(self) -> Option<T8> {
+    /// Returns the T1 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the enum is not of type T1.
+    #[track_caller]
+    pub fn expect_t1(self, msg: &str) -> T1 {
         match self {
-            Self::T8(t8) => Some(t8),
-            _ => None,
+            Self::T1(t1) => t1,
+            _ => panic!("{}", msg),
+        }
+    }
+
+    /// Returns the T2 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum is not of type T2, naming the variant it is of.
+    #[track_caller]
+    pub fn unwrap_t2(self) -> T2 {
+        match self {
+            Self::T2(t2) => t2,
+            _ => panic!(
+                "called `Or4::unwrap_t2()` on a `{:?}` value",
+                self.discriminant()
+            ),
+        }
+    }
+
+    /// Returns the T2 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the enum is not of type T2.
+    #[track_caller]
+    pub fn expect_t2(self, msg: &str) -> T2 {
+        match self {
+            Self::T2(t2) => t2,
+            _ => panic!("{}", msg),
+        }
+    }
+
+    /// Returns the T3 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum is not of type T3, naming the variant it is of.
+    #[track_caller]
+    pub fn unwrap_t3(self) -> T3 {
+        match self {
+            Self::T3(t3) => t3,
+            _ => panic!(
+                "called `Or4::unwrap_t3()` on a `{:?}` value",
+                self.discriminant()
+            ),
+        }
+    }
+
+    /// Returns the T3 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the enum is not of type T3.
+    #[track_caller]
+    pub fn expect_t3(self, msg: &str) -> T3 {
+        match self {
+            Self::T3(t3) => t3,
+            _ => panic!("{}", msg),
+        }
+    }
+
+    /// Returns the T4 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum is not of type T4, naming the variant it is of.
+    #[track_caller]
+    pub fn unwrap_t4(self) -> T4 {
+        match self {
+            Self::T4(t4) => t4,
+            _ => panic!(
+                "called `Or4::unwrap_t4()` on a `{:?}` value",
+                self.discriminant()
+            ),
+        }
+    }
+
+    /// Returns the T4 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the enum is not of type T4.
+    #[track_caller]
+    pub fn expect_t4(self, msg: &str) -> T4 {
+        match self {
+            Self::T4(t4) => t4,
+            _ => panic!("{}", msg),
+        }
+    }
+
+    /// Converts from `&Or4<T1, ...>` to `Or4<&T1, ...>`.
+    #[must_use]
+    pub fn as_ref(&self) -> Or4<&T1, &T2, &T3, &T4> {
+        match self {
+            Self::T1(t1) => Or4::T1(t1),
+            Self::T2(t2) => Or4::T2(t2),
+            Self::T3(t3) => Or4::T3(t3),
+            Self::T4(t4) => Or4::T4(t4),
+        }
+    }
+
+    /// Converts from `&mut Or4<T1, ...>` to `Or4<&mut T1, ...>`.
+    #[must_use]
+    pub fn as_mut(&mut self) -> Or4<&mut T1, &mut T2, &mut T3, &mut T4> {
+        match self {
+            Self::T1(t1) => Or4::T1(t1),
+            Self::T2(t2) => Or4::T2(t2),
+            Self::T3(t3) => Or4::T3(t3),
+            Self::T4(t4) => Or4::T4(t4),
         }
     }
 
     /// Transforms the T1 value of the enum using a provided function,
     /// maintaining other types as is.
-    pub fn map_t1<F, B>(self, f: F) -> Or8<B, T2, T3, T4, T5, T6, T7, T8>
+    #[must_use = "this returns the mapped enum, without modifying the original"]
+    pub fn map_t1<F, B>(self, f: F) -> Or4<B, T2, T3, T4>
     where
         F: FnOnce(T1) -> B,
     {
         match self {
-            Self::T1(t1) => Or8::<B, T2, T3, T4, T5, T6, T7, T8>::T1(f(t1)),
-            Self::T2(t2) => Or8::<B, T2, T3, T4, T5, T6, T7, T8>::T2(t2),
-            Self::T3(t3) => Or8::<B, T2, T3, T4, T5, T6, T7, T8>::T3(t3),
-            Self::T4(t4) => Or8::<B, T2, T3, T4, T5, T6, T7, T8>::T4(t4),
-            Self::T5(t5) => Or8::<B, T2, T3, T4, T5, T6, T7, T8>::T5(t5),
-            Self::T6(t6) => Or8::<B, T2, T3, T4, T5, T6, T7, T8>::T6(t6),
-            Self::T7(t7) => Or8::<B, T2, T3, T4, T5, T6, T7, T8>::T7(t7),
-            Self::T8(t8) => Or8::<B, T2, T3, T4, T5, T6, T7, T8>::T8(t8),
+            Self::T1(t1) => Or4::<B, T2, T3, T4>::T1(f(t1)),
+            Self::T2(t2) => Or4::<B, T2, T3, T4>::T2(t2),
+            Self::T3(t3) => Or4::<B, T2, T3, T4>::T3(t3),
+            Self::T4(t4) => Or4::<B, T2, T3, T4>::T4(t4),
         }
     }
 
     /// Transforms the T2 value of the enum using a provided function,
     /// maintaining other types as is.
-    pub fn map_t2<F, B>(self, f: F) -> Or8<T1, B, T3, T4, T5, T6, T7, T8>
+    #[must_use = "this returns the mapped enum, without modifying the original"]
+    pub fn map_t2<F, B>(self, f: F) -> Or4<T1, B, T3, T4>
     where
         F: FnOnce(T2) -> B,
     {
         match self {
-            Self::T1(t1) => Or8::<T1, B, T3, T4, T5, T6, T7, T8>::T1(t1),
-            Self::T2(t2) => Or8::<T1, B, T3, T4, T5, T6, T7, T8>::T2(f(t2)),
-            Self::T3(t3) => Or8::<T1, B, T3, T4, T5, T6, T7, T8>::T3(t3),
-            Self::T4(t4) => Or8::<T1, B, T3, T4, T5, T6, T7, T8>::T4(t4),
-            Self::T5(t5) => Or8::<T1, B, T3, T4, T5, T6, T7, T8>::T5(t5),
-            Self::T6(t6) => Or8::<T1, B, T3, T4, T5, T6, T7, T8>::T6(t6),
-            Self::T7(t7) => Or8::<T1, B, T3, T4, T5, T6, T7, T8>::T7(t7),
-            Self::T8(t8) => Or8::<T1, B, T3, T4, T5, T6, T7, T8>::T8(t8),
+            Self::T1(t1) => Or4::<T1, B, T3, T4>::T1(t1),
+            Self::T2(t2) => Or4::<T1, B, T3, T4>::T2(f(t2)),
+            Self::T3(t3) => Or4::<T1, B, T3, T4>::T3(t3),
+            Self::T4(t4) => Or4::<T1, B, T3, T4>::T4(t4),
         }
     }
 
     /// Transforms the T3 value of the enum using a provided function,
     /// maintaining other types as is.
-    pub fn map_t3<F, B>(self, f: F) -> Or8<T1, T2, B, T4, T5, T6, T7, T8>
+    #[must_use = "this returns the mapped enum, without modifying the original"]
+    pub fn map_t3<F, B>(self, f: F) -> Or4<T1, T2, B, T4>
     where
         F: FnOnce(T3) -> B,
     {
         match self {
-            Self::T1(t1) => Or8::<T1, T2, B, T4, T5, T6, T7, T8>::T1(t1),
-            Self::T2(t2) => Or8::<T1, T2, B, T4, T5, T6, T7, T8>::T2(t2),
-            Self::T3(t3) => Or8::<T1, T2, B, T4, T5, T6, T7, T8>::T3(f(t3)),
-            Self::T4(t4) => Or8::<T1, T2, B, T4, T5, T6, T7, T8>::T4(t4),
-            Self::T5(t5) => Or8::<T1, T2, B, T4, T5, T6, T7, T8>::T5(t5),
-            Self::T6(t6) => Or8::<T1, T2, B, T4, T5, T6, T7, T8>::T6(t6),
-            Self::T7(t7) => Or8::<T1, T2, B, T4, T5, T6, T7, T8>::T7(t7),
-            Self::T8(t8) => Or8::<T1, T2, B, T4, T5, T6, T7, T8>::T8(t8),
+            Self::T1(t1) => Or4::<T1, T2, B, T4>::T1(t1),
+            Self::T2(t2) => Or4::<T1, T2, B, T4>::T2(t2),
+            Self::T3(t3) => Or4::<T1, T2, B, T4>::T3(f(t3)),
+            Self::T4(t4) => Or4::<T1, T2, B, T4>::T4(t4),
         }
     }
 
     /// Transforms the T4 value of the enum using a provided function,
     /// maintaining other types as is.
-    pub fn map_t4<F, B>(self, f: F) -> Or8<T1, T2, T3, B, T5, T6, T7, T8>
+    #[must_use = "this returns the mapped enum, without modifying the original"]
+    pub fn map_t4<F, B>(self, f: F) -> Or4<T1, T2, T3, B>
     where
         F: FnOnce(T4) -> B,
     {
         match self {
-            Self::T1(t1) => Or8::<T1, T2, T3, B, T5, T6, T7, T8>::T1(t1),
-            Self::T2(t2) => Or8::<T1, T2, T3, B, T5, T6, T7, T8>::T2(t2),
-            Self::T3(t3) => Or8::<T1, T2, T3, B, T5, T6, T7, T8>::T3(t3),
-            Self::T4(t4) => Or8::<T1, T2, T3, B, T5, T6, T7, T8>::T4(f(t4)),
-            Self::T5(t5) => Or8::<T1, T2, T3, B, T5, T6, T7, T8>::T5(t5),
-            Self::T6(t6) => Or8::<T1, T2, T3, B, T5, T6, T7, T8>::T6(t6),
-            Self::T7(t7) => Or8::<T1, T2, T3, B, T5, T6, T7, T8>::T7(t7),
-            Self::T8(t8) => Or8::<T1, T2, T3, B, T5, T6, T7, T8>::T8(t8),
+            Self::T1(t1) => Or4::<T1, T2, T3, B>::T1(t1),
+            Self::T2(t2) => Or4::<T1, T2, T3, B>::T2(t2),
+            Self::T3(t3) => Or4::<T1, T2, T3, B>::T3(t3),
+            Self::T4(t4) => Or4::<T1, T2, T3, B>::T4(f(t4)),
         }
     }
 
-    /// Transforms the T5 value of the enum using a provided function,
+    /// Transforms the T1 value of the enum using a provided async function and awaits it,
     /// maintaining other types as is.
-    pub fn map_t5<F, B>(self, f: F) -> Or8<T1, T2, T3, T4, B, T6, T7, T8>
+    pub async fn map_t1_async<F, Fut, B>(self, f: F) -> Or4<B, T2, T3, T4>
     where
-        F: FnOnce(T5) -> B,
+        F: FnOnce(T1) -> Fut,
+        Fut: Future<Output = B>,
     {
         match self {
-            Self::T1(t1) => Or8::<T1, T2, T3, T4, B, T6, T7, T8>::T1(t1),
-            Self::T2(t2) => Or8::<T1, T2, T3, T4, B, T6, T7, T8>::T2(t2),
-            Self::T3(t3) => Or8::<T1, T2, T3, T4, B, T6, T7, T8>::T3(t3),
-            Self::T4(t4) => Or8::<T1, T2, T3, T4, B, T6, T7, T8>::T4(t4),
-            Self::T5(t5) => Or8::<T1, T2, T3, T4, B, T6, T7, T8>::T5(f(t5)),
-            Self::T6(t6) => Or8::<T1, T2, T3, T4, B, T6, T7, T8>::T6(t6),
-            Self::T7(t7) => Or8::<T1, T2, T3, T4, B, T6, T7, T8>::T7(t7),
-            Self::T8(t8) => Or8::<T1, T2, T3, T4, B, T6, T7, T8>::T8(t8),
+            Self::T1(t1) => Or4::T1(f(t1).await),
+            Self::T2(t2) => Or4::T2(t2),
+            Self::T3(t3) => Or4::T3(t3),
+            Self::T4(t4) => Or4::T4(t4),
         }
     }
 
-    /// Transforms the T6 value of the enum using a provided function,
+    /// Transforms the T2 value of the enum using a provided async function and awaits it,
     /// maintaining other types as is.
-    pub fn map_t6<F, B>(self, f: F) -> Or8<T1, T2, T3, T4, T5, B, T7, T8>
+    pub async fn map_t2_async<F, Fut, B>(self, f: F) -> Or4<T1, B, T3, T4>
     where
-        F: FnOnce(T6) -> B,
+        F: FnOnce(T2) -> Fut,
+        Fut: Future<Output = B>,
     {
         match self {
-            Self::T1(t1) => Or8::<T1, T2, T3, T4, T5, B, T7, T8>::T1(t1),
-            Self::T2(t2) => Or8::<T1, T2, T3, T4, T5, B, T7, T8>::T2(t2),
-            Self::T3(t3) => Or8::<T1, T2, T3, T4, T5, B, T7, T8>::T3(t3),
-            Self::T4(t4) => Or8::<T1, T2, T3, T4, T5, B, T7, T8>::T4(t4),
-            Self::T5(t5) => Or8::<T1, T2, T3, T4, T5, B, T7, T8>::T5(t5),
-            Self::T6(t6) => Or8::<T1, T2, T3, T4, T5, B, T7, T8>::T6(f(t6)),
-            Self::T7(t7) => Or8::<T1, T2, T3, T4, T5, B, T7, T8>::T7(t7),
-            Self::T8(t8) => Or8::<T1, T2, T3, T4, T5, B, T7, T8>::T8(t8),
+            Self::T1(t1) => Or4::T1(t1),
+            Self::T2(t2) => Or4::T2(f(t2).await),
+            Self::T3(t3) => Or4::T3(t3),
+            Self::T4(t4) => Or4::T4(t4),
         }
     }
 
-    /// Transforms the T7 value of the enum using a provided function,
+    /// Transforms the T3 value of the enum using a provided async function and awaits it,
     /// maintaining other types as is.
-    pub fn map_t7<F, B>(self, f: F) -> Or8<T1, T2, T3, T4, T5, T6, B, T8>
+    pub async fn map_t3_async<F, Fut, B>(self, f: F) -> Or4<T1, T2, B, T4>
     where
-        F: FnOnce(T7) -> B,
+        F: FnOnce(T3) -> Fut,
+        Fut: Future<Output = B>,
     {
         match self {
-            Self::T1(t1) => Or8::<T1, T2, T3, T4, T5, T6, B, T8>::T1(t1),
-            Self::T2(t2) => Or8::<T1, T2, T3, T4, T5, T6, B, T8>::T2(t2),
-            Self::T3(t3) => Or8::<T1, T2, T3, T4, T5, T6, B, T8>::T3(t3),
-            Self::T4(t4) => Or8::<T1, T2, T3, T4, T5, T6, B, T8>::T4(t4),
-            Self::T5(t5) => Or8::<T1, T2, T3, T4, T5, T6, B, T8>::T5(t5),
-            Self::T6(t6) => Or8::<T1, T2, T3, T4, T5, T6, B, T8>::T6(t6),
-            Self::T7(t7) => Or8::<T1, T2, T3, T4, T5, T6, B, T8>::T7(f(t7)),
-            Self::T8(t8) => Or8::<T1, T2, T3, T4, T5, T6, B, T8>::T8(t8),
+            Self::T1(t1) => Or4::T1(t1),
+            Self::T2(t2) => Or4::T2(t2),
+            Self::T3(t3) => Or4::T3(f(t3).await),
+            Self::T4(t4) => Or4::T4(t4),
         }
     }
 
-    /// Transforms the T8 value of the enum using a provided function,
+    /// Transforms the T4 value of the enum using a provided async function and awaits it,
     /// maintaining other types as is.
-    pub fn map_t8<F, B>(self, f: F) -> Or8<T1, T2, T3, T4, T5, T6, T7, B>
+    pub async fn map_t4_async<F, Fut, B>(self, f: F) -> Or4<T1, T2, T3, B>
     where
-        F: FnOnce(T8) -> B,
+        F: FnOnce(T4) -> Fut,
+        Fut: Future<Output = B>,
     {
         match self {
-            Self::T1(t1) => Or8::<T1, T2, T3, T4, T5, T6, T7, B>::T1(t1),
-            Self::T2(t2) => Or8::<T1, T2, T3, T4, T5, T6, T7, B>::T2(t2),
-            Self::T3(t3) => Or8::<T1, T2, T3, T4, T5, T6, T7, B>::T3(t3),
-            Self::T4(t4) => Or8::<T1, T2, T3, T4, T5, T6, T7, B>::T4(t4),
-            Self::T5(t5) => Or8::<T1, T2, T3, T4, T5, T6, T7, B>::T5(t5),
-            Self::T6(t6) => Or8::<T1, T2, T3, T4, T5, T6, T7, B>::T6(t6),
-            Self::T7(t7) => Or8::<T1, T2, T3, T4, T5, T6, T7, B>::T7(t7),
-            Self::T8(t8) => Or8::<T1, T2, T3, T4, T5, T6, T7, B>::T8(f(t8)),
+            Self::T1(t1) => Or4::T1(t1),
+            Self::T2(t2) => Or4::T2(t2),
+            Self::T3(t3) => Or4::T3(t3),
+            Self::T4(t4) => Or4::T4(f(t4).await),
         }
     }
 
-    /// Consolidates the `Or8` enum into a single value of type `T`,
+    /// Consolidates the `Or4` enum into a single value of type `T`,
     /// by applying provided functions.
-    pub fn fold<T, F0, F1, F2, F3, F4, F5, F6, F7, F8>(
-        self,
-        f1: F1,
-        f2: F2,
-        f3: F3,
-        f4: F4,
-        f5: F5,
-        f6: F6,
-        f7: F7,
-        f8: F8,
-    ) -> T
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn fold<T, F1, F2, F3, F4>(self, f1: F1, f2: F2, f3: F3, f4: F4) -> T
     where
         F1: FnOnce(T1) -> T,
         F2: FnOnce(T2) -> T,
         F3: FnOnce(T3) -> T,
         F4: FnOnce(T4) -> T,
-        F5: FnOnce(T5) -> T,
-        F6: FnOnce(T6) -> T,
-        F7: FnOnce(T7) -> T,
-        F8: FnOnce(T8) -> T,
     {
         match self {
             Self::T1(t1) => f1(t1),
             Self::T2(t2) => f2(t2),
             Self::T3(t3) => f3(t3),
             Self::T4(t4) => f4(t4),
-            Self::T5(t5) => f5(t5),
-            Self::T6(t6) => f6(t6),
-            Self::T7(t7) => f7(t7),
-            Self::T8(t8) => f8(t8),
         }
     }
-}
 
-/// Extension to `Or8` to check if the enum's type matches a arbitrary type.
-/// Currently, these functions depend on the rustc intrinsics, and the constraints
-/// of the intrinsics require that the type must satisfy `'static'`.
-impl<T1, T2, T3, T4, T5, T6, T7, T8> Or8<T1, T2, T3, T4, T5, T6, T7, T8>
-where
-    T1: 'static,
-    T2: 'static,
-    T3: 'static,
-    T4: 'static,
-    T5: 'static,
-    T6: 'static,
-    T7: 'static,
-    T8: 'static,
-{
-    pub fn is_type<T: 'static>(&self) -> bool {
+    /// Consolidates the `Or4` enum into a single value of type `T` by applying provided
+    /// fallible functions, returning the error of the applied one, so that the functions can use `?`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_fold<T, E, F1, F2, F3, F4>(self, f1: F1, f2: F2, f3: F3, f4: F4) -> Result<T, E>
+    where
+        F1: FnOnce(T1) -> Result<T, E>,
+        F2: FnOnce(T2) -> Result<T, E>,
+        F3: FnOnce(T3) -> Result<T, E>,
+        F4: FnOnce(T4) -> Result<T, E>,
+    {
         match self {
-            Self::T1(_) => TypeId::of::<T>() == TypeId::of::<T1>(),
-            Self::T2(_) => TypeId::of::<T>() == TypeId::of::<T2>(),
-            Self::T3(_) => TypeId::of::<T>() == TypeId::of::<T3>(),
-            Self::T4(_) => TypeId::of::<T>() == TypeId::of::<T4>(),
-            Self::T5(_) => TypeId::of::<T>() == TypeId::of::<T5>(),
-            Self::T6(_) => TypeId::of::<T>() == TypeId::of::<T6>(),
-            Self::T7(_) => TypeId::of::<T>() == TypeId::of::<T7>(),
-            Self::T8(_) => TypeId::of::<T>() == TypeId::of::<T8>(),
+            Self::T1(t1) => f1(t1),
+            Self::T2(t2) => f2(t2),
+            Self::T3(t3) => f3(t3),
+            Self::T4(t4) => f4(t4),
         }
     }
-}
 
-/// `Or9` is an enum representing a value that can be either of 9 types, T1 ... T9.
-pub enum Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9> {
+    /// Consolidates the `Or4` enum into a single value of type `T`,
+    /// by applying provided async functions and awaiting the result.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn fold_async<T, F1, Fut1, F2, Fut2, F3, Fut3, F4, Fut4>(
+        self,
+        f1: F1,
+        f2: F2,
+        f3: F3,
+        f4: F4,
+    ) -> T
+    where
+        F1: FnOnce(T1) -> Fut1,
+        Fut1: Future<Output = T>,
+        F2: FnOnce(T2) -> Fut2,
+        Fut2: Future<Output = T>,
+        F3: FnOnce(T3) -> Fut3,
+        Fut3: Future<Output = T>,
+        F4: FnOnce(T4) -> Fut4,
+        Fut4: Future<Output = T>,
+    {
+        match self {
+            Self::T1(t1) => f1(t1).await,
+            Self::T2(t2) => f2(t2).await,
+            Self::T3(t3) => f3(t3).await,
+            Self::T4(t4) => f4(t4).await,
+        }
+    }
+
+    /// Handles the T1 value of the enum using a provided function, and leaves the other
+    /// variants to the subsequent `when_tx` calls.
+    pub fn when_t1<R, F>(self, f: F) -> When<R, Or4<Infallible, T2, T3, T4>>
+    where
+        F: FnOnce(T1) -> R,
+    {
+        match self {
+            Self::T1(t1) => When::handled(f(t1)),
+            Self::T2(t2) => When::pending(Or4::T2(t2)),
+            Self::T3(t3) => When::pending(Or4::T3(t3)),
+            Self::T4(t4) => When::pending(Or4::T4(t4)),
+        }
+    }
+
+    /// Handles the T2 value of the enum using a provided function, and leaves the other
+    /// variants to the subsequent `when_tx` calls.
+    pub fn when_t2<R, F>(self, f: F) -> When<R, Or4<T1, Infallible, T3, T4>>
+    where
+        F: FnOnce(T2) -> R,
+    {
+        match self {
+            Self::T1(t1) => When::pending(Or4::T1(t1)),
+            Self::T2(t2) => When::handled(f(t2)),
+            Self::T3(t3) => When::pending(Or4::T3(t3)),
+            Self::T4(t4) => When::pending(Or4::T4(t4)),
+        }
+    }
+
+    /// Handles the T3 value of the enum using a provided function, and leaves the other
+    /// variants to the subsequent `when_tx` calls.
+    pub fn when_t3<R, F>(self, f: F) -> When<R, Or4<T1, T2, Infallible, T4>>
+    where
+        F: FnOnce(T3) -> R,
+    {
+        match self {
+            Self::T1(t1) => When::pending(Or4::T1(t1)),
+            Self::T2(t2) => When::pending(Or4::T2(t2)),
+            Self::T3(t3) => When::handled(f(t3)),
+            Self::T4(t4) => When::pending(Or4::T4(t4)),
+        }
+    }
+
+    /// Handles the T4 value of the enum using a provided function, and leaves the other
+    /// variants to the subsequent `when_tx` calls.
+    pub fn when_t4<R, F>(self, f: F) -> When<R, Or4<T1, T2, T3, Infallible>>
+    where
+        F: FnOnce(T4) -> R,
+    {
+        match self {
+            Self::T1(t1) => When::pending(Or4::T1(t1)),
+            Self::T2(t2) => When::pending(Or4::T2(t2)),
+            Self::T3(t3) => When::pending(Or4::T3(t3)),
+            Self::T4(t4) => When::handled(f(t4)),
+        }
+    }
+
+    /// Converts the enum into a tuple of Options, where only the slot of the active variant is `Some`.
+    #[must_use]
+    pub fn into_options(self) -> (Option<T1>, Option<T2>, Option<T3>, Option<T4>) {
+        match self {
+            Self::T1(t1) => (Some(t1), None, None, None),
+            Self::T2(t2) => (None, Some(t2), None, None),
+            Self::T3(t3) => (None, None, Some(t3), None),
+            Self::T4(t4) => (None, None, None, Some(t4)),
+        }
+    }
+
+    /// Borrows the enum as a tuple of Options, where only the slot of the active variant is `Some`.
+    #[must_use]
+    pub fn as_options(&self) -> (Option<&T1>, Option<&T2>, Option<&T3>, Option<&T4>) {
+        self.as_ref().into_options()
+    }
+
+    /// Builds the enum from a tuple of Options, which succeeds only when exactly one of them is `Some`.
+    /// Otherwise the tuple is handed back as is.
+    pub fn try_from_options(
+        options: (Option<T1>, Option<T2>, Option<T3>, Option<T4>),
+    ) -> Result<Self, (Option<T1>, Option<T2>, Option<T3>, Option<T4>)> {
+        match options {
+            (Some(t1), None, None, None) => Ok(Self::T1(t1)),
+            (None, Some(t2), None, None) => Ok(Self::T2(t2)),
+            (None, None, Some(t3), None) => Ok(Self::T3(t3)),
+            (None, None, None, Some(t4)) => Ok(Self::T4(t4)),
+            options => Err(options),
+        }
+    }
+}
+
+impl_forwarding!(Or4, Or4Items, Or4Discriminant {
+    T1(t1, e1, I1, i1, E1, 0, T1),
+    T2(t2, e2, I2, i2, E2, 1, T2),
+    T3(t3, e3, I3, i3, E3, 2, T3),
+    T4(t4, e4, I4, i4, E4, 3, T4)
+});
+
+impl<T1, T2, T3, T4> Or4<T1, T2, T3, T4> {
+    /// The number of the variants, which is the size of the arrays returned by
+    /// [`Or4::variant_sizes`] and friends, indexed by [`Or4::variant_index`].
+    pub const VARIANT_COUNT: usize = 4;
+
+    /// Returns the 0-based position of the active variant, like `0` for `T1`.
+    ///
+    /// This can index a table of handlers or counters, with `VARIANT_COUNT` entries.
+    #[must_use]
+    pub const fn variant_index(&self) -> usize {
+        match self {
+            Self::T1(_) => 0,
+            Self::T2(_) => 1,
+            Self::T3(_) => 2,
+            Self::T4(_) => 3,
+        }
+    }
+
+    /// Returns the sizes of the payload types, in the order of the variants.
+    ///
+    /// Comparing them with the size of the enum tells whether one oversized variant bloats
+    /// the whole enum, in which case it can be shrunk by [`Or4::boxed`].
+    pub const fn variant_sizes() -> [usize; 4] {
+        [
+            size_of::<T1>(),
+            size_of::<T2>(),
+            size_of::<T3>(),
+            size_of::<T4>(),
+        ]
+    }
+
+    /// Returns the alignments of the payload types, in the order of the variants.
+    pub const fn variant_aligns() -> [usize; 4] {
+        [
+            align_of::<T1>(),
+            align_of::<T2>(),
+            align_of::<T3>(),
+            align_of::<T4>(),
+        ]
+    }
+
+    /// Returns the size of the largest payload type, which the size of the enum is at least.
+    pub const fn max_variant_size() -> usize {
+        let sizes = Self::variant_sizes();
+        let mut max = 0;
+        let mut i = 0;
+        while i < sizes.len() {
+            if sizes[i] > max {
+                max = sizes[i];
+            }
+            i += 1;
+        }
+        max
+    }
+}
+
+impl<T2, T3, T4> Or4<Infallible, T2, T3, T4> {
+    /// Removes the uninhabited `T1` position from `Or4`, which is useful to simplify
+    /// an `Or` type once some of its branches become impossible.
+    #[must_use]
+    pub fn compact_t1(self) -> Or3<T2, T3, T4> {
+        match self {
+            Self::T1(never) => match never {},
+            Self::T2(t2) => Or3::T1(t2),
+            Self::T3(t3) => Or3::T2(t3),
+            Self::T4(t4) => Or3::T3(t4),
+        }
+    }
+}
+
+impl<T1, T3, T4> Or4<T1, Infallible, T3, T4> {
+    /// Removes the uninhabited `T2` position from `Or4`, which is useful to simplify
+    /// an `Or` type once some of its branches become impossible.
+    #[must_use]
+    pub fn compact_t2(self) -> Or3<T1, T3, T4> {
+        match self {
+            Self::T1(t1) => Or3::T1(t1),
+            Self::T2(never) => match never {},
+            Self::T3(t3) => Or3::T2(t3),
+            Self::T4(t4) => Or3::T3(t4),
+        }
+    }
+}
+
+impl<T1, T2, T4> Or4<T1, T2, Infallible, T4> {
+    /// Removes the uninhabited `T3` position from `Or4`, which is useful to simplify
+    /// an `Or` type once some of its branches become impossible.
+    #[must_use]
+    pub fn compact_t3(self) -> Or3<T1, T2, T4> {
+        match self {
+            Self::T1(t1) => Or3::T1(t1),
+            Self::T2(t2) => Or3::T2(t2),
+            Self::T3(never) => match never {},
+            Self::T4(t4) => Or3::T3(t4),
+        }
+    }
+}
+
+impl<T1, T2, T3> Or4<T1, T2, T3, Infallible> {
+    /// Removes the uninhabited `T4` position from `Or4`, which is useful to simplify
+    /// an `Or` type once some of its branches become impossible.
+    #[must_use]
+    pub fn compact_t4(self) -> Or3<T1, T2, T3> {
+        match self {
+            Self::T1(t1) => Or3::T1(t1),
+            Self::T2(t2) => Or3::T2(t2),
+            Self::T3(t3) => Or3::T3(t3),
+            Self::T4(never) => match never {},
+        }
+    }
+}
+
+impl<T1, T2, T3, T4> Or4<T1, T2, T3, T4> {
+    /// Converts the enum into a `Result`, treating `T1` as the ok value and the others as the
+    /// error. The conversion is lossless, and can be reverted by `From`.
+    pub fn into_result(self) -> Result<T1, Or3<T2, T3, T4>> {
+        match self {
+            Self::T1(t1) => Ok(t1),
+            Self::T2(t2) => Err(Or3::T1(t2)),
+            Self::T3(t3) => Err(Or3::T2(t3)),
+            Self::T4(t4) => Err(Or3::T3(t4)),
+        }
+    }
+
+    /// Converts the enum into a `Result`, treating `T1` as the ok value and converting the
+    /// others into the error type `E`.
+    pub fn try_into_result<E>(self) -> Result<T1, E>
+    where
+        T2: Into<E>,
+        T3: Into<E>,
+        T4: Into<E>,
+    {
+        match self {
+            Self::T1(t1) => Ok(t1),
+            Self::T2(t2) => Err(t2.into()),
+            Self::T3(t3) => Err(t3.into()),
+            Self::T4(t4) => Err(t4.into()),
+        }
+    }
+}
+
+/// Converts the ok value into `T1` and the error into the remaining positions, which reverts
+/// `into_result`.
+impl<T1, T2, T3, T4> From<Result<T1, Or3<T2, T3, T4>>> for Or4<T1, T2, T3, T4> {
+    fn from(result: Result<T1, Or3<T2, T3, T4>>) -> Self {
+        match result {
+            Ok(t1) => Self::T1(t1),
+            Err(Or3::T1(t2)) => Self::T2(t2),
+            Err(Or3::T2(t3)) => Self::T3(t3),
+            Err(Or3::T3(t4)) => Self::T4(t4),
+        }
+    }
+}
+
+impl<T1, T2, T3> Or4<T1, T2, T3, ()> {
+    /// Converts the enum into an `Option`, which is `None` for the `()` in the last position.
+    /// The conversion is lossless, and can be reverted by `From`.
+    pub fn into_option(self) -> Option<Or3<T1, T2, T3>> {
+        match self {
+            Self::T1(t1) => Some(Or3::T1(t1)),
+            Self::T2(t2) => Some(Or3::T2(t2)),
+            Self::T3(t3) => Some(Or3::T3(t3)),
+            Self::T4(()) => None,
+        }
+    }
+}
+
+/// Converts `None` into the `()` in the last position, which reverts `into_option`.
+impl<T1, T2, T3> From<Option<Or3<T1, T2, T3>>> for Or4<T1, T2, T3, ()> {
+    fn from(option: Option<Or3<T1, T2, T3>>) -> Self {
+        match option {
+            Some(Or3::T1(t1)) => Self::T1(t1),
+            Some(Or3::T2(t2)) => Self::T2(t2),
+            Some(Or3::T3(t3)) => Self::T3(t3),
+            None => Self::T4(()),
+        }
+    }
+}
+
+impl<R, T1, T2, T3, T4> When<R, Or4<T1, T2, T3, T4>> {
+    /// Handles the T1 value of the pending `Or4` using a provided function,
+    /// unless a previous `when_tx` has already handled the value.
+    pub fn when_t1<F>(self, f: F) -> When<R, Or4<Infallible, T2, T3, T4>>
+    where
+        F: FnOnce(T1) -> R,
+    {
+        match self.into_state() {
+            Ok(result) => When::handled(result),
+            Err(or) => or.when_t1(f),
+        }
+    }
+
+    /// Handles the T2 value of the pending `Or4` using a provided function,
+    /// unless a previous `when_tx` has already handled the value.
+    pub fn when_t2<F>(self, f: F) -> When<R, Or4<T1, Infallible, T3, T4>>
+    where
+        F: FnOnce(T2) -> R,
+    {
+        match self.into_state() {
+            Ok(result) => When::handled(result),
+            Err(or) => or.when_t2(f),
+        }
+    }
+
+    /// Handles the T3 value of the pending `Or4` using a provided function,
+    /// unless a previous `when_tx` has already handled the value.
+    pub fn when_t3<F>(self, f: F) -> When<R, Or4<T1, T2, Infallible, T4>>
+    where
+        F: FnOnce(T3) -> R,
+    {
+        match self.into_state() {
+            Ok(result) => When::handled(result),
+            Err(or) => or.when_t3(f),
+        }
+    }
+
+    /// Handles the T4 value of the pending `Or4` using a provided function,
+    /// unless a previous `when_tx` has already handled the value.
+    pub fn when_t4<F>(self, f: F) -> When<R, Or4<T1, T2, T3, Infallible>>
+    where
+        F: FnOnce(T4) -> R,
+    {
+        match self.into_state() {
+            Ok(result) => When::handled(result),
+            Err(or) => or.when_t4(f),
+        }
+    }
+}
+
+impl<R> When<R, Or4<Infallible, Infallible, Infallible, Infallible>> {
+    /// Finishes the chain and returns the result. This is available only when every
+    /// variant of `Or4` has been handled.
+    pub fn finish(self) -> R {
+        match self.into_state() {
+            Ok(result) => result,
+            Err(or) => match or {
+                Or4::T1(never) => match never {},
+                Or4::T2(never) => match never {},
+                Or4::T3(never) => match never {},
+                Or4::T4(never) => match never {},
+            },
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6> Concat<Or2<T5, T6>> for Or4<T1, T2, T3, T4> {
+    type Output = Or6<T1, T2, T3, T4, T5, T6>;
+
+    fn left(lhs: Self) -> Self::Output {
+        match lhs {
+            Or4::T1(x) => Or6::T1(x),
+            Or4::T2(x) => Or6::T2(x),
+            Or4::T3(x) => Or6::T3(x),
+            Or4::T4(x) => Or6::T4(x),
+        }
+    }
+
+    fn right(rhs: Or2<T5, T6>) -> Self::Output {
+        match rhs {
+            Or2::T1(x) => Or6::T5(x),
+            Or2::T2(x) => Or6::T6(x),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7> Concat<Or3<T5, T6, T7>> for Or4<T1, T2, T3, T4> {
+    type Output = Or7<T1, T2, T3, T4, T5, T6, T7>;
+
+    fn left(lhs: Self) -> Self::Output {
+        match lhs {
+            Or4::T1(x) => Or7::T1(x),
+            Or4::T2(x) => Or7::T2(x),
+            Or4::T3(x) => Or7::T3(x),
+            Or4::T4(x) => Or7::T4(x),
+        }
+    }
+
+    fn right(rhs: Or3<T5, T6, T7>) -> Self::Output {
+        match rhs {
+            Or3::T1(x) => Or7::T5(x),
+            Or3::T2(x) => Or7::T6(x),
+            Or3::T3(x) => Or7::T7(x),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7, T8> Concat<Or4<T5, T6, T7, T8>> for Or4<T1, T2, T3, T4> {
+    type Output = Or8<T1, T2, T3, T4, T5, T6, T7, T8>;
+
+    fn left(lhs: Self) -> Self::Output {
+        match lhs {
+            Or4::T1(x) => Or8::T1(x),
+            Or4::T2(x) => Or8::T2(x),
+            Or4::T3(x) => Or8::T3(x),
+            Or4::T4(x) => Or8::T4(x),
+        }
+    }
+
+    fn right(rhs: Or4<T5, T6, T7, T8>) -> Self::Output {
+        match rhs {
+            Or4::T1(x) => Or8::T5(x),
+            Or4::T2(x) => Or8::T6(x),
+            Or4::T3(x) => Or8::T7(x),
+            Or4::T4(x) => Or8::T8(x),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7, T8, T9> Concat<Or5<T5, T6, T7, T8, T9>> for Or4<T1, T2, T3, T4> {
+    type Output = Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>;
+
+    fn left(lhs: Self) -> Self::Output {
+        match lhs {
+            Or4::T1(x) => Or9::T1(x),
+            Or4::T2(x) => Or9::T2(x),
+            Or4::T3(x) => Or9::T3(x),
+            Or4::T4(x) => Or9::T4(x),
+        }
+    }
+
+    fn right(rhs: Or5<T5, T6, T7, T8, T9>) -> Self::Output {
+        match rhs {
+            Or5::T1(x) => Or9::T5(x),
+            Or5::T2(x) => Or9::T6(x),
+            Or5::T3(x) => Or9::T7(x),
+            Or5::T4(x) => Or9::T8(x),
+            Or5::T5(x) => Or9::T9(x),
+        }
+    }
+}
+
+/// `Or5` is an enum representing a value that can be either of 5 types, T1 ... T5.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(untagged)
+)]
+#[cfg_attr(feature = "repr-c", repr(C))]
+#[cfg_attr(feature = "repr-u8", repr(u8))]
+pub enum Or5<T1, T2, T3, T4, T5> {
     T1(T1),
     T2(T2),
     T3(T3),
     T4(T4),
     T5(T5),
-    T6(T6),
-    T7(T7),
-    T8(T8),
-    T9(T9),
 }
 
-impl<T1, T2, T3, T4, T5, T6, T7, T8, T9> Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9> {
+impl<T1, T2, T3, T4, T5> Or5<T1, T2, T3, T4, T5> {
     /// Returns true if the enum is of type T1.
+    #[must_use]
     pub fn is_t1(&self) -> bool {
+        matches!(self, Self::T1(_))
+    }
+
+    /// Returns true if the enum is of type T2.
+    #[must_use]
+    pub fn is_t2(&self) -> bool {
+        matches!(self, Self::T2(_))
+    }
+
+    /// Returns true if the enum is of type T3.
+    #[must_use]
+    pub fn is_t3(&self) -> bool {
+        matches!(self, Self::T3(_))
+    }
+
+    /// Returns true if the enum is of type T4.
+    #[must_use]
+    pub fn is_t4(&self) -> bool {
+        matches!(self, Self::T4(_))
+    }
+
+    /// Returns true if the enum is of type T5.
+    #[must_use]
+    pub fn is_t5(&self) -> bool {
+        matches!(self, Self::T5(_))
+    }
+
+    /// Converts the enum to an Option containing the T1 value, if it is of type T1.
+    #[must_use]
+    pub fn as_t1(self) -> Option<T1> {
+        match self {
+            Self::T1(t1) => Some(t1),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the T1 value, if it is of type T1, without consuming the enum.
+    #[must_use]
+    pub fn as_t1_ref(&self) -> Option<&T1> {
+        match self {
+            Self::T1(t1) => Some(t1),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the T1 value, if it is of type T1.
+    #[must_use]
+    pub fn as_t1_mut(&mut self) -> Option<&mut T1> {
+        match self {
+            Self::T1(t1) => Some(t1),
+            _ => None,
+        }
+    }
+
+    /// Converts the enum to an Option containing the T2 value, if it is of type T2.
+    #[must_use]
+    pub fn as_t2(self) -> Option<T2> {
+        match self {
+            Self::T2(t2) => Some(t2),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the T2 value, if it is of type T2, without consuming the enum.
+    #[must_use]
+    pub fn as_t2_ref(&self) -> Option<&T2> {
+        match self {
+            Self::T2(t2) => Some(t2),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the T2 value, if it is of type T2.
+    #[must_use]
+    pub fn as_t2_mut(&mut self) -> Option<&mut T2> {
+        match self {
+            Self::T2(t2) => Some(t2),
+            _ => None,
+        }
+    }
+
+    /// Converts the enum to an Option containing the T3 value, if it is of type T3.
+    #[must_use]
+    pub fn as_t3(self) -> Option<T3> {
+        match self {
+            Self::T3(t3) => Some(t3),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the T3 value, if it is of type T3, without consuming the enum.
+    #[must_use]
+    pub fn as_t3_ref(&self) -> Option<&T3> {
+        match self {
+            Self::T3(t3) => Some(t3),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the T3 value, if it is of type T3.
+    #[must_use]
+    pub fn as_t3_mut(&mut self) -> Option<&mut T3> {
+        match self {
+            Self::T3(t3) => Some(t3),
+            _ => None,
+        }
+    }
+
+    /// Converts the enum to an Option containing the T4 value, if it is of type T4.
+    #[must_use]
+    pub fn as_t4(self) -> Option<T4> {
+        match self {
+            Self::T4(t4) => Some(t4),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the T4 value, if it is of type T4, without consuming the enum.
+    #[must_use]
+    pub fn as_t4_ref(&self) -> Option<&T4> {
+        match self {
+            Self::T4(t4) => Some(t4),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the T4 value, if it is of type T4.
+    #[must_use]
+    pub fn as_t4_mut(&mut self) -> Option<&mut T4> {
+        match self {
+            Self::T4(t4) => Some(t4),
+            _ => None,
+        }
+    }
+
+    /// Converts the enum to an Option containing the T5 value, if it is of type T5.
+    #[must_use]
+    pub fn as_t5(self) -> Option<T5> {
+        match self {
+            Self::T5(t5) => Some(t5),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the T5 value, if it is of type T5, without consuming the enum.
+    #[must_use]
+    pub fn as_t5_ref(&self) -> Option<&T5> {
+        match self {
+            Self::T5(t5) => Some(t5),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the T5 value, if it is of type T5.
+    #[must_use]
+    pub fn as_t5_mut(&mut self) -> Option<&mut T5> {
+        match self {
+            Self::T5(t5) => Some(t5),
+            _ => None,
+        }
+    }
+
+    /// Returns the T1 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum is not of type T1, naming the variant it is of.
+    #[track_caller]
+    pub fn unwrap_t1(self) -> T1 {
+        match self {
+            Self::T1(t1) => t1,
+            _ => panic!(
+                "called `Or5::unwrap_t1()` on a `{:?}` value",
+                self.discriminant()
+            ),
+        }
+    }
+
+    /// Returns the T1 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the enum is not of type T1.
+    #[track_caller]
+    pub fn expect_t1(self, msg: &str) -> T1 {
+        match self {
+            Self::T1(t1) => t1,
+            _ => panic!("{}", msg),
+        }
+    }
+
+    /// Returns the T2 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum is not of type T2, naming the variant it is of.
+    #[track_caller]
+    pub fn unwrap_t2(self) -> T2 {
+        match self {
+            Self::T2(t2) => t2,
+            _ => panic!(
+                "called `Or5::unwrap_t2()` on a `{:?}` value",
+                self.discriminant()
+            ),
+        }
+    }
+
+    /// Returns the T2 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the enum is not of type T2.
+    #[track_caller]
+    pub fn expect_t2(self, msg: &str) -> T2 {
+        match self {
+            Self::T2(t2) => t2,
+            _ => panic!("{}", msg),
+        }
+    }
+
+    /// Returns the T3 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum is not of type T3, naming the variant it is of.
+    #[track_caller]
+    pub fn unwrap_t3(self) -> T3 {
+        match self {
+            Self::T3(t3) => t3,
+            _ => panic!(
+                "called `Or5::unwrap_t3()` on a `{:?}` value",
+                self.discriminant()
+            ),
+        }
+    }
+
+    /// Returns the T3 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the enum is not of type T3.
+    #[track_caller]
+    pub fn expect_t3(self, msg: &str) -> T3 {
+        match self {
+            Self::T3(t3) => t3,
+            _ => panic!("{}", msg),
+        }
+    }
+
+    /// Returns the T4 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum is not of type T4, naming the variant it is of.
+    #[track_caller]
+    pub fn unwrap_t4(self) -> T4 {
+        match self {
+            Self::T4(t4) => t4,
+            _ => panic!(
+                "called `Or5::unwrap_t4()` on a `{:?}` value",
+                self.discriminant()
+            ),
+        }
+    }
+
+    /// Returns the T4 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the enum is not of type T4.
+    #[track_caller]
+    pub fn expect_t4(self, msg: &str) -> T4 {
+        match self {
+            Self::T4(t4) => t4,
+            _ => panic!("{}", msg),
+        }
+    }
+
+    /// Returns the T5 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum is not of type T5, naming the variant it is of.
+    #[track_caller]
+    pub fn unwrap_t5(self) -> T5 {
+        match self {
+            Self::T5(t5) => t5,
+            _ => panic!(
+                "called `Or5::unwrap_t5()` on a `{:?}` value",
+                self.discriminant()
+            ),
+        }
+    }
+
+    /// Returns the T5 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the enum is not of type T5.
+    #[track_caller]
+    pub fn expect_t5(self, msg: &str) -> T5 {
+        match self {
+            Self::T5(t5) => t5,
+            _ => panic!("{}", msg),
+        }
+    }
+
+    /// Converts from `&Or5<T1, ...>` to `Or5<&T1, ...>`.
+    #[must_use]
+    pub fn as_ref(&self) -> Or5<&T1, &T2, &T3, &T4, &T5> {
+        match self {
+            Self::T1(t1) => Or5::T1(t1),
+            Self::T2(t2) => Or5::T2(t2),
+            Self::T3(t3) => Or5::T3(t3),
+            Self::T4(t4) => Or5::T4(t4),
+            Self::T5(t5) => Or5::T5(t5),
+        }
+    }
+
+    /// Converts from `&mut Or5<T1, ...>` to `Or5<&mut T1, ...>`.
+    #[must_use]
+    pub fn as_mut(&mut self) -> Or5<&mut T1, &mut T2, &mut T3, &mut T4, &mut T5> {
+        match self {
+            Self::T1(t1) => Or5::T1(t1),
+            Self::T2(t2) => Or5::T2(t2),
+            Self::T3(t3) => Or5::T3(t3),
+            Self::T4(t4) => Or5::T4(t4),
+            Self::T5(t5) => Or5::T5(t5),
+        }
+    }
+
+    /// Transforms the T1 value of the enum using a provided function,
+    /// maintaining other types as is.
+    #[must_use = "this returns the mapped enum, without modifying the original"]
+    pub fn map_t1<F, B>(self, f: F) -> Or5<B, T2, T3, T4, T5>
+    where
+        F: FnOnce(T1) -> B,
+    {
+        match self {
+            Self::T1(t1) => Or5::<B, T2, T3, T4, T5>::T1(f(t1)),
+            Self::T2(t2) => Or5::<B, T2, T3, T4, T5>::T2(t2),
+            Self::T3(t3) => Or5::<B, T2, T3, T4, T5>::T3(t3),
+            Self::T4(t4) => Or5::<B, T2, T3, T4, T5>::T4(t4),
+            Self::T5(t5) => Or5::<B, T2, T3, T4, T5>::T5(t5),
+        }
+    }
+
+    /// Transforms the T2 value of the enum using a provided function,
+    /// maintaining other types as is.
+    #[must_use = "this returns the mapped enum, without modifying the original"]
+    pub fn map_t2<F, B>(self, f: F) -> Or5<T1, B, T3, T4, T5>
+    where
+        F: FnOnce(T2) -> B,
+    {
+        match self {
+            Self::T1(t1) => Or5::<T1, B, T3, T4, T5>::T1(t1),
+            Self::T2(t2) => Or5::<T1, B, T3, T4, T5>::T2(f(t2)),
+            Self::T3(t3) => Or5::<T1, B, T3, T4, T5>::T3(t3),
+            Self::T4(t4) => Or5::<T1, B, T3, T4, T5>::T4(t4),
+            Self::T5(t5) => Or5::<T1, B, T3, T4, T5>::T5(t5),
+        }
+    }
+
+    /// Transforms the T3 value of the enum using a provided function,
+    /// maintaining other types as is.
+    #[must_use = "this returns the mapped enum, without modifying the original"]
+    pub fn map_t3<F, B>(self, f: F) -> Or5<T1, T2, B, T4, T5>
+    where
+        F: FnOnce(T3) -> B,
+    {
+        match self {
+            Self::T1(t1) => Or5::<T1, T2, B, T4, T5>::T1(t1),
+            Self::T2(t2) => Or5::<T1, T2, B, T4, T5>::T2(t2),
+            Self::T3(t3) => Or5::<T1, T2, B, T4, T5>::T3(f(t3)),
+            Self::T4(t4) => Or5::<T1, T2, B, T4, T5>::T4(t4),
+            Self::T5(t5) => Or5::<T1, T2, B, T4, T5>::T5(t5),
+        }
+    }
+
+    /// Transforms the T4 value of the enum using a provided function,
+    /// maintaining other types as is.
+    #[must_use = "this returns the mapped enum, without modifying the original"]
+    pub fn map_t4<F, B>(self, f: F) -> Or5<T1, T2, T3, B, T5>
+    where
+        F: FnOnce(T4) -> B,
+    {
+        match self {
+            Self::T1(t1) => Or5::<T1, T2, T3, B, T5>::T1(t1),
+            Self::T2(t2) => Or5::<T1, T2, T3, B, T5>::T2(t2),
+            Self::T3(t3) => Or5::<T1, T2, T3, B, T5>::T3(t3),
+            Self::T4(t4) => Or5::<T1, T2, T3, B, T5>::T4(f(t4)),
+            Self::T5(t5) => Or5::<T1, T2, T3, B, T5>::T5(t5),
+        }
+    }
+
+    /// Transforms the T5 value of the enum using a provided function,
+    /// maintaining other types as is.
+    #[must_use = "this returns the mapped enum, without modifying the original"]
+    pub fn map_t5<F, B>(self, f: F) -> Or5<T1, T2, T3, T4, B>
+    where
+        F: FnOnce(T5) -> B,
+    {
+        match self {
+            Self::T1(t1) => Or5::<T1, T2, T3, T4, B>::T1(t1),
+            Self::T2(t2) => Or5::<T1, T2, T3, T4, B>::T2(t2),
+            Self::T3(t3) => Or5::<T1, T2, T3, T4, B>::T3(t3),
+            Self::T4(t4) => Or5::<T1, T2, T3, T4, B>::T4(t4),
+            Self::T5(t5) => Or5::<T1, T2, T3, T4, B>::T5(f(t5)),
+        }
+    }
+
+    /// Transforms the T1 value of the enum using a provided async function and awaits it,
+    /// maintaining other types as is.
+    pub async fn map_t1_async<F, Fut, B>(self, f: F) -> Or5<B, T2, T3, T4, T5>
+    where
+        F: FnOnce(T1) -> Fut,
+        Fut: Future<Output = B>,
+    {
+        match self {
+            Self::T1(t1) => Or5::T1(f(t1).await),
+            Self::T2(t2) => Or5::T2(t2),
+            Self::T3(t3) => Or5::T3(t3),
+            Self::T4(t4) => Or5::T4(t4),
+            Self::T5(t5) => Or5::T5(t5),
+        }
+    }
+
+    /// Transforms the T2 value of the enum using a provided async function and awaits it,
+    /// maintaining other types as is.
+    pub async fn map_t2_async<F, Fut, B>(self, f: F) -> Or5<T1, B, T3, T4, T5>
+    where
+        F: FnOnce(T2) -> Fut,
+        Fut: Future<Output = B>,
+    {
+        match self {
+            Self::T1(t1) => Or5::T1(t1),
+            Self::T2(t2) => Or5::T2(f(t2).await),
+            Self::T3(t3) => Or5::T3(t3),
+            Self::T4(t4) => Or5::T4(t4),
+            Self::T5(t5) => Or5::T5(t5),
+        }
+    }
+
+    /// Transforms the T3 value of the enum using a provided async function and awaits it,
+    /// maintaining other types as is.
+    pub async fn map_t3_async<F, Fut, B>(self, f: F) -> Or5<T1, T2, B, T4, T5>
+    where
+        F: FnOnce(T3) -> Fut,
+        Fut: Future<Output = B>,
+    {
+        match self {
+            Self::T1(t1) => Or5::T1(t1),
+            Self::T2(t2) => Or5::T2(t2),
+            Self::T3(t3) => Or5::T3(f(t3).await),
+            Self::T4(t4) => Or5::T4(t4),
+            Self::T5(t5) => Or5::T5(t5),
+        }
+    }
+
+    /// Transforms the T4 value of the enum using a provided async function and awaits it,
+    /// maintaining other types as is.
+    pub async fn map_t4_async<F, Fut, B>(self, f: F) -> Or5<T1, T2, T3, B, T5>
+    where
+        F: FnOnce(T4) -> Fut,
+        Fut: Future<Output = B>,
+    {
+        match self {
+            Self::T1(t1) => Or5::T1(t1),
+            Self::T2(t2) => Or5::T2(t2),
+            Self::T3(t3) => Or5::T3(t3),
+            Self::T4(t4) => Or5::T4(f(t4).await),
+            Self::T5(t5) => Or5::T5(t5),
+        }
+    }
+
+    /// Transforms the T5 value of the enum using a provided async function and awaits it,
+    /// maintaining other types as is.
+    pub async fn map_t5_async<F, Fut, B>(self, f: F) -> Or5<T1, T2, T3, T4, B>
+    where
+        F: FnOnce(T5) -> Fut,
+        Fut: Future<Output = B>,
+    {
+        match self {
+            Self::T1(t1) => Or5::T1(t1),
+            Self::T2(t2) => Or5::T2(t2),
+            Self::T3(t3) => Or5::T3(t3),
+            Self::T4(t4) => Or5::T4(t4),
+            Self::T5(t5) => Or5::T5(f(t5).await),
+        }
+    }
+
+    /// Consolidates the `Or5` enum into a single value of type `T`,
+    /// by applying provided functions.
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn fold<T, F1, F2, F3, F4, F5>(self, f1: F1, f2: F2, f3: F3, f4: F4, f5: F5) -> T
+    where
+        F1: FnOnce(T1) -> T,
+        F2: FnOnce(T2) -> T,
+        F3: FnOnce(T3) -> T,
+        F4: FnOnce(T4) -> T,
+        F5: FnOnce(T5) -> T,
+    {
+        match self {
+            Self::T1(t1) => f1(t1),
+            Self::T2(t2) => f2(t2),
+            Self::T3(t3) => f3(t3),
+            Self::T4(t4) => f4(t4),
+            Self::T5(t5) => f5(t5),
+        }
+    }
+
+    /// Consolidates the `Or5` enum into a single value of type `T` by applying provided
+    /// fallible functions, returning the error of the applied one, so that the functions can use `?`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_fold<T, E, F1, F2, F3, F4, F5>(
+        self,
+        f1: F1,
+        f2: F2,
+        f3: F3,
+        f4: F4,
+        f5: F5,
+    ) -> Result<T, E>
+    where
+        F1: FnOnce(T1) -> Result<T, E>,
+        F2: FnOnce(T2) -> Result<T, E>,
+        F3: FnOnce(T3) -> Result<T, E>,
+        F4: FnOnce(T4) -> Result<T, E>,
+        F5: FnOnce(T5) -> Result<T, E>,
+    {
+        match self {
+            Self::T1(t1) => f1(t1),
+            Self::T2(t2) => f2(t2),
+            Self::T3(t3) => f3(t3),
+            Self::T4(t4) => f4(t4),
+            Self::T5(t5) => f5(t5),
+        }
+    }
+
+    /// Consolidates the `Or5` enum into a single value of type `T`,
+    /// by applying provided async functions and awaiting the result.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn fold_async<T, F1, Fut1, F2, Fut2, F3, Fut3, F4, Fut4, F5, Fut5>(
+        self,
+        f1: F1,
+        f2: F2,
+        f3: F3,
+        f4: F4,
+        f5: F5,
+    ) -> T
+    where
+        F1: FnOnce(T1) -> Fut1,
+        Fut1: Future<Output = T>,
+        F2: FnOnce(T2) -> Fut2,
+        Fut2: Future<Output = T>,
+        F3: FnOnce(T3) -> Fut3,
+        Fut3: Future<Output = T>,
+        F4: FnOnce(T4) -> Fut4,
+        Fut4: Future<Output = T>,
+        F5: FnOnce(T5) -> Fut5,
+        Fut5: Future<Output = T>,
+    {
+        match self {
+            Self::T1(t1) => f1(t1).await,
+            Self::T2(t2) => f2(t2).await,
+            Self::T3(t3) => f3(t3).await,
+            Self::T4(t4) => f4(t4).await,
+            Self::T5(t5) => f5(t5).await,
+        }
+    }
+
+    /// Handles the T1 value of the enum using a provided function, and leaves the other
+    /// variants to the subsequent `when_tx` calls.
+    pub fn when_t1<R, F>(self, f: F) -> When<R, Or5<Infallible, T2, T3, T4, T5>>
+    where
+        F: FnOnce(T1) -> R,
+    {
+        match self {
+            Self::T1(t1) => When::handled(f(t1)),
+            Self::T2(t2) => When::pending(Or5::T2(t2)),
+            Self::T3(t3) => When::pending(Or5::T3(t3)),
+            Self::T4(t4) => When::pending(Or5::T4(t4)),
+            Self::T5(t5) => When::pending(Or5::T5(t5)),
+        }
+    }
+
+    /// Handles the T2 value of the enum using a provided function, and leaves the other
+    /// variants to the subsequent `when_tx` calls.
+    pub fn when_t2<R, F>(self, f: F) -> When<R, Or5<T1, Infallible, T3, T4, T5>>
+    where
+        F: FnOnce(T2) -> R,
+    {
+        match self {
+            Self::T1(t1) => When::pending(Or5::T1(t1)),
+            Self::T2(t2) => When::handled(f(t2)),
+            Self::T3(t3) => When::pending(Or5::T3(t3)),
+            Self::T4(t4) => When::pending(Or5::T4(t4)),
+            Self::T5(t5) => When::pending(Or5::T5(t5)),
+        }
+    }
+
+    /// Handles the T3 value of the enum using a provided function, and leaves the other
+    /// variants to the subsequent `when_tx` calls.
+    pub fn when_t3<R, F>(self, f: F) -> When<R, Or5<T1, T2, Infallible, T4, T5>>
+    where
+        F: FnOnce(T3) -> R,
+    {
+        match self {
+            Self::T1(t1) => When::pending(Or5::T1(t1)),
+            Self::T2(t2) => When::pending(Or5::T2(t2)),
+            Self::T3(t3) => When::handled(f(t3)),
+            Self::T4(t4) => When::pending(Or5::T4(t4)),
+            Self::T5(t5) => When::pending(Or5::T5(t5)),
+        }
+    }
+
+    /// Handles the T4 value of the enum using a provided function, and leaves the other
+    /// variants to the subsequent `when_tx` calls.
+    pub fn when_t4<R, F>(self, f: F) -> When<R, Or5<T1, T2, T3, Infallible, T5>>
+    where
+        F: FnOnce(T4) -> R,
+    {
+        match self {
+            Self::T1(t1) => When::pending(Or5::T1(t1)),
+            Self::T2(t2) => When::pending(Or5::T2(t2)),
+            Self::T3(t3) => When::pending(Or5::T3(t3)),
+            Self::T4(t4) => When::handled(f(t4)),
+            Self::T5(t5) => When::pending(Or5::T5(t5)),
+        }
+    }
+
+    /// Handles the T5 value of the enum using a provided function, and leaves the other
+    /// variants to the subsequent `when_tx` calls.
+    pub fn when_t5<R, F>(self, f: F) -> When<R, Or5<T1, T2, T3, T4, Infallible>>
+    where
+        F: FnOnce(T5) -> R,
+    {
+        match self {
+            Self::T1(t1) => When::pending(Or5::T1(t1)),
+            Self::T2(t2) => When::pending(Or5::T2(t2)),
+            Self::T3(t3) => When::pending(Or5::T3(t3)),
+            Self::T4(t4) => When::pending(Or5::T4(t4)),
+            Self::T5(t5) => When::handled(f(t5)),
+        }
+    }
+
+    /// Converts the enum into a tuple of Options, where only the slot of the active variant is `Some`.
+    #[must_use]
+    pub fn into_options(self) -> (Option<T1>, Option<T2>, Option<T3>, Option<T4>, Option<T5>) {
+        match self {
+            Self::T1(t1) => (Some(t1), None, None, None, None),
+            Self::T2(t2) => (None, Some(t2), None, None, None),
+            Self::T3(t3) => (None, None, Some(t3), None, None),
+            Self::T4(t4) => (None, None, None, Some(t4), None),
+            Self::T5(t5) => (None, None, None, None, Some(t5)),
+        }
+    }
+
+    /// Borrows the enum as a tuple of Options, where only the slot of the active variant is `Some`.
+    #[must_use]
+    pub fn as_options(
+        &self,
+    ) -> (
+        Option<&T1>,
+        Option<&T2>,
+        Option<&T3>,
+        Option<&T4>,
+        Option<&T5>,
+    ) {
+        self.as_ref().into_options()
+    }
+
+    /// Builds the enum from a tuple of Options, which succeeds only when exactly one of them is `Some`.
+    /// Otherwise the tuple is handed back as is.
+    pub fn try_from_options(
+        options: (Option<T1>, Option<T2>, Option<T3>, Option<T4>, Option<T5>),
+    ) -> Result<Self, (Option<T1>, Option<T2>, Option<T3>, Option<T4>, Option<T5>)> {
+        match options {
+            (Some(t1), None, None, None, None) => Ok(Self::T1(t1)),
+            (None, Some(t2), None, None, None) => Ok(Self::T2(t2)),
+            (None, None, Some(t3), None, None) => Ok(Self::T3(t3)),
+            (None, None, None, Some(t4), None) => Ok(Self::T4(t4)),
+            (None, None, None, None, Some(t5)) => Ok(Self::T5(t5)),
+            options => Err(options),
+        }
+    }
+}
+
+impl_forwarding!(Or5, Or5Items, Or5Discriminant {
+    T1(t1, e1, I1, i1, E1, 0, T1),
+    T2(t2, e2, I2, i2, E2, 1, T2),
+    T3(t3, e3, I3, i3, E3, 2, T3),
+    T4(t4, e4, I4, i4, E4, 3, T4),
+    T5(t5, e5, I5, i5, E5, 4, T5)
+});
+
+impl<T1, T2, T3, T4, T5> Or5<T1, T2, T3, T4, T5> {
+    /// The number of the variants, which is the size of the arrays returned by
+    /// [`Or5::variant_sizes`] and friends, indexed by [`Or5::variant_index`].
+    pub const VARIANT_COUNT: usize = 5;
+
+    /// Returns the 0-based position of the active variant, like `0` for `T1`.
+    ///
+    /// This can index a table of handlers or counters, with `VARIANT_COUNT` entries.
+    #[must_use]
+    pub const fn variant_index(&self) -> usize {
+        match self {
+            Self::T1(_) => 0,
+            Self::T2(_) => 1,
+            Self::T3(_) => 2,
+            Self::T4(_) => 3,
+            Self::T5(_) => 4,
+        }
+    }
+
+    /// Returns the sizes of the payload types, in the order of the variants.
+    ///
+    /// Comparing them with the size of the enum tells whether one oversized variant bloats
+    /// the whole enum, in which case it can be shrunk by [`Or5::boxed`].
+    pub const fn variant_sizes() -> [usize; 5] {
+        [
+            size_of::<T1>(),
+            size_of::<T2>(),
+            size_of::<T3>(),
+            size_of::<T4>(),
+            size_of::<T5>(),
+        ]
+    }
+
+    /// Returns the alignments of the payload types, in the order of the variants.
+    pub const fn variant_aligns() -> [usize; 5] {
+        [
+            align_of::<T1>(),
+            align_of::<T2>(),
+            align_of::<T3>(),
+            align_of::<T4>(),
+            align_of::<T5>(),
+        ]
+    }
+
+    /// Returns the size of the largest payload type, which the size of the enum is at least.
+    pub const fn max_variant_size() -> usize {
+        let sizes = Self::variant_sizes();
+        let mut max = 0;
+        let mut i = 0;
+        while i < sizes.len() {
+            if sizes[i] > max {
+                max = sizes[i];
+            }
+            i += 1;
+        }
+        max
+    }
+}
+
+impl<T2, T3, T4, T5> Or5<Infallible, T2, T3, T4, T5> {
+    /// Removes the uninhabited `T1` position from `Or5`, which is useful to simplify
+    /// an `Or` type once some of its branches become impossible.
+    #[must_use]
+    pub fn compact_t1(self) -> Or4<T2, T3, T4, T5> {
+        match self {
+            Self::T1(never) => match never {},
+            Self::T2(t2) => Or4::T1(t2),
+            Self::T3(t3) => Or4::T2(t3),
+            Self::T4(t4) => Or4::T3(t4),
+            Self::T5(t5) => Or4::T4(t5),
+        }
+    }
+}
+
+impl<T1, T3, T4, T5> Or5<T1, Infallible, T3, T4, T5> {
+    /// Removes the uninhabited `T2` position from `Or5`, which is useful to simplify
+    /// an `Or` type once some of its branches become impossible.
+    #[must_use]
+    pub fn compact_t2(self) -> Or4<T1, T3, T4, T5> {
+        match self {
+            Self::T1(t1) => Or4::T1(t1),
+            Self::T2(never) => match never {},
+            Self::T3(t3) => Or4::T2(t3),
+            Self::T4(t4) => Or4::T3(t4),
+            Self::T5(t5) => Or4::T4(t5),
+        }
+    }
+}
+
+impl<T1, T2, T4, T5> Or5<T1, T2, Infallible, T4, T5> {
+    /// Removes the uninhabited `T3` position from `Or5`, which is useful to simplify
+    /// an `Or` type once some of its branches become impossible.
+    #[must_use]
+    pub fn compact_t3(self) -> Or4<T1, T2, T4, T5> {
+        match self {
+            Self::T1(t1) => Or4::T1(t1),
+            Self::T2(t2) => Or4::T2(t2),
+            Self::T3(never) => match never {},
+            Self::T4(t4) => Or4::T3(t4),
+            Self::T5(t5) => Or4::T4(t5),
+        }
+    }
+}
+
+impl<T1, T2, T3, T5> Or5<T1, T2, T3, Infallible, T5> {
+    /// Removes the uninhabited `T4` position from `Or5`, which is useful to simplify
+    /// an `Or` type once some of its branches become impossible.
+    #[must_use]
+    pub fn compact_t4(self) -> Or4<T1, T2, T3, T5> {
+        match self {
+            Self::T1(t1) => Or4::T1(t1),
+            Self::T2(t2) => Or4::T2(t2),
+            Self::T3(t3) => Or4::T3(t3),
+            Self::T4(never) => match never {},
+            Self::T5(t5) => Or4::T4(t5),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4> Or5<T1, T2, T3, T4, Infallible> {
+    /// Removes the uninhabited `T5` position from `Or5`, which is useful to simplify
+    /// an `Or` type once some of its branches become impossible.
+    #[must_use]
+    pub fn compact_t5(self) -> Or4<T1, T2, T3, T4> {
+        match self {
+            Self::T1(t1) => Or4::T1(t1),
+            Self::T2(t2) => Or4::T2(t2),
+            Self::T3(t3) => Or4::T3(t3),
+            Self::T4(t4) => Or4::T4(t4),
+            Self::T5(never) => match never {},
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5> Or5<T1, T2, T3, T4, T5> {
+    /// Converts the enum into a `Result`, treating `T1` as the ok value and the others as the
+    /// error. The conversion is lossless, and can be reverted by `From`.
+    pub fn into_result(self) -> Result<T1, Or4<T2, T3, T4, T5>> {
+        match self {
+            Self::T1(t1) => Ok(t1),
+            Self::T2(t2) => Err(Or4::T1(t2)),
+            Self::T3(t3) => Err(Or4::T2(t3)),
+            Self::T4(t4) => Err(Or4::T3(t4)),
+            Self::T5(t5) => Err(Or4::T4(t5)),
+        }
+    }
+
+    /// Converts the enum into a `Result`, treating `T1` as the ok value and converting the
+    /// others into the error type `E`.
+    pub fn try_into_result<E>(self) -> Result<T1, E>
+    where
+        T2: Into<E>,
+        T3: Into<E>,
+        T4: Into<E>,
+        T5: Into<E>,
+    {
+        match self {
+            Self::T1(t1) => Ok(t1),
+            Self::T2(t2) => Err(t2.into()),
+            Self::T3(t3) => Err(t3.into()),
+            Self::T4(t4) => Err(t4.into()),
+            Self::T5(t5) => Err(t5.into()),
+        }
+    }
+}
+
+/// Converts the ok value into `T1` and the error into the remaining positions, which reverts
+/// `into_result`.
+impl<T1, T2, T3, T4, T5> From<Result<T1, Or4<T2, T3, T4, T5>>> for Or5<T1, T2, T3, T4, T5> {
+    fn from(result: Result<T1, Or4<T2, T3, T4, T5>>) -> Self {
+        match result {
+            Ok(t1) => Self::T1(t1),
+            Err(Or4::T1(t2)) => Self::T2(t2),
+            Err(Or4::T2(t3)) => Self::T3(t3),
+            Err(Or4::T3(t4)) => Self::T4(t4),
+            Err(Or4::T4(t5)) => Self::T5(t5),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4> Or5<T1, T2, T3, T4, ()> {
+    /// Converts the enum into an `Option`, which is `None` for the `()` in the last position.
+    /// The conversion is lossless, and can be reverted by `From`.
+    pub fn into_option(self) -> Option<Or4<T1, T2, T3, T4>> {
+        match self {
+            Self::T1(t1) => Some(Or4::T1(t1)),
+            Self::T2(t2) => Some(Or4::T2(t2)),
+            Self::T3(t3) => Some(Or4::T3(t3)),
+            Self::T4(t4) => Some(Or4::T4(t4)),
+            Self::T5(()) => None,
+        }
+    }
+}
+
+/// Converts `None` into the `()` in the last position, which reverts `into_option`.
+impl<T1, T2, T3, T4> From<Option<Or4<T1, T2, T3, T4>>> for Or5<T1, T2, T3, T4, ()> {
+    fn from(option: Option<Or4<T1, T2, T3, T4>>) -> Self {
+        match option {
+            Some(Or4::T1(t1)) => Self::T1(t1),
+            Some(Or4::T2(t2)) => Self::T2(t2),
+            Some(Or4::T3(t3)) => Self::T3(t3),
+            Some(Or4::T4(t4)) => Self::T4(t4),
+            None => Self::T5(()),
+        }
+    }
+}
+
+impl<R, T1, T2, T3, T4, T5> When<R, Or5<T1, T2, T3, T4, T5>> {
+    /// Handles the T1 value of the pending `Or5` using a provided function,
+    /// unless a previous `when_tx` has already handled the value.
+    pub fn when_t1<F>(self, f: F) -> When<R, Or5<Infallible, T2, T3, T4, T5>>
+    where
+        F: FnOnce(T1) -> R,
+    {
+        match self.into_state() {
+            Ok(result) => When::handled(result),
+            Err(or) => or.when_t1(f),
+        }
+    }
+
+    /// Handles the T2 value of the pending `Or5` using a provided function,
+    /// unless a previous `when_tx` has already handled the value.
+    pub fn when_t2<F>(self, f: F) -> When<R, Or5<T1, Infallible, T3, T4, T5>>
+    where
+        F: FnOnce(T2) -> R,
+    {
+        match self.into_state() {
+            Ok(result) => When::handled(result),
+            Err(or) => or.when_t2(f),
+        }
+    }
+
+    /// Handles the T3 value of the pending `Or5` using a provided function,
+    /// unless a previous `when_tx` has already handled the value.
+    pub fn when_t3<F>(self, f: F) -> When<R, Or5<T1, T2, Infallible, T4, T5>>
+    where
+        F: FnOnce(T3) -> R,
+    {
+        match self.into_state() {
+            Ok(result) => When::handled(result),
+            Err(or) => or.when_t3(f),
+        }
+    }
+
+    /// Handles the T4 value of the pending `Or5` using a provided function,
+    /// unless a previous `when_tx` has already handled the value.
+    pub fn when_t4<F>(self, f: F) -> When<R, Or5<T1, T2, T3, Infallible, T5>>
+    where
+        F: FnOnce(T4) -> R,
+    {
+        match self.into_state() {
+            Ok(result) => When::handled(result),
+            Err(or) => or.when_t4(f),
+        }
+    }
+
+    /// Handles the T5 value of the pending `Or5` using a provided function,
+    /// unless a previous `when_tx` has already handled the value.
+    pub fn when_t5<F>(self, f: F) -> When<R, Or5<T1, T2, T3, T4, Infallible>>
+    where
+        F: FnOnce(T5) -> R,
+    {
+        match self.into_state() {
+            Ok(result) => When::handled(result),
+            Err(or) => or.when_t5(f),
+        }
+    }
+}
+
+impl<R> When<R, Or5<Infallible, Infallible, Infallible, Infallible, Infallible>> {
+    /// Finishes the chain and returns the result. This is available only when every
+    /// variant of `Or5` has been handled.
+    pub fn finish(self) -> R {
+        match self.into_state() {
+            Ok(result) => result,
+            Err(or) => match or {
+                Or5::T1(never) => match never {},
+                Or5::T2(never) => match never {},
+                Or5::T3(never) => match never {},
+                Or5::T4(never) => match never {},
+                Or5::T5(never) => match never {},
+            },
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7> Concat<Or2<T6, T7>> for Or5<T1, T2, T3, T4, T5> {
+    type Output = Or7<T1, T2, T3, T4, T5, T6, T7>;
+
+    fn left(lhs: Self) -> Self::Output {
+        match lhs {
+            Or5::T1(x) => Or7::T1(x),
+            Or5::T2(x) => Or7::T2(x),
+            Or5::T3(x) => Or7::T3(x),
+            Or5::T4(x) => Or7::T4(x),
+            Or5::T5(x) => Or7::T5(x),
+        }
+    }
+
+    fn right(rhs: Or2<T6, T7>) -> Self::Output {
+        match rhs {
+            Or2::T1(x) => Or7::T6(x),
+            Or2::T2(x) => Or7::T7(x),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7, T8> Concat<Or3<T6, T7, T8>> for Or5<T1, T2, T3, T4, T5> {
+    type Output = Or8<T1, T2, T3, T4, T5, T6, T7, T8>;
+
+    fn left(lhs: Self) -> Self::Output {
+        match lhs {
+            Or5::T1(x) => Or8::T1(x),
+            Or5::T2(x) => Or8::T2(x),
+            Or5::T3(x) => Or8::T3(x),
+            Or5::T4(x) => Or8::T4(x),
+            Or5::T5(x) => Or8::T5(x),
+        }
+    }
+
+    fn right(rhs: Or3<T6, T7, T8>) -> Self::Output {
+        match rhs {
+            Or3::T1(x) => Or8::T6(x),
+            Or3::T2(x) => Or8::T7(x),
+            Or3::T3(x) => Or8::T8(x),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7, T8, T9> Concat<Or4<T6, T7, T8, T9>> for Or5<T1, T2, T3, T4, T5> {
+    type Output = Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>;
+
+    fn left(lhs: Self) -> Self::Output {
+        match lhs {
+            Or5::T1(x) => Or9::T1(x),
+            Or5::T2(x) => Or9::T2(x),
+            Or5::T3(x) => Or9::T3(x),
+            Or5::T4(x) => Or9::T4(x),
+            Or5::T5(x) => Or9::T5(x),
+        }
+    }
+
+    fn right(rhs: Or4<T6, T7, T8, T9>) -> Self::Output {
+        match rhs {
+            Or4::T1(x) => Or9::T6(x),
+            Or4::T2(x) => Or9::T7(x),
+            Or4::T3(x) => Or9::T8(x),
+            Or4::T4(x) => Or9::T9(x),
+        }
+    }
+}
+
+/// `Or6` is an enum representing a value that can be either of 6 types, T1 ... T6.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(untagged)
+)]
+#[cfg_attr(feature = "repr-c", repr(C))]
+#[cfg_attr(feature = "repr-u8", repr(u8))]
+pub enum Or6<T1, T2, T3, T4, T5, T6> {
+    T1(T1),
+    T2(T2),
+    T3(T3),
+    T4(T4),
+    T5(T5),
+    T6(T6),
+}
+
+impl<T1, T2, T3, T4, T5, T6> Or6<T1, T2, T3, T4, T5, T6> {
+    /// Returns true if the enum is of type T1.
+    #[must_use]
+    pub fn is_t1(&self) -> bool {
+        matches!(self, Self::T1(_))
+    }
+
+    /// Returns true if the enum is of type T2.
+    #[must_use]
+    pub fn is_t2(&self) -> bool {
+        matches!(self, Self::T2(_))
+    }
+
+    /// Returns true if the enum is of type T3.
+    #[must_use]
+    pub fn is_t3(&self) -> bool {
+        matches!(self, Self::T3(_))
+    }
+
+    /// Returns true if the enum is of type T4.
+    #[must_use]
+    pub fn is_t4(&self) -> bool {
+        matches!(self, Self::T4(_))
+    }
+
+    /// Returns true if the enum is of type T5.
+    #[must_use]
+    pub fn is_t5(&self) -> bool {
+        matches!(self, Self::T5(_))
+    }
+
+    /// Returns true if the enum is of type T6.
+    #[must_use]
+    pub fn is_t6(&self) -> bool {
+        matches!(self, Self::T6(_))
+    }
+
+    /// Converts the enum to an Option containing the T1 value, if it is of type T1.
+    #[must_use]
+    pub fn as_t1(self) -> Option<T1> {
+        match self {
+            Self::T1(t1) => Some(t1),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the T1 value, if it is of type T1, without consuming the enum.
+    #[must_use]
+    pub fn as_t1_ref(&self) -> Option<&T1> {
+        match self {
+            Self::T1(t1) => Some(t1),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the T1 value, if it is of type T1.
+    #[must_use]
+    pub fn as_t1_mut(&mut self) -> Option<&mut T1> {
+        match self {
+            Self::T1(t1) => Some(t1),
+            _ => None,
+        }
+    }
+
+    /// Converts the enum to an Option containing the T2 value, if it is of type T2.
+    #[must_use]
+    pub fn as_t2(self) -> Option<T2> {
+        match self {
+            Self::T2(t2) => Some(t2),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the T2 value, if it is of type T2, without consuming the enum.
+    #[must_use]
+    pub fn as_t2_ref(&self) -> Option<&T2> {
+        match self {
+            Self::T2(t2) => Some(t2),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the T2 value, if it is of type T2.
+    #[must_use]
+    pub fn as_t2_mut(&mut self) -> Option<&mut T2> {
+        match self {
+            Self::T2(t2) => Some(t2),
+            _ => None,
+        }
+    }
+
+    /// Converts the enum to an Option containing the T3 value, if it is of type T3.
+    #[must_use]
+    pub fn as_t3(self) -> Option<T3> {
+        match self {
+            Self::T3(t3) => Some(t3),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the T3 value, if it is of type T3, without consuming the enum.
+    #[must_use]
+    pub fn as_t3_ref(&self) -> Option<&T3> {
+        match self {
+            Self::T3(t3) => Some(t3),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the T3 value, if it is of type T3.
+    #[must_use]
+    pub fn as_t3_mut(&mut self) -> Option<&mut T3> {
+        match self {
+            Self::T3(t3) => Some(t3),
+            _ => None,
+        }
+    }
+
+    /// Converts the enum to an Option containing the T4 value, if it is of type T4.
+    #[must_use]
+    pub fn as_t4(self) -> Option<T4> {
+        match self {
+            Self::T4(t4) => Some(t4),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the T4 value, if it is of type T4, without consuming the enum.
+    #[must_use]
+    pub fn as_t4_ref(&self) -> Option<&T4> {
+        match self {
+            Self::T4(t4) => Some(t4),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the T4 value, if it is of type T4.
+    #[must_use]
+    pub fn as_t4_mut(&mut self) -> Option<&mut T4> {
+        match self {
+            Self::T4(t4) => Some(t4),
+            _ => None,
+        }
+    }
+
+    /// Converts the enum to an Option containing the T5 value, if it is of type T5.
+    #[must_use]
+    pub fn as_t5(self) -> Option<T5> {
+        match self {
+            Self::T5(t5) => Some(t5),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the T5 value, if it is of type T5, without consuming the enum.
+    #[must_use]
+    pub fn as_t5_ref(&self) -> Option<&T5> {
+        match self {
+            Self::T5(t5) => Some(t5),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the T5 value, if it is of type T5.
+    #[must_use]
+    pub fn as_t5_mut(&mut self) -> Option<&mut T5> {
+        match self {
+            Self::T5(t5) => Some(t5),
+            _ => None,
+        }
+    }
+
+    /// Converts the enum to an Option containing the T6 value, if it is of type T6.
+    #[must_use]
+    pub fn as_t6(self) -> Option<T6> {
+        match self {
+            Self::T6(t6) => Some(t6),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the T6 value, if it is of type T6, without consuming the enum.
+    #[must_use]
+    pub fn as_t6_ref(&self) -> Option<&T6> {
+        match self {
+            Self::T6(t6) => Some(t6),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the T6 value, if it is of type T6.
+    #[must_use]
+    pub fn as_t6_mut(&mut self) -> Option<&mut T6> {
+        match self {
+            Self::T6(t6) => Some(t6),
+            _ => None,
+        }
+    }
+
+    /// Returns the T1 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum is not of type T1, naming the variant it is of.
+    #[track_caller]
+    pub fn unwrap_t1(self) -> T1 {
+        match self {
+            Self::T1(t1) => t1,
+            _ => panic!(
+                "called `Or6::unwrap_t1()` on a `{:?}` value",
+                self.discriminant()
+            ),
+        }
+    }
+
+    /// Returns the T1 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the enum is not of type T1.
+    #[track_caller]
+    pub fn expect_t1(self, msg: &str) -> T1 {
+        match self {
+            Self::T1(t1) => t1,
+            _ => panic!("{}", msg),
+        }
+    }
+
+    /// Returns the T2 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum is not of type T2, naming the variant it is of.
+    #[track_caller]
+    pub fn unwrap_t2(self) -> T2 {
+        match self {
+            Self::T2(t2) => t2,
+            _ => panic!(
+                "called `Or6::unwrap_t2()` on a `{:?}` value",
+                self.discriminant()
+            ),
+        }
+    }
+
+    /// Returns the T2 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the enum is not of type T2.
+    #[track_caller]
+    pub fn expect_t2(self, msg: &str) -> T2 {
+        match self {
+            Self::T2(t2) => t2,
+            _ => panic!("{}", msg),
+        }
+    }
+
+    /// Returns the T3 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum is not of type T3, naming the variant it is of.
+    #[track_caller]
+    pub fn unwrap_t3(self) -> T3 {
+        match self {
+            Self::T3(t3) => t3,
+            _ => panic!(
+                "called `Or6::unwrap_t3()` on a `{:?}` value",
+                self.discriminant()
+            ),
+        }
+    }
+
+    /// Returns the T3 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the enum is not of type T3.
+    #[track_caller]
+    pub fn expect_t3(self, msg: &str) -> T3 {
+        match self {
+            Self::T3(t3) => t3,
+            _ => panic!("{}", msg),
+        }
+    }
+
+    /// Returns the T4 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum is not of type T4, naming the variant it is of.
+    #[track_caller]
+    pub fn unwrap_t4(self) -> T4 {
+        match self {
+            Self::T4(t4) => t4,
+            _ => panic!(
+                "called `Or6::unwrap_t4()` on a `{:?}` value",
+                self.discriminant()
+            ),
+        }
+    }
+
+    /// Returns the T4 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the enum is not of type T4.
+    #[track_caller]
+    pub fn expect_t4(self, msg: &str) -> T4 {
+        match self {
+            Self::T4(t4) => t4,
+            _ => panic!("{}", msg),
+        }
+    }
+
+    /// Returns the T5 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum is not of type T5, naming the variant it is of.
+    #[track_caller]
+    pub fn unwrap_t5(self) -> T5 {
+        match self {
+            Self::T5(t5) => t5,
+            _ => panic!(
+                "called `Or6::unwrap_t5()` on a `{:?}` value",
+                self.discriminant()
+            ),
+        }
+    }
+
+    /// Returns the T5 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the enum is not of type T5.
+    #[track_caller]
+    pub fn expect_t5(self, msg: &str) -> T5 {
+        match self {
+            Self::T5(t5) => t5,
+            _ => panic!("{}", msg),
+        }
+    }
+
+    /// Returns the T6 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum is not of type T6, naming the variant it is of.
+    #[track_caller]
+    pub fn unwrap_t6(self) -> T6 {
+        match self {
+            Self::T6(t6) => t6,
+            _ => panic!(
+                "called `Or6::unwrap_t6()` on a `{:?}` value",
+                self.discriminant()
+            ),
+        }
+    }
+
+    /// Returns the T6 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the enum is not of type T6.
+    #[track_caller]
+    pub fn expect_t6(self, msg: &str) -> T6 {
+        match self {
+            Self::T6(t6) => t6,
+            _ => panic!("{}", msg),
+        }
+    }
+
+    /// Converts from `&Or6<T1, ...>` to `Or6<&T1, ...>`.
+    #[must_use]
+    pub fn as_ref(&self) -> Or6<&T1, &T2, &T3, &T4, &T5, &T6> {
+        match self {
+            Self::T1(t1) => Or6::T1(t1),
+            Self::T2(t2) => Or6::T2(t2),
+            Self::T3(t3) => Or6::T3(t3),
+            Self::T4(t4) => Or6::T4(t4),
+            Self::T5(t5) => Or6::T5(t5),
+            Self::T6(t6) => Or6::T6(t6),
+        }
+    }
+
+    /// Converts from `&mut Or6<T1, ...>` to `Or6<&mut T1, ...>`.
+    #[must_use]
+    pub fn as_mut(&mut self) -> Or6<&mut T1, &mut T2, &mut T3, &mut T4, &mut T5, &mut T6> {
+        match self {
+            Self::T1(t1) => Or6::T1(t1),
+            Self::T2(t2) => Or6::T2(t2),
+            Self::T3(t3) => Or6::T3(t3),
+            Self::T4(t4) => Or6::T4(t4),
+            Self::T5(t5) => Or6::T5(t5),
+            Self::T6(t6) => Or6::T6(t6),
+        }
+    }
+
+    /// Transforms the T1 value of the enum using a provided function,
+    /// maintaining other types as is.
+    #[must_use = "this returns the mapped enum, without modifying the original"]
+    pub fn map_t1<F, B>(self, f: F) -> Or6<B, T2, T3, T4, T5, T6>
+    where
+        F: FnOnce(T1) -> B,
+    {
+        match self {
+            Self::T1(t1) => Or6::<B, T2, T3, T4, T5, T6>::T1(f(t1)),
+            Self::T2(t2) => Or6::<B, T2, T3, T4, T5, T6>::T2(t2),
+            Self::T3(t3) => Or6::<B, T2, T3, T4, T5, T6>::T3(t3),
+            Self::T4(t4) => Or6::<B, T2, T3, T4, T5, T6>::T4(t4),
+            Self::T5(t5) => Or6::<B, T2, T3, T4, T5, T6>::T5(t5),
+            Self::T6(t6) => Or6::<B, T2, T3, T4, T5, T6>::T6(t6),
+        }
+    }
+
+    /// Transforms the T2 value of the enum using a provided function,
+    /// maintaining other types as is.
+    #[must_use = "this returns the mapped enum, without modifying the original"]
+    pub fn map_t2<F, B>(self, f: F) -> Or6<T1, B, T3, T4, T5, T6>
+    where
+        F: FnOnce(T2) -> B,
+    {
+        match self {
+            Self::T1(t1) => Or6::<T1, B, T3, T4, T5, T6>::T1(t1),
+            Self::T2(t2) => Or6::<T1, B, T3, T4, T5, T6>::T2(f(t2)),
+            Self::T3(t3) => Or6::<T1, B, T3, T4, T5, T6>::T3(t3),
+            Self::T4(t4) => Or6::<T1, B, T3, T4, T5, T6>::T4(t4),
+            Self::T5(t5) => Or6::<T1, B, T3, T4, T5, T6>::T5(t5),
+            Self::T6(t6) => Or6::<T1, B, T3, T4, T5, T6>::T6(t6),
+        }
+    }
+
+    /// Transforms the T3 value of the enum using a provided function,
+    /// maintaining other types as is.
+    #[must_use = "this returns the mapped enum, without modifying the original"]
+    pub fn map_t3<F, B>(self, f: F) -> Or6<T1, T2, B, T4, T5, T6>
+    where
+        F: FnOnce(T3) -> B,
+    {
+        match self {
+            Self::T1(t1) => Or6::<T1, T2, B, T4, T5, T6>::T1(t1),
+            Self::T2(t2) => Or6::<T1, T2, B, T4, T5, T6>::T2(t2),
+            Self::T3(t3) => Or6::<T1, T2, B, T4, T5, T6>::T3(f(t3)),
+            Self::T4(t4) => Or6::<T1, T2, B, T4, T5, T6>::T4(t4),
+            Self::T5(t5) => Or6::<T1, T2, B, T4, T5, T6>::T5(t5),
+            Self::T6(t6) => Or6::<T1, T2, B, T4, T5, T6>::T6(t6),
+        }
+    }
+
+    /// Transforms the T4 value of the enum using a provided function,
+    /// maintaining other types as is.
+    #[must_use = "this returns the mapped enum, without modifying the original"]
+    pub fn map_t4<F, B>(self, f: F) -> Or6<T1, T2, T3, B, T5, T6>
+    where
+        F: FnOnce(T4) -> B,
+    {
+        match self {
+            Self::T1(t1) => Or6::<T1, T2, T3, B, T5, T6>::T1(t1),
+            Self::T2(t2) => Or6::<T1, T2, T3, B, T5, T6>::T2(t2),
+            Self::T3(t3) => Or6::<T1, T2, T3, B, T5, T6>::T3(t3),
+            Self::T4(t4) => Or6::<T1, T2, T3, B, T5, T6>::T4(f(t4)),
+            Self::T5(t5) => Or6::<T1, T2, T3, B, T5, T6>::T5(t5),
+            Self::T6(t6) => Or6::<T1, T2, T3, B, T5, T6>::T6(t6),
+        }
+    }
+
+    /// Transforms the T5 value of the enum using a provided function,
+    /// maintaining other types as is.
+    #[must_use = "this returns the mapped enum, without modifying the original"]
+    pub fn map_t5<F, B>(self, f: F) -> Or6<T1, T2, T3, T4, B, T6>
+    where
+        F: FnOnce(T5) -> B,
+    {
+        match self {
+            Self::T1(t1) => Or6::<T1, T2, T3, T4, B, T6>::T1(t1),
+            Self::T2(t2) => Or6::<T1, T2, T3, T4, B, T6>::T2(t2),
+            Self::T3(t3) => Or6::<T1, T2, T3, T4, B, T6>::T3(t3),
+            Self::T4(t4) => Or6::<T1, T2, T3, T4, B, T6>::T4(t4),
+            Self::T5(t5) => Or6::<T1, T2, T3, T4, B, T6>::T5(f(t5)),
+            Self::T6(t6) => Or6::<T1, T2, T3, T4, B, T6>::T6(t6),
+        }
+    }
+
+    /// Transforms the T6 value of the enum using a provided function,
+    /// maintaining other types as is.
+    #[must_use = "this returns the mapped enum, without modifying the original"]
+    pub fn map_t6<F, B>(self, f: F) -> Or6<T1, T2, T3, T4, T5, B>
+    where
+        F: FnOnce(T6) -> B,
+    {
+        match self {
+            Self::T1(t1) => Or6::<T1, T2, T3, T4, T5, B>::T1(t1),
+            Self::T2(t2) => Or6::<T1, T2, T3, T4, T5, B>::T2(t2),
+            Self::T3(t3) => Or6::<T1, T2, T3, T4, T5, B>::T3(t3),
+            Self::T4(t4) => Or6::<T1, T2, T3, T4, T5, B>::T4(t4),
+            Self::T5(t5) => Or6::<T1, T2, T3, T4, T5, B>::T5(t5),
+            Self::T6(t6) => Or6::<T1, T2, T3, T4, T5, B>::T6(f(t6)),
+        }
+    }
+
+    /// Transforms the T1 value of the enum using a provided async function and awaits it,
+    /// maintaining other types as is.
+    pub async fn map_t1_async<F, Fut, B>(self, f: F) -> Or6<B, T2, T3, T4, T5, T6>
+    where
+        F: FnOnce(T1) -> Fut,
+        Fut: Future<Output = B>,
+    {
+        match self {
+            Self::T1(t1) => Or6::T1(f(t1).await),
+            Self::T2(t2) => Or6::T2(t2),
+            Self::T3(t3) => Or6::T3(t3),
+            Self::T4(t4) => Or6::T4(t4),
+            Self::T5(t5) => Or6::T5(t5),
+            Self::T6(t6) => Or6::T6(t6),
+        }
+    }
+
+    /// Transforms the T2 value of the enum using a provided async function and awaits it,
+    /// maintaining other types as is.
+    pub async fn map_t2_async<F, Fut, B>(self, f: F) -> Or6<T1, B, T3, T4, T5, T6>
+    where
+        F: FnOnce(T2) -> Fut,
+        Fut: Future<Output = B>,
+    {
+        match self {
+            Self::T1(t1) => Or6::T1(t1),
+            Self::T2(t2) => Or6::T2(f(t2).await),
+            Self::T3(t3) => Or6::T3(t3),
+            Self::T4(t4) => Or6::T4(t4),
+            Self::T5(t5) => Or6::T5(t5),
+            Self::T6(t6) => Or6::T6(t6),
+        }
+    }
+
+    /// Transforms the T3 value of the enum using a provided async function and awaits it,
+    /// maintaining other types as is.
+    pub async fn map_t3_async<F, Fut, B>(self, f: F) -> Or6<T1, T2, B, T4, T5, T6>
+    where
+        F: FnOnce(T3) -> Fut,
+        Fut: Future<Output = B>,
+    {
+        match self {
+            Self::T1(t1) => Or6::T1(t1),
+            Self::T2(t2) => Or6::T2(t2),
+            Self::T3(t3) => Or6::T3(f(t3).await),
+            Self::T4(t4) => Or6::T4(t4),
+            Self::T5(t5) => Or6::T5(t5),
+            Self::T6(t6) => Or6::T6(t6),
+        }
+    }
+
+    /// Transforms the T4 value of the enum using a provided async function and awaits it,
+    /// maintaining other types as is.
+    pub async fn map_t4_async<F, Fut, B>(self, f: F) -> Or6<T1, T2, T3, B, T5, T6>
+    where
+        F: FnOnce(T4) -> Fut,
+        Fut: Future<Output = B>,
+    {
+        match self {
+            Self::T1(t1) => Or6::T1(t1),
+            Self::T2(t2) => Or6::T2(t2),
+            Self::T3(t3) => Or6::T3(t3),
+            Self::T4(t4) => Or6::T4(f(t4).await),
+            Self::T5(t5) => Or6::T5(t5),
+            Self::T6(t6) => Or6::T6(t6),
+        }
+    }
+
+    /// Transforms the T5 value of the enum using a provided async function and awaits it,
+    /// maintaining other types as is.
+    pub async fn map_t5_async<F, Fut, B>(self, f: F) -> Or6<T1, T2, T3, T4, B, T6>
+    where
+        F: FnOnce(T5) -> Fut,
+        Fut: Future<Output = B>,
+    {
+        match self {
+            Self::T1(t1) => Or6::T1(t1),
+            Self::T2(t2) => Or6::T2(t2),
+            Self::T3(t3) => Or6::T3(t3),
+            Self::T4(t4) => Or6::T4(t4),
+            Self::T5(t5) => Or6::T5(f(t5).await),
+            Self::T6(t6) => Or6::T6(t6),
+        }
+    }
+
+    /// Transforms the T6 value of the enum using a provided async function and awaits it,
+    /// maintaining other types as is.
+    pub async fn map_t6_async<F, Fut, B>(self, f: F) -> Or6<T1, T2, T3, T4, T5, B>
+    where
+        F: FnOnce(T6) -> Fut,
+        Fut: Future<Output = B>,
+    {
+        match self {
+            Self::T1(t1) => Or6::T1(t1),
+            Self::T2(t2) => Or6::T2(t2),
+            Self::T3(t3) => Or6::T3(t3),
+            Self::T4(t4) => Or6::T4(t4),
+            Self::T5(t5) => Or6::T5(t5),
+            Self::T6(t6) => Or6::T6(f(t6).await),
+        }
+    }
+
+    /// Consolidates the `Or6` enum into a single value of type `T`,
+    /// by applying provided functions.
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn fold<T, F1, F2, F3, F4, F5, F6>(
+        self,
+        f1: F1,
+        f2: F2,
+        f3: F3,
+        f4: F4,
+        f5: F5,
+        f6: F6,
+    ) -> T
+    where
+        F1: FnOnce(T1) -> T,
+        F2: FnOnce(T2) -> T,
+        F3: FnOnce(T3) -> T,
+        F4: FnOnce(T4) -> T,
+        F5: FnOnce(T5) -> T,
+        F6: FnOnce(T6) -> T,
+    {
+        match self {
+            Self::T1(t1) => f1(t1),
+            Self::T2(t2) => f2(t2),
+            Self::T3(t3) => f3(t3),
+            Self::T4(t4) => f4(t4),
+            Self::T5(t5) => f5(t5),
+            Self::T6(t6) => f6(t6),
+        }
+    }
+
+    /// Consolidates the `Or6` enum into a single value of type `T` by applying provided
+    /// fallible functions, returning the error of the applied one, so that the functions can use `?`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_fold<T, E, F1, F2, F3, F4, F5, F6>(
+        self,
+        f1: F1,
+        f2: F2,
+        f3: F3,
+        f4: F4,
+        f5: F5,
+        f6: F6,
+    ) -> Result<T, E>
+    where
+        F1: FnOnce(T1) -> Result<T, E>,
+        F2: FnOnce(T2) -> Result<T, E>,
+        F3: FnOnce(T3) -> Result<T, E>,
+        F4: FnOnce(T4) -> Result<T, E>,
+        F5: FnOnce(T5) -> Result<T, E>,
+        F6: FnOnce(T6) -> Result<T, E>,
+    {
+        match self {
+            Self::T1(t1) => f1(t1),
+            Self::T2(t2) => f2(t2),
+            Self::T3(t3) => f3(t3),
+            Self::T4(t4) => f4(t4),
+            Self::T5(t5) => f5(t5),
+            Self::T6(t6) => f6(t6),
+        }
+    }
+
+    /// Consolidates the `Or6` enum into a single value of type `T`,
+    /// by applying provided async functions and awaiting the result.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn fold_async<T, F1, Fut1, F2, Fut2, F3, Fut3, F4, Fut4, F5, Fut5, F6, Fut6>(
+        self,
+        f1: F1,
+        f2: F2,
+        f3: F3,
+        f4: F4,
+        f5: F5,
+        f6: F6,
+    ) -> T
+    where
+        F1: FnOnce(T1) -> Fut1,
+        Fut1: Future<Output = T>,
+        F2: FnOnce(T2) -> Fut2,
+        Fut2: Future<Output = T>,
+        F3: FnOnce(T3) -> Fut3,
+        Fut3: Future<Output = T>,
+        F4: FnOnce(T4) -> Fut4,
+        Fut4: Future<Output = T>,
+        F5: FnOnce(T5) -> Fut5,
+        Fut5: Future<Output = T>,
+        F6: FnOnce(T6) -> Fut6,
+        Fut6: Future<Output = T>,
+    {
+        match self {
+            Self::T1(t1) => f1(t1).await,
+            Self::T2(t2) => f2(t2).await,
+            Self::T3(t3) => f3(t3).await,
+            Self::T4(t4) => f4(t4).await,
+            Self::T5(t5) => f5(t5).await,
+            Self::T6(t6) => f6(t6).await,
+        }
+    }
+
+    /// Handles the T1 value of the enum using a provided function, and leaves the other
+    /// variants to the subsequent `when_tx` calls.
+    pub fn when_t1<R, F>(self, f: F) -> When<R, Or6<Infallible, T2, T3, T4, T5, T6>>
+    where
+        F: FnOnce(T1) -> R,
+    {
+        match self {
+            Self::T1(t1) => When::handled(f(t1)),
+            Self::T2(t2) => When::pending(Or6::T2(t2)),
+            Self::T3(t3) => When::pending(Or6::T3(t3)),
+            Self::T4(t4) => When::pending(Or6::T4(t4)),
+            Self::T5(t5) => When::pending(Or6::T5(t5)),
+            Self::T6(t6) => When::pending(Or6::T6(t6)),
+        }
+    }
+
+    /// Handles the T2 value of the enum using a provided function, and leaves the other
+    /// variants to the subsequent `when_tx` calls.
+    pub fn when_t2<R, F>(self, f: F) -> When<R, Or6<T1, Infallible, T3, T4, T5, T6>>
+    where
+        F: FnOnce(T2) -> R,
+    {
+        match self {
+            Self::T1(t1) => When::pending(Or6::T1(t1)),
+            Self::T2(t2) => When::handled(f(t2)),
+            Self::T3(t3) => When::pending(Or6::T3(t3)),
+            Self::T4(t4) => When::pending(Or6::T4(t4)),
+            Self::T5(t5) => When::pending(Or6::T5(t5)),
+            Self::T6(t6) => When::pending(Or6::T6(t6)),
+        }
+    }
+
+    /// Handles the T3 value of the enum using a provided function, and leaves the other
+    /// variants to the subsequent `when_tx` calls.
+    pub fn when_t3<R, F>(self, f: F) -> When<R, Or6<T1, T2, Infallible, T4, T5, T6>>
+    where
+        F: FnOnce(T3) -> R,
+    {
+        match self {
+            Self::T1(t1) => When::pending(Or6::T1(t1)),
+            Self::T2(t2) => When::pending(Or6::T2(t2)),
+            Self::T3(t3) => When::handled(f(t3)),
+            Self::T4(t4) => When::pending(Or6::T4(t4)),
+            Self::T5(t5) => When::pending(Or6::T5(t5)),
+            Self::T6(t6) => When::pending(Or6::T6(t6)),
+        }
+    }
+
+    /// Handles the T4 value of the enum using a provided function, and leaves the other
+    /// variants to the subsequent `when_tx` calls.
+    pub fn when_t4<R, F>(self, f: F) -> When<R, Or6<T1, T2, T3, Infallible, T5, T6>>
+    where
+        F: FnOnce(T4) -> R,
+    {
+        match self {
+            Self::T1(t1) => When::pending(Or6::T1(t1)),
+            Self::T2(t2) => When::pending(Or6::T2(t2)),
+            Self::T3(t3) => When::pending(Or6::T3(t3)),
+            Self::T4(t4) => When::handled(f(t4)),
+            Self::T5(t5) => When::pending(Or6::T5(t5)),
+            Self::T6(t6) => When::pending(Or6::T6(t6)),
+        }
+    }
+
+    /// Handles the T5 value of the enum using a provided function, and leaves the other
+    /// variants to the subsequent `when_tx` calls.
+    pub fn when_t5<R, F>(self, f: F) -> When<R, Or6<T1, T2, T3, T4, Infallible, T6>>
+    where
+        F: FnOnce(T5) -> R,
+    {
+        match self {
+            Self::T1(t1) => When::pending(Or6::T1(t1)),
+            Self::T2(t2) => When::pending(Or6::T2(t2)),
+            Self::T3(t3) => When::pending(Or6::T3(t3)),
+            Self::T4(t4) => When::pending(Or6::T4(t4)),
+            Self::T5(t5) => When::handled(f(t5)),
+            Self::T6(t6) => When::pending(Or6::T6(t6)),
+        }
+    }
+
+    /// Handles the T6 value of the enum using a provided function, and leaves the other
+    /// variants to the subsequent `when_tx` calls.
+    pub fn when_t6<R, F>(self, f: F) -> When<R, Or6<T1, T2, T3, T4, T5, Infallible>>
+    where
+        F: FnOnce(T6) -> R,
+    {
+        match self {
+            Self::T1(t1) => When::pending(Or6::T1(t1)),
+            Self::T2(t2) => When::pending(Or6::T2(t2)),
+            Self::T3(t3) => When::pending(Or6::T3(t3)),
+            Self::T4(t4) => When::pending(Or6::T4(t4)),
+            Self::T5(t5) => When::pending(Or6::T5(t5)),
+            Self::T6(t6) => When::handled(f(t6)),
+        }
+    }
+
+    /// Converts the enum into a tuple of Options, where only the slot of the active variant is `Some`.
+    #[must_use]
+    pub fn into_options(
+        self,
+    ) -> (
+        Option<T1>,
+        Option<T2>,
+        Option<T3>,
+        Option<T4>,
+        Option<T5>,
+        Option<T6>,
+    ) {
+        match self {
+            Self::T1(t1) => (Some(t1), None, None, None, None, None),
+            Self::T2(t2) => (None, Some(t2), None, None, None, None),
+            Self::T3(t3) => (None, None, Some(t3), None, None, None),
+            Self::T4(t4) => (None, None, None, Some(t4), None, None),
+            Self::T5(t5) => (None, None, None, None, Some(t5), None),
+            Self::T6(t6) => (None, None, None, None, None, Some(t6)),
+        }
+    }
+
+    /// Borrows the enum as a tuple of Options, where only the slot of the active variant is `Some`.
+    #[must_use]
+    pub fn as_options(
+        &self,
+    ) -> (
+        Option<&T1>,
+        Option<&T2>,
+        Option<&T3>,
+        Option<&T4>,
+        Option<&T5>,
+        Option<&T6>,
+    ) {
+        self.as_ref().into_options()
+    }
+
+    /// Builds the enum from a tuple of Options, which succeeds only when exactly one of them is `Some`.
+    /// Otherwise the tuple is handed back as is.
+    pub fn try_from_options(
+        options: (
+            Option<T1>,
+            Option<T2>,
+            Option<T3>,
+            Option<T4>,
+            Option<T5>,
+            Option<T6>,
+        ),
+    ) -> Result<
+        Self,
+        (
+            Option<T1>,
+            Option<T2>,
+            Option<T3>,
+            Option<T4>,
+            Option<T5>,
+            Option<T6>,
+        ),
+    > {
+        match options {
+            (Some(t1), None, None, None, None, None) => Ok(Self::T1(t1)),
+            (None, Some(t2), None, None, None, None) => Ok(Self::T2(t2)),
+            (None, None, Some(t3), None, None, None) => Ok(Self::T3(t3)),
+            (None, None, None, Some(t4), None, None) => Ok(Self::T4(t4)),
+            (None, None, None, None, Some(t5), None) => Ok(Self::T5(t5)),
+            (None, None, None, None, None, Some(t6)) => Ok(Self::T6(t6)),
+            options => Err(options),
+        }
+    }
+}
+
+impl_forwarding!(Or6, Or6Items, Or6Discriminant {
+    T1(t1, e1, I1, i1, E1, 0, T1),
+    T2(t2, e2, I2, i2, E2, 1, T2),
+    T3(t3, e3, I3, i3, E3, 2, T3),
+    T4(t4, e4, I4, i4, E4, 3, T4),
+    T5(t5, e5, I5, i5, E5, 4, T5),
+    T6(t6, e6, I6, i6, E6, 5, T6)
+});
+
+impl<T1, T2, T3, T4, T5, T6> Or6<T1, T2, T3, T4, T5, T6> {
+    /// The number of the variants, which is the size of the arrays returned by
+    /// [`Or6::variant_sizes`] and friends, indexed by [`Or6::variant_index`].
+    pub const VARIANT_COUNT: usize = 6;
+
+    /// Returns the 0-based position of the active variant, like `0` for `T1`.
+    ///
+    /// This can index a table of handlers or counters, with `VARIANT_COUNT` entries.
+    #[must_use]
+    pub const fn variant_index(&self) -> usize {
+        match self {
+            Self::T1(_) => 0,
+            Self::T2(_) => 1,
+            Self::T3(_) => 2,
+            Self::T4(_) => 3,
+            Self::T5(_) => 4,
+            Self::T6(_) => 5,
+        }
+    }
+
+    /// Returns the sizes of the payload types, in the order of the variants.
+    ///
+    /// Comparing them with the size of the enum tells whether one oversized variant bloats
+    /// the whole enum, in which case it can be shrunk by [`Or6::boxed`].
+    pub const fn variant_sizes() -> [usize; 6] {
+        [
+            size_of::<T1>(),
+            size_of::<T2>(),
+            size_of::<T3>(),
+            size_of::<T4>(),
+            size_of::<T5>(),
+            size_of::<T6>(),
+        ]
+    }
+
+    /// Returns the alignments of the payload types, in the order of the variants.
+    pub const fn variant_aligns() -> [usize; 6] {
+        [
+            align_of::<T1>(),
+            align_of::<T2>(),
+            align_of::<T3>(),
+            align_of::<T4>(),
+            align_of::<T5>(),
+            align_of::<T6>(),
+        ]
+    }
+
+    /// Returns the size of the largest payload type, which the size of the enum is at least.
+    pub const fn max_variant_size() -> usize {
+        let sizes = Self::variant_sizes();
+        let mut max = 0;
+        let mut i = 0;
+        while i < sizes.len() {
+            if sizes[i] > max {
+                max = sizes[i];
+            }
+            i += 1;
+        }
+        max
+    }
+}
+
+impl<T2, T3, T4, T5, T6> Or6<Infallible, T2, T3, T4, T5, T6> {
+    /// Removes the uninhabited `T1` position from `Or6`, which is useful to simplify
+    /// an `Or` type once some of its branches become impossible.
+    #[must_use]
+    pub fn compact_t1(self) -> Or5<T2, T3, T4, T5, T6> {
+        match self {
+            Self::T1(never) => match never {},
+            Self::T2(t2) => Or5::T1(t2),
+            Self::T3(t3) => Or5::T2(t3),
+            Self::T4(t4) => Or5::T3(t4),
+            Self::T5(t5) => Or5::T4(t5),
+            Self::T6(t6) => Or5::T5(t6),
+        }
+    }
+}
+
+impl<T1, T3, T4, T5, T6> Or6<T1, Infallible, T3, T4, T5, T6> {
+    /// Removes the uninhabited `T2` position from `Or6`, which is useful to simplify
+    /// an `Or` type once some of its branches become impossible.
+    #[must_use]
+    pub fn compact_t2(self) -> Or5<T1, T3, T4, T5, T6> {
+        match self {
+            Self::T1(t1) => Or5::T1(t1),
+            Self::T2(never) => match never {},
+            Self::T3(t3) => Or5::T2(t3),
+            Self::T4(t4) => Or5::T3(t4),
+            Self::T5(t5) => Or5::T4(t5),
+            Self::T6(t6) => Or5::T5(t6),
+        }
+    }
+}
+
+impl<T1, T2, T4, T5, T6> Or6<T1, T2, Infallible, T4, T5, T6> {
+    /// Removes the uninhabited `T3` position from `Or6`, which is useful to simplify
+    /// an `Or` type once some of its branches become impossible.
+    #[must_use]
+    pub fn compact_t3(self) -> Or5<T1, T2, T4, T5, T6> {
+        match self {
+            Self::T1(t1) => Or5::T1(t1),
+            Self::T2(t2) => Or5::T2(t2),
+            Self::T3(never) => match never {},
+            Self::T4(t4) => Or5::T3(t4),
+            Self::T5(t5) => Or5::T4(t5),
+            Self::T6(t6) => Or5::T5(t6),
+        }
+    }
+}
+
+impl<T1, T2, T3, T5, T6> Or6<T1, T2, T3, Infallible, T5, T6> {
+    /// Removes the uninhabited `T4` position from `Or6`, which is useful to simplify
+    /// an `Or` type once some of its branches become impossible.
+    #[must_use]
+    pub fn compact_t4(self) -> Or5<T1, T2, T3, T5, T6> {
+        match self {
+            Self::T1(t1) => Or5::T1(t1),
+            Self::T2(t2) => Or5::T2(t2),
+            Self::T3(t3) => Or5::T3(t3),
+            Self::T4(never) => match never {},
+            Self::T5(t5) => Or5::T4(t5),
+            Self::T6(t6) => Or5::T5(t6),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T6> Or6<T1, T2, T3, T4, Infallible, T6> {
+    /// Removes the uninhabited `T5` position from `Or6`, which is useful to simplify
+    /// an `Or` type once some of its branches become impossible.
+    #[must_use]
+    pub fn compact_t5(self) -> Or5<T1, T2, T3, T4, T6> {
+        match self {
+            Self::T1(t1) => Or5::T1(t1),
+            Self::T2(t2) => Or5::T2(t2),
+            Self::T3(t3) => Or5::T3(t3),
+            Self::T4(t4) => Or5::T4(t4),
+            Self::T5(never) => match never {},
+            Self::T6(t6) => Or5::T5(t6),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5> Or6<T1, T2, T3, T4, T5, Infallible> {
+    /// Removes the uninhabited `T6` position from `Or6`, which is useful to simplify
+    /// an `Or` type once some of its branches become impossible.
+    #[must_use]
+    pub fn compact_t6(self) -> Or5<T1, T2, T3, T4, T5> {
+        match self {
+            Self::T1(t1) => Or5::T1(t1),
+            Self::T2(t2) => Or5::T2(t2),
+            Self::T3(t3) => Or5::T3(t3),
+            Self::T4(t4) => Or5::T4(t4),
+            Self::T5(t5) => Or5::T5(t5),
+            Self::T6(never) => match never {},
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6> Or6<T1, T2, T3, T4, T5, T6> {
+    /// Converts the enum into a `Result`, treating `T1` as the ok value and the others as the
+    /// error. The conversion is lossless, and can be reverted by `From`.
+    pub fn into_result(self) -> Result<T1, Or5<T2, T3, T4, T5, T6>> {
+        match self {
+            Self::T1(t1) => Ok(t1),
+            Self::T2(t2) => Err(Or5::T1(t2)),
+            Self::T3(t3) => Err(Or5::T2(t3)),
+            Self::T4(t4) => Err(Or5::T3(t4)),
+            Self::T5(t5) => Err(Or5::T4(t5)),
+            Self::T6(t6) => Err(Or5::T5(t6)),
+        }
+    }
+
+    /// Converts the enum into a `Result`, treating `T1` as the ok value and converting the
+    /// others into the error type `E`.
+    pub fn try_into_result<E>(self) -> Result<T1, E>
+    where
+        T2: Into<E>,
+        T3: Into<E>,
+        T4: Into<E>,
+        T5: Into<E>,
+        T6: Into<E>,
+    {
+        match self {
+            Self::T1(t1) => Ok(t1),
+            Self::T2(t2) => Err(t2.into()),
+            Self::T3(t3) => Err(t3.into()),
+            Self::T4(t4) => Err(t4.into()),
+            Self::T5(t5) => Err(t5.into()),
+            Self::T6(t6) => Err(t6.into()),
+        }
+    }
+}
+
+/// Converts the ok value into `T1` and the error into the remaining positions, which reverts
+/// `into_result`.
+impl<T1, T2, T3, T4, T5, T6> From<Result<T1, Or5<T2, T3, T4, T5, T6>>>
+    for Or6<T1, T2, T3, T4, T5, T6>
+{
+    fn from(result: Result<T1, Or5<T2, T3, T4, T5, T6>>) -> Self {
+        match result {
+            Ok(t1) => Self::T1(t1),
+            Err(Or5::T1(t2)) => Self::T2(t2),
+            Err(Or5::T2(t3)) => Self::T3(t3),
+            Err(Or5::T3(t4)) => Self::T4(t4),
+            Err(Or5::T4(t5)) => Self::T5(t5),
+            Err(Or5::T5(t6)) => Self::T6(t6),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5> Or6<T1, T2, T3, T4, T5, ()> {
+    /// Converts the enum into an `Option`, which is `None` for the `()` in the last position.
+    /// The conversion is lossless, and can be reverted by `From`.
+    pub fn into_option(self) -> Option<Or5<T1, T2, T3, T4, T5>> {
+        match self {
+            Self::T1(t1) => Some(Or5::T1(t1)),
+            Self::T2(t2) => Some(Or5::T2(t2)),
+            Self::T3(t3) => Some(Or5::T3(t3)),
+            Self::T4(t4) => Some(Or5::T4(t4)),
+            Self::T5(t5) => Some(Or5::T5(t5)),
+            Self::T6(()) => None,
+        }
+    }
+}
+
+/// Converts `None` into the `()` in the last position, which reverts `into_option`.
+impl<T1, T2, T3, T4, T5> From<Option<Or5<T1, T2, T3, T4, T5>>> for Or6<T1, T2, T3, T4, T5, ()> {
+    fn from(option: Option<Or5<T1, T2, T3, T4, T5>>) -> Self {
+        match option {
+            Some(Or5::T1(t1)) => Self::T1(t1),
+            Some(Or5::T2(t2)) => Self::T2(t2),
+            Some(Or5::T3(t3)) => Self::T3(t3),
+            Some(Or5::T4(t4)) => Self::T4(t4),
+            Some(Or5::T5(t5)) => Self::T5(t5),
+            None => Self::T6(()),
+        }
+    }
+}
+
+impl<R, T1, T2, T3, T4, T5, T6> When<R, Or6<T1, T2, T3, T4, T5, T6>> {
+    /// Handles the T1 value of the pending `Or6` using a provided function,
+    /// unless a previous `when_tx` has already handled the value.
+    pub fn when_t1<F>(self, f: F) -> When<R, Or6<Infallible, T2, T3, T4, T5, T6>>
+    where
+        F: FnOnce(T1) -> R,
+    {
+        match self.into_state() {
+            Ok(result) => When::handled(result),
+            Err(or) => or.when_t1(f),
+        }
+    }
+
+    /// Handles the T2 value of the pending `Or6` using a provided function,
+    /// unless a previous `when_tx` has already handled the value.
+    pub fn when_t2<F>(self, f: F) -> When<R, Or6<T1, Infallible, T3, T4, T5, T6>>
+    where
+        F: FnOnce(T2) -> R,
+    {
+        match self.into_state() {
+            Ok(result) => When::handled(result),
+            Err(or) => or.when_t2(f),
+        }
+    }
+
+    /// Handles the T3 value of the pending `Or6` using a provided function,
+    /// unless a previous `when_tx` has already handled the value.
+    pub fn when_t3<F>(self, f: F) -> When<R, Or6<T1, T2, Infallible, T4, T5, T6>>
+    where
+        F: FnOnce(T3) -> R,
+    {
+        match self.into_state() {
+            Ok(result) => When::handled(result),
+            Err(or) => or.when_t3(f),
+        }
+    }
+
+    /// Handles the T4 value of the pending `Or6` using a provided function,
+    /// unless a previous `when_tx` has already handled the value.
+    pub fn when_t4<F>(self, f: F) -> When<R, Or6<T1, T2, T3, Infallible, T5, T6>>
+    where
+        F: FnOnce(T4) -> R,
+    {
+        match self.into_state() {
+            Ok(result) => When::handled(result),
+            Err(or) => or.when_t4(f),
+        }
+    }
+
+    /// Handles the T5 value of the pending `Or6` using a provided function,
+    /// unless a previous `when_tx` has already handled the value.
+    pub fn when_t5<F>(self, f: F) -> When<R, Or6<T1, T2, T3, T4, Infallible, T6>>
+    where
+        F: FnOnce(T5) -> R,
+    {
+        match self.into_state() {
+            Ok(result) => When::handled(result),
+            Err(or) => or.when_t5(f),
+        }
+    }
+
+    /// Handles the T6 value of the pending `Or6` using a provided function,
+    /// unless a previous `when_tx` has already handled the value.
+    pub fn when_t6<F>(self, f: F) -> When<R, Or6<T1, T2, T3, T4, T5, Infallible>>
+    where
+        F: FnOnce(T6) -> R,
+    {
+        match self.into_state() {
+            Ok(result) => When::handled(result),
+            Err(or) => or.when_t6(f),
+        }
+    }
+}
+
+impl<R> When<R, Or6<Infallible, Infallible, Infallible, Infallible, Infallible, Infallible>> {
+    /// Finishes the chain and returns the result. This is available only when every
+    /// variant of `Or6` has been handled.
+    pub fn finish(self) -> R {
+        match self.into_state() {
+            Ok(result) => result,
+            Err(or) => match or {
+                Or6::T1(never) => match never {},
+                Or6::T2(never) => match never {},
+                Or6::T3(never) => match never {},
+                Or6::T4(never) => match never {},
+                Or6::T5(never) => match never {},
+                Or6::T6(never) => match never {},
+            },
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7, T8> Concat<Or2<T7, T8>> for Or6<T1, T2, T3, T4, T5, T6> {
+    type Output = Or8<T1, T2, T3, T4, T5, T6, T7, T8>;
+
+    fn left(lhs: Self) -> Self::Output {
+        match lhs {
+            Or6::T1(x) => Or8::T1(x),
+            Or6::T2(x) => Or8::T2(x),
+            Or6::T3(x) => Or8::T3(x),
+            Or6::T4(x) => Or8::T4(x),
+            Or6::T5(x) => Or8::T5(x),
+            Or6::T6(x) => Or8::T6(x),
+        }
+    }
+
+    fn right(rhs: Or2<T7, T8>) -> Self::Output {
+        match rhs {
+            Or2::T1(x) => Or8::T7(x),
+            Or2::T2(x) => Or8::T8(x),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7, T8, T9> Concat<Or3<T7, T8, T9>> for Or6<T1, T2, T3, T4, T5, T6> {
+    type Output = Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>;
+
+    fn left(lhs: Self) -> Self::Output {
+        match lhs {
+            Or6::T1(x) => Or9::T1(x),
+            Or6::T2(x) => Or9::T2(x),
+            Or6::T3(x) => Or9::T3(x),
+            Or6::T4(x) => Or9::T4(x),
+            Or6::T5(x) => Or9::T5(x),
+            Or6::T6(x) => Or9::T6(x),
+        }
+    }
+
+    fn right(rhs: Or3<T7, T8, T9>) -> Self::Output {
+        match rhs {
+            Or3::T1(x) => Or9::T7(x),
+            Or3::T2(x) => Or9::T8(x),
+            Or3::T3(x) => Or9::T9(x),
+        }
+    }
+}
+
+/// `Or7` is an enum representing a value that can be either of 7 types, T1 ... T7.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(untagged)
+)]
+#[cfg_attr(feature = "repr-c", repr(C))]
+#[cfg_attr(feature = "repr-u8", repr(u8))]
+pub enum Or7<T1, T2, T3, T4, T5, T6, T7> {
+    T1(T1),
+    T2(T2),
+    T3(T3),
+    T4(T4),
+    T5(T5),
+    T6(T6),
+    T7(T7),
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7> Or7<T1, T2, T3, T4, T5, T6, T7> {
+    /// Returns true if the enum is of type T1.
+    #[must_use]
+    pub fn is_t1(&self) -> bool {
+        matches!(self, Self::T1(_))
+    }
+
+    /// Returns true if the enum is of type T2.
+    #[must_use]
+    pub fn is_t2(&self) -> bool {
+        matches!(self, Self::T2(_))
+    }
+
+    /// Returns true if the enum is of type T3.
+    #[must_use]
+    pub fn is_t3(&self) -> bool {
+        matches!(self, Self::T3(_))
+    }
+
+    /// Returns true if the enum is of type T4.
+    #[must_use]
+    pub fn is_t4(&self) -> bool {
+        matches!(self, Self::T4(_))
+    }
+
+    /// Returns true if the enum is of type T5.
+    #[must_use]
+    pub fn is_t5(&self) -> bool {
+        matches!(self, Self::T5(_))
+    }
+
+    /// Returns true if the enum is of type T6.
+    #[must_use]
+    pub fn is_t6(&self) -> bool {
+        matches!(self, Self::T6(_))
+    }
+
+    /// Returns true if the enum is of type T7.
+    #[must_use]
+    pub fn is_t7(&self) -> bool {
+        matches!(self, Self::T7(_))
+    }
+
+    /// Converts the enum to an Option containing the T1 value, if it is of type T1.
+    #[must_use]
+    pub fn as_t1(self) -> Option<T1> {
+        match self {
+            Self::T1(t1) => Some(t1),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the T1 value, if it is of type T1, without consuming the enum.
+    #[must_use]
+    pub fn as_t1_ref(&self) -> Option<&T1> {
+        match self {
+            Self::T1(t1) => Some(t1),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the T1 value, if it is of type T1.
+    #[must_use]
+    pub fn as_t1_mut(&mut self) -> Option<&mut T1> {
+        match self {
+            Self::T1(t1) => Some(t1),
+            _ => None,
+        }
+    }
+
+    /// Converts the enum to an Option containing the T2 value, if it is of type T2.
+    #[must_use]
+    pub fn as_t2(self) -> Option<T2> {
+        match self {
+            Self::T2(t2) => Some(t2),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the T2 value, if it is of type T2, without consuming the enum.
+    #[must_use]
+    pub fn as_t2_ref(&self) -> Option<&T2> {
+        match self {
+            Self::T2(t2) => Some(t2),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the T2 value, if it is of type T2.
+    #[must_use]
+    pub fn as_t2_mut(&mut self) -> Option<&mut T2> {
+        match self {
+            Self::T2(t2) => Some(t2),
+            _ => None,
+        }
+    }
+
+    /// Converts the enum to an Option containing the T3 value, if it is of type T3.
+    #[must_use]
+    pub fn as_t3(self) -> Option<T3> {
+        match self {
+            Self::T3(t3) => Some(t3),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the T3 value, if it is of type T3, without consuming the enum.
+    #[must_use]
+    pub fn as_t3_ref(&self) -> Option<&T3> {
+        match self {
+            Self::T3(t3) => Some(t3),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the T3 value, if it is of type T3.
+    #[must_use]
+    pub fn as_t3_mut(&mut self) -> Option<&mut T3> {
+        match self {
+            Self::T3(t3) => Some(t3),
+            _ => None,
+        }
+    }
+
+    /// Converts the enum to an Option containing the T4 value, if it is of type T4.
+    #[must_use]
+    pub fn as_t4(self) -> Option<T4> {
+        match self {
+            Self::T4(t4) => Some(t4),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the T4 value, if it is of type T4, without consuming the enum.
+    #[must_use]
+    pub fn as_t4_ref(&self) -> Option<&T4> {
+        match self {
+            Self::T4(t4) => Some(t4),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the T4 value, if it is of type T4.
+    #[must_use]
+    pub fn as_t4_mut(&mut self) -> Option<&mut T4> {
+        match self {
+            Self::T4(t4) => Some(t4),
+            _ => None,
+        }
+    }
+
+    /// Converts the enum to an Option containing the T5 value, if it is of type T5.
+    #[must_use]
+    pub fn as_t5(self) -> Option<T5> {
+        match self {
+            Self::T5(t5) => Some(t5),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the T5 value, if it is of type T5, without consuming the enum.
+    #[must_use]
+    pub fn as_t5_ref(&self) -> Option<&T5> {
+        match self {
+            Self::T5(t5) => Some(t5),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the T5 value, if it is of type T5.
+    #[must_use]
+    pub fn as_t5_mut(&mut self) -> Option<&mut T5> {
+        match self {
+            Self::T5(t5) => Some(t5),
+            _ => None,
+        }
+    }
+
+    /// Converts the enum to an Option containing the T6 value, if it is of type T6.
+    #[must_use]
+    pub fn as_t6(self) -> Option<T6> {
+        match self {
+            Self::T6(t6) => Some(t6),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the T6 value, if it is of type T6, without consuming the enum.
+    #[must_use]
+    pub fn as_t6_ref(&self) -> Option<&T6> {
+        match self {
+            Self::T6(t6) => Some(t6),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the T6 value, if it is of type T6.
+    #[must_use]
+    pub fn as_t6_mut(&mut self) -> Option<&mut T6> {
+        match self {
+            Self::T6(t6) => Some(t6),
+            _ => None,
+        }
+    }
+
+    /// Converts the enum to an Option containing the T7 value, if it is of type T7.
+    #[must_use]
+    pub fn as_t7(self) -> Option<T7> {
+        match self {
+            Self::T7(t7) => Some(t7),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the T7 value, if it is of type T7, without consuming the enum.
+    #[must_use]
+    pub fn as_t7_ref(&self) -> Option<&T7> {
+        match self {
+            Self::T7(t7) => Some(t7),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the T7 value, if it is of type T7.
+    #[must_use]
+    pub fn as_t7_mut(&mut self) -> Option<&mut T7> {
+        match self {
+            Self::T7(t7) => Some(t7),
+            _ => None,
+        }
+    }
+
+    /// Returns the T1 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum is not of type T1, naming the variant it is of.
+    #[track_caller]
+    pub fn unwrap_t1(self) -> T1 {
+        match self {
+            Self::T1(t1) => t1,
+            _ => panic!(
+                "called `Or7::unwrap_t1()` on a `{:?}` value",
+                self.discriminant()
+            ),
+        }
+    }
+
+    /// Returns the T1 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the enum is not of type T1.
+    #[track_caller]
+    pub fn expect_t1(self, msg: &str) -> T1 {
+        match self {
+            Self::T1(t1) => t1,
+            _ => panic!("{}", msg),
+        }
+    }
+
+    /// Returns the T2 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum is not of type T2, naming the variant it is of.
+    #[track_caller]
+    pub fn unwrap_t2(self) -> T2 {
+        match self {
+            Self::T2(t2) => t2,
+            _ => panic!(
+                "called `Or7::unwrap_t2()` on a `{:?}` value",
+                self.discriminant()
+            ),
+        }
+    }
+
+    /// Returns the T2 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the enum is not of type T2.
+    #[track_caller]
+    pub fn expect_t2(self, msg: &str) -> T2 {
+        match self {
+            Self::T2(t2) => t2,
+            _ => panic!("{}", msg),
+        }
+    }
+
+    /// Returns the T3 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum is not of type T3, naming the variant it is of.
+    #[track_caller]
+    pub fn unwrap_t3(self) -> T3 {
+        match self {
+            Self::T3(t3) => t3,
+            _ => panic!(
+                "called `Or7::unwrap_t3()` on a `{:?}` value",
+                self.discriminant()
+            ),
+        }
+    }
+
+    /// Returns the T3 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the enum is not of type T3.
+    #[track_caller]
+    pub fn expect_t3(self, msg: &str) -> T3 {
+        match self {
+            Self::T3(t3) => t3,
+            _ => panic!("{}", msg),
+        }
+    }
+
+    /// Returns the T4 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum is not of type T4, naming the variant it is of.
+    #[track_caller]
+    pub fn unwrap_t4(self) -> T4 {
+        match self {
+            Self::T4(t4) => t4,
+            _ => panic!(
+                "called `Or7::unwrap_t4()` on a `{:?}` value",
+                self.discriminant()
+            ),
+        }
+    }
+
+    /// Returns the T4 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the enum is not of type T4.
+    #[track_caller]
+    pub fn expect_t4(self, msg: &str) -> T4 {
+        match self {
+            Self::T4(t4) => t4,
+            _ => panic!("{}", msg),
+        }
+    }
+
+    /// Returns the T5 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum is not of type T5, naming the variant it is of.
+    #[track_caller]
+    pub fn unwrap_t5(self) -> T5 {
+        match self {
+            Self::T5(t5) => t5,
+            _ => panic!(
+                "called `Or7::unwrap_t5()` on a `{:?}` value",
+                self.discriminant()
+            ),
+        }
+    }
+
+    /// Returns the T5 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the enum is not of type T5.
+    #[track_caller]
+    pub fn expect_t5(self, msg: &str) -> T5 {
+        match self {
+            Self::T5(t5) => t5,
+            _ => panic!("{}", msg),
+        }
+    }
+
+    /// Returns the T6 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum is not of type T6, naming the variant it is of.
+    #[track_caller]
+    pub fn unwrap_t6(self) -> T6 {
+        match self {
+            Self::T6(t6) => t6,
+            _ => panic!(
+                "called `Or7::unwrap_t6()` on a `{:?}` value",
+                self.discriminant()
+            ),
+        }
+    }
+
+    /// Returns the T6 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the enum is not of type T6.
+    #[track_caller]
+    pub fn expect_t6(self, msg: &str) -> T6 {
+        match self {
+            Self::T6(t6) => t6,
+            _ => panic!("{}", msg),
+        }
+    }
+
+    /// Returns the T7 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum is not of type T7, naming the variant it is of.
+    #[track_caller]
+    pub fn unwrap_t7(self) -> T7 {
+        match self {
+            Self::T7(t7) => t7,
+            _ => panic!(
+                "called `Or7::unwrap_t7()` on a `{:?}` value",
+                self.discriminant()
+            ),
+        }
+    }
+
+    /// Returns the T7 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the enum is not of type T7.
+    #[track_caller]
+    pub fn expect_t7(self, msg: &str) -> T7 {
+        match self {
+            Self::T7(t7) => t7,
+            _ => panic!("{}", msg),
+        }
+    }
+
+    /// Converts from `&Or7<T1, ...>` to `Or7<&T1, ...>`.
+    #[must_use]
+    pub fn as_ref(&self) -> Or7<&T1, &T2, &T3, &T4, &T5, &T6, &T7> {
+        match self {
+            Self::T1(t1) => Or7::T1(t1),
+            Self::T2(t2) => Or7::T2(t2),
+            Self::T3(t3) => Or7::T3(t3),
+            Self::T4(t4) => Or7::T4(t4),
+            Self::T5(t5) => Or7::T5(t5),
+            Self::T6(t6) => Or7::T6(t6),
+            Self::T7(t7) => Or7::T7(t7),
+        }
+    }
+
+    /// Converts from `&mut Or7<T1, ...>` to `Or7<&mut T1, ...>`.
+    #[must_use]
+    pub fn as_mut(&mut self) -> Or7<&mut T1, &mut T2, &mut T3, &mut T4, &mut T5, &mut T6, &mut T7> {
+        match self {
+            Self::T1(t1) => Or7::T1(t1),
+            Self::T2(t2) => Or7::T2(t2),
+            Self::T3(t3) => Or7::T3(t3),
+            Self::T4(t4) => Or7::T4(t4),
+            Self::T5(t5) => Or7::T5(t5),
+            Self::T6(t6) => Or7::T6(t6),
+            Self::T7(t7) => Or7::T7(t7),
+        }
+    }
+
+    /// Transforms the T1 value of the enum using a provided function,
+    /// maintaining other types as is.
+    #[must_use = "this returns the mapped enum, without modifying the original"]
+    pub fn map_t1<F, B>(self, f: F) -> Or7<B, T2, T3, T4, T5, T6, T7>
+    where
+        F: FnOnce(T1) -> B,
+    {
+        match self {
+            Self::T1(t1) => Or7::<B, T2, T3, T4, T5, T6, T7>::T1(f(t1)),
+            Self::T2(t2) => Or7::<B, T2, T3, T4, T5, T6, T7>::T2(t2),
+            Self::T3(t3) => Or7::<B, T2, T3, T4, T5, T6, T7>::T3(t3),
+            Self::T4(t4) => Or7::<B, T2, T3, T4, T5, T6, T7>::T4(t4),
+            Self::T5(t5) => Or7::<B, T2, T3, T4, T5, T6, T7>::T5(t5),
+            Self::T6(t6) => Or7::<B, T2, T3, T4, T5, T6, T7>::T6(t6),
+            Self::T7(t7) => Or7::<B, T2, T3, T4, T5, T6, T7>::T7(t7),
+        }
+    }
+
+    /// Transforms the T2 value of the enum using a provided function,
+    /// maintaining other types as is.
+    #[must_use = "this returns the mapped enum, without modifying the original"]
+    pub fn map_t2<F, B>(self, f: F) -> Or7<T1, B, T3, T4, T5, T6, T7>
+    where
+        F: FnOnce(T2) -> B,
+    {
+        match self {
+            Self::T1(t1) => Or7::<T1, B, T3, T4, T5, T6, T7>::T1(t1),
+            Self::T2(t2) => Or7::<T1, B, T3, T4, T5, T6, T7>::T2(f(t2)),
+            Self::T3(t3) => Or7::<T1, B, T3, T4, T5, T6, T7>::T3(t3),
+            Self::T4(t4) => Or7::<T1, B, T3, T4, T5, T6, T7>::T4(t4),
+            Self::T5(t5) => Or7::<T1, B, T3, T4, T5, T6, T7>::T5(t5),
+            Self::T6(t6) => Or7::<T1, B, T3, T4, T5, T6, T7>::T6(t6),
+            Self::T7(t7) => Or7::<T1, B, T3, T4, T5, T6, T7>::T7(t7),
+        }
+    }
+
+    /// Transforms the T3 value of the enum using a provided function,
+    /// maintaining other types as is.
+    #[must_use = "this returns the mapped enum, without modifying the original"]
+    pub fn map_t3<F, B>(self, f: F) -> Or7<T1, T2, B, T4, T5, T6, T7>
+    where
+        F: FnOnce(T3) -> B,
+    {
+        match self {
+            Self::T1(t1) => Or7::<T1, T2, B, T4, T5, T6, T7>::T1(t1),
+            Self::T2(t2) => Or7::<T1, T2, B, T4, T5, T6, T7>::T2(t2),
+            Self::T3(t3) => Or7::<T1, T2, B, T4, T5, T6, T7>::T3(f(t3)),
+            Self::T4(t4) => Or7::<T1, T2, B, T4, T5, T6, T7>::T4(t4),
+            Self::T5(t5) => Or7::<T1, T2, B, T4, T5, T6, T7>::T5(t5),
+            Self::T6(t6) => Or7::<T1, T2, B, T4, T5, T6, T7>::T6(t6),
+            Self::T7(t7) => Or7::<T1, T2, B, T4, T5, T6, T7>::T7(t7),
+        }
+    }
+
+    /// Transforms the T4 value of the enum using a provided function,
+    /// maintaining other types as is.
+    #[must_use = "this returns the mapped enum, without modifying the original"]
+    pub fn map_t4<F, B>(self, f: F) -> Or7<T1, T2, T3, B, T5, T6, T7>
+    where
+        F: FnOnce(T4) -> B,
+    {
+        match self {
+            Self::T1(t1) => Or7::<T1, T2, T3, B, T5, T6, T7>::T1(t1),
+            Self::T2(t2) => Or7::<T1, T2, T3, B, T5, T6, T7>::T2(t2),
+            Self::T3(t3) => Or7::<T1, T2, T3, B, T5, T6, T7>::T3(t3),
+            Self::T4(t4) => Or7::<T1, T2, T3, B, T5, T6, T7>::T4(f(t4)),
+            Self::T5(t5) => Or7::<T1, T2, T3, B, T5, T6, T7>::T5(t5),
+            Self::T6(t6) => Or7::<T1, T2, T3, B, T5, T6, T7>::T6(t6),
+            Self::T7(t7) => Or7::<T1, T2, T3, B, T5, T6, T7>::T7(t7),
+        }
+    }
+
+    /// Transforms the T5 value of the enum using a provided function,
+    /// maintaining other types as is.
+    #[must_use = "this returns the mapped enum, without modifying the original"]
+    pub fn map_t5<F, B>(self, f: F) -> Or7<T1, T2, T3, T4, B, T6, T7>
+    where
+        F: FnOnce(T5) -> B,
+    {
+        match self {
+            Self::T1(t1) => Or7::<T1, T2, T3, T4, B, T6, T7>::T1(t1),
+            Self::T2(t2) => Or7::<T1, T2, T3, T4, B, T6, T7>::T2(t2),
+            Self::T3(t3) => Or7::<T1, T2, T3, T4, B, T6, T7>::T3(t3),
+            Self::T4(t4) => Or7::<T1, T2, T3, T4, B, T6, T7>::T4(t4),
+            Self::T5(t5) => Or7::<T1, T2, T3, T4, B, T6, T7>::T5(f(t5)),
+            Self::T6(t6) => Or7::<T1, T2, T3, T4, B, T6, T7>::T6(t6),
+            Self::T7(t7) => Or7::<T1, T2, T3, T4, B, T6, T7>::T7(t7),
+        }
+    }
+
+    /// Transforms the T6 value of the enum using a provided function,
+    /// maintaining other types as is.
+    #[must_use = "this returns the mapped enum, without modifying the original"]
+    pub fn map_t6<F, B>(self, f: F) -> Or7<T1, T2, T3, T4, T5, B, T7>
+    where
+        F: FnOnce(T6) -> B,
+    {
+        match self {
+            Self::T1(t1) => Or7::<T1, T2, T3, T4, T5, B, T7>::T1(t1),
+            Self::T2(t2) => Or7::<T1, T2, T3, T4, T5, B, T7>::T2(t2),
+            Self::T3(t3) => Or7::<T1, T2, T3, T4, T5, B, T7>::T3(t3),
+            Self::T4(t4) => Or7::<T1, T2, T3, T4, T5, B, T7>::T4(t4),
+            Self::T5(t5) => Or7::<T1, T2, T3, T4, T5, B, T7>::T5(t5),
+            Self::T6(t6) => Or7::<T1, T2, T3, T4, T5, B, T7>::T6(f(t6)),
+            Self::T7(t7) => Or7::<T1, T2, T3, T4, T5, B, T7>::T7(t7),
+        }
+    }
+
+    /// Transforms the T7 value of the enum using a provided function,
+    /// maintaining other types as is.
+    #[must_use = "this returns the mapped enum, without modifying the original"]
+    pub fn map_t7<F, B>(self, f: F) -> Or7<T1, T2, T3, T4, T5, T6, B>
+    where
+        F: FnOnce(T7) -> B,
+    {
+        match self {
+            Self::T1(t1) => Or7::<T1, T2, T3, T4, T5, T6, B>::T1(t1),
+            Self::T2(t2) => Or7::<T1, T2, T3, T4, T5, T6, B>::T2(t2),
+            Self::T3(t3) => Or7::<T1, T2, T3, T4, T5, T6, B>::T3(t3),
+            Self::T4(t4) => Or7::<T1, T2, T3, T4, T5, T6, B>::T4(t4),
+            Self::T5(t5) => Or7::<T1, T2, T3, T4, T5, T6, B>::T5(t5),
+            Self::T6(t6) => Or7::<T1, T2, T3, T4, T5, T6, B>::T6(t6),
+            Self::T7(t7) => Or7::<T1, T2, T3, T4, T5, T6, B>::T7(f(t7)),
+        }
+    }
+
+    /// Transforms the T1 value of the enum using a provided async function and awaits it,
+    /// maintaining other types as is.
+    pub async fn map_t1_async<F, Fut, B>(self, f: F) -> Or7<B, T2, T3, T4, T5, T6, T7>
+    where
+        F: FnOnce(T1) -> Fut,
+        Fut: Future<Output = B>,
+    {
+        match self {
+            Self::T1(t1) => Or7::T1(f(t1).await),
+            Self::T2(t2) => Or7::T2(t2),
+            Self::T3(t3) => Or7::T3(t3),
+            Self::T4(t4) => Or7::T4(t4),
+            Self::T5(t5) => Or7::T5(t5),
+            Self::T6(t6) => Or7::T6(t6),
+            Self::T7(t7) => Or7::T7(t7),
+        }
+    }
+
+    /// Transforms the T2 value of the enum using a provided async function and awaits it,
+    /// maintaining other types as is.
+    pub async fn map_t2_async<F, Fut, B>(self, f: F) -> Or7<T1, B, T3, T4, T5, T6, T7>
+    where
+        F: FnOnce(T2) -> Fut,
+        Fut: Future<Output = B>,
+    {
+        match self {
+            Self::T1(t1) => Or7::T1(t1),
+            Self::T2(t2) => Or7::T2(f(t2).await),
+            Self::T3(t3) => Or7::T3(t3),
+            Self::T4(t4) => Or7::T4(t4),
+            Self::T5(t5) => Or7::T5(t5),
+            Self::T6(t6) => Or7::T6(t6),
+            Self::T7(t7) => Or7::T7(t7),
+        }
+    }
+
+    /// Transforms the T3 value of the enum using a provided async function and awaits it,
+    /// maintaining other types as is.
+    pub async fn map_t3_async<F, Fut, B>(self, f: F) -> Or7<T1, T2, B, T4, T5, T6, T7>
+    where
+        F: FnOnce(T3) -> Fut,
+        Fut: Future<Output = B>,
+    {
+        match self {
+            Self::T1(t1) => Or7::T1(t1),
+            Self::T2(t2) => Or7::T2(t2),
+            Self::T3(t3) => Or7::T3(f(t3).await),
+            Self::T4(t4) => Or7::T4(t4),
+            Self::T5(t5) => Or7::T5(t5),
+            Self::T6(t6) => Or7::T6(t6),
+            Self::T7(t7) => Or7::T7(t7),
+        }
+    }
+
+    /// Transforms the T4 value of the enum using a provided async function and awaits it,
+    /// maintaining other types as is.
+    pub async fn map_t4_async<F, Fut, B>(self, f: F) -> Or7<T1, T2, T3, B, T5, T6, T7>
+    where
+        F: FnOnce(T4) -> Fut,
+        Fut: Future<Output = B>,
+    {
+        match self {
+            Self::T1(t1) => Or7::T1(t1),
+            Self::T2(t2) => Or7::T2(t2),
+            Self::T3(t3) => Or7::T3(t3),
+            Self::T4(t4) => Or7::T4(f(t4).await),
+            Self::T5(t5) => Or7::T5(t5),
+            Self::T6(t6) => Or7::T6(t6),
+            Self::T7(t7) => Or7::T7(t7),
+        }
+    }
+
+    /// Transforms the T5 value of the enum using a provided async function and awaits it,
+    /// maintaining other types as is.
+    pub async fn map_t5_async<F, Fut, B>(self, f: F) -> Or7<T1, T2, T3, T4, B, T6, T7>
+    where
+        F: FnOnce(T5) -> Fut,
+        Fut: Future<Output = B>,
+    {
+        match self {
+            Self::T1(t1) => Or7::T1(t1),
+            Self::T2(t2) => Or7::T2(t2),
+            Self::T3(t3) => Or7::T3(t3),
+            Self::T4(t4) => Or7::T4(t4),
+            Self::T5(t5) => Or7::T5(f(t5).await),
+            Self::T6(t6) => Or7::T6(t6),
+            Self::T7(t7) => Or7::T7(t7),
+        }
+    }
+
+    /// Transforms the T6 value of the enum using a provided async function and awaits it,
+    /// maintaining other types as is.
+    pub async fn map_t6_async<F, Fut, B>(self, f: F) -> Or7<T1, T2, T3, T4, T5, B, T7>
+    where
+        F: FnOnce(T6) -> Fut,
+        Fut: Future<Output = B>,
+    {
+        match self {
+            Self::T1(t1) => Or7::T1(t1),
+            Self::T2(t2) => Or7::T2(t2),
+            Self::T3(t3) => Or7::T3(t3),
+            Self::T4(t4) => Or7::T4(t4),
+            Self::T5(t5) => Or7::T5(t5),
+            Self::T6(t6) => Or7::T6(f(t6).await),
+            Self::T7(t7) => Or7::T7(t7),
+        }
+    }
+
+    /// Transforms the T7 value of the enum using a provided async function and awaits it,
+    /// maintaining other types as is.
+    pub async fn map_t7_async<F, Fut, B>(self, f: F) -> Or7<T1, T2, T3, T4, T5, T6, B>
+    where
+        F: FnOnce(T7) -> Fut,
+        Fut: Future<Output = B>,
+    {
+        match self {
+            Self::T1(t1) => Or7::T1(t1),
+            Self::T2(t2) => Or7::T2(t2),
+            Self::T3(t3) => Or7::T3(t3),
+            Self::T4(t4) => Or7::T4(t4),
+            Self::T5(t5) => Or7::T5(t5),
+            Self::T6(t6) => Or7::T6(t6),
+            Self::T7(t7) => Or7::T7(f(t7).await),
+        }
+    }
+
+    /// Consolidates the `Or7` enum into a single value of type `T`,
+    /// by applying provided functions.
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn fold<T, F1, F2, F3, F4, F5, F6, F7>(
+        self,
+        f1: F1,
+        f2: F2,
+        f3: F3,
+        f4: F4,
+        f5: F5,
+        f6: F6,
+        f7: F7,
+    ) -> T
+    where
+        F1: FnOnce(T1) -> T,
+        F2: FnOnce(T2) -> T,
+        F3: FnOnce(T3) -> T,
+        F4: FnOnce(T4) -> T,
+        F5: FnOnce(T5) -> T,
+        F6: FnOnce(T6) -> T,
+        F7: FnOnce(T7) -> T,
+    {
+        match self {
+            Self::T1(t1) => f1(t1),
+            Self::T2(t2) => f2(t2),
+            Self::T3(t3) => f3(t3),
+            Self::T4(t4) => f4(t4),
+            Self::T5(t5) => f5(t5),
+            Self::T6(t6) => f6(t6),
+            Self::T7(t7) => f7(t7),
+        }
+    }
+
+    /// Consolidates the `Or7` enum into a single value of type `T` by applying provided
+    /// fallible functions, returning the error of the applied one, so that the functions can use `?`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_fold<T, E, F1, F2, F3, F4, F5, F6, F7>(
+        self,
+        f1: F1,
+        f2: F2,
+        f3: F3,
+        f4: F4,
+        f5: F5,
+        f6: F6,
+        f7: F7,
+    ) -> Result<T, E>
+    where
+        F1: FnOnce(T1) -> Result<T, E>,
+        F2: FnOnce(T2) -> Result<T, E>,
+        F3: FnOnce(T3) -> Result<T, E>,
+        F4: FnOnce(T4) -> Result<T, E>,
+        F5: FnOnce(T5) -> Result<T, E>,
+        F6: FnOnce(T6) -> Result<T, E>,
+        F7: FnOnce(T7) -> Result<T, E>,
+    {
+        match self {
+            Self::T1(t1) => f1(t1),
+            Self::T2(t2) => f2(t2),
+            Self::T3(t3) => f3(t3),
+            Self::T4(t4) => f4(t4),
+            Self::T5(t5) => f5(t5),
+            Self::T6(t6) => f6(t6),
+            Self::T7(t7) => f7(t7),
+        }
+    }
+
+    /// Consolidates the `Or7` enum into a single value of type `T`,
+    /// by applying provided async functions and awaiting the result.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn fold_async<
+        T,
+        F1,
+        Fut1,
+        F2,
+        Fut2,
+        F3,
+        Fut3,
+        F4,
+        Fut4,
+        F5,
+        Fut5,
+        F6,
+        Fut6,
+        F7,
+        Fut7,
+    >(
+        self,
+        f1: F1,
+        f2: F2,
+        f3: F3,
+        f4: F4,
+        f5: F5,
+        f6: F6,
+        f7: F7,
+    ) -> T
+    where
+        F1: FnOnce(T1) -> Fut1,
+        Fut1: Future<Output = T>,
+        F2: FnOnce(T2) -> Fut2,
+        Fut2: Future<Output = T>,
+        F3: FnOnce(T3) -> Fut3,
+        Fut3: Future<Output = T>,
+        F4: FnOnce(T4) -> Fut4,
+        Fut4: Future<Output = T>,
+        F5: FnOnce(T5) -> Fut5,
+        Fut5: Future<Output = T>,
+        F6: FnOnce(T6) -> Fut6,
+        Fut6: Future<Output = T>,
+        F7: FnOnce(T7) -> Fut7,
+        Fut7: Future<Output = T>,
+    {
+        match self {
+            Self::T1(t1) => f1(t1).await,
+            Self::T2(t2) => f2(t2).await,
+            Self::T3(t3) => f3(t3).await,
+            Self::T4(t4) => f4(t4).await,
+            Self::T5(t5) => f5(t5).await,
+            Self::T6(t6) => f6(t6).await,
+            Self::T7(t7) => f7(t7).await,
+        }
+    }
+
+    /// Handles the T1 value of the enum using a provided function, and leaves the other
+    /// variants to the subsequent `when_tx` calls.
+    pub fn when_t1<R, F>(self, f: F) -> When<R, Or7<Infallible, T2, T3, T4, T5, T6, T7>>
+    where
+        F: FnOnce(T1) -> R,
+    {
+        match self {
+            Self::T1(t1) => When::handled(f(t1)),
+            Self::T2(t2) => When::pending(Or7::T2(t2)),
+            Self::T3(t3) => When::pending(Or7::T3(t3)),
+            Self::T4(t4) => When::pending(Or7::T4(t4)),
+            Self::T5(t5) => When::pending(Or7::T5(t5)),
+            Self::T6(t6) => When::pending(Or7::T6(t6)),
+            Self::T7(t7) => When::pending(Or7::T7(t7)),
+        }
+    }
+
+    /// Handles the T2 value of the enum using a provided function, and leaves the other
+    /// variants to the subsequent `when_tx` calls.
+    pub fn when_t2<R, F>(self, f: F) -> When<R, Or7<T1, Infallible, T3, T4, T5, T6, T7>>
+    where
+        F: FnOnce(T2) -> R,
+    {
+        match self {
+            Self::T1(t1) => When::pending(Or7::T1(t1)),
+            Self::T2(t2) => When::handled(f(t2)),
+            Self::T3(t3) => When::pending(Or7::T3(t3)),
+            Self::T4(t4) => When::pending(Or7::T4(t4)),
+            Self::T5(t5) => When::pending(Or7::T5(t5)),
+            Self::T6(t6) => When::pending(Or7::T6(t6)),
+            Self::T7(t7) => When::pending(Or7::T7(t7)),
+        }
+    }
+
+    /// Handles the T3 value of the enum using a provided function, and leaves the other
+    /// variants to the subsequent `when_tx` calls.
+    pub fn when_t3<R, F>(self, f: F) -> When<R, Or7<T1, T2, Infallible, T4, T5, T6, T7>>
+    where
+        F: FnOnce(T3) -> R,
+    {
+        match self {
+            Self::T1(t1) => When::pending(Or7::T1(t1)),
+            Self::T2(t2) => When::pending(Or7::T2(t2)),
+            Self::T3(t3) => When::handled(f(t3)),
+            Self::T4(t4) => When::pending(Or7::T4(t4)),
+            Self::T5(t5) => When::pending(Or7::T5(t5)),
+            Self::T6(t6) => When::pending(Or7::T6(t6)),
+            Self::T7(t7) => When::pending(Or7::T7(t7)),
+        }
+    }
+
+    /// Handles the T4 value of the enum using a provided function, and leaves the other
+    /// variants to the subsequent `when_tx` calls.
+    pub fn when_t4<R, F>(self, f: F) -> When<R, Or7<T1, T2, T3, Infallible, T5, T6, T7>>
+    where
+        F: FnOnce(T4) -> R,
+    {
+        match self {
+            Self::T1(t1) => When::pending(Or7::T1(t1)),
+            Self::T2(t2) => When::pending(Or7::T2(t2)),
+            Self::T3(t3) => When::pending(Or7::T3(t3)),
+            Self::T4(t4) => When::handled(f(t4)),
+            Self::T5(t5) => When::pending(Or7::T5(t5)),
+            Self::T6(t6) => When::pending(Or7::T6(t6)),
+            Self::T7(t7) => When::pending(Or7::T7(t7)),
+        }
+    }
+
+    /// Handles the T5 value of the enum using a provided function, and leaves the other
+    /// variants to the subsequent `when_tx` calls.
+    pub fn when_t5<R, F>(self, f: F) -> When<R, Or7<T1, T2, T3, T4, Infallible, T6, T7>>
+    where
+        F: FnOnce(T5) -> R,
+    {
+        match self {
+            Self::T1(t1) => When::pending(Or7::T1(t1)),
+            Self::T2(t2) => When::pending(Or7::T2(t2)),
+            Self::T3(t3) => When::pending(Or7::T3(t3)),
+            Self::T4(t4) => When::pending(Or7::T4(t4)),
+            Self::T5(t5) => When::handled(f(t5)),
+            Self::T6(t6) => When::pending(Or7::T6(t6)),
+            Self::T7(t7) => When::pending(Or7::T7(t7)),
+        }
+    }
+
+    /// Handles the T6 value of the enum using a provided function, and leaves the other
+    /// variants to the subsequent `when_tx` calls.
+    pub fn when_t6<R, F>(self, f: F) -> When<R, Or7<T1, T2, T3, T4, T5, Infallible, T7>>
+    where
+        F: FnOnce(T6) -> R,
+    {
+        match self {
+            Self::T1(t1) => When::pending(Or7::T1(t1)),
+            Self::T2(t2) => When::pending(Or7::T2(t2)),
+            Self::T3(t3) => When::pending(Or7::T3(t3)),
+            Self::T4(t4) => When::pending(Or7::T4(t4)),
+            Self::T5(t5) => When::pending(Or7::T5(t5)),
+            Self::T6(t6) => When::handled(f(t6)),
+            Self::T7(t7) => When::pending(Or7::T7(t7)),
+        }
+    }
+
+    /// Handles the T7 value of the enum using a provided function, and leaves the other
+    /// variants to the subsequent `when_tx` calls.
+    pub fn when_t7<R, F>(self, f: F) -> When<R, Or7<T1, T2, T3, T4, T5, T6, Infallible>>
+    where
+        F: FnOnce(T7) -> R,
+    {
+        match self {
+            Self::T1(t1) => When::pending(Or7::T1(t1)),
+            Self::T2(t2) => When::pending(Or7::T2(t2)),
+            Self::T3(t3) => When::pending(Or7::T3(t3)),
+            Self::T4(t4) => When::pending(Or7::T4(t4)),
+            Self::T5(t5) => When::pending(Or7::T5(t5)),
+            Self::T6(t6) => When::pending(Or7::T6(t6)),
+            Self::T7(t7) => When::handled(f(t7)),
+        }
+    }
+
+    /// Converts the enum into a tuple of Options, where only the slot of the active variant is `Some`.
+    #[must_use]
+    pub fn into_options(
+        self,
+    ) -> (
+        Option<T1>,
+        Option<T2>,
+        Option<T3>,
+        Option<T4>,
+        Option<T5>,
+        Option<T6>,
+        Option<T7>,
+    ) {
+        match self {
+            Self::T1(t1) => (Some(t1), None, None, None, None, None, None),
+            Self::T2(t2) => (None, Some(t2), None, None, None, None, None),
+            Self::T3(t3) => (None, None, Some(t3), None, None, None, None),
+            Self::T4(t4) => (None, None, None, Some(t4), None, None, None),
+            Self::T5(t5) => (None, None, None, None, Some(t5), None, None),
+            Self::T6(t6) => (None, None, None, None, None, Some(t6), None),
+            Self::T7(t7) => (None, None, None, None, None, None, Some(t7)),
+        }
+    }
+
+    /// Borrows the enum as a tuple of Options, where only the slot of the active variant is `Some`.
+    #[must_use]
+    pub fn as_options(
+        &self,
+    ) -> (
+        Option<&T1>,
+        Option<&T2>,
+        Option<&T3>,
+        Option<&T4>,
+        Option<&T5>,
+        Option<&T6>,
+        Option<&T7>,
+    ) {
+        self.as_ref().into_options()
+    }
+
+    /// Builds the enum from a tuple of Options, which succeeds only when exactly one of them is `Some`.
+    /// Otherwise the tuple is handed back as is.
+    pub fn try_from_options(
+        options: (
+            Option<T1>,
+            Option<T2>,
+            Option<T3>,
+            Option<T4>,
+            Option<T5>,
+            Option<T6>,
+            Option<T7>,
+        ),
+    ) -> Result<
+        Self,
+        (
+            Option<T1>,
+            Option<T2>,
+            Option<T3>,
+            Option<T4>,
+            Option<T5>,
+            Option<T6>,
+            Option<T7>,
+        ),
+    > {
+        match options {
+            (Some(t1), None, None, None, None, None, None) => Ok(Self::T1(t1)),
+            (None, Some(t2), None, None, None, None, None) => Ok(Self::T2(t2)),
+            (None, None, Some(t3), None, None, None, None) => Ok(Self::T3(t3)),
+            (None, None, None, Some(t4), None, None, None) => Ok(Self::T4(t4)),
+            (None, None, None, None, Some(t5), None, None) => Ok(Self::T5(t5)),
+            (None, None, None, None, None, Some(t6), None) => Ok(Self::T6(t6)),
+            (None, None, None, None, None, None, Some(t7)) => Ok(Self::T7(t7)),
+            options => Err(options),
+        }
+    }
+}
+
+impl_forwarding!(Or7, Or7Items, Or7Discriminant {
+    T1(t1, e1, I1, i1, E1, 0, T1),
+    T2(t2, e2, I2, i2, E2, 1, T2),
+    T3(t3, e3, I3, i3, E3, 2, T3),
+    T4(t4, e4, I4, i4, E4, 3, T4),
+    T5(t5, e5, I5, i5, E5, 4, T5),
+    T6(t6, e6, I6, i6, E6, 5, T6),
+    T7(t7, e7, I7, i7, E7, 6, T7)
+});
+
+impl<T1, T2, T3, T4, T5, T6, T7> Or7<T1, T2, T3, T4, T5, T6, T7> {
+    /// The number of the variants, which is the size of the arrays returned by
+    /// [`Or7::variant_sizes`] and friends, indexed by [`Or7::variant_index`].
+    pub const VARIANT_COUNT: usize = 7;
+
+    /// Returns the 0-based position of the active variant, like `0` for `T1`.
+    ///
+    /// This can index a table of handlers or counters, with `VARIANT_COUNT` entries.
+    #[must_use]
+    pub const fn variant_index(&self) -> usize {
+        match self {
+            Self::T1(_) => 0,
+            Self::T2(_) => 1,
+            Self::T3(_) => 2,
+            Self::T4(_) => 3,
+            Self::T5(_) => 4,
+            Self::T6(_) => 5,
+            Self::T7(_) => 6,
+        }
+    }
+
+    /// Returns the sizes of the payload types, in the order of the variants.
+    ///
+    /// Comparing them with the size of the enum tells whether one oversized variant bloats
+    /// the whole enum, in which case it can be shrunk by [`Or7::boxed`].
+    pub const fn variant_sizes() -> [usize; 7] {
+        [
+            size_of::<T1>(),
+            size_of::<T2>(),
+            size_of::<T3>(),
+            size_of::<T4>(),
+            size_of::<T5>(),
+            size_of::<T6>(),
+            size_of::<T7>(),
+        ]
+    }
+
+    /// Returns the alignments of the payload types, in the order of the variants.
+    pub const fn variant_aligns() -> [usize; 7] {
+        [
+            align_of::<T1>(),
+            align_of::<T2>(),
+            align_of::<T3>(),
+            align_of::<T4>(),
+            align_of::<T5>(),
+            align_of::<T6>(),
+            align_of::<T7>(),
+        ]
+    }
+
+    /// Returns the size of the largest payload type, which the size of the enum is at least.
+    pub const fn max_variant_size() -> usize {
+        let sizes = Self::variant_sizes();
+        let mut max = 0;
+        let mut i = 0;
+        while i < sizes.len() {
+            if sizes[i] > max {
+                max = sizes[i];
+            }
+            i += 1;
+        }
+        max
+    }
+}
+
+impl<T2, T3, T4, T5, T6, T7> Or7<Infallible, T2, T3, T4, T5, T6, T7> {
+    /// Removes the uninhabited `T1` position from `Or7`, which is useful to simplify
+    /// an `Or` type once some of its branches become impossible.
+    #[must_use]
+    pub fn compact_t1(self) -> Or6<T2, T3, T4, T5, T6, T7> {
+        match self {
+            Self::T1(never) => match never {},
+            Self::T2(t2) => Or6::T1(t2),
+            Self::T3(t3) => Or6::T2(t3),
+            Self::T4(t4) => Or6::T3(t4),
+            Self::T5(t5) => Or6::T4(t5),
+            Self::T6(t6) => Or6::T5(t6),
+            Self::T7(t7) => Or6::T6(t7),
+        }
+    }
+}
+
+impl<T1, T3, T4, T5, T6, T7> Or7<T1, Infallible, T3, T4, T5, T6, T7> {
+    /// Removes the uninhabited `T2` position from `Or7`, which is useful to simplify
+    /// an `Or` type once some of its branches become impossible.
+    #[must_use]
+    pub fn compact_t2(self) -> Or6<T1, T3, T4, T5, T6, T7> {
+        match self {
+            Self::T1(t1) => Or6::T1(t1),
+            Self::T2(never) => match never {},
+            Self::T3(t3) => Or6::T2(t3),
+            Self::T4(t4) => Or6::T3(t4),
+            Self::T5(t5) => Or6::T4(t5),
+            Self::T6(t6) => Or6::T5(t6),
+            Self::T7(t7) => Or6::T6(t7),
+        }
+    }
+}
+
+impl<T1, T2, T4, T5, T6, T7> Or7<T1, T2, Infallible, T4, T5, T6, T7> {
+    /// Removes the uninhabited `T3` position from `Or7`, which is useful to simplify
+    /// an `Or` type once some of its branches become impossible.
+    #[must_use]
+    pub fn compact_t3(self) -> Or6<T1, T2, T4, T5, T6, T7> {
+        match self {
+            Self::T1(t1) => Or6::T1(t1),
+            Self::T2(t2) => Or6::T2(t2),
+            Self::T3(never) => match never {},
+            Self::T4(t4) => Or6::T3(t4),
+            Self::T5(t5) => Or6::T4(t5),
+            Self::T6(t6) => Or6::T5(t6),
+            Self::T7(t7) => Or6::T6(t7),
+        }
+    }
+}
+
+impl<T1, T2, T3, T5, T6, T7> Or7<T1, T2, T3, Infallible, T5, T6, T7> {
+    /// Removes the uninhabited `T4` position from `Or7`, which is useful to simplify
+    /// an `Or` type once some of its branches become impossible.
+    #[must_use]
+    pub fn compact_t4(self) -> Or6<T1, T2, T3, T5, T6, T7> {
+        match self {
+            Self::T1(t1) => Or6::T1(t1),
+            Self::T2(t2) => Or6::T2(t2),
+            Self::T3(t3) => Or6::T3(t3),
+            Self::T4(never) => match never {},
+            Self::T5(t5) => Or6::T4(t5),
+            Self::T6(t6) => Or6::T5(t6),
+            Self::T7(t7) => Or6::T6(t7),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T6, T7> Or7<T1, T2, T3, T4, Infallible, T6, T7> {
+    /// Removes the uninhabited `T5` position from `Or7`, which is useful to simplify
+    /// an `Or` type once some of its branches become impossible.
+    #[must_use]
+    pub fn compact_t5(self) -> Or6<T1, T2, T3, T4, T6, T7> {
+        match self {
+            Self::T1(t1) => Or6::T1(t1),
+            Self::T2(t2) => Or6::T2(t2),
+            Self::T3(t3) => Or6::T3(t3),
+            Self::T4(t4) => Or6::T4(t4),
+            Self::T5(never) => match never {},
+            Self::T6(t6) => Or6::T5(t6),
+            Self::T7(t7) => Or6::T6(t7),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T7> Or7<T1, T2, T3, T4, T5, Infallible, T7> {
+    /// Removes the uninhabited `T6` position from `Or7`, which is useful to simplify
+    /// an `Or` type once some of its branches become impossible.
+    #[must_use]
+    pub fn compact_t6(self) -> Or6<T1, T2, T3, T4, T5, T7> {
+        match self {
+            Self::T1(t1) => Or6::T1(t1),
+            Self::T2(t2) => Or6::T2(t2),
+            Self::T3(t3) => Or6::T3(t3),
+            Self::T4(t4) => Or6::T4(t4),
+            Self::T5(t5) => Or6::T5(t5),
+            Self::T6(never) => match never {},
+            Self::T7(t7) => Or6::T6(t7),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6> Or7<T1, T2, T3, T4, T5, T6, Infallible> {
+    /// Removes the uninhabited `T7` position from `Or7`, which is useful to simplify
+    /// an `Or` type once some of its branches become impossible.
+    #[must_use]
+    pub fn compact_t7(self) -> Or6<T1, T2, T3, T4, T5, T6> {
+        match self {
+            Self::T1(t1) => Or6::T1(t1),
+            Self::T2(t2) => Or6::T2(t2),
+            Self::T3(t3) => Or6::T3(t3),
+            Self::T4(t4) => Or6::T4(t4),
+            Self::T5(t5) => Or6::T5(t5),
+            Self::T6(t6) => Or6::T6(t6),
+            Self::T7(never) => match never {},
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7> Or7<T1, T2, T3, T4, T5, T6, T7> {
+    /// Converts the enum into a `Result`, treating `T1` as the ok value and the others as the
+    /// error. The conversion is lossless, and can be reverted by `From`.
+    pub fn into_result(self) -> Result<T1, Or6<T2, T3, T4, T5, T6, T7>> {
+        match self {
+            Self::T1(t1) => Ok(t1),
+            Self::T2(t2) => Err(Or6::T1(t2)),
+            Self::T3(t3) => Err(Or6::T2(t3)),
+            Self::T4(t4) => Err(Or6::T3(t4)),
+            Self::T5(t5) => Err(Or6::T4(t5)),
+            Self::T6(t6) => Err(Or6::T5(t6)),
+            Self::T7(t7) => Err(Or6::T6(t7)),
+        }
+    }
+
+    /// Converts the enum into a `Result`, treating `T1` as the ok value and converting the
+    /// others into the error type `E`.
+    pub fn try_into_result<E>(self) -> Result<T1, E>
+    where
+        T2: Into<E>,
+        T3: Into<E>,
+        T4: Into<E>,
+        T5: Into<E>,
+        T6: Into<E>,
+        T7: Into<E>,
+    {
+        match self {
+            Self::T1(t1) => Ok(t1),
+            Self::T2(t2) => Err(t2.into()),
+            Self::T3(t3) => Err(t3.into()),
+            Self::T4(t4) => Err(t4.into()),
+            Self::T5(t5) => Err(t5.into()),
+            Self::T6(t6) => Err(t6.into()),
+            Self::T7(t7) => Err(t7.into()),
+        }
+    }
+}
+
+/// Converts the ok value into `T1` and the error into the remaining positions, which reverts
+/// `into_result`.
+impl<T1, T2, T3, T4, T5, T6, T7> From<Result<T1, Or6<T2, T3, T4, T5, T6, T7>>>
+    for Or7<T1, T2, T3, T4, T5, T6, T7>
+{
+    fn from(result: Result<T1, Or6<T2, T3, T4, T5, T6, T7>>) -> Self {
+        match result {
+            Ok(t1) => Self::T1(t1),
+            Err(Or6::T1(t2)) => Self::T2(t2),
+            Err(Or6::T2(t3)) => Self::T3(t3),
+            Err(Or6::T3(t4)) => Self::T4(t4),
+            Err(Or6::T4(t5)) => Self::T5(t5),
+            Err(Or6::T5(t6)) => Self::T6(t6),
+            Err(Or6::T6(t7)) => Self::T7(t7),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6> Or7<T1, T2, T3, T4, T5, T6, ()> {
+    /// Converts the enum into an `Option`, which is `None` for the `()` in the last position.
+    /// The conversion is lossless, and can be reverted by `From`.
+    pub fn into_option(self) -> Option<Or6<T1, T2, T3, T4, T5, T6>> {
+        match self {
+            Self::T1(t1) => Some(Or6::T1(t1)),
+            Self::T2(t2) => Some(Or6::T2(t2)),
+            Self::T3(t3) => Some(Or6::T3(t3)),
+            Self::T4(t4) => Some(Or6::T4(t4)),
+            Self::T5(t5) => Some(Or6::T5(t5)),
+            Self::T6(t6) => Some(Or6::T6(t6)),
+            Self::T7(()) => None,
+        }
+    }
+}
+
+/// Converts `None` into the `()` in the last position, which reverts `into_option`.
+impl<T1, T2, T3, T4, T5, T6> From<Option<Or6<T1, T2, T3, T4, T5, T6>>>
+    for Or7<T1, T2, T3, T4, T5, T6, ()>
+{
+    fn from(option: Option<Or6<T1, T2, T3, T4, T5, T6>>) -> Self {
+        match option {
+            Some(Or6::T1(t1)) => Self::T1(t1),
+            Some(Or6::T2(t2)) => Self::T2(t2),
+            Some(Or6::T3(t3)) => Self::T3(t3),
+            Some(Or6::T4(t4)) => Self::T4(t4),
+            Some(Or6::T5(t5)) => Self::T5(t5),
+            Some(Or6::T6(t6)) => Self::T6(t6),
+            None => Self::T7(()),
+        }
+    }
+}
+
+impl<R, T1, T2, T3, T4, T5, T6, T7> When<R, Or7<T1, T2, T3, T4, T5, T6, T7>> {
+    /// Handles the T1 value of the pending `Or7` using a provided function,
+    /// unless a previous `when_tx` has already handled the value.
+    pub fn when_t1<F>(self, f: F) -> When<R, Or7<Infallible, T2, T3, T4, T5, T6, T7>>
+    where
+        F: FnOnce(T1) -> R,
+    {
+        match self.into_state() {
+            Ok(result) => When::handled(result),
+            Err(or) => or.when_t1(f),
+        }
+    }
+
+    /// Handles the T2 value of the pending `Or7` using a provided function,
+    /// unless a previous `when_tx` has already handled the value.
+    pub fn when_t2<F>(self, f: F) -> When<R, Or7<T1, Infallible, T3, T4, T5, T6, T7>>
+    where
+        F: FnOnce(T2) -> R,
+    {
+        match self.into_state() {
+            Ok(result) => When::handled(result),
+            Err(or) => or.when_t2(f),
+        }
+    }
+
+    /// Handles the T3 value of the pending `Or7` using a provided function,
+    /// unless a previous `when_tx` has already handled the value.
+    pub fn when_t3<F>(self, f: F) -> When<R, Or7<T1, T2, Infallible, T4, T5, T6, T7>>
+    where
+        F: FnOnce(T3) -> R,
+    {
+        match self.into_state() {
+            Ok(result) => When::handled(result),
+            Err(or) => or.when_t3(f),
+        }
+    }
+
+    /// Handles the T4 value of the pending `Or7` using a provided function,
+    /// unless a previous `when_tx` has already handled the value.
+    pub fn when_t4<F>(self, f: F) -> When<R, Or7<T1, T2, T3, Infallible, T5, T6, T7>>
+    where
+        F: FnOnce(T4) -> R,
+    {
+        match self.into_state() {
+            Ok(result) => When::handled(result),
+            Err(or) => or.when_t4(f),
+        }
+    }
+
+    /// Handles the T5 value of the pending `Or7` using a provided function,
+    /// unless a previous `when_tx` has already handled the value.
+    pub fn when_t5<F>(self, f: F) -> When<R, Or7<T1, T2, T3, T4, Infallible, T6, T7>>
+    where
+        F: FnOnce(T5) -> R,
+    {
+        match self.into_state() {
+            Ok(result) => When::handled(result),
+            Err(or) => or.when_t5(f),
+        }
+    }
+
+    /// Handles the T6 value of the pending `Or7` using a provided function,
+    /// unless a previous `when_tx` has already handled the value.
+    pub fn when_t6<F>(self, f: F) -> When<R, Or7<T1, T2, T3, T4, T5, Infallible, T7>>
+    where
+        F: FnOnce(T6) -> R,
+    {
+        match self.into_state() {
+            Ok(result) => When::handled(result),
+            Err(or) => or.when_t6(f),
+        }
+    }
+
+    /// Handles the T7 value of the pending `Or7` using a provided function,
+    /// unless a previous `when_tx` has already handled the value.
+    pub fn when_t7<F>(self, f: F) -> When<R, Or7<T1, T2, T3, T4, T5, T6, Infallible>>
+    where
+        F: FnOnce(T7) -> R,
+    {
+        match self.into_state() {
+            Ok(result) => When::handled(result),
+            Err(or) => or.when_t7(f),
+        }
+    }
+}
+
+impl<R>
+    When<R, Or7<Infallible, Infallible, Infallible, Infallible, Infallible, Infallible, Infallible>>
+{
+    /// Finishes the chain and returns the result. This is available only when every
+    /// variant of `Or7` has been handled.
+    pub fn finish(self) -> R {
+        match self.into_state() {
+            Ok(result) => result,
+            Err(or) => match or {
+                Or7::T1(never) => match never {},
+                Or7::T2(never) => match never {},
+                Or7::T3(never) => match never {},
+                Or7::T4(never) => match never {},
+                Or7::T5(never) => match never {},
+                Or7::T6(never) => match never {},
+                Or7::T7(never) => match never {},
+            },
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7, T8, T9> Concat<Or2<T8, T9>> for Or7<T1, T2, T3, T4, T5, T6, T7> {
+    type Output = Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>;
+
+    fn left(lhs: Self) -> Self::Output {
+        match lhs {
+            Or7::T1(x) => Or9::T1(x),
+            Or7::T2(x) => Or9::T2(x),
+            Or7::T3(x) => Or9::T3(x),
+            Or7::T4(x) => Or9::T4(x),
+            Or7::T5(x) => Or9::T5(x),
+            Or7::T6(x) => Or9::T6(x),
+            Or7::T7(x) => Or9::T7(x),
+        }
+    }
+
+    fn right(rhs: Or2<T8, T9>) -> Self::Output {
+        match rhs {
+            Or2::T1(x) => Or9::T8(x),
+            Or2::T2(x) => Or9::T9(x),
+        }
+    }
+}
+
+/// `Or8` is an enum representing a value that can be either of 8 types, T1 ... T8.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(untagged)
+)]
+#[cfg_attr(feature = "repr-c", repr(C))]
+#[cfg_attr(feature = "repr-u8", repr(u8))]
+pub enum Or8<T1, T2, T3, T4, T5, T6, T7, T8> {
+    T1(T1),
+    T2(T2),
+    T3(T3),
+    T4(T4),
+    T5(T5),
+    T6(T6),
+    T7(T7),
+    T8(T8),
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7, T8> Or8<T1, T2, T3, T4, T5, T6, T7, T8> {
+    /// Returns true if the enum is of type T1.
+    #[must_use]
+    pub fn is_t1(&self) -> bool {
+        matches!(self, Self::T1(_))
+    }
+
+    /// Returns true if the enum is of type T2.
+    #[must_use]
+    pub fn is_t2(&self) -> bool {
+        matches!(self, Self::T2(_))
+    }
+
+    /// Returns true if the enum is of type T3.
+    #[must_use]
+    pub fn is_t3(&self) -> bool {
+        matches!(self, Self::T3(_))
+    }
+
+    /// Returns true if the enum is of type T4.
+    #[must_use]
+    pub fn is_t4(&self) -> bool {
+        matches!(self, Self::T4(_))
+    }
+
+    /// Returns true if the enum is of type T5.
+    #[must_use]
+    pub fn is_t5(&self) -> bool {
+        matches!(self, Self::T5(_))
+    }
+
+    /// Returns true if the enum is of type T6.
+    #[must_use]
+    pub fn is_t6(&self) -> bool {
+        matches!(self, Self::T6(_))
+    }
+
+    /// Returns true if the enum is of type T7.
+    #[must_use]
+    pub fn is_t7(&self) -> bool {
+        matches!(self, Self::T7(_))
+    }
+
+    /// Returns true if the enum is of type T8.
+    #[must_use]
+    pub fn is_t8(&self) -> bool {
+        matches!(self, Self::T8(_))
+    }
+
+    /// Converts the enum to an Option containing the T1 value, if it is of type T1.
+    #[must_use]
+    pub fn as_t1(self) -> Option<T1> {
+        match self {
+            Self::T1(t1) => Some(t1),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the T1 value, if it is of type T1, without consuming the enum.
+    #[must_use]
+    pub fn as_t1_ref(&self) -> Option<&T1> {
+        match self {
+            Self::T1(t1) => Some(t1),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the T1 value, if it is of type T1.
+    #[must_use]
+    pub fn as_t1_mut(&mut self) -> Option<&mut T1> {
+        match self {
+            Self::T1(t1) => Some(t1),
+            _ => None,
+        }
+    }
+
+    /// Converts the enum to an Option containing the T2 value, if it is of type T2.
+    #[must_use]
+    pub fn as_t2(self) -> Option<T2> {
+        match self {
+            Self::T2(t2) => Some(t2),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the T2 value, if it is of type T2, without consuming the enum.
+    #[must_use]
+    pub fn as_t2_ref(&self) -> Option<&T2> {
+        match self {
+            Self::T2(t2) => Some(t2),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the T2 value, if it is of type T2.
+    #[must_use]
+    pub fn as_t2_mut(&mut self) -> Option<&mut T2> {
+        match self {
+            Self::T2(t2) => Some(t2),
+            _ => None,
+        }
+    }
+
+    /// Converts the enum to an Option containing the T3 value, if it is of type T3.
+    #[must_use]
+    pub fn as_t3(self) -> Option<T3> {
+        match self {
+            Self::T3(t3) => Some(t3),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the T3 value, if it is of type T3, without consuming the enum.
+    #[must_use]
+    pub fn as_t3_ref(&self) -> Option<&T3> {
+        match self {
+            Self::T3(t3) => Some(t3),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the T3 value, if it is of type T3.
+    #[must_use]
+    pub fn as_t3_mut(&mut self) -> Option<&mut T3> {
+        match self {
+            Self::T3(t3) => Some(t3),
+            _ => None,
+        }
+    }
+
+    /// Converts the enum to an Option containing the T4 value, if it is of type T4.
+    #[must_use]
+    pub fn as_t4(self) -> Option<T4> {
+        match self {
+            Self::T4(t4) => Some(t4),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the T4 value, if it is of type T4, without consuming the enum.
+    #[must_use]
+    pub fn as_t4_ref(&self) -> Option<&T4> {
+        match self {
+            Self::T4(t4) => Some(t4),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the T4 value, if it is of type T4.
+    #[must_use]
+    pub fn as_t4_mut(&mut self) -> Option<&mut T4> {
+        match self {
+            Self::T4(t4) => Some(t4),
+            _ => None,
+        }
+    }
+
+    /// Converts the enum to an Option containing the T5 value, if it is of type T5.
+    #[must_use]
+    pub fn as_t5(self) -> Option<T5> {
+        match self {
+            Self::T5(t5) => Some(t5),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the T5 value, if it is of type T5, without consuming the enum.
+    #[must_use]
+    pub fn as_t5_ref(&self) -> Option<&T5> {
+        match self {
+            Self::T5(t5) => Some(t5),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the T5 value, if it is of type T5.
+    #[must_use]
+    pub fn as_t5_mut(&mut self) -> Option<&mut T5> {
+        match self {
+            Self::T5(t5) => Some(t5),
+            _ => None,
+        }
+    }
+
+    /// Converts the enum to an Option containing the T6 value, if it is of type T6.
+    #[must_use]
+    pub fn as_t6(self) -> Option<T6> {
+        match self {
+            Self::T6(t6) => Some(t6),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the T6 value, if it is of type T6, without consuming the enum.
+    #[must_use]
+    pub fn as_t6_ref(&self) -> Option<&T6> {
+        match self {
+            Self::T6(t6) => Some(t6),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the T6 value, if it is of type T6.
+    #[must_use]
+    pub fn as_t6_mut(&mut self) -> Option<&mut T6> {
+        match self {
+            Self::T6(t6) => Some(t6),
+            _ => None,
+        }
+    }
+
+    /// Converts the enum to an Option containing the T7 value, if it is of type T7.
+    #[must_use]
+    pub fn as_t7(self) -> Option<T7> {
+        match self {
+            Self::T7(t7) => Some(t7),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the T7 value, if it is of type T7, without consuming the enum.
+    #[must_use]
+    pub fn as_t7_ref(&self) -> Option<&T7> {
+        match self {
+            Self::T7(t7) => Some(t7),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the T7 value, if it is of type T7.
+    #[must_use]
+    pub fn as_t7_mut(&mut self) -> Option<&mut T7> {
+        match self {
+            Self::T7(t7) => Some(t7),
+            _ => None,
+        }
+    }
+
+    /// Converts the enum to an Option containing the T8 value, if it is of type T8.
+    #[must_use]
+    pub fn as_t8(self) -> Option<T8> {
+        match self {
+            Self::T8(t8) => Some(t8),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the T8 value, if it is of type T8, without consuming the enum.
+    #[must_use]
+    pub fn as_t8_ref(&self) -> Option<&T8> {
+        match self {
+            Self::T8(t8) => Some(t8),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the T8 value, if it is of type T8.
+    #[must_use]
+    pub fn as_t8_mut(&mut self) -> Option<&mut T8> {
+        match self {
+            Self::T8(t8) => Some(t8),
+            _ => None,
+        }
+    }
+
+    /// Returns the T1 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum is not of type T1, naming the variant it is of.
+    #[track_caller]
+    pub fn unwrap_t1(self) -> T1 {
+        match self {
+            Self::T1(t1) => t1,
+            _ => panic!(
+                "called `Or8::unwrap_t1()` on a `{:?}` value",
+                self.discriminant()
+            ),
+        }
+    }
+
+    /// Returns the T1 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the enum is not of type T1.
+    #[track_caller]
+    pub fn expect_t1(self, msg: &str) -> T1 {
+        match self {
+            Self::T1(t1) => t1,
+            _ => panic!("{}", msg),
+        }
+    }
+
+    /// Returns the T2 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum is not of type T2, naming the variant it is of.
+    #[track_caller]
+    pub fn unwrap_t2(self) -> T2 {
+        match self {
+            Self::T2(t2) => t2,
+            _ => panic!(
+                "called `Or8::unwrap_t2()` on a `{:?}` value",
+                self.discriminant()
+            ),
+        }
+    }
+
+    /// Returns the T2 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the enum is not of type T2.
+    #[track_caller]
+    pub fn expect_t2(self, msg: &str) -> T2 {
+        match self {
+            Self::T2(t2) => t2,
+            _ => panic!("{}", msg),
+        }
+    }
+
+    /// Returns the T3 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum is not of type T3, naming the variant it is of.
+    #[track_caller]
+    pub fn unwrap_t3(self) -> T3 {
+        match self {
+            Self::T3(t3) => t3,
+            _ => panic!(
+                "called `Or8::unwrap_t3()` on a `{:?}` value",
+                self.discriminant()
+            ),
+        }
+    }
+
+    /// Returns the T3 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the enum is not of type T3.
+    #[track_caller]
+    pub fn expect_t3(self, msg: &str) -> T3 {
+        match self {
+            Self::T3(t3) => t3,
+            _ => panic!("{}", msg),
+        }
+    }
+
+    /// Returns the T4 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum is not of type T4, naming the variant it is of.
+    #[track_caller]
+    pub fn unwrap_t4(self) -> T4 {
+        match self {
+            Self::T4(t4) => t4,
+            _ => panic!(
+                "called `Or8::unwrap_t4()` on a `{:?}` value",
+                self.discriminant()
+            ),
+        }
+    }
+
+    /// Returns the T4 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the enum is not of type T4.
+    #[track_caller]
+    pub fn expect_t4(self, msg: &str) -> T4 {
+        match self {
+            Self::T4(t4) => t4,
+            _ => panic!("{}", msg),
+        }
+    }
+
+    /// Returns the T5 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum is not of type T5, naming the variant it is of.
+    #[track_caller]
+    pub fn unwrap_t5(self) -> T5 {
+        match self {
+            Self::T5(t5) => t5,
+            _ => panic!(
+                "called `Or8::unwrap_t5()` on a `{:?}` value",
+                self.discriminant()
+            ),
+        }
+    }
+
+    /// Returns the T5 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the enum is not of type T5.
+    #[track_caller]
+    pub fn expect_t5(self, msg: &str) -> T5 {
+        match self {
+            Self::T5(t5) => t5,
+            _ => panic!("{}", msg),
+        }
+    }
+
+    /// Returns the T6 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum is not of type T6, naming the variant it is of.
+    #[track_caller]
+    pub fn unwrap_t6(self) -> T6 {
+        match self {
+            Self::T6(t6) => t6,
+            _ => panic!(
+                "called `Or8::unwrap_t6()` on a `{:?}` value",
+                self.discriminant()
+            ),
+        }
+    }
+
+    /// Returns the T6 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the enum is not of type T6.
+    #[track_caller]
+    pub fn expect_t6(self, msg: &str) -> T6 {
+        match self {
+            Self::T6(t6) => t6,
+            _ => panic!("{}", msg),
+        }
+    }
+
+    /// Returns the T7 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum is not of type T7, naming the variant it is of.
+    #[track_caller]
+    pub fn unwrap_t7(self) -> T7 {
+        match self {
+            Self::T7(t7) => t7,
+            _ => panic!(
+                "called `Or8::unwrap_t7()` on a `{:?}` value",
+                self.discriminant()
+            ),
+        }
+    }
+
+    /// Returns the T7 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the enum is not of type T7.
+    #[track_caller]
+    pub fn expect_t7(self, msg: &str) -> T7 {
+        match self {
+            Self::T7(t7) => t7,
+            _ => panic!("{}", msg),
+        }
+    }
+
+    /// Returns the T8 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum is not of type T8, naming the variant it is of.
+    #[track_caller]
+    pub fn unwrap_t8(self) -> T8 {
+        match self {
+            Self::T8(t8) => t8,
+            _ => panic!(
+                "called `Or8::unwrap_t8()` on a `{:?}` value",
+                self.discriminant()
+            ),
+        }
+    }
+
+    /// Returns the T8 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the enum is not of type T8.
+    #[track_caller]
+    pub fn expect_t8(self, msg: &str) -> T8 {
+        match self {
+            Self::T8(t8) => t8,
+            _ => panic!("{}", msg),
+        }
+    }
+
+    /// Converts from `&Or8<T1, ...>` to `Or8<&T1, ...>`.
+    #[must_use]
+    pub fn as_ref(&self) -> Or8<&T1, &T2, &T3, &T4, &T5, &T6, &T7, &T8> {
+        match self {
+            Self::T1(t1) => Or8::T1(t1),
+            Self::T2(t2) => Or8::T2(t2),
+            Self::T3(t3) => Or8::T3(t3),
+            Self::T4(t4) => Or8::T4(t4),
+            Self::T5(t5) => Or8::T5(t5),
+            Self::T6(t6) => Or8::T6(t6),
+            Self::T7(t7) => Or8::T7(t7),
+            Self::T8(t8) => Or8::T8(t8),
+        }
+    }
+
+    /// Converts from `&mut Or8<T1, ...>` to `Or8<&mut T1, ...>`.
+    #[must_use]
+    pub fn as_mut(
+        &mut self,
+    ) -> Or8<&mut T1, &mut T2, &mut T3, &mut T4, &mut T5, &mut T6, &mut T7, &mut T8> {
+        match self {
+            Self::T1(t1) => Or8::T1(t1),
+            Self::T2(t2) => Or8::T2(t2),
+            Self::T3(t3) => Or8::T3(t3),
+            Self::T4(t4) => Or8::T4(t4),
+            Self::T5(t5) => Or8::T5(t5),
+            Self::T6(t6) => Or8::T6(t6),
+            Self::T7(t7) => Or8::T7(t7),
+            Self::T8(t8) => Or8::T8(t8),
+        }
+    }
+
+    /// Transforms the T1 value of the enum using a provided function,
+    /// maintaining other types as is.
+    #[must_use = "this returns the mapped enum, without modifying the original"]
+    pub fn map_t1<F, B>(self, f: F) -> Or8<B, T2, T3, T4, T5, T6, T7, T8>
+    where
+        F: FnOnce(T1) -> B,
+    {
+        match self {
+            Self::T1(t1) => Or8::<B, T2, T3, T4, T5, T6, T7, T8>::T1(f(t1)),
+            Self::T2(t2) => Or8::<B, T2, T3, T4, T5, T6, T7, T8>::T2(t2),
+            Self::T3(t3) => Or8::<B, T2, T3, T4, T5, T6, T7, T8>::T3(t3),
+            Self::T4(t4) => Or8::<B, T2, T3, T4, T5, T6, T7, T8>::T4(t4),
+            Self::T5(t5) => Or8::<B, T2, T3, T4, T5, T6, T7, T8>::T5(t5),
+            Self::T6(t6) => Or8::<B, T2, T3, T4, T5, T6, T7, T8>::T6(t6),
+            Self::T7(t7) => Or8::<B, T2, T3, T4, T5, T6, T7, T8>::T7(t7),
+            Self::T8(t8) => Or8::<B, T2, T3, T4, T5, T6, T7, T8>::T8(t8),
+        }
+    }
+
+    /// Transforms the T2 value of the enum using a provided function,
+    /// maintaining other types as is.
+    #[must_use = "this returns the mapped enum, without modifying the original"]
+    pub fn map_t2<F, B>(self, f: F) -> Or8<T1, B, T3, T4, T5, T6, T7, T8>
+    where
+        F: FnOnce(T2) -> B,
+    {
+        match self {
+            Self::T1(t1) => Or8::<T1, B, T3, T4, T5, T6, T7, T8>::T1(t1),
+            Self::T2(t2) => Or8::<T1, B, T3, T4, T5, T6, T7, T8>::T2(f(t2)),
+            Self::T3(t3) => Or8::<T1, B, T3, T4, T5, T6, T7, T8>::T3(t3),
+            Self::T4(t4) => Or8::<T1, B, T3, T4, T5, T6, T7, T8>::T4(t4),
+            Self::T5(t5) => Or8::<T1, B, T3, T4, T5, T6, T7, T8>::T5(t5),
+            Self::T6(t6) => Or8::<T1, B, T3, T4, T5, T6, T7, T8>::T6(t6),
+            Self::T7(t7) => Or8::<T1, B, T3, T4, T5, T6, T7, T8>::T7(t7),
+            Self::T8(t8) => Or8::<T1, B, T3, T4, T5, T6, T7, T8>::T8(t8),
+        }
+    }
+
+    /// Transforms the T3 value of the enum using a provided function,
+    /// maintaining other types as is.
+    #[must_use = "this returns the mapped enum, without modifying the original"]
+    pub fn map_t3<F, B>(self, f: F) -> Or8<T1, T2, B, T4, T5, T6, T7, T8>
+    where
+        F: FnOnce(T3) -> B,
+    {
+        match self {
+            Self::T1(t1) => Or8::<T1, T2, B, T4, T5, T6, T7, T8>::T1(t1),
+            Self::T2(t2) => Or8::<T1, T2, B, T4, T5, T6, T7, T8>::T2(t2),
+            Self::T3(t3) => Or8::<T1, T2, B, T4, T5, T6, T7, T8>::T3(f(t3)),
+            Self::T4(t4) => Or8::<T1, T2, B, T4, T5, T6, T7, T8>::T4(t4),
+            Self::T5(t5) => Or8::<T1, T2, B, T4, T5, T6, T7, T8>::T5(t5),
+            Self::T6(t6) => Or8::<T1, T2, B, T4, T5, T6, T7, T8>::T6(t6),
+            Self::T7(t7) => Or8::<T1, T2, B, T4, T5, T6, T7, T8>::T7(t7),
+            Self::T8(t8) => Or8::<T1, T2, B, T4, T5, T6, T7, T8>::T8(t8),
+        }
+    }
+
+    /// Transforms the T4 value of the enum using a provided function,
+    /// maintaining other types as is.
+    #[must_use = "this returns the mapped enum, without modifying the original"]
+    pub fn map_t4<F, B>(self, f: F) -> Or8<T1, T2, T3, B, T5, T6, T7, T8>
+    where
+        F: FnOnce(T4) -> B,
+    {
+        match self {
+            Self::T1(t1) => Or8::<T1, T2, T3, B, T5, T6, T7, T8>::T1(t1),
+            Self::T2(t2) => Or8::<T1, T2, T3, B, T5, T6, T7, T8>::T2(t2),
+            Self::T3(t3) => Or8::<T1, T2, T3, B, T5, T6, T7, T8>::T3(t3),
+            Self::T4(t4) => Or8::<T1, T2, T3, B, T5, T6, T7, T8>::T4(f(t4)),
+            Self::T5(t5) => Or8::<T1, T2, T3, B, T5, T6, T7, T8>::T5(t5),
+            Self::T6(t6) => Or8::<T1, T2, T3, B, T5, T6, T7, T8>::T6(t6),
+            Self::T7(t7) => Or8::<T1, T2, T3, B, T5, T6, T7, T8>::T7(t7),
+            Self::T8(t8) => Or8::<T1, T2, T3, B, T5, T6, T7, T8>::T8(t8),
+        }
+    }
+
+    /// Transforms the T5 value of the enum using a provided function,
+    /// maintaining other types as is.
+    #[must_use = "this returns the mapped enum, without modifying the original"]
+    pub fn map_t5<F, B>(self, f: F) -> Or8<T1, T2, T3, T4, B, T6, T7, T8>
+    where
+        F: FnOnce(T5) -> B,
+    {
+        match self {
+            Self::T1(t1) => Or8::<T1, T2, T3, T4, B, T6, T7, T8>::T1(t1),
+            Self::T2(t2) => Or8::<T1, T2, T3, T4, B, T6, T7, T8>::T2(t2),
+            Self::T3(t3) => Or8::<T1, T2, T3, T4, B, T6, T7, T8>::T3(t3),
+            Self::T4(t4) => Or8::<T1, T2, T3, T4, B, T6, T7, T8>::T4(t4),
+            Self::T5(t5) => Or8::<T1, T2, T3, T4, B, T6, T7, T8>::T5(f(t5)),
+            Self::T6(t6) => Or8::<T1, T2, T3, T4, B, T6, T7, T8>::T6(t6),
+            Self::T7(t7) => Or8::<T1, T2, T3, T4, B, T6, T7, T8>::T7(t7),
+            Self::T8(t8) => Or8::<T1, T2, T3, T4, B, T6, T7, T8>::T8(t8),
+        }
+    }
+
+    /// Transforms the T6 value of the enum using a provided function,
+    /// maintaining other types as is.
+    #[must_use = "this returns the mapped enum, without modifying the original"]
+    pub fn map_t6<F, B>(self, f: F) -> Or8<T1, T2, T3, T4, T5, B, T7, T8>
+    where
+        F: FnOnce(T6) -> B,
+    {
+        match self {
+            Self::T1(t1) => Or8::<T1, T2, T3, T4, T5, B, T7, T8>::T1(t1),
+            Self::T2(t2) => Or8::<T1, T2, T3, T4, T5, B, T7, T8>::T2(t2),
+            Self::T3(t3) => Or8::<T1, T2, T3, T4, T5, B, T7, T8>::T3(t3),
+            Self::T4(t4) => Or8::<T1, T2, T3, T4, T5, B, T7, T8>::T4(t4),
+            Self::T5(t5) => Or8::<T1, T2, T3, T4, T5, B, T7, T8>::T5(t5),
+            Self::T6(t6) => Or8::<T1, T2, T3, T4, T5, B, T7, T8>::T6(f(t6)),
+            Self::T7(t7) => Or8::<T1, T2, T3, T4, T5, B, T7, T8>::T7(t7),
+            Self::T8(t8) => Or8::<T1, T2, T3, T4, T5, B, T7, T8>::T8(t8),
+        }
+    }
+
+    /// Transforms the T7 value of the enum using a provided function,
+    /// maintaining other types as is.
+    #[must_use = "this returns the mapped enum, without modifying the original"]
+    pub fn map_t7<F, B>(self, f: F) -> Or8<T1, T2, T3, T4, T5, T6, B, T8>
+    where
+        F: FnOnce(T7) -> B,
+    {
+        match self {
+            Self::T1(t1) => Or8::<T1, T2, T3, T4, T5, T6, B, T8>::T1(t1),
+            Self::T2(t2) => Or8::<T1, T2, T3, T4, T5, T6, B, T8>::T2(t2),
+            Self::T3(t3) => Or8::<T1, T2, T3, T4, T5, T6, B, T8>::T3(t3),
+            Self::T4(t4) => Or8::<T1, T2, T3, T4, T5, T6, B, T8>::T4(t4),
+            Self::T5(t5) => Or8::<T1, T2, T3, T4, T5, T6, B, T8>::T5(t5),
+            Self::T6(t6) => Or8::<T1, T2, T3, T4, T5, T6, B, T8>::T6(t6),
+            Self::T7(t7) => Or8::<T1, T2, T3, T4, T5, T6, B, T8>::T7(f(t7)),
+            Self::T8(t8) => Or8::<T1, T2, T3, T4, T5, T6, B, T8>::T8(t8),
+        }
+    }
+
+    /// Transforms the T8 value of the enum using a provided function,
+    /// maintaining other types as is.
+    #[must_use = "this returns the mapped enum, without modifying the original"]
+    pub fn map_t8<F, B>(self, f: F) -> Or8<T1, T2, T3, T4, T5, T6, T7, B>
+    where
+        F: FnOnce(T8) -> B,
+    {
+        match self {
+            Self::T1(t1) => Or8::<T1, T2, T3, T4, T5, T6, T7, B>::T1(t1),
+            Self::T2(t2) => Or8::<T1, T2, T3, T4, T5, T6, T7, B>::T2(t2),
+            Self::T3(t3) => Or8::<T1, T2, T3, T4, T5, T6, T7, B>::T3(t3),
+            Self::T4(t4) => Or8::<T1, T2, T3, T4, T5, T6, T7, B>::T4(t4),
+            Self::T5(t5) => Or8::<T1, T2, T3, T4, T5, T6, T7, B>::T5(t5),
+            Self::T6(t6) => Or8::<T1, T2, T3, T4, T5, T6, T7, B>::T6(t6),
+            Self::T7(t7) => Or8::<T1, T2, T3, T4, T5, T6, T7, B>::T7(t7),
+            Self::T8(t8) => Or8::<T1, T2, T3, T4, T5, T6, T7, B>::T8(f(t8)),
+        }
+    }
+
+    /// Transforms the T1 value of the enum using a provided async function and awaits it,
+    /// maintaining other types as is.
+    pub async fn map_t1_async<F, Fut, B>(self, f: F) -> Or8<B, T2, T3, T4, T5, T6, T7, T8>
+    where
+        F: FnOnce(T1) -> Fut,
+        Fut: Future<Output = B>,
+    {
+        match self {
+            Self::T1(t1) => Or8::T1(f(t1).await),
+            Self::T2(t2) => Or8::T2(t2),
+            Self::T3(t3) => Or8::T3(t3),
+            Self::T4(t4) => Or8::T4(t4),
+            Self::T5(t5) => Or8::T5(t5),
+            Self::T6(t6) => Or8::T6(t6),
+            Self::T7(t7) => Or8::T7(t7),
+            Self::T8(t8) => Or8::T8(t8),
+        }
+    }
+
+    /// Transforms the T2 value of the enum using a provided async function and awaits it,
+    /// maintaining other types as is.
+    pub async fn map_t2_async<F, Fut, B>(self, f: F) -> Or8<T1, B, T3, T4, T5, T6, T7, T8>
+    where
+        F: FnOnce(T2) -> Fut,
+        Fut: Future<Output = B>,
+    {
+        match self {
+            Self::T1(t1) => Or8::T1(t1),
+            Self::T2(t2) => Or8::T2(f(t2).await),
+            Self::T3(t3) => Or8::T3(t3),
+            Self::T4(t4) => Or8::T4(t4),
+            Self::T5(t5) => Or8::T5(t5),
+            Self::T6(t6) => Or8::T6(t6),
+            Self::T7(t7) => Or8::T7(t7),
+            Self::T8(t8) => Or8::T8(t8),
+        }
+    }
+
+    /// Transforms the T3 value of the enum using a provided async function and awaits it,
+    /// maintaining other types as is.
+    pub async fn map_t3_async<F, Fut, B>(self, f: F) -> Or8<T1, T2, B, T4, T5, T6, T7, T8>
+    where
+        F: FnOnce(T3) -> Fut,
+        Fut: Future<Output = B>,
+    {
+        match self {
+            Self::T1(t1) => Or8::T1(t1),
+            Self::T2(t2) => Or8::T2(t2),
+            Self::T3(t3) => Or8::T3(f(t3).await),
+            Self::T4(t4) => Or8::T4(t4),
+            Self::T5(t5) => Or8::T5(t5),
+            Self::T6(t6) => Or8::T6(t6),
+            Self::T7(t7) => Or8::T7(t7),
+            Self::T8(t8) => Or8::T8(t8),
+        }
+    }
+
+    /// Transforms the T4 value of the enum using a provided async function and awaits it,
+    /// maintaining other types as is.
+    pub async fn map_t4_async<F, Fut, B>(self, f: F) -> Or8<T1, T2, T3, B, T5, T6, T7, T8>
+    where
+        F: FnOnce(T4) -> Fut,
+        Fut: Future<Output = B>,
+    {
+        match self {
+            Self::T1(t1) => Or8::T1(t1),
+            Self::T2(t2) => Or8::T2(t2),
+            Self::T3(t3) => Or8::T3(t3),
+            Self::T4(t4) => Or8::T4(f(t4).await),
+            Self::T5(t5) => Or8::T5(t5),
+            Self::T6(t6) => Or8::T6(t6),
+            Self::T7(t7) => Or8::T7(t7),
+            Self::T8(t8) => Or8::T8(t8),
+        }
+    }
+
+    /// Transforms the T5 value of the enum using a provided async function and awaits it,
+    /// maintaining other types as is.
+    pub async fn map_t5_async<F, Fut, B>(self, f: F) -> Or8<T1, T2, T3, T4, B, T6, T7, T8>
+    where
+        F: FnOnce(T5) -> Fut,
+        Fut: Future<Output = B>,
+    {
+        match self {
+            Self::T1(t1) => Or8::T1(t1),
+            Self::T2(t2) => Or8::T2(t2),
+            Self::T3(t3) => Or8::T3(t3),
+            Self::T4(t4) => Or8::T4(t4),
+            Self::T5(t5) => Or8::T5(f(t5).await),
+            Self::T6(t6) => Or8::T6(t6),
+            Self::T7(t7) => Or8::T7(t7),
+            Self::T8(t8) => Or8::T8(t8),
+        }
+    }
+
+    /// Transforms the T6 value of the enum using a provided async function and awaits it,
+    /// maintaining other types as is.
+    pub async fn map_t6_async<F, Fut, B>(self, f: F) -> Or8<T1, T2, T3, T4, T5, B, T7, T8>
+    where
+        F: FnOnce(T6) -> Fut,
+        Fut: Future<Output = B>,
+    {
+        match self {
+            Self::T1(t1) => Or8::T1(t1),
+            Self::T2(t2) => Or8::T2(t2),
+            Self::T3(t3) => Or8::T3(t3),
+            Self::T4(t4) => Or8::T4(t4),
+            Self::T5(t5) => Or8::T5(t5),
+            Self::T6(t6) => Or8::T6(f(t6).await),
+            Self::T7(t7) => Or8::T7(t7),
+            Self::T8(t8) => Or8::T8(t8),
+        }
+    }
+
+    /// Transforms the T7 value of the enum using a provided async function and awaits it,
+    /// maintaining other types as is.
+    pub async fn map_t7_async<F, Fut, B>(self, f: F) -> Or8<T1, T2, T3, T4, T5, T6, B, T8>
+    where
+        F: FnOnce(T7) -> Fut,
+        Fut: Future<Output = B>,
+    {
+        match self {
+            Self::T1(t1) => Or8::T1(t1),
+            Self::T2(t2) => Or8::T2(t2),
+            Self::T3(t3) => Or8::T3(t3),
+            Self::T4(t4) => Or8::T4(t4),
+            Self::T5(t5) => Or8::T5(t5),
+            Self::T6(t6) => Or8::T6(t6),
+            Self::T7(t7) => Or8::T7(f(t7).await),
+            Self::T8(t8) => Or8::T8(t8),
+        }
+    }
+
+    /// Transforms the T8 value of the enum using a provided async function and awaits it,
+    /// maintaining other types as is.
+    pub async fn map_t8_async<F, Fut, B>(self, f: F) -> Or8<T1, T2, T3, T4, T5, T6, T7, B>
+    where
+        F: FnOnce(T8) -> Fut,
+        Fut: Future<Output = B>,
+    {
+        match self {
+            Self::T1(t1) => Or8::T1(t1),
+            Self::T2(t2) => Or8::T2(t2),
+            Self::T3(t3) => Or8::T3(t3),
+            Self::T4(t4) => Or8::T4(t4),
+            Self::T5(t5) => Or8::T5(t5),
+            Self::T6(t6) => Or8::T6(t6),
+            Self::T7(t7) => Or8::T7(t7),
+            Self::T8(t8) => Or8::T8(f(t8).await),
+        }
+    }
+
+    /// Consolidates the `Or8` enum into a single value of type `T`,
+    /// by applying provided functions.
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn fold<T, F1, F2, F3, F4, F5, F6, F7, F8>(
+        self,
+        f1: F1,
+        f2: F2,
+        f3: F3,
+        f4: F4,
+        f5: F5,
+        f6: F6,
+        f7: F7,
+        f8: F8,
+    ) -> T
+    where
+        F1: FnOnce(T1) -> T,
+        F2: FnOnce(T2) -> T,
+        F3: FnOnce(T3) -> T,
+        F4: FnOnce(T4) -> T,
+        F5: FnOnce(T5) -> T,
+        F6: FnOnce(T6) -> T,
+        F7: FnOnce(T7) -> T,
+        F8: FnOnce(T8) -> T,
+    {
+        match self {
+            Self::T1(t1) => f1(t1),
+            Self::T2(t2) => f2(t2),
+            Self::T3(t3) => f3(t3),
+            Self::T4(t4) => f4(t4),
+            Self::T5(t5) => f5(t5),
+            Self::T6(t6) => f6(t6),
+            Self::T7(t7) => f7(t7),
+            Self::T8(t8) => f8(t8),
+        }
+    }
+
+    /// Consolidates the `Or8` enum into a single value of type `T` by applying provided
+    /// fallible functions, returning the error of the applied one, so that the functions can use `?`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_fold<T, E, F1, F2, F3, F4, F5, F6, F7, F8>(
+        self,
+        f1: F1,
+        f2: F2,
+        f3: F3,
+        f4: F4,
+        f5: F5,
+        f6: F6,
+        f7: F7,
+        f8: F8,
+    ) -> Result<T, E>
+    where
+        F1: FnOnce(T1) -> Result<T, E>,
+        F2: FnOnce(T2) -> Result<T, E>,
+        F3: FnOnce(T3) -> Result<T, E>,
+        F4: FnOnce(T4) -> Result<T, E>,
+        F5: FnOnce(T5) -> Result<T, E>,
+        F6: FnOnce(T6) -> Result<T, E>,
+        F7: FnOnce(T7) -> Result<T, E>,
+        F8: FnOnce(T8) -> Result<T, E>,
+    {
+        match self {
+            Self::T1(t1) => f1(t1),
+            Self::T2(t2) => f2(t2),
+            Self::T3(t3) => f3(t3),
+            Self::T4(t4) => f4(t4),
+            Self::T5(t5) => f5(t5),
+            Self::T6(t6) => f6(t6),
+            Self::T7(t7) => f7(t7),
+            Self::T8(t8) => f8(t8),
+        }
+    }
+
+    /// Consolidates the `Or8` enum into a single value of type `T`,
+    /// by applying provided async functions and awaiting the result.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn fold_async<
+        T,
+        F1,
+        Fut1,
+        F2,
+        Fut2,
+        F3,
+        Fut3,
+        F4,
+        Fut4,
+        F5,
+        Fut5,
+        F6,
+        Fut6,
+        F7,
+        Fut7,
+        F8,
+        Fut8,
+    >(
+        self,
+        f1: F1,
+        f2: F2,
+        f3: F3,
+        f4: F4,
+        f5: F5,
+        f6: F6,
+        f7: F7,
+        f8: F8,
+    ) -> T
+    where
+        F1: FnOnce(T1) -> Fut1,
+        Fut1: Future<Output = T>,
+        F2: FnOnce(T2) -> Fut2,
+        Fut2: Future<Output = T>,
+        F3: FnOnce(T3) -> Fut3,
+        Fut3: Future<Output = T>,
+        F4: FnOnce(T4) -> Fut4,
+        Fut4: Future<Output = T>,
+        F5: FnOnce(T5) -> Fut5,
+        Fut5: Future<Output = T>,
+        F6: FnOnce(T6) -> Fut6,
+        Fut6: Future<Output = T>,
+        F7: FnOnce(T7) -> Fut7,
+        Fut7: Future<Output = T>,
+        F8: FnOnce(T8) -> Fut8,
+        Fut8: Future<Output = T>,
+    {
+        match self {
+            Self::T1(t1) => f1(t1).await,
+            Self::T2(t2) => f2(t2).await,
+            Self::T3(t3) => f3(t3).await,
+            Self::T4(t4) => f4(t4).await,
+            Self::T5(t5) => f5(t5).await,
+            Self::T6(t6) => f6(t6).await,
+            Self::T7(t7) => f7(t7).await,
+            Self::T8(t8) => f8(t8).await,
+        }
+    }
+
+    /// Handles the T1 value of the enum using a provided function, and leaves the other
+    /// variants to the subsequent `when_tx` calls.
+    pub fn when_t1<R, F>(self, f: F) -> When<R, Or8<Infallible, T2, T3, T4, T5, T6, T7, T8>>
+    where
+        F: FnOnce(T1) -> R,
+    {
+        match self {
+            Self::T1(t1) => When::handled(f(t1)),
+            Self::T2(t2) => When::pending(Or8::T2(t2)),
+            Self::T3(t3) => When::pending(Or8::T3(t3)),
+            Self::T4(t4) => When::pending(Or8::T4(t4)),
+            Self::T5(t5) => When::pending(Or8::T5(t5)),
+            Self::T6(t6) => When::pending(Or8::T6(t6)),
+            Self::T7(t7) => When::pending(Or8::T7(t7)),
+            Self::T8(t8) => When::pending(Or8::T8(t8)),
+        }
+    }
+
+    /// Handles the T2 value of the enum using a provided function, and leaves the other
+    /// variants to the subsequent `when_tx` calls.
+    pub fn when_t2<R, F>(self, f: F) -> When<R, Or8<T1, Infallible, T3, T4, T5, T6, T7, T8>>
+    where
+        F: FnOnce(T2) -> R,
+    {
+        match self {
+            Self::T1(t1) => When::pending(Or8::T1(t1)),
+            Self::T2(t2) => When::handled(f(t2)),
+            Self::T3(t3) => When::pending(Or8::T3(t3)),
+            Self::T4(t4) => When::pending(Or8::T4(t4)),
+            Self::T5(t5) => When::pending(Or8::T5(t5)),
+            Self::T6(t6) => When::pending(Or8::T6(t6)),
+            Self::T7(t7) => When::pending(Or8::T7(t7)),
+            Self::T8(t8) => When::pending(Or8::T8(t8)),
+        }
+    }
+
+    /// Handles the T3 value of the enum using a provided function, and leaves the other
+    /// variants to the subsequent `when_tx` calls.
+    pub fn when_t3<R, F>(self, f: F) -> When<R, Or8<T1, T2, Infallible, T4, T5, T6, T7, T8>>
+    where
+        F: FnOnce(T3) -> R,
+    {
+        match self {
+            Self::T1(t1) => When::pending(Or8::T1(t1)),
+            Self::T2(t2) => When::pending(Or8::T2(t2)),
+            Self::T3(t3) => When::handled(f(t3)),
+            Self::T4(t4) => When::pending(Or8::T4(t4)),
+            Self::T5(t5) => When::pending(Or8::T5(t5)),
+            Self::T6(t6) => When::pending(Or8::T6(t6)),
+            Self::T7(t7) => When::pending(Or8::T7(t7)),
+            Self::T8(t8) => When::pending(Or8::T8(t8)),
+        }
+    }
+
+    /// Handles the T4 value of the enum using a provided function, and leaves the other
+    /// variants to the subsequent `when_tx` calls.
+    pub fn when_t4<R, F>(self, f: F) -> When<R, Or8<T1, T2, T3, Infallible, T5, T6, T7, T8>>
+    where
+        F: FnOnce(T4) -> R,
+    {
+        match self {
+            Self::T1(t1) => When::pending(Or8::T1(t1)),
+            Self::T2(t2) => When::pending(Or8::T2(t2)),
+            Self::T3(t3) => When::pending(Or8::T3(t3)),
+            Self::T4(t4) => When::handled(f(t4)),
+            Self::T5(t5) => When::pending(Or8::T5(t5)),
+            Self::T6(t6) => When::pending(Or8::T6(t6)),
+            Self::T7(t7) => When::pending(Or8::T7(t7)),
+            Self::T8(t8) => When::pending(Or8::T8(t8)),
+        }
+    }
+
+    /// Handles the T5 value of the enum using a provided function, and leaves the other
+    /// variants to the subsequent `when_tx` calls.
+    pub fn when_t5<R, F>(self, f: F) -> When<R, Or8<T1, T2, T3, T4, Infallible, T6, T7, T8>>
+    where
+        F: FnOnce(T5) -> R,
+    {
+        match self {
+            Self::T1(t1) => When::pending(Or8::T1(t1)),
+            Self::T2(t2) => When::pending(Or8::T2(t2)),
+            Self::T3(t3) => When::pending(Or8::T3(t3)),
+            Self::T4(t4) => When::pending(Or8::T4(t4)),
+            Self::T5(t5) => When::handled(f(t5)),
+            Self::T6(t6) => When::pending(Or8::T6(t6)),
+            Self::T7(t7) => When::pending(Or8::T7(t7)),
+            Self::T8(t8) => When::pending(Or8::T8(t8)),
+        }
+    }
+
+    /// Handles the T6 value of the enum using a provided function, and leaves the other
+    /// variants to the subsequent `when_tx` calls.
+    pub fn when_t6<R, F>(self, f: F) -> When<R, Or8<T1, T2, T3, T4, T5, Infallible, T7, T8>>
+    where
+        F: FnOnce(T6) -> R,
+    {
+        match self {
+            Self::T1(t1) => When::pending(Or8::T1(t1)),
+            Self::T2(t2) => When::pending(Or8::T2(t2)),
+            Self::T3(t3) => When::pending(Or8::T3(t3)),
+            Self::T4(t4) => When::pending(Or8::T4(t4)),
+            Self::T5(t5) => When::pending(Or8::T5(t5)),
+            Self::T6(t6) => When::handled(f(t6)),
+            Self::T7(t7) => When::pending(Or8::T7(t7)),
+            Self::T8(t8) => When::pending(Or8::T8(t8)),
+        }
+    }
+
+    /// Handles the T7 value of the enum using a provided function, and leaves the other
+    /// variants to the subsequent `when_tx` calls.
+    pub fn when_t7<R, F>(self, f: F) -> When<R, Or8<T1, T2, T3, T4, T5, T6, Infallible, T8>>
+    where
+        F: FnOnce(T7) -> R,
+    {
+        match self {
+            Self::T1(t1) => When::pending(Or8::T1(t1)),
+            Self::T2(t2) => When::pending(Or8::T2(t2)),
+            Self::T3(t3) => When::pending(Or8::T3(t3)),
+            Self::T4(t4) => When::pending(Or8::T4(t4)),
+            Self::T5(t5) => When::pending(Or8::T5(t5)),
+            Self::T6(t6) => When::pending(Or8::T6(t6)),
+            Self::T7(t7) => When::handled(f(t7)),
+            Self::T8(t8) => When::pending(Or8::T8(t8)),
+        }
+    }
+
+    /// Handles the T8 value of the enum using a provided function, and leaves the other
+    /// variants to the subsequent `when_tx` calls.
+    pub fn when_t8<R, F>(self, f: F) -> When<R, Or8<T1, T2, T3, T4, T5, T6, T7, Infallible>>
+    where
+        F: FnOnce(T8) -> R,
+    {
+        match self {
+            Self::T1(t1) => When::pending(Or8::T1(t1)),
+            Self::T2(t2) => When::pending(Or8::T2(t2)),
+            Self::T3(t3) => When::pending(Or8::T3(t3)),
+            Self::T4(t4) => When::pending(Or8::T4(t4)),
+            Self::T5(t5) => When::pending(Or8::T5(t5)),
+            Self::T6(t6) => When::pending(Or8::T6(t6)),
+            Self::T7(t7) => When::pending(Or8::T7(t7)),
+            Self::T8(t8) => When::handled(f(t8)),
+        }
+    }
+
+    /// Converts the enum into a tuple of Options, where only the slot of the active variant is `Some`.
+    #[must_use]
+    pub fn into_options(
+        self,
+    ) -> (
+        Option<T1>,
+        Option<T2>,
+        Option<T3>,
+        Option<T4>,
+        Option<T5>,
+        Option<T6>,
+        Option<T7>,
+        Option<T8>,
+    ) {
+        match self {
+            Self::T1(t1) => (Some(t1), None, None, None, None, None, None, None),
+            Self::T2(t2) => (None, Some(t2), None, None, None, None, None, None),
+            Self::T3(t3) => (None, None, Some(t3), None, None, None, None, None),
+            Self::T4(t4) => (None, None, None, Some(t4), None, None, None, None),
+            Self::T5(t5) => (None, None, None, None, Some(t5), None, None, None),
+            Self::T6(t6) => (None, None, None, None, None, Some(t6), None, None),
+            Self::T7(t7) => (None, None, None, None, None, None, Some(t7), None),
+            Self::T8(t8) => (None, None, None, None, None, None, None, Some(t8)),
+        }
+    }
+
+    /// Borrows the enum as a tuple of Options, where only the slot of the active variant is `Some`.
+    #[must_use]
+    pub fn as_options(
+        &self,
+    ) -> (
+        Option<&T1>,
+        Option<&T2>,
+        Option<&T3>,
+        Option<&T4>,
+        Option<&T5>,
+        Option<&T6>,
+        Option<&T7>,
+        Option<&T8>,
+    ) {
+        self.as_ref().into_options()
+    }
+
+    /// Builds the enum from a tuple of Options, which succeeds only when exactly one of them is `Some`.
+    /// Otherwise the tuple is handed back as is.
+    pub fn try_from_options(
+        options: (
+            Option<T1>,
+            Option<T2>,
+            Option<T3>,
+            Option<T4>,
+            Option<T5>,
+            Option<T6>,
+            Option<T7>,
+            Option<T8>,
+        ),
+    ) -> Result<
+        Self,
+        (
+            Option<T1>,
+            Option<T2>,
+            Option<T3>,
+            Option<T4>,
+            Option<T5>,
+            Option<T6>,
+            Option<T7>,
+            Option<T8>,
+        ),
+    > {
+        match options {
+            (Some(t1), None, None, None, None, None, None, None) => Ok(Self::T1(t1)),
+            (None, Some(t2), None, None, None, None, None, None) => Ok(Self::T2(t2)),
+            (None, None, Some(t3), None, None, None, None, None) => Ok(Self::T3(t3)),
+            (None, None, None, Some(t4), None, None, None, None) => Ok(Self::T4(t4)),
+            (None, None, None, None, Some(t5), None, None, None) => Ok(Self::T5(t5)),
+            (None, None, None, None, None, Some(t6), None, None) => Ok(Self::T6(t6)),
+            (None, None, None, None, None, None, Some(t7), None) => Ok(Self::T7(t7)),
+            (None, None, None, None, None, None, None, Some(t8)) => Ok(Self::T8(t8)),
+            options => Err(options),
+        }
+    }
+}
+
+impl_forwarding!(Or8, Or8Items, Or8Discriminant {
+    T1(t1, e1, I1, i1, E1, 0, T1),
+    T2(t2, e2, I2, i2, E2, 1, T2),
+    T3(t3, e3, I3, i3, E3, 2, T3),
+    T4(t4, e4, I4, i4, E4, 3, T4),
+    T5(t5, e5, I5, i5, E5, 4, T5),
+    T6(t6, e6, I6, i6, E6, 5, T6),
+    T7(t7, e7, I7, i7, E7, 6, T7),
+    T8(t8, e8, I8, i8, E8, 7, T8)
+});
+
+impl<T1, T2, T3, T4, T5, T6, T7, T8> Or8<T1, T2, T3, T4, T5, T6, T7, T8> {
+    /// The number of the variants, which is the size of the arrays returned by
+    /// [`Or8::variant_sizes`] and friends, indexed by [`Or8::variant_index`].
+    pub const VARIANT_COUNT: usize = 8;
+
+    /// Returns the 0-based position of the active variant, like `0` for `T1`.
+    ///
+    /// This can index a table of handlers or counters, with `VARIANT_COUNT` entries.
+    #[must_use]
+    pub const fn variant_index(&self) -> usize {
+        match self {
+            Self::T1(_) => 0,
+            Self::T2(_) => 1,
+            Self::T3(_) => 2,
+            Self::T4(_) => 3,
+            Self::T5(_) => 4,
+            Self::T6(_) => 5,
+            Self::T7(_) => 6,
+            Self::T8(_) => 7,
+        }
+    }
+
+    /// Returns the sizes of the payload types, in the order of the variants.
+    ///
+    /// Comparing them with the size of the enum tells whether one oversized variant bloats
+    /// the whole enum, in which case it can be shrunk by [`Or8::boxed`].
+    pub const fn variant_sizes() -> [usize; 8] {
+        [
+            size_of::<T1>(),
+            size_of::<T2>(),
+            size_of::<T3>(),
+            size_of::<T4>(),
+            size_of::<T5>(),
+            size_of::<T6>(),
+            size_of::<T7>(),
+            size_of::<T8>(),
+        ]
+    }
+
+    /// Returns the alignments of the payload types, in the order of the variants.
+    pub const fn variant_aligns() -> [usize; 8] {
+        [
+            align_of::<T1>(),
+            align_of::<T2>(),
+            align_of::<T3>(),
+            align_of::<T4>(),
+            align_of::<T5>(),
+            align_of::<T6>(),
+            align_of::<T7>(),
+            align_of::<T8>(),
+        ]
+    }
+
+    /// Returns the size of the largest payload type, which the size of the enum is at least.
+    pub const fn max_variant_size() -> usize {
+        let sizes = Self::variant_sizes();
+        let mut max = 0;
+        let mut i = 0;
+        while i < sizes.len() {
+            if sizes[i] > max {
+                max = sizes[i];
+            }
+            i += 1;
+        }
+        max
+    }
+}
+
+impl<T2, T3, T4, T5, T6, T7, T8> Or8<Infallible, T2, T3, T4, T5, T6, T7, T8> {
+    /// Removes the uninhabited `T1` position from `Or8`, which is useful to simplify
+    /// an `Or` type once some of its branches become impossible.
+    #[must_use]
+    pub fn compact_t1(self) -> Or7<T2, T3, T4, T5, T6, T7, T8> {
+        match self {
+            Self::T1(never) => match never {},
+            Self::T2(t2) => Or7::T1(t2),
+            Self::T3(t3) => Or7::T2(t3),
+            Self::T4(t4) => Or7::T3(t4),
+            Self::T5(t5) => Or7::T4(t5),
+            Self::T6(t6) => Or7::T5(t6),
+            Self::T7(t7) => Or7::T6(t7),
+            Self::T8(t8) => Or7::T7(t8),
+        }
+    }
+}
+
+impl<T1, T3, T4, T5, T6, T7, T8> Or8<T1, Infallible, T3, T4, T5, T6, T7, T8> {
+    /// Removes the uninhabited `T2` position from `Or8`, which is useful to simplify
+    /// an `Or` type once some of its branches become impossible.
+    #[must_use]
+    pub fn compact_t2(self) -> Or7<T1, T3, T4, T5, T6, T7, T8> {
+        match self {
+            Self::T1(t1) => Or7::T1(t1),
+            Self::T2(never) => match never {},
+            Self::T3(t3) => Or7::T2(t3),
+            Self::T4(t4) => Or7::T3(t4),
+            Self::T5(t5) => Or7::T4(t5),
+            Self::T6(t6) => Or7::T5(t6),
+            Self::T7(t7) => Or7::T6(t7),
+            Self::T8(t8) => Or7::T7(t8),
+        }
+    }
+}
+
+impl<T1, T2, T4, T5, T6, T7, T8> Or8<T1, T2, Infallible, T4, T5, T6, T7, T8> {
+    /// Removes the uninhabited `T3` position from `Or8`, which is useful to simplify
+    /// an `Or` type once some of its branches become impossible.
+    #[must_use]
+    pub fn compact_t3(self) -> Or7<T1, T2, T4, T5, T6, T7, T8> {
+        match self {
+            Self::T1(t1) => Or7::T1(t1),
+            Self::T2(t2) => Or7::T2(t2),
+            Self::T3(never) => match never {},
+            Self::T4(t4) => Or7::T3(t4),
+            Self::T5(t5) => Or7::T4(t5),
+            Self::T6(t6) => Or7::T5(t6),
+            Self::T7(t7) => Or7::T6(t7),
+            Self::T8(t8) => Or7::T7(t8),
+        }
+    }
+}
+
+impl<T1, T2, T3, T5, T6, T7, T8> Or8<T1, T2, T3, Infallible, T5, T6, T7, T8> {
+    /// Removes the uninhabited `T4` position from `Or8`, which is useful to simplify
+    /// an `Or` type once some of its branches become impossible.
+    #[must_use]
+    pub fn compact_t4(self) -> Or7<T1, T2, T3, T5, T6, T7, T8> {
+        match self {
+            Self::T1(t1) => Or7::T1(t1),
+            Self::T2(t2) => Or7::T2(t2),
+            Self::T3(t3) => Or7::T3(t3),
+            Self::T4(never) => match never {},
+            Self::T5(t5) => Or7::T4(t5),
+            Self::T6(t6) => Or7::T5(t6),
+            Self::T7(t7) => Or7::T6(t7),
+            Self::T8(t8) => Or7::T7(t8),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T6, T7, T8> Or8<T1, T2, T3, T4, Infallible, T6, T7, T8> {
+    /// Removes the uninhabited `T5` position from `Or8`, which is useful to simplify
+    /// an `Or` type once some of its branches become impossible.
+    #[must_use]
+    pub fn compact_t5(self) -> Or7<T1, T2, T3, T4, T6, T7, T8> {
+        match self {
+            Self::T1(t1) => Or7::T1(t1),
+            Self::T2(t2) => Or7::T2(t2),
+            Self::T3(t3) => Or7::T3(t3),
+            Self::T4(t4) => Or7::T4(t4),
+            Self::T5(never) => match never {},
+            Self::T6(t6) => Or7::T5(t6),
+            Self::T7(t7) => Or7::T6(t7),
+            Self::T8(t8) => Or7::T7(t8),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T7, T8> Or8<T1, T2, T3, T4, T5, Infallible, T7, T8> {
+    /// Removes the uninhabited `T6` position from `Or8`, which is useful to simplify
+    /// an `Or` type once some of its branches become impossible.
+    #[must_use]
+    pub fn compact_t6(self) -> Or7<T1, T2, T3, T4, T5, T7, T8> {
+        match self {
+            Self::T1(t1) => Or7::T1(t1),
+            Self::T2(t2) => Or7::T2(t2),
+            Self::T3(t3) => Or7::T3(t3),
+            Self::T4(t4) => Or7::T4(t4),
+            Self::T5(t5) => Or7::T5(t5),
+            Self::T6(never) => match never {},
+            Self::T7(t7) => Or7::T6(t7),
+            Self::T8(t8) => Or7::T7(t8),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T8> Or8<T1, T2, T3, T4, T5, T6, Infallible, T8> {
+    /// Removes the uninhabited `T7` position from `Or8`, which is useful to simplify
+    /// an `Or` type once some of its branches become impossible.
+    #[must_use]
+    pub fn compact_t7(self) -> Or7<T1, T2, T3, T4, T5, T6, T8> {
+        match self {
+            Self::T1(t1) => Or7::T1(t1),
+            Self::T2(t2) => Or7::T2(t2),
+            Self::T3(t3) => Or7::T3(t3),
+            Self::T4(t4) => Or7::T4(t4),
+            Self::T5(t5) => Or7::T5(t5),
+            Self::T6(t6) => Or7::T6(t6),
+            Self::T7(never) => match never {},
+            Self::T8(t8) => Or7::T7(t8),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7> Or8<T1, T2, T3, T4, T5, T6, T7, Infallible> {
+    /// Removes the uninhabited `T8` position from `Or8`, which is useful to simplify
+    /// an `Or` type once some of its branches become impossible.
+    #[must_use]
+    pub fn compact_t8(self) -> Or7<T1, T2, T3, T4, T5, T6, T7> {
+        match self {
+            Self::T1(t1) => Or7::T1(t1),
+            Self::T2(t2) => Or7::T2(t2),
+            Self::T3(t3) => Or7::T3(t3),
+            Self::T4(t4) => Or7::T4(t4),
+            Self::T5(t5) => Or7::T5(t5),
+            Self::T6(t6) => Or7::T6(t6),
+            Self::T7(t7) => Or7::T7(t7),
+            Self::T8(never) => match never {},
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7, T8> Or8<T1, T2, T3, T4, T5, T6, T7, T8> {
+    /// Converts the enum into a `Result`, treating `T1` as the ok value and the others as the
+    /// error. The conversion is lossless, and can be reverted by `From`.
+    pub fn into_result(self) -> Result<T1, Or7<T2, T3, T4, T5, T6, T7, T8>> {
+        match self {
+            Self::T1(t1) => Ok(t1),
+            Self::T2(t2) => Err(Or7::T1(t2)),
+            Self::T3(t3) => Err(Or7::T2(t3)),
+            Self::T4(t4) => Err(Or7::T3(t4)),
+            Self::T5(t5) => Err(Or7::T4(t5)),
+            Self::T6(t6) => Err(Or7::T5(t6)),
+            Self::T7(t7) => Err(Or7::T6(t7)),
+            Self::T8(t8) => Err(Or7::T7(t8)),
+        }
+    }
+
+    /// Converts the enum into a `Result`, treating `T1` as the ok value and converting the
+    /// others into the error type `E`.
+    pub fn try_into_result<E>(self) -> Result<T1, E>
+    where
+        T2: Into<E>,
+        T3: Into<E>,
+        T4: Into<E>,
+        T5: Into<E>,
+        T6: Into<E>,
+        T7: Into<E>,
+        T8: Into<E>,
+    {
+        match self {
+            Self::T1(t1) => Ok(t1),
+            Self::T2(t2) => Err(t2.into()),
+            Self::T3(t3) => Err(t3.into()),
+            Self::T4(t4) => Err(t4.into()),
+            Self::T5(t5) => Err(t5.into()),
+            Self::T6(t6) => Err(t6.into()),
+            Self::T7(t7) => Err(t7.into()),
+            Self::T8(t8) => Err(t8.into()),
+        }
+    }
+}
+
+/// Converts the ok value into `T1` and the error into the remaining positions, which reverts
+/// `into_result`.
+impl<T1, T2, T3, T4, T5, T6, T7, T8> From<Result<T1, Or7<T2, T3, T4, T5, T6, T7, T8>>>
+    for Or8<T1, T2, T3, T4, T5, T6, T7, T8>
+{
+    fn from(result: Result<T1, Or7<T2, T3, T4, T5, T6, T7, T8>>) -> Self {
+        match result {
+            Ok(t1) => Self::T1(t1),
+            Err(Or7::T1(t2)) => Self::T2(t2),
+            Err(Or7::T2(t3)) => Self::T3(t3),
+            Err(Or7::T3(t4)) => Self::T4(t4),
+            Err(Or7::T4(t5)) => Self::T5(t5),
+            Err(Or7::T5(t6)) => Self::T6(t6),
+            Err(Or7::T6(t7)) => Self::T7(t7),
+            Err(Or7::T7(t8)) => Self::T8(t8),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7> Or8<T1, T2, T3, T4, T5, T6, T7, ()> {
+    /// Converts the enum into an `Option`, which is `None` for the `()` in the last position.
+    /// The conversion is lossless, and can be reverted by `From`.
+    pub fn into_option(self) -> Option<Or7<T1, T2, T3, T4, T5, T6, T7>> {
+        match self {
+            Self::T1(t1) => Some(Or7::T1(t1)),
+            Self::T2(t2) => Some(Or7::T2(t2)),
+            Self::T3(t3) => Some(Or7::T3(t3)),
+            Self::T4(t4) => Some(Or7::T4(t4)),
+            Self::T5(t5) => Some(Or7::T5(t5)),
+            Self::T6(t6) => Some(Or7::T6(t6)),
+            Self::T7(t7) => Some(Or7::T7(t7)),
+            Self::T8(()) => None,
+        }
+    }
+}
+
+/// Converts `None` into the `()` in the last position, which reverts `into_option`.
+impl<T1, T2, T3, T4, T5, T6, T7> From<Option<Or7<T1, T2, T3, T4, T5, T6, T7>>>
+    for Or8<T1, T2, T3, T4, T5, T6, T7, ()>
+{
+    fn from(option: Option<Or7<T1, T2, T3, T4, T5, T6, T7>>) -> Self {
+        match option {
+            Some(Or7::T1(t1)) => Self::T1(t1),
+            Some(Or7::T2(t2)) => Self::T2(t2),
+            Some(Or7::T3(t3)) => Self::T3(t3),
+            Some(Or7::T4(t4)) => Self::T4(t4),
+            Some(Or7::T5(t5)) => Self::T5(t5),
+            Some(Or7::T6(t6)) => Self::T6(t6),
+            Some(Or7::T7(t7)) => Self::T7(t7),
+            None => Self::T8(()),
+        }
+    }
+}
+
+impl<R, T1, T2, T3, T4, T5, T6, T7, T8> When<R, Or8<T1, T2, T3, T4, T5, T6, T7, T8>> {
+    /// Handles the T1 value of the pending `Or8` using a provided function,
+    /// unless a previous `when_tx` has already handled the value.
+    pub fn when_t1<F>(self, f: F) -> When<R, Or8<Infallible, T2, T3, T4, T5, T6, T7, T8>>
+    where
+        F: FnOnce(T1) -> R,
+    {
+        match self.into_state() {
+            Ok(result) => When::handled(result),
+            Err(or) => or.when_t1(f),
+        }
+    }
+
+    /// Handles the T2 value of the pending `Or8` using a provided function,
+    /// unless a previous `when_tx` has already handled the value.
+    pub fn when_t2<F>(self, f: F) -> When<R, Or8<T1, Infallible, T3, T4, T5, T6, T7, T8>>
+    where
+        F: FnOnce(T2) -> R,
+    {
+        match self.into_state() {
+            Ok(result) => When::handled(result),
+            Err(or) => or.when_t2(f),
+        }
+    }
+
+    /// Handles the T3 value of the pending `Or8` using a provided function,
+    /// unless a previous `when_tx` has already handled the value.
+    pub fn when_t3<F>(self, f: F) -> When<R, Or8<T1, T2, Infallible, T4, T5, T6, T7, T8>>
+    where
+        F: FnOnce(T3) -> R,
+    {
+        match self.into_state() {
+            Ok(result) => When::handled(result),
+            Err(or) => or.when_t3(f),
+        }
+    }
+
+    /// Handles the T4 value of the pending `Or8` using a provided function,
+    /// unless a previous `when_tx` has already handled the value.
+    pub fn when_t4<F>(self, f: F) -> When<R, Or8<T1, T2, T3, Infallible, T5, T6, T7, T8>>
+    where
+        F: FnOnce(T4) -> R,
+    {
+        match self.into_state() {
+            Ok(result) => When::handled(result),
+            Err(or) => or.when_t4(f),
+        }
+    }
+
+    /// Handles the T5 value of the pending `Or8` using a provided function,
+    /// unless a previous `when_tx` has already handled the value.
+    pub fn when_t5<F>(self, f: F) -> When<R, Or8<T1, T2, T3, T4, Infallible, T6, T7, T8>>
+    where
+        F: FnOnce(T5) -> R,
+    {
+        match self.into_state() {
+            Ok(result) => When::handled(result),
+            Err(or) => or.when_t5(f),
+        }
+    }
+
+    /// Handles the T6 value of the pending `Or8` using a provided function,
+    /// unless a previous `when_tx` has already handled the value.
+    pub fn when_t6<F>(self, f: F) -> When<R, Or8<T1, T2, T3, T4, T5, Infallible, T7, T8>>
+    where
+        F: FnOnce(T6) -> R,
+    {
+        match self.into_state() {
+            Ok(result) => When::handled(result),
+            Err(or) => or.when_t6(f),
+        }
+    }
+
+    /// Handles the T7 value of the pending `Or8` using a provided function,
+    /// unless a previous `when_tx` has already handled the value.
+    pub fn when_t7<F>(self, f: F) -> When<R, Or8<T1, T2, T3, T4, T5, T6, Infallible, T8>>
+    where
+        F: FnOnce(T7) -> R,
+    {
+        match self.into_state() {
+            Ok(result) => When::handled(result),
+            Err(or) => or.when_t7(f),
+        }
+    }
+
+    /// Handles the T8 value of the pending `Or8` using a provided function,
+    /// unless a previous `when_tx` has already handled the value.
+    pub fn when_t8<F>(self, f: F) -> When<R, Or8<T1, T2, T3, T4, T5, T6, T7, Infallible>>
+    where
+        F: FnOnce(T8) -> R,
+    {
+        match self.into_state() {
+            Ok(result) => When::handled(result),
+            Err(or) => or.when_t8(f),
+        }
+    }
+}
+
+impl<R>
+    When<
+        R,
+        Or8<
+            Infallible,
+            Infallible,
+            Infallible,
+            Infallible,
+            Infallible,
+            Infallible,
+            Infallible,
+            Infallible,
+        >,
+    >
+{
+    /// Finishes the chain and returns the result. This is available only when every
+    /// variant of `Or8` has been handled.
+    pub fn finish(self) -> R {
+        match self.into_state() {
+            Ok(result) => result,
+            Err(or) => match or {
+                Or8::T1(never) => match never {},
+                Or8::T2(never) => match never {},
+                Or8::T3(never) => match never {},
+                Or8::T4(never) => match never {},
+                Or8::T5(never) => match never {},
+                Or8::T6(never) => match never {},
+                Or8::T7(never) => match never {},
+                Or8::T8(never) => match never {},
+            },
+        }
+    }
+}
+
+/// `Or9` is an enum representing a value that can be either of 9 types, T1 ... T9.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(untagged)
+)]
+#[cfg_attr(feature = "repr-c", repr(C))]
+#[cfg_attr(feature = "repr-u8", repr(u8))]
+pub enum Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9> {
+    T1(T1),
+    T2(T2),
+    T3(T3),
+    T4(T4),
+    T5(T5),
+    T6(T6),
+    T7(T7),
+    T8(T8),
+    T9(T9),
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7, T8, T9> Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9> {
+    /// Returns true if the enum is of type T1.
+    #[must_use]
+    pub fn is_t1(&self) -> bool {
+        matches!(self, Self::T1(_))
+    }
+
+    /// Returns true if the enum is of type T2.
+    #[must_use]
+    pub fn is_t2(&self) -> bool {
+        matches!(self, Self::T2(_))
+    }
+
+    /// Returns true if the enum is of type T3.
+    #[must_use]
+    pub fn is_t3(&self) -> bool {
+        matches!(self, Self::T3(_))
+    }
+
+    /// Returns true if the enum is of type T4.
+    #[must_use]
+    pub fn is_t4(&self) -> bool {
+        matches!(self, Self::T4(_))
+    }
+
+    /// Returns true if the enum is of type T5.
+    #[must_use]
+    pub fn is_t5(&self) -> bool {
+        matches!(self, Self::T5(_))
+    }
+
+    /// Returns true if the enum is of type T6.
+    #[must_use]
+    pub fn is_t6(&self) -> bool {
+        matches!(self, Self::T6(_))
+    }
+
+    /// Returns true if the enum is of type T7.
+    #[must_use]
+    pub fn is_t7(&self) -> bool {
+        matches!(self, Self::T7(_))
+    }
+
+    /// Returns true if the enum is of type T8.
+    #[must_use]
+    pub fn is_t8(&self) -> bool {
+        matches!(self, Self::T8(_))
+    }
+
+    /// Returns true if the enum is of type T9.
+    #[must_use]
+    pub fn is_t9(&self) -> bool {
+        matches!(self, Self::T9(_))
+    }
+
+    /// Converts the enum to an Option containing the T1 value, if it is of type T1.
+    #[must_use]
+    pub fn as_t1(self) -> Option<T1> {
+        match self {
+            Self::T1(t1) => Some(t1),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the T1 value, if it is of type T1, without consuming the enum.
+    #[must_use]
+    pub fn as_t1_ref(&self) -> Option<&T1> {
+        match self {
+            Self::T1(t1) => Some(t1),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the T1 value, if it is of type T1.
+    #[must_use]
+    pub fn as_t1_mut(&mut self) -> Option<&mut T1> {
+        match self {
+            Self::T1(t1) => Some(t1),
+            _ => None,
+        }
+    }
+
+    /// Converts the enum to an Option containing the T2 value, if it is of type T2.
+    #[must_use]
+    pub fn as_t2(self) -> Option<T2> {
+        match self {
+            Self::T2(t2) => Some(t2),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the T2 value, if it is of type T2, without consuming the enum.
+    #[must_use]
+    pub fn as_t2_ref(&self) -> Option<&T2> {
+        match self {
+            Self::T2(t2) => Some(t2),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the T2 value, if it is of type T2.
+    #[must_use]
+    pub fn as_t2_mut(&mut self) -> Option<&mut T2> {
+        match self {
+            Self::T2(t2) => Some(t2),
+            _ => None,
+        }
+    }
+
+    /// Converts the enum to an Option containing the T3 value, if it is of type T3.
+    #[must_use]
+    pub fn as_t3(self) -> Option<T3> {
+        match self {
+            Self::T3(t3) => Some(t3),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the T3 value, if it is of type T3, without consuming the enum.
+    #[must_use]
+    pub fn as_t3_ref(&self) -> Option<&T3> {
+        match self {
+            Self::T3(t3) => Some(t3),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the T3 value, if it is of type T3.
+    #[must_use]
+    pub fn as_t3_mut(&mut self) -> Option<&mut T3> {
+        match self {
+            Self::T3(t3) => Some(t3),
+            _ => None,
+        }
+    }
+
+    /// Converts the enum to an Option containing the T4 value, if it is of type T4.
+    #[must_use]
+    pub fn as_t4(self) -> Option<T4> {
+        match self {
+            Self::T4(t4) => Some(t4),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the T4 value, if it is of type T4, without consuming the enum.
+    #[must_use]
+    pub fn as_t4_ref(&self) -> Option<&T4> {
+        match self {
+            Self::T4(t4) => Some(t4),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the T4 value, if it is of type T4.
+    #[must_use]
+    pub fn as_t4_mut(&mut self) -> Option<&mut T4> {
+        match self {
+            Self::T4(t4) => Some(t4),
+            _ => None,
+        }
+    }
+
+    /// Converts the enum to an Option containing the T5 value, if it is of type T5.
+    #[must_use]
+    pub fn as_t5(self) -> Option<T5> {
+        match self {
+            Self::T5(t5) => Some(t5),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the T5 value, if it is of type T5, without consuming the enum.
+    #[must_use]
+    pub fn as_t5_ref(&self) -> Option<&T5> {
+        match self {
+            Self::T5(t5) => Some(t5),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the T5 value, if it is of type T5.
+    #[must_use]
+    pub fn as_t5_mut(&mut self) -> Option<&mut T5> {
+        match self {
+            Self::T5(t5) => Some(t5),
+            _ => None,
+        }
+    }
+
+    /// Converts the enum to an Option containing the T6 value, if it is of type T6.
+    #[must_use]
+    pub fn as_t6(self) -> Option<T6> {
+        match self {
+            Self::T6(t6) => Some(t6),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the T6 value, if it is of type T6, without consuming the enum.
+    #[must_use]
+    pub fn as_t6_ref(&self) -> Option<&T6> {
+        match self {
+            Self::T6(t6) => Some(t6),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the T6 value, if it is of type T6.
+    #[must_use]
+    pub fn as_t6_mut(&mut self) -> Option<&mut T6> {
+        match self {
+            Self::T6(t6) => Some(t6),
+            _ => None,
+        }
+    }
+
+    /// Converts the enum to an Option containing the T7 value, if it is of type T7.
+    #[must_use]
+    pub fn as_t7(self) -> Option<T7> {
+        match self {
+            Self::T7(t7) => Some(t7),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the T7 value, if it is of type T7, without consuming the enum.
+    #[must_use]
+    pub fn as_t7_ref(&self) -> Option<&T7> {
+        match self {
+            Self::T7(t7) => Some(t7),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the T7 value, if it is of type T7.
+    #[must_use]
+    pub fn as_t7_mut(&mut self) -> Option<&mut T7> {
+        match self {
+            Self::T7(t7) => Some(t7),
+            _ => None,
+        }
+    }
+
+    /// Converts the enum to an Option containing the T8 value, if it is of type T8.
+    #[must_use]
+    pub fn as_t8(self) -> Option<T8> {
+        match self {
+            Self::T8(t8) => Some(t8),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the T8 value, if it is of type T8, without consuming the enum.
+    #[must_use]
+    pub fn as_t8_ref(&self) -> Option<&T8> {
+        match self {
+            Self::T8(t8) => Some(t8),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the T8 value, if it is of type T8.
+    #[must_use]
+    pub fn as_t8_mut(&mut self) -> Option<&mut T8> {
+        match self {
+            Self::T8(t8) => Some(t8),
+            _ => None,
+        }
+    }
+
+    /// Converts the enum to an Option containing the T9 value, if it is of type T9.
+    #[must_use]
+    pub fn as_t9(self) -> Option<T9> {
+        match self {
+            Self::T9(t9) => Some(t9),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the T9 value, if it is of type T9, without consuming the enum.
+    #[must_use]
+    pub fn as_t9_ref(&self) -> Option<&T9> {
+        match self {
+            Self::T9(t9) => Some(t9),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the T9 value, if it is of type T9.
+    #[must_use]
+    pub fn as_t9_mut(&mut self) -> Option<&mut T9> {
+        match self {
+            Self::T9(t9) => Some(t9),
+            _ => None,
+        }
+    }
+
+    /// Returns the T1 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum is not of type T1, naming the variant it is of.
+    #[track_caller]
+    pub fn unwrap_t1(self) -> T1 {
+        match self {
+            Self::T1(t1) => t1,
+            _ => panic!(
+                "called `Or9::unwrap_t1()` on a `{:?}` value",
+                self.discriminant()
+            ),
+        }
+    }
+
+    /// Returns the T1 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the enum is not of type T1.
+    #[track_caller]
+    pub fn expect_t1(self, msg: &str) -> T1 {
+        match self {
+            Self::T1(t1) => t1,
+            _ => panic!("{}", msg),
+        }
+    }
+
+    /// Returns the T2 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum is not of type T2, naming the variant it is of.
+    #[track_caller]
+    pub fn unwrap_t2(self) -> T2 {
+        match self {
+            Self::T2(t2) => t2,
+            _ => panic!(
+                "called `Or9::unwrap_t2()` on a `{:?}` value",
+                self.discriminant()
+            ),
+        }
+    }
+
+    /// Returns the T2 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the enum is not of type T2.
+    #[track_caller]
+    pub fn expect_t2(self, msg: &str) -> T2 {
+        match self {
+            Self::T2(t2) => t2,
+            _ => panic!("{}", msg),
+        }
+    }
+
+    /// Returns the T3 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum is not of type T3, naming the variant it is of.
+    #[track_caller]
+    pub fn unwrap_t3(self) -> T3 {
+        match self {
+            Self::T3(t3) => t3,
+            _ => panic!(
+                "called `Or9::unwrap_t3()` on a `{:?}` value",
+                self.discriminant()
+            ),
+        }
+    }
+
+    /// Returns the T3 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the enum is not of type T3.
+    #[track_caller]
+    pub fn expect_t3(self, msg: &str) -> T3 {
+        match self {
+            Self::T3(t3) => t3,
+            _ => panic!("{}", msg),
+        }
+    }
+
+    /// Returns the T4 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum is not of type T4, naming the variant it is of.
+    #[track_caller]
+    pub fn unwrap_t4(self) -> T4 {
+        match self {
+            Self::T4(t4) => t4,
+            _ => panic!(
+                "called `Or9::unwrap_t4()` on a `{:?}` value",
+                self.discriminant()
+            ),
+        }
+    }
+
+    /// Returns the T4 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the enum is not of type T4.
+    #[track_caller]
+    pub fn expect_t4(self, msg: &str) -> T4 {
+        match self {
+            Self::T4(t4) => t4,
+            _ => panic!("{}", msg),
+        }
+    }
+
+    /// Returns the T5 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum is not of type T5, naming the variant it is of.
+    #[track_caller]
+    pub fn unwrap_t5(self) -> T5 {
+        match self {
+            Self::T5(t5) => t5,
+            _ => panic!(
+                "called `Or9::unwrap_t5()` on a `{:?}` value",
+                self.discriminant()
+            ),
+        }
+    }
+
+    /// Returns the T5 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the enum is not of type T5.
+    #[track_caller]
+    pub fn expect_t5(self, msg: &str) -> T5 {
+        match self {
+            Self::T5(t5) => t5,
+            _ => panic!("{}", msg),
+        }
+    }
+
+    /// Returns the T6 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum is not of type T6, naming the variant it is of.
+    #[track_caller]
+    pub fn unwrap_t6(self) -> T6 {
+        match self {
+            Self::T6(t6) => t6,
+            _ => panic!(
+                "called `Or9::unwrap_t6()` on a `{:?}` value",
+                self.discriminant()
+            ),
+        }
+    }
+
+    /// Returns the T6 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the enum is not of type T6.
+    #[track_caller]
+    pub fn expect_t6(self, msg: &str) -> T6 {
+        match self {
+            Self::T6(t6) => t6,
+            _ => panic!("{}", msg),
+        }
+    }
+
+    /// Returns the T7 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum is not of type T7, naming the variant it is of.
+    #[track_caller]
+    pub fn unwrap_t7(self) -> T7 {
+        match self {
+            Self::T7(t7) => t7,
+            _ => panic!(
+                "called `Or9::unwrap_t7()` on a `{:?}` value",
+                self.discriminant()
+            ),
+        }
+    }
+
+    /// Returns the T7 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the enum is not of type T7.
+    #[track_caller]
+    pub fn expect_t7(self, msg: &str) -> T7 {
+        match self {
+            Self::T7(t7) => t7,
+            _ => panic!("{}", msg),
+        }
+    }
+
+    /// Returns the T8 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum is not of type T8, naming the variant it is of.
+    #[track_caller]
+    pub fn unwrap_t8(self) -> T8 {
+        match self {
+            Self::T8(t8) => t8,
+            _ => panic!(
+                "called `Or9::unwrap_t8()` on a `{:?}` value",
+                self.discriminant()
+            ),
+        }
+    }
+
+    /// Returns the T8 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the enum is not of type T8.
+    #[track_caller]
+    pub fn expect_t8(self, msg: &str) -> T8 {
+        match self {
+            Self::T8(t8) => t8,
+            _ => panic!("{}", msg),
+        }
+    }
+
+    /// Returns the T9 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the enum is not of type T9, naming the variant it is of.
+    #[track_caller]
+    pub fn unwrap_t9(self) -> T9 {
+        match self {
+            Self::T9(t9) => t9,
+            _ => panic!(
+                "called `Or9::unwrap_t9()` on a `{:?}` value",
+                self.discriminant()
+            ),
+        }
+    }
+
+    /// Returns the T9 value, consuming the enum.
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the enum is not of type T9.
+    #[track_caller]
+    pub fn expect_t9(self, msg: &str) -> T9 {
+        match self {
+            Self::T9(t9) => t9,
+            _ => panic!("{}", msg),
+        }
+    }
+
+    /// Converts from `&Or9<T1, ...>` to `Or9<&T1, ...>`.
+    #[must_use]
+    pub fn as_ref(&self) -> Or9<&T1, &T2, &T3, &T4, &T5, &T6, &T7, &T8, &T9> {
+        match self {
+            Self::T1(t1) => Or9::T1(t1),
+            Self::T2(t2) => Or9::T2(t2),
+            Self::T3(t3) => Or9::T3(t3),
+            Self::T4(t4) => Or9::T4(t4),
+            Self::T5(t5) => Or9::T5(t5),
+            Self::T6(t6) => Or9::T6(t6),
+            Self::T7(t7) => Or9::T7(t7),
+            Self::T8(t8) => Or9::T8(t8),
+            Self::T9(t9) => Or9::T9(t9),
+        }
+    }
+
+    /// Converts from `&mut Or9<T1, ...>` to `Or9<&mut T1, ...>`.
+    #[must_use]
+    pub fn as_mut(
+        &mut self,
+    ) -> Or9<&mut T1, &mut T2, &mut T3, &mut T4, &mut T5, &mut T6, &mut T7, &mut T8, &mut T9> {
+        match self {
+            Self::T1(t1) => Or9::T1(t1),
+            Self::T2(t2) => Or9::T2(t2),
+            Self::T3(t3) => Or9::T3(t3),
+            Self::T4(t4) => Or9::T4(t4),
+            Self::T5(t5) => Or9::T5(t5),
+            Self::T6(t6) => Or9::T6(t6),
+            Self::T7(t7) => Or9::T7(t7),
+            Self::T8(t8) => Or9::T8(t8),
+            Self::T9(t9) => Or9::T9(t9),
+        }
+    }
+
+    /// Transforms the T1 value of the enum using a provided function,
+    /// maintaining other types as is.
+    #[must_use = "this returns the mapped enum, without modifying the original"]
+    pub fn map_t1<F, B>(self, f: F) -> Or9<B, T2, T3, T4, T5, T6, T7, T8, T9>
+    where
+        F: FnOnce(T1) -> B,
+    {
+        match self {
+            Self::T1(t1) => Or9::<B, T2, T3, T4, T5, T6, T7, T8, T9>::T1(f(t1)),
+            Self::T2(t2) => Or9::<B, T2, T3, T4, T5, T6, T7, T8, T9>::T2(t2),
+            Self::T3(t3) => Or9::<B, T2, T3, T4, T5, T6, T7, T8, T9>::T3(t3),
+            Self::T4(t4) => Or9::<B, T2, T3, T4, T5, T6, T7, T8, T9>::T4(t4),
+            Self::T5(t5) => Or9::<B, T2, T3, T4, T5, T6, T7, T8, T9>::T5(t5),
+            Self::T6(t6) => Or9::<B, T2, T3, T4, T5, T6, T7, T8, T9>::T6(t6),
+            Self::T7(t7) => Or9::<B, T2, T3, T4, T5, T6, T7, T8, T9>::T7(t7),
+            Self::T8(t8) => Or9::<B, T2, T3, T4, T5, T6, T7, T8, T9>::T8(t8),
+            Self::T9(t9) => Or9::<B, T2, T3, T4, T5, T6, T7, T8, T9>::T9(t9),
+        }
+    }
+
+    /// Transforms the T2 value of the enum using a provided function,
+    /// maintaining other types as is.
+    #[must_use = "this returns the mapped enum, without modifying the original"]
+    pub fn map_t2<F, B>(self, f: F) -> Or9<T1, B, T3, T4, T5, T6, T7, T8, T9>
+    where
+        F: FnOnce(T2) -> B,
+    {
+        match self {
+            Self::T1(t1) => Or9::<T1, B, T3, T4, T5, T6, T7, T8, T9>::T1(t1),
+            Self::T2(t2) => Or9::<T1, B, T3, T4, T5, T6, T7, T8, T9>::T2(f(t2)),
+            Self::T3(t3) => Or9::<T1, B, T3, T4, T5, T6, T7, T8, T9>::T3(t3),
+            Self::T4(t4) => Or9::<T1, B, T3, T4, T5, T6, T7, T8, T9>::T4(t4),
+            Self::T5(t5) => Or9::<T1, B, T3, T4, T5, T6, T7, T8, T9>::T5(t5),
+            Self::T6(t6) => Or9::<T1, B, T3, T4, T5, T6, T7, T8, T9>::T6(t6),
+            Self::T7(t7) => Or9::<T1, B, T3, T4, T5, T6, T7, T8, T9>::T7(t7),
+            Self::T8(t8) => Or9::<T1, B, T3, T4, T5, T6, T7, T8, T9>::T8(t8),
+            Self::T9(t9) => Or9::<T1, B, T3, T4, T5, T6, T7, T8, T9>::T9(t9),
+        }
+    }
+
+    /// Transforms the T3 value of the enum using a provided function,
+    /// maintaining other types as is.
+    #[must_use = "this returns the mapped enum, without modifying the original"]
+    pub fn map_t3<F, B>(self, f: F) -> Or9<T1, T2, B, T4, T5, T6, T7, T8, T9>
+    where
+        F: FnOnce(T3) -> B,
+    {
+        match self {
+            Self::T1(t1) => Or9::<T1, T2, B, T4, T5, T6, T7, T8, T9>::T1(t1),
+            Self::T2(t2) => Or9::<T1, T2, B, T4, T5, T6, T7, T8, T9>::T2(t2),
+            Self::T3(t3) => Or9::<T1, T2, B, T4, T5, T6, T7, T8, T9>::T3(f(t3)),
+            Self::T4(t4) => Or9::<T1, T2, B, T4, T5, T6, T7, T8, T9>::T4(t4),
+            Self::T5(t5) => Or9::<T1, T2, B, T4, T5, T6, T7, T8, T9>::T5(t5),
+            Self::T6(t6) => Or9::<T1, T2, B, T4, T5, T6, T7, T8, T9>::T6(t6),
+            Self::T7(t7) => Or9::<T1, T2, B, T4, T5, T6, T7, T8, T9>::T7(t7),
+            Self::T8(t8) => Or9::<T1, T2, B, T4, T5, T6, T7, T8, T9>::T8(t8),
+            Self::T9(t9) => Or9::<T1, T2, B, T4, T5, T6, T7, T8, T9>::T9(t9),
+        }
+    }
+
+    /// Transforms the T4 value of the enum using a provided function,
+    /// maintaining other types as is.
+    #[must_use = "this returns the mapped enum, without modifying the original"]
+    pub fn map_t4<F, B>(self, f: F) -> Or9<T1, T2, T3, B, T5, T6, T7, T8, T9>
+    where
+        F: FnOnce(T4) -> B,
+    {
+        match self {
+            Self::T1(t1) => Or9::<T1, T2, T3, B, T5, T6, T7, T8, T9>::T1(t1),
+            Self::T2(t2) => Or9::<T1, T2, T3, B, T5, T6, T7, T8, T9>::T2(t2),
+            Self::T3(t3) => Or9::<T1, T2, T3, B, T5, T6, T7, T8, T9>::T3(t3),
+            Self::T4(t4) => Or9::<T1, T2, T3, B, T5, T6, T7, T8, T9>::T4(f(t4)),
+            Self::T5(t5) => Or9::<T1, T2, T3, B, T5, T6, T7, T8, T9>::T5(t5),
+            Self::T6(t6) => Or9::<T1, T2, T3, B, T5, T6, T7, T8, T9>::T6(t6),
+            Self::T7(t7) => Or9::<T1, T2, T3, B, T5, T6, T7, T8, T9>::T7(t7),
+            Self::T8(t8) => Or9::<T1, T2, T3, B, T5, T6, T7, T8, T9>::T8(t8),
+            Self::T9(t9) => Or9::<T1, T2, T3, B, T5, T6, T7, T8, T9>::T9(t9),
+        }
+    }
+
+    /// Transforms the T5 value of the enum using a provided function,
+    /// maintaining other types as is.
+    #[must_use = "this returns the mapped enum, without modifying the original"]
+    pub fn map_t5<F, B>(self, f: F) -> Or9<T1, T2, T3, T4, B, T6, T7, T8, T9>
+    where
+        F: FnOnce(T5) -> B,
+    {
+        match self {
+            Self::T1(t1) => Or9::<T1, T2, T3, T4, B, T6, T7, T8, T9>::T1(t1),
+            Self::T2(t2) => Or9::<T1, T2, T3, T4, B, T6, T7, T8, T9>::T2(t2),
+            Self::T3(t3) => Or9::<T1, T2, T3, T4, B, T6, T7, T8, T9>::T3(t3),
+            Self::T4(t4) => Or9::<T1, T2, T3, T4, B, T6, T7, T8, T9>::T4(t4),
+            Self::T5(t5) => Or9::<T1, T2, T3, T4, B, T6, T7, T8, T9>::T5(f(t5)),
+            Self::T6(t6) => Or9::<T1, T2, T3, T4, B, T6, T7, T8, T9>::T6(t6),
+            Self::T7(t7) => Or9::<T1, T2, T3, T4, B, T6, T7, T8, T9>::T7(t7),
+            Self::T8(t8) => Or9::<T1, T2, T3, T4, B, T6, T7, T8, T9>::T8(t8),
+            Self::T9(t9) => Or9::<T1, T2, T3, T4, B, T6, T7, T8, T9>::T9(t9),
+        }
+    }
+
+    /// Transforms the T6 value of the enum using a provided function,
+    /// maintaining other types as is.
+    #[must_use = "this returns the mapped enum, without modifying the original"]
+    pub fn map_t6<F, B>(self, f: F) -> Or9<T1, T2, T3, T4, T5, B, T7, T8, T9>
+    where
+        F: FnOnce(T6) -> B,
+    {
+        match self {
+            Self::T1(t1) => Or9::<T1, T2, T3, T4, T5, B, T7, T8, T9>::T1(t1),
+            Self::T2(t2) => Or9::<T1, T2, T3, T4, T5, B, T7, T8, T9>::T2(t2),
+            Self::T3(t3) => Or9::<T1, T2, T3, T4, T5, B, T7, T8, T9>::T3(t3),
+            Self::T4(t4) => Or9::<T1, T2, T3, T4, T5, B, T7, T8, T9>::T4(t4),
+            Self::T5(t5) => Or9::<T1, T2, T3, T4, T5, B, T7, T8, T9>::T5(t5),
+            Self::T6(t6) => Or9::<T1, T2, T3, T4, T5, B, T7, T8, T9>::T6(f(t6)),
+            Self::T7(t7) => Or9::<T1, T2, T3, T4, T5, B, T7, T8, T9>::T7(t7),
+            Self::T8(t8) => Or9::<T1, T2, T3, T4, T5, B, T7, T8, T9>::T8(t8),
+            Self::T9(t9) => Or9::<T1, T2, T3, T4, T5, B, T7, T8, T9>::T9(t9),
+        }
+    }
+
+    /// Transforms the T7 value of the enum using a provided function,
+    /// maintaining other types as is.
+    #[must_use = "this returns the mapped enum, without modifying the original"]
+    pub fn map_t7<F, B>(self, f: F) -> Or9<T1, T2, T3, T4, T5, T6, B, T8, T9>
+    where
+        F: FnOnce(T7) -> B,
+    {
+        match self {
+            Self::T1(t1) => Or9::<T1, T2, T3, T4, T5, T6, B, T8, T9>::T1(t1),
+            Self::T2(t2) => Or9::<T1, T2, T3, T4, T5, T6, B, T8, T9>::T2(t2),
+            Self::T3(t3) => Or9::<T1, T2, T3, T4, T5, T6, B, T8, T9>::T3(t3),
+            Self::T4(t4) => Or9::<T1, T2, T3, T4, T5, T6, B, T8, T9>::T4(t4),
+            Self::T5(t5) => Or9::<T1, T2, T3, T4, T5, T6, B, T8, T9>::T5(t5),
+            Self::T6(t6) => Or9::<T1, T2, T3, T4, T5, T6, B, T8, T9>::T6(t6),
+            Self::T7(t7) => Or9::<T1, T2, T3, T4, T5, T6, B, T8, T9>::T7(f(t7)),
+            Self::T8(t8) => Or9::<T1, T2, T3, T4, T5, T6, B, T8, T9>::T8(t8),
+            Self::T9(t9) => Or9::<T1, T2, T3, T4, T5, T6, B, T8, T9>::T9(t9),
+        }
+    }
+
+    /// Transforms the T8 value of the enum using a provided function,
+    /// maintaining other types as is.
+    #[must_use = "this returns the mapped enum, without modifying the original"]
+    pub fn map_t8<F, B>(self, f: F) -> Or9<T1, T2, T3, T4, T5, T6, T7, B, T9>
+    where
+        F: FnOnce(T8) -> B,
+    {
+        match self {
+            Self::T1(t1) => Or9::<T1, T2, T3, T4, T5, T6, T7, B, T9>::T1(t1),
+            Self::T2(t2) => Or9::<T1, T2, T3, T4, T5, T6, T7, B, T9>::T2(t2),
+            Self::T3(t3) => Or9::<T1, T2, T3, T4, T5, T6, T7, B, T9>::T3(t3),
+            Self::T4(t4) => Or9::<T1, T2, T3, T4, T5, T6, T7, B, T9>::T4(t4),
+            Self::T5(t5) => Or9::<T1, T2, T3, T4, T5, T6, T7, B, T9>::T5(t5),
+            Self::T6(t6) => Or9::<T1, T2, T3, T4, T5, T6, T7, B, T9>::T6(t6),
+            Self::T7(t7) => Or9::<T1, T2, T3, T4, T5, T6, T7, B, T9>::T7(t7),
+            Self::T8(t8) => Or9::<T1, T2, T3, T4, T5, T6, T7, B, T9>::T8(f(t8)),
+            Self::T9(t9) => Or9::<T1, T2, T3, T4, T5, T6, T7, B, T9>::T9(t9),
+        }
+    }
+
+    /// Transforms the T9 value of the enum using a provided function,
+    /// maintaining other types as is.
+    #[must_use = "this returns the mapped enum, without modifying the original"]
+    pub fn map_t9<F, B>(self, f: F) -> Or9<T1, T2, T3, T4, T5, T6, T7, T8, B>
+    where
+        F: FnOnce(T9) -> B,
+    {
+        match self {
+            Self::T1(t1) => Or9::<T1, T2, T3, T4, T5, T6, T7, T8, B>::T1(t1),
+            Self::T2(t2) => Or9::<T1, T2, T3, T4, T5, T6, T7, T8, B>::T2(t2),
+            Self::T3(t3) => Or9::<T1, T2, T3, T4, T5, T6, T7, T8, B>::T3(t3),
+            Self::T4(t4) => Or9::<T1, T2, T3, T4, T5, T6, T7, T8, B>::T4(t4),
+            Self::T5(t5) => Or9::<T1, T2, T3, T4, T5, T6, T7, T8, B>::T5(t5),
+            Self::T6(t6) => Or9::<T1, T2, T3, T4, T5, T6, T7, T8, B>::T6(t6),
+            Self::T7(t7) => Or9::<T1, T2, T3, T4, T5, T6, T7, T8, B>::T7(t7),
+            Self::T8(t8) => Or9::<T1, T2, T3, T4, T5, T6, T7, T8, B>::T8(t8),
+            Self::T9(t9) => Or9::<T1, T2, T3, T4, T5, T6, T7, T8, B>::T9(f(t9)),
+        }
+    }
+
+    /// Transforms the T1 value of the enum using a provided async function and awaits it,
+    /// maintaining other types as is.
+    pub async fn map_t1_async<F, Fut, B>(self, f: F) -> Or9<B, T2, T3, T4, T5, T6, T7, T8, T9>
+    where
+        F: FnOnce(T1) -> Fut,
+        Fut: Future<Output = B>,
+    {
+        match self {
+            Self::T1(t1) => Or9::T1(f(t1).await),
+            Self::T2(t2) => Or9::T2(t2),
+            Self::T3(t3) => Or9::T3(t3),
+            Self::T4(t4) => Or9::T4(t4),
+            Self::T5(t5) => Or9::T5(t5),
+            Self::T6(t6) => Or9::T6(t6),
+            Self::T7(t7) => Or9::T7(t7),
+            Self::T8(t8) => Or9::T8(t8),
+            Self::T9(t9) => Or9::T9(t9),
+        }
+    }
+
+    /// Transforms the T2 value of the enum using a provided async function and awaits it,
+    /// maintaining other types as is.
+    pub async fn map_t2_async<F, Fut, B>(self, f: F) -> Or9<T1, B, T3, T4, T5, T6, T7, T8, T9>
+    where
+        F: FnOnce(T2) -> Fut,
+        Fut: Future<Output = B>,
+    {
+        match self {
+            Self::T1(t1) => Or9::T1(t1),
+            Self::T2(t2) => Or9::T2(f(t2).await),
+            Self::T3(t3) => Or9::T3(t3),
+            Self::T4(t4) => Or9::T4(t4),
+            Self::T5(t5) => Or9::T5(t5),
+            Self::T6(t6) => Or9::T6(t6),
+            Self::T7(t7) => Or9::T7(t7),
+            Self::T8(t8) => Or9::T8(t8),
+            Self::T9(t9) => Or9::T9(t9),
+        }
+    }
+
+    /// Transforms the T3 value of the enum using a provided async function and awaits it,
+    /// maintaining other types as is.
+    pub async fn map_t3_async<F, Fut, B>(self, f: F) -> Or9<T1, T2, B, T4, T5, T6, T7, T8, T9>
+    where
+        F: FnOnce(T3) -> Fut,
+        Fut: Future<Output = B>,
+    {
+        match self {
+            Self::T1(t1) => Or9::T1(t1),
+            Self::T2(t2) => Or9::T2(t2),
+            Self::T3(t3) => Or9::T3(f(t3).await),
+            Self::T4(t4) => Or9::T4(t4),
+            Self::T5(t5) => Or9::T5(t5),
+            Self::T6(t6) => Or9::T6(t6),
+            Self::T7(t7) => Or9::T7(t7),
+            Self::T8(t8) => Or9::T8(t8),
+            Self::T9(t9) => Or9::T9(t9),
+        }
+    }
+
+    /// Transforms the T4 value of the enum using a provided async function and awaits it,
+    /// maintaining other types as is.
+    pub async fn map_t4_async<F, Fut, B>(self, f: F) -> Or9<T1, T2, T3, B, T5, T6, T7, T8, T9>
+    where
+        F: FnOnce(T4) -> Fut,
+        Fut: Future<Output = B>,
+    {
+        match self {
+            Self::T1(t1) => Or9::T1(t1),
+            Self::T2(t2) => Or9::T2(t2),
+            Self::T3(t3) => Or9::T3(t3),
+            Self::T4(t4) => Or9::T4(f(t4).await),
+            Self::T5(t5) => Or9::T5(t5),
+            Self::T6(t6) => Or9::T6(t6),
+            Self::T7(t7) => Or9::T7(t7),
+            Self::T8(t8) => Or9::T8(t8),
+            Self::T9(t9) => Or9::T9(t9),
+        }
+    }
+
+    /// Transforms the T5 value of the enum using a provided async function and awaits it,
+    /// maintaining other types as is.
+    pub async fn map_t5_async<F, Fut, B>(self, f: F) -> Or9<T1, T2, T3, T4, B, T6, T7, T8, T9>
+    where
+        F: FnOnce(T5) -> Fut,
+        Fut: Future<Output = B>,
+    {
+        match self {
+            Self::T1(t1) => Or9::T1(t1),
+            Self::T2(t2) => Or9::T2(t2),
+            Self::T3(t3) => Or9::T3(t3),
+            Self::T4(t4) => Or9::T4(t4),
+            Self::T5(t5) => Or9::T5(f(t5).await),
+            Self::T6(t6) => Or9::T6(t6),
+            Self::T7(t7) => Or9::T7(t7),
+            Self::T8(t8) => Or9::T8(t8),
+            Self::T9(t9) => Or9::T9(t9),
+        }
+    }
+
+    /// Transforms the T6 value of the enum using a provided async function and awaits it,
+    /// maintaining other types as is.
+    pub async fn map_t6_async<F, Fut, B>(self, f: F) -> Or9<T1, T2, T3, T4, T5, B, T7, T8, T9>
+    where
+        F: FnOnce(T6) -> Fut,
+        Fut: Future<Output = B>,
+    {
+        match self {
+            Self::T1(t1) => Or9::T1(t1),
+            Self::T2(t2) => Or9::T2(t2),
+            Self::T3(t3) => Or9::T3(t3),
+            Self::T4(t4) => Or9::T4(t4),
+            Self::T5(t5) => Or9::T5(t5),
+            Self::T6(t6) => Or9::T6(f(t6).await),
+            Self::T7(t7) => Or9::T7(t7),
+            Self::T8(t8) => Or9::T8(t8),
+            Self::T9(t9) => Or9::T9(t9),
+        }
+    }
+
+    /// Transforms the T7 value of the enum using a provided async function and awaits it,
+    /// maintaining other types as is.
+    pub async fn map_t7_async<F, Fut, B>(self, f: F) -> Or9<T1, T2, T3, T4, T5, T6, B, T8, T9>
+    where
+        F: FnOnce(T7) -> Fut,
+        Fut: Future<Output = B>,
+    {
+        match self {
+            Self::T1(t1) => Or9::T1(t1),
+            Self::T2(t2) => Or9::T2(t2),
+            Self::T3(t3) => Or9::T3(t3),
+            Self::T4(t4) => Or9::T4(t4),
+            Self::T5(t5) => Or9::T5(t5),
+            Self::T6(t6) => Or9::T6(t6),
+            Self::T7(t7) => Or9::T7(f(t7).await),
+            Self::T8(t8) => Or9::T8(t8),
+            Self::T9(t9) => Or9::T9(t9),
+        }
+    }
+
+    /// Transforms the T8 value of the enum using a provided async function and awaits it,
+    /// maintaining other types as is.
+    pub async fn map_t8_async<F, Fut, B>(self, f: F) -> Or9<T1, T2, T3, T4, T5, T6, T7, B, T9>
+    where
+        F: FnOnce(T8) -> Fut,
+        Fut: Future<Output = B>,
+    {
+        match self {
+            Self::T1(t1) => Or9::T1(t1),
+            Self::T2(t2) => Or9::T2(t2),
+            Self::T3(t3) => Or9::T3(t3),
+            Self::T4(t4) => Or9::T4(t4),
+            Self::T5(t5) => Or9::T5(t5),
+            Self::T6(t6) => Or9::T6(t6),
+            Self::T7(t7) => Or9::T7(t7),
+            Self::T8(t8) => Or9::T8(f(t8).await),
+            Self::T9(t9) => Or9::T9(t9),
+        }
+    }
+
+    /// Transforms the T9 value of the enum using a provided async function and awaits it,
+    /// maintaining other types as is.
+    pub async fn map_t9_async<F, Fut, B>(self, f: F) -> Or9<T1, T2, T3, T4, T5, T6, T7, T8, B>
+    where
+        F: FnOnce(T9) -> Fut,
+        Fut: Future<Output = B>,
+    {
+        match self {
+            Self::T1(t1) => Or9::T1(t1),
+            Self::T2(t2) => Or9::T2(t2),
+            Self::T3(t3) => Or9::T3(t3),
+            Self::T4(t4) => Or9::T4(t4),
+            Self::T5(t5) => Or9::T5(t5),
+            Self::T6(t6) => Or9::T6(t6),
+            Self::T7(t7) => Or9::T7(t7),
+            Self::T8(t8) => Or9::T8(t8),
+            Self::T9(t9) => Or9::T9(f(t9).await),
+        }
+    }
+
+    /// Consolidates the `Or9` enum into a single value of type `T`,
+    /// by applying provided functions.
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn fold<T, F1, F2, F3, F4, F5, F6, F7, F8, F9>(
+        self,
+        f1: F1,
+        f2: F2,
+        f3: F3,
+        f4: F4,
+        f5: F5,
+        f6: F6,
+        f7: F7,
+        f8: F8,
+        f9: F9,
+    ) -> T
+    where
+        F1: FnOnce(T1) -> T,
+        F2: FnOnce(T2) -> T,
+        F3: FnOnce(T3) -> T,
+        F4: FnOnce(T4) -> T,
+        F5: FnOnce(T5) -> T,
+        F6: FnOnce(T6) -> T,
+        F7: FnOnce(T7) -> T,
+        F8: FnOnce(T8) -> T,
+        F9: FnOnce(T9) -> T,
+    {
+        match self {
+            Self::T1(t1) => f1(t1),
+            Self::T2(t2) => f2(t2),
+            Self::T3(t3) => f3(t3),
+            Self::T4(t4) => f4(t4),
+            Self::T5(t5) => f5(t5),
+            Self::T6(t6) => f6(t6),
+            Self::T7(t7) => f7(t7),
+            Self::T8(t8) => f8(t8),
+            Self::T9(t9) => f9(t9),
+        }
+    }
+
+    /// Consolidates the `Or9` enum into a single value of type `T` by applying provided
+    /// fallible functions, returning the error of the applied one, so that the functions can use `?`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_fold<T, E, F1, F2, F3, F4, F5, F6, F7, F8, F9>(
+        self,
+        f1: F1,
+        f2: F2,
+        f3: F3,
+        f4: F4,
+        f5: F5,
+        f6: F6,
+        f7: F7,
+        f8: F8,
+        f9: F9,
+    ) -> Result<T, E>
+    where
+        F1: FnOnce(T1) -> Result<T, E>,
+        F2: FnOnce(T2) -> Result<T, E>,
+        F3: FnOnce(T3) -> Result<T, E>,
+        F4: FnOnce(T4) -> Result<T, E>,
+        F5: FnOnce(T5) -> Result<T, E>,
+        F6: FnOnce(T6) -> Result<T, E>,
+        F7: FnOnce(T7) -> Result<T, E>,
+        F8: FnOnce(T8) -> Result<T, E>,
+        F9: FnOnce(T9) -> Result<T, E>,
+    {
+        match self {
+            Self::T1(t1) => f1(t1),
+            Self::T2(t2) => f2(t2),
+            Self::T3(t3) => f3(t3),
+            Self::T4(t4) => f4(t4),
+            Self::T5(t5) => f5(t5),
+            Self::T6(t6) => f6(t6),
+            Self::T7(t7) => f7(t7),
+            Self::T8(t8) => f8(t8),
+            Self::T9(t9) => f9(t9),
+        }
+    }
+
+    /// Consolidates the `Or9` enum into a single value of type `T`,
+    /// by applying provided async functions and awaiting the result.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn fold_async<
+        T,
+        F1,
+        Fut1,
+        F2,
+        Fut2,
+        F3,
+        Fut3,
+        F4,
+        Fut4,
+        F5,
+        Fut5,
+        F6,
+        Fut6,
+        F7,
+        Fut7,
+        F8,
+        Fut8,
+        F9,
+        Fut9,
+    >(
+        self,
+        f1: F1,
+        f2: F2,
+        f3: F3,
+        f4: F4,
+        f5: F5,
+        f6: F6,
+        f7: F7,
+        f8: F8,
+        f9: F9,
+    ) -> T
+    where
+        F1: FnOnce(T1) -> Fut1,
+        Fut1: Future<Output = T>,
+        F2: FnOnce(T2) -> Fut2,
+        Fut2: Future<Output = T>,
+        F3: FnOnce(T3) -> Fut3,
+        Fut3: Future<Output = T>,
+        F4: FnOnce(T4) -> Fut4,
+        Fut4: Future<Output = T>,
+        F5: FnOnce(T5) -> Fut5,
+        Fut5: Future<Output = T>,
+        F6: FnOnce(T6) -> Fut6,
+        Fut6: Future<Output = T>,
+        F7: FnOnce(T7) -> Fut7,
+        Fut7: Future<Output = T>,
+        F8: FnOnce(T8) -> Fut8,
+        Fut8: Future<Output = T>,
+        F9: FnOnce(T9) -> Fut9,
+        Fut9: Future<Output = T>,
+    {
+        match self {
+            Self::T1(t1) => f1(t1).await,
+            Self::T2(t2) => f2(t2).await,
+            Self::T3(t3) => f3(t3).await,
+            Self::T4(t4) => f4(t4).await,
+            Self::T5(t5) => f5(t5).await,
+            Self::T6(t6) => f6(t6).await,
+            Self::T7(t7) => f7(t7).await,
+            Self::T8(t8) => f8(t8).await,
+            Self::T9(t9) => f9(t9).await,
+        }
+    }
+
+    /// Handles the T1 value of the enum using a provided function, and leaves the other
+    /// variants to the subsequent `when_tx` calls.
+    pub fn when_t1<R, F>(self, f: F) -> When<R, Or9<Infallible, T2, T3, T4, T5, T6, T7, T8, T9>>
+    where
+        F: FnOnce(T1) -> R,
+    {
+        match self {
+            Self::T1(t1) => When::handled(f(t1)),
+            Self::T2(t2) => When::pending(Or9::T2(t2)),
+            Self::T3(t3) => When::pending(Or9::T3(t3)),
+            Self::T4(t4) => When::pending(Or9::T4(t4)),
+            Self::T5(t5) => When::pending(Or9::T5(t5)),
+            Self::T6(t6) => When::pending(Or9::T6(t6)),
+            Self::T7(t7) => When::pending(Or9::T7(t7)),
+            Self::T8(t8) => When::pending(Or9::T8(t8)),
+            Self::T9(t9) => When::pending(Or9::T9(t9)),
+        }
+    }
+
+    /// Handles the T2 value of the enum using a provided function, and leaves the other
+    /// variants to the subsequent `when_tx` calls.
+    pub fn when_t2<R, F>(self, f: F) -> When<R, Or9<T1, Infallible, T3, T4, T5, T6, T7, T8, T9>>
+    where
+        F: FnOnce(T2) -> R,
+    {
+        match self {
+            Self::T1(t1) => When::pending(Or9::T1(t1)),
+            Self::T2(t2) => When::handled(f(t2)),
+            Self::T3(t3) => When::pending(Or9::T3(t3)),
+            Self::T4(t4) => When::pending(Or9::T4(t4)),
+            Self::T5(t5) => When::pending(Or9::T5(t5)),
+            Self::T6(t6) => When::pending(Or9::T6(t6)),
+            Self::T7(t7) => When::pending(Or9::T7(t7)),
+            Self::T8(t8) => When::pending(Or9::T8(t8)),
+            Self::T9(t9) => When::pending(Or9::T9(t9)),
+        }
+    }
+
+    /// Handles the T3 value of the enum using a provided function, and leaves the other
+    /// variants to the subsequent `when_tx` calls.
+    pub fn when_t3<R, F>(self, f: F) -> When<R, Or9<T1, T2, Infallible, T4, T5, T6, T7, T8, T9>>
+    where
+        F: FnOnce(T3) -> R,
+    {
+        match self {
+            Self::T1(t1) => When::pending(Or9::T1(t1)),
+            Self::T2(t2) => When::pending(Or9::T2(t2)),
+            Self::T3(t3) => When::handled(f(t3)),
+            Self::T4(t4) => When::pending(Or9::T4(t4)),
+            Self::T5(t5) => When::pending(Or9::T5(t5)),
+            Self::T6(t6) => When::pending(Or9::T6(t6)),
+            Self::T7(t7) => When::pending(Or9::T7(t7)),
+            Self::T8(t8) => When::pending(Or9::T8(t8)),
+            Self::T9(t9) => When::pending(Or9::T9(t9)),
+        }
+    }
+
+    /// Handles the T4 value of the enum using a provided function, and leaves the other
+    /// variants to the subsequent `when_tx` calls.
+    pub fn when_t4<R, F>(self, f: F) -> When<R, Or9<T1, T2, T3, Infallible, T5, T6, T7, T8, T9>>
+    where
+        F: FnOnce(T4) -> R,
+    {
         match self {
-            Self::T1(_) => true,
-            _ => false,
+            Self::T1(t1) => When::pending(Or9::T1(t1)),
+            Self::T2(t2) => When::pending(Or9::T2(t2)),
+            Self::T3(t3) => When::pending(Or9::T3(t3)),
+            Self::T4(t4) => When::handled(f(t4)),
+            Self::T5(t5) => When::pending(Or9::T5(t5)),
+            Self::T6(t6) => When::pending(Or9::T6(t6)),
+            Self::T7(t7) => When::pending(Or9::T7(t7)),
+            Self::T8(t8) => When::pending(Or9::T8(t8)),
+            Self::T9(t9) => When::pending(Or9::T9(t9)),
         }
     }
 
-    /// Returns true if the enum is of type T2.
-    pub fn is_t2(&self) -> bool {
+    /// Handles the T5 value of the enum using a provided function, and leaves the other
+    /// variants to the subsequent `when_tx` calls.
+    pub fn when_t5<R, F>(self, f: F) -> When<R, Or9<T1, T2, T3, T4, Infallible, T6, T7, T8, T9>>
+    where
+        F: FnOnce(T5) -> R,
+    {
         match self {
-            Self::T2(_) => true,
-            _ => false,
+            Self::T1(t1) => When::pending(Or9::T1(t1)),
+            Self::T2(t2) => When::pending(Or9::T2(t2)),
+            Self::T3(t3) => When::pending(Or9::T3(t3)),
+            Self::T4(t4) => When::pending(Or9::T4(t4)),
+            Self::T5(t5) => When::handled(f(t5)),
+            Self::T6(t6) => When::pending(Or9::T6(t6)),
+            Self::T7(t7) => When::pending(Or9::T7(t7)),
+            Self::T8(t8) => When::pending(Or9::T8(t8)),
+            Self::T9(t9) => When::pending(Or9::T9(t9)),
         }
     }
 
-    /// Returns true if the enum is of type T3.
-    pub fn is_t3(&self) -> bool {
+    /// Handles the T6 value of the enum using a provided function, and leaves the other
+    /// variants to the subsequent `when_tx` calls.
+    pub fn when_t6<R, F>(self, f: F) -> When<R, Or9<T1, T2, T3, T4, T5, Infallible, T7, T8, T9>>
+    where
+        F: FnOnce(T6) -> R,
+    {
         match self {
-            Self::T3(_) => true,
-            _ => false,
+            Self::T1(t1) => When::pending(Or9::T1(t1)),
+            Self::T2(t2) => When::pending(Or9::T2(t2)),
+            Self::T3(t3) => When::pending(Or9::T3(t3)),
+            Self::T4(t4) => When::pending(Or9::T4(t4)),
+            Self::T5(t5) => When::pending(Or9::T5(t5)),
+            Self::T6(t6) => When::handled(f(t6)),
+            Self::T7(t7) => When::pending(Or9::T7(t7)),
+            Self::T8(t8) => When::pending(Or9::T8(t8)),
+            Self::T9(t9) => When::pending(Or9::T9(t9)),
         }
     }
 
-    /// Returns true if the enum is of type T4.
-    pub fn is_t4(&self) -> bool {
+    /// Handles the T7 value of the enum using a provided function, and leaves the other
+    /// variants to the subsequent `when_tx` calls.
+    pub fn when_t7<R, F>(self, f: F) -> When<R, Or9<T1, T2, T3, T4, T5, T6, Infallible, T8, T9>>
+    where
+        F: FnOnce(T7) -> R,
+    {
         match self {
-            Self::T4(_) => true,
-            _ => false,
+            Self::T1(t1) => When::pending(Or9::T1(t1)),
+            Self::T2(t2) => When::pending(Or9::T2(t2)),
+            Self::T3(t3) => When::pending(Or9::T3(t3)),
+            Self::T4(t4) => When::pending(Or9::T4(t4)),
+            Self::T5(t5) => When::pending(Or9::T5(t5)),
+            Self::T6(t6) => When::pending(Or9::T6(t6)),
+            Self::T7(t7) => When::handled(f(t7)),
+            Self::T8(t8) => When::pending(Or9::T8(t8)),
+            Self::T9(t9) => When::pending(Or9::T9(t9)),
         }
     }
 
-    /// Returns true if the enum is of type T5.
-    pub fn is_t5(&self) -> bool {
+    /// Handles the T8 value of the enum using a provided function, and leaves the other
+    /// variants to the subsequent `when_tx` calls.
+    pub fn when_t8<R, F>(self, f: F) -> When<R, Or9<T1, T2, T3, T4, T5, T6, T7, Infallible, T9>>
+    where
+        F: FnOnce(T8) -> R,
+    {
         match self {
-            Self::T5(_) => true,
-            _ => false,
+            Self::T1(t1) => When::pending(Or9::T1(t1)),
+            Self::T2(t2) => When::pending(Or9::T2(t2)),
+            Self::T3(t3) => When::pending(Or9::T3(t3)),
+            Self::T4(t4) => When::pending(Or9::T4(t4)),
+            Self::T5(t5) => When::pending(Or9::T5(t5)),
+            Self::T6(t6) => When::pending(Or9::T6(t6)),
+            Self::T7(t7) => When::pending(Or9::T7(t7)),
+            Self::T8(t8) => When::handled(f(t8)),
+            Self::T9(t9) => When::pending(Or9::T9(t9)),
         }
     }
 
-    /// Returns true if the enum is of type T6.
-    pub fn is_t6(&self) -> bool {
+    /// Handles the T9 value of the enum using a provided function, and leaves the other
+    /// variants to the subsequent `when_tx` calls.
+    pub fn when_t9<R, F>(self, f: F) -> When<R, Or9<T1, T2, T3, T4, T5, T6, T7, T8, Infallible>>
+    where
+        F: FnOnce(T9) -> R,
+    {
         match self {
-            Self::T6(_) => true,
-            _ => false,
+            Self::T1(t1) => When::pending(Or9::T1(t1)),
+            Self::T2(t2) => When::pending(Or9::T2(t2)),
+            Self::T3(t3) => When::pending(Or9::T3(t3)),
+            Self::T4(t4) => When::pending(Or9::T4(t4)),
+            Self::T5(t5) => When::pending(Or9::T5(t5)),
+            Self::T6(t6) => When::pending(Or9::T6(t6)),
+            Self::T7(t7) => When::pending(Or9::T7(t7)),
+            Self::T8(t8) => When::pending(Or9::T8(t8)),
+            Self::T9(t9) => When::handled(f(t9)),
         }
     }
 
-    /// Returns true if the enum is of type T7.
-    pub fn is_t7(&self) -> bool {
-        match self {
-            Self::T7(_) => true,
-            _ => false,
+    /// Converts the enum into a tuple of Options, where only the slot of the active variant is `Some`.
+    #[must_use]
+    pub fn into_options(
+        self,
+    ) -> (
+        Option<T1>,
+        Option<T2>,
+        Option<T3>,
+        Option<T4>,
+        Option<T5>,
+        Option<T6>,
+        Option<T7>,
+        Option<T8>,
+        Option<T9>,
+    ) {
+        match self {
+            Self::T1(t1) => (Some(t1), None, None, None, None, None, None, None, None),
+            Self::T2(t2) => (None, Some(t2), None, None, None, None, None, None, None),
+            Self::T3(t3) => (None, None, Some(t3), None, None, None, None, None, None),
+            Self::T4(t4) => (None, None, None, Some(t4), None, None, None, None, None),
+            Self::T5(t5) => (None, None, None, None, Some(t5), None, None, None, None),
+            Self::T6(t6) => (None, None, None, None, None, Some(t6), None, None, None),
+            Self::T7(t7) => (None, None, None, None, None, None, Some(t7), None, None),
+            Self::T8(t8) => (None, None, None, None, None, None, None, Some(t8), None),
+            Self::T9(t9) => (None, None, None, None, None, None, None, None, Some(t9)),
+        }
+    }
+
+    /// Borrows the enum as a tuple of Options, where only the slot of the active variant is `Some`.
+    #[must_use]
+    pub fn as_options(
+        &self,
+    ) -> (
+        Option<&T1>,
+        Option<&T2>,
+        Option<&T3>,
+        Option<&T4>,
+        Option<&T5>,
+        Option<&T6>,
+        Option<&T7>,
+        Option<&T8>,
+        Option<&T9>,
+    ) {
+        self.as_ref().into_options()
+    }
+
+    /// Builds the enum from a tuple of Options, which succeeds only when exactly one of them is `Some`.
+    /// Otherwise the tuple is handed back as is.
+    pub fn try_from_options(
+        options: (
+            Option<T1>,
+            Option<T2>,
+            Option<T3>,
+            Option<T4>,
+            Option<T5>,
+            Option<T6>,
+            Option<T7>,
+            Option<T8>,
+            Option<T9>,
+        ),
+    ) -> Result<
+        Self,
+        (
+            Option<T1>,
+            Option<T2>,
+            Option<T3>,
+            Option<T4>,
+            Option<T5>,
+            Option<T6>,
+            Option<T7>,
+            Option<T8>,
+            Option<T9>,
+        ),
+    > {
+        match options {
+            (Some(t1), None, None, None, None, None, None, None, None) => Ok(Self::T1(t1)),
+            (None, Some(t2), None, None, None, None, None, None, None) => Ok(Self::T2(t2)),
+            (None, None, Some(t3), None, None, None, None, None, None) => Ok(Self::T3(t3)),
+            (None, None, None, Some(t4), None, None, None, None, None) => Ok(Self::T4(t4)),
+            (None, None, None, None, Some(t5), None, None, None, None) => Ok(Self::T5(t5)),
+            (None, None, None, None, None, Some(t6), None, None, None) => Ok(Self::T6(t6)),
+            (None, None, None, None, None, None, Some(t7), None, None) => Ok(Self::T7(t7)),
+            (None, None, None, None, None, None, None, Some(t8), None) => Ok(Self::T8(t8)),
+            (None, None, None, None, None, None, None, None, Some(t9)) => Ok(Self::T9(t9)),
+            options => Err(options),
         }
     }
+}
 
-    /// Returns true if the enum is of type T8.
-    pub fn is_t8(&self) -> bool {
-        match self {
-            Self::T8(_) => true,
-            _ => false,
-        }
+impl_forwarding!(Or9, Or9Items, Or9Discriminant {
+    T1(t1, e1, I1, i1, E1, 0, T1),
+    T2(t2, e2, I2, i2, E2, 1, T2),
+    T3(t3, e3, I3, i3, E3, 2, T3),
+    T4(t4, e4, I4, i4, E4, 3, T4),
+    T5(t5, e5, I5, i5, E5, 4, T5),
+    T6(t6, e6, I6, i6, E6, 5, T6),
+    T7(t7, e7, I7, i7, E7, 6, T7),
+    T8(t8, e8, I8, i8, E8, 7, T8),
+    T9(t9, e9, I9, i9, E9, 8, T9)
+});
+
+impl<T1, T2, T3, T4, T5, T6, T7, T8, T9> Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9> {
+    /// The number of the variants, which is the size of the arrays returned by
+    /// [`Or9::variant_sizes`] and friends, indexed by [`Or9::variant_index`].
+    pub const VARIANT_COUNT: usize = 9;
+
+    /// Returns the 0-based position of the active variant, like `0` for `T1`.
+    ///
+    /// This can index a table of handlers or counters, with `VARIANT_COUNT` entries.
+    #[must_use]
+    pub const fn variant_index(&self) -> usize {
+        match self {
+            Self::T1(_) => 0,
+            Self::T2(_) => 1,
+            Self::T3(_) => 2,
+            Self::T4(_) => 3,
+            Self::T5(_) => 4,
+            Self::T6(_) => 5,
+            Self::T7(_) => 6,
+            Self::T8(_) => 7,
+            Self::T9(_) => 8,
+        }
+    }
+
+    /// Returns the sizes of the payload types, in the order of the variants.
+    ///
+    /// Comparing them with the size of the enum tells whether one oversized variant bloats
+    /// the whole enum, in which case it can be shrunk by [`Or9::boxed`].
+    pub const fn variant_sizes() -> [usize; 9] {
+        [
+            size_of::<T1>(),
+            size_of::<T2>(),
+            size_of::<T3>(),
+            size_of::<T4>(),
+            size_of::<T5>(),
+            size_of::<T6>(),
+            size_of::<T7>(),
+            size_of::<T8>(),
+            size_of::<T9>(),
+        ]
+    }
+
+    /// Returns the alignments of the payload types, in the order of the variants.
+    pub const fn variant_aligns() -> [usize; 9] {
+        [
+            align_of::<T1>(),
+            align_of::<T2>(),
+            align_of::<T3>(),
+            align_of::<T4>(),
+            align_of::<T5>(),
+            align_of::<T6>(),
+            align_of::<T7>(),
+            align_of::<T8>(),
+            align_of::<T9>(),
+        ]
+    }
+
+    /// Returns the size of the largest payload type, which the size of the enum is at least.
+    pub const fn max_variant_size() -> usize {
+        let sizes = Self::variant_sizes();
+        let mut max = 0;
+        let mut i = 0;
+        while i < sizes.len() {
+            if sizes[i] > max {
+                max = sizes[i];
+            }
+            i += 1;
+        }
+        max
     }
+}
 
-    /// Returns true if the enum is of type T9.
-    pub fn is_t9(&self) -> bool {
+impl<T2, T3, T4, T5, T6, T7, T8, T9> Or9<Infallible, T2, T3, T4, T5, T6, T7, T8, T9> {
+    /// Removes the uninhabited `T1` position from `Or9`, which is useful to simplify
+    /// an `Or` type once some of its branches become impossible.
+    #[must_use]
+    pub fn compact_t1(self) -> Or8<T2, T3, T4, T5, T6, T7, T8, T9> {
         match self {
-            Self::T9(_) => true,
-            _ => false,
+            Self::T1(never) => match never {},
+            Self::T2(t2) => Or8::T1(t2),
+            Self::T3(t3) => Or8::T2(t3),
+            Self::T4(t4) => Or8::T3(t4),
+            Self::T5(t5) => Or8::T4(t5),
+            Self::T6(t6) => Or8::T5(t6),
+            Self::T7(t7) => Or8::T6(t7),
+            Self::T8(t8) => Or8::T7(t8),
+            Self::T9(t9) => Or8::T8(t9),
         }
     }
+}
 
-    /// Converts the enum to an Option containing the T1 value, if it is of type T1.
-    pub fn as_t1(self) -> Option<T1> {
+impl<T1, T3, T4, T5, T6, T7, T8, T9> Or9<T1, Infallible, T3, T4, T5, T6, T7, T8, T9> {
+    /// Removes the uninhabited `T2` position from `Or9`, which is useful to simplify
+    /// an `Or` type once some of its branches become impossible.
+    #[must_use]
+    pub fn compact_t2(self) -> Or8<T1, T3, T4, T5, T6, T7, T8, T9> {
         match self {
-            Self::T1(t1) => Some(t1),
-            _ => None,
+            Self::T1(t1) => Or8::T1(t1),
+            Self::T2(never) => match never {},
+            Self::T3(t3) => Or8::T2(t3),
+            Self::T4(t4) => Or8::T3(t4),
+            Self::T5(t5) => Or8::T4(t5),
+            Self::T6(t6) => Or8::T5(t6),
+            Self::T7(t7) => Or8::T6(t7),
+            Self::T8(t8) => Or8::T7(t8),
+            Self::T9(t9) => Or8::T8(t9),
         }
     }
+}
 
-    /// Converts the enum to an Option containing the T2 value, if it is of type T2.
-    pub fn as_t2(self) -> Option<T2> {
+impl<T1, T2, T4, T5, T6, T7, T8, T9> Or9<T1, T2, Infallible, T4, T5, T6, T7, T8, T9> {
+    /// Removes the uninhabited `T3` position from `Or9`, which is useful to simplify
+    /// an `Or` type once some of its branches become impossible.
+    #[must_use]
+    pub fn compact_t3(self) -> Or8<T1, T2, T4, T5, T6, T7, T8, T9> {
         match self {
-            Self::T2(t2) => Some(t2),
-            _ => None,
+            Self::T1(t1) => Or8::T1(t1),
+            Self::T2(t2) => Or8::T2(t2),
+            Self::T3(never) => match never {},
+            Self::T4(t4) => Or8::T3(t4),
+            Self::T5(t5) => Or8::T4(t5),
+            Self::T6(t6) => Or8::T5(t6),
+            Self::T7(t7) => Or8::T6(t7),
+            Self::T8(t8) => Or8::T7(t8),
+            Self::T9(t9) => Or8::T8(t9),
         }
     }
+}
 
-    /// Converts the enum to an Option containing the T3 value, if it is of type T3.
-    pub fn as_t3(self) -> Option<T3> {
+impl<T1, T2, T3, T5, T6, T7, T8, T9> Or9<T1, T2, T3, Infallible, T5, T6, T7, T8, T9> {
+    /// Removes the uninhabited `T4` position from `Or9`, which is useful to simplify
+    /// an `Or` type once some of its branches become impossible.
+    #[must_use]
+    pub fn compact_t4(self) -> Or8<T1, T2, T3, T5, T6, T7, T8, T9> {
         match self {
-            Self::T3(t3) => Some(t3),
-            _ => None,
+            Self::T1(t1) => Or8::T1(t1),
+            Self::T2(t2) => Or8::T2(t2),
+            Self::T3(t3) => Or8::T3(t3),
+            Self::T4(never) => match never {},
+            Self::T5(t5) => Or8::T4(t5),
+            Self::T6(t6) => Or8::T5(t6),
+            Self::T7(t7) => Or8::T6(t7),
+            Self::T8(t8) => Or8::T7(t8),
+            Self::T9(t9) => Or8::T8(t9),
         }
     }
+}
 
-    /// Converts the enum to an Option containing the T4 value, if it is of type T4.
-    pub fn as_t4(self) -> Option<T4> {
+impl<T1, T2, T3, T4, T6, T7, T8, T9> Or9<T1, T2, T3, T4, Infallible, T6, T7, T8, T9> {
+    /// Removes the uninhabited `T5` position from `Or9`, which is useful to simplify
+    /// an `Or` type once some of its branches become impossible.
+    #[must_use]
+    pub fn compact_t5(self) -> Or8<T1, T2, T3, T4, T6, T7, T8, T9> {
         match self {
-            Self::T4(t4) => Some(t4),
-            _ => None,
+            Self::T1(t1) => Or8::T1(t1),
+            Self::T2(t2) => Or8::T2(t2),
+            Self::T3(t3) => Or8::T3(t3),
+            Self::T4(t4) => Or8::T4(t4),
+            Self::T5(never) => match never {},
+            Self::T6(t6) => Or8::T5(t6),
+            Self::T7(t7) => Or8::T6(t7),
+            Self::T8(t8) => Or8::T7(t8),
+            Self::T9(t9) => Or8::T8(t9),
         }
     }
+}
 
-    /// Converts the enum to an Option containing the T5 value, if it is of type T5.
-    pub fn as_t5(self) -> Option<T5> {
+impl<T1, T2, T3, T4, T5, T7, T8, T9> Or9<T1, T2, T3, T4, T5, Infallible, T7, T8, T9> {
+    /// Removes the uninhabited `T6` position from `Or9`, which is useful to simplify
+    /// an `Or` type once some of its branches become impossible.
+    #[must_use]
+    pub fn compact_t6(self) -> Or8<T1, T2, T3, T4, T5, T7, T8, T9> {
         match self {
-            Self::T5(t5) => Some(t5),
-            _ => None,
+            Self::T1(t1) => Or8::T1(t1),
+            Self::T2(t2) => Or8::T2(t2),
+            Self::T3(t3) => Or8::T3(t3),
+            Self::T4(t4) => Or8::T4(t4),
+            Self::T5(t5) => Or8::T5(t5),
+            Self::T6(never) => match never {},
+            Self::T7(t7) => Or8::T6(t7),
+            Self::T8(t8) => Or8::T7(t8),
+            Self::T9(t9) => Or8::T8(t9),
         }
     }
+}
 
-    /// Converts the enum to an Option containing the T6 value, if it is of type T6.
-    pub fn as_t6(self) -> Option<T6> {
+impl<T1, T2, T3, T4, T5, T6, T8, T9> Or9<T1, T2, T3, T4, T5, T6, Infallible, T8, T9> {
+    /// Removes the uninhabited `T7` position from `Or9`, which is useful to simplify
+    /// an `Or` type once some of its branches become impossible.
+    #[must_use]
+    pub fn compact_t7(self) -> Or8<T1, T2, T3, T4, T5, T6, T8, T9> {
         match self {
-            Self::T6(t6) => Some(t6),
-            _ => None,
+            Self::T1(t1) => Or8::T1(t1),
+            Self::T2(t2) => Or8::T2(t2),
+            Self::T3(t3) => Or8::T3(t3),
+            Self::T4(t4) => Or8::T4(t4),
+            Self::T5(t5) => Or8::T5(t5),
+            Self::T6(t6) => Or8::T6(t6),
+            Self::T7(never) => match never {},
+            Self::T8(t8) => Or8::T7(t8),
+            Self::T9(t9) => Or8::T8(t9),
         }
     }
+}
 
-    /// Converts the enum to an Option containing the T7 value, if it is of type T7.
-    pub fn as_t7(self) -> Option<T7> {
+impl<T1, T2, T3, T4, T5, T6, T7, T9> Or9<T1, T2, T3, T4, T5, T6, T7, Infallible, T9> {
+    /// Removes the uninhabited `T8` position from `Or9`, which is useful to simplify
+    /// an `Or` type once some of its branches become impossible.
+    #[must_use]
+    pub fn compact_t8(self) -> Or8<T1, T2, T3, T4, T5, T6, T7, T9> {
         match self {
-            Self::T7(t7) => Some(t7),
-            _ => None,
+            Self::T1(t1) => Or8::T1(t1),
+            Self::T2(t2) => Or8::T2(t2),
+            Self::T3(t3) => Or8::T3(t3),
+            Self::T4(t4) => Or8::T4(t4),
+            Self::T5(t5) => Or8::T5(t5),
+            Self::T6(t6) => Or8::T6(t6),
+            Self::T7(t7) => Or8::T7(t7),
+            Self::T8(never) => match never {},
+            Self::T9(t9) => Or8::T8(t9),
         }
     }
+}
 
-    /// Converts the enum to an Option containing the T8 value, if it is of type T8.
-    pub fn as_t8(self) -> Option<T8> {
+impl<T1, T2, T3, T4, T5, T6, T7, T8> Or9<T1, T2, T3, T4, T5, T6, T7, T8, Infallible> {
+    /// Removes the uninhabited `T9` position from `Or9`, which is useful to simplify
+    /// an `Or` type once some of its branches become impossible.
+    #[must_use]
+    pub fn compact_t9(self) -> Or8<T1, T2, T3, T4, T5, T6, T7, T8> {
         match self {
-            Self::T8(t8) => Some(t8),
-            _ => None,
+            Self::T1(t1) => Or8::T1(t1),
+            Self::T2(t2) => Or8::T2(t2),
+            Self::T3(t3) => Or8::T3(t3),
+            Self::T4(t4) => Or8::T4(t4),
+            Self::T5(t5) => Or8::T5(t5),
+            Self::T6(t6) => Or8::T6(t6),
+            Self::T7(t7) => Or8::T7(t7),
+            Self::T8(t8) => Or8::T8(t8),
+            Self::T9(never) => match never {},
         }
     }
+}
 
-    /// Converts the enum to an Option containing the T9 value, if it is of type T9.
-    pub fn as_t9(self) -> Option<T9> {
+impl<T1, T2, T3, T4, T5, T6, T7, T8, T9> Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9> {
+    /// Converts the enum into a `Result`, treating `T1` as the ok value and the others as the
+    /// error. The conversion is lossless, and can be reverted by `From`.
+    pub fn into_result(self) -> Result<T1, Or8<T2, T3, T4, T5, T6, T7, T8, T9>> {
         match self {
-            Self::T9(t9) => Some(t9),
-            _ => None,
+            Self::T1(t1) => Ok(t1),
+            Self::T2(t2) => Err(Or8::T1(t2)),
+            Self::T3(t3) => Err(Or8::T2(t3)),
+            Self::T4(t4) => Err(Or8::T3(t4)),
+            Self::T5(t5) => Err(Or8::T4(t5)),
+            Self::T6(t6) => Err(Or8::T5(t6)),
+            Self::T7(t7) => Err(Or8::T6(t7)),
+            Self::T8(t8) => Err(Or8::T7(t8)),
+            Self::T9(t9) => Err(Or8::T8(t9)),
         }
     }
 
-    /// Transforms the T1 value of the enum using a provided function,
-    /// maintaining other types as is.
-    pub fn map_t1<F, B>(self, f: F) -> Or9<B, T2, T3, T4, T5, T6, T7, T8, T9>
+    /// Converts the enum into a `Result`, treating `T1` as the ok value and converting the
+    /// others into the error type `E`.
+    pub fn try_into_result<E>(self) -> Result<T1, E>
     where
-        F: FnOnce(T1) -> B,
+        T2: Into<E>,
+        T3: Into<E>,
+        T4: Into<E>,
+        T5: Into<E>,
+        T6: Into<E>,
+        T7: Into<E>,
+        T8: Into<E>,
+        T9: Into<E>,
     {
         match self {
-            Self::T1(t1) => Or9::<B, T2, T3, T4, T5, T6, T7, T8, T9>::T1(f(t1)),
-            Self::T2(t2) => Or9::<B, T2, T3, T4, T5, T6, T7, T8, T9>::T2(t2),
-            Self::T3(t3) => Or9::<B, T2, T3, T4, T5, T6, T7, T8, T9>::T3(t3),
-            Self::T4(t4) => Or9::<B, T2, T3, T4, T5, T6, T7, T8, T9>::T4(t4),
-            Self::T5(t5) => Or9::<B, T2, T3, T4, T5, T6, T7, T8, T9>::T5(t5),
-            Self::T6(t6) => Or9::<B, T2, T3, T4, T5, T6, T7, T8, T9>::T6(t6),
-            Self::T7(t7) => Or9::<B, T2, T3, T4, T5, T6, T7, T8, T9>::T7(t7),
-            Self::T8(t8) => Or9::<B, T2, T3, T4, T5, T6, T7, T8, T9>::T8(t8),
-            Self::T9(t9) => Or9::<B, T2, T3, T4, T5, T6, T7, T8, T9>::T9(t9),
+            Self::T1(t1) => Ok(t1),
+            Self::T2(t2) => Err(t2.into()),
+            Self::T3(t3) => Err(t3.into()),
+            Self::T4(t4) => Err(t4.into()),
+            Self::T5(t5) => Err(t5.into()),
+            Self::T6(t6) => Err(t6.into()),
+            Self::T7(t7) => Err(t7.into()),
+            Self::T8(t8) => Err(t8.into()),
+            Self::T9(t9) => Err(t9.into()),
         }
     }
+}
 
-    /// Transforms the T2 value of the enum using a provided function,
-    /// maintaining other types as is.
-    pub fn map_t2<F, B>(self, f: F) -> Or9<T1, B, T3, T4, T5, T6, T7, T8, T9>
+/// Converts the ok value into `T1` and the error into the remaining positions, which reverts
+/// `into_result`.
+impl<T1, T2, T3, T4, T5, T6, T7, T8, T9> From<Result<T1, Or8<T2, T3, T4, T5, T6, T7, T8, T9>>>
+    for Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>
+{
+    fn from(result: Result<T1, Or8<T2, T3, T4, T5, T6, T7, T8, T9>>) -> Self {
+        match result {
+            Ok(t1) => Self::T1(t1),
+            Err(Or8::T1(t2)) => Self::T2(t2),
+            Err(Or8::T2(t3)) => Self::T3(t3),
+            Err(Or8::T3(t4)) => Self::T4(t4),
+            Err(Or8::T4(t5)) => Self::T5(t5),
+            Err(Or8::T5(t6)) => Self::T6(t6),
+            Err(Or8::T6(t7)) => Self::T7(t7),
+            Err(Or8::T7(t8)) => Self::T8(t8),
+            Err(Or8::T8(t9)) => Self::T9(t9),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7, T8> Or9<T1, T2, T3, T4, T5, T6, T7, T8, ()> {
+    /// Converts the enum into an `Option`, which is `None` for the `()` in the last position.
+    /// The conversion is lossless, and can be reverted by `From`.
+    pub fn into_option(self) -> Option<Or8<T1, T2, T3, T4, T5, T6, T7, T8>> {
+        match self {
+            Self::T1(t1) => Some(Or8::T1(t1)),
+            Self::T2(t2) => Some(Or8::T2(t2)),
+            Self::T3(t3) => Some(Or8::T3(t3)),
+            Self::T4(t4) => Some(Or8::T4(t4)),
+            Self::T5(t5) => Some(Or8::T5(t5)),
+            Self::T6(t6) => Some(Or8::T6(t6)),
+            Self::T7(t7) => Some(Or8::T7(t7)),
+            Self::T8(t8) => Some(Or8::T8(t8)),
+            Self::T9(()) => None,
+        }
+    }
+}
+
+/// Converts `None` into the `()` in the last position, which reverts `into_option`.
+impl<T1, T2, T3, T4, T5, T6, T7, T8> From<Option<Or8<T1, T2, T3, T4, T5, T6, T7, T8>>>
+    for Or9<T1, T2, T3, T4, T5, T6, T7, T8, ()>
+{
+    fn from(option: Option<Or8<T1, T2, T3, T4, T5, T6, T7, T8>>) -> Self {
+        match option {
+            Some(Or8::T1(t1)) => Self::T1(t1),
+            Some(Or8::T2(t2)) => Self::T2(t2),
+            Some(Or8::T3(t3)) => Self::T3(t3),
+            Some(Or8::T4(t4)) => Self::T4(t4),
+            Some(Or8::T5(t5)) => Self::T5(t5),
+            Some(Or8::T6(t6)) => Self::T6(t6),
+            Some(Or8::T7(t7)) => Self::T7(t7),
+            Some(Or8::T8(t8)) => Self::T8(t8),
+            None => Self::T9(()),
+        }
+    }
+}
+
+impl<R, T1, T2, T3, T4, T5, T6, T7, T8, T9> When<R, Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>> {
+    /// Handles the T1 value of the pending `Or9` using a provided function,
+    /// unless a previous `when_tx` has already handled the value.
+    pub fn when_t1<F>(self, f: F) -> When<R, Or9<Infallible, T2, T3, T4, T5, T6, T7, T8, T9>>
     where
-        F: FnOnce(T2) -> B,
+        F: FnOnce(T1) -> R,
     {
-        match self {
-            Self::T1(t1) => Or9::<T1, B, T3, T4, T5, T6, T7, T8, T9>::T1(t1),
-            Self::T2(t2) => Or9::<T1, B, T3, T4, T5, T6, T7, T8, T9>::T2(f(t2)),
-            Self::T3(t3) => Or9::<T1, B, T3, T4, T5, T6, T7, T8, T9>::T3(t3),
-            Self::T4(t4) => Or9::<T1, B, T3, T4, T5, T6, T7, T8, T9>::T4(t4),
-            Self::T5(t5) => Or9::<T1, B, T3, T4, T5, T6, T7, T8, T9>::T5(t5),
-            Self::T6(t6) => Or9::<T1, B, T3, T4, T5, T6, T7, T8, T9>::T6(t6),
-            Self::T7(t7) => Or9::<T1, B, T3, T4, T5, T6, T7, T8, T9>::T7(t7),
-            Self::T8(t8) => Or9::<T1, B, T3, T4, T5, T6, T7, T8, T9>::T8(t8),
-            Self::T9(t9) => Or9::<T1, B, T3, T4, T5, T6, T7, T8, T9>::T9(t9),
+        match self.into_state() {
+            Ok(result) => When::handled(result),
+            Err(or) => or.when_t1(f),
         }
     }
 
-    /// Transforms the T3 value of the enum using a provided function,
-    /// maintaining other types as is.
-    pub fn map_t3<F, B>(self, f: F) -> Or9<T1, T2, B, T4, T5, T6, T7, T8, T9>
+    /// Handles the T2 value of the pending `Or9` using a provided function,
+    /// unless a previous `when_tx` has already handled the value.
+    pub fn when_t2<F>(self, f: F) -> When<R, Or9<T1, Infallible, T3, T4, T5, T6, T7, T8, T9>>
     where
-        F: FnOnce(T3) -> B,
+        F: FnOnce(T2) -> R,
     {
-        match self {
-            Self::T1(t1) => Or9::<T1, T2, B, T4, T5, T6, T7, T8, T9>::T1(t1),
-            Self::T2(t2) => Or9::<T1, T2, B, T4, T5, T6, T7, T8, T9>::T2(t2),
-            Self::T3(t3) => Or9::<T1, T2, B, T4, T5, T6, T7, T8, T9>::T3(f(t3)),
-            Self::T4(t4) => Or9::<T1, T2, B, T4, T5, T6, T7, T8, T9>::T4(t4),
-            Self::T5(t5) => Or9::<T1, T2, B, T4, T5, T6, T7, T8, T9>::T5(t5),
-            Self::T6(t6) => Or9::<T1, T2, B, T4, T5, T6, T7, T8, T9>::T6(t6),
-            Self::T7(t7) => Or9::<T1, T2, B, T4, T5, T6, T7, T8, T9>::T7(t7),
-            Self::T8(t8) => Or9::<T1, T2, B, T4, T5, T6, T7, T8, T9>::T8(t8),
-            Self::T9(t9) => Or9::<T1, T2, B, T4, T5, T6, T7, T8, T9>::T9(t9),
+        match self.into_state() {
+            Ok(result) => When::handled(result),
+            Err(or) => or.when_t2(f),
         }
     }
 
-    /// Transforms the T4 value of the enum using a provided function,
-    /// maintaining other types as is.
-    pub fn map_t4<F, B>(self, f: F) -> Or9<T1, T2, T3, B, T5, T6, T7, T8, T9>
+    /// Handles the T3 value of the pending `Or9` using a provided function,
+    /// unless a previous `when_tx` has already handled the value.
+    pub fn when_t3<F>(self, f: F) -> When<R, Or9<T1, T2, Infallible, T4, T5, T6, T7, T8, T9>>
     where
-        F: FnOnce(T4) -> B,
+        F: FnOnce(T3) -> R,
     {
-        match self {
-            Self::T1(t1) => Or9::<T1, T2, T3, B, T5, T6, T7, T8, T9>::T1(t1),
-            Self::T2(t2) => Or9::<T1, T2, T3, B, T5, T6, T7, T8, T9>::T2(t2),
-            Self::T3(t3) => Or9::<T1, T2, T3, B, T5, T6, T7, T8, T9>::T3(t3),
-            Self::T4(t4) => Or9::<T1, T2, T3, B, T5, T6, T7, T8, T9>::T4(f(t4)),
-            Self::T5(t5) => Or9::<T1, T2, T3, B, T5, T6, T7, T8, T9>::T5(t5),
-            Self::T6(t6) => Or9::<T1, T2, T3, B, T5, T6, T7, T8, T9>::T6(t6),
-            Self::T7(t7) => Or9::<T1, T2, T3, B, T5, T6, T7, T8, T9>::T7(t7),
-            Self::T8(t8) => Or9::<T1, T2, T3, B, T5, T6, T7, T8, T9>::T8(t8),
-            Self::T9(t9) => Or9::<T1, T2, T3, B, T5, T6, T7, T8, T9>::T9(t9),
+        match self.into_state() {
+            Ok(result) => When::handled(result),
+            Err(or) => or.when_t3(f),
         }
     }
 
-    /// Transforms the T5 value of the enum using a provided function,
-    /// maintaining other types as is.
-    pub fn map_t5<F, B>(self, f: F) -> Or9<T1, T2, T3, T4, B, T6, T7, T8, T9>
+    /// Handles the T4 value of the pending `Or9` using a provided function,
+    /// unless a previous `when_tx` has already handled the value.
+    pub fn when_t4<F>(self, f: F) -> When<R, Or9<T1, T2, T3, Infallible, T5, T6, T7, T8, T9>>
     where
-        F: FnOnce(T5) -> B,
+        F: FnOnce(T4) -> R,
     {
-        match self {
-            Self::T1(t1) => Or9::<T1, T2, T3, T4, B, T6, T7, T8, T9>::T1(t1),
-            Self::T2(t2) => Or9::<T1, T2, T3, T4, B, T6, T7, T8, T9>::T2(t2),
-            Self::T3(t3) => Or9::<T1, T2, T3, T4, B, T6, T7, T8, T9>::T3(t3),
-            Self::T4(t4) => Or9::<T1, T2, T3, T4, B, T6, T7, T8, T9>::T4(t4),
-            Self::T5(t5) => Or9::<T1, T2, T3, T4, B, T6, T7, T8, T9>::T5(f(t5)),
-            Self::T6(t6) => Or9::<T1, T2, T3, T4, B, T6, T7, T8, T9>::T6(t6),
-            Self::T7(t7) => Or9::<T1, T2, T3, T4, B, T6, T7, T8, T9>::T7(t7),
-            Self::T8(t8) => Or9::<T1, T2, T3, T4, B, T6, T7, T8, T9>::T8(t8),
-            Self::T9(t9) => Or9::<T1, T2, T3, T4, B, T6, T7, T8, T9>::T9(t9),
+        match self.into_state() {
+            Ok(result) => When::handled(result),
+            Err(or) => or.when_t4(f),
         }
     }
 
-    /// Transforms the T6 value of the enum using a provided function,
-    /// maintaining other types as is.
-    pub fn map_t6<F, B>(self, f: F) -> Or9<T1, T2, T3, T4, T5, B, T7, T8, T9>
+    /// Handles the T5 value of the pending `Or9` using a provided function,
+    /// unless a previous `when_tx` has already handled the value.
+    pub fn when_t5<F>(self, f: F) -> When<R, Or9<T1, T2, T3, T4, Infallible, T6, T7, T8, T9>>
     where
-        F: FnOnce(T6) -> B,
+        F: FnOnce(T5) -> R,
     {
-        match self {
-            Self::T1(t1) => Or9::<T1, T2, T3, T4, T5, B, T7, T8, T9>::T1(t1),
-            Self::T2(t2) => Or9::<T1, T2, T3, T4, T5, B, T7, T8, T9>::T2(t2),
-            Self::T3(t3) => Or9::<T1, T2, T3, T4, T5, B, T7, T8, T9>::T3(t3),
-            Self::T4(t4) => Or9::<T1, T2, T3, T4, T5, B, T7, T8, T9>::T4(t4),
-            Self::T5(t5) => Or9::<T1, T2, T3, T4, T5, B, T7, T8, T9>::T5(t5),
-            Self::T6(t6) => Or9::<T1, T2, T3, T4, T5, B, T7, T8, T9>::T6(f(t6)),
-            Self::T7(t7) => Or9::<T1, T2, T3, T4, T5, B, T7, T8, T9>::T7(t7),
-            Self::T8(t8) => Or9::<T1, T2, T3, T4, T5, B, T7, T8, T9>::T8(t8),
-            Self::T9(t9) => Or9::<T1, T2, T3, T4, T5, B, T7, T8, T9>::T9(t9),
+        match self.into_state() {
+            Ok(result) => When::handled(result),
+            Err(or) => or.when_t5(f),
         }
     }
 
-    /// Transforms the T7 value of the enum using a provided function,
-    /// maintaining other types as is.
-    pub fn map_t7<F, B>(self, f: F) -> Or9<T1, T2, T3, T4, T5, T6, B, T8, T9>
+    /// Handles the T6 value of the pending `Or9` using a provided function,
+    /// unless a previous `when_tx` has already handled the value.
+    pub fn when_t6<F>(self, f: F) -> When<R, Or9<T1, T2, T3, T4, T5, Infallible, T7, T8, T9>>
     where
-        F: FnOnce(T7) -> B,
+        F: FnOnce(T6) -> R,
     {
-        match self {
-            Self::T1(t1) => Or9::<T1, T2, T3, T4, T5, T6, B, T8, T9>::T1(t1),
-            Self::T2(t2) => Or9::<T1, T2, T3, T4, T5, T6, B, T8, T9>::T2(t2),
-            Self::T3(t3) => Or9::<T1, T2, T3, T4, T5, T6, B, T8, T9>::T3(t3),
-            Self::T4(t4) => Or9::<T1, T2, T3, T4, T5, T6, B, T8, T9>::T4(t4),
-            Self::T5(t5) => Or9::<T1, T2, T3, T4, T5, T6, B, T8, T9>::T5(t5),
-            Self::T6(t6) => Or9::<T1, T2, T3, T4, T5, T6, B, T8, T9>::T6(t6),
-            Self::T7(t7) => Or9::<T1, T2, T3, T4, T5, T6, B, T8, T9>::T7(f(t7)),
-            Self::T8(t8) => Or9::<T1, T2, T3, T4, T5, T6, B, T8, T9>::T8(t8),
-            Self::T9(t9) => Or9::<T1, T2, T3, T4, T5, T6, B, T8, T9>::T9(t9),
+        match self.into_state() {
+            Ok(result) => When::handled(result),
+            Err(or) => or.when_t6(f),
         }
     }
 
-    /// Transforms the T8 value of the enum using a provided function,
-    /// maintaining other types as is.
-    pub fn map_t8<F, B>(self, f: F) -> Or9<T1, T2, T3, T4, T5, T6, T7, B, T9>
+    /// Handles the T7 value of the pending `Or9` using a provided function,
+    /// unless a previous `when_tx` has already handled the value.
+    pub fn when_t7<F>(self, f: F) -> When<R, Or9<T1, T2, T3, T4, T5, T6, Infallible, T8, T9>>
     where
-        F: FnOnce(T8) -> B,
+        F: FnOnce(T7) -> R,
     {
-        match self {
-            Self::T1(t1) => Or9::<T1, T2, T3, T4, T5, T6, T7, B, T9>::T1(t1),
-            Self::T2(t2) => Or9::<T1, T2, T3, T4, T5, T6, T7, B, T9>::T2(t2),
-            Self::T3(t3) => Or9::<T1, T2, T3, T4, T5, T6, T7, B, T9>::T3(t3),
-            Self::T4(t4) => Or9::<T1, T2, T3, T4, T5, T6, T7, B, T9>::T4(t4),
-            Self::T5(t5) => Or9::<T1, T2, T3, T4, T5, T6, T7, B, T9>::T5(t5),
-            Self::T6(t6) => Or9::<T1, T2, T3, T4, T5, T6, T7, B, T9>::T6(t6),
-            Self::T7(t7) => Or9::<T1, T2, T3, T4, T5, T6, T7, B, T9>::T7(t7),
-            Self::T8(t8) => Or9::<T1, T2, T3, T4, T5, T6, T7, B, T9>::T8(f(t8)),
-            Self::T9(t9) => Or9::<T1, T2, T3, T4, T5, T6, T7, B, T9>::T9(t9),
+        match self.into_state() {
+            Ok(result) => When::handled(result),
+            Err(or) => or.when_t7(f),
         }
     }
 
-    /// Transforms the T9 value of the enum using a provided function,
-    /// maintaining other types as is.
-    pub fn map_t9<F, B>(self, f: F) -> Or9<T1, T2, T3, T4, T5, T6, T7, T8, B>
+    /// Handles the T8 value of the pending `Or9` using a provided function,
+    /// unless a previous `when_tx` has already handled the value.
+    pub fn when_t8<F>(self, f: F) -> When<R, Or9<T1, T2, T3, T4, T5, T6, T7, Infallible, T9>>
     where
-        F: FnOnce(T9) -> B,
+        F: FnOnce(T8) -> R,
     {
-        match self {
-            Self::T1(t1) => Or9::<T1, T2, T3, T4, T5, T6, T7, T8, B>::T1(t1),
-            Self::T2(t2) => Or9::<T1, T2, T3, T4, T5, T6, T7, T8, B>::T2(t2),
-            Self::T3(t3) => Or9::<T1, T2, T3, T4, T5, T6, T7, T8, B>::T3(t3),
-            Self::T4(t4) => Or9::<T1, T2, T3, T4, T5, T6, T7, T8, B>::T4(t4),
-            Self::T5(t5) => Or9::<T1, T2, T3, T4, T5, T6, T7, T8, B>::T5(t5),
-            Self::T6(t6) => Or9::<T1, T2, T3, T4, T5, T6, T7, T8, B>::T6(t6),
-            Self::T7(t7) => Or9::<T1, T2, T3, T4, T5, T6, T7, T8, B>::T7(t7),
-            Self::T8(t8) => Or9::<T1, T2, T3, T4, T5, T6, T7, T8, B>::T8(t8),
-            Self::T9(t9) => Or9::<T1, T2, T3, T4, T5, T6, T7, T8, B>::T9(f(t9)),
+        match self.into_state() {
+            Ok(result) => When::handled(result),
+            Err(or) => or.when_t8(f),
         }
     }
 
-    /// Consolidates the `Or9` enum into a single value of type `T`,
-    /// by applying provided functions.
-    pub fn fold<T, F0, F1, F2, F3, F4, F5, F6, F7, F8, F9>(
-        self,
-        f1: F1,
-        f2: F2,
-        f3: F3,
-        f4: F4,
-        f5: F5,
-        f6: F6,
-        f7: F7,
-        f8: F8,
-        f9: F9,
-    ) -> T
+    /// Handles the T9 value of the pending `Or9` using a provided function,
+    /// unless a previous `when_tx` has already handled the value.
+    pub fn when_t9<F>(self, f: F) -> When<R, Or9<T1, T2, T3, T4, T5, T6, T7, T8, Infallible>>
     where
-        F1: FnOnce(T1) -> T,
-        F2: FnOnce(T2) -> T,
-        F3: FnOnce(T3) -> T,
-        F4: FnOnce(T4) -> T,
-        F5: FnOnce(T5) -> T,
-        F6: FnOnce(T6) -> T,
-        F7: FnOnce(T7) -> T,
-        F8: FnOnce(T8) -> T,
-        F9: FnOnce(T9) -> T,
+        F: FnOnce(T9) -> R,
     {
-        match self {
-            Self::T1(t1) => f1(t1),
-            Self::T2(t2) => f2(t2),
-            Self::T3(t3) => f3(t3),
-            Self::T4(t4) => f4(t4),
-            Self::T5(t5) => f5(t5),
-            Self::T6(t6) => f6(t6),
-            Self::T7(t7) => f7(t7),
-            Self::T8(t8) => f8(t8),
-            Self::T9(t9) => f9(t9),
+        match self.into_state() {
+            Ok(result) => When::handled(result),
+            Err(or) => or.when_t9(f),
         }
     }
 }
 
-/// Extension to `Or9` to check if the enum's type matches a arbitrary type.
-/// Currently, these functions depend on the rustc intrinsics, and the constraints
-/// of the intrinsics require that the type must satisfy `'static'`.
-impl<T1, T2, T3, T4, T5, T6, T7, T8, T9> Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>
-where
-    T1: 'static,
-    T2: 'static,
-    T3: 'static,
-    T4: 'static,
-    T5: 'static,
-    T6: 'static,
-    T7: 'static,
-    T8: 'static,
-    T9: 'static,
+impl<R>
+    When<
+        R,
+        Or9<
+            Infallible,
+            Infallible,
+            Infallible,
+            Infallible,
+            Infallible,
+            Infallible,
+            Infallible,
+            Infallible,
+            Infallible,
+        >,
+    >
 {
-    pub fn is_type<T: 'static>(&self) -> bool {
-        match self {
-            Self::T1(_) => TypeId::of::<T>() == TypeId::of::<T1>(),
-            Self::T2(_) => TypeId::of::<T>() == TypeId::of::<T2>(),
-            Self::T3(_) => TypeId::of::<T>() == TypeId::of::<T3>(),
-            Self::T4(_) => TypeId::of::<T>() == TypeId::of::<T4>(),
-            Self::T5(_) => TypeId::of::<T>() == TypeId::of::<T5>(),
-            Self::T6(_) => TypeId::of::<T>() == TypeId::of::<T6>(),
-            Self::T7(_) => TypeId::of::<T>() == TypeId::of::<T7>(),
-            Self::T8(_) => TypeId::of::<T>() == TypeId::of::<T8>(),
-            Self::T9(_) => TypeId::of::<T>() == TypeId::of::<T9>(),
+    /// Finishes the chain and returns the result. This is available only when every
+    /// variant of `Or9` has been handled.
+    pub fn finish(self) -> R {
+        match self.into_state() {
+            Ok(result) => result,
+            Err(or) => match or {
+                Or9::T1(never) => match never {},
+                Or9::T2(never) => match never {},
+                Or9::T3(never) => match never {},
+                Or9::T4(never) => match never {},
+                Or9::T5(never) => match never {},
+                Or9::T6(never) => match never {},
+                Or9::T7(never) => match never {},
+                Or9::T8(never) => match never {},
+                Or9::T9(never) => match never {},
+            },
         }
     }
 }
+
+// lists the `Or` types for `as_dyn!`, which implements its local trait for each of them.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __as_dyn_impls {
+    ($($args:tt)+) => {
+        $crate::__as_dyn_impl!(Or2 { T1 T2 } $($args)+);
+        $crate::__as_dyn_impl!(Or3 { T1 T2 T3 } $($args)+);
+        $crate::__as_dyn_impl!(Or4 { T1 T2 T3 T4 } $($args)+);
+        $crate::__as_dyn_impl!(Or5 { T1 T2 T3 T4 T5 } $($args)+);
+        $crate::__as_dyn_impl!(Or6 { T1 T2 T3 T4 T5 T6 } $($args)+);
+        $crate::__as_dyn_impl!(Or7 { T1 T2 T3 T4 T5 T6 T7 } $($args)+);
+        $crate::__as_dyn_impl!(Or8 { T1 T2 T3 T4 T5 T6 T7 T8 } $($args)+);
+        $crate::__as_dyn_impl!(Or9 { T1 T2 T3 T4 T5 T6 T7 T8 T9 } $($args)+);
+    };
+}
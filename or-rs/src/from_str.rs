@@ -0,0 +1,41 @@
+//! The error of parsing a string into an `Or` type.
+//!
+//! `FromStr` of the `Or` types tries each of their types in order, and fails only if every one
+//! of them fails, with the errors of all the types in [`ParseOrError`].
+//!
+//! ```rust
+//! use or_rs::enums::Or2;
+//!
+//! let err = "hello".parse::<Or2<i32, bool>>().unwrap_err();
+//! assert_eq!(err.errors().0, "hello".parse::<i32>().unwrap_err());
+//! assert_eq!(
+//!     err.to_string(),
+//!     "no type could parse the string: invalid digit found in string, provided string was not `true` or `false`"
+//! );
+//! ```
+
+/// The errors of all the types of an `Or` type failing to parse a string, as a tuple in the
+/// order of the types.
+///
+/// `Debug`, `Display` and `Error` are implemented for every arity, even for the tuples larger
+/// than 12, which implement none of them.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ParseOrError<E> {
+    errors: E,
+}
+
+impl<E> ParseOrError<E> {
+    pub(crate) fn new(errors: E) -> Self {
+        Self { errors }
+    }
+
+    /// Borrows the errors of the types.
+    pub fn errors(&self) -> &E {
+        &self.errors
+    }
+
+    /// Takes out the errors of the types.
+    pub fn into_errors(self) -> E {
+        self.errors
+    }
+}
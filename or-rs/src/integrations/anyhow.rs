@@ -0,0 +1,302 @@
+//! `anyhow` conversions of the `Or` types, enabled by the `anyhow` feature.
+
+use std::error::Error as StdError;
+use std::fmt::Display;
+
+use crate::enums::*;
+
+impl<T1, T2> Or2<T1, T2>
+where
+    T1: StdError + Send + Sync + 'static,
+    T2: StdError + Send + Sync + 'static,
+{
+    /// Converts the error of the active variant into an `anyhow::Error`.
+    ///
+    /// The error is moved as is rather than wrapped, so it can be recovered with
+    /// `anyhow::Error::downcast` and its `source` chain is kept.
+    ///
+    /// The `?` operator converts `Or2` into an `anyhow::Error` as well, by the blanket `From`
+    /// impl of `anyhow` for every error type, which is why `Or2` can not have its own. That
+    /// keeps the enum as the error, so it is downcast to `Or2` rather than to the variant.
+    pub fn into_anyhow(self) -> anyhow::Error {
+        match self {
+            Self::T1(t1) => anyhow::Error::new(t1),
+            Self::T2(t2) => anyhow::Error::new(t2),
+        }
+    }
+
+    /// Converts the error of the active variant into an `anyhow::Error` with the given context,
+    /// the same as `anyhow::Error::context`.
+    pub fn context<C>(self, context: C) -> anyhow::Error
+    where
+        C: Display + Send + Sync + 'static,
+    {
+        self.into_anyhow().context(context)
+    }
+}
+
+impl<T1, T2, T3> Or3<T1, T2, T3>
+where
+    T1: StdError + Send + Sync + 'static,
+    T2: StdError + Send + Sync + 'static,
+    T3: StdError + Send + Sync + 'static,
+{
+    /// Converts the error of the active variant into an `anyhow::Error`.
+    ///
+    /// The error is moved as is rather than wrapped, so it can be recovered with
+    /// `anyhow::Error::downcast` and its `source` chain is kept.
+    ///
+    /// The `?` operator converts `Or3` into an `anyhow::Error` as well, by the blanket `From`
+    /// impl of `anyhow` for every error type, which is why `Or3` can not have its own. That
+    /// keeps the enum as the error, so it is downcast to `Or3` rather than to the variant.
+    pub fn into_anyhow(self) -> anyhow::Error {
+        match self {
+            Self::T1(t1) => anyhow::Error::new(t1),
+            Self::T2(t2) => anyhow::Error::new(t2),
+            Self::T3(t3) => anyhow::Error::new(t3),
+        }
+    }
+
+    /// Converts the error of the active variant into an `anyhow::Error` with the given context,
+    /// the same as `anyhow::Error::context`.
+    pub fn context<C>(self, context: C) -> anyhow::Error
+    where
+        C: Display + Send + Sync + 'static,
+    {
+        self.into_anyhow().context(context)
+    }
+}
+
+impl<T1, T2, T3, T4> Or4<T1, T2, T3, T4>
+where
+    T1: StdError + Send + Sync + 'static,
+    T2: StdError + Send + Sync + 'static,
+    T3: StdError + Send + Sync + 'static,
+    T4: StdError + Send + Sync + 'static,
+{
+    /// Converts the error of the active variant into an `anyhow::Error`.
+    ///
+    /// The error is moved as is rather than wrapped, so it can be recovered with
+    /// `anyhow::Error::downcast` and its `source` chain is kept.
+    ///
+    /// The `?` operator converts `Or4` into an `anyhow::Error` as well, by the blanket `From`
+    /// impl of `anyhow` for every error type, which is why `Or4` can not have its own. That
+    /// keeps the enum as the error, so it is downcast to `Or4` rather than to the variant.
+    pub fn into_anyhow(self) -> anyhow::Error {
+        match self {
+            Self::T1(t1) => anyhow::Error::new(t1),
+            Self::T2(t2) => anyhow::Error::new(t2),
+            Self::T3(t3) => anyhow::Error::new(t3),
+            Self::T4(t4) => anyhow::Error::new(t4),
+        }
+    }
+
+    /// Converts the error of the active variant into an `anyhow::Error` with the given context,
+    /// the same as `anyhow::Error::context`.
+    pub fn context<C>(self, context: C) -> anyhow::Error
+    where
+        C: Display + Send + Sync + 'static,
+    {
+        self.into_anyhow().context(context)
+    }
+}
+
+impl<T1, T2, T3, T4, T5> Or5<T1, T2, T3, T4, T5>
+where
+    T1: StdError + Send + Sync + 'static,
+    T2: StdError + Send + Sync + 'static,
+    T3: StdError + Send + Sync + 'static,
+    T4: StdError + Send + Sync + 'static,
+    T5: StdError + Send + Sync + 'static,
+{
+    /// Converts the error of the active variant into an `anyhow::Error`.
+    ///
+    /// The error is moved as is rather than wrapped, so it can be recovered with
+    /// `anyhow::Error::downcast` and its `source` chain is kept.
+    ///
+    /// The `?` operator converts `Or5` into an `anyhow::Error` as well, by the blanket `From`
+    /// impl of `anyhow` for every error type, which is why `Or5` can not have its own. That
+    /// keeps the enum as the error, so it is downcast to `Or5` rather than to the variant.
+    pub fn into_anyhow(self) -> anyhow::Error {
+        match self {
+            Self::T1(t1) => anyhow::Error::new(t1),
+            Self::T2(t2) => anyhow::Error::new(t2),
+            Self::T3(t3) => anyhow::Error::new(t3),
+            Self::T4(t4) => anyhow::Error::new(t4),
+            Self::T5(t5) => anyhow::Error::new(t5),
+        }
+    }
+
+    /// Converts the error of the active variant into an `anyhow::Error` with the given context,
+    /// the same as `anyhow::Error::context`.
+    pub fn context<C>(self, context: C) -> anyhow::Error
+    where
+        C: Display + Send + Sync + 'static,
+    {
+        self.into_anyhow().context(context)
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6> Or6<T1, T2, T3, T4, T5, T6>
+where
+    T1: StdError + Send + Sync + 'static,
+    T2: StdError + Send + Sync + 'static,
+    T3: StdError + Send + Sync + 'static,
+    T4: StdError + Send + Sync + 'static,
+    T5: StdError + Send + Sync + 'static,
+    T6: StdError + Send + Sync + 'static,
+{
+    /// Converts the error of the active variant into an `anyhow::Error`.
+    ///
+    /// The error is moved as is rather than wrapped, so it can be recovered with
+    /// `anyhow::Error::downcast` and its `source` chain is kept.
+    ///
+    /// The `?` operator converts `Or6` into an `anyhow::Error` as well, by the blanket `From`
+    /// impl of `anyhow` for every error type, which is why `Or6` can not have its own. That
+    /// keeps the enum as the error, so it is downcast to `Or6` rather than to the variant.
+    pub fn into_anyhow(self) -> anyhow::Error {
+        match self {
+            Self::T1(t1) => anyhow::Error::new(t1),
+            Self::T2(t2) => anyhow::Error::new(t2),
+            Self::T3(t3) => anyhow::Error::new(t3),
+            Self::T4(t4) => anyhow::Error::new(t4),
+            Self::T5(t5) => anyhow::Error::new(t5),
+            Self::T6(t6) => anyhow::Error::new(t6),
+        }
+    }
+
+    /// Converts the error of the active variant into an `anyhow::Error` with the given context,
+    /// the same as `anyhow::Error::context`.
+    pub fn context<C>(self, context: C) -> anyhow::Error
+    where
+        C: Display + Send + Sync + 'static,
+    {
+        self.into_anyhow().context(context)
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7> Or7<T1, T2, T3, T4, T5, T6, T7>
+where
+    T1: StdError + Send + Sync + 'static,
+    T2: StdError + Send + Sync + 'static,
+    T3: StdError + Send + Sync + 'static,
+    T4: StdError + Send + Sync + 'static,
+    T5: StdError + Send + Sync + 'static,
+    T6: StdError + Send + Sync + 'static,
+    T7: StdError + Send + Sync + 'static,
+{
+    /// Converts the error of the active variant into an `anyhow::Error`.
+    ///
+    /// The error is moved as is rather than wrapped, so it can be recovered with
+    /// `anyhow::Error::downcast` and its `source` chain is kept.
+    ///
+    /// The `?` operator converts `Or7` into an `anyhow::Error` as well, by the blanket `From`
+    /// impl of `anyhow` for every error type, which is why `Or7` can not have its own. That
+    /// keeps the enum as the error, so it is downcast to `Or7` rather than to the variant.
+    pub fn into_anyhow(self) -> anyhow::Error {
+        match self {
+            Self::T1(t1) => anyhow::Error::new(t1),
+            Self::T2(t2) => anyhow::Error::new(t2),
+            Self::T3(t3) => anyhow::Error::new(t3),
+            Self::T4(t4) => anyhow::Error::new(t4),
+            Self::T5(t5) => anyhow::Error::new(t5),
+            Self::T6(t6) => anyhow::Error::new(t6),
+            Self::T7(t7) => anyhow::Error::new(t7),
+        }
+    }
+
+    /// Converts the error of the active variant into an `anyhow::Error` with the given context,
+    /// the same as `anyhow::Error::context`.
+    pub fn context<C>(self, context: C) -> anyhow::Error
+    where
+        C: Display + Send + Sync + 'static,
+    {
+        self.into_anyhow().context(context)
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7, T8> Or8<T1, T2, T3, T4, T5, T6, T7, T8>
+where
+    T1: StdError + Send + Sync + 'static,
+    T2: StdError + Send + Sync + 'static,
+    T3: StdError + Send + Sync + 'static,
+    T4: StdError + Send + Sync + 'static,
+    T5: StdError + Send + Sync + 'static,
+    T6: StdError + Send + Sync + 'static,
+    T7: StdError + Send + Sync + 'static,
+    T8: StdError + Send + Sync + 'static,
+{
+    /// Converts the error of the active variant into an `anyhow::Error`.
+    ///
+    /// The error is moved as is rather than wrapped, so it can be recovered with
+    /// `anyhow::Error::downcast` and its `source` chain is kept.
+    ///
+    /// The `?` operator converts `Or8` into an `anyhow::Error` as well, by the blanket `From`
+    /// impl of `anyhow` for every error type, which is why `Or8` can not have its own. That
+    /// keeps the enum as the error, so it is downcast to `Or8` rather than to the variant.
+    pub fn into_anyhow(self) -> anyhow::Error {
+        match self {
+            Self::T1(t1) => anyhow::Error::new(t1),
+            Self::T2(t2) => anyhow::Error::new(t2),
+            Self::T3(t3) => anyhow::Error::new(t3),
+            Self::T4(t4) => anyhow::Error::new(t4),
+            Self::T5(t5) => anyhow::Error::new(t5),
+            Self::T6(t6) => anyhow::Error::new(t6),
+            Self::T7(t7) => anyhow::Error::new(t7),
+            Self::T8(t8) => anyhow::Error::new(t8),
+        }
+    }
+
+    /// Converts the error of the active variant into an `anyhow::Error` with the given context,
+    /// the same as `anyhow::Error::context`.
+    pub fn context<C>(self, context: C) -> anyhow::Error
+    where
+        C: Display + Send + Sync + 'static,
+    {
+        self.into_anyhow().context(context)
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7, T8, T9> Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>
+where
+    T1: StdError + Send + Sync + 'static,
+    T2: StdError + Send + Sync + 'static,
+    T3: StdError + Send + Sync + 'static,
+    T4: StdError + Send + Sync + 'static,
+    T5: StdError + Send + Sync + 'static,
+    T6: StdError + Send + Sync + 'static,
+    T7: StdError + Send + Sync + 'static,
+    T8: StdError + Send + Sync + 'static,
+    T9: StdError + Send + Sync + 'static,
+{
+    /// Converts the error of the active variant into an `anyhow::Error`.
+    ///
+    /// The error is moved as is rather than wrapped, so it can be recovered with
+    /// `anyhow::Error::downcast` and its `source` chain is kept.
+    ///
+    /// The `?` operator converts `Or9` into an `anyhow::Error` as well, by the blanket `From`
+    /// impl of `anyhow` for every error type, which is why `Or9` can not have its own. That
+    /// keeps the enum as the error, so it is downcast to `Or9` rather than to the variant.
+    pub fn into_anyhow(self) -> anyhow::Error {
+        match self {
+            Self::T1(t1) => anyhow::Error::new(t1),
+            Self::T2(t2) => anyhow::Error::new(t2),
+            Self::T3(t3) => anyhow::Error::new(t3),
+            Self::T4(t4) => anyhow::Error::new(t4),
+            Self::T5(t5) => anyhow::Error::new(t5),
+            Self::T6(t6) => anyhow::Error::new(t6),
+            Self::T7(t7) => anyhow::Error::new(t7),
+            Self::T8(t8) => anyhow::Error::new(t8),
+            Self::T9(t9) => anyhow::Error::new(t9),
+        }
+    }
+
+    /// Converts the error of the active variant into an `anyhow::Error` with the given context,
+    /// the same as `anyhow::Error::context`.
+    pub fn context<C>(self, context: C) -> anyhow::Error
+    where
+        C: Display + Send + Sync + 'static,
+    {
+        self.into_anyhow().context(context)
+    }
+}
@@ -0,0 +1,913 @@
+//! `async-graphql` union output of the `Or` types, enabled by the `async-graphql` feature.
+
+use std::borrow::Cow;
+
+use ::async_graphql::indexmap::IndexSet;
+use ::async_graphql::parser::types::Field;
+use ::async_graphql::registry::{MetaType, MetaTypeId, Registry};
+use ::async_graphql::resolver_utils::{resolve_container, ContainerType, Fields};
+use ::async_graphql::{
+    Context, ContextSelectionSet, ObjectType, OutputType, Positioned, ServerResult, UnionType,
+    Value,
+};
+
+use crate::enums::*;
+
+/// Outputs `Or2` as a GraphQL union of the object types `T1` ... `T2`.
+///
+/// The union is named after its members joined with `Or`, e.g. `UserOrPost` for
+/// `Or2<User, Post>`.
+impl<T1, T2> OutputType for Or2<T1, T2>
+where
+    T1: ObjectType,
+    T2: ObjectType,
+{
+    fn type_name() -> Cow<'static, str> {
+        Cow::Owned([T1::type_name(), T2::type_name()].join("Or"))
+    }
+
+    fn introspection_type_name(&self) -> Cow<'static, str> {
+        match self {
+            Self::T1(t1) => t1.introspection_type_name(),
+            Self::T2(t2) => t2.introspection_type_name(),
+        }
+    }
+
+    fn create_type_info(registry: &mut Registry) -> String {
+        registry.create_output_type::<Self, _>(MetaTypeId::Union, |registry| {
+            T1::create_type_info(registry);
+            T2::create_type_info(registry);
+
+            let mut possible_types = IndexSet::new();
+            possible_types.insert(T1::type_name().into_owned());
+            possible_types.insert(T2::type_name().into_owned());
+
+            MetaType::Union {
+                name: <Self as OutputType>::type_name().into_owned(),
+                description: None,
+                possible_types,
+                visible: None,
+                inaccessible: false,
+                tags: Vec::new(),
+                rust_typename: Some(std::any::type_name::<Self>()),
+                directive_invocations: Vec::new(),
+            }
+        })
+    }
+
+    async fn resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        _field: &Positioned<Field>,
+    ) -> ServerResult<Value> {
+        resolve_container(ctx, self).await
+    }
+}
+
+/// Collects the fields of the active variant, so the selection set is resolved against it.
+impl<T1, T2> ContainerType for Or2<T1, T2>
+where
+    T1: ObjectType,
+    T2: ObjectType,
+{
+    async fn resolve_field(&self, _ctx: &Context<'_>) -> ServerResult<Option<Value>> {
+        Ok(None)
+    }
+
+    fn collect_all_fields<'a>(
+        &'a self,
+        ctx: &ContextSelectionSet<'a>,
+        fields: &mut Fields<'a>,
+    ) -> ServerResult<()> {
+        match self {
+            Self::T1(t1) => t1.collect_all_fields(ctx, fields),
+            Self::T2(t2) => t2.collect_all_fields(ctx, fields),
+        }
+    }
+}
+
+impl<T1, T2> UnionType for Or2<T1, T2>
+where
+    T1: ObjectType,
+    T2: ObjectType,
+{
+}
+
+/// Outputs `Or3` as a GraphQL union of the object types `T1` ... `T3`.
+///
+/// The union is named after its members joined with `Or`, e.g. `UserOrPost` for
+/// `Or2<User, Post>`.
+impl<T1, T2, T3> OutputType for Or3<T1, T2, T3>
+where
+    T1: ObjectType,
+    T2: ObjectType,
+    T3: ObjectType,
+{
+    fn type_name() -> Cow<'static, str> {
+        Cow::Owned([T1::type_name(), T2::type_name(), T3::type_name()].join("Or"))
+    }
+
+    fn introspection_type_name(&self) -> Cow<'static, str> {
+        match self {
+            Self::T1(t1) => t1.introspection_type_name(),
+            Self::T2(t2) => t2.introspection_type_name(),
+            Self::T3(t3) => t3.introspection_type_name(),
+        }
+    }
+
+    fn create_type_info(registry: &mut Registry) -> String {
+        registry.create_output_type::<Self, _>(MetaTypeId::Union, |registry| {
+            T1::create_type_info(registry);
+            T2::create_type_info(registry);
+            T3::create_type_info(registry);
+
+            let mut possible_types = IndexSet::new();
+            possible_types.insert(T1::type_name().into_owned());
+            possible_types.insert(T2::type_name().into_owned());
+            possible_types.insert(T3::type_name().into_owned());
+
+            MetaType::Union {
+                name: <Self as OutputType>::type_name().into_owned(),
+                description: None,
+                possible_types,
+                visible: None,
+                inaccessible: false,
+                tags: Vec::new(),
+                rust_typename: Some(std::any::type_name::<Self>()),
+                directive_invocations: Vec::new(),
+            }
+        })
+    }
+
+    async fn resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        _field: &Positioned<Field>,
+    ) -> ServerResult<Value> {
+        resolve_container(ctx, self).await
+    }
+}
+
+/// Collects the fields of the active variant, so the selection set is resolved against it.
+impl<T1, T2, T3> ContainerType for Or3<T1, T2, T3>
+where
+    T1: ObjectType,
+    T2: ObjectType,
+    T3: ObjectType,
+{
+    async fn resolve_field(&self, _ctx: &Context<'_>) -> ServerResult<Option<Value>> {
+        Ok(None)
+    }
+
+    fn collect_all_fields<'a>(
+        &'a self,
+        ctx: &ContextSelectionSet<'a>,
+        fields: &mut Fields<'a>,
+    ) -> ServerResult<()> {
+        match self {
+            Self::T1(t1) => t1.collect_all_fields(ctx, fields),
+            Self::T2(t2) => t2.collect_all_fields(ctx, fields),
+            Self::T3(t3) => t3.collect_all_fields(ctx, fields),
+        }
+    }
+}
+
+impl<T1, T2, T3> UnionType for Or3<T1, T2, T3>
+where
+    T1: ObjectType,
+    T2: ObjectType,
+    T3: ObjectType,
+{
+}
+
+/// Outputs `Or4` as a GraphQL union of the object types `T1` ... `T4`.
+///
+/// The union is named after its members joined with `Or`, e.g. `UserOrPost` for
+/// `Or2<User, Post>`.
+impl<T1, T2, T3, T4> OutputType for Or4<T1, T2, T3, T4>
+where
+    T1: ObjectType,
+    T2: ObjectType,
+    T3: ObjectType,
+    T4: ObjectType,
+{
+    fn type_name() -> Cow<'static, str> {
+        Cow::Owned(
+            [
+                T1::type_name(),
+                T2::type_name(),
+                T3::type_name(),
+                T4::type_name(),
+            ]
+            .join("Or"),
+        )
+    }
+
+    fn introspection_type_name(&self) -> Cow<'static, str> {
+        match self {
+            Self::T1(t1) => t1.introspection_type_name(),
+            Self::T2(t2) => t2.introspection_type_name(),
+            Self::T3(t3) => t3.introspection_type_name(),
+            Self::T4(t4) => t4.introspection_type_name(),
+        }
+    }
+
+    fn create_type_info(registry: &mut Registry) -> String {
+        registry.create_output_type::<Self, _>(MetaTypeId::Union, |registry| {
+            T1::create_type_info(registry);
+            T2::create_type_info(registry);
+            T3::create_type_info(registry);
+            T4::create_type_info(registry);
+
+            let mut possible_types = IndexSet::new();
+            possible_types.insert(T1::type_name().into_owned());
+            possible_types.insert(T2::type_name().into_owned());
+            possible_types.insert(T3::type_name().into_owned());
+            possible_types.insert(T4::type_name().into_owned());
+
+            MetaType::Union {
+                name: <Self as OutputType>::type_name().into_owned(),
+                description: None,
+                possible_types,
+                visible: None,
+                inaccessible: false,
+                tags: Vec::new(),
+                rust_typename: Some(std::any::type_name::<Self>()),
+                directive_invocations: Vec::new(),
+            }
+        })
+    }
+
+    async fn resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        _field: &Positioned<Field>,
+    ) -> ServerResult<Value> {
+        resolve_container(ctx, self).await
+    }
+}
+
+/// Collects the fields of the active variant, so the selection set is resolved against it.
+impl<T1, T2, T3, T4> ContainerType for Or4<T1, T2, T3, T4>
+where
+    T1: ObjectType,
+    T2: ObjectType,
+    T3: ObjectType,
+    T4: ObjectType,
+{
+    async fn resolve_field(&self, _ctx: &Context<'_>) -> ServerResult<Option<Value>> {
+        Ok(None)
+    }
+
+    fn collect_all_fields<'a>(
+        &'a self,
+        ctx: &ContextSelectionSet<'a>,
+        fields: &mut Fields<'a>,
+    ) -> ServerResult<()> {
+        match self {
+            Self::T1(t1) => t1.collect_all_fields(ctx, fields),
+            Self::T2(t2) => t2.collect_all_fields(ctx, fields),
+            Self::T3(t3) => t3.collect_all_fields(ctx, fields),
+            Self::T4(t4) => t4.collect_all_fields(ctx, fields),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4> UnionType for Or4<T1, T2, T3, T4>
+where
+    T1: ObjectType,
+    T2: ObjectType,
+    T3: ObjectType,
+    T4: ObjectType,
+{
+}
+
+/// Outputs `Or5` as a GraphQL union of the object types `T1` ... `T5`.
+///
+/// The union is named after its members joined with `Or`, e.g. `UserOrPost` for
+/// `Or2<User, Post>`.
+impl<T1, T2, T3, T4, T5> OutputType for Or5<T1, T2, T3, T4, T5>
+where
+    T1: ObjectType,
+    T2: ObjectType,
+    T3: ObjectType,
+    T4: ObjectType,
+    T5: ObjectType,
+{
+    fn type_name() -> Cow<'static, str> {
+        Cow::Owned(
+            [
+                T1::type_name(),
+                T2::type_name(),
+                T3::type_name(),
+                T4::type_name(),
+                T5::type_name(),
+            ]
+            .join("Or"),
+        )
+    }
+
+    fn introspection_type_name(&self) -> Cow<'static, str> {
+        match self {
+            Self::T1(t1) => t1.introspection_type_name(),
+            Self::T2(t2) => t2.introspection_type_name(),
+            Self::T3(t3) => t3.introspection_type_name(),
+            Self::T4(t4) => t4.introspection_type_name(),
+            Self::T5(t5) => t5.introspection_type_name(),
+        }
+    }
+
+    fn create_type_info(registry: &mut Registry) -> String {
+        registry.create_output_type::<Self, _>(MetaTypeId::Union, |registry| {
+            T1::create_type_info(registry);
+            T2::create_type_info(registry);
+            T3::create_type_info(registry);
+            T4::create_type_info(registry);
+            T5::create_type_info(registry);
+
+            let mut possible_types = IndexSet::new();
+            possible_types.insert(T1::type_name().into_owned());
+            possible_types.insert(T2::type_name().into_owned());
+            possible_types.insert(T3::type_name().into_owned());
+            possible_types.insert(T4::type_name().into_owned());
+            possible_types.insert(T5::type_name().into_owned());
+
+            MetaType::Union {
+                name: <Self as OutputType>::type_name().into_owned(),
+                description: None,
+                possible_types,
+                visible: None,
+                inaccessible: false,
+                tags: Vec::new(),
+                rust_typename: Some(std::any::type_name::<Self>()),
+                directive_invocations: Vec::new(),
+            }
+        })
+    }
+
+    async fn resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        _field: &Positioned<Field>,
+    ) -> ServerResult<Value> {
+        resolve_container(ctx, self).await
+    }
+}
+
+/// Collects the fields of the active variant, so the selection set is resolved against it.
+impl<T1, T2, T3, T4, T5> ContainerType for Or5<T1, T2, T3, T4, T5>
+where
+    T1: ObjectType,
+    T2: ObjectType,
+    T3: ObjectType,
+    T4: ObjectType,
+    T5: ObjectType,
+{
+    async fn resolve_field(&self, _ctx: &Context<'_>) -> ServerResult<Option<Value>> {
+        Ok(None)
+    }
+
+    fn collect_all_fields<'a>(
+        &'a self,
+        ctx: &ContextSelectionSet<'a>,
+        fields: &mut Fields<'a>,
+    ) -> ServerResult<()> {
+        match self {
+            Self::T1(t1) => t1.collect_all_fields(ctx, fields),
+            Self::T2(t2) => t2.collect_all_fields(ctx, fields),
+            Self::T3(t3) => t3.collect_all_fields(ctx, fields),
+            Self::T4(t4) => t4.collect_all_fields(ctx, fields),
+            Self::T5(t5) => t5.collect_all_fields(ctx, fields),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5> UnionType for Or5<T1, T2, T3, T4, T5>
+where
+    T1: ObjectType,
+    T2: ObjectType,
+    T3: ObjectType,
+    T4: ObjectType,
+    T5: ObjectType,
+{
+}
+
+/// Outputs `Or6` as a GraphQL union of the object types `T1` ... `T6`.
+///
+/// The union is named after its members joined with `Or`, e.g. `UserOrPost` for
+/// `Or2<User, Post>`.
+impl<T1, T2, T3, T4, T5, T6> OutputType for Or6<T1, T2, T3, T4, T5, T6>
+where
+    T1: ObjectType,
+    T2: ObjectType,
+    T3: ObjectType,
+    T4: ObjectType,
+    T5: ObjectType,
+    T6: ObjectType,
+{
+    fn type_name() -> Cow<'static, str> {
+        Cow::Owned(
+            [
+                T1::type_name(),
+                T2::type_name(),
+                T3::type_name(),
+                T4::type_name(),
+                T5::type_name(),
+                T6::type_name(),
+            ]
+            .join("Or"),
+        )
+    }
+
+    fn introspection_type_name(&self) -> Cow<'static, str> {
+        match self {
+            Self::T1(t1) => t1.introspection_type_name(),
+            Self::T2(t2) => t2.introspection_type_name(),
+            Self::T3(t3) => t3.introspection_type_name(),
+            Self::T4(t4) => t4.introspection_type_name(),
+            Self::T5(t5) => t5.introspection_type_name(),
+            Self::T6(t6) => t6.introspection_type_name(),
+        }
+    }
+
+    fn create_type_info(registry: &mut Registry) -> String {
+        registry.create_output_type::<Self, _>(MetaTypeId::Union, |registry| {
+            T1::create_type_info(registry);
+            T2::create_type_info(registry);
+            T3::create_type_info(registry);
+            T4::create_type_info(registry);
+            T5::create_type_info(registry);
+            T6::create_type_info(registry);
+
+            let mut possible_types = IndexSet::new();
+            possible_types.insert(T1::type_name().into_owned());
+            possible_types.insert(T2::type_name().into_owned());
+            possible_types.insert(T3::type_name().into_owned());
+            possible_types.insert(T4::type_name().into_owned());
+            possible_types.insert(T5::type_name().into_owned());
+            possible_types.insert(T6::type_name().into_owned());
+
+            MetaType::Union {
+                name: <Self as OutputType>::type_name().into_owned(),
+                description: None,
+                possible_types,
+                visible: None,
+                inaccessible: false,
+                tags: Vec::new(),
+                rust_typename: Some(std::any::type_name::<Self>()),
+                directive_invocations: Vec::new(),
+            }
+        })
+    }
+
+    async fn resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        _field: &Positioned<Field>,
+    ) -> ServerResult<Value> {
+        resolve_container(ctx, self).await
+    }
+}
+
+/// Collects the fields of the active variant, so the selection set is resolved against it.
+impl<T1, T2, T3, T4, T5, T6> ContainerType for Or6<T1, T2, T3, T4, T5, T6>
+where
+    T1: ObjectType,
+    T2: ObjectType,
+    T3: ObjectType,
+    T4: ObjectType,
+    T5: ObjectType,
+    T6: ObjectType,
+{
+    async fn resolve_field(&self, _ctx: &Context<'_>) -> ServerResult<Option<Value>> {
+        Ok(None)
+    }
+
+    fn collect_all_fields<'a>(
+        &'a self,
+        ctx: &ContextSelectionSet<'a>,
+        fields: &mut Fields<'a>,
+    ) -> ServerResult<()> {
+        match self {
+            Self::T1(t1) => t1.collect_all_fields(ctx, fields),
+            Self::T2(t2) => t2.collect_all_fields(ctx, fields),
+            Self::T3(t3) => t3.collect_all_fields(ctx, fields),
+            Self::T4(t4) => t4.collect_all_fields(ctx, fields),
+            Self::T5(t5) => t5.collect_all_fields(ctx, fields),
+            Self::T6(t6) => t6.collect_all_fields(ctx, fields),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6> UnionType for Or6<T1, T2, T3, T4, T5, T6>
+where
+    T1: ObjectType,
+    T2: ObjectType,
+    T3: ObjectType,
+    T4: ObjectType,
+    T5: ObjectType,
+    T6: ObjectType,
+{
+}
+
+/// Outputs `Or7` as a GraphQL union of the object types `T1` ... `T7`.
+///
+/// The union is named after its members joined with `Or`, e.g. `UserOrPost` for
+/// `Or2<User, Post>`.
+impl<T1, T2, T3, T4, T5, T6, T7> OutputType for Or7<T1, T2, T3, T4, T5, T6, T7>
+where
+    T1: ObjectType,
+    T2: ObjectType,
+    T3: ObjectType,
+    T4: ObjectType,
+    T5: ObjectType,
+    T6: ObjectType,
+    T7: ObjectType,
+{
+    fn type_name() -> Cow<'static, str> {
+        Cow::Owned(
+            [
+                T1::type_name(),
+                T2::type_name(),
+                T3::type_name(),
+                T4::type_name(),
+                T5::type_name(),
+                T6::type_name(),
+                T7::type_name(),
+            ]
+            .join("Or"),
+        )
+    }
+
+    fn introspection_type_name(&self) -> Cow<'static, str> {
+        match self {
+            Self::T1(t1) => t1.introspection_type_name(),
+            Self::T2(t2) => t2.introspection_type_name(),
+            Self::T3(t3) => t3.introspection_type_name(),
+            Self::T4(t4) => t4.introspection_type_name(),
+            Self::T5(t5) => t5.introspection_type_name(),
+            Self::T6(t6) => t6.introspection_type_name(),
+            Self::T7(t7) => t7.introspection_type_name(),
+        }
+    }
+
+    fn create_type_info(registry: &mut Registry) -> String {
+        registry.create_output_type::<Self, _>(MetaTypeId::Union, |registry| {
+            T1::create_type_info(registry);
+            T2::create_type_info(registry);
+            T3::create_type_info(registry);
+            T4::create_type_info(registry);
+            T5::create_type_info(registry);
+            T6::create_type_info(registry);
+            T7::create_type_info(registry);
+
+            let mut possible_types = IndexSet::new();
+            possible_types.insert(T1::type_name().into_owned());
+            possible_types.insert(T2::type_name().into_owned());
+            possible_types.insert(T3::type_name().into_owned());
+            possible_types.insert(T4::type_name().into_owned());
+            possible_types.insert(T5::type_name().into_owned());
+            possible_types.insert(T6::type_name().into_owned());
+            possible_types.insert(T7::type_name().into_owned());
+
+            MetaType::Union {
+                name: <Self as OutputType>::type_name().into_owned(),
+                description: None,
+                possible_types,
+                visible: None,
+                inaccessible: false,
+                tags: Vec::new(),
+                rust_typename: Some(std::any::type_name::<Self>()),
+                directive_invocations: Vec::new(),
+            }
+        })
+    }
+
+    async fn resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        _field: &Positioned<Field>,
+    ) -> ServerResult<Value> {
+        resolve_container(ctx, self).await
+    }
+}
+
+/// Collects the fields of the active variant, so the selection set is resolved against it.
+impl<T1, T2, T3, T4, T5, T6, T7> ContainerType for Or7<T1, T2, T3, T4, T5, T6, T7>
+where
+    T1: ObjectType,
+    T2: ObjectType,
+    T3: ObjectType,
+    T4: ObjectType,
+    T5: ObjectType,
+    T6: ObjectType,
+    T7: ObjectType,
+{
+    async fn resolve_field(&self, _ctx: &Context<'_>) -> ServerResult<Option<Value>> {
+        Ok(None)
+    }
+
+    fn collect_all_fields<'a>(
+        &'a self,
+        ctx: &ContextSelectionSet<'a>,
+        fields: &mut Fields<'a>,
+    ) -> ServerResult<()> {
+        match self {
+            Self::T1(t1) => t1.collect_all_fields(ctx, fields),
+            Self::T2(t2) => t2.collect_all_fields(ctx, fields),
+            Self::T3(t3) => t3.collect_all_fields(ctx, fields),
+            Self::T4(t4) => t4.collect_all_fields(ctx, fields),
+            Self::T5(t5) => t5.collect_all_fields(ctx, fields),
+            Self::T6(t6) => t6.collect_all_fields(ctx, fields),
+            Self::T7(t7) => t7.collect_all_fields(ctx, fields),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7> UnionType for Or7<T1, T2, T3, T4, T5, T6, T7>
+where
+    T1: ObjectType,
+    T2: ObjectType,
+    T3: ObjectType,
+    T4: ObjectType,
+    T5: ObjectType,
+    T6: ObjectType,
+    T7: ObjectType,
+{
+}
+
+/// Outputs `Or8` as a GraphQL union of the object types `T1` ... `T8`.
+///
+/// The union is named after its members joined with `Or`, e.g. `UserOrPost` for
+/// `Or2<User, Post>`.
+impl<T1, T2, T3, T4, T5, T6, T7, T8> OutputType for Or8<T1, T2, T3, T4, T5, T6, T7, T8>
+where
+    T1: ObjectType,
+    T2: ObjectType,
+    T3: ObjectType,
+    T4: ObjectType,
+    T5: ObjectType,
+    T6: ObjectType,
+    T7: ObjectType,
+    T8: ObjectType,
+{
+    fn type_name() -> Cow<'static, str> {
+        Cow::Owned(
+            [
+                T1::type_name(),
+                T2::type_name(),
+                T3::type_name(),
+                T4::type_name(),
+                T5::type_name(),
+                T6::type_name(),
+                T7::type_name(),
+                T8::type_name(),
+            ]
+            .join("Or"),
+        )
+    }
+
+    fn introspection_type_name(&self) -> Cow<'static, str> {
+        match self {
+            Self::T1(t1) => t1.introspection_type_name(),
+            Self::T2(t2) => t2.introspection_type_name(),
+            Self::T3(t3) => t3.introspection_type_name(),
+            Self::T4(t4) => t4.introspection_type_name(),
+            Self::T5(t5) => t5.introspection_type_name(),
+            Self::T6(t6) => t6.introspection_type_name(),
+            Self::T7(t7) => t7.introspection_type_name(),
+            Self::T8(t8) => t8.introspection_type_name(),
+        }
+    }
+
+    fn create_type_info(registry: &mut Registry) -> String {
+        registry.create_output_type::<Self, _>(MetaTypeId::Union, |registry| {
+            T1::create_type_info(registry);
+            T2::create_type_info(registry);
+            T3::create_type_info(registry);
+            T4::create_type_info(registry);
+            T5::create_type_info(registry);
+            T6::create_type_info(registry);
+            T7::create_type_info(registry);
+            T8::create_type_info(registry);
+
+            let mut possible_types = IndexSet::new();
+            possible_types.insert(T1::type_name().into_owned());
+            possible_types.insert(T2::type_name().into_owned());
+            possible_types.insert(T3::type_name().into_owned());
+            possible_types.insert(T4::type_name().into_owned());
+            possible_types.insert(T5::type_name().into_owned());
+            possible_types.insert(T6::type_name().into_owned());
+            possible_types.insert(T7::type_name().into_owned());
+            possible_types.insert(T8::type_name().into_owned());
+
+            MetaType::Union {
+                name: <Self as OutputType>::type_name().into_owned(),
+                description: None,
+                possible_types,
+                visible: None,
+                inaccessible: false,
+                tags: Vec::new(),
+                rust_typename: Some(std::any::type_name::<Self>()),
+                directive_invocations: Vec::new(),
+            }
+        })
+    }
+
+    async fn resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        _field: &Positioned<Field>,
+    ) -> ServerResult<Value> {
+        resolve_container(ctx, self).await
+    }
+}
+
+/// Collects the fields of the active variant, so the selection set is resolved against it.
+impl<T1, T2, T3, T4, T5, T6, T7, T8> ContainerType for Or8<T1, T2, T3, T4, T5, T6, T7, T8>
+where
+    T1: ObjectType,
+    T2: ObjectType,
+    T3: ObjectType,
+    T4: ObjectType,
+    T5: ObjectType,
+    T6: ObjectType,
+    T7: ObjectType,
+    T8: ObjectType,
+{
+    async fn resolve_field(&self, _ctx: &Context<'_>) -> ServerResult<Option<Value>> {
+        Ok(None)
+    }
+
+    fn collect_all_fields<'a>(
+        &'a self,
+        ctx: &ContextSelectionSet<'a>,
+        fields: &mut Fields<'a>,
+    ) -> ServerResult<()> {
+        match self {
+            Self::T1(t1) => t1.collect_all_fields(ctx, fields),
+            Self::T2(t2) => t2.collect_all_fields(ctx, fields),
+            Self::T3(t3) => t3.collect_all_fields(ctx, fields),
+            Self::T4(t4) => t4.collect_all_fields(ctx, fields),
+            Self::T5(t5) => t5.collect_all_fields(ctx, fields),
+            Self::T6(t6) => t6.collect_all_fields(ctx, fields),
+            Self::T7(t7) => t7.collect_all_fields(ctx, fields),
+            Self::T8(t8) => t8.collect_all_fields(ctx, fields),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7, T8> UnionType for Or8<T1, T2, T3, T4, T5, T6, T7, T8>
+where
+    T1: ObjectType,
+    T2: ObjectType,
+    T3: ObjectType,
+    T4: ObjectType,
+    T5: ObjectType,
+    T6: ObjectType,
+    T7: ObjectType,
+    T8: ObjectType,
+{
+}
+
+/// Outputs `Or9` as a GraphQL union of the object types `T1` ... `T9`.
+///
+/// The union is named after its members joined with `Or`, e.g. `UserOrPost` for
+/// `Or2<User, Post>`.
+impl<T1, T2, T3, T4, T5, T6, T7, T8, T9> OutputType for Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>
+where
+    T1: ObjectType,
+    T2: ObjectType,
+    T3: ObjectType,
+    T4: ObjectType,
+    T5: ObjectType,
+    T6: ObjectType,
+    T7: ObjectType,
+    T8: ObjectType,
+    T9: ObjectType,
+{
+    fn type_name() -> Cow<'static, str> {
+        Cow::Owned(
+            [
+                T1::type_name(),
+                T2::type_name(),
+                T3::type_name(),
+                T4::type_name(),
+                T5::type_name(),
+                T6::type_name(),
+                T7::type_name(),
+                T8::type_name(),
+                T9::type_name(),
+            ]
+            .join("Or"),
+        )
+    }
+
+    fn introspection_type_name(&self) -> Cow<'static, str> {
+        match self {
+            Self::T1(t1) => t1.introspection_type_name(),
+            Self::T2(t2) => t2.introspection_type_name(),
+            Self::T3(t3) => t3.introspection_type_name(),
+            Self::T4(t4) => t4.introspection_type_name(),
+            Self::T5(t5) => t5.introspection_type_name(),
+            Self::T6(t6) => t6.introspection_type_name(),
+            Self::T7(t7) => t7.introspection_type_name(),
+            Self::T8(t8) => t8.introspection_type_name(),
+            Self::T9(t9) => t9.introspection_type_name(),
+        }
+    }
+
+    fn create_type_info(registry: &mut Registry) -> String {
+        registry.create_output_type::<Self, _>(MetaTypeId::Union, |registry| {
+            T1::create_type_info(registry);
+            T2::create_type_info(registry);
+            T3::create_type_info(registry);
+            T4::create_type_info(registry);
+            T5::create_type_info(registry);
+            T6::create_type_info(registry);
+            T7::create_type_info(registry);
+            T8::create_type_info(registry);
+            T9::create_type_info(registry);
+
+            let mut possible_types = IndexSet::new();
+            possible_types.insert(T1::type_name().into_owned());
+            possible_types.insert(T2::type_name().into_owned());
+            possible_types.insert(T3::type_name().into_owned());
+            possible_types.insert(T4::type_name().into_owned());
+            possible_types.insert(T5::type_name().into_owned());
+            possible_types.insert(T6::type_name().into_owned());
+            possible_types.insert(T7::type_name().into_owned());
+            possible_types.insert(T8::type_name().into_owned());
+            possible_types.insert(T9::type_name().into_owned());
+
+            MetaType::Union {
+                name: <Self as OutputType>::type_name().into_owned(),
+                description: None,
+                possible_types,
+                visible: None,
+                inaccessible: false,
+                tags: Vec::new(),
+                rust_typename: Some(std::any::type_name::<Self>()),
+                directive_invocations: Vec::new(),
+            }
+        })
+    }
+
+    async fn resolve(
+        &self,
+        ctx: &ContextSelectionSet<'_>,
+        _field: &Positioned<Field>,
+    ) -> ServerResult<Value> {
+        resolve_container(ctx, self).await
+    }
+}
+
+/// Collects the fields of the active variant, so the selection set is resolved against it.
+impl<T1, T2, T3, T4, T5, T6, T7, T8, T9> ContainerType for Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>
+where
+    T1: ObjectType,
+    T2: ObjectType,
+    T3: ObjectType,
+    T4: ObjectType,
+    T5: ObjectType,
+    T6: ObjectType,
+    T7: ObjectType,
+    T8: ObjectType,
+    T9: ObjectType,
+{
+    async fn resolve_field(&self, _ctx: &Context<'_>) -> ServerResult<Option<Value>> {
+        Ok(None)
+    }
+
+    fn collect_all_fields<'a>(
+        &'a self,
+        ctx: &ContextSelectionSet<'a>,
+        fields: &mut Fields<'a>,
+    ) -> ServerResult<()> {
+        match self {
+            Self::T1(t1) => t1.collect_all_fields(ctx, fields),
+            Self::T2(t2) => t2.collect_all_fields(ctx, fields),
+            Self::T3(t3) => t3.collect_all_fields(ctx, fields),
+            Self::T4(t4) => t4.collect_all_fields(ctx, fields),
+            Self::T5(t5) => t5.collect_all_fields(ctx, fields),
+            Self::T6(t6) => t6.collect_all_fields(ctx, fields),
+            Self::T7(t7) => t7.collect_all_fields(ctx, fields),
+            Self::T8(t8) => t8.collect_all_fields(ctx, fields),
+            Self::T9(t9) => t9.collect_all_fields(ctx, fields),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7, T8, T9> UnionType for Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>
+where
+    T1: ObjectType,
+    T2: ObjectType,
+    T3: ObjectType,
+    T4: ObjectType,
+    T5: ObjectType,
+    T6: ObjectType,
+    T7: ObjectType,
+    T8: ObjectType,
+    T9: ObjectType,
+{
+}
@@ -0,0 +1,501 @@
+//! `borsh` serialization of the `Or` types, enabled by the `borsh` feature.
+//!
+//! A value is encoded as the (0-based) `u8` index of its variant followed by the payload.
+
+use borsh::io::{Error, ErrorKind, Read, Result, Write};
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::enums::*;
+
+impl<T1, T2> BorshSerialize for Or2<T1, T2>
+where
+    T1: BorshSerialize,
+    T2: BorshSerialize,
+{
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        match self {
+            Self::T1(t1) => {
+                0u8.serialize(writer)?;
+                t1.serialize(writer)
+            }
+            Self::T2(t2) => {
+                1u8.serialize(writer)?;
+                t2.serialize(writer)
+            }
+        }
+    }
+}
+
+/// Fails with `ErrorKind::InvalidData` if the variant tag is out of range.
+impl<T1, T2> BorshDeserialize for Or2<T1, T2>
+where
+    T1: BorshDeserialize,
+    T2: BorshDeserialize,
+{
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        match u8::deserialize_reader(reader)? {
+            0 => Ok(Self::T1(T1::deserialize_reader(reader)?)),
+            1 => Ok(Self::T2(T2::deserialize_reader(reader)?)),
+            tag => Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("invalid Or2 variant tag: {}", tag),
+            )),
+        }
+    }
+}
+
+impl<T1, T2, T3> BorshSerialize for Or3<T1, T2, T3>
+where
+    T1: BorshSerialize,
+    T2: BorshSerialize,
+    T3: BorshSerialize,
+{
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        match self {
+            Self::T1(t1) => {
+                0u8.serialize(writer)?;
+                t1.serialize(writer)
+            }
+            Self::T2(t2) => {
+                1u8.serialize(writer)?;
+                t2.serialize(writer)
+            }
+            Self::T3(t3) => {
+                2u8.serialize(writer)?;
+                t3.serialize(writer)
+            }
+        }
+    }
+}
+
+/// Fails with `ErrorKind::InvalidData` if the variant tag is out of range.
+impl<T1, T2, T3> BorshDeserialize for Or3<T1, T2, T3>
+where
+    T1: BorshDeserialize,
+    T2: BorshDeserialize,
+    T3: BorshDeserialize,
+{
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        match u8::deserialize_reader(reader)? {
+            0 => Ok(Self::T1(T1::deserialize_reader(reader)?)),
+            1 => Ok(Self::T2(T2::deserialize_reader(reader)?)),
+            2 => Ok(Self::T3(T3::deserialize_reader(reader)?)),
+            tag => Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("invalid Or3 variant tag: {}", tag),
+            )),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4> BorshSerialize for Or4<T1, T2, T3, T4>
+where
+    T1: BorshSerialize,
+    T2: BorshSerialize,
+    T3: BorshSerialize,
+    T4: BorshSerialize,
+{
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        match self {
+            Self::T1(t1) => {
+                0u8.serialize(writer)?;
+                t1.serialize(writer)
+            }
+            Self::T2(t2) => {
+                1u8.serialize(writer)?;
+                t2.serialize(writer)
+            }
+            Self::T3(t3) => {
+                2u8.serialize(writer)?;
+                t3.serialize(writer)
+            }
+            Self::T4(t4) => {
+                3u8.serialize(writer)?;
+                t4.serialize(writer)
+            }
+        }
+    }
+}
+
+/// Fails with `ErrorKind::InvalidData` if the variant tag is out of range.
+impl<T1, T2, T3, T4> BorshDeserialize for Or4<T1, T2, T3, T4>
+where
+    T1: BorshDeserialize,
+    T2: BorshDeserialize,
+    T3: BorshDeserialize,
+    T4: BorshDeserialize,
+{
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        match u8::deserialize_reader(reader)? {
+            0 => Ok(Self::T1(T1::deserialize_reader(reader)?)),
+            1 => Ok(Self::T2(T2::deserialize_reader(reader)?)),
+            2 => Ok(Self::T3(T3::deserialize_reader(reader)?)),
+            3 => Ok(Self::T4(T4::deserialize_reader(reader)?)),
+            tag => Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("invalid Or4 variant tag: {}", tag),
+            )),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5> BorshSerialize for Or5<T1, T2, T3, T4, T5>
+where
+    T1: BorshSerialize,
+    T2: BorshSerialize,
+    T3: BorshSerialize,
+    T4: BorshSerialize,
+    T5: BorshSerialize,
+{
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        match self {
+            Self::T1(t1) => {
+                0u8.serialize(writer)?;
+                t1.serialize(writer)
+            }
+            Self::T2(t2) => {
+                1u8.serialize(writer)?;
+                t2.serialize(writer)
+            }
+            Self::T3(t3) => {
+                2u8.serialize(writer)?;
+                t3.serialize(writer)
+            }
+            Self::T4(t4) => {
+                3u8.serialize(writer)?;
+                t4.serialize(writer)
+            }
+            Self::T5(t5) => {
+                4u8.serialize(writer)?;
+                t5.serialize(writer)
+            }
+        }
+    }
+}
+
+/// Fails with `ErrorKind::InvalidData` if the variant tag is out of range.
+impl<T1, T2, T3, T4, T5> BorshDeserialize for Or5<T1, T2, T3, T4, T5>
+where
+    T1: BorshDeserialize,
+    T2: BorshDeserialize,
+    T3: BorshDeserialize,
+    T4: BorshDeserialize,
+    T5: BorshDeserialize,
+{
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        match u8::deserialize_reader(reader)? {
+            0 => Ok(Self::T1(T1::deserialize_reader(reader)?)),
+            1 => Ok(Self::T2(T2::deserialize_reader(reader)?)),
+            2 => Ok(Self::T3(T3::deserialize_reader(reader)?)),
+            3 => Ok(Self::T4(T4::deserialize_reader(reader)?)),
+            4 => Ok(Self::T5(T5::deserialize_reader(reader)?)),
+            tag => Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("invalid Or5 variant tag: {}", tag),
+            )),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6> BorshSerialize for Or6<T1, T2, T3, T4, T5, T6>
+where
+    T1: BorshSerialize,
+    T2: BorshSerialize,
+    T3: BorshSerialize,
+    T4: BorshSerialize,
+    T5: BorshSerialize,
+    T6: BorshSerialize,
+{
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        match self {
+            Self::T1(t1) => {
+                0u8.serialize(writer)?;
+                t1.serialize(writer)
+            }
+            Self::T2(t2) => {
+                1u8.serialize(writer)?;
+                t2.serialize(writer)
+            }
+            Self::T3(t3) => {
+                2u8.serialize(writer)?;
+                t3.serialize(writer)
+            }
+            Self::T4(t4) => {
+                3u8.serialize(writer)?;
+                t4.serialize(writer)
+            }
+            Self::T5(t5) => {
+                4u8.serialize(writer)?;
+                t5.serialize(writer)
+            }
+            Self::T6(t6) => {
+                5u8.serialize(writer)?;
+                t6.serialize(writer)
+            }
+        }
+    }
+}
+
+/// Fails with `ErrorKind::InvalidData` if the variant tag is out of range.
+impl<T1, T2, T3, T4, T5, T6> BorshDeserialize for Or6<T1, T2, T3, T4, T5, T6>
+where
+    T1: BorshDeserialize,
+    T2: BorshDeserialize,
+    T3: BorshDeserialize,
+    T4: BorshDeserialize,
+    T5: BorshDeserialize,
+    T6: BorshDeserialize,
+{
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        match u8::deserialize_reader(reader)? {
+            0 => Ok(Self::T1(T1::deserialize_reader(reader)?)),
+            1 => Ok(Self::T2(T2::deserialize_reader(reader)?)),
+            2 => Ok(Self::T3(T3::deserialize_reader(reader)?)),
+            3 => Ok(Self::T4(T4::deserialize_reader(reader)?)),
+            4 => Ok(Self::T5(T5::deserialize_reader(reader)?)),
+            5 => Ok(Self::T6(T6::deserialize_reader(reader)?)),
+            tag => Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("invalid Or6 variant tag: {}", tag),
+            )),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7> BorshSerialize for Or7<T1, T2, T3, T4, T5, T6, T7>
+where
+    T1: BorshSerialize,
+    T2: BorshSerialize,
+    T3: BorshSerialize,
+    T4: BorshSerialize,
+    T5: BorshSerialize,
+    T6: BorshSerialize,
+    T7: BorshSerialize,
+{
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        match self {
+            Self::T1(t1) => {
+                0u8.serialize(writer)?;
+                t1.serialize(writer)
+            }
+            Self::T2(t2) => {
+                1u8.serialize(writer)?;
+                t2.serialize(writer)
+            }
+            Self::T3(t3) => {
+                2u8.serialize(writer)?;
+                t3.serialize(writer)
+            }
+            Self::T4(t4) => {
+                3u8.serialize(writer)?;
+                t4.serialize(writer)
+            }
+            Self::T5(t5) => {
+                4u8.serialize(writer)?;
+                t5.serialize(writer)
+            }
+            Self::T6(t6) => {
+                5u8.serialize(writer)?;
+                t6.serialize(writer)
+            }
+            Self::T7(t7) => {
+                6u8.serialize(writer)?;
+                t7.serialize(writer)
+            }
+        }
+    }
+}
+
+/// Fails with `ErrorKind::InvalidData` if the variant tag is out of range.
+impl<T1, T2, T3, T4, T5, T6, T7> BorshDeserialize for Or7<T1, T2, T3, T4, T5, T6, T7>
+where
+    T1: BorshDeserialize,
+    T2: BorshDeserialize,
+    T3: BorshDeserialize,
+    T4: BorshDeserialize,
+    T5: BorshDeserialize,
+    T6: BorshDeserialize,
+    T7: BorshDeserialize,
+{
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        match u8::deserialize_reader(reader)? {
+            0 => Ok(Self::T1(T1::deserialize_reader(reader)?)),
+            1 => Ok(Self::T2(T2::deserialize_reader(reader)?)),
+            2 => Ok(Self::T3(T3::deserialize_reader(reader)?)),
+            3 => Ok(Self::T4(T4::deserialize_reader(reader)?)),
+            4 => Ok(Self::T5(T5::deserialize_reader(reader)?)),
+            5 => Ok(Self::T6(T6::deserialize_reader(reader)?)),
+            6 => Ok(Self::T7(T7::deserialize_reader(reader)?)),
+            tag => Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("invalid Or7 variant tag: {}", tag),
+            )),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7, T8> BorshSerialize for Or8<T1, T2, T3, T4, T5, T6, T7, T8>
+where
+    T1: BorshSerialize,
+    T2: BorshSerialize,
+    T3: BorshSerialize,
+    T4: BorshSerialize,
+    T5: BorshSerialize,
+    T6: BorshSerialize,
+    T7: BorshSerialize,
+    T8: BorshSerialize,
+{
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        match self {
+            Self::T1(t1) => {
+                0u8.serialize(writer)?;
+                t1.serialize(writer)
+            }
+            Self::T2(t2) => {
+                1u8.serialize(writer)?;
+                t2.serialize(writer)
+            }
+            Self::T3(t3) => {
+                2u8.serialize(writer)?;
+                t3.serialize(writer)
+            }
+            Self::T4(t4) => {
+                3u8.serialize(writer)?;
+                t4.serialize(writer)
+            }
+            Self::T5(t5) => {
+                4u8.serialize(writer)?;
+                t5.serialize(writer)
+            }
+            Self::T6(t6) => {
+                5u8.serialize(writer)?;
+                t6.serialize(writer)
+            }
+            Self::T7(t7) => {
+                6u8.serialize(writer)?;
+                t7.serialize(writer)
+            }
+            Self::T8(t8) => {
+                7u8.serialize(writer)?;
+                t8.serialize(writer)
+            }
+        }
+    }
+}
+
+/// Fails with `ErrorKind::InvalidData` if the variant tag is out of range.
+impl<T1, T2, T3, T4, T5, T6, T7, T8> BorshDeserialize for Or8<T1, T2, T3, T4, T5, T6, T7, T8>
+where
+    T1: BorshDeserialize,
+    T2: BorshDeserialize,
+    T3: BorshDeserialize,
+    T4: BorshDeserialize,
+    T5: BorshDeserialize,
+    T6: BorshDeserialize,
+    T7: BorshDeserialize,
+    T8: BorshDeserialize,
+{
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        match u8::deserialize_reader(reader)? {
+            0 => Ok(Self::T1(T1::deserialize_reader(reader)?)),
+            1 => Ok(Self::T2(T2::deserialize_reader(reader)?)),
+            2 => Ok(Self::T3(T3::deserialize_reader(reader)?)),
+            3 => Ok(Self::T4(T4::deserialize_reader(reader)?)),
+            4 => Ok(Self::T5(T5::deserialize_reader(reader)?)),
+            5 => Ok(Self::T6(T6::deserialize_reader(reader)?)),
+            6 => Ok(Self::T7(T7::deserialize_reader(reader)?)),
+            7 => Ok(Self::T8(T8::deserialize_reader(reader)?)),
+            tag => Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("invalid Or8 variant tag: {}", tag),
+            )),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7, T8, T9> BorshSerialize for Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>
+where
+    T1: BorshSerialize,
+    T2: BorshSerialize,
+    T3: BorshSerialize,
+    T4: BorshSerialize,
+    T5: BorshSerialize,
+    T6: BorshSerialize,
+    T7: BorshSerialize,
+    T8: BorshSerialize,
+    T9: BorshSerialize,
+{
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        match self {
+            Self::T1(t1) => {
+                0u8.serialize(writer)?;
+                t1.serialize(writer)
+            }
+            Self::T2(t2) => {
+                1u8.serialize(writer)?;
+                t2.serialize(writer)
+            }
+            Self::T3(t3) => {
+                2u8.serialize(writer)?;
+                t3.serialize(writer)
+            }
+            Self::T4(t4) => {
+                3u8.serialize(writer)?;
+                t4.serialize(writer)
+            }
+            Self::T5(t5) => {
+                4u8.serialize(writer)?;
+                t5.serialize(writer)
+            }
+            Self::T6(t6) => {
+                5u8.serialize(writer)?;
+                t6.serialize(writer)
+            }
+            Self::T7(t7) => {
+                6u8.serialize(writer)?;
+                t7.serialize(writer)
+            }
+            Self::T8(t8) => {
+                7u8.serialize(writer)?;
+                t8.serialize(writer)
+            }
+            Self::T9(t9) => {
+                8u8.serialize(writer)?;
+                t9.serialize(writer)
+            }
+        }
+    }
+}
+
+/// Fails with `ErrorKind::InvalidData` if the variant tag is out of range.
+impl<T1, T2, T3, T4, T5, T6, T7, T8, T9> BorshDeserialize
+    for Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>
+where
+    T1: BorshDeserialize,
+    T2: BorshDeserialize,
+    T3: BorshDeserialize,
+    T4: BorshDeserialize,
+    T5: BorshDeserialize,
+    T6: BorshDeserialize,
+    T7: BorshDeserialize,
+    T8: BorshDeserialize,
+    T9: BorshDeserialize,
+{
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        match u8::deserialize_reader(reader)? {
+            0 => Ok(Self::T1(T1::deserialize_reader(reader)?)),
+            1 => Ok(Self::T2(T2::deserialize_reader(reader)?)),
+            2 => Ok(Self::T3(T3::deserialize_reader(reader)?)),
+            3 => Ok(Self::T4(T4::deserialize_reader(reader)?)),
+            4 => Ok(Self::T5(T5::deserialize_reader(reader)?)),
+            5 => Ok(Self::T6(T6::deserialize_reader(reader)?)),
+            6 => Ok(Self::T7(T7::deserialize_reader(reader)?)),
+            7 => Ok(Self::T8(T8::deserialize_reader(reader)?)),
+            8 => Ok(Self::T9(T9::deserialize_reader(reader)?)),
+            tag => Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("invalid Or9 variant tag: {}", tag),
+            )),
+        }
+    }
+}
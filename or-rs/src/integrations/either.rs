@@ -0,0 +1,315 @@
+//! `either` conversions of the `Or` types, enabled by the `either` feature.
+//!
+//! `Or2<L, R>` converts into `Either<L, R>` and back, and is folded like `Either` with
+//! `fold_either`. The larger `Or` types convert into an `Either` whose right side is an `Or` of
+//! the remaining types, like `Either<T1, Or2<T2, T3>>` for `Or3<T1, T2, T3>`.
+
+use either::Either;
+
+use crate::enums::*;
+
+impl<T1, T2> Or2<T1, T2> {
+    /// Converts the enum into an `Either`, with `T1` on the left and the others on the right.
+    #[allow(clippy::type_complexity)]
+    pub fn into_either(self) -> Either<T1, T2> {
+        match self {
+            Self::T1(t1) => Either::Left(t1),
+            Self::T2(t2) => Either::Right(t2),
+        }
+    }
+
+    /// Applies `f` to the value of `T1` or `g` to the value of `T2`, the same as
+    /// `Either::either` on the converted value.
+    pub fn fold_either<F, G, B>(self, f: F, g: G) -> B
+    where
+        F: FnOnce(T1) -> B,
+        G: FnOnce(T2) -> B,
+    {
+        self.into_either().either(f, g)
+    }
+}
+
+/// Converts the left value into `T1` and the right value into the remaining positions.
+impl<T1, T2> From<Either<T1, T2>> for Or2<T1, T2> {
+    fn from(either: Either<T1, T2>) -> Self {
+        match either {
+            Either::Left(t1) => Self::T1(t1),
+            Either::Right(t2) => Self::T2(t2),
+        }
+    }
+}
+
+/// Converts `T1` into the left value and the others into the right value.
+impl<T1, T2> From<Or2<T1, T2>> for Either<T1, T2> {
+    fn from(or: Or2<T1, T2>) -> Self {
+        or.into_either()
+    }
+}
+
+impl<T1, T2, T3> Or3<T1, T2, T3> {
+    /// Converts the enum into an `Either`, with `T1` on the left and the others on the right.
+    #[allow(clippy::type_complexity)]
+    pub fn into_either(self) -> Either<T1, Or2<T2, T3>> {
+        match self {
+            Self::T1(t1) => Either::Left(t1),
+            Self::T2(t2) => Either::Right(Or2::T1(t2)),
+            Self::T3(t3) => Either::Right(Or2::T2(t3)),
+        }
+    }
+}
+
+/// Converts the left value into `T1` and the right value into the remaining positions.
+impl<T1, T2, T3> From<Either<T1, Or2<T2, T3>>> for Or3<T1, T2, T3> {
+    fn from(either: Either<T1, Or2<T2, T3>>) -> Self {
+        match either {
+            Either::Left(t1) => Self::T1(t1),
+            Either::Right(Or2::T1(t2)) => Self::T2(t2),
+            Either::Right(Or2::T2(t3)) => Self::T3(t3),
+        }
+    }
+}
+
+/// Converts `T1` into the left value and the others into the right value.
+impl<T1, T2, T3> From<Or3<T1, T2, T3>> for Either<T1, Or2<T2, T3>> {
+    fn from(or: Or3<T1, T2, T3>) -> Self {
+        or.into_either()
+    }
+}
+
+impl<T1, T2, T3, T4> Or4<T1, T2, T3, T4> {
+    /// Converts the enum into an `Either`, with `T1` on the left and the others on the right.
+    #[allow(clippy::type_complexity)]
+    pub fn into_either(self) -> Either<T1, Or3<T2, T3, T4>> {
+        match self {
+            Self::T1(t1) => Either::Left(t1),
+            Self::T2(t2) => Either::Right(Or3::T1(t2)),
+            Self::T3(t3) => Either::Right(Or3::T2(t3)),
+            Self::T4(t4) => Either::Right(Or3::T3(t4)),
+        }
+    }
+}
+
+/// Converts the left value into `T1` and the right value into the remaining positions.
+impl<T1, T2, T3, T4> From<Either<T1, Or3<T2, T3, T4>>> for Or4<T1, T2, T3, T4> {
+    fn from(either: Either<T1, Or3<T2, T3, T4>>) -> Self {
+        match either {
+            Either::Left(t1) => Self::T1(t1),
+            Either::Right(Or3::T1(t2)) => Self::T2(t2),
+            Either::Right(Or3::T2(t3)) => Self::T3(t3),
+            Either::Right(Or3::T3(t4)) => Self::T4(t4),
+        }
+    }
+}
+
+/// Converts `T1` into the left value and the others into the right value.
+impl<T1, T2, T3, T4> From<Or4<T1, T2, T3, T4>> for Either<T1, Or3<T2, T3, T4>> {
+    fn from(or: Or4<T1, T2, T3, T4>) -> Self {
+        or.into_either()
+    }
+}
+
+impl<T1, T2, T3, T4, T5> Or5<T1, T2, T3, T4, T5> {
+    /// Converts the enum into an `Either`, with `T1` on the left and the others on the right.
+    #[allow(clippy::type_complexity)]
+    pub fn into_either(self) -> Either<T1, Or4<T2, T3, T4, T5>> {
+        match self {
+            Self::T1(t1) => Either::Left(t1),
+            Self::T2(t2) => Either::Right(Or4::T1(t2)),
+            Self::T3(t3) => Either::Right(Or4::T2(t3)),
+            Self::T4(t4) => Either::Right(Or4::T3(t4)),
+            Self::T5(t5) => Either::Right(Or4::T4(t5)),
+        }
+    }
+}
+
+/// Converts the left value into `T1` and the right value into the remaining positions.
+impl<T1, T2, T3, T4, T5> From<Either<T1, Or4<T2, T3, T4, T5>>> for Or5<T1, T2, T3, T4, T5> {
+    fn from(either: Either<T1, Or4<T2, T3, T4, T5>>) -> Self {
+        match either {
+            Either::Left(t1) => Self::T1(t1),
+            Either::Right(Or4::T1(t2)) => Self::T2(t2),
+            Either::Right(Or4::T2(t3)) => Self::T3(t3),
+            Either::Right(Or4::T3(t4)) => Self::T4(t4),
+            Either::Right(Or4::T4(t5)) => Self::T5(t5),
+        }
+    }
+}
+
+/// Converts `T1` into the left value and the others into the right value.
+impl<T1, T2, T3, T4, T5> From<Or5<T1, T2, T3, T4, T5>> for Either<T1, Or4<T2, T3, T4, T5>> {
+    fn from(or: Or5<T1, T2, T3, T4, T5>) -> Self {
+        or.into_either()
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6> Or6<T1, T2, T3, T4, T5, T6> {
+    /// Converts the enum into an `Either`, with `T1` on the left and the others on the right.
+    #[allow(clippy::type_complexity)]
+    pub fn into_either(self) -> Either<T1, Or5<T2, T3, T4, T5, T6>> {
+        match self {
+            Self::T1(t1) => Either::Left(t1),
+            Self::T2(t2) => Either::Right(Or5::T1(t2)),
+            Self::T3(t3) => Either::Right(Or5::T2(t3)),
+            Self::T4(t4) => Either::Right(Or5::T3(t4)),
+            Self::T5(t5) => Either::Right(Or5::T4(t5)),
+            Self::T6(t6) => Either::Right(Or5::T5(t6)),
+        }
+    }
+}
+
+/// Converts the left value into `T1` and the right value into the remaining positions.
+impl<T1, T2, T3, T4, T5, T6> From<Either<T1, Or5<T2, T3, T4, T5, T6>>>
+    for Or6<T1, T2, T3, T4, T5, T6>
+{
+    fn from(either: Either<T1, Or5<T2, T3, T4, T5, T6>>) -> Self {
+        match either {
+            Either::Left(t1) => Self::T1(t1),
+            Either::Right(Or5::T1(t2)) => Self::T2(t2),
+            Either::Right(Or5::T2(t3)) => Self::T3(t3),
+            Either::Right(Or5::T3(t4)) => Self::T4(t4),
+            Either::Right(Or5::T4(t5)) => Self::T5(t5),
+            Either::Right(Or5::T5(t6)) => Self::T6(t6),
+        }
+    }
+}
+
+/// Converts `T1` into the left value and the others into the right value.
+impl<T1, T2, T3, T4, T5, T6> From<Or6<T1, T2, T3, T4, T5, T6>>
+    for Either<T1, Or5<T2, T3, T4, T5, T6>>
+{
+    fn from(or: Or6<T1, T2, T3, T4, T5, T6>) -> Self {
+        or.into_either()
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7> Or7<T1, T2, T3, T4, T5, T6, T7> {
+    /// Converts the enum into an `Either`, with `T1` on the left and the others on the right.
+    #[allow(clippy::type_complexity)]
+    pub fn into_either(self) -> Either<T1, Or6<T2, T3, T4, T5, T6, T7>> {
+        match self {
+            Self::T1(t1) => Either::Left(t1),
+            Self::T2(t2) => Either::Right(Or6::T1(t2)),
+            Self::T3(t3) => Either::Right(Or6::T2(t3)),
+            Self::T4(t4) => Either::Right(Or6::T3(t4)),
+            Self::T5(t5) => Either::Right(Or6::T4(t5)),
+            Self::T6(t6) => Either::Right(Or6::T5(t6)),
+            Self::T7(t7) => Either::Right(Or6::T6(t7)),
+        }
+    }
+}
+
+/// Converts the left value into `T1` and the right value into the remaining positions.
+impl<T1, T2, T3, T4, T5, T6, T7> From<Either<T1, Or6<T2, T3, T4, T5, T6, T7>>>
+    for Or7<T1, T2, T3, T4, T5, T6, T7>
+{
+    fn from(either: Either<T1, Or6<T2, T3, T4, T5, T6, T7>>) -> Self {
+        match either {
+            Either::Left(t1) => Self::T1(t1),
+            Either::Right(Or6::T1(t2)) => Self::T2(t2),
+            Either::Right(Or6::T2(t3)) => Self::T3(t3),
+            Either::Right(Or6::T3(t4)) => Self::T4(t4),
+            Either::Right(Or6::T4(t5)) => Self::T5(t5),
+            Either::Right(Or6::T5(t6)) => Self::T6(t6),
+            Either::Right(Or6::T6(t7)) => Self::T7(t7),
+        }
+    }
+}
+
+/// Converts `T1` into the left value and the others into the right value.
+impl<T1, T2, T3, T4, T5, T6, T7> From<Or7<T1, T2, T3, T4, T5, T6, T7>>
+    for Either<T1, Or6<T2, T3, T4, T5, T6, T7>>
+{
+    fn from(or: Or7<T1, T2, T3, T4, T5, T6, T7>) -> Self {
+        or.into_either()
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7, T8> Or8<T1, T2, T3, T4, T5, T6, T7, T8> {
+    /// Converts the enum into an `Either`, with `T1` on the left and the others on the right.
+    #[allow(clippy::type_complexity)]
+    pub fn into_either(self) -> Either<T1, Or7<T2, T3, T4, T5, T6, T7, T8>> {
+        match self {
+            Self::T1(t1) => Either::Left(t1),
+            Self::T2(t2) => Either::Right(Or7::T1(t2)),
+            Self::T3(t3) => Either::Right(Or7::T2(t3)),
+            Self::T4(t4) => Either::Right(Or7::T3(t4)),
+            Self::T5(t5) => Either::Right(Or7::T4(t5)),
+            Self::T6(t6) => Either::Right(Or7::T5(t6)),
+            Self::T7(t7) => Either::Right(Or7::T6(t7)),
+            Self::T8(t8) => Either::Right(Or7::T7(t8)),
+        }
+    }
+}
+
+/// Converts the left value into `T1` and the right value into the remaining positions.
+impl<T1, T2, T3, T4, T5, T6, T7, T8> From<Either<T1, Or7<T2, T3, T4, T5, T6, T7, T8>>>
+    for Or8<T1, T2, T3, T4, T5, T6, T7, T8>
+{
+    fn from(either: Either<T1, Or7<T2, T3, T4, T5, T6, T7, T8>>) -> Self {
+        match either {
+            Either::Left(t1) => Self::T1(t1),
+            Either::Right(Or7::T1(t2)) => Self::T2(t2),
+            Either::Right(Or7::T2(t3)) => Self::T3(t3),
+            Either::Right(Or7::T3(t4)) => Self::T4(t4),
+            Either::Right(Or7::T4(t5)) => Self::T5(t5),
+            Either::Right(Or7::T5(t6)) => Self::T6(t6),
+            Either::Right(Or7::T6(t7)) => Self::T7(t7),
+            Either::Right(Or7::T7(t8)) => Self::T8(t8),
+        }
+    }
+}
+
+/// Converts `T1` into the left value and the others into the right value.
+impl<T1, T2, T3, T4, T5, T6, T7, T8> From<Or8<T1, T2, T3, T4, T5, T6, T7, T8>>
+    for Either<T1, Or7<T2, T3, T4, T5, T6, T7, T8>>
+{
+    fn from(or: Or8<T1, T2, T3, T4, T5, T6, T7, T8>) -> Self {
+        or.into_either()
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7, T8, T9> Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9> {
+    /// Converts the enum into an `Either`, with `T1` on the left and the others on the right.
+    #[allow(clippy::type_complexity)]
+    pub fn into_either(self) -> Either<T1, Or8<T2, T3, T4, T5, T6, T7, T8, T9>> {
+        match self {
+            Self::T1(t1) => Either::Left(t1),
+            Self::T2(t2) => Either::Right(Or8::T1(t2)),
+            Self::T3(t3) => Either::Right(Or8::T2(t3)),
+            Self::T4(t4) => Either::Right(Or8::T3(t4)),
+            Self::T5(t5) => Either::Right(Or8::T4(t5)),
+            Self::T6(t6) => Either::Right(Or8::T5(t6)),
+            Self::T7(t7) => Either::Right(Or8::T6(t7)),
+            Self::T8(t8) => Either::Right(Or8::T7(t8)),
+            Self::T9(t9) => Either::Right(Or8::T8(t9)),
+        }
+    }
+}
+
+/// Converts the left value into `T1` and the right value into the remaining positions.
+impl<T1, T2, T3, T4, T5, T6, T7, T8, T9> From<Either<T1, Or8<T2, T3, T4, T5, T6, T7, T8, T9>>>
+    for Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>
+{
+    fn from(either: Either<T1, Or8<T2, T3, T4, T5, T6, T7, T8, T9>>) -> Self {
+        match either {
+            Either::Left(t1) => Self::T1(t1),
+            Either::Right(Or8::T1(t2)) => Self::T2(t2),
+            Either::Right(Or8::T2(t3)) => Self::T3(t3),
+            Either::Right(Or8::T3(t4)) => Self::T4(t4),
+            Either::Right(Or8::T4(t5)) => Self::T5(t5),
+            Either::Right(Or8::T5(t6)) => Self::T6(t6),
+            Either::Right(Or8::T6(t7)) => Self::T7(t7),
+            Either::Right(Or8::T7(t8)) => Self::T8(t8),
+            Either::Right(Or8::T8(t9)) => Self::T9(t9),
+        }
+    }
+}
+
+/// Converts `T1` into the left value and the others into the right value.
+impl<T1, T2, T3, T4, T5, T6, T7, T8, T9> From<Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>>
+    for Either<T1, Or8<T2, T3, T4, T5, T6, T7, T8, T9>>
+{
+    fn from(or: Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>) -> Self {
+        or.into_either()
+    }
+}
@@ -0,0 +1,2204 @@
+//! `embedded-hal` drivers of the `Or` types, enabled by the `embedded-hal` feature.
+//!
+//! The I2C, SPI and serial traits are forwarded to the driver of the active variant, so the
+//! choice between peripherals made at init time can be stored without a `dyn` trait object.
+//! Every variant must share the same error type.
+
+use embedded_hal::i2c::{self, AddressMode, I2c};
+use embedded_hal::spi::{self, SpiBus, SpiDevice};
+use embedded_hal_nb::nb;
+use embedded_hal_nb::serial;
+
+use crate::enums::*;
+
+impl<T1, T2> i2c::ErrorType for Or2<T1, T2>
+where
+    T1: i2c::ErrorType,
+    T2: i2c::ErrorType<Error = T1::Error>,
+{
+    type Error = T1::Error;
+}
+
+/// Forwards to the I2C driver of the active variant.
+impl<A: AddressMode, T1, T2> I2c<A> for Or2<T1, T2>
+where
+    T1: I2c<A>,
+    T2: I2c<A, Error = T1::Error>,
+{
+    fn read(&mut self, address: A, read: &mut [u8]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.read(address, read),
+            Self::T2(t2) => t2.read(address, read),
+        }
+    }
+
+    fn write(&mut self, address: A, write: &[u8]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.write(address, write),
+            Self::T2(t2) => t2.write(address, write),
+        }
+    }
+
+    fn write_read(&mut self, address: A, write: &[u8], read: &mut [u8]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.write_read(address, write, read),
+            Self::T2(t2) => t2.write_read(address, write, read),
+        }
+    }
+
+    fn transaction(
+        &mut self,
+        address: A,
+        operations: &mut [i2c::Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transaction(address, operations),
+            Self::T2(t2) => t2.transaction(address, operations),
+        }
+    }
+}
+
+impl<T1, T2> spi::ErrorType for Or2<T1, T2>
+where
+    T1: spi::ErrorType,
+    T2: spi::ErrorType<Error = T1::Error>,
+{
+    type Error = T1::Error;
+}
+
+/// Forwards to the SPI bus of the active variant.
+impl<Word: Copy + 'static, T1, T2> SpiBus<Word> for Or2<T1, T2>
+where
+    T1: SpiBus<Word>,
+    T2: SpiBus<Word, Error = T1::Error>,
+{
+    fn read(&mut self, words: &mut [Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.read(words),
+            Self::T2(t2) => t2.read(words),
+        }
+    }
+
+    fn write(&mut self, words: &[Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.write(words),
+            Self::T2(t2) => t2.write(words),
+        }
+    }
+
+    fn transfer(&mut self, read: &mut [Word], write: &[Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transfer(read, write),
+            Self::T2(t2) => t2.transfer(read, write),
+        }
+    }
+
+    fn transfer_in_place(&mut self, words: &mut [Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transfer_in_place(words),
+            Self::T2(t2) => t2.transfer_in_place(words),
+        }
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.flush(),
+            Self::T2(t2) => t2.flush(),
+        }
+    }
+}
+
+/// Forwards to the SPI device of the active variant.
+impl<Word: Copy + 'static, T1, T2> SpiDevice<Word> for Or2<T1, T2>
+where
+    T1: SpiDevice<Word>,
+    T2: SpiDevice<Word, Error = T1::Error>,
+{
+    fn transaction(
+        &mut self,
+        operations: &mut [spi::Operation<'_, Word>],
+    ) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transaction(operations),
+            Self::T2(t2) => t2.transaction(operations),
+        }
+    }
+
+    fn read(&mut self, words: &mut [Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.read(words),
+            Self::T2(t2) => t2.read(words),
+        }
+    }
+
+    fn write(&mut self, words: &[Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.write(words),
+            Self::T2(t2) => t2.write(words),
+        }
+    }
+
+    fn transfer(&mut self, read: &mut [Word], write: &[Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transfer(read, write),
+            Self::T2(t2) => t2.transfer(read, write),
+        }
+    }
+
+    fn transfer_in_place(&mut self, words: &mut [Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transfer_in_place(words),
+            Self::T2(t2) => t2.transfer_in_place(words),
+        }
+    }
+}
+
+impl<T1, T2> serial::ErrorType for Or2<T1, T2>
+where
+    T1: serial::ErrorType,
+    T2: serial::ErrorType<Error = T1::Error>,
+{
+    type Error = T1::Error;
+}
+
+/// Forwards to the serial reader of the active variant.
+impl<Word: Copy, T1, T2> serial::Read<Word> for Or2<T1, T2>
+where
+    T1: serial::Read<Word>,
+    T2: serial::Read<Word, Error = T1::Error>,
+{
+    fn read(&mut self) -> nb::Result<Word, Self::Error> {
+        match self {
+            Self::T1(t1) => t1.read(),
+            Self::T2(t2) => t2.read(),
+        }
+    }
+}
+
+/// Forwards to the serial writer of the active variant.
+impl<Word: Copy, T1, T2> serial::Write<Word> for Or2<T1, T2>
+where
+    T1: serial::Write<Word>,
+    T2: serial::Write<Word, Error = T1::Error>,
+{
+    fn write(&mut self, word: Word) -> nb::Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.write(word),
+            Self::T2(t2) => t2.write(word),
+        }
+    }
+
+    fn flush(&mut self) -> nb::Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.flush(),
+            Self::T2(t2) => t2.flush(),
+        }
+    }
+}
+
+impl<T1, T2, T3> i2c::ErrorType for Or3<T1, T2, T3>
+where
+    T1: i2c::ErrorType,
+    T2: i2c::ErrorType<Error = T1::Error>,
+    T3: i2c::ErrorType<Error = T1::Error>,
+{
+    type Error = T1::Error;
+}
+
+/// Forwards to the I2C driver of the active variant.
+impl<A: AddressMode, T1, T2, T3> I2c<A> for Or3<T1, T2, T3>
+where
+    T1: I2c<A>,
+    T2: I2c<A, Error = T1::Error>,
+    T3: I2c<A, Error = T1::Error>,
+{
+    fn read(&mut self, address: A, read: &mut [u8]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.read(address, read),
+            Self::T2(t2) => t2.read(address, read),
+            Self::T3(t3) => t3.read(address, read),
+        }
+    }
+
+    fn write(&mut self, address: A, write: &[u8]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.write(address, write),
+            Self::T2(t2) => t2.write(address, write),
+            Self::T3(t3) => t3.write(address, write),
+        }
+    }
+
+    fn write_read(&mut self, address: A, write: &[u8], read: &mut [u8]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.write_read(address, write, read),
+            Self::T2(t2) => t2.write_read(address, write, read),
+            Self::T3(t3) => t3.write_read(address, write, read),
+        }
+    }
+
+    fn transaction(
+        &mut self,
+        address: A,
+        operations: &mut [i2c::Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transaction(address, operations),
+            Self::T2(t2) => t2.transaction(address, operations),
+            Self::T3(t3) => t3.transaction(address, operations),
+        }
+    }
+}
+
+impl<T1, T2, T3> spi::ErrorType for Or3<T1, T2, T3>
+where
+    T1: spi::ErrorType,
+    T2: spi::ErrorType<Error = T1::Error>,
+    T3: spi::ErrorType<Error = T1::Error>,
+{
+    type Error = T1::Error;
+}
+
+/// Forwards to the SPI bus of the active variant.
+impl<Word: Copy + 'static, T1, T2, T3> SpiBus<Word> for Or3<T1, T2, T3>
+where
+    T1: SpiBus<Word>,
+    T2: SpiBus<Word, Error = T1::Error>,
+    T3: SpiBus<Word, Error = T1::Error>,
+{
+    fn read(&mut self, words: &mut [Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.read(words),
+            Self::T2(t2) => t2.read(words),
+            Self::T3(t3) => t3.read(words),
+        }
+    }
+
+    fn write(&mut self, words: &[Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.write(words),
+            Self::T2(t2) => t2.write(words),
+            Self::T3(t3) => t3.write(words),
+        }
+    }
+
+    fn transfer(&mut self, read: &mut [Word], write: &[Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transfer(read, write),
+            Self::T2(t2) => t2.transfer(read, write),
+            Self::T3(t3) => t3.transfer(read, write),
+        }
+    }
+
+    fn transfer_in_place(&mut self, words: &mut [Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transfer_in_place(words),
+            Self::T2(t2) => t2.transfer_in_place(words),
+            Self::T3(t3) => t3.transfer_in_place(words),
+        }
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.flush(),
+            Self::T2(t2) => t2.flush(),
+            Self::T3(t3) => t3.flush(),
+        }
+    }
+}
+
+/// Forwards to the SPI device of the active variant.
+impl<Word: Copy + 'static, T1, T2, T3> SpiDevice<Word> for Or3<T1, T2, T3>
+where
+    T1: SpiDevice<Word>,
+    T2: SpiDevice<Word, Error = T1::Error>,
+    T3: SpiDevice<Word, Error = T1::Error>,
+{
+    fn transaction(
+        &mut self,
+        operations: &mut [spi::Operation<'_, Word>],
+    ) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transaction(operations),
+            Self::T2(t2) => t2.transaction(operations),
+            Self::T3(t3) => t3.transaction(operations),
+        }
+    }
+
+    fn read(&mut self, words: &mut [Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.read(words),
+            Self::T2(t2) => t2.read(words),
+            Self::T3(t3) => t3.read(words),
+        }
+    }
+
+    fn write(&mut self, words: &[Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.write(words),
+            Self::T2(t2) => t2.write(words),
+            Self::T3(t3) => t3.write(words),
+        }
+    }
+
+    fn transfer(&mut self, read: &mut [Word], write: &[Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transfer(read, write),
+            Self::T2(t2) => t2.transfer(read, write),
+            Self::T3(t3) => t3.transfer(read, write),
+        }
+    }
+
+    fn transfer_in_place(&mut self, words: &mut [Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transfer_in_place(words),
+            Self::T2(t2) => t2.transfer_in_place(words),
+            Self::T3(t3) => t3.transfer_in_place(words),
+        }
+    }
+}
+
+impl<T1, T2, T3> serial::ErrorType for Or3<T1, T2, T3>
+where
+    T1: serial::ErrorType,
+    T2: serial::ErrorType<Error = T1::Error>,
+    T3: serial::ErrorType<Error = T1::Error>,
+{
+    type Error = T1::Error;
+}
+
+/// Forwards to the serial reader of the active variant.
+impl<Word: Copy, T1, T2, T3> serial::Read<Word> for Or3<T1, T2, T3>
+where
+    T1: serial::Read<Word>,
+    T2: serial::Read<Word, Error = T1::Error>,
+    T3: serial::Read<Word, Error = T1::Error>,
+{
+    fn read(&mut self) -> nb::Result<Word, Self::Error> {
+        match self {
+            Self::T1(t1) => t1.read(),
+            Self::T2(t2) => t2.read(),
+            Self::T3(t3) => t3.read(),
+        }
+    }
+}
+
+/// Forwards to the serial writer of the active variant.
+impl<Word: Copy, T1, T2, T3> serial::Write<Word> for Or3<T1, T2, T3>
+where
+    T1: serial::Write<Word>,
+    T2: serial::Write<Word, Error = T1::Error>,
+    T3: serial::Write<Word, Error = T1::Error>,
+{
+    fn write(&mut self, word: Word) -> nb::Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.write(word),
+            Self::T2(t2) => t2.write(word),
+            Self::T3(t3) => t3.write(word),
+        }
+    }
+
+    fn flush(&mut self) -> nb::Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.flush(),
+            Self::T2(t2) => t2.flush(),
+            Self::T3(t3) => t3.flush(),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4> i2c::ErrorType for Or4<T1, T2, T3, T4>
+where
+    T1: i2c::ErrorType,
+    T2: i2c::ErrorType<Error = T1::Error>,
+    T3: i2c::ErrorType<Error = T1::Error>,
+    T4: i2c::ErrorType<Error = T1::Error>,
+{
+    type Error = T1::Error;
+}
+
+/// Forwards to the I2C driver of the active variant.
+impl<A: AddressMode, T1, T2, T3, T4> I2c<A> for Or4<T1, T2, T3, T4>
+where
+    T1: I2c<A>,
+    T2: I2c<A, Error = T1::Error>,
+    T3: I2c<A, Error = T1::Error>,
+    T4: I2c<A, Error = T1::Error>,
+{
+    fn read(&mut self, address: A, read: &mut [u8]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.read(address, read),
+            Self::T2(t2) => t2.read(address, read),
+            Self::T3(t3) => t3.read(address, read),
+            Self::T4(t4) => t4.read(address, read),
+        }
+    }
+
+    fn write(&mut self, address: A, write: &[u8]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.write(address, write),
+            Self::T2(t2) => t2.write(address, write),
+            Self::T3(t3) => t3.write(address, write),
+            Self::T4(t4) => t4.write(address, write),
+        }
+    }
+
+    fn write_read(&mut self, address: A, write: &[u8], read: &mut [u8]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.write_read(address, write, read),
+            Self::T2(t2) => t2.write_read(address, write, read),
+            Self::T3(t3) => t3.write_read(address, write, read),
+            Self::T4(t4) => t4.write_read(address, write, read),
+        }
+    }
+
+    fn transaction(
+        &mut self,
+        address: A,
+        operations: &mut [i2c::Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transaction(address, operations),
+            Self::T2(t2) => t2.transaction(address, operations),
+            Self::T3(t3) => t3.transaction(address, operations),
+            Self::T4(t4) => t4.transaction(address, operations),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4> spi::ErrorType for Or4<T1, T2, T3, T4>
+where
+    T1: spi::ErrorType,
+    T2: spi::ErrorType<Error = T1::Error>,
+    T3: spi::ErrorType<Error = T1::Error>,
+    T4: spi::ErrorType<Error = T1::Error>,
+{
+    type Error = T1::Error;
+}
+
+/// Forwards to the SPI bus of the active variant.
+impl<Word: Copy + 'static, T1, T2, T3, T4> SpiBus<Word> for Or4<T1, T2, T3, T4>
+where
+    T1: SpiBus<Word>,
+    T2: SpiBus<Word, Error = T1::Error>,
+    T3: SpiBus<Word, Error = T1::Error>,
+    T4: SpiBus<Word, Error = T1::Error>,
+{
+    fn read(&mut self, words: &mut [Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.read(words),
+            Self::T2(t2) => t2.read(words),
+            Self::T3(t3) => t3.read(words),
+            Self::T4(t4) => t4.read(words),
+        }
+    }
+
+    fn write(&mut self, words: &[Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.write(words),
+            Self::T2(t2) => t2.write(words),
+            Self::T3(t3) => t3.write(words),
+            Self::T4(t4) => t4.write(words),
+        }
+    }
+
+    fn transfer(&mut self, read: &mut [Word], write: &[Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transfer(read, write),
+            Self::T2(t2) => t2.transfer(read, write),
+            Self::T3(t3) => t3.transfer(read, write),
+            Self::T4(t4) => t4.transfer(read, write),
+        }
+    }
+
+    fn transfer_in_place(&mut self, words: &mut [Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transfer_in_place(words),
+            Self::T2(t2) => t2.transfer_in_place(words),
+            Self::T3(t3) => t3.transfer_in_place(words),
+            Self::T4(t4) => t4.transfer_in_place(words),
+        }
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.flush(),
+            Self::T2(t2) => t2.flush(),
+            Self::T3(t3) => t3.flush(),
+            Self::T4(t4) => t4.flush(),
+        }
+    }
+}
+
+/// Forwards to the SPI device of the active variant.
+impl<Word: Copy + 'static, T1, T2, T3, T4> SpiDevice<Word> for Or4<T1, T2, T3, T4>
+where
+    T1: SpiDevice<Word>,
+    T2: SpiDevice<Word, Error = T1::Error>,
+    T3: SpiDevice<Word, Error = T1::Error>,
+    T4: SpiDevice<Word, Error = T1::Error>,
+{
+    fn transaction(
+        &mut self,
+        operations: &mut [spi::Operation<'_, Word>],
+    ) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transaction(operations),
+            Self::T2(t2) => t2.transaction(operations),
+            Self::T3(t3) => t3.transaction(operations),
+            Self::T4(t4) => t4.transaction(operations),
+        }
+    }
+
+    fn read(&mut self, words: &mut [Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.read(words),
+            Self::T2(t2) => t2.read(words),
+            Self::T3(t3) => t3.read(words),
+            Self::T4(t4) => t4.read(words),
+        }
+    }
+
+    fn write(&mut self, words: &[Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.write(words),
+            Self::T2(t2) => t2.write(words),
+            Self::T3(t3) => t3.write(words),
+            Self::T4(t4) => t4.write(words),
+        }
+    }
+
+    fn transfer(&mut self, read: &mut [Word], write: &[Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transfer(read, write),
+            Self::T2(t2) => t2.transfer(read, write),
+            Self::T3(t3) => t3.transfer(read, write),
+            Self::T4(t4) => t4.transfer(read, write),
+        }
+    }
+
+    fn transfer_in_place(&mut self, words: &mut [Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transfer_in_place(words),
+            Self::T2(t2) => t2.transfer_in_place(words),
+            Self::T3(t3) => t3.transfer_in_place(words),
+            Self::T4(t4) => t4.transfer_in_place(words),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4> serial::ErrorType for Or4<T1, T2, T3, T4>
+where
+    T1: serial::ErrorType,
+    T2: serial::ErrorType<Error = T1::Error>,
+    T3: serial::ErrorType<Error = T1::Error>,
+    T4: serial::ErrorType<Error = T1::Error>,
+{
+    type Error = T1::Error;
+}
+
+/// Forwards to the serial reader of the active variant.
+impl<Word: Copy, T1, T2, T3, T4> serial::Read<Word> for Or4<T1, T2, T3, T4>
+where
+    T1: serial::Read<Word>,
+    T2: serial::Read<Word, Error = T1::Error>,
+    T3: serial::Read<Word, Error = T1::Error>,
+    T4: serial::Read<Word, Error = T1::Error>,
+{
+    fn read(&mut self) -> nb::Result<Word, Self::Error> {
+        match self {
+            Self::T1(t1) => t1.read(),
+            Self::T2(t2) => t2.read(),
+            Self::T3(t3) => t3.read(),
+            Self::T4(t4) => t4.read(),
+        }
+    }
+}
+
+/// Forwards to the serial writer of the active variant.
+impl<Word: Copy, T1, T2, T3, T4> serial::Write<Word> for Or4<T1, T2, T3, T4>
+where
+    T1: serial::Write<Word>,
+    T2: serial::Write<Word, Error = T1::Error>,
+    T3: serial::Write<Word, Error = T1::Error>,
+    T4: serial::Write<Word, Error = T1::Error>,
+{
+    fn write(&mut self, word: Word) -> nb::Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.write(word),
+            Self::T2(t2) => t2.write(word),
+            Self::T3(t3) => t3.write(word),
+            Self::T4(t4) => t4.write(word),
+        }
+    }
+
+    fn flush(&mut self) -> nb::Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.flush(),
+            Self::T2(t2) => t2.flush(),
+            Self::T3(t3) => t3.flush(),
+            Self::T4(t4) => t4.flush(),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5> i2c::ErrorType for Or5<T1, T2, T3, T4, T5>
+where
+    T1: i2c::ErrorType,
+    T2: i2c::ErrorType<Error = T1::Error>,
+    T3: i2c::ErrorType<Error = T1::Error>,
+    T4: i2c::ErrorType<Error = T1::Error>,
+    T5: i2c::ErrorType<Error = T1::Error>,
+{
+    type Error = T1::Error;
+}
+
+/// Forwards to the I2C driver of the active variant.
+impl<A: AddressMode, T1, T2, T3, T4, T5> I2c<A> for Or5<T1, T2, T3, T4, T5>
+where
+    T1: I2c<A>,
+    T2: I2c<A, Error = T1::Error>,
+    T3: I2c<A, Error = T1::Error>,
+    T4: I2c<A, Error = T1::Error>,
+    T5: I2c<A, Error = T1::Error>,
+{
+    fn read(&mut self, address: A, read: &mut [u8]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.read(address, read),
+            Self::T2(t2) => t2.read(address, read),
+            Self::T3(t3) => t3.read(address, read),
+            Self::T4(t4) => t4.read(address, read),
+            Self::T5(t5) => t5.read(address, read),
+        }
+    }
+
+    fn write(&mut self, address: A, write: &[u8]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.write(address, write),
+            Self::T2(t2) => t2.write(address, write),
+            Self::T3(t3) => t3.write(address, write),
+            Self::T4(t4) => t4.write(address, write),
+            Self::T5(t5) => t5.write(address, write),
+        }
+    }
+
+    fn write_read(&mut self, address: A, write: &[u8], read: &mut [u8]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.write_read(address, write, read),
+            Self::T2(t2) => t2.write_read(address, write, read),
+            Self::T3(t3) => t3.write_read(address, write, read),
+            Self::T4(t4) => t4.write_read(address, write, read),
+            Self::T5(t5) => t5.write_read(address, write, read),
+        }
+    }
+
+    fn transaction(
+        &mut self,
+        address: A,
+        operations: &mut [i2c::Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transaction(address, operations),
+            Self::T2(t2) => t2.transaction(address, operations),
+            Self::T3(t3) => t3.transaction(address, operations),
+            Self::T4(t4) => t4.transaction(address, operations),
+            Self::T5(t5) => t5.transaction(address, operations),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5> spi::ErrorType for Or5<T1, T2, T3, T4, T5>
+where
+    T1: spi::ErrorType,
+    T2: spi::ErrorType<Error = T1::Error>,
+    T3: spi::ErrorType<Error = T1::Error>,
+    T4: spi::ErrorType<Error = T1::Error>,
+    T5: spi::ErrorType<Error = T1::Error>,
+{
+    type Error = T1::Error;
+}
+
+/// Forwards to the SPI bus of the active variant.
+impl<Word: Copy + 'static, T1, T2, T3, T4, T5> SpiBus<Word> for Or5<T1, T2, T3, T4, T5>
+where
+    T1: SpiBus<Word>,
+    T2: SpiBus<Word, Error = T1::Error>,
+    T3: SpiBus<Word, Error = T1::Error>,
+    T4: SpiBus<Word, Error = T1::Error>,
+    T5: SpiBus<Word, Error = T1::Error>,
+{
+    fn read(&mut self, words: &mut [Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.read(words),
+            Self::T2(t2) => t2.read(words),
+            Self::T3(t3) => t3.read(words),
+            Self::T4(t4) => t4.read(words),
+            Self::T5(t5) => t5.read(words),
+        }
+    }
+
+    fn write(&mut self, words: &[Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.write(words),
+            Self::T2(t2) => t2.write(words),
+            Self::T3(t3) => t3.write(words),
+            Self::T4(t4) => t4.write(words),
+            Self::T5(t5) => t5.write(words),
+        }
+    }
+
+    fn transfer(&mut self, read: &mut [Word], write: &[Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transfer(read, write),
+            Self::T2(t2) => t2.transfer(read, write),
+            Self::T3(t3) => t3.transfer(read, write),
+            Self::T4(t4) => t4.transfer(read, write),
+            Self::T5(t5) => t5.transfer(read, write),
+        }
+    }
+
+    fn transfer_in_place(&mut self, words: &mut [Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transfer_in_place(words),
+            Self::T2(t2) => t2.transfer_in_place(words),
+            Self::T3(t3) => t3.transfer_in_place(words),
+            Self::T4(t4) => t4.transfer_in_place(words),
+            Self::T5(t5) => t5.transfer_in_place(words),
+        }
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.flush(),
+            Self::T2(t2) => t2.flush(),
+            Self::T3(t3) => t3.flush(),
+            Self::T4(t4) => t4.flush(),
+            Self::T5(t5) => t5.flush(),
+        }
+    }
+}
+
+/// Forwards to the SPI device of the active variant.
+impl<Word: Copy + 'static, T1, T2, T3, T4, T5> SpiDevice<Word> for Or5<T1, T2, T3, T4, T5>
+where
+    T1: SpiDevice<Word>,
+    T2: SpiDevice<Word, Error = T1::Error>,
+    T3: SpiDevice<Word, Error = T1::Error>,
+    T4: SpiDevice<Word, Error = T1::Error>,
+    T5: SpiDevice<Word, Error = T1::Error>,
+{
+    fn transaction(
+        &mut self,
+        operations: &mut [spi::Operation<'_, Word>],
+    ) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transaction(operations),
+            Self::T2(t2) => t2.transaction(operations),
+            Self::T3(t3) => t3.transaction(operations),
+            Self::T4(t4) => t4.transaction(operations),
+            Self::T5(t5) => t5.transaction(operations),
+        }
+    }
+
+    fn read(&mut self, words: &mut [Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.read(words),
+            Self::T2(t2) => t2.read(words),
+            Self::T3(t3) => t3.read(words),
+            Self::T4(t4) => t4.read(words),
+            Self::T5(t5) => t5.read(words),
+        }
+    }
+
+    fn write(&mut self, words: &[Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.write(words),
+            Self::T2(t2) => t2.write(words),
+            Self::T3(t3) => t3.write(words),
+            Self::T4(t4) => t4.write(words),
+            Self::T5(t5) => t5.write(words),
+        }
+    }
+
+    fn transfer(&mut self, read: &mut [Word], write: &[Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transfer(read, write),
+            Self::T2(t2) => t2.transfer(read, write),
+            Self::T3(t3) => t3.transfer(read, write),
+            Self::T4(t4) => t4.transfer(read, write),
+            Self::T5(t5) => t5.transfer(read, write),
+        }
+    }
+
+    fn transfer_in_place(&mut self, words: &mut [Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transfer_in_place(words),
+            Self::T2(t2) => t2.transfer_in_place(words),
+            Self::T3(t3) => t3.transfer_in_place(words),
+            Self::T4(t4) => t4.transfer_in_place(words),
+            Self::T5(t5) => t5.transfer_in_place(words),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5> serial::ErrorType for Or5<T1, T2, T3, T4, T5>
+where
+    T1: serial::ErrorType,
+    T2: serial::ErrorType<Error = T1::Error>,
+    T3: serial::ErrorType<Error = T1::Error>,
+    T4: serial::ErrorType<Error = T1::Error>,
+    T5: serial::ErrorType<Error = T1::Error>,
+{
+    type Error = T1::Error;
+}
+
+/// Forwards to the serial reader of the active variant.
+impl<Word: Copy, T1, T2, T3, T4, T5> serial::Read<Word> for Or5<T1, T2, T3, T4, T5>
+where
+    T1: serial::Read<Word>,
+    T2: serial::Read<Word, Error = T1::Error>,
+    T3: serial::Read<Word, Error = T1::Error>,
+    T4: serial::Read<Word, Error = T1::Error>,
+    T5: serial::Read<Word, Error = T1::Error>,
+{
+    fn read(&mut self) -> nb::Result<Word, Self::Error> {
+        match self {
+            Self::T1(t1) => t1.read(),
+            Self::T2(t2) => t2.read(),
+            Self::T3(t3) => t3.read(),
+            Self::T4(t4) => t4.read(),
+            Self::T5(t5) => t5.read(),
+        }
+    }
+}
+
+/// Forwards to the serial writer of the active variant.
+impl<Word: Copy, T1, T2, T3, T4, T5> serial::Write<Word> for Or5<T1, T2, T3, T4, T5>
+where
+    T1: serial::Write<Word>,
+    T2: serial::Write<Word, Error = T1::Error>,
+    T3: serial::Write<Word, Error = T1::Error>,
+    T4: serial::Write<Word, Error = T1::Error>,
+    T5: serial::Write<Word, Error = T1::Error>,
+{
+    fn write(&mut self, word: Word) -> nb::Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.write(word),
+            Self::T2(t2) => t2.write(word),
+            Self::T3(t3) => t3.write(word),
+            Self::T4(t4) => t4.write(word),
+            Self::T5(t5) => t5.write(word),
+        }
+    }
+
+    fn flush(&mut self) -> nb::Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.flush(),
+            Self::T2(t2) => t2.flush(),
+            Self::T3(t3) => t3.flush(),
+            Self::T4(t4) => t4.flush(),
+            Self::T5(t5) => t5.flush(),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6> i2c::ErrorType for Or6<T1, T2, T3, T4, T5, T6>
+where
+    T1: i2c::ErrorType,
+    T2: i2c::ErrorType<Error = T1::Error>,
+    T3: i2c::ErrorType<Error = T1::Error>,
+    T4: i2c::ErrorType<Error = T1::Error>,
+    T5: i2c::ErrorType<Error = T1::Error>,
+    T6: i2c::ErrorType<Error = T1::Error>,
+{
+    type Error = T1::Error;
+}
+
+/// Forwards to the I2C driver of the active variant.
+impl<A: AddressMode, T1, T2, T3, T4, T5, T6> I2c<A> for Or6<T1, T2, T3, T4, T5, T6>
+where
+    T1: I2c<A>,
+    T2: I2c<A, Error = T1::Error>,
+    T3: I2c<A, Error = T1::Error>,
+    T4: I2c<A, Error = T1::Error>,
+    T5: I2c<A, Error = T1::Error>,
+    T6: I2c<A, Error = T1::Error>,
+{
+    fn read(&mut self, address: A, read: &mut [u8]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.read(address, read),
+            Self::T2(t2) => t2.read(address, read),
+            Self::T3(t3) => t3.read(address, read),
+            Self::T4(t4) => t4.read(address, read),
+            Self::T5(t5) => t5.read(address, read),
+            Self::T6(t6) => t6.read(address, read),
+        }
+    }
+
+    fn write(&mut self, address: A, write: &[u8]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.write(address, write),
+            Self::T2(t2) => t2.write(address, write),
+            Self::T3(t3) => t3.write(address, write),
+            Self::T4(t4) => t4.write(address, write),
+            Self::T5(t5) => t5.write(address, write),
+            Self::T6(t6) => t6.write(address, write),
+        }
+    }
+
+    fn write_read(&mut self, address: A, write: &[u8], read: &mut [u8]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.write_read(address, write, read),
+            Self::T2(t2) => t2.write_read(address, write, read),
+            Self::T3(t3) => t3.write_read(address, write, read),
+            Self::T4(t4) => t4.write_read(address, write, read),
+            Self::T5(t5) => t5.write_read(address, write, read),
+            Self::T6(t6) => t6.write_read(address, write, read),
+        }
+    }
+
+    fn transaction(
+        &mut self,
+        address: A,
+        operations: &mut [i2c::Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transaction(address, operations),
+            Self::T2(t2) => t2.transaction(address, operations),
+            Self::T3(t3) => t3.transaction(address, operations),
+            Self::T4(t4) => t4.transaction(address, operations),
+            Self::T5(t5) => t5.transaction(address, operations),
+            Self::T6(t6) => t6.transaction(address, operations),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6> spi::ErrorType for Or6<T1, T2, T3, T4, T5, T6>
+where
+    T1: spi::ErrorType,
+    T2: spi::ErrorType<Error = T1::Error>,
+    T3: spi::ErrorType<Error = T1::Error>,
+    T4: spi::ErrorType<Error = T1::Error>,
+    T5: spi::ErrorType<Error = T1::Error>,
+    T6: spi::ErrorType<Error = T1::Error>,
+{
+    type Error = T1::Error;
+}
+
+/// Forwards to the SPI bus of the active variant.
+impl<Word: Copy + 'static, T1, T2, T3, T4, T5, T6> SpiBus<Word> for Or6<T1, T2, T3, T4, T5, T6>
+where
+    T1: SpiBus<Word>,
+    T2: SpiBus<Word, Error = T1::Error>,
+    T3: SpiBus<Word, Error = T1::Error>,
+    T4: SpiBus<Word, Error = T1::Error>,
+    T5: SpiBus<Word, Error = T1::Error>,
+    T6: SpiBus<Word, Error = T1::Error>,
+{
+    fn read(&mut self, words: &mut [Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.read(words),
+            Self::T2(t2) => t2.read(words),
+            Self::T3(t3) => t3.read(words),
+            Self::T4(t4) => t4.read(words),
+            Self::T5(t5) => t5.read(words),
+            Self::T6(t6) => t6.read(words),
+        }
+    }
+
+    fn write(&mut self, words: &[Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.write(words),
+            Self::T2(t2) => t2.write(words),
+            Self::T3(t3) => t3.write(words),
+            Self::T4(t4) => t4.write(words),
+            Self::T5(t5) => t5.write(words),
+            Self::T6(t6) => t6.write(words),
+        }
+    }
+
+    fn transfer(&mut self, read: &mut [Word], write: &[Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transfer(read, write),
+            Self::T2(t2) => t2.transfer(read, write),
+            Self::T3(t3) => t3.transfer(read, write),
+            Self::T4(t4) => t4.transfer(read, write),
+            Self::T5(t5) => t5.transfer(read, write),
+            Self::T6(t6) => t6.transfer(read, write),
+        }
+    }
+
+    fn transfer_in_place(&mut self, words: &mut [Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transfer_in_place(words),
+            Self::T2(t2) => t2.transfer_in_place(words),
+            Self::T3(t3) => t3.transfer_in_place(words),
+            Self::T4(t4) => t4.transfer_in_place(words),
+            Self::T5(t5) => t5.transfer_in_place(words),
+            Self::T6(t6) => t6.transfer_in_place(words),
+        }
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.flush(),
+            Self::T2(t2) => t2.flush(),
+            Self::T3(t3) => t3.flush(),
+            Self::T4(t4) => t4.flush(),
+            Self::T5(t5) => t5.flush(),
+            Self::T6(t6) => t6.flush(),
+        }
+    }
+}
+
+/// Forwards to the SPI device of the active variant.
+impl<Word: Copy + 'static, T1, T2, T3, T4, T5, T6> SpiDevice<Word> for Or6<T1, T2, T3, T4, T5, T6>
+where
+    T1: SpiDevice<Word>,
+    T2: SpiDevice<Word, Error = T1::Error>,
+    T3: SpiDevice<Word, Error = T1::Error>,
+    T4: SpiDevice<Word, Error = T1::Error>,
+    T5: SpiDevice<Word, Error = T1::Error>,
+    T6: SpiDevice<Word, Error = T1::Error>,
+{
+    fn transaction(
+        &mut self,
+        operations: &mut [spi::Operation<'_, Word>],
+    ) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transaction(operations),
+            Self::T2(t2) => t2.transaction(operations),
+            Self::T3(t3) => t3.transaction(operations),
+            Self::T4(t4) => t4.transaction(operations),
+            Self::T5(t5) => t5.transaction(operations),
+            Self::T6(t6) => t6.transaction(operations),
+        }
+    }
+
+    fn read(&mut self, words: &mut [Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.read(words),
+            Self::T2(t2) => t2.read(words),
+            Self::T3(t3) => t3.read(words),
+            Self::T4(t4) => t4.read(words),
+            Self::T5(t5) => t5.read(words),
+            Self::T6(t6) => t6.read(words),
+        }
+    }
+
+    fn write(&mut self, words: &[Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.write(words),
+            Self::T2(t2) => t2.write(words),
+            Self::T3(t3) => t3.write(words),
+            Self::T4(t4) => t4.write(words),
+            Self::T5(t5) => t5.write(words),
+            Self::T6(t6) => t6.write(words),
+        }
+    }
+
+    fn transfer(&mut self, read: &mut [Word], write: &[Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transfer(read, write),
+            Self::T2(t2) => t2.transfer(read, write),
+            Self::T3(t3) => t3.transfer(read, write),
+            Self::T4(t4) => t4.transfer(read, write),
+            Self::T5(t5) => t5.transfer(read, write),
+            Self::T6(t6) => t6.transfer(read, write),
+        }
+    }
+
+    fn transfer_in_place(&mut self, words: &mut [Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transfer_in_place(words),
+            Self::T2(t2) => t2.transfer_in_place(words),
+            Self::T3(t3) => t3.transfer_in_place(words),
+            Self::T4(t4) => t4.transfer_in_place(words),
+            Self::T5(t5) => t5.transfer_in_place(words),
+            Self::T6(t6) => t6.transfer_in_place(words),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6> serial::ErrorType for Or6<T1, T2, T3, T4, T5, T6>
+where
+    T1: serial::ErrorType,
+    T2: serial::ErrorType<Error = T1::Error>,
+    T3: serial::ErrorType<Error = T1::Error>,
+    T4: serial::ErrorType<Error = T1::Error>,
+    T5: serial::ErrorType<Error = T1::Error>,
+    T6: serial::ErrorType<Error = T1::Error>,
+{
+    type Error = T1::Error;
+}
+
+/// Forwards to the serial reader of the active variant.
+impl<Word: Copy, T1, T2, T3, T4, T5, T6> serial::Read<Word> for Or6<T1, T2, T3, T4, T5, T6>
+where
+    T1: serial::Read<Word>,
+    T2: serial::Read<Word, Error = T1::Error>,
+    T3: serial::Read<Word, Error = T1::Error>,
+    T4: serial::Read<Word, Error = T1::Error>,
+    T5: serial::Read<Word, Error = T1::Error>,
+    T6: serial::Read<Word, Error = T1::Error>,
+{
+    fn read(&mut self) -> nb::Result<Word, Self::Error> {
+        match self {
+            Self::T1(t1) => t1.read(),
+            Self::T2(t2) => t2.read(),
+            Self::T3(t3) => t3.read(),
+            Self::T4(t4) => t4.read(),
+            Self::T5(t5) => t5.read(),
+            Self::T6(t6) => t6.read(),
+        }
+    }
+}
+
+/// Forwards to the serial writer of the active variant.
+impl<Word: Copy, T1, T2, T3, T4, T5, T6> serial::Write<Word> for Or6<T1, T2, T3, T4, T5, T6>
+where
+    T1: serial::Write<Word>,
+    T2: serial::Write<Word, Error = T1::Error>,
+    T3: serial::Write<Word, Error = T1::Error>,
+    T4: serial::Write<Word, Error = T1::Error>,
+    T5: serial::Write<Word, Error = T1::Error>,
+    T6: serial::Write<Word, Error = T1::Error>,
+{
+    fn write(&mut self, word: Word) -> nb::Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.write(word),
+            Self::T2(t2) => t2.write(word),
+            Self::T3(t3) => t3.write(word),
+            Self::T4(t4) => t4.write(word),
+            Self::T5(t5) => t5.write(word),
+            Self::T6(t6) => t6.write(word),
+        }
+    }
+
+    fn flush(&mut self) -> nb::Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.flush(),
+            Self::T2(t2) => t2.flush(),
+            Self::T3(t3) => t3.flush(),
+            Self::T4(t4) => t4.flush(),
+            Self::T5(t5) => t5.flush(),
+            Self::T6(t6) => t6.flush(),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7> i2c::ErrorType for Or7<T1, T2, T3, T4, T5, T6, T7>
+where
+    T1: i2c::ErrorType,
+    T2: i2c::ErrorType<Error = T1::Error>,
+    T3: i2c::ErrorType<Error = T1::Error>,
+    T4: i2c::ErrorType<Error = T1::Error>,
+    T5: i2c::ErrorType<Error = T1::Error>,
+    T6: i2c::ErrorType<Error = T1::Error>,
+    T7: i2c::ErrorType<Error = T1::Error>,
+{
+    type Error = T1::Error;
+}
+
+/// Forwards to the I2C driver of the active variant.
+impl<A: AddressMode, T1, T2, T3, T4, T5, T6, T7> I2c<A> for Or7<T1, T2, T3, T4, T5, T6, T7>
+where
+    T1: I2c<A>,
+    T2: I2c<A, Error = T1::Error>,
+    T3: I2c<A, Error = T1::Error>,
+    T4: I2c<A, Error = T1::Error>,
+    T5: I2c<A, Error = T1::Error>,
+    T6: I2c<A, Error = T1::Error>,
+    T7: I2c<A, Error = T1::Error>,
+{
+    fn read(&mut self, address: A, read: &mut [u8]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.read(address, read),
+            Self::T2(t2) => t2.read(address, read),
+            Self::T3(t3) => t3.read(address, read),
+            Self::T4(t4) => t4.read(address, read),
+            Self::T5(t5) => t5.read(address, read),
+            Self::T6(t6) => t6.read(address, read),
+            Self::T7(t7) => t7.read(address, read),
+        }
+    }
+
+    fn write(&mut self, address: A, write: &[u8]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.write(address, write),
+            Self::T2(t2) => t2.write(address, write),
+            Self::T3(t3) => t3.write(address, write),
+            Self::T4(t4) => t4.write(address, write),
+            Self::T5(t5) => t5.write(address, write),
+            Self::T6(t6) => t6.write(address, write),
+            Self::T7(t7) => t7.write(address, write),
+        }
+    }
+
+    fn write_read(&mut self, address: A, write: &[u8], read: &mut [u8]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.write_read(address, write, read),
+            Self::T2(t2) => t2.write_read(address, write, read),
+            Self::T3(t3) => t3.write_read(address, write, read),
+            Self::T4(t4) => t4.write_read(address, write, read),
+            Self::T5(t5) => t5.write_read(address, write, read),
+            Self::T6(t6) => t6.write_read(address, write, read),
+            Self::T7(t7) => t7.write_read(address, write, read),
+        }
+    }
+
+    fn transaction(
+        &mut self,
+        address: A,
+        operations: &mut [i2c::Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transaction(address, operations),
+            Self::T2(t2) => t2.transaction(address, operations),
+            Self::T3(t3) => t3.transaction(address, operations),
+            Self::T4(t4) => t4.transaction(address, operations),
+            Self::T5(t5) => t5.transaction(address, operations),
+            Self::T6(t6) => t6.transaction(address, operations),
+            Self::T7(t7) => t7.transaction(address, operations),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7> spi::ErrorType for Or7<T1, T2, T3, T4, T5, T6, T7>
+where
+    T1: spi::ErrorType,
+    T2: spi::ErrorType<Error = T1::Error>,
+    T3: spi::ErrorType<Error = T1::Error>,
+    T4: spi::ErrorType<Error = T1::Error>,
+    T5: spi::ErrorType<Error = T1::Error>,
+    T6: spi::ErrorType<Error = T1::Error>,
+    T7: spi::ErrorType<Error = T1::Error>,
+{
+    type Error = T1::Error;
+}
+
+/// Forwards to the SPI bus of the active variant.
+impl<Word: Copy + 'static, T1, T2, T3, T4, T5, T6, T7> SpiBus<Word>
+    for Or7<T1, T2, T3, T4, T5, T6, T7>
+where
+    T1: SpiBus<Word>,
+    T2: SpiBus<Word, Error = T1::Error>,
+    T3: SpiBus<Word, Error = T1::Error>,
+    T4: SpiBus<Word, Error = T1::Error>,
+    T5: SpiBus<Word, Error = T1::Error>,
+    T6: SpiBus<Word, Error = T1::Error>,
+    T7: SpiBus<Word, Error = T1::Error>,
+{
+    fn read(&mut self, words: &mut [Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.read(words),
+            Self::T2(t2) => t2.read(words),
+            Self::T3(t3) => t3.read(words),
+            Self::T4(t4) => t4.read(words),
+            Self::T5(t5) => t5.read(words),
+            Self::T6(t6) => t6.read(words),
+            Self::T7(t7) => t7.read(words),
+        }
+    }
+
+    fn write(&mut self, words: &[Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.write(words),
+            Self::T2(t2) => t2.write(words),
+            Self::T3(t3) => t3.write(words),
+            Self::T4(t4) => t4.write(words),
+            Self::T5(t5) => t5.write(words),
+            Self::T6(t6) => t6.write(words),
+            Self::T7(t7) => t7.write(words),
+        }
+    }
+
+    fn transfer(&mut self, read: &mut [Word], write: &[Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transfer(read, write),
+            Self::T2(t2) => t2.transfer(read, write),
+            Self::T3(t3) => t3.transfer(read, write),
+            Self::T4(t4) => t4.transfer(read, write),
+            Self::T5(t5) => t5.transfer(read, write),
+            Self::T6(t6) => t6.transfer(read, write),
+            Self::T7(t7) => t7.transfer(read, write),
+        }
+    }
+
+    fn transfer_in_place(&mut self, words: &mut [Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transfer_in_place(words),
+            Self::T2(t2) => t2.transfer_in_place(words),
+            Self::T3(t3) => t3.transfer_in_place(words),
+            Self::T4(t4) => t4.transfer_in_place(words),
+            Self::T5(t5) => t5.transfer_in_place(words),
+            Self::T6(t6) => t6.transfer_in_place(words),
+            Self::T7(t7) => t7.transfer_in_place(words),
+        }
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.flush(),
+            Self::T2(t2) => t2.flush(),
+            Self::T3(t3) => t3.flush(),
+            Self::T4(t4) => t4.flush(),
+            Self::T5(t5) => t5.flush(),
+            Self::T6(t6) => t6.flush(),
+            Self::T7(t7) => t7.flush(),
+        }
+    }
+}
+
+/// Forwards to the SPI device of the active variant.
+impl<Word: Copy + 'static, T1, T2, T3, T4, T5, T6, T7> SpiDevice<Word>
+    for Or7<T1, T2, T3, T4, T5, T6, T7>
+where
+    T1: SpiDevice<Word>,
+    T2: SpiDevice<Word, Error = T1::Error>,
+    T3: SpiDevice<Word, Error = T1::Error>,
+    T4: SpiDevice<Word, Error = T1::Error>,
+    T5: SpiDevice<Word, Error = T1::Error>,
+    T6: SpiDevice<Word, Error = T1::Error>,
+    T7: SpiDevice<Word, Error = T1::Error>,
+{
+    fn transaction(
+        &mut self,
+        operations: &mut [spi::Operation<'_, Word>],
+    ) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transaction(operations),
+            Self::T2(t2) => t2.transaction(operations),
+            Self::T3(t3) => t3.transaction(operations),
+            Self::T4(t4) => t4.transaction(operations),
+            Self::T5(t5) => t5.transaction(operations),
+            Self::T6(t6) => t6.transaction(operations),
+            Self::T7(t7) => t7.transaction(operations),
+        }
+    }
+
+    fn read(&mut self, words: &mut [Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.read(words),
+            Self::T2(t2) => t2.read(words),
+            Self::T3(t3) => t3.read(words),
+            Self::T4(t4) => t4.read(words),
+            Self::T5(t5) => t5.read(words),
+            Self::T6(t6) => t6.read(words),
+            Self::T7(t7) => t7.read(words),
+        }
+    }
+
+    fn write(&mut self, words: &[Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.write(words),
+            Self::T2(t2) => t2.write(words),
+            Self::T3(t3) => t3.write(words),
+            Self::T4(t4) => t4.write(words),
+            Self::T5(t5) => t5.write(words),
+            Self::T6(t6) => t6.write(words),
+            Self::T7(t7) => t7.write(words),
+        }
+    }
+
+    fn transfer(&mut self, read: &mut [Word], write: &[Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transfer(read, write),
+            Self::T2(t2) => t2.transfer(read, write),
+            Self::T3(t3) => t3.transfer(read, write),
+            Self::T4(t4) => t4.transfer(read, write),
+            Self::T5(t5) => t5.transfer(read, write),
+            Self::T6(t6) => t6.transfer(read, write),
+            Self::T7(t7) => t7.transfer(read, write),
+        }
+    }
+
+    fn transfer_in_place(&mut self, words: &mut [Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transfer_in_place(words),
+            Self::T2(t2) => t2.transfer_in_place(words),
+            Self::T3(t3) => t3.transfer_in_place(words),
+            Self::T4(t4) => t4.transfer_in_place(words),
+            Self::T5(t5) => t5.transfer_in_place(words),
+            Self::T6(t6) => t6.transfer_in_place(words),
+            Self::T7(t7) => t7.transfer_in_place(words),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7> serial::ErrorType for Or7<T1, T2, T3, T4, T5, T6, T7>
+where
+    T1: serial::ErrorType,
+    T2: serial::ErrorType<Error = T1::Error>,
+    T3: serial::ErrorType<Error = T1::Error>,
+    T4: serial::ErrorType<Error = T1::Error>,
+    T5: serial::ErrorType<Error = T1::Error>,
+    T6: serial::ErrorType<Error = T1::Error>,
+    T7: serial::ErrorType<Error = T1::Error>,
+{
+    type Error = T1::Error;
+}
+
+/// Forwards to the serial reader of the active variant.
+impl<Word: Copy, T1, T2, T3, T4, T5, T6, T7> serial::Read<Word> for Or7<T1, T2, T3, T4, T5, T6, T7>
+where
+    T1: serial::Read<Word>,
+    T2: serial::Read<Word, Error = T1::Error>,
+    T3: serial::Read<Word, Error = T1::Error>,
+    T4: serial::Read<Word, Error = T1::Error>,
+    T5: serial::Read<Word, Error = T1::Error>,
+    T6: serial::Read<Word, Error = T1::Error>,
+    T7: serial::Read<Word, Error = T1::Error>,
+{
+    fn read(&mut self) -> nb::Result<Word, Self::Error> {
+        match self {
+            Self::T1(t1) => t1.read(),
+            Self::T2(t2) => t2.read(),
+            Self::T3(t3) => t3.read(),
+            Self::T4(t4) => t4.read(),
+            Self::T5(t5) => t5.read(),
+            Self::T6(t6) => t6.read(),
+            Self::T7(t7) => t7.read(),
+        }
+    }
+}
+
+/// Forwards to the serial writer of the active variant.
+impl<Word: Copy, T1, T2, T3, T4, T5, T6, T7> serial::Write<Word> for Or7<T1, T2, T3, T4, T5, T6, T7>
+where
+    T1: serial::Write<Word>,
+    T2: serial::Write<Word, Error = T1::Error>,
+    T3: serial::Write<Word, Error = T1::Error>,
+    T4: serial::Write<Word, Error = T1::Error>,
+    T5: serial::Write<Word, Error = T1::Error>,
+    T6: serial::Write<Word, Error = T1::Error>,
+    T7: serial::Write<Word, Error = T1::Error>,
+{
+    fn write(&mut self, word: Word) -> nb::Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.write(word),
+            Self::T2(t2) => t2.write(word),
+            Self::T3(t3) => t3.write(word),
+            Self::T4(t4) => t4.write(word),
+            Self::T5(t5) => t5.write(word),
+            Self::T6(t6) => t6.write(word),
+            Self::T7(t7) => t7.write(word),
+        }
+    }
+
+    fn flush(&mut self) -> nb::Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.flush(),
+            Self::T2(t2) => t2.flush(),
+            Self::T3(t3) => t3.flush(),
+            Self::T4(t4) => t4.flush(),
+            Self::T5(t5) => t5.flush(),
+            Self::T6(t6) => t6.flush(),
+            Self::T7(t7) => t7.flush(),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7, T8> i2c::ErrorType for Or8<T1, T2, T3, T4, T5, T6, T7, T8>
+where
+    T1: i2c::ErrorType,
+    T2: i2c::ErrorType<Error = T1::Error>,
+    T3: i2c::ErrorType<Error = T1::Error>,
+    T4: i2c::ErrorType<Error = T1::Error>,
+    T5: i2c::ErrorType<Error = T1::Error>,
+    T6: i2c::ErrorType<Error = T1::Error>,
+    T7: i2c::ErrorType<Error = T1::Error>,
+    T8: i2c::ErrorType<Error = T1::Error>,
+{
+    type Error = T1::Error;
+}
+
+/// Forwards to the I2C driver of the active variant.
+impl<A: AddressMode, T1, T2, T3, T4, T5, T6, T7, T8> I2c<A> for Or8<T1, T2, T3, T4, T5, T6, T7, T8>
+where
+    T1: I2c<A>,
+    T2: I2c<A, Error = T1::Error>,
+    T3: I2c<A, Error = T1::Error>,
+    T4: I2c<A, Error = T1::Error>,
+    T5: I2c<A, Error = T1::Error>,
+    T6: I2c<A, Error = T1::Error>,
+    T7: I2c<A, Error = T1::Error>,
+    T8: I2c<A, Error = T1::Error>,
+{
+    fn read(&mut self, address: A, read: &mut [u8]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.read(address, read),
+            Self::T2(t2) => t2.read(address, read),
+            Self::T3(t3) => t3.read(address, read),
+            Self::T4(t4) => t4.read(address, read),
+            Self::T5(t5) => t5.read(address, read),
+            Self::T6(t6) => t6.read(address, read),
+            Self::T7(t7) => t7.read(address, read),
+            Self::T8(t8) => t8.read(address, read),
+        }
+    }
+
+    fn write(&mut self, address: A, write: &[u8]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.write(address, write),
+            Self::T2(t2) => t2.write(address, write),
+            Self::T3(t3) => t3.write(address, write),
+            Self::T4(t4) => t4.write(address, write),
+            Self::T5(t5) => t5.write(address, write),
+            Self::T6(t6) => t6.write(address, write),
+            Self::T7(t7) => t7.write(address, write),
+            Self::T8(t8) => t8.write(address, write),
+        }
+    }
+
+    fn write_read(&mut self, address: A, write: &[u8], read: &mut [u8]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.write_read(address, write, read),
+            Self::T2(t2) => t2.write_read(address, write, read),
+            Self::T3(t3) => t3.write_read(address, write, read),
+            Self::T4(t4) => t4.write_read(address, write, read),
+            Self::T5(t5) => t5.write_read(address, write, read),
+            Self::T6(t6) => t6.write_read(address, write, read),
+            Self::T7(t7) => t7.write_read(address, write, read),
+            Self::T8(t8) => t8.write_read(address, write, read),
+        }
+    }
+
+    fn transaction(
+        &mut self,
+        address: A,
+        operations: &mut [i2c::Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transaction(address, operations),
+            Self::T2(t2) => t2.transaction(address, operations),
+            Self::T3(t3) => t3.transaction(address, operations),
+            Self::T4(t4) => t4.transaction(address, operations),
+            Self::T5(t5) => t5.transaction(address, operations),
+            Self::T6(t6) => t6.transaction(address, operations),
+            Self::T7(t7) => t7.transaction(address, operations),
+            Self::T8(t8) => t8.transaction(address, operations),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7, T8> spi::ErrorType for Or8<T1, T2, T3, T4, T5, T6, T7, T8>
+where
+    T1: spi::ErrorType,
+    T2: spi::ErrorType<Error = T1::Error>,
+    T3: spi::ErrorType<Error = T1::Error>,
+    T4: spi::ErrorType<Error = T1::Error>,
+    T5: spi::ErrorType<Error = T1::Error>,
+    T6: spi::ErrorType<Error = T1::Error>,
+    T7: spi::ErrorType<Error = T1::Error>,
+    T8: spi::ErrorType<Error = T1::Error>,
+{
+    type Error = T1::Error;
+}
+
+/// Forwards to the SPI bus of the active variant.
+impl<Word: Copy + 'static, T1, T2, T3, T4, T5, T6, T7, T8> SpiBus<Word>
+    for Or8<T1, T2, T3, T4, T5, T6, T7, T8>
+where
+    T1: SpiBus<Word>,
+    T2: SpiBus<Word, Error = T1::Error>,
+    T3: SpiBus<Word, Error = T1::Error>,
+    T4: SpiBus<Word, Error = T1::Error>,
+    T5: SpiBus<Word, Error = T1::Error>,
+    T6: SpiBus<Word, Error = T1::Error>,
+    T7: SpiBus<Word, Error = T1::Error>,
+    T8: SpiBus<Word, Error = T1::Error>,
+{
+    fn read(&mut self, words: &mut [Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.read(words),
+            Self::T2(t2) => t2.read(words),
+            Self::T3(t3) => t3.read(words),
+            Self::T4(t4) => t4.read(words),
+            Self::T5(t5) => t5.read(words),
+            Self::T6(t6) => t6.read(words),
+            Self::T7(t7) => t7.read(words),
+            Self::T8(t8) => t8.read(words),
+        }
+    }
+
+    fn write(&mut self, words: &[Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.write(words),
+            Self::T2(t2) => t2.write(words),
+            Self::T3(t3) => t3.write(words),
+            Self::T4(t4) => t4.write(words),
+            Self::T5(t5) => t5.write(words),
+            Self::T6(t6) => t6.write(words),
+            Self::T7(t7) => t7.write(words),
+            Self::T8(t8) => t8.write(words),
+        }
+    }
+
+    fn transfer(&mut self, read: &mut [Word], write: &[Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transfer(read, write),
+            Self::T2(t2) => t2.transfer(read, write),
+            Self::T3(t3) => t3.transfer(read, write),
+            Self::T4(t4) => t4.transfer(read, write),
+            Self::T5(t5) => t5.transfer(read, write),
+            Self::T6(t6) => t6.transfer(read, write),
+            Self::T7(t7) => t7.transfer(read, write),
+            Self::T8(t8) => t8.transfer(read, write),
+        }
+    }
+
+    fn transfer_in_place(&mut self, words: &mut [Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transfer_in_place(words),
+            Self::T2(t2) => t2.transfer_in_place(words),
+            Self::T3(t3) => t3.transfer_in_place(words),
+            Self::T4(t4) => t4.transfer_in_place(words),
+            Self::T5(t5) => t5.transfer_in_place(words),
+            Self::T6(t6) => t6.transfer_in_place(words),
+            Self::T7(t7) => t7.transfer_in_place(words),
+            Self::T8(t8) => t8.transfer_in_place(words),
+        }
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.flush(),
+            Self::T2(t2) => t2.flush(),
+            Self::T3(t3) => t3.flush(),
+            Self::T4(t4) => t4.flush(),
+            Self::T5(t5) => t5.flush(),
+            Self::T6(t6) => t6.flush(),
+            Self::T7(t7) => t7.flush(),
+            Self::T8(t8) => t8.flush(),
+        }
+    }
+}
+
+/// Forwards to the SPI device of the active variant.
+impl<Word: Copy + 'static, T1, T2, T3, T4, T5, T6, T7, T8> SpiDevice<Word>
+    for Or8<T1, T2, T3, T4, T5, T6, T7, T8>
+where
+    T1: SpiDevice<Word>,
+    T2: SpiDevice<Word, Error = T1::Error>,
+    T3: SpiDevice<Word, Error = T1::Error>,
+    T4: SpiDevice<Word, Error = T1::Error>,
+    T5: SpiDevice<Word, Error = T1::Error>,
+    T6: SpiDevice<Word, Error = T1::Error>,
+    T7: SpiDevice<Word, Error = T1::Error>,
+    T8: SpiDevice<Word, Error = T1::Error>,
+{
+    fn transaction(
+        &mut self,
+        operations: &mut [spi::Operation<'_, Word>],
+    ) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transaction(operations),
+            Self::T2(t2) => t2.transaction(operations),
+            Self::T3(t3) => t3.transaction(operations),
+            Self::T4(t4) => t4.transaction(operations),
+            Self::T5(t5) => t5.transaction(operations),
+            Self::T6(t6) => t6.transaction(operations),
+            Self::T7(t7) => t7.transaction(operations),
+            Self::T8(t8) => t8.transaction(operations),
+        }
+    }
+
+    fn read(&mut self, words: &mut [Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.read(words),
+            Self::T2(t2) => t2.read(words),
+            Self::T3(t3) => t3.read(words),
+            Self::T4(t4) => t4.read(words),
+            Self::T5(t5) => t5.read(words),
+            Self::T6(t6) => t6.read(words),
+            Self::T7(t7) => t7.read(words),
+            Self::T8(t8) => t8.read(words),
+        }
+    }
+
+    fn write(&mut self, words: &[Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.write(words),
+            Self::T2(t2) => t2.write(words),
+            Self::T3(t3) => t3.write(words),
+            Self::T4(t4) => t4.write(words),
+            Self::T5(t5) => t5.write(words),
+            Self::T6(t6) => t6.write(words),
+            Self::T7(t7) => t7.write(words),
+            Self::T8(t8) => t8.write(words),
+        }
+    }
+
+    fn transfer(&mut self, read: &mut [Word], write: &[Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transfer(read, write),
+            Self::T2(t2) => t2.transfer(read, write),
+            Self::T3(t3) => t3.transfer(read, write),
+            Self::T4(t4) => t4.transfer(read, write),
+            Self::T5(t5) => t5.transfer(read, write),
+            Self::T6(t6) => t6.transfer(read, write),
+            Self::T7(t7) => t7.transfer(read, write),
+            Self::T8(t8) => t8.transfer(read, write),
+        }
+    }
+
+    fn transfer_in_place(&mut self, words: &mut [Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transfer_in_place(words),
+            Self::T2(t2) => t2.transfer_in_place(words),
+            Self::T3(t3) => t3.transfer_in_place(words),
+            Self::T4(t4) => t4.transfer_in_place(words),
+            Self::T5(t5) => t5.transfer_in_place(words),
+            Self::T6(t6) => t6.transfer_in_place(words),
+            Self::T7(t7) => t7.transfer_in_place(words),
+            Self::T8(t8) => t8.transfer_in_place(words),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7, T8> serial::ErrorType for Or8<T1, T2, T3, T4, T5, T6, T7, T8>
+where
+    T1: serial::ErrorType,
+    T2: serial::ErrorType<Error = T1::Error>,
+    T3: serial::ErrorType<Error = T1::Error>,
+    T4: serial::ErrorType<Error = T1::Error>,
+    T5: serial::ErrorType<Error = T1::Error>,
+    T6: serial::ErrorType<Error = T1::Error>,
+    T7: serial::ErrorType<Error = T1::Error>,
+    T8: serial::ErrorType<Error = T1::Error>,
+{
+    type Error = T1::Error;
+}
+
+/// Forwards to the serial reader of the active variant.
+impl<Word: Copy, T1, T2, T3, T4, T5, T6, T7, T8> serial::Read<Word>
+    for Or8<T1, T2, T3, T4, T5, T6, T7, T8>
+where
+    T1: serial::Read<Word>,
+    T2: serial::Read<Word, Error = T1::Error>,
+    T3: serial::Read<Word, Error = T1::Error>,
+    T4: serial::Read<Word, Error = T1::Error>,
+    T5: serial::Read<Word, Error = T1::Error>,
+    T6: serial::Read<Word, Error = T1::Error>,
+    T7: serial::Read<Word, Error = T1::Error>,
+    T8: serial::Read<Word, Error = T1::Error>,
+{
+    fn read(&mut self) -> nb::Result<Word, Self::Error> {
+        match self {
+            Self::T1(t1) => t1.read(),
+            Self::T2(t2) => t2.read(),
+            Self::T3(t3) => t3.read(),
+            Self::T4(t4) => t4.read(),
+            Self::T5(t5) => t5.read(),
+            Self::T6(t6) => t6.read(),
+            Self::T7(t7) => t7.read(),
+            Self::T8(t8) => t8.read(),
+        }
+    }
+}
+
+/// Forwards to the serial writer of the active variant.
+impl<Word: Copy, T1, T2, T3, T4, T5, T6, T7, T8> serial::Write<Word>
+    for Or8<T1, T2, T3, T4, T5, T6, T7, T8>
+where
+    T1: serial::Write<Word>,
+    T2: serial::Write<Word, Error = T1::Error>,
+    T3: serial::Write<Word, Error = T1::Error>,
+    T4: serial::Write<Word, Error = T1::Error>,
+    T5: serial::Write<Word, Error = T1::Error>,
+    T6: serial::Write<Word, Error = T1::Error>,
+    T7: serial::Write<Word, Error = T1::Error>,
+    T8: serial::Write<Word, Error = T1::Error>,
+{
+    fn write(&mut self, word: Word) -> nb::Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.write(word),
+            Self::T2(t2) => t2.write(word),
+            Self::T3(t3) => t3.write(word),
+            Self::T4(t4) => t4.write(word),
+            Self::T5(t5) => t5.write(word),
+            Self::T6(t6) => t6.write(word),
+            Self::T7(t7) => t7.write(word),
+            Self::T8(t8) => t8.write(word),
+        }
+    }
+
+    fn flush(&mut self) -> nb::Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.flush(),
+            Self::T2(t2) => t2.flush(),
+            Self::T3(t3) => t3.flush(),
+            Self::T4(t4) => t4.flush(),
+            Self::T5(t5) => t5.flush(),
+            Self::T6(t6) => t6.flush(),
+            Self::T7(t7) => t7.flush(),
+            Self::T8(t8) => t8.flush(),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7, T8, T9> i2c::ErrorType for Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>
+where
+    T1: i2c::ErrorType,
+    T2: i2c::ErrorType<Error = T1::Error>,
+    T3: i2c::ErrorType<Error = T1::Error>,
+    T4: i2c::ErrorType<Error = T1::Error>,
+    T5: i2c::ErrorType<Error = T1::Error>,
+    T6: i2c::ErrorType<Error = T1::Error>,
+    T7: i2c::ErrorType<Error = T1::Error>,
+    T8: i2c::ErrorType<Error = T1::Error>,
+    T9: i2c::ErrorType<Error = T1::Error>,
+{
+    type Error = T1::Error;
+}
+
+/// Forwards to the I2C driver of the active variant.
+impl<A: AddressMode, T1, T2, T3, T4, T5, T6, T7, T8, T9> I2c<A>
+    for Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>
+where
+    T1: I2c<A>,
+    T2: I2c<A, Error = T1::Error>,
+    T3: I2c<A, Error = T1::Error>,
+    T4: I2c<A, Error = T1::Error>,
+    T5: I2c<A, Error = T1::Error>,
+    T6: I2c<A, Error = T1::Error>,
+    T7: I2c<A, Error = T1::Error>,
+    T8: I2c<A, Error = T1::Error>,
+    T9: I2c<A, Error = T1::Error>,
+{
+    fn read(&mut self, address: A, read: &mut [u8]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.read(address, read),
+            Self::T2(t2) => t2.read(address, read),
+            Self::T3(t3) => t3.read(address, read),
+            Self::T4(t4) => t4.read(address, read),
+            Self::T5(t5) => t5.read(address, read),
+            Self::T6(t6) => t6.read(address, read),
+            Self::T7(t7) => t7.read(address, read),
+            Self::T8(t8) => t8.read(address, read),
+            Self::T9(t9) => t9.read(address, read),
+        }
+    }
+
+    fn write(&mut self, address: A, write: &[u8]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.write(address, write),
+            Self::T2(t2) => t2.write(address, write),
+            Self::T3(t3) => t3.write(address, write),
+            Self::T4(t4) => t4.write(address, write),
+            Self::T5(t5) => t5.write(address, write),
+            Self::T6(t6) => t6.write(address, write),
+            Self::T7(t7) => t7.write(address, write),
+            Self::T8(t8) => t8.write(address, write),
+            Self::T9(t9) => t9.write(address, write),
+        }
+    }
+
+    fn write_read(&mut self, address: A, write: &[u8], read: &mut [u8]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.write_read(address, write, read),
+            Self::T2(t2) => t2.write_read(address, write, read),
+            Self::T3(t3) => t3.write_read(address, write, read),
+            Self::T4(t4) => t4.write_read(address, write, read),
+            Self::T5(t5) => t5.write_read(address, write, read),
+            Self::T6(t6) => t6.write_read(address, write, read),
+            Self::T7(t7) => t7.write_read(address, write, read),
+            Self::T8(t8) => t8.write_read(address, write, read),
+            Self::T9(t9) => t9.write_read(address, write, read),
+        }
+    }
+
+    fn transaction(
+        &mut self,
+        address: A,
+        operations: &mut [i2c::Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transaction(address, operations),
+            Self::T2(t2) => t2.transaction(address, operations),
+            Self::T3(t3) => t3.transaction(address, operations),
+            Self::T4(t4) => t4.transaction(address, operations),
+            Self::T5(t5) => t5.transaction(address, operations),
+            Self::T6(t6) => t6.transaction(address, operations),
+            Self::T7(t7) => t7.transaction(address, operations),
+            Self::T8(t8) => t8.transaction(address, operations),
+            Self::T9(t9) => t9.transaction(address, operations),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7, T8, T9> spi::ErrorType for Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>
+where
+    T1: spi::ErrorType,
+    T2: spi::ErrorType<Error = T1::Error>,
+    T3: spi::ErrorType<Error = T1::Error>,
+    T4: spi::ErrorType<Error = T1::Error>,
+    T5: spi::ErrorType<Error = T1::Error>,
+    T6: spi::ErrorType<Error = T1::Error>,
+    T7: spi::ErrorType<Error = T1::Error>,
+    T8: spi::ErrorType<Error = T1::Error>,
+    T9: spi::ErrorType<Error = T1::Error>,
+{
+    type Error = T1::Error;
+}
+
+/// Forwards to the SPI bus of the active variant.
+impl<Word: Copy + 'static, T1, T2, T3, T4, T5, T6, T7, T8, T9> SpiBus<Word>
+    for Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>
+where
+    T1: SpiBus<Word>,
+    T2: SpiBus<Word, Error = T1::Error>,
+    T3: SpiBus<Word, Error = T1::Error>,
+    T4: SpiBus<Word, Error = T1::Error>,
+    T5: SpiBus<Word, Error = T1::Error>,
+    T6: SpiBus<Word, Error = T1::Error>,
+    T7: SpiBus<Word, Error = T1::Error>,
+    T8: SpiBus<Word, Error = T1::Error>,
+    T9: SpiBus<Word, Error = T1::Error>,
+{
+    fn read(&mut self, words: &mut [Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.read(words),
+            Self::T2(t2) => t2.read(words),
+            Self::T3(t3) => t3.read(words),
+            Self::T4(t4) => t4.read(words),
+            Self::T5(t5) => t5.read(words),
+            Self::T6(t6) => t6.read(words),
+            Self::T7(t7) => t7.read(words),
+            Self::T8(t8) => t8.read(words),
+            Self::T9(t9) => t9.read(words),
+        }
+    }
+
+    fn write(&mut self, words: &[Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.write(words),
+            Self::T2(t2) => t2.write(words),
+            Self::T3(t3) => t3.write(words),
+            Self::T4(t4) => t4.write(words),
+            Self::T5(t5) => t5.write(words),
+            Self::T6(t6) => t6.write(words),
+            Self::T7(t7) => t7.write(words),
+            Self::T8(t8) => t8.write(words),
+            Self::T9(t9) => t9.write(words),
+        }
+    }
+
+    fn transfer(&mut self, read: &mut [Word], write: &[Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transfer(read, write),
+            Self::T2(t2) => t2.transfer(read, write),
+            Self::T3(t3) => t3.transfer(read, write),
+            Self::T4(t4) => t4.transfer(read, write),
+            Self::T5(t5) => t5.transfer(read, write),
+            Self::T6(t6) => t6.transfer(read, write),
+            Self::T7(t7) => t7.transfer(read, write),
+            Self::T8(t8) => t8.transfer(read, write),
+            Self::T9(t9) => t9.transfer(read, write),
+        }
+    }
+
+    fn transfer_in_place(&mut self, words: &mut [Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transfer_in_place(words),
+            Self::T2(t2) => t2.transfer_in_place(words),
+            Self::T3(t3) => t3.transfer_in_place(words),
+            Self::T4(t4) => t4.transfer_in_place(words),
+            Self::T5(t5) => t5.transfer_in_place(words),
+            Self::T6(t6) => t6.transfer_in_place(words),
+            Self::T7(t7) => t7.transfer_in_place(words),
+            Self::T8(t8) => t8.transfer_in_place(words),
+            Self::T9(t9) => t9.transfer_in_place(words),
+        }
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.flush(),
+            Self::T2(t2) => t2.flush(),
+            Self::T3(t3) => t3.flush(),
+            Self::T4(t4) => t4.flush(),
+            Self::T5(t5) => t5.flush(),
+            Self::T6(t6) => t6.flush(),
+            Self::T7(t7) => t7.flush(),
+            Self::T8(t8) => t8.flush(),
+            Self::T9(t9) => t9.flush(),
+        }
+    }
+}
+
+/// Forwards to the SPI device of the active variant.
+impl<Word: Copy + 'static, T1, T2, T3, T4, T5, T6, T7, T8, T9> SpiDevice<Word>
+    for Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>
+where
+    T1: SpiDevice<Word>,
+    T2: SpiDevice<Word, Error = T1::Error>,
+    T3: SpiDevice<Word, Error = T1::Error>,
+    T4: SpiDevice<Word, Error = T1::Error>,
+    T5: SpiDevice<Word, Error = T1::Error>,
+    T6: SpiDevice<Word, Error = T1::Error>,
+    T7: SpiDevice<Word, Error = T1::Error>,
+    T8: SpiDevice<Word, Error = T1::Error>,
+    T9: SpiDevice<Word, Error = T1::Error>,
+{
+    fn transaction(
+        &mut self,
+        operations: &mut [spi::Operation<'_, Word>],
+    ) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transaction(operations),
+            Self::T2(t2) => t2.transaction(operations),
+            Self::T3(t3) => t3.transaction(operations),
+            Self::T4(t4) => t4.transaction(operations),
+            Self::T5(t5) => t5.transaction(operations),
+            Self::T6(t6) => t6.transaction(operations),
+            Self::T7(t7) => t7.transaction(operations),
+            Self::T8(t8) => t8.transaction(operations),
+            Self::T9(t9) => t9.transaction(operations),
+        }
+    }
+
+    fn read(&mut self, words: &mut [Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.read(words),
+            Self::T2(t2) => t2.read(words),
+            Self::T3(t3) => t3.read(words),
+            Self::T4(t4) => t4.read(words),
+            Self::T5(t5) => t5.read(words),
+            Self::T6(t6) => t6.read(words),
+            Self::T7(t7) => t7.read(words),
+            Self::T8(t8) => t8.read(words),
+            Self::T9(t9) => t9.read(words),
+        }
+    }
+
+    fn write(&mut self, words: &[Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.write(words),
+            Self::T2(t2) => t2.write(words),
+            Self::T3(t3) => t3.write(words),
+            Self::T4(t4) => t4.write(words),
+            Self::T5(t5) => t5.write(words),
+            Self::T6(t6) => t6.write(words),
+            Self::T7(t7) => t7.write(words),
+            Self::T8(t8) => t8.write(words),
+            Self::T9(t9) => t9.write(words),
+        }
+    }
+
+    fn transfer(&mut self, read: &mut [Word], write: &[Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transfer(read, write),
+            Self::T2(t2) => t2.transfer(read, write),
+            Self::T3(t3) => t3.transfer(read, write),
+            Self::T4(t4) => t4.transfer(read, write),
+            Self::T5(t5) => t5.transfer(read, write),
+            Self::T6(t6) => t6.transfer(read, write),
+            Self::T7(t7) => t7.transfer(read, write),
+            Self::T8(t8) => t8.transfer(read, write),
+            Self::T9(t9) => t9.transfer(read, write),
+        }
+    }
+
+    fn transfer_in_place(&mut self, words: &mut [Word]) -> Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.transfer_in_place(words),
+            Self::T2(t2) => t2.transfer_in_place(words),
+            Self::T3(t3) => t3.transfer_in_place(words),
+            Self::T4(t4) => t4.transfer_in_place(words),
+            Self::T5(t5) => t5.transfer_in_place(words),
+            Self::T6(t6) => t6.transfer_in_place(words),
+            Self::T7(t7) => t7.transfer_in_place(words),
+            Self::T8(t8) => t8.transfer_in_place(words),
+            Self::T9(t9) => t9.transfer_in_place(words),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7, T8, T9> serial::ErrorType
+    for Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>
+where
+    T1: serial::ErrorType,
+    T2: serial::ErrorType<Error = T1::Error>,
+    T3: serial::ErrorType<Error = T1::Error>,
+    T4: serial::ErrorType<Error = T1::Error>,
+    T5: serial::ErrorType<Error = T1::Error>,
+    T6: serial::ErrorType<Error = T1::Error>,
+    T7: serial::ErrorType<Error = T1::Error>,
+    T8: serial::ErrorType<Error = T1::Error>,
+    T9: serial::ErrorType<Error = T1::Error>,
+{
+    type Error = T1::Error;
+}
+
+/// Forwards to the serial reader of the active variant.
+impl<Word: Copy, T1, T2, T3, T4, T5, T6, T7, T8, T9> serial::Read<Word>
+    for Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>
+where
+    T1: serial::Read<Word>,
+    T2: serial::Read<Word, Error = T1::Error>,
+    T3: serial::Read<Word, Error = T1::Error>,
+    T4: serial::Read<Word, Error = T1::Error>,
+    T5: serial::Read<Word, Error = T1::Error>,
+    T6: serial::Read<Word, Error = T1::Error>,
+    T7: serial::Read<Word, Error = T1::Error>,
+    T8: serial::Read<Word, Error = T1::Error>,
+    T9: serial::Read<Word, Error = T1::Error>,
+{
+    fn read(&mut self) -> nb::Result<Word, Self::Error> {
+        match self {
+            Self::T1(t1) => t1.read(),
+            Self::T2(t2) => t2.read(),
+            Self::T3(t3) => t3.read(),
+            Self::T4(t4) => t4.read(),
+            Self::T5(t5) => t5.read(),
+            Self::T6(t6) => t6.read(),
+            Self::T7(t7) => t7.read(),
+            Self::T8(t8) => t8.read(),
+            Self::T9(t9) => t9.read(),
+        }
+    }
+}
+
+/// Forwards to the serial writer of the active variant.
+impl<Word: Copy, T1, T2, T3, T4, T5, T6, T7, T8, T9> serial::Write<Word>
+    for Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>
+where
+    T1: serial::Write<Word>,
+    T2: serial::Write<Word, Error = T1::Error>,
+    T3: serial::Write<Word, Error = T1::Error>,
+    T4: serial::Write<Word, Error = T1::Error>,
+    T5: serial::Write<Word, Error = T1::Error>,
+    T6: serial::Write<Word, Error = T1::Error>,
+    T7: serial::Write<Word, Error = T1::Error>,
+    T8: serial::Write<Word, Error = T1::Error>,
+    T9: serial::Write<Word, Error = T1::Error>,
+{
+    fn write(&mut self, word: Word) -> nb::Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.write(word),
+            Self::T2(t2) => t2.write(word),
+            Self::T3(t3) => t3.write(word),
+            Self::T4(t4) => t4.write(word),
+            Self::T5(t5) => t5.write(word),
+            Self::T6(t6) => t6.write(word),
+            Self::T7(t7) => t7.write(word),
+            Self::T8(t8) => t8.write(word),
+            Self::T9(t9) => t9.write(word),
+        }
+    }
+
+    fn flush(&mut self) -> nb::Result<(), Self::Error> {
+        match self {
+            Self::T1(t1) => t1.flush(),
+            Self::T2(t2) => t2.flush(),
+            Self::T3(t3) => t3.flush(),
+            Self::T4(t4) => t4.flush(),
+            Self::T5(t5) => t5.flush(),
+            Self::T6(t6) => t6.flush(),
+            Self::T7(t7) => t7.flush(),
+            Self::T8(t8) => t8.flush(),
+            Self::T9(t9) => t9.flush(),
+        }
+    }
+}
@@ -0,0 +1,549 @@
+//! `frunk` conversions of the `Or` types, enabled by the `frunk` feature.
+//!
+//! `Or3<T1, T2, T3>` converts into `Coprod!(T1, T2, T3)` and back, keeping the position of
+//! the value.
+
+use frunk_core::coproduct::{CNil, Coproduct};
+
+use crate::enums::*;
+
+impl<T1, T2> Or2<T1, T2> {
+    /// Converts the enum into the `Coproduct` of its types, which is `Coprod!(T1, T2)`.
+    #[allow(clippy::type_complexity)]
+    pub fn into_coproduct(self) -> Coproduct<T1, Coproduct<T2, CNil>> {
+        match self {
+            Self::T1(t1) => Coproduct::Inl(t1),
+            Self::T2(t2) => Coproduct::Inr(Coproduct::Inl(t2)),
+        }
+    }
+}
+
+/// Converts the value of a `Coproduct` into the variant at the same position.
+impl<T1, T2> From<Coproduct<T1, Coproduct<T2, CNil>>> for Or2<T1, T2> {
+    fn from(coproduct: Coproduct<T1, Coproduct<T2, CNil>>) -> Self {
+        match coproduct {
+            Coproduct::Inl(t1) => Self::T1(t1),
+            Coproduct::Inr(Coproduct::Inl(t2)) => Self::T2(t2),
+            Coproduct::Inr(Coproduct::Inr(cnil)) => match cnil {},
+        }
+    }
+}
+
+/// Converts the value of the active variant into the `Coproduct` at the same position.
+impl<T1, T2> From<Or2<T1, T2>> for Coproduct<T1, Coproduct<T2, CNil>> {
+    fn from(or: Or2<T1, T2>) -> Self {
+        or.into_coproduct()
+    }
+}
+
+impl<T1, T2, T3> Or3<T1, T2, T3> {
+    /// Converts the enum into the `Coproduct` of its types, which is `Coprod!(T1, T2, T3)`.
+    #[allow(clippy::type_complexity)]
+    pub fn into_coproduct(self) -> Coproduct<T1, Coproduct<T2, Coproduct<T3, CNil>>> {
+        match self {
+            Self::T1(t1) => Coproduct::Inl(t1),
+            Self::T2(t2) => Coproduct::Inr(Coproduct::Inl(t2)),
+            Self::T3(t3) => Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(t3))),
+        }
+    }
+}
+
+/// Converts the value of a `Coproduct` into the variant at the same position.
+impl<T1, T2, T3> From<Coproduct<T1, Coproduct<T2, Coproduct<T3, CNil>>>> for Or3<T1, T2, T3> {
+    fn from(coproduct: Coproduct<T1, Coproduct<T2, Coproduct<T3, CNil>>>) -> Self {
+        match coproduct {
+            Coproduct::Inl(t1) => Self::T1(t1),
+            Coproduct::Inr(Coproduct::Inl(t2)) => Self::T2(t2),
+            Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(t3))) => Self::T3(t3),
+            Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(cnil))) => match cnil {},
+        }
+    }
+}
+
+/// Converts the value of the active variant into the `Coproduct` at the same position.
+impl<T1, T2, T3> From<Or3<T1, T2, T3>> for Coproduct<T1, Coproduct<T2, Coproduct<T3, CNil>>> {
+    fn from(or: Or3<T1, T2, T3>) -> Self {
+        or.into_coproduct()
+    }
+}
+
+impl<T1, T2, T3, T4> Or4<T1, T2, T3, T4> {
+    /// Converts the enum into the `Coproduct` of its types, which is `Coprod!(T1, T2, T3, T4)`.
+    #[allow(clippy::type_complexity)]
+    pub fn into_coproduct(
+        self,
+    ) -> Coproduct<T1, Coproduct<T2, Coproduct<T3, Coproduct<T4, CNil>>>> {
+        match self {
+            Self::T1(t1) => Coproduct::Inl(t1),
+            Self::T2(t2) => Coproduct::Inr(Coproduct::Inl(t2)),
+            Self::T3(t3) => Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(t3))),
+            Self::T4(t4) => Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(t4)))),
+        }
+    }
+}
+
+/// Converts the value of a `Coproduct` into the variant at the same position.
+impl<T1, T2, T3, T4> From<Coproduct<T1, Coproduct<T2, Coproduct<T3, Coproduct<T4, CNil>>>>>
+    for Or4<T1, T2, T3, T4>
+{
+    fn from(coproduct: Coproduct<T1, Coproduct<T2, Coproduct<T3, Coproduct<T4, CNil>>>>) -> Self {
+        match coproduct {
+            Coproduct::Inl(t1) => Self::T1(t1),
+            Coproduct::Inr(Coproduct::Inl(t2)) => Self::T2(t2),
+            Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(t3))) => Self::T3(t3),
+            Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(t4)))) => Self::T4(t4),
+            Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(cnil)))) => match cnil {},
+        }
+    }
+}
+
+/// Converts the value of the active variant into the `Coproduct` at the same position.
+impl<T1, T2, T3, T4> From<Or4<T1, T2, T3, T4>>
+    for Coproduct<T1, Coproduct<T2, Coproduct<T3, Coproduct<T4, CNil>>>>
+{
+    fn from(or: Or4<T1, T2, T3, T4>) -> Self {
+        or.into_coproduct()
+    }
+}
+
+impl<T1, T2, T3, T4, T5> Or5<T1, T2, T3, T4, T5> {
+    /// Converts the enum into the `Coproduct` of its types, which is `Coprod!(T1, T2, T3, T4, T5)`.
+    #[allow(clippy::type_complexity)]
+    pub fn into_coproduct(
+        self,
+    ) -> Coproduct<T1, Coproduct<T2, Coproduct<T3, Coproduct<T4, Coproduct<T5, CNil>>>>> {
+        match self {
+            Self::T1(t1) => Coproduct::Inl(t1),
+            Self::T2(t2) => Coproduct::Inr(Coproduct::Inl(t2)),
+            Self::T3(t3) => Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(t3))),
+            Self::T4(t4) => Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(t4)))),
+            Self::T5(t5) => Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(
+                Coproduct::Inl(t5),
+            )))),
+        }
+    }
+}
+
+/// Converts the value of a `Coproduct` into the variant at the same position.
+impl<T1, T2, T3, T4, T5>
+    From<Coproduct<T1, Coproduct<T2, Coproduct<T3, Coproduct<T4, Coproduct<T5, CNil>>>>>>
+    for Or5<T1, T2, T3, T4, T5>
+{
+    fn from(
+        coproduct: Coproduct<T1, Coproduct<T2, Coproduct<T3, Coproduct<T4, Coproduct<T5, CNil>>>>>,
+    ) -> Self {
+        match coproduct {
+            Coproduct::Inl(t1) => Self::T1(t1),
+            Coproduct::Inr(Coproduct::Inl(t2)) => Self::T2(t2),
+            Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(t3))) => Self::T3(t3),
+            Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(t4)))) => Self::T4(t4),
+            Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(t5))))) => {
+                Self::T5(t5)
+            }
+            Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(
+                cnil,
+            ))))) => match cnil {},
+        }
+    }
+}
+
+/// Converts the value of the active variant into the `Coproduct` at the same position.
+impl<T1, T2, T3, T4, T5> From<Or5<T1, T2, T3, T4, T5>>
+    for Coproduct<T1, Coproduct<T2, Coproduct<T3, Coproduct<T4, Coproduct<T5, CNil>>>>>
+{
+    fn from(or: Or5<T1, T2, T3, T4, T5>) -> Self {
+        or.into_coproduct()
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6> Or6<T1, T2, T3, T4, T5, T6> {
+    /// Converts the enum into the `Coproduct` of its types, which is `Coprod!(T1, T2, T3, T4, T5, T6)`.
+    #[allow(clippy::type_complexity)]
+    pub fn into_coproduct(
+        self,
+    ) -> Coproduct<
+        T1,
+        Coproduct<T2, Coproduct<T3, Coproduct<T4, Coproduct<T5, Coproduct<T6, CNil>>>>>,
+    > {
+        match self {
+            Self::T1(t1) => Coproduct::Inl(t1),
+            Self::T2(t2) => Coproduct::Inr(Coproduct::Inl(t2)),
+            Self::T3(t3) => Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(t3))),
+            Self::T4(t4) => Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(t4)))),
+            Self::T5(t5) => Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(
+                Coproduct::Inl(t5),
+            )))),
+            Self::T6(t6) => Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(
+                Coproduct::Inr(Coproduct::Inl(t6)),
+            )))),
+        }
+    }
+}
+
+/// Converts the value of a `Coproduct` into the variant at the same position.
+impl<T1, T2, T3, T4, T5, T6>
+    From<
+        Coproduct<
+            T1,
+            Coproduct<T2, Coproduct<T3, Coproduct<T4, Coproduct<T5, Coproduct<T6, CNil>>>>>,
+        >,
+    > for Or6<T1, T2, T3, T4, T5, T6>
+{
+    fn from(
+        coproduct: Coproduct<
+            T1,
+            Coproduct<T2, Coproduct<T3, Coproduct<T4, Coproduct<T5, Coproduct<T6, CNil>>>>>,
+        >,
+    ) -> Self {
+        match coproduct {
+            Coproduct::Inl(t1) => Self::T1(t1),
+            Coproduct::Inr(Coproduct::Inl(t2)) => Self::T2(t2),
+            Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(t3))) => Self::T3(t3),
+            Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(t4)))) => Self::T4(t4),
+            Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(t5))))) => {
+                Self::T5(t5)
+            }
+            Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(
+                Coproduct::Inl(t6),
+            ))))) => Self::T6(t6),
+            Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(
+                Coproduct::Inr(cnil),
+            ))))) => match cnil {},
+        }
+    }
+}
+
+/// Converts the value of the active variant into the `Coproduct` at the same position.
+impl<T1, T2, T3, T4, T5, T6> From<Or6<T1, T2, T3, T4, T5, T6>>
+    for Coproduct<
+        T1,
+        Coproduct<T2, Coproduct<T3, Coproduct<T4, Coproduct<T5, Coproduct<T6, CNil>>>>>,
+    >
+{
+    fn from(or: Or6<T1, T2, T3, T4, T5, T6>) -> Self {
+        or.into_coproduct()
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7> Or7<T1, T2, T3, T4, T5, T6, T7> {
+    /// Converts the enum into the `Coproduct` of its types, which is `Coprod!(T1, T2, T3, T4, T5, T6, T7)`.
+    #[allow(clippy::type_complexity)]
+    pub fn into_coproduct(
+        self,
+    ) -> Coproduct<
+        T1,
+        Coproduct<
+            T2,
+            Coproduct<T3, Coproduct<T4, Coproduct<T5, Coproduct<T6, Coproduct<T7, CNil>>>>>,
+        >,
+    > {
+        match self {
+            Self::T1(t1) => Coproduct::Inl(t1),
+            Self::T2(t2) => Coproduct::Inr(Coproduct::Inl(t2)),
+            Self::T3(t3) => Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(t3))),
+            Self::T4(t4) => Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(t4)))),
+            Self::T5(t5) => Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(
+                Coproduct::Inl(t5),
+            )))),
+            Self::T6(t6) => Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(
+                Coproduct::Inr(Coproduct::Inl(t6)),
+            )))),
+            Self::T7(t7) => Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(
+                Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(t7))),
+            )))),
+        }
+    }
+}
+
+/// Converts the value of a `Coproduct` into the variant at the same position.
+impl<T1, T2, T3, T4, T5, T6, T7>
+    From<
+        Coproduct<
+            T1,
+            Coproduct<
+                T2,
+                Coproduct<T3, Coproduct<T4, Coproduct<T5, Coproduct<T6, Coproduct<T7, CNil>>>>>,
+            >,
+        >,
+    > for Or7<T1, T2, T3, T4, T5, T6, T7>
+{
+    fn from(
+        coproduct: Coproduct<
+            T1,
+            Coproduct<
+                T2,
+                Coproduct<T3, Coproduct<T4, Coproduct<T5, Coproduct<T6, Coproduct<T7, CNil>>>>>,
+            >,
+        >,
+    ) -> Self {
+        match coproduct {
+            Coproduct::Inl(t1) => Self::T1(t1),
+            Coproduct::Inr(Coproduct::Inl(t2)) => Self::T2(t2),
+            Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(t3))) => Self::T3(t3),
+            Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(t4)))) => Self::T4(t4),
+            Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(t5))))) => {
+                Self::T5(t5)
+            }
+            Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(
+                Coproduct::Inl(t6),
+            ))))) => Self::T6(t6),
+            Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(
+                Coproduct::Inr(Coproduct::Inl(t7)),
+            ))))) => Self::T7(t7),
+            Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(
+                Coproduct::Inr(Coproduct::Inr(cnil)),
+            ))))) => match cnil {},
+        }
+    }
+}
+
+/// Converts the value of the active variant into the `Coproduct` at the same position.
+impl<T1, T2, T3, T4, T5, T6, T7> From<Or7<T1, T2, T3, T4, T5, T6, T7>>
+    for Coproduct<
+        T1,
+        Coproduct<
+            T2,
+            Coproduct<T3, Coproduct<T4, Coproduct<T5, Coproduct<T6, Coproduct<T7, CNil>>>>>,
+        >,
+    >
+{
+    fn from(or: Or7<T1, T2, T3, T4, T5, T6, T7>) -> Self {
+        or.into_coproduct()
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7, T8> Or8<T1, T2, T3, T4, T5, T6, T7, T8> {
+    /// Converts the enum into the `Coproduct` of its types, which is `Coprod!(T1, T2, T3, T4, T5, T6, T7, T8)`.
+    #[allow(clippy::type_complexity)]
+    pub fn into_coproduct(
+        self,
+    ) -> Coproduct<
+        T1,
+        Coproduct<
+            T2,
+            Coproduct<
+                T3,
+                Coproduct<T4, Coproduct<T5, Coproduct<T6, Coproduct<T7, Coproduct<T8, CNil>>>>>,
+            >,
+        >,
+    > {
+        match self {
+            Self::T1(t1) => Coproduct::Inl(t1),
+            Self::T2(t2) => Coproduct::Inr(Coproduct::Inl(t2)),
+            Self::T3(t3) => Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(t3))),
+            Self::T4(t4) => Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(t4)))),
+            Self::T5(t5) => Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(
+                Coproduct::Inl(t5),
+            )))),
+            Self::T6(t6) => Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(
+                Coproduct::Inr(Coproduct::Inl(t6)),
+            )))),
+            Self::T7(t7) => Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(
+                Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(t7))),
+            )))),
+            Self::T8(t8) => Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(
+                Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(t8)))),
+            )))),
+        }
+    }
+}
+
+/// Converts the value of a `Coproduct` into the variant at the same position.
+impl<T1, T2, T3, T4, T5, T6, T7, T8>
+    From<
+        Coproduct<
+            T1,
+            Coproduct<
+                T2,
+                Coproduct<
+                    T3,
+                    Coproduct<T4, Coproduct<T5, Coproduct<T6, Coproduct<T7, Coproduct<T8, CNil>>>>>,
+                >,
+            >,
+        >,
+    > for Or8<T1, T2, T3, T4, T5, T6, T7, T8>
+{
+    fn from(
+        coproduct: Coproduct<
+            T1,
+            Coproduct<
+                T2,
+                Coproduct<
+                    T3,
+                    Coproduct<T4, Coproduct<T5, Coproduct<T6, Coproduct<T7, Coproduct<T8, CNil>>>>>,
+                >,
+            >,
+        >,
+    ) -> Self {
+        match coproduct {
+            Coproduct::Inl(t1) => Self::T1(t1),
+            Coproduct::Inr(Coproduct::Inl(t2)) => Self::T2(t2),
+            Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(t3))) => Self::T3(t3),
+            Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(t4)))) => Self::T4(t4),
+            Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(t5))))) => {
+                Self::T5(t5)
+            }
+            Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(
+                Coproduct::Inl(t6),
+            ))))) => Self::T6(t6),
+            Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(
+                Coproduct::Inr(Coproduct::Inl(t7)),
+            ))))) => Self::T7(t7),
+            Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(
+                Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(t8))),
+            ))))) => Self::T8(t8),
+            Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(
+                Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(cnil))),
+            ))))) => match cnil {},
+        }
+    }
+}
+
+/// Converts the value of the active variant into the `Coproduct` at the same position.
+impl<T1, T2, T3, T4, T5, T6, T7, T8> From<Or8<T1, T2, T3, T4, T5, T6, T7, T8>>
+    for Coproduct<
+        T1,
+        Coproduct<
+            T2,
+            Coproduct<
+                T3,
+                Coproduct<T4, Coproduct<T5, Coproduct<T6, Coproduct<T7, Coproduct<T8, CNil>>>>>,
+            >,
+        >,
+    >
+{
+    fn from(or: Or8<T1, T2, T3, T4, T5, T6, T7, T8>) -> Self {
+        or.into_coproduct()
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7, T8, T9> Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9> {
+    /// Converts the enum into the `Coproduct` of its types, which is `Coprod!(T1, T2, T3, T4, T5, T6, T7, T8, T9)`.
+    #[allow(clippy::type_complexity)]
+    pub fn into_coproduct(
+        self,
+    ) -> Coproduct<
+        T1,
+        Coproduct<
+            T2,
+            Coproduct<
+                T3,
+                Coproduct<
+                    T4,
+                    Coproduct<T5, Coproduct<T6, Coproduct<T7, Coproduct<T8, Coproduct<T9, CNil>>>>>,
+                >,
+            >,
+        >,
+    > {
+        match self {
+            Self::T1(t1) => Coproduct::Inl(t1),
+            Self::T2(t2) => Coproduct::Inr(Coproduct::Inl(t2)),
+            Self::T3(t3) => Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(t3))),
+            Self::T4(t4) => Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(t4)))),
+            Self::T5(t5) => Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(
+                Coproduct::Inl(t5),
+            )))),
+            Self::T6(t6) => Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(
+                Coproduct::Inr(Coproduct::Inl(t6)),
+            )))),
+            Self::T7(t7) => Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(
+                Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(t7))),
+            )))),
+            Self::T8(t8) => Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(
+                Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(t8)))),
+            )))),
+            Self::T9(t9) => Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(
+                Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(
+                    Coproduct::Inl(t9),
+                )))),
+            )))),
+        }
+    }
+}
+
+/// Converts the value of a `Coproduct` into the variant at the same position.
+impl<T1, T2, T3, T4, T5, T6, T7, T8, T9>
+    From<
+        Coproduct<
+            T1,
+            Coproduct<
+                T2,
+                Coproduct<
+                    T3,
+                    Coproduct<
+                        T4,
+                        Coproduct<
+                            T5,
+                            Coproduct<T6, Coproduct<T7, Coproduct<T8, Coproduct<T9, CNil>>>>,
+                        >,
+                    >,
+                >,
+            >,
+        >,
+    > for Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>
+{
+    fn from(
+        coproduct: Coproduct<
+            T1,
+            Coproduct<
+                T2,
+                Coproduct<
+                    T3,
+                    Coproduct<
+                        T4,
+                        Coproduct<
+                            T5,
+                            Coproduct<T6, Coproduct<T7, Coproduct<T8, Coproduct<T9, CNil>>>>,
+                        >,
+                    >,
+                >,
+            >,
+        >,
+    ) -> Self {
+        match coproduct {
+            Coproduct::Inl(t1) => Self::T1(t1),
+            Coproduct::Inr(Coproduct::Inl(t2)) => Self::T2(t2),
+            Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(t3))) => Self::T3(t3),
+            Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(t4)))) => Self::T4(t4),
+            Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(t5))))) => {
+                Self::T5(t5)
+            }
+            Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(
+                Coproduct::Inl(t6),
+            ))))) => Self::T6(t6),
+            Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(
+                Coproduct::Inr(Coproduct::Inl(t7)),
+            ))))) => Self::T7(t7),
+            Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(
+                Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(t8))),
+            ))))) => Self::T8(t8),
+            Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(
+                Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inl(t9)))),
+            ))))) => Self::T9(t9),
+            Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(
+                Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(Coproduct::Inr(cnil)))),
+            ))))) => match cnil {},
+        }
+    }
+}
+
+/// Converts the value of the active variant into the `Coproduct` at the same position.
+impl<T1, T2, T3, T4, T5, T6, T7, T8, T9> From<Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>>
+    for Coproduct<
+        T1,
+        Coproduct<
+            T2,
+            Coproduct<
+                T3,
+                Coproduct<
+                    T4,
+                    Coproduct<T5, Coproduct<T6, Coproduct<T7, Coproduct<T8, Coproduct<T9, CNil>>>>>,
+                >,
+            >,
+        >,
+    >
+{
+    fn from(or: Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>) -> Self {
+        or.into_coproduct()
+    }
+}
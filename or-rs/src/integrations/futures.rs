@@ -0,0 +1,444 @@
+//! `futures` streams of the `Or` types, enabled by the `futures` feature.
+
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::stream::{FusedStream, Stream};
+
+use crate::enums::*;
+
+/// Polls the stream of the active variant, so the branches returning different streams of the
+/// same item can be consumed as a single stream.
+impl<T1, T2> Stream for Or2<T1, T2>
+where
+    T1: Stream,
+    T2: Stream<Item = T1::Item>,
+{
+    type Item = T1::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match self.as_pin_mut() {
+            Or2::T1(t1) => t1.poll_next(cx),
+            Or2::T2(t2) => t2.poll_next(cx),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Self::T1(t1) => t1.size_hint(),
+            Self::T2(t2) => t2.size_hint(),
+        }
+    }
+}
+
+impl<T1, T2> FusedStream for Or2<T1, T2>
+where
+    T1: FusedStream,
+    T2: FusedStream<Item = T1::Item>,
+{
+    fn is_terminated(&self) -> bool {
+        match self {
+            Self::T1(t1) => t1.is_terminated(),
+            Self::T2(t2) => t2.is_terminated(),
+        }
+    }
+}
+
+/// Polls the stream of the active variant, so the branches returning different streams of the
+/// same item can be consumed as a single stream.
+impl<T1, T2, T3> Stream for Or3<T1, T2, T3>
+where
+    T1: Stream,
+    T2: Stream<Item = T1::Item>,
+    T3: Stream<Item = T1::Item>,
+{
+    type Item = T1::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match self.as_pin_mut() {
+            Or3::T1(t1) => t1.poll_next(cx),
+            Or3::T2(t2) => t2.poll_next(cx),
+            Or3::T3(t3) => t3.poll_next(cx),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Self::T1(t1) => t1.size_hint(),
+            Self::T2(t2) => t2.size_hint(),
+            Self::T3(t3) => t3.size_hint(),
+        }
+    }
+}
+
+impl<T1, T2, T3> FusedStream for Or3<T1, T2, T3>
+where
+    T1: FusedStream,
+    T2: FusedStream<Item = T1::Item>,
+    T3: FusedStream<Item = T1::Item>,
+{
+    fn is_terminated(&self) -> bool {
+        match self {
+            Self::T1(t1) => t1.is_terminated(),
+            Self::T2(t2) => t2.is_terminated(),
+            Self::T3(t3) => t3.is_terminated(),
+        }
+    }
+}
+
+/// Polls the stream of the active variant, so the branches returning different streams of the
+/// same item can be consumed as a single stream.
+impl<T1, T2, T3, T4> Stream for Or4<T1, T2, T3, T4>
+where
+    T1: Stream,
+    T2: Stream<Item = T1::Item>,
+    T3: Stream<Item = T1::Item>,
+    T4: Stream<Item = T1::Item>,
+{
+    type Item = T1::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match self.as_pin_mut() {
+            Or4::T1(t1) => t1.poll_next(cx),
+            Or4::T2(t2) => t2.poll_next(cx),
+            Or4::T3(t3) => t3.poll_next(cx),
+            Or4::T4(t4) => t4.poll_next(cx),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Self::T1(t1) => t1.size_hint(),
+            Self::T2(t2) => t2.size_hint(),
+            Self::T3(t3) => t3.size_hint(),
+            Self::T4(t4) => t4.size_hint(),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4> FusedStream for Or4<T1, T2, T3, T4>
+where
+    T1: FusedStream,
+    T2: FusedStream<Item = T1::Item>,
+    T3: FusedStream<Item = T1::Item>,
+    T4: FusedStream<Item = T1::Item>,
+{
+    fn is_terminated(&self) -> bool {
+        match self {
+            Self::T1(t1) => t1.is_terminated(),
+            Self::T2(t2) => t2.is_terminated(),
+            Self::T3(t3) => t3.is_terminated(),
+            Self::T4(t4) => t4.is_terminated(),
+        }
+    }
+}
+
+/// Polls the stream of the active variant, so the branches returning different streams of the
+/// same item can be consumed as a single stream.
+impl<T1, T2, T3, T4, T5> Stream for Or5<T1, T2, T3, T4, T5>
+where
+    T1: Stream,
+    T2: Stream<Item = T1::Item>,
+    T3: Stream<Item = T1::Item>,
+    T4: Stream<Item = T1::Item>,
+    T5: Stream<Item = T1::Item>,
+{
+    type Item = T1::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match self.as_pin_mut() {
+            Or5::T1(t1) => t1.poll_next(cx),
+            Or5::T2(t2) => t2.poll_next(cx),
+            Or5::T3(t3) => t3.poll_next(cx),
+            Or5::T4(t4) => t4.poll_next(cx),
+            Or5::T5(t5) => t5.poll_next(cx),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Self::T1(t1) => t1.size_hint(),
+            Self::T2(t2) => t2.size_hint(),
+            Self::T3(t3) => t3.size_hint(),
+            Self::T4(t4) => t4.size_hint(),
+            Self::T5(t5) => t5.size_hint(),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5> FusedStream for Or5<T1, T2, T3, T4, T5>
+where
+    T1: FusedStream,
+    T2: FusedStream<Item = T1::Item>,
+    T3: FusedStream<Item = T1::Item>,
+    T4: FusedStream<Item = T1::Item>,
+    T5: FusedStream<Item = T1::Item>,
+{
+    fn is_terminated(&self) -> bool {
+        match self {
+            Self::T1(t1) => t1.is_terminated(),
+            Self::T2(t2) => t2.is_terminated(),
+            Self::T3(t3) => t3.is_terminated(),
+            Self::T4(t4) => t4.is_terminated(),
+            Self::T5(t5) => t5.is_terminated(),
+        }
+    }
+}
+
+/// Polls the stream of the active variant, so the branches returning different streams of the
+/// same item can be consumed as a single stream.
+impl<T1, T2, T3, T4, T5, T6> Stream for Or6<T1, T2, T3, T4, T5, T6>
+where
+    T1: Stream,
+    T2: Stream<Item = T1::Item>,
+    T3: Stream<Item = T1::Item>,
+    T4: Stream<Item = T1::Item>,
+    T5: Stream<Item = T1::Item>,
+    T6: Stream<Item = T1::Item>,
+{
+    type Item = T1::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match self.as_pin_mut() {
+            Or6::T1(t1) => t1.poll_next(cx),
+            Or6::T2(t2) => t2.poll_next(cx),
+            Or6::T3(t3) => t3.poll_next(cx),
+            Or6::T4(t4) => t4.poll_next(cx),
+            Or6::T5(t5) => t5.poll_next(cx),
+            Or6::T6(t6) => t6.poll_next(cx),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Self::T1(t1) => t1.size_hint(),
+            Self::T2(t2) => t2.size_hint(),
+            Self::T3(t3) => t3.size_hint(),
+            Self::T4(t4) => t4.size_hint(),
+            Self::T5(t5) => t5.size_hint(),
+            Self::T6(t6) => t6.size_hint(),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6> FusedStream for Or6<T1, T2, T3, T4, T5, T6>
+where
+    T1: FusedStream,
+    T2: FusedStream<Item = T1::Item>,
+    T3: FusedStream<Item = T1::Item>,
+    T4: FusedStream<Item = T1::Item>,
+    T5: FusedStream<Item = T1::Item>,
+    T6: FusedStream<Item = T1::Item>,
+{
+    fn is_terminated(&self) -> bool {
+        match self {
+            Self::T1(t1) => t1.is_terminated(),
+            Self::T2(t2) => t2.is_terminated(),
+            Self::T3(t3) => t3.is_terminated(),
+            Self::T4(t4) => t4.is_terminated(),
+            Self::T5(t5) => t5.is_terminated(),
+            Self::T6(t6) => t6.is_terminated(),
+        }
+    }
+}
+
+/// Polls the stream of the active variant, so the branches returning different streams of the
+/// same item can be consumed as a single stream.
+impl<T1, T2, T3, T4, T5, T6, T7> Stream for Or7<T1, T2, T3, T4, T5, T6, T7>
+where
+    T1: Stream,
+    T2: Stream<Item = T1::Item>,
+    T3: Stream<Item = T1::Item>,
+    T4: Stream<Item = T1::Item>,
+    T5: Stream<Item = T1::Item>,
+    T6: Stream<Item = T1::Item>,
+    T7: Stream<Item = T1::Item>,
+{
+    type Item = T1::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match self.as_pin_mut() {
+            Or7::T1(t1) => t1.poll_next(cx),
+            Or7::T2(t2) => t2.poll_next(cx),
+            Or7::T3(t3) => t3.poll_next(cx),
+            Or7::T4(t4) => t4.poll_next(cx),
+            Or7::T5(t5) => t5.poll_next(cx),
+            Or7::T6(t6) => t6.poll_next(cx),
+            Or7::T7(t7) => t7.poll_next(cx),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Self::T1(t1) => t1.size_hint(),
+            Self::T2(t2) => t2.size_hint(),
+            Self::T3(t3) => t3.size_hint(),
+            Self::T4(t4) => t4.size_hint(),
+            Self::T5(t5) => t5.size_hint(),
+            Self::T6(t6) => t6.size_hint(),
+            Self::T7(t7) => t7.size_hint(),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7> FusedStream for Or7<T1, T2, T3, T4, T5, T6, T7>
+where
+    T1: FusedStream,
+    T2: FusedStream<Item = T1::Item>,
+    T3: FusedStream<Item = T1::Item>,
+    T4: FusedStream<Item = T1::Item>,
+    T5: FusedStream<Item = T1::Item>,
+    T6: FusedStream<Item = T1::Item>,
+    T7: FusedStream<Item = T1::Item>,
+{
+    fn is_terminated(&self) -> bool {
+        match self {
+            Self::T1(t1) => t1.is_terminated(),
+            Self::T2(t2) => t2.is_terminated(),
+            Self::T3(t3) => t3.is_terminated(),
+            Self::T4(t4) => t4.is_terminated(),
+            Self::T5(t5) => t5.is_terminated(),
+            Self::T6(t6) => t6.is_terminated(),
+            Self::T7(t7) => t7.is_terminated(),
+        }
+    }
+}
+
+/// Polls the stream of the active variant, so the branches returning different streams of the
+/// same item can be consumed as a single stream.
+impl<T1, T2, T3, T4, T5, T6, T7, T8> Stream for Or8<T1, T2, T3, T4, T5, T6, T7, T8>
+where
+    T1: Stream,
+    T2: Stream<Item = T1::Item>,
+    T3: Stream<Item = T1::Item>,
+    T4: Stream<Item = T1::Item>,
+    T5: Stream<Item = T1::Item>,
+    T6: Stream<Item = T1::Item>,
+    T7: Stream<Item = T1::Item>,
+    T8: Stream<Item = T1::Item>,
+{
+    type Item = T1::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match self.as_pin_mut() {
+            Or8::T1(t1) => t1.poll_next(cx),
+            Or8::T2(t2) => t2.poll_next(cx),
+            Or8::T3(t3) => t3.poll_next(cx),
+            Or8::T4(t4) => t4.poll_next(cx),
+            Or8::T5(t5) => t5.poll_next(cx),
+            Or8::T6(t6) => t6.poll_next(cx),
+            Or8::T7(t7) => t7.poll_next(cx),
+            Or8::T8(t8) => t8.poll_next(cx),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Self::T1(t1) => t1.size_hint(),
+            Self::T2(t2) => t2.size_hint(),
+            Self::T3(t3) => t3.size_hint(),
+            Self::T4(t4) => t4.size_hint(),
+            Self::T5(t5) => t5.size_hint(),
+            Self::T6(t6) => t6.size_hint(),
+            Self::T7(t7) => t7.size_hint(),
+            Self::T8(t8) => t8.size_hint(),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7, T8> FusedStream for Or8<T1, T2, T3, T4, T5, T6, T7, T8>
+where
+    T1: FusedStream,
+    T2: FusedStream<Item = T1::Item>,
+    T3: FusedStream<Item = T1::Item>,
+    T4: FusedStream<Item = T1::Item>,
+    T5: FusedStream<Item = T1::Item>,
+    T6: FusedStream<Item = T1::Item>,
+    T7: FusedStream<Item = T1::Item>,
+    T8: FusedStream<Item = T1::Item>,
+{
+    fn is_terminated(&self) -> bool {
+        match self {
+            Self::T1(t1) => t1.is_terminated(),
+            Self::T2(t2) => t2.is_terminated(),
+            Self::T3(t3) => t3.is_terminated(),
+            Self::T4(t4) => t4.is_terminated(),
+            Self::T5(t5) => t5.is_terminated(),
+            Self::T6(t6) => t6.is_terminated(),
+            Self::T7(t7) => t7.is_terminated(),
+            Self::T8(t8) => t8.is_terminated(),
+        }
+    }
+}
+
+/// Polls the stream of the active variant, so the branches returning different streams of the
+/// same item can be consumed as a single stream.
+impl<T1, T2, T3, T4, T5, T6, T7, T8, T9> Stream for Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>
+where
+    T1: Stream,
+    T2: Stream<Item = T1::Item>,
+    T3: Stream<Item = T1::Item>,
+    T4: Stream<Item = T1::Item>,
+    T5: Stream<Item = T1::Item>,
+    T6: Stream<Item = T1::Item>,
+    T7: Stream<Item = T1::Item>,
+    T8: Stream<Item = T1::Item>,
+    T9: Stream<Item = T1::Item>,
+{
+    type Item = T1::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match self.as_pin_mut() {
+            Or9::T1(t1) => t1.poll_next(cx),
+            Or9::T2(t2) => t2.poll_next(cx),
+            Or9::T3(t3) => t3.poll_next(cx),
+            Or9::T4(t4) => t4.poll_next(cx),
+            Or9::T5(t5) => t5.poll_next(cx),
+            Or9::T6(t6) => t6.poll_next(cx),
+            Or9::T7(t7) => t7.poll_next(cx),
+            Or9::T8(t8) => t8.poll_next(cx),
+            Or9::T9(t9) => t9.poll_next(cx),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Self::T1(t1) => t1.size_hint(),
+            Self::T2(t2) => t2.size_hint(),
+            Self::T3(t3) => t3.size_hint(),
+            Self::T4(t4) => t4.size_hint(),
+            Self::T5(t5) => t5.size_hint(),
+            Self::T6(t6) => t6.size_hint(),
+            Self::T7(t7) => t7.size_hint(),
+            Self::T8(t8) => t8.size_hint(),
+            Self::T9(t9) => t9.size_hint(),
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7, T8, T9> FusedStream for Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>
+where
+    T1: FusedStream,
+    T2: FusedStream<Item = T1::Item>,
+    T3: FusedStream<Item = T1::Item>,
+    T4: FusedStream<Item = T1::Item>,
+    T5: FusedStream<Item = T1::Item>,
+    T6: FusedStream<Item = T1::Item>,
+    T7: FusedStream<Item = T1::Item>,
+    T8: FusedStream<Item = T1::Item>,
+    T9: FusedStream<Item = T1::Item>,
+{
+    fn is_terminated(&self) -> bool {
+        match self {
+            Self::T1(t1) => t1.is_terminated(),
+            Self::T2(t2) => t2.is_terminated(),
+            Self::T3(t3) => t3.is_terminated(),
+            Self::T4(t4) => t4.is_terminated(),
+            Self::T5(t5) => t5.is_terminated(),
+            Self::T6(t6) => t6.is_terminated(),
+            Self::T7(t7) => t7.is_terminated(),
+            Self::T8(t8) => t8.is_terminated(),
+            Self::T9(t9) => t9.is_terminated(),
+        }
+    }
+}
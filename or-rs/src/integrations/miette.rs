@@ -0,0 +1,771 @@
+//! `miette` diagnostics of the `Or` types, enabled by the `miette` feature.
+
+use std::fmt::Display;
+
+use miette::{Diagnostic, LabeledSpan, Severity, SourceCode};
+
+use crate::enums::*;
+
+/// Forwards every method to the diagnostic of the active variant, so the report looks
+/// the same as the one of the variant itself.
+impl<T1, T2> Diagnostic for Or2<T1, T2>
+where
+    T1: Diagnostic,
+    T2: Diagnostic,
+{
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        match self {
+            Self::T1(t1) => t1.code(),
+            Self::T2(t2) => t2.code(),
+        }
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        match self {
+            Self::T1(t1) => t1.severity(),
+            Self::T2(t2) => t2.severity(),
+        }
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        match self {
+            Self::T1(t1) => t1.help(),
+            Self::T2(t2) => t2.help(),
+        }
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        match self {
+            Self::T1(t1) => t1.url(),
+            Self::T2(t2) => t2.url(),
+        }
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        match self {
+            Self::T1(t1) => t1.source_code(),
+            Self::T2(t2) => t2.source_code(),
+        }
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        match self {
+            Self::T1(t1) => t1.labels(),
+            Self::T2(t2) => t2.labels(),
+        }
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        match self {
+            Self::T1(t1) => t1.related(),
+            Self::T2(t2) => t2.related(),
+        }
+    }
+
+    fn diagnostic_source(&self) -> Option<&dyn Diagnostic> {
+        match self {
+            Self::T1(t1) => t1.diagnostic_source(),
+            Self::T2(t2) => t2.diagnostic_source(),
+        }
+    }
+}
+
+/// Forwards every method to the diagnostic of the active variant, so the report looks
+/// the same as the one of the variant itself.
+impl<T1, T2, T3> Diagnostic for Or3<T1, T2, T3>
+where
+    T1: Diagnostic,
+    T2: Diagnostic,
+    T3: Diagnostic,
+{
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        match self {
+            Self::T1(t1) => t1.code(),
+            Self::T2(t2) => t2.code(),
+            Self::T3(t3) => t3.code(),
+        }
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        match self {
+            Self::T1(t1) => t1.severity(),
+            Self::T2(t2) => t2.severity(),
+            Self::T3(t3) => t3.severity(),
+        }
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        match self {
+            Self::T1(t1) => t1.help(),
+            Self::T2(t2) => t2.help(),
+            Self::T3(t3) => t3.help(),
+        }
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        match self {
+            Self::T1(t1) => t1.url(),
+            Self::T2(t2) => t2.url(),
+            Self::T3(t3) => t3.url(),
+        }
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        match self {
+            Self::T1(t1) => t1.source_code(),
+            Self::T2(t2) => t2.source_code(),
+            Self::T3(t3) => t3.source_code(),
+        }
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        match self {
+            Self::T1(t1) => t1.labels(),
+            Self::T2(t2) => t2.labels(),
+            Self::T3(t3) => t3.labels(),
+        }
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        match self {
+            Self::T1(t1) => t1.related(),
+            Self::T2(t2) => t2.related(),
+            Self::T3(t3) => t3.related(),
+        }
+    }
+
+    fn diagnostic_source(&self) -> Option<&dyn Diagnostic> {
+        match self {
+            Self::T1(t1) => t1.diagnostic_source(),
+            Self::T2(t2) => t2.diagnostic_source(),
+            Self::T3(t3) => t3.diagnostic_source(),
+        }
+    }
+}
+
+/// Forwards every method to the diagnostic of the active variant, so the report looks
+/// the same as the one of the variant itself.
+impl<T1, T2, T3, T4> Diagnostic for Or4<T1, T2, T3, T4>
+where
+    T1: Diagnostic,
+    T2: Diagnostic,
+    T3: Diagnostic,
+    T4: Diagnostic,
+{
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        match self {
+            Self::T1(t1) => t1.code(),
+            Self::T2(t2) => t2.code(),
+            Self::T3(t3) => t3.code(),
+            Self::T4(t4) => t4.code(),
+        }
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        match self {
+            Self::T1(t1) => t1.severity(),
+            Self::T2(t2) => t2.severity(),
+            Self::T3(t3) => t3.severity(),
+            Self::T4(t4) => t4.severity(),
+        }
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        match self {
+            Self::T1(t1) => t1.help(),
+            Self::T2(t2) => t2.help(),
+            Self::T3(t3) => t3.help(),
+            Self::T4(t4) => t4.help(),
+        }
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        match self {
+            Self::T1(t1) => t1.url(),
+            Self::T2(t2) => t2.url(),
+            Self::T3(t3) => t3.url(),
+            Self::T4(t4) => t4.url(),
+        }
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        match self {
+            Self::T1(t1) => t1.source_code(),
+            Self::T2(t2) => t2.source_code(),
+            Self::T3(t3) => t3.source_code(),
+            Self::T4(t4) => t4.source_code(),
+        }
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        match self {
+            Self::T1(t1) => t1.labels(),
+            Self::T2(t2) => t2.labels(),
+            Self::T3(t3) => t3.labels(),
+            Self::T4(t4) => t4.labels(),
+        }
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        match self {
+            Self::T1(t1) => t1.related(),
+            Self::T2(t2) => t2.related(),
+            Self::T3(t3) => t3.related(),
+            Self::T4(t4) => t4.related(),
+        }
+    }
+
+    fn diagnostic_source(&self) -> Option<&dyn Diagnostic> {
+        match self {
+            Self::T1(t1) => t1.diagnostic_source(),
+            Self::T2(t2) => t2.diagnostic_source(),
+            Self::T3(t3) => t3.diagnostic_source(),
+            Self::T4(t4) => t4.diagnostic_source(),
+        }
+    }
+}
+
+/// Forwards every method to the diagnostic of the active variant, so the report looks
+/// the same as the one of the variant itself.
+impl<T1, T2, T3, T4, T5> Diagnostic for Or5<T1, T2, T3, T4, T5>
+where
+    T1: Diagnostic,
+    T2: Diagnostic,
+    T3: Diagnostic,
+    T4: Diagnostic,
+    T5: Diagnostic,
+{
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        match self {
+            Self::T1(t1) => t1.code(),
+            Self::T2(t2) => t2.code(),
+            Self::T3(t3) => t3.code(),
+            Self::T4(t4) => t4.code(),
+            Self::T5(t5) => t5.code(),
+        }
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        match self {
+            Self::T1(t1) => t1.severity(),
+            Self::T2(t2) => t2.severity(),
+            Self::T3(t3) => t3.severity(),
+            Self::T4(t4) => t4.severity(),
+            Self::T5(t5) => t5.severity(),
+        }
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        match self {
+            Self::T1(t1) => t1.help(),
+            Self::T2(t2) => t2.help(),
+            Self::T3(t3) => t3.help(),
+            Self::T4(t4) => t4.help(),
+            Self::T5(t5) => t5.help(),
+        }
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        match self {
+            Self::T1(t1) => t1.url(),
+            Self::T2(t2) => t2.url(),
+            Self::T3(t3) => t3.url(),
+            Self::T4(t4) => t4.url(),
+            Self::T5(t5) => t5.url(),
+        }
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        match self {
+            Self::T1(t1) => t1.source_code(),
+            Self::T2(t2) => t2.source_code(),
+            Self::T3(t3) => t3.source_code(),
+            Self::T4(t4) => t4.source_code(),
+            Self::T5(t5) => t5.source_code(),
+        }
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        match self {
+            Self::T1(t1) => t1.labels(),
+            Self::T2(t2) => t2.labels(),
+            Self::T3(t3) => t3.labels(),
+            Self::T4(t4) => t4.labels(),
+            Self::T5(t5) => t5.labels(),
+        }
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        match self {
+            Self::T1(t1) => t1.related(),
+            Self::T2(t2) => t2.related(),
+            Self::T3(t3) => t3.related(),
+            Self::T4(t4) => t4.related(),
+            Self::T5(t5) => t5.related(),
+        }
+    }
+
+    fn diagnostic_source(&self) -> Option<&dyn Diagnostic> {
+        match self {
+            Self::T1(t1) => t1.diagnostic_source(),
+            Self::T2(t2) => t2.diagnostic_source(),
+            Self::T3(t3) => t3.diagnostic_source(),
+            Self::T4(t4) => t4.diagnostic_source(),
+            Self::T5(t5) => t5.diagnostic_source(),
+        }
+    }
+}
+
+/// Forwards every method to the diagnostic of the active variant, so the report looks
+/// the same as the one of the variant itself.
+impl<T1, T2, T3, T4, T5, T6> Diagnostic for Or6<T1, T2, T3, T4, T5, T6>
+where
+    T1: Diagnostic,
+    T2: Diagnostic,
+    T3: Diagnostic,
+    T4: Diagnostic,
+    T5: Diagnostic,
+    T6: Diagnostic,
+{
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        match self {
+            Self::T1(t1) => t1.code(),
+            Self::T2(t2) => t2.code(),
+            Self::T3(t3) => t3.code(),
+            Self::T4(t4) => t4.code(),
+            Self::T5(t5) => t5.code(),
+            Self::T6(t6) => t6.code(),
+        }
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        match self {
+            Self::T1(t1) => t1.severity(),
+            Self::T2(t2) => t2.severity(),
+            Self::T3(t3) => t3.severity(),
+            Self::T4(t4) => t4.severity(),
+            Self::T5(t5) => t5.severity(),
+            Self::T6(t6) => t6.severity(),
+        }
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        match self {
+            Self::T1(t1) => t1.help(),
+            Self::T2(t2) => t2.help(),
+            Self::T3(t3) => t3.help(),
+            Self::T4(t4) => t4.help(),
+            Self::T5(t5) => t5.help(),
+            Self::T6(t6) => t6.help(),
+        }
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        match self {
+            Self::T1(t1) => t1.url(),
+            Self::T2(t2) => t2.url(),
+            Self::T3(t3) => t3.url(),
+            Self::T4(t4) => t4.url(),
+            Self::T5(t5) => t5.url(),
+            Self::T6(t6) => t6.url(),
+        }
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        match self {
+            Self::T1(t1) => t1.source_code(),
+            Self::T2(t2) => t2.source_code(),
+            Self::T3(t3) => t3.source_code(),
+            Self::T4(t4) => t4.source_code(),
+            Self::T5(t5) => t5.source_code(),
+            Self::T6(t6) => t6.source_code(),
+        }
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        match self {
+            Self::T1(t1) => t1.labels(),
+            Self::T2(t2) => t2.labels(),
+            Self::T3(t3) => t3.labels(),
+            Self::T4(t4) => t4.labels(),
+            Self::T5(t5) => t5.labels(),
+            Self::T6(t6) => t6.labels(),
+        }
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        match self {
+            Self::T1(t1) => t1.related(),
+            Self::T2(t2) => t2.related(),
+            Self::T3(t3) => t3.related(),
+            Self::T4(t4) => t4.related(),
+            Self::T5(t5) => t5.related(),
+            Self::T6(t6) => t6.related(),
+        }
+    }
+
+    fn diagnostic_source(&self) -> Option<&dyn Diagnostic> {
+        match self {
+            Self::T1(t1) => t1.diagnostic_source(),
+            Self::T2(t2) => t2.diagnostic_source(),
+            Self::T3(t3) => t3.diagnostic_source(),
+            Self::T4(t4) => t4.diagnostic_source(),
+            Self::T5(t5) => t5.diagnostic_source(),
+            Self::T6(t6) => t6.diagnostic_source(),
+        }
+    }
+}
+
+/// Forwards every method to the diagnostic of the active variant, so the report looks
+/// the same as the one of the variant itself.
+impl<T1, T2, T3, T4, T5, T6, T7> Diagnostic for Or7<T1, T2, T3, T4, T5, T6, T7>
+where
+    T1: Diagnostic,
+    T2: Diagnostic,
+    T3: Diagnostic,
+    T4: Diagnostic,
+    T5: Diagnostic,
+    T6: Diagnostic,
+    T7: Diagnostic,
+{
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        match self {
+            Self::T1(t1) => t1.code(),
+            Self::T2(t2) => t2.code(),
+            Self::T3(t3) => t3.code(),
+            Self::T4(t4) => t4.code(),
+            Self::T5(t5) => t5.code(),
+            Self::T6(t6) => t6.code(),
+            Self::T7(t7) => t7.code(),
+        }
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        match self {
+            Self::T1(t1) => t1.severity(),
+            Self::T2(t2) => t2.severity(),
+            Self::T3(t3) => t3.severity(),
+            Self::T4(t4) => t4.severity(),
+            Self::T5(t5) => t5.severity(),
+            Self::T6(t6) => t6.severity(),
+            Self::T7(t7) => t7.severity(),
+        }
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        match self {
+            Self::T1(t1) => t1.help(),
+            Self::T2(t2) => t2.help(),
+            Self::T3(t3) => t3.help(),
+            Self::T4(t4) => t4.help(),
+            Self::T5(t5) => t5.help(),
+            Self::T6(t6) => t6.help(),
+            Self::T7(t7) => t7.help(),
+        }
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        match self {
+            Self::T1(t1) => t1.url(),
+            Self::T2(t2) => t2.url(),
+            Self::T3(t3) => t3.url(),
+            Self::T4(t4) => t4.url(),
+            Self::T5(t5) => t5.url(),
+            Self::T6(t6) => t6.url(),
+            Self::T7(t7) => t7.url(),
+        }
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        match self {
+            Self::T1(t1) => t1.source_code(),
+            Self::T2(t2) => t2.source_code(),
+            Self::T3(t3) => t3.source_code(),
+            Self::T4(t4) => t4.source_code(),
+            Self::T5(t5) => t5.source_code(),
+            Self::T6(t6) => t6.source_code(),
+            Self::T7(t7) => t7.source_code(),
+        }
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        match self {
+            Self::T1(t1) => t1.labels(),
+            Self::T2(t2) => t2.labels(),
+            Self::T3(t3) => t3.labels(),
+            Self::T4(t4) => t4.labels(),
+            Self::T5(t5) => t5.labels(),
+            Self::T6(t6) => t6.labels(),
+            Self::T7(t7) => t7.labels(),
+        }
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        match self {
+            Self::T1(t1) => t1.related(),
+            Self::T2(t2) => t2.related(),
+            Self::T3(t3) => t3.related(),
+            Self::T4(t4) => t4.related(),
+            Self::T5(t5) => t5.related(),
+            Self::T6(t6) => t6.related(),
+            Self::T7(t7) => t7.related(),
+        }
+    }
+
+    fn diagnostic_source(&self) -> Option<&dyn Diagnostic> {
+        match self {
+            Self::T1(t1) => t1.diagnostic_source(),
+            Self::T2(t2) => t2.diagnostic_source(),
+            Self::T3(t3) => t3.diagnostic_source(),
+            Self::T4(t4) => t4.diagnostic_source(),
+            Self::T5(t5) => t5.diagnostic_source(),
+            Self::T6(t6) => t6.diagnostic_source(),
+            Self::T7(t7) => t7.diagnostic_source(),
+        }
+    }
+}
+
+/// Forwards every method to the diagnostic of the active variant, so the report looks
+/// the same as the one of the variant itself.
+impl<T1, T2, T3, T4, T5, T6, T7, T8> Diagnostic for Or8<T1, T2, T3, T4, T5, T6, T7, T8>
+where
+    T1: Diagnostic,
+    T2: Diagnostic,
+    T3: Diagnostic,
+    T4: Diagnostic,
+    T5: Diagnostic,
+    T6: Diagnostic,
+    T7: Diagnostic,
+    T8: Diagnostic,
+{
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        match self {
+            Self::T1(t1) => t1.code(),
+            Self::T2(t2) => t2.code(),
+            Self::T3(t3) => t3.code(),
+            Self::T4(t4) => t4.code(),
+            Self::T5(t5) => t5.code(),
+            Self::T6(t6) => t6.code(),
+            Self::T7(t7) => t7.code(),
+            Self::T8(t8) => t8.code(),
+        }
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        match self {
+            Self::T1(t1) => t1.severity(),
+            Self::T2(t2) => t2.severity(),
+            Self::T3(t3) => t3.severity(),
+            Self::T4(t4) => t4.severity(),
+            Self::T5(t5) => t5.severity(),
+            Self::T6(t6) => t6.severity(),
+            Self::T7(t7) => t7.severity(),
+            Self::T8(t8) => t8.severity(),
+        }
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        match self {
+            Self::T1(t1) => t1.help(),
+            Self::T2(t2) => t2.help(),
+            Self::T3(t3) => t3.help(),
+            Self::T4(t4) => t4.help(),
+            Self::T5(t5) => t5.help(),
+            Self::T6(t6) => t6.help(),
+            Self::T7(t7) => t7.help(),
+            Self::T8(t8) => t8.help(),
+        }
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        match self {
+            Self::T1(t1) => t1.url(),
+            Self::T2(t2) => t2.url(),
+            Self::T3(t3) => t3.url(),
+            Self::T4(t4) => t4.url(),
+            Self::T5(t5) => t5.url(),
+            Self::T6(t6) => t6.url(),
+            Self::T7(t7) => t7.url(),
+            Self::T8(t8) => t8.url(),
+        }
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        match self {
+            Self::T1(t1) => t1.source_code(),
+            Self::T2(t2) => t2.source_code(),
+            Self::T3(t3) => t3.source_code(),
+            Self::T4(t4) => t4.source_code(),
+            Self::T5(t5) => t5.source_code(),
+            Self::T6(t6) => t6.source_code(),
+            Self::T7(t7) => t7.source_code(),
+            Self::T8(t8) => t8.source_code(),
+        }
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        match self {
+            Self::T1(t1) => t1.labels(),
+            Self::T2(t2) => t2.labels(),
+            Self::T3(t3) => t3.labels(),
+            Self::T4(t4) => t4.labels(),
+            Self::T5(t5) => t5.labels(),
+            Self::T6(t6) => t6.labels(),
+            Self::T7(t7) => t7.labels(),
+            Self::T8(t8) => t8.labels(),
+        }
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        match self {
+            Self::T1(t1) => t1.related(),
+            Self::T2(t2) => t2.related(),
+            Self::T3(t3) => t3.related(),
+            Self::T4(t4) => t4.related(),
+            Self::T5(t5) => t5.related(),
+            Self::T6(t6) => t6.related(),
+            Self::T7(t7) => t7.related(),
+            Self::T8(t8) => t8.related(),
+        }
+    }
+
+    fn diagnostic_source(&self) -> Option<&dyn Diagnostic> {
+        match self {
+            Self::T1(t1) => t1.diagnostic_source(),
+            Self::T2(t2) => t2.diagnostic_source(),
+            Self::T3(t3) => t3.diagnostic_source(),
+            Self::T4(t4) => t4.diagnostic_source(),
+            Self::T5(t5) => t5.diagnostic_source(),
+            Self::T6(t6) => t6.diagnostic_source(),
+            Self::T7(t7) => t7.diagnostic_source(),
+            Self::T8(t8) => t8.diagnostic_source(),
+        }
+    }
+}
+
+/// Forwards every method to the diagnostic of the active variant, so the report looks
+/// the same as the one of the variant itself.
+impl<T1, T2, T3, T4, T5, T6, T7, T8, T9> Diagnostic for Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>
+where
+    T1: Diagnostic,
+    T2: Diagnostic,
+    T3: Diagnostic,
+    T4: Diagnostic,
+    T5: Diagnostic,
+    T6: Diagnostic,
+    T7: Diagnostic,
+    T8: Diagnostic,
+    T9: Diagnostic,
+{
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        match self {
+            Self::T1(t1) => t1.code(),
+            Self::T2(t2) => t2.code(),
+            Self::T3(t3) => t3.code(),
+            Self::T4(t4) => t4.code(),
+            Self::T5(t5) => t5.code(),
+            Self::T6(t6) => t6.code(),
+            Self::T7(t7) => t7.code(),
+            Self::T8(t8) => t8.code(),
+            Self::T9(t9) => t9.code(),
+        }
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        match self {
+            Self::T1(t1) => t1.severity(),
+            Self::T2(t2) => t2.severity(),
+            Self::T3(t3) => t3.severity(),
+            Self::T4(t4) => t4.severity(),
+            Self::T5(t5) => t5.severity(),
+            Self::T6(t6) => t6.severity(),
+            Self::T7(t7) => t7.severity(),
+            Self::T8(t8) => t8.severity(),
+            Self::T9(t9) => t9.severity(),
+        }
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        match self {
+            Self::T1(t1) => t1.help(),
+            Self::T2(t2) => t2.help(),
+            Self::T3(t3) => t3.help(),
+            Self::T4(t4) => t4.help(),
+            Self::T5(t5) => t5.help(),
+            Self::T6(t6) => t6.help(),
+            Self::T7(t7) => t7.help(),
+            Self::T8(t8) => t8.help(),
+            Self::T9(t9) => t9.help(),
+        }
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        match self {
+            Self::T1(t1) => t1.url(),
+            Self::T2(t2) => t2.url(),
+            Self::T3(t3) => t3.url(),
+            Self::T4(t4) => t4.url(),
+            Self::T5(t5) => t5.url(),
+            Self::T6(t6) => t6.url(),
+            Self::T7(t7) => t7.url(),
+            Self::T8(t8) => t8.url(),
+            Self::T9(t9) => t9.url(),
+        }
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        match self {
+            Self::T1(t1) => t1.source_code(),
+            Self::T2(t2) => t2.source_code(),
+            Self::T3(t3) => t3.source_code(),
+            Self::T4(t4) => t4.source_code(),
+            Self::T5(t5) => t5.source_code(),
+            Self::T6(t6) => t6.source_code(),
+            Self::T7(t7) => t7.source_code(),
+            Self::T8(t8) => t8.source_code(),
+            Self::T9(t9) => t9.source_code(),
+        }
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        match self {
+            Self::T1(t1) => t1.labels(),
+            Self::T2(t2) => t2.labels(),
+            Self::T3(t3) => t3.labels(),
+            Self::T4(t4) => t4.labels(),
+            Self::T5(t5) => t5.labels(),
+            Self::T6(t6) => t6.labels(),
+            Self::T7(t7) => t7.labels(),
+            Self::T8(t8) => t8.labels(),
+            Self::T9(t9) => t9.labels(),
+        }
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        match self {
+            Self::T1(t1) => t1.related(),
+            Self::T2(t2) => t2.related(),
+            Self::T3(t3) => t3.related(),
+            Self::T4(t4) => t4.related(),
+            Self::T5(t5) => t5.related(),
+            Self::T6(t6) => t6.related(),
+            Self::T7(t7) => t7.related(),
+            Self::T8(t8) => t8.related(),
+            Self::T9(t9) => t9.related(),
+        }
+    }
+
+    fn diagnostic_source(&self) -> Option<&dyn Diagnostic> {
+        match self {
+            Self::T1(t1) => t1.diagnostic_source(),
+            Self::T2(t2) => t2.diagnostic_source(),
+            Self::T3(t3) => t3.diagnostic_source(),
+            Self::T4(t4) => t4.diagnostic_source(),
+            Self::T5(t5) => t5.diagnostic_source(),
+            Self::T6(t6) => t6.diagnostic_source(),
+            Self::T7(t7) => t7.diagnostic_source(),
+            Self::T8(t8) => t8.diagnostic_source(),
+            Self::T9(t9) => t9.diagnostic_source(),
+        }
+    }
+}
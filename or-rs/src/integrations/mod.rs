@@ -0,0 +1,36 @@
+//! Integrations of the `Or` types with third-party crates, each enabled by a cargo feature.
+//!
+//! The modules here are generated by [code_gen](../../code_gen/index.html) crate.
+
+// declares the module of an integration, which is `include!`d from `OUT_DIR`
+// in the `codegen` mode.
+macro_rules! integration {
+    ($feature:literal, $name:ident) => {
+        #[cfg(all(feature = $feature, not(feature = "codegen")))]
+        mod $name;
+
+        #[cfg(all(feature = $feature, feature = "codegen"))]
+        mod $name {
+            include!(concat!(
+                env!("OUT_DIR"),
+                "/integrations/",
+                stringify!($name),
+                ".rs"
+            ));
+        }
+    };
+}
+
+integration!("pyo3", pyo3);
+integration!("anyhow", anyhow);
+integration!("async-graphql", async_graphql);
+integration!("borsh", borsh);
+integration!("either", either);
+integration!("embedded-hal", embedded_hal);
+integration!("frunk", frunk);
+integration!("futures", futures);
+integration!("miette", miette);
+integration!("rocket", rocket);
+integration!("serde", serde_tagged);
+integration!("tokio", tokio);
+integration!("tower", tower);
@@ -0,0 +1,500 @@
+//! `pyo3` conversions of the `Or` types, enabled by the `pyo3` feature.
+
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+
+use crate::enums::*;
+
+/// Converts the value of the active variant into a Python object.
+impl<T1, T2> IntoPy<PyObject> for Or2<T1, T2>
+where
+    T1: IntoPy<PyObject>,
+    T2: IntoPy<PyObject>,
+{
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        match self {
+            Self::T1(t1) => t1.into_py(py),
+            Self::T2(t2) => t2.into_py(py),
+        }
+    }
+}
+
+/// Extracts `T1` ... `T2` from a Python object in order, and the first successful
+/// extraction wins. If every type fails, a `TypeError` describing all the failures is raised.
+impl<'py, T1, T2> FromPyObject<'py> for Or2<T1, T2>
+where
+    T1: FromPyObject<'py>,
+    T2: FromPyObject<'py>,
+{
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let e1 = match ob.extract::<T1>() {
+            Ok(t1) => return Ok(Self::T1(t1)),
+            Err(e1) => e1,
+        };
+        let e2 = match ob.extract::<T2>() {
+            Ok(t2) => return Ok(Self::T2(t2)),
+            Err(e2) => e2,
+        };
+        Err(PyTypeError::new_err(format!(
+            "failed to extract Or2 (T1: {}, T2: {})",
+            e1, e2
+        )))
+    }
+}
+
+/// Converts the value of the active variant into a Python object.
+impl<T1, T2, T3> IntoPy<PyObject> for Or3<T1, T2, T3>
+where
+    T1: IntoPy<PyObject>,
+    T2: IntoPy<PyObject>,
+    T3: IntoPy<PyObject>,
+{
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        match self {
+            Self::T1(t1) => t1.into_py(py),
+            Self::T2(t2) => t2.into_py(py),
+            Self::T3(t3) => t3.into_py(py),
+        }
+    }
+}
+
+/// Extracts `T1` ... `T3` from a Python object in order, and the first successful
+/// extraction wins. If every type fails, a `TypeError` describing all the failures is raised.
+impl<'py, T1, T2, T3> FromPyObject<'py> for Or3<T1, T2, T3>
+where
+    T1: FromPyObject<'py>,
+    T2: FromPyObject<'py>,
+    T3: FromPyObject<'py>,
+{
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let e1 = match ob.extract::<T1>() {
+            Ok(t1) => return Ok(Self::T1(t1)),
+            Err(e1) => e1,
+        };
+        let e2 = match ob.extract::<T2>() {
+            Ok(t2) => return Ok(Self::T2(t2)),
+            Err(e2) => e2,
+        };
+        let e3 = match ob.extract::<T3>() {
+            Ok(t3) => return Ok(Self::T3(t3)),
+            Err(e3) => e3,
+        };
+        Err(PyTypeError::new_err(format!(
+            "failed to extract Or3 (T1: {}, T2: {}, T3: {})",
+            e1, e2, e3
+        )))
+    }
+}
+
+/// Converts the value of the active variant into a Python object.
+impl<T1, T2, T3, T4> IntoPy<PyObject> for Or4<T1, T2, T3, T4>
+where
+    T1: IntoPy<PyObject>,
+    T2: IntoPy<PyObject>,
+    T3: IntoPy<PyObject>,
+    T4: IntoPy<PyObject>,
+{
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        match self {
+            Self::T1(t1) => t1.into_py(py),
+            Self::T2(t2) => t2.into_py(py),
+            Self::T3(t3) => t3.into_py(py),
+            Self::T4(t4) => t4.into_py(py),
+        }
+    }
+}
+
+/// Extracts `T1` ... `T4` from a Python object in order, and the first successful
+/// extraction wins. If every type fails, a `TypeError` describing all the failures is raised.
+impl<'py, T1, T2, T3, T4> FromPyObject<'py> for Or4<T1, T2, T3, T4>
+where
+    T1: FromPyObject<'py>,
+    T2: FromPyObject<'py>,
+    T3: FromPyObject<'py>,
+    T4: FromPyObject<'py>,
+{
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let e1 = match ob.extract::<T1>() {
+            Ok(t1) => return Ok(Self::T1(t1)),
+            Err(e1) => e1,
+        };
+        let e2 = match ob.extract::<T2>() {
+            Ok(t2) => return Ok(Self::T2(t2)),
+            Err(e2) => e2,
+        };
+        let e3 = match ob.extract::<T3>() {
+            Ok(t3) => return Ok(Self::T3(t3)),
+            Err(e3) => e3,
+        };
+        let e4 = match ob.extract::<T4>() {
+            Ok(t4) => return Ok(Self::T4(t4)),
+            Err(e4) => e4,
+        };
+        Err(PyTypeError::new_err(format!(
+            "failed to extract Or4 (T1: {}, T2: {}, T3: {}, T4: {})",
+            e1, e2, e3, e4
+        )))
+    }
+}
+
+/// Converts the value of the active variant into a Python object.
+impl<T1, T2, T3, T4, T5> IntoPy<PyObject> for Or5<T1, T2, T3, T4, T5>
+where
+    T1: IntoPy<PyObject>,
+    T2: IntoPy<PyObject>,
+    T3: IntoPy<PyObject>,
+    T4: IntoPy<PyObject>,
+    T5: IntoPy<PyObject>,
+{
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        match self {
+            Self::T1(t1) => t1.into_py(py),
+            Self::T2(t2) => t2.into_py(py),
+            Self::T3(t3) => t3.into_py(py),
+            Self::T4(t4) => t4.into_py(py),
+            Self::T5(t5) => t5.into_py(py),
+        }
+    }
+}
+
+/// Extracts `T1` ... `T5` from a Python object in order, and the first successful
+/// extraction wins. If every type fails, a `TypeError` describing all the failures is raised.
+impl<'py, T1, T2, T3, T4, T5> FromPyObject<'py> for Or5<T1, T2, T3, T4, T5>
+where
+    T1: FromPyObject<'py>,
+    T2: FromPyObject<'py>,
+    T3: FromPyObject<'py>,
+    T4: FromPyObject<'py>,
+    T5: FromPyObject<'py>,
+{
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let e1 = match ob.extract::<T1>() {
+            Ok(t1) => return Ok(Self::T1(t1)),
+            Err(e1) => e1,
+        };
+        let e2 = match ob.extract::<T2>() {
+            Ok(t2) => return Ok(Self::T2(t2)),
+            Err(e2) => e2,
+        };
+        let e3 = match ob.extract::<T3>() {
+            Ok(t3) => return Ok(Self::T3(t3)),
+            Err(e3) => e3,
+        };
+        let e4 = match ob.extract::<T4>() {
+            Ok(t4) => return Ok(Self::T4(t4)),
+            Err(e4) => e4,
+        };
+        let e5 = match ob.extract::<T5>() {
+            Ok(t5) => return Ok(Self::T5(t5)),
+            Err(e5) => e5,
+        };
+        Err(PyTypeError::new_err(format!(
+            "failed to extract Or5 (T1: {}, T2: {}, T3: {}, T4: {}, T5: {})",
+            e1, e2, e3, e4, e5
+        )))
+    }
+}
+
+/// Converts the value of the active variant into a Python object.
+impl<T1, T2, T3, T4, T5, T6> IntoPy<PyObject> for Or6<T1, T2, T3, T4, T5, T6>
+where
+    T1: IntoPy<PyObject>,
+    T2: IntoPy<PyObject>,
+    T3: IntoPy<PyObject>,
+    T4: IntoPy<PyObject>,
+    T5: IntoPy<PyObject>,
+    T6: IntoPy<PyObject>,
+{
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        match self {
+            Self::T1(t1) => t1.into_py(py),
+            Self::T2(t2) => t2.into_py(py),
+            Self::T3(t3) => t3.into_py(py),
+            Self::T4(t4) => t4.into_py(py),
+            Self::T5(t5) => t5.into_py(py),
+            Self::T6(t6) => t6.into_py(py),
+        }
+    }
+}
+
+/// Extracts `T1` ... `T6` from a Python object in order, and the first successful
+/// extraction wins. If every type fails, a `TypeError` describing all the failures is raised.
+impl<'py, T1, T2, T3, T4, T5, T6> FromPyObject<'py> for Or6<T1, T2, T3, T4, T5, T6>
+where
+    T1: FromPyObject<'py>,
+    T2: FromPyObject<'py>,
+    T3: FromPyObject<'py>,
+    T4: FromPyObject<'py>,
+    T5: FromPyObject<'py>,
+    T6: FromPyObject<'py>,
+{
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let e1 = match ob.extract::<T1>() {
+            Ok(t1) => return Ok(Self::T1(t1)),
+            Err(e1) => e1,
+        };
+        let e2 = match ob.extract::<T2>() {
+            Ok(t2) => return Ok(Self::T2(t2)),
+            Err(e2) => e2,
+        };
+        let e3 = match ob.extract::<T3>() {
+            Ok(t3) => return Ok(Self::T3(t3)),
+            Err(e3) => e3,
+        };
+        let e4 = match ob.extract::<T4>() {
+            Ok(t4) => return Ok(Self::T4(t4)),
+            Err(e4) => e4,
+        };
+        let e5 = match ob.extract::<T5>() {
+            Ok(t5) => return Ok(Self::T5(t5)),
+            Err(e5) => e5,
+        };
+        let e6 = match ob.extract::<T6>() {
+            Ok(t6) => return Ok(Self::T6(t6)),
+            Err(e6) => e6,
+        };
+        Err(PyTypeError::new_err(format!(
+            "failed to extract Or6 (T1: {}, T2: {}, T3: {}, T4: {}, T5: {}, T6: {})",
+            e1, e2, e3, e4, e5, e6
+        )))
+    }
+}
+
+/// Converts the value of the active variant into a Python object.
+impl<T1, T2, T3, T4, T5, T6, T7> IntoPy<PyObject> for Or7<T1, T2, T3, T4, T5, T6, T7>
+where
+    T1: IntoPy<PyObject>,
+    T2: IntoPy<PyObject>,
+    T3: IntoPy<PyObject>,
+    T4: IntoPy<PyObject>,
+    T5: IntoPy<PyObject>,
+    T6: IntoPy<PyObject>,
+    T7: IntoPy<PyObject>,
+{
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        match self {
+            Self::T1(t1) => t1.into_py(py),
+            Self::T2(t2) => t2.into_py(py),
+            Self::T3(t3) => t3.into_py(py),
+            Self::T4(t4) => t4.into_py(py),
+            Self::T5(t5) => t5.into_py(py),
+            Self::T6(t6) => t6.into_py(py),
+            Self::T7(t7) => t7.into_py(py),
+        }
+    }
+}
+
+/// Extracts `T1` ... `T7` from a Python object in order, and the first successful
+/// extraction wins. If every type fails, a `TypeError` describing all the failures is raised.
+impl<'py, T1, T2, T3, T4, T5, T6, T7> FromPyObject<'py> for Or7<T1, T2, T3, T4, T5, T6, T7>
+where
+    T1: FromPyObject<'py>,
+    T2: FromPyObject<'py>,
+    T3: FromPyObject<'py>,
+    T4: FromPyObject<'py>,
+    T5: FromPyObject<'py>,
+    T6: FromPyObject<'py>,
+    T7: FromPyObject<'py>,
+{
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let e1 = match ob.extract::<T1>() {
+            Ok(t1) => return Ok(Self::T1(t1)),
+            Err(e1) => e1,
+        };
+        let e2 = match ob.extract::<T2>() {
+            Ok(t2) => return Ok(Self::T2(t2)),
+            Err(e2) => e2,
+        };
+        let e3 = match ob.extract::<T3>() {
+            Ok(t3) => return Ok(Self::T3(t3)),
+            Err(e3) => e3,
+        };
+        let e4 = match ob.extract::<T4>() {
+            Ok(t4) => return Ok(Self::T4(t4)),
+            Err(e4) => e4,
+        };
+        let e5 = match ob.extract::<T5>() {
+            Ok(t5) => return Ok(Self::T5(t5)),
+            Err(e5) => e5,
+        };
+        let e6 = match ob.extract::<T6>() {
+            Ok(t6) => return Ok(Self::T6(t6)),
+            Err(e6) => e6,
+        };
+        let e7 = match ob.extract::<T7>() {
+            Ok(t7) => return Ok(Self::T7(t7)),
+            Err(e7) => e7,
+        };
+        Err(PyTypeError::new_err(format!(
+            "failed to extract Or7 (T1: {}, T2: {}, T3: {}, T4: {}, T5: {}, T6: {}, T7: {})",
+            e1, e2, e3, e4, e5, e6, e7
+        )))
+    }
+}
+
+/// Converts the value of the active variant into a Python object.
+impl<T1, T2, T3, T4, T5, T6, T7, T8> IntoPy<PyObject> for Or8<T1, T2, T3, T4, T5, T6, T7, T8>
+where
+    T1: IntoPy<PyObject>,
+    T2: IntoPy<PyObject>,
+    T3: IntoPy<PyObject>,
+    T4: IntoPy<PyObject>,
+    T5: IntoPy<PyObject>,
+    T6: IntoPy<PyObject>,
+    T7: IntoPy<PyObject>,
+    T8: IntoPy<PyObject>,
+{
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        match self {
+            Self::T1(t1) => t1.into_py(py),
+            Self::T2(t2) => t2.into_py(py),
+            Self::T3(t3) => t3.into_py(py),
+            Self::T4(t4) => t4.into_py(py),
+            Self::T5(t5) => t5.into_py(py),
+            Self::T6(t6) => t6.into_py(py),
+            Self::T7(t7) => t7.into_py(py),
+            Self::T8(t8) => t8.into_py(py),
+        }
+    }
+}
+
+/// Extracts `T1` ... `T8` from a Python object in order, and the first successful
+/// extraction wins. If every type fails, a `TypeError` describing all the failures is raised.
+impl<'py, T1, T2, T3, T4, T5, T6, T7, T8> FromPyObject<'py> for Or8<T1, T2, T3, T4, T5, T6, T7, T8>
+where
+    T1: FromPyObject<'py>,
+    T2: FromPyObject<'py>,
+    T3: FromPyObject<'py>,
+    T4: FromPyObject<'py>,
+    T5: FromPyObject<'py>,
+    T6: FromPyObject<'py>,
+    T7: FromPyObject<'py>,
+    T8: FromPyObject<'py>,
+{
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let e1 = match ob.extract::<T1>() {
+            Ok(t1) => return Ok(Self::T1(t1)),
+            Err(e1) => e1,
+        };
+        let e2 = match ob.extract::<T2>() {
+            Ok(t2) => return Ok(Self::T2(t2)),
+            Err(e2) => e2,
+        };
+        let e3 = match ob.extract::<T3>() {
+            Ok(t3) => return Ok(Self::T3(t3)),
+            Err(e3) => e3,
+        };
+        let e4 = match ob.extract::<T4>() {
+            Ok(t4) => return Ok(Self::T4(t4)),
+            Err(e4) => e4,
+        };
+        let e5 = match ob.extract::<T5>() {
+            Ok(t5) => return Ok(Self::T5(t5)),
+            Err(e5) => e5,
+        };
+        let e6 = match ob.extract::<T6>() {
+            Ok(t6) => return Ok(Self::T6(t6)),
+            Err(e6) => e6,
+        };
+        let e7 = match ob.extract::<T7>() {
+            Ok(t7) => return Ok(Self::T7(t7)),
+            Err(e7) => e7,
+        };
+        let e8 = match ob.extract::<T8>() {
+            Ok(t8) => return Ok(Self::T8(t8)),
+            Err(e8) => e8,
+        };
+        Err(PyTypeError::new_err(format!(
+            "failed to extract Or8 (T1: {}, T2: {}, T3: {}, T4: {}, T5: {}, T6: {}, T7: {}, T8: {})",
+            e1,e2,e3,e4,e5,e6,e7,e8
+        )))
+    }
+}
+
+/// Converts the value of the active variant into a Python object.
+impl<T1, T2, T3, T4, T5, T6, T7, T8, T9> IntoPy<PyObject>
+    for Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>
+where
+    T1: IntoPy<PyObject>,
+    T2: IntoPy<PyObject>,
+    T3: IntoPy<PyObject>,
+    T4: IntoPy<PyObject>,
+    T5: IntoPy<PyObject>,
+    T6: IntoPy<PyObject>,
+    T7: IntoPy<PyObject>,
+    T8: IntoPy<PyObject>,
+    T9: IntoPy<PyObject>,
+{
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        match self {
+            Self::T1(t1) => t1.into_py(py),
+            Self::T2(t2) => t2.into_py(py),
+            Self::T3(t3) => t3.into_py(py),
+            Self::T4(t4) => t4.into_py(py),
+            Self::T5(t5) => t5.into_py(py),
+            Self::T6(t6) => t6.into_py(py),
+            Self::T7(t7) => t7.into_py(py),
+            Self::T8(t8) => t8.into_py(py),
+            Self::T9(t9) => t9.into_py(py),
+        }
+    }
+}
+
+/// Extracts `T1` ... `T9` from a Python object in order, and the first successful
+/// extraction wins. If every type fails, a `TypeError` describing all the failures is raised.
+impl<'py, T1, T2, T3, T4, T5, T6, T7, T8, T9> FromPyObject<'py>
+    for Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>
+where
+    T1: FromPyObject<'py>,
+    T2: FromPyObject<'py>,
+    T3: FromPyObject<'py>,
+    T4: FromPyObject<'py>,
+    T5: FromPyObject<'py>,
+    T6: FromPyObject<'py>,
+    T7: FromPyObject<'py>,
+    T8: FromPyObject<'py>,
+    T9: FromPyObject<'py>,
+{
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let e1 = match ob.extract::<T1>() {
+            Ok(t1) => return Ok(Self::T1(t1)),
+            Err(e1) => e1,
+        };
+        let e2 = match ob.extract::<T2>() {
+            Ok(t2) => return Ok(Self::T2(t2)),
+            Err(e2) => e2,
+        };
+        let e3 = match ob.extract::<T3>() {
+            Ok(t3) => return Ok(Self::T3(t3)),
+            Err(e3) => e3,
+        };
+        let e4 = match ob.extract::<T4>() {
+            Ok(t4) => return Ok(Self::T4(t4)),
+            Err(e4) => e4,
+        };
+        let e5 = match ob.extract::<T5>() {
+            Ok(t5) => return Ok(Self::T5(t5)),
+            Err(e5) => e5,
+        };
+        let e6 = match ob.extract::<T6>() {
+            Ok(t6) => return Ok(Self::T6(t6)),
+            Err(e6) => e6,
+        };
+        let e7 = match ob.extract::<T7>() {
+            Ok(t7) => return Ok(Self::T7(t7)),
+            Err(e7) => e7,
+        };
+        let e8 = match ob.extract::<T8>() {
+            Ok(t8) => return Ok(Self::T8(t8)),
+            Err(e8) => e8,
+        };
+        let e9 = match ob.extract::<T9>() {
+            Ok(t9) => return Ok(Self::T9(t9)),
+            Err(e9) => e9,
+        };
+        Err(PyTypeError::new_err(format!(
+            "failed to extract Or9 (T1: {}, T2: {}, T3: {}, T4: {}, T5: {}, T6: {}, T7: {}, T8: {}, T9: {})",
+            e1,e2,e3,e4,e5,e6,e7,e8,e9
+        )))
+    }
+}
@@ -0,0 +1,184 @@
+//! `rocket` responses of the `Or` types, enabled by the `rocket` feature.
+
+use rocket::request::Request;
+use rocket::response::{self, Responder};
+
+use crate::enums::*;
+
+/// Responds with the responder of the active variant, so a handler can return
+/// different kinds of responses.
+impl<'r, 'o: 'r, T1, T2> Responder<'r, 'o> for Or2<T1, T2>
+where
+    T1: Responder<'r, 'o>,
+    T2: Responder<'r, 'o>,
+{
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'o> {
+        match self {
+            Self::T1(t1) => t1.respond_to(req),
+            Self::T2(t2) => t2.respond_to(req),
+        }
+    }
+}
+
+/// Responds with the responder of the active variant, so a handler can return
+/// different kinds of responses.
+impl<'r, 'o: 'r, T1, T2, T3> Responder<'r, 'o> for Or3<T1, T2, T3>
+where
+    T1: Responder<'r, 'o>,
+    T2: Responder<'r, 'o>,
+    T3: Responder<'r, 'o>,
+{
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'o> {
+        match self {
+            Self::T1(t1) => t1.respond_to(req),
+            Self::T2(t2) => t2.respond_to(req),
+            Self::T3(t3) => t3.respond_to(req),
+        }
+    }
+}
+
+/// Responds with the responder of the active variant, so a handler can return
+/// different kinds of responses.
+impl<'r, 'o: 'r, T1, T2, T3, T4> Responder<'r, 'o> for Or4<T1, T2, T3, T4>
+where
+    T1: Responder<'r, 'o>,
+    T2: Responder<'r, 'o>,
+    T3: Responder<'r, 'o>,
+    T4: Responder<'r, 'o>,
+{
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'o> {
+        match self {
+            Self::T1(t1) => t1.respond_to(req),
+            Self::T2(t2) => t2.respond_to(req),
+            Self::T3(t3) => t3.respond_to(req),
+            Self::T4(t4) => t4.respond_to(req),
+        }
+    }
+}
+
+/// Responds with the responder of the active variant, so a handler can return
+/// different kinds of responses.
+impl<'r, 'o: 'r, T1, T2, T3, T4, T5> Responder<'r, 'o> for Or5<T1, T2, T3, T4, T5>
+where
+    T1: Responder<'r, 'o>,
+    T2: Responder<'r, 'o>,
+    T3: Responder<'r, 'o>,
+    T4: Responder<'r, 'o>,
+    T5: Responder<'r, 'o>,
+{
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'o> {
+        match self {
+            Self::T1(t1) => t1.respond_to(req),
+            Self::T2(t2) => t2.respond_to(req),
+            Self::T3(t3) => t3.respond_to(req),
+            Self::T4(t4) => t4.respond_to(req),
+            Self::T5(t5) => t5.respond_to(req),
+        }
+    }
+}
+
+/// Responds with the responder of the active variant, so a handler can return
+/// different kinds of responses.
+impl<'r, 'o: 'r, T1, T2, T3, T4, T5, T6> Responder<'r, 'o> for Or6<T1, T2, T3, T4, T5, T6>
+where
+    T1: Responder<'r, 'o>,
+    T2: Responder<'r, 'o>,
+    T3: Responder<'r, 'o>,
+    T4: Responder<'r, 'o>,
+    T5: Responder<'r, 'o>,
+    T6: Responder<'r, 'o>,
+{
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'o> {
+        match self {
+            Self::T1(t1) => t1.respond_to(req),
+            Self::T2(t2) => t2.respond_to(req),
+            Self::T3(t3) => t3.respond_to(req),
+            Self::T4(t4) => t4.respond_to(req),
+            Self::T5(t5) => t5.respond_to(req),
+            Self::T6(t6) => t6.respond_to(req),
+        }
+    }
+}
+
+/// Responds with the responder of the active variant, so a handler can return
+/// different kinds of responses.
+impl<'r, 'o: 'r, T1, T2, T3, T4, T5, T6, T7> Responder<'r, 'o> for Or7<T1, T2, T3, T4, T5, T6, T7>
+where
+    T1: Responder<'r, 'o>,
+    T2: Responder<'r, 'o>,
+    T3: Responder<'r, 'o>,
+    T4: Responder<'r, 'o>,
+    T5: Responder<'r, 'o>,
+    T6: Responder<'r, 'o>,
+    T7: Responder<'r, 'o>,
+{
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'o> {
+        match self {
+            Self::T1(t1) => t1.respond_to(req),
+            Self::T2(t2) => t2.respond_to(req),
+            Self::T3(t3) => t3.respond_to(req),
+            Self::T4(t4) => t4.respond_to(req),
+            Self::T5(t5) => t5.respond_to(req),
+            Self::T6(t6) => t6.respond_to(req),
+            Self::T7(t7) => t7.respond_to(req),
+        }
+    }
+}
+
+/// Responds with the responder of the active variant, so a handler can return
+/// different kinds of responses.
+impl<'r, 'o: 'r, T1, T2, T3, T4, T5, T6, T7, T8> Responder<'r, 'o>
+    for Or8<T1, T2, T3, T4, T5, T6, T7, T8>
+where
+    T1: Responder<'r, 'o>,
+    T2: Responder<'r, 'o>,
+    T3: Responder<'r, 'o>,
+    T4: Responder<'r, 'o>,
+    T5: Responder<'r, 'o>,
+    T6: Responder<'r, 'o>,
+    T7: Responder<'r, 'o>,
+    T8: Responder<'r, 'o>,
+{
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'o> {
+        match self {
+            Self::T1(t1) => t1.respond_to(req),
+            Self::T2(t2) => t2.respond_to(req),
+            Self::T3(t3) => t3.respond_to(req),
+            Self::T4(t4) => t4.respond_to(req),
+            Self::T5(t5) => t5.respond_to(req),
+            Self::T6(t6) => t6.respond_to(req),
+            Self::T7(t7) => t7.respond_to(req),
+            Self::T8(t8) => t8.respond_to(req),
+        }
+    }
+}
+
+/// Responds with the responder of the active variant, so a handler can return
+/// different kinds of responses.
+impl<'r, 'o: 'r, T1, T2, T3, T4, T5, T6, T7, T8, T9> Responder<'r, 'o>
+    for Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>
+where
+    T1: Responder<'r, 'o>,
+    T2: Responder<'r, 'o>,
+    T3: Responder<'r, 'o>,
+    T4: Responder<'r, 'o>,
+    T5: Responder<'r, 'o>,
+    T6: Responder<'r, 'o>,
+    T7: Responder<'r, 'o>,
+    T8: Responder<'r, 'o>,
+    T9: Responder<'r, 'o>,
+{
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'o> {
+        match self {
+            Self::T1(t1) => t1.respond_to(req),
+            Self::T2(t2) => t2.respond_to(req),
+            Self::T3(t3) => t3.respond_to(req),
+            Self::T4(t4) => t4.respond_to(req),
+            Self::T5(t5) => t5.respond_to(req),
+            Self::T6(t6) => t6.respond_to(req),
+            Self::T7(t7) => t7.respond_to(req),
+            Self::T8(t8) => t8.respond_to(req),
+            Self::T9(t9) => t9.respond_to(req),
+        }
+    }
+}
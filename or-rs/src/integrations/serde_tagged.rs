@@ -0,0 +1,534 @@
+//! `serde` impls of the tagged representation of the `Or` types, enabled by the `serde`
+//! feature.
+//!
+//! Each `Or` type is serialized and deserialized through a private mirror enum, which derives
+//! the adjacently tagged representation.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::tagged::Tagged;
+
+use crate::enums::*;
+
+#[derive(Serialize)]
+#[serde(tag = "variant", content = "value", rename = "Or2")]
+enum Or2Ref<'a, T1, T2> {
+    T1(&'a T1),
+    T2(&'a T2),
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "variant", content = "value", rename = "Or2")]
+enum Or2Owned<T1, T2> {
+    T1(T1),
+    T2(T2),
+}
+
+/// Serializes the name of the active variant as `variant`, along with its value as `value`.
+impl<T1, T2> Serialize for Tagged<Or2<T1, T2>>
+where
+    T1: Serialize,
+    T2: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match &self.0 {
+            Or2::T1(t1) => Or2Ref::T1(t1),
+            Or2::T2(t2) => Or2Ref::T2(t2),
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Deserializes the value into the variant named by `variant`.
+impl<'de, T1, T2> Deserialize<'de> for Tagged<Or2<T1, T2>>
+where
+    T1: Deserialize<'de>,
+    T2: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = match Or2Owned::deserialize(deserializer)? {
+            Or2Owned::T1(t1) => Or2::T1(t1),
+            Or2Owned::T2(t2) => Or2::T2(t2),
+        };
+        Ok(Tagged(value))
+    }
+}
+
+#[derive(Serialize)]
+#[serde(tag = "variant", content = "value", rename = "Or3")]
+enum Or3Ref<'a, T1, T2, T3> {
+    T1(&'a T1),
+    T2(&'a T2),
+    T3(&'a T3),
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "variant", content = "value", rename = "Or3")]
+enum Or3Owned<T1, T2, T3> {
+    T1(T1),
+    T2(T2),
+    T3(T3),
+}
+
+/// Serializes the name of the active variant as `variant`, along with its value as `value`.
+impl<T1, T2, T3> Serialize for Tagged<Or3<T1, T2, T3>>
+where
+    T1: Serialize,
+    T2: Serialize,
+    T3: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match &self.0 {
+            Or3::T1(t1) => Or3Ref::T1(t1),
+            Or3::T2(t2) => Or3Ref::T2(t2),
+            Or3::T3(t3) => Or3Ref::T3(t3),
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Deserializes the value into the variant named by `variant`.
+impl<'de, T1, T2, T3> Deserialize<'de> for Tagged<Or3<T1, T2, T3>>
+where
+    T1: Deserialize<'de>,
+    T2: Deserialize<'de>,
+    T3: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = match Or3Owned::deserialize(deserializer)? {
+            Or3Owned::T1(t1) => Or3::T1(t1),
+            Or3Owned::T2(t2) => Or3::T2(t2),
+            Or3Owned::T3(t3) => Or3::T3(t3),
+        };
+        Ok(Tagged(value))
+    }
+}
+
+#[derive(Serialize)]
+#[serde(tag = "variant", content = "value", rename = "Or4")]
+enum Or4Ref<'a, T1, T2, T3, T4> {
+    T1(&'a T1),
+    T2(&'a T2),
+    T3(&'a T3),
+    T4(&'a T4),
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "variant", content = "value", rename = "Or4")]
+enum Or4Owned<T1, T2, T3, T4> {
+    T1(T1),
+    T2(T2),
+    T3(T3),
+    T4(T4),
+}
+
+/// Serializes the name of the active variant as `variant`, along with its value as `value`.
+impl<T1, T2, T3, T4> Serialize for Tagged<Or4<T1, T2, T3, T4>>
+where
+    T1: Serialize,
+    T2: Serialize,
+    T3: Serialize,
+    T4: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match &self.0 {
+            Or4::T1(t1) => Or4Ref::T1(t1),
+            Or4::T2(t2) => Or4Ref::T2(t2),
+            Or4::T3(t3) => Or4Ref::T3(t3),
+            Or4::T4(t4) => Or4Ref::T4(t4),
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Deserializes the value into the variant named by `variant`.
+impl<'de, T1, T2, T3, T4> Deserialize<'de> for Tagged<Or4<T1, T2, T3, T4>>
+where
+    T1: Deserialize<'de>,
+    T2: Deserialize<'de>,
+    T3: Deserialize<'de>,
+    T4: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = match Or4Owned::deserialize(deserializer)? {
+            Or4Owned::T1(t1) => Or4::T1(t1),
+            Or4Owned::T2(t2) => Or4::T2(t2),
+            Or4Owned::T3(t3) => Or4::T3(t3),
+            Or4Owned::T4(t4) => Or4::T4(t4),
+        };
+        Ok(Tagged(value))
+    }
+}
+
+#[derive(Serialize)]
+#[serde(tag = "variant", content = "value", rename = "Or5")]
+enum Or5Ref<'a, T1, T2, T3, T4, T5> {
+    T1(&'a T1),
+    T2(&'a T2),
+    T3(&'a T3),
+    T4(&'a T4),
+    T5(&'a T5),
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "variant", content = "value", rename = "Or5")]
+enum Or5Owned<T1, T2, T3, T4, T5> {
+    T1(T1),
+    T2(T2),
+    T3(T3),
+    T4(T4),
+    T5(T5),
+}
+
+/// Serializes the name of the active variant as `variant`, along with its value as `value`.
+impl<T1, T2, T3, T4, T5> Serialize for Tagged<Or5<T1, T2, T3, T4, T5>>
+where
+    T1: Serialize,
+    T2: Serialize,
+    T3: Serialize,
+    T4: Serialize,
+    T5: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match &self.0 {
+            Or5::T1(t1) => Or5Ref::T1(t1),
+            Or5::T2(t2) => Or5Ref::T2(t2),
+            Or5::T3(t3) => Or5Ref::T3(t3),
+            Or5::T4(t4) => Or5Ref::T4(t4),
+            Or5::T5(t5) => Or5Ref::T5(t5),
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Deserializes the value into the variant named by `variant`.
+impl<'de, T1, T2, T3, T4, T5> Deserialize<'de> for Tagged<Or5<T1, T2, T3, T4, T5>>
+where
+    T1: Deserialize<'de>,
+    T2: Deserialize<'de>,
+    T3: Deserialize<'de>,
+    T4: Deserialize<'de>,
+    T5: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = match Or5Owned::deserialize(deserializer)? {
+            Or5Owned::T1(t1) => Or5::T1(t1),
+            Or5Owned::T2(t2) => Or5::T2(t2),
+            Or5Owned::T3(t3) => Or5::T3(t3),
+            Or5Owned::T4(t4) => Or5::T4(t4),
+            Or5Owned::T5(t5) => Or5::T5(t5),
+        };
+        Ok(Tagged(value))
+    }
+}
+
+#[derive(Serialize)]
+#[serde(tag = "variant", content = "value", rename = "Or6")]
+enum Or6Ref<'a, T1, T2, T3, T4, T5, T6> {
+    T1(&'a T1),
+    T2(&'a T2),
+    T3(&'a T3),
+    T4(&'a T4),
+    T5(&'a T5),
+    T6(&'a T6),
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "variant", content = "value", rename = "Or6")]
+enum Or6Owned<T1, T2, T3, T4, T5, T6> {
+    T1(T1),
+    T2(T2),
+    T3(T3),
+    T4(T4),
+    T5(T5),
+    T6(T6),
+}
+
+/// Serializes the name of the active variant as `variant`, along with its value as `value`.
+impl<T1, T2, T3, T4, T5, T6> Serialize for Tagged<Or6<T1, T2, T3, T4, T5, T6>>
+where
+    T1: Serialize,
+    T2: Serialize,
+    T3: Serialize,
+    T4: Serialize,
+    T5: Serialize,
+    T6: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match &self.0 {
+            Or6::T1(t1) => Or6Ref::T1(t1),
+            Or6::T2(t2) => Or6Ref::T2(t2),
+            Or6::T3(t3) => Or6Ref::T3(t3),
+            Or6::T4(t4) => Or6Ref::T4(t4),
+            Or6::T5(t5) => Or6Ref::T5(t5),
+            Or6::T6(t6) => Or6Ref::T6(t6),
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Deserializes the value into the variant named by `variant`.
+impl<'de, T1, T2, T3, T4, T5, T6> Deserialize<'de> for Tagged<Or6<T1, T2, T3, T4, T5, T6>>
+where
+    T1: Deserialize<'de>,
+    T2: Deserialize<'de>,
+    T3: Deserialize<'de>,
+    T4: Deserialize<'de>,
+    T5: Deserialize<'de>,
+    T6: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = match Or6Owned::deserialize(deserializer)? {
+            Or6Owned::T1(t1) => Or6::T1(t1),
+            Or6Owned::T2(t2) => Or6::T2(t2),
+            Or6Owned::T3(t3) => Or6::T3(t3),
+            Or6Owned::T4(t4) => Or6::T4(t4),
+            Or6Owned::T5(t5) => Or6::T5(t5),
+            Or6Owned::T6(t6) => Or6::T6(t6),
+        };
+        Ok(Tagged(value))
+    }
+}
+
+#[derive(Serialize)]
+#[serde(tag = "variant", content = "value", rename = "Or7")]
+enum Or7Ref<'a, T1, T2, T3, T4, T5, T6, T7> {
+    T1(&'a T1),
+    T2(&'a T2),
+    T3(&'a T3),
+    T4(&'a T4),
+    T5(&'a T5),
+    T6(&'a T6),
+    T7(&'a T7),
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "variant", content = "value", rename = "Or7")]
+enum Or7Owned<T1, T2, T3, T4, T5, T6, T7> {
+    T1(T1),
+    T2(T2),
+    T3(T3),
+    T4(T4),
+    T5(T5),
+    T6(T6),
+    T7(T7),
+}
+
+/// Serializes the name of the active variant as `variant`, along with its value as `value`.
+impl<T1, T2, T3, T4, T5, T6, T7> Serialize for Tagged<Or7<T1, T2, T3, T4, T5, T6, T7>>
+where
+    T1: Serialize,
+    T2: Serialize,
+    T3: Serialize,
+    T4: Serialize,
+    T5: Serialize,
+    T6: Serialize,
+    T7: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match &self.0 {
+            Or7::T1(t1) => Or7Ref::T1(t1),
+            Or7::T2(t2) => Or7Ref::T2(t2),
+            Or7::T3(t3) => Or7Ref::T3(t3),
+            Or7::T4(t4) => Or7Ref::T4(t4),
+            Or7::T5(t5) => Or7Ref::T5(t5),
+            Or7::T6(t6) => Or7Ref::T6(t6),
+            Or7::T7(t7) => Or7Ref::T7(t7),
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Deserializes the value into the variant named by `variant`.
+impl<'de, T1, T2, T3, T4, T5, T6, T7> Deserialize<'de> for Tagged<Or7<T1, T2, T3, T4, T5, T6, T7>>
+where
+    T1: Deserialize<'de>,
+    T2: Deserialize<'de>,
+    T3: Deserialize<'de>,
+    T4: Deserialize<'de>,
+    T5: Deserialize<'de>,
+    T6: Deserialize<'de>,
+    T7: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = match Or7Owned::deserialize(deserializer)? {
+            Or7Owned::T1(t1) => Or7::T1(t1),
+            Or7Owned::T2(t2) => Or7::T2(t2),
+            Or7Owned::T3(t3) => Or7::T3(t3),
+            Or7Owned::T4(t4) => Or7::T4(t4),
+            Or7Owned::T5(t5) => Or7::T5(t5),
+            Or7Owned::T6(t6) => Or7::T6(t6),
+            Or7Owned::T7(t7) => Or7::T7(t7),
+        };
+        Ok(Tagged(value))
+    }
+}
+
+#[derive(Serialize)]
+#[serde(tag = "variant", content = "value", rename = "Or8")]
+enum Or8Ref<'a, T1, T2, T3, T4, T5, T6, T7, T8> {
+    T1(&'a T1),
+    T2(&'a T2),
+    T3(&'a T3),
+    T4(&'a T4),
+    T5(&'a T5),
+    T6(&'a T6),
+    T7(&'a T7),
+    T8(&'a T8),
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "variant", content = "value", rename = "Or8")]
+enum Or8Owned<T1, T2, T3, T4, T5, T6, T7, T8> {
+    T1(T1),
+    T2(T2),
+    T3(T3),
+    T4(T4),
+    T5(T5),
+    T6(T6),
+    T7(T7),
+    T8(T8),
+}
+
+/// Serializes the name of the active variant as `variant`, along with its value as `value`.
+impl<T1, T2, T3, T4, T5, T6, T7, T8> Serialize for Tagged<Or8<T1, T2, T3, T4, T5, T6, T7, T8>>
+where
+    T1: Serialize,
+    T2: Serialize,
+    T3: Serialize,
+    T4: Serialize,
+    T5: Serialize,
+    T6: Serialize,
+    T7: Serialize,
+    T8: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match &self.0 {
+            Or8::T1(t1) => Or8Ref::T1(t1),
+            Or8::T2(t2) => Or8Ref::T2(t2),
+            Or8::T3(t3) => Or8Ref::T3(t3),
+            Or8::T4(t4) => Or8Ref::T4(t4),
+            Or8::T5(t5) => Or8Ref::T5(t5),
+            Or8::T6(t6) => Or8Ref::T6(t6),
+            Or8::T7(t7) => Or8Ref::T7(t7),
+            Or8::T8(t8) => Or8Ref::T8(t8),
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Deserializes the value into the variant named by `variant`.
+impl<'de, T1, T2, T3, T4, T5, T6, T7, T8> Deserialize<'de>
+    for Tagged<Or8<T1, T2, T3, T4, T5, T6, T7, T8>>
+where
+    T1: Deserialize<'de>,
+    T2: Deserialize<'de>,
+    T3: Deserialize<'de>,
+    T4: Deserialize<'de>,
+    T5: Deserialize<'de>,
+    T6: Deserialize<'de>,
+    T7: Deserialize<'de>,
+    T8: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = match Or8Owned::deserialize(deserializer)? {
+            Or8Owned::T1(t1) => Or8::T1(t1),
+            Or8Owned::T2(t2) => Or8::T2(t2),
+            Or8Owned::T3(t3) => Or8::T3(t3),
+            Or8Owned::T4(t4) => Or8::T4(t4),
+            Or8Owned::T5(t5) => Or8::T5(t5),
+            Or8Owned::T6(t6) => Or8::T6(t6),
+            Or8Owned::T7(t7) => Or8::T7(t7),
+            Or8Owned::T8(t8) => Or8::T8(t8),
+        };
+        Ok(Tagged(value))
+    }
+}
+
+#[derive(Serialize)]
+#[serde(tag = "variant", content = "value", rename = "Or9")]
+enum Or9Ref<'a, T1, T2, T3, T4, T5, T6, T7, T8, T9> {
+    T1(&'a T1),
+    T2(&'a T2),
+    T3(&'a T3),
+    T4(&'a T4),
+    T5(&'a T5),
+    T6(&'a T6),
+    T7(&'a T7),
+    T8(&'a T8),
+    T9(&'a T9),
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "variant", content = "value", rename = "Or9")]
+enum Or9Owned<T1, T2, T3, T4, T5, T6, T7, T8, T9> {
+    T1(T1),
+    T2(T2),
+    T3(T3),
+    T4(T4),
+    T5(T5),
+    T6(T6),
+    T7(T7),
+    T8(T8),
+    T9(T9),
+}
+
+/// Serializes the name of the active variant as `variant`, along with its value as `value`.
+impl<T1, T2, T3, T4, T5, T6, T7, T8, T9> Serialize
+    for Tagged<Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>>
+where
+    T1: Serialize,
+    T2: Serialize,
+    T3: Serialize,
+    T4: Serialize,
+    T5: Serialize,
+    T6: Serialize,
+    T7: Serialize,
+    T8: Serialize,
+    T9: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match &self.0 {
+            Or9::T1(t1) => Or9Ref::T1(t1),
+            Or9::T2(t2) => Or9Ref::T2(t2),
+            Or9::T3(t3) => Or9Ref::T3(t3),
+            Or9::T4(t4) => Or9Ref::T4(t4),
+            Or9::T5(t5) => Or9Ref::T5(t5),
+            Or9::T6(t6) => Or9Ref::T6(t6),
+            Or9::T7(t7) => Or9Ref::T7(t7),
+            Or9::T8(t8) => Or9Ref::T8(t8),
+            Or9::T9(t9) => Or9Ref::T9(t9),
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Deserializes the value into the variant named by `variant`.
+impl<'de, T1, T2, T3, T4, T5, T6, T7, T8, T9> Deserialize<'de>
+    for Tagged<Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>>
+where
+    T1: Deserialize<'de>,
+    T2: Deserialize<'de>,
+    T3: Deserialize<'de>,
+    T4: Deserialize<'de>,
+    T5: Deserialize<'de>,
+    T6: Deserialize<'de>,
+    T7: Deserialize<'de>,
+    T8: Deserialize<'de>,
+    T9: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = match Or9Owned::deserialize(deserializer)? {
+            Or9Owned::T1(t1) => Or9::T1(t1),
+            Or9Owned::T2(t2) => Or9::T2(t2),
+            Or9Owned::T3(t3) => Or9::T3(t3),
+            Or9Owned::T4(t4) => Or9::T4(t4),
+            Or9Owned::T5(t5) => Or9::T5(t5),
+            Or9Owned::T6(t6) => Or9::T6(t6),
+            Or9Owned::T7(t7) => Or9::T7(t7),
+            Or9Owned::T8(t8) => Or9::T8(t8),
+            Or9Owned::T9(t9) => Or9::T9(t9),
+        };
+        Ok(Tagged(value))
+    }
+}
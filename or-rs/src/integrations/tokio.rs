@@ -0,0 +1,1284 @@
+//! `tokio` I/O of the `Or` types, enabled by the `tokio` feature.
+//!
+//! The I/O traits are forwarded to the I/O object of the active variant, so a connection
+//! chosen at runtime, like a `TcpStream` or a `UnixStream`, can be held as a single type.
+
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use tokio::io::{AsyncBufRead, AsyncRead, AsyncSeek, AsyncWrite, ReadBuf};
+
+use crate::enums::*;
+
+/// Reads from the I/O object of the active variant.
+impl<T1, T2> AsyncRead for Or2<T1, T2>
+where
+    T1: AsyncRead,
+    T2: AsyncRead,
+{
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        match self.as_pin_mut() {
+            Or2::T1(t1) => t1.poll_read(cx, buf),
+            Or2::T2(t2) => t2.poll_read(cx, buf),
+        }
+    }
+}
+
+/// Reads from the buffered I/O object of the active variant.
+impl<T1, T2> AsyncBufRead for Or2<T1, T2>
+where
+    T1: AsyncBufRead,
+    T2: AsyncBufRead,
+{
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        match self.as_pin_mut() {
+            Or2::T1(t1) => t1.poll_fill_buf(cx),
+            Or2::T2(t2) => t2.poll_fill_buf(cx),
+        }
+    }
+
+    fn consume(self: Pin<&mut Self>, amt: usize) {
+        match self.as_pin_mut() {
+            Or2::T1(t1) => t1.consume(amt),
+            Or2::T2(t2) => t2.consume(amt),
+        }
+    }
+}
+
+/// Writes to the I/O object of the active variant.
+impl<T1, T2> AsyncWrite for Or2<T1, T2>
+where
+    T1: AsyncWrite,
+    T2: AsyncWrite,
+{
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        match self.as_pin_mut() {
+            Or2::T1(t1) => t1.poll_write(cx, buf),
+            Or2::T2(t2) => t2.poll_write(cx, buf),
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match self.as_pin_mut() {
+            Or2::T1(t1) => t1.poll_flush(cx),
+            Or2::T2(t2) => t2.poll_flush(cx),
+        }
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match self.as_pin_mut() {
+            Or2::T1(t1) => t1.poll_shutdown(cx),
+            Or2::T2(t2) => t2.poll_shutdown(cx),
+        }
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[io::IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        match self.as_pin_mut() {
+            Or2::T1(t1) => t1.poll_write_vectored(cx, bufs),
+            Or2::T2(t2) => t2.poll_write_vectored(cx, bufs),
+        }
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        match self {
+            Self::T1(t1) => t1.is_write_vectored(),
+            Self::T2(t2) => t2.is_write_vectored(),
+        }
+    }
+}
+
+/// Seeks the I/O object of the active variant.
+impl<T1, T2> AsyncSeek for Or2<T1, T2>
+where
+    T1: AsyncSeek,
+    T2: AsyncSeek,
+{
+    fn start_seek(self: Pin<&mut Self>, position: io::SeekFrom) -> io::Result<()> {
+        match self.as_pin_mut() {
+            Or2::T1(t1) => t1.start_seek(position),
+            Or2::T2(t2) => t2.start_seek(position),
+        }
+    }
+
+    fn poll_complete(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<u64>> {
+        match self.as_pin_mut() {
+            Or2::T1(t1) => t1.poll_complete(cx),
+            Or2::T2(t2) => t2.poll_complete(cx),
+        }
+    }
+}
+
+/// Reads from the I/O object of the active variant.
+impl<T1, T2, T3> AsyncRead for Or3<T1, T2, T3>
+where
+    T1: AsyncRead,
+    T2: AsyncRead,
+    T3: AsyncRead,
+{
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        match self.as_pin_mut() {
+            Or3::T1(t1) => t1.poll_read(cx, buf),
+            Or3::T2(t2) => t2.poll_read(cx, buf),
+            Or3::T3(t3) => t3.poll_read(cx, buf),
+        }
+    }
+}
+
+/// Reads from the buffered I/O object of the active variant.
+impl<T1, T2, T3> AsyncBufRead for Or3<T1, T2, T3>
+where
+    T1: AsyncBufRead,
+    T2: AsyncBufRead,
+    T3: AsyncBufRead,
+{
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        match self.as_pin_mut() {
+            Or3::T1(t1) => t1.poll_fill_buf(cx),
+            Or3::T2(t2) => t2.poll_fill_buf(cx),
+            Or3::T3(t3) => t3.poll_fill_buf(cx),
+        }
+    }
+
+    fn consume(self: Pin<&mut Self>, amt: usize) {
+        match self.as_pin_mut() {
+            Or3::T1(t1) => t1.consume(amt),
+            Or3::T2(t2) => t2.consume(amt),
+            Or3::T3(t3) => t3.consume(amt),
+        }
+    }
+}
+
+/// Writes to the I/O object of the active variant.
+impl<T1, T2, T3> AsyncWrite for Or3<T1, T2, T3>
+where
+    T1: AsyncWrite,
+    T2: AsyncWrite,
+    T3: AsyncWrite,
+{
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        match self.as_pin_mut() {
+            Or3::T1(t1) => t1.poll_write(cx, buf),
+            Or3::T2(t2) => t2.poll_write(cx, buf),
+            Or3::T3(t3) => t3.poll_write(cx, buf),
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match self.as_pin_mut() {
+            Or3::T1(t1) => t1.poll_flush(cx),
+            Or3::T2(t2) => t2.poll_flush(cx),
+            Or3::T3(t3) => t3.poll_flush(cx),
+        }
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match self.as_pin_mut() {
+            Or3::T1(t1) => t1.poll_shutdown(cx),
+            Or3::T2(t2) => t2.poll_shutdown(cx),
+            Or3::T3(t3) => t3.poll_shutdown(cx),
+        }
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[io::IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        match self.as_pin_mut() {
+            Or3::T1(t1) => t1.poll_write_vectored(cx, bufs),
+            Or3::T2(t2) => t2.poll_write_vectored(cx, bufs),
+            Or3::T3(t3) => t3.poll_write_vectored(cx, bufs),
+        }
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        match self {
+            Self::T1(t1) => t1.is_write_vectored(),
+            Self::T2(t2) => t2.is_write_vectored(),
+            Self::T3(t3) => t3.is_write_vectored(),
+        }
+    }
+}
+
+/// Seeks the I/O object of the active variant.
+impl<T1, T2, T3> AsyncSeek for Or3<T1, T2, T3>
+where
+    T1: AsyncSeek,
+    T2: AsyncSeek,
+    T3: AsyncSeek,
+{
+    fn start_seek(self: Pin<&mut Self>, position: io::SeekFrom) -> io::Result<()> {
+        match self.as_pin_mut() {
+            Or3::T1(t1) => t1.start_seek(position),
+            Or3::T2(t2) => t2.start_seek(position),
+            Or3::T3(t3) => t3.start_seek(position),
+        }
+    }
+
+    fn poll_complete(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<u64>> {
+        match self.as_pin_mut() {
+            Or3::T1(t1) => t1.poll_complete(cx),
+            Or3::T2(t2) => t2.poll_complete(cx),
+            Or3::T3(t3) => t3.poll_complete(cx),
+        }
+    }
+}
+
+/// Reads from the I/O object of the active variant.
+impl<T1, T2, T3, T4> AsyncRead for Or4<T1, T2, T3, T4>
+where
+    T1: AsyncRead,
+    T2: AsyncRead,
+    T3: AsyncRead,
+    T4: AsyncRead,
+{
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        match self.as_pin_mut() {
+            Or4::T1(t1) => t1.poll_read(cx, buf),
+            Or4::T2(t2) => t2.poll_read(cx, buf),
+            Or4::T3(t3) => t3.poll_read(cx, buf),
+            Or4::T4(t4) => t4.poll_read(cx, buf),
+        }
+    }
+}
+
+/// Reads from the buffered I/O object of the active variant.
+impl<T1, T2, T3, T4> AsyncBufRead for Or4<T1, T2, T3, T4>
+where
+    T1: AsyncBufRead,
+    T2: AsyncBufRead,
+    T3: AsyncBufRead,
+    T4: AsyncBufRead,
+{
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        match self.as_pin_mut() {
+            Or4::T1(t1) => t1.poll_fill_buf(cx),
+            Or4::T2(t2) => t2.poll_fill_buf(cx),
+            Or4::T3(t3) => t3.poll_fill_buf(cx),
+            Or4::T4(t4) => t4.poll_fill_buf(cx),
+        }
+    }
+
+    fn consume(self: Pin<&mut Self>, amt: usize) {
+        match self.as_pin_mut() {
+            Or4::T1(t1) => t1.consume(amt),
+            Or4::T2(t2) => t2.consume(amt),
+            Or4::T3(t3) => t3.consume(amt),
+            Or4::T4(t4) => t4.consume(amt),
+        }
+    }
+}
+
+/// Writes to the I/O object of the active variant.
+impl<T1, T2, T3, T4> AsyncWrite for Or4<T1, T2, T3, T4>
+where
+    T1: AsyncWrite,
+    T2: AsyncWrite,
+    T3: AsyncWrite,
+    T4: AsyncWrite,
+{
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        match self.as_pin_mut() {
+            Or4::T1(t1) => t1.poll_write(cx, buf),
+            Or4::T2(t2) => t2.poll_write(cx, buf),
+            Or4::T3(t3) => t3.poll_write(cx, buf),
+            Or4::T4(t4) => t4.poll_write(cx, buf),
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match self.as_pin_mut() {
+            Or4::T1(t1) => t1.poll_flush(cx),
+            Or4::T2(t2) => t2.poll_flush(cx),
+            Or4::T3(t3) => t3.poll_flush(cx),
+            Or4::T4(t4) => t4.poll_flush(cx),
+        }
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match self.as_pin_mut() {
+            Or4::T1(t1) => t1.poll_shutdown(cx),
+            Or4::T2(t2) => t2.poll_shutdown(cx),
+            Or4::T3(t3) => t3.poll_shutdown(cx),
+            Or4::T4(t4) => t4.poll_shutdown(cx),
+        }
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[io::IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        match self.as_pin_mut() {
+            Or4::T1(t1) => t1.poll_write_vectored(cx, bufs),
+            Or4::T2(t2) => t2.poll_write_vectored(cx, bufs),
+            Or4::T3(t3) => t3.poll_write_vectored(cx, bufs),
+            Or4::T4(t4) => t4.poll_write_vectored(cx, bufs),
+        }
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        match self {
+            Self::T1(t1) => t1.is_write_vectored(),
+            Self::T2(t2) => t2.is_write_vectored(),
+            Self::T3(t3) => t3.is_write_vectored(),
+            Self::T4(t4) => t4.is_write_vectored(),
+        }
+    }
+}
+
+/// Seeks the I/O object of the active variant.
+impl<T1, T2, T3, T4> AsyncSeek for Or4<T1, T2, T3, T4>
+where
+    T1: AsyncSeek,
+    T2: AsyncSeek,
+    T3: AsyncSeek,
+    T4: AsyncSeek,
+{
+    fn start_seek(self: Pin<&mut Self>, position: io::SeekFrom) -> io::Result<()> {
+        match self.as_pin_mut() {
+            Or4::T1(t1) => t1.start_seek(position),
+            Or4::T2(t2) => t2.start_seek(position),
+            Or4::T3(t3) => t3.start_seek(position),
+            Or4::T4(t4) => t4.start_seek(position),
+        }
+    }
+
+    fn poll_complete(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<u64>> {
+        match self.as_pin_mut() {
+            Or4::T1(t1) => t1.poll_complete(cx),
+            Or4::T2(t2) => t2.poll_complete(cx),
+            Or4::T3(t3) => t3.poll_complete(cx),
+            Or4::T4(t4) => t4.poll_complete(cx),
+        }
+    }
+}
+
+/// Reads from the I/O object of the active variant.
+impl<T1, T2, T3, T4, T5> AsyncRead for Or5<T1, T2, T3, T4, T5>
+where
+    T1: AsyncRead,
+    T2: AsyncRead,
+    T3: AsyncRead,
+    T4: AsyncRead,
+    T5: AsyncRead,
+{
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        match self.as_pin_mut() {
+            Or5::T1(t1) => t1.poll_read(cx, buf),
+            Or5::T2(t2) => t2.poll_read(cx, buf),
+            Or5::T3(t3) => t3.poll_read(cx, buf),
+            Or5::T4(t4) => t4.poll_read(cx, buf),
+            Or5::T5(t5) => t5.poll_read(cx, buf),
+        }
+    }
+}
+
+/// Reads from the buffered I/O object of the active variant.
+impl<T1, T2, T3, T4, T5> AsyncBufRead for Or5<T1, T2, T3, T4, T5>
+where
+    T1: AsyncBufRead,
+    T2: AsyncBufRead,
+    T3: AsyncBufRead,
+    T4: AsyncBufRead,
+    T5: AsyncBufRead,
+{
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        match self.as_pin_mut() {
+            Or5::T1(t1) => t1.poll_fill_buf(cx),
+            Or5::T2(t2) => t2.poll_fill_buf(cx),
+            Or5::T3(t3) => t3.poll_fill_buf(cx),
+            Or5::T4(t4) => t4.poll_fill_buf(cx),
+            Or5::T5(t5) => t5.poll_fill_buf(cx),
+        }
+    }
+
+    fn consume(self: Pin<&mut Self>, amt: usize) {
+        match self.as_pin_mut() {
+            Or5::T1(t1) => t1.consume(amt),
+            Or5::T2(t2) => t2.consume(amt),
+            Or5::T3(t3) => t3.consume(amt),
+            Or5::T4(t4) => t4.consume(amt),
+            Or5::T5(t5) => t5.consume(amt),
+        }
+    }
+}
+
+/// Writes to the I/O object of the active variant.
+impl<T1, T2, T3, T4, T5> AsyncWrite for Or5<T1, T2, T3, T4, T5>
+where
+    T1: AsyncWrite,
+    T2: AsyncWrite,
+    T3: AsyncWrite,
+    T4: AsyncWrite,
+    T5: AsyncWrite,
+{
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        match self.as_pin_mut() {
+            Or5::T1(t1) => t1.poll_write(cx, buf),
+            Or5::T2(t2) => t2.poll_write(cx, buf),
+            Or5::T3(t3) => t3.poll_write(cx, buf),
+            Or5::T4(t4) => t4.poll_write(cx, buf),
+            Or5::T5(t5) => t5.poll_write(cx, buf),
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match self.as_pin_mut() {
+            Or5::T1(t1) => t1.poll_flush(cx),
+            Or5::T2(t2) => t2.poll_flush(cx),
+            Or5::T3(t3) => t3.poll_flush(cx),
+            Or5::T4(t4) => t4.poll_flush(cx),
+            Or5::T5(t5) => t5.poll_flush(cx),
+        }
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match self.as_pin_mut() {
+            Or5::T1(t1) => t1.poll_shutdown(cx),
+            Or5::T2(t2) => t2.poll_shutdown(cx),
+            Or5::T3(t3) => t3.poll_shutdown(cx),
+            Or5::T4(t4) => t4.poll_shutdown(cx),
+            Or5::T5(t5) => t5.poll_shutdown(cx),
+        }
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[io::IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        match self.as_pin_mut() {
+            Or5::T1(t1) => t1.poll_write_vectored(cx, bufs),
+            Or5::T2(t2) => t2.poll_write_vectored(cx, bufs),
+            Or5::T3(t3) => t3.poll_write_vectored(cx, bufs),
+            Or5::T4(t4) => t4.poll_write_vectored(cx, bufs),
+            Or5::T5(t5) => t5.poll_write_vectored(cx, bufs),
+        }
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        match self {
+            Self::T1(t1) => t1.is_write_vectored(),
+            Self::T2(t2) => t2.is_write_vectored(),
+            Self::T3(t3) => t3.is_write_vectored(),
+            Self::T4(t4) => t4.is_write_vectored(),
+            Self::T5(t5) => t5.is_write_vectored(),
+        }
+    }
+}
+
+/// Seeks the I/O object of the active variant.
+impl<T1, T2, T3, T4, T5> AsyncSeek for Or5<T1, T2, T3, T4, T5>
+where
+    T1: AsyncSeek,
+    T2: AsyncSeek,
+    T3: AsyncSeek,
+    T4: AsyncSeek,
+    T5: AsyncSeek,
+{
+    fn start_seek(self: Pin<&mut Self>, position: io::SeekFrom) -> io::Result<()> {
+        match self.as_pin_mut() {
+            Or5::T1(t1) => t1.start_seek(position),
+            Or5::T2(t2) => t2.start_seek(position),
+            Or5::T3(t3) => t3.start_seek(position),
+            Or5::T4(t4) => t4.start_seek(position),
+            Or5::T5(t5) => t5.start_seek(position),
+        }
+    }
+
+    fn poll_complete(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<u64>> {
+        match self.as_pin_mut() {
+            Or5::T1(t1) => t1.poll_complete(cx),
+            Or5::T2(t2) => t2.poll_complete(cx),
+            Or5::T3(t3) => t3.poll_complete(cx),
+            Or5::T4(t4) => t4.poll_complete(cx),
+            Or5::T5(t5) => t5.poll_complete(cx),
+        }
+    }
+}
+
+/// Reads from the I/O object of the active variant.
+impl<T1, T2, T3, T4, T5, T6> AsyncRead for Or6<T1, T2, T3, T4, T5, T6>
+where
+    T1: AsyncRead,
+    T2: AsyncRead,
+    T3: AsyncRead,
+    T4: AsyncRead,
+    T5: AsyncRead,
+    T6: AsyncRead,
+{
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        match self.as_pin_mut() {
+            Or6::T1(t1) => t1.poll_read(cx, buf),
+            Or6::T2(t2) => t2.poll_read(cx, buf),
+            Or6::T3(t3) => t3.poll_read(cx, buf),
+            Or6::T4(t4) => t4.poll_read(cx, buf),
+            Or6::T5(t5) => t5.poll_read(cx, buf),
+            Or6::T6(t6) => t6.poll_read(cx, buf),
+        }
+    }
+}
+
+/// Reads from the buffered I/O object of the active variant.
+impl<T1, T2, T3, T4, T5, T6> AsyncBufRead for Or6<T1, T2, T3, T4, T5, T6>
+where
+    T1: AsyncBufRead,
+    T2: AsyncBufRead,
+    T3: AsyncBufRead,
+    T4: AsyncBufRead,
+    T5: AsyncBufRead,
+    T6: AsyncBufRead,
+{
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        match self.as_pin_mut() {
+            Or6::T1(t1) => t1.poll_fill_buf(cx),
+            Or6::T2(t2) => t2.poll_fill_buf(cx),
+            Or6::T3(t3) => t3.poll_fill_buf(cx),
+            Or6::T4(t4) => t4.poll_fill_buf(cx),
+            Or6::T5(t5) => t5.poll_fill_buf(cx),
+            Or6::T6(t6) => t6.poll_fill_buf(cx),
+        }
+    }
+
+    fn consume(self: Pin<&mut Self>, amt: usize) {
+        match self.as_pin_mut() {
+            Or6::T1(t1) => t1.consume(amt),
+            Or6::T2(t2) => t2.consume(amt),
+            Or6::T3(t3) => t3.consume(amt),
+            Or6::T4(t4) => t4.consume(amt),
+            Or6::T5(t5) => t5.consume(amt),
+            Or6::T6(t6) => t6.consume(amt),
+        }
+    }
+}
+
+/// Writes to the I/O object of the active variant.
+impl<T1, T2, T3, T4, T5, T6> AsyncWrite for Or6<T1, T2, T3, T4, T5, T6>
+where
+    T1: AsyncWrite,
+    T2: AsyncWrite,
+    T3: AsyncWrite,
+    T4: AsyncWrite,
+    T5: AsyncWrite,
+    T6: AsyncWrite,
+{
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        match self.as_pin_mut() {
+            Or6::T1(t1) => t1.poll_write(cx, buf),
+            Or6::T2(t2) => t2.poll_write(cx, buf),
+            Or6::T3(t3) => t3.poll_write(cx, buf),
+            Or6::T4(t4) => t4.poll_write(cx, buf),
+            Or6::T5(t5) => t5.poll_write(cx, buf),
+            Or6::T6(t6) => t6.poll_write(cx, buf),
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match self.as_pin_mut() {
+            Or6::T1(t1) => t1.poll_flush(cx),
+            Or6::T2(t2) => t2.poll_flush(cx),
+            Or6::T3(t3) => t3.poll_flush(cx),
+            Or6::T4(t4) => t4.poll_flush(cx),
+            Or6::T5(t5) => t5.poll_flush(cx),
+            Or6::T6(t6) => t6.poll_flush(cx),
+        }
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match self.as_pin_mut() {
+            Or6::T1(t1) => t1.poll_shutdown(cx),
+            Or6::T2(t2) => t2.poll_shutdown(cx),
+            Or6::T3(t3) => t3.poll_shutdown(cx),
+            Or6::T4(t4) => t4.poll_shutdown(cx),
+            Or6::T5(t5) => t5.poll_shutdown(cx),
+            Or6::T6(t6) => t6.poll_shutdown(cx),
+        }
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[io::IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        match self.as_pin_mut() {
+            Or6::T1(t1) => t1.poll_write_vectored(cx, bufs),
+            Or6::T2(t2) => t2.poll_write_vectored(cx, bufs),
+            Or6::T3(t3) => t3.poll_write_vectored(cx, bufs),
+            Or6::T4(t4) => t4.poll_write_vectored(cx, bufs),
+            Or6::T5(t5) => t5.poll_write_vectored(cx, bufs),
+            Or6::T6(t6) => t6.poll_write_vectored(cx, bufs),
+        }
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        match self {
+            Self::T1(t1) => t1.is_write_vectored(),
+            Self::T2(t2) => t2.is_write_vectored(),
+            Self::T3(t3) => t3.is_write_vectored(),
+            Self::T4(t4) => t4.is_write_vectored(),
+            Self::T5(t5) => t5.is_write_vectored(),
+            Self::T6(t6) => t6.is_write_vectored(),
+        }
+    }
+}
+
+/// Seeks the I/O object of the active variant.
+impl<T1, T2, T3, T4, T5, T6> AsyncSeek for Or6<T1, T2, T3, T4, T5, T6>
+where
+    T1: AsyncSeek,
+    T2: AsyncSeek,
+    T3: AsyncSeek,
+    T4: AsyncSeek,
+    T5: AsyncSeek,
+    T6: AsyncSeek,
+{
+    fn start_seek(self: Pin<&mut Self>, position: io::SeekFrom) -> io::Result<()> {
+        match self.as_pin_mut() {
+            Or6::T1(t1) => t1.start_seek(position),
+            Or6::T2(t2) => t2.start_seek(position),
+            Or6::T3(t3) => t3.start_seek(position),
+            Or6::T4(t4) => t4.start_seek(position),
+            Or6::T5(t5) => t5.start_seek(position),
+            Or6::T6(t6) => t6.start_seek(position),
+        }
+    }
+
+    fn poll_complete(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<u64>> {
+        match self.as_pin_mut() {
+            Or6::T1(t1) => t1.poll_complete(cx),
+            Or6::T2(t2) => t2.poll_complete(cx),
+            Or6::T3(t3) => t3.poll_complete(cx),
+            Or6::T4(t4) => t4.poll_complete(cx),
+            Or6::T5(t5) => t5.poll_complete(cx),
+            Or6::T6(t6) => t6.poll_complete(cx),
+        }
+    }
+}
+
+/// Reads from the I/O object of the active variant.
+impl<T1, T2, T3, T4, T5, T6, T7> AsyncRead for Or7<T1, T2, T3, T4, T5, T6, T7>
+where
+    T1: AsyncRead,
+    T2: AsyncRead,
+    T3: AsyncRead,
+    T4: AsyncRead,
+    T5: AsyncRead,
+    T6: AsyncRead,
+    T7: AsyncRead,
+{
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        match self.as_pin_mut() {
+            Or7::T1(t1) => t1.poll_read(cx, buf),
+            Or7::T2(t2) => t2.poll_read(cx, buf),
+            Or7::T3(t3) => t3.poll_read(cx, buf),
+            Or7::T4(t4) => t4.poll_read(cx, buf),
+            Or7::T5(t5) => t5.poll_read(cx, buf),
+            Or7::T6(t6) => t6.poll_read(cx, buf),
+            Or7::T7(t7) => t7.poll_read(cx, buf),
+        }
+    }
+}
+
+/// Reads from the buffered I/O object of the active variant.
+impl<T1, T2, T3, T4, T5, T6, T7> AsyncBufRead for Or7<T1, T2, T3, T4, T5, T6, T7>
+where
+    T1: AsyncBufRead,
+    T2: AsyncBufRead,
+    T3: AsyncBufRead,
+    T4: AsyncBufRead,
+    T5: AsyncBufRead,
+    T6: AsyncBufRead,
+    T7: AsyncBufRead,
+{
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        match self.as_pin_mut() {
+            Or7::T1(t1) => t1.poll_fill_buf(cx),
+            Or7::T2(t2) => t2.poll_fill_buf(cx),
+            Or7::T3(t3) => t3.poll_fill_buf(cx),
+            Or7::T4(t4) => t4.poll_fill_buf(cx),
+            Or7::T5(t5) => t5.poll_fill_buf(cx),
+            Or7::T6(t6) => t6.poll_fill_buf(cx),
+            Or7::T7(t7) => t7.poll_fill_buf(cx),
+        }
+    }
+
+    fn consume(self: Pin<&mut Self>, amt: usize) {
+        match self.as_pin_mut() {
+            Or7::T1(t1) => t1.consume(amt),
+            Or7::T2(t2) => t2.consume(amt),
+            Or7::T3(t3) => t3.consume(amt),
+            Or7::T4(t4) => t4.consume(amt),
+            Or7::T5(t5) => t5.consume(amt),
+            Or7::T6(t6) => t6.consume(amt),
+            Or7::T7(t7) => t7.consume(amt),
+        }
+    }
+}
+
+/// Writes to the I/O object of the active variant.
+impl<T1, T2, T3, T4, T5, T6, T7> AsyncWrite for Or7<T1, T2, T3, T4, T5, T6, T7>
+where
+    T1: AsyncWrite,
+    T2: AsyncWrite,
+    T3: AsyncWrite,
+    T4: AsyncWrite,
+    T5: AsyncWrite,
+    T6: AsyncWrite,
+    T7: AsyncWrite,
+{
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        match self.as_pin_mut() {
+            Or7::T1(t1) => t1.poll_write(cx, buf),
+            Or7::T2(t2) => t2.poll_write(cx, buf),
+            Or7::T3(t3) => t3.poll_write(cx, buf),
+            Or7::T4(t4) => t4.poll_write(cx, buf),
+            Or7::T5(t5) => t5.poll_write(cx, buf),
+            Or7::T6(t6) => t6.poll_write(cx, buf),
+            Or7::T7(t7) => t7.poll_write(cx, buf),
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match self.as_pin_mut() {
+            Or7::T1(t1) => t1.poll_flush(cx),
+            Or7::T2(t2) => t2.poll_flush(cx),
+            Or7::T3(t3) => t3.poll_flush(cx),
+            Or7::T4(t4) => t4.poll_flush(cx),
+            Or7::T5(t5) => t5.poll_flush(cx),
+            Or7::T6(t6) => t6.poll_flush(cx),
+            Or7::T7(t7) => t7.poll_flush(cx),
+        }
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match self.as_pin_mut() {
+            Or7::T1(t1) => t1.poll_shutdown(cx),
+            Or7::T2(t2) => t2.poll_shutdown(cx),
+            Or7::T3(t3) => t3.poll_shutdown(cx),
+            Or7::T4(t4) => t4.poll_shutdown(cx),
+            Or7::T5(t5) => t5.poll_shutdown(cx),
+            Or7::T6(t6) => t6.poll_shutdown(cx),
+            Or7::T7(t7) => t7.poll_shutdown(cx),
+        }
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[io::IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        match self.as_pin_mut() {
+            Or7::T1(t1) => t1.poll_write_vectored(cx, bufs),
+            Or7::T2(t2) => t2.poll_write_vectored(cx, bufs),
+            Or7::T3(t3) => t3.poll_write_vectored(cx, bufs),
+            Or7::T4(t4) => t4.poll_write_vectored(cx, bufs),
+            Or7::T5(t5) => t5.poll_write_vectored(cx, bufs),
+            Or7::T6(t6) => t6.poll_write_vectored(cx, bufs),
+            Or7::T7(t7) => t7.poll_write_vectored(cx, bufs),
+        }
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        match self {
+            Self::T1(t1) => t1.is_write_vectored(),
+            Self::T2(t2) => t2.is_write_vectored(),
+            Self::T3(t3) => t3.is_write_vectored(),
+            Self::T4(t4) => t4.is_write_vectored(),
+            Self::T5(t5) => t5.is_write_vectored(),
+            Self::T6(t6) => t6.is_write_vectored(),
+            Self::T7(t7) => t7.is_write_vectored(),
+        }
+    }
+}
+
+/// Seeks the I/O object of the active variant.
+impl<T1, T2, T3, T4, T5, T6, T7> AsyncSeek for Or7<T1, T2, T3, T4, T5, T6, T7>
+where
+    T1: AsyncSeek,
+    T2: AsyncSeek,
+    T3: AsyncSeek,
+    T4: AsyncSeek,
+    T5: AsyncSeek,
+    T6: AsyncSeek,
+    T7: AsyncSeek,
+{
+    fn start_seek(self: Pin<&mut Self>, position: io::SeekFrom) -> io::Result<()> {
+        match self.as_pin_mut() {
+            Or7::T1(t1) => t1.start_seek(position),
+            Or7::T2(t2) => t2.start_seek(position),
+            Or7::T3(t3) => t3.start_seek(position),
+            Or7::T4(t4) => t4.start_seek(position),
+            Or7::T5(t5) => t5.start_seek(position),
+            Or7::T6(t6) => t6.start_seek(position),
+            Or7::T7(t7) => t7.start_seek(position),
+        }
+    }
+
+    fn poll_complete(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<u64>> {
+        match self.as_pin_mut() {
+            Or7::T1(t1) => t1.poll_complete(cx),
+            Or7::T2(t2) => t2.poll_complete(cx),
+            Or7::T3(t3) => t3.poll_complete(cx),
+            Or7::T4(t4) => t4.poll_complete(cx),
+            Or7::T5(t5) => t5.poll_complete(cx),
+            Or7::T6(t6) => t6.poll_complete(cx),
+            Or7::T7(t7) => t7.poll_complete(cx),
+        }
+    }
+}
+
+/// Reads from the I/O object of the active variant.
+impl<T1, T2, T3, T4, T5, T6, T7, T8> AsyncRead for Or8<T1, T2, T3, T4, T5, T6, T7, T8>
+where
+    T1: AsyncRead,
+    T2: AsyncRead,
+    T3: AsyncRead,
+    T4: AsyncRead,
+    T5: AsyncRead,
+    T6: AsyncRead,
+    T7: AsyncRead,
+    T8: AsyncRead,
+{
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        match self.as_pin_mut() {
+            Or8::T1(t1) => t1.poll_read(cx, buf),
+            Or8::T2(t2) => t2.poll_read(cx, buf),
+            Or8::T3(t3) => t3.poll_read(cx, buf),
+            Or8::T4(t4) => t4.poll_read(cx, buf),
+            Or8::T5(t5) => t5.poll_read(cx, buf),
+            Or8::T6(t6) => t6.poll_read(cx, buf),
+            Or8::T7(t7) => t7.poll_read(cx, buf),
+            Or8::T8(t8) => t8.poll_read(cx, buf),
+        }
+    }
+}
+
+/// Reads from the buffered I/O object of the active variant.
+impl<T1, T2, T3, T4, T5, T6, T7, T8> AsyncBufRead for Or8<T1, T2, T3, T4, T5, T6, T7, T8>
+where
+    T1: AsyncBufRead,
+    T2: AsyncBufRead,
+    T3: AsyncBufRead,
+    T4: AsyncBufRead,
+    T5: AsyncBufRead,
+    T6: AsyncBufRead,
+    T7: AsyncBufRead,
+    T8: AsyncBufRead,
+{
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        match self.as_pin_mut() {
+            Or8::T1(t1) => t1.poll_fill_buf(cx),
+            Or8::T2(t2) => t2.poll_fill_buf(cx),
+            Or8::T3(t3) => t3.poll_fill_buf(cx),
+            Or8::T4(t4) => t4.poll_fill_buf(cx),
+            Or8::T5(t5) => t5.poll_fill_buf(cx),
+            Or8::T6(t6) => t6.poll_fill_buf(cx),
+            Or8::T7(t7) => t7.poll_fill_buf(cx),
+            Or8::T8(t8) => t8.poll_fill_buf(cx),
+        }
+    }
+
+    fn consume(self: Pin<&mut Self>, amt: usize) {
+        match self.as_pin_mut() {
+            Or8::T1(t1) => t1.consume(amt),
+            Or8::T2(t2) => t2.consume(amt),
+            Or8::T3(t3) => t3.consume(amt),
+            Or8::T4(t4) => t4.consume(amt),
+            Or8::T5(t5) => t5.consume(amt),
+            Or8::T6(t6) => t6.consume(amt),
+            Or8::T7(t7) => t7.consume(amt),
+            Or8::T8(t8) => t8.consume(amt),
+        }
+    }
+}
+
+/// Writes to the I/O object of the active variant.
+impl<T1, T2, T3, T4, T5, T6, T7, T8> AsyncWrite for Or8<T1, T2, T3, T4, T5, T6, T7, T8>
+where
+    T1: AsyncWrite,
+    T2: AsyncWrite,
+    T3: AsyncWrite,
+    T4: AsyncWrite,
+    T5: AsyncWrite,
+    T6: AsyncWrite,
+    T7: AsyncWrite,
+    T8: AsyncWrite,
+{
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        match self.as_pin_mut() {
+            Or8::T1(t1) => t1.poll_write(cx, buf),
+            Or8::T2(t2) => t2.poll_write(cx, buf),
+            Or8::T3(t3) => t3.poll_write(cx, buf),
+            Or8::T4(t4) => t4.poll_write(cx, buf),
+            Or8::T5(t5) => t5.poll_write(cx, buf),
+            Or8::T6(t6) => t6.poll_write(cx, buf),
+            Or8::T7(t7) => t7.poll_write(cx, buf),
+            Or8::T8(t8) => t8.poll_write(cx, buf),
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match self.as_pin_mut() {
+            Or8::T1(t1) => t1.poll_flush(cx),
+            Or8::T2(t2) => t2.poll_flush(cx),
+            Or8::T3(t3) => t3.poll_flush(cx),
+            Or8::T4(t4) => t4.poll_flush(cx),
+            Or8::T5(t5) => t5.poll_flush(cx),
+            Or8::T6(t6) => t6.poll_flush(cx),
+            Or8::T7(t7) => t7.poll_flush(cx),
+            Or8::T8(t8) => t8.poll_flush(cx),
+        }
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match self.as_pin_mut() {
+            Or8::T1(t1) => t1.poll_shutdown(cx),
+            Or8::T2(t2) => t2.poll_shutdown(cx),
+            Or8::T3(t3) => t3.poll_shutdown(cx),
+            Or8::T4(t4) => t4.poll_shutdown(cx),
+            Or8::T5(t5) => t5.poll_shutdown(cx),
+            Or8::T6(t6) => t6.poll_shutdown(cx),
+            Or8::T7(t7) => t7.poll_shutdown(cx),
+            Or8::T8(t8) => t8.poll_shutdown(cx),
+        }
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[io::IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        match self.as_pin_mut() {
+            Or8::T1(t1) => t1.poll_write_vectored(cx, bufs),
+            Or8::T2(t2) => t2.poll_write_vectored(cx, bufs),
+            Or8::T3(t3) => t3.poll_write_vectored(cx, bufs),
+            Or8::T4(t4) => t4.poll_write_vectored(cx, bufs),
+            Or8::T5(t5) => t5.poll_write_vectored(cx, bufs),
+            Or8::T6(t6) => t6.poll_write_vectored(cx, bufs),
+            Or8::T7(t7) => t7.poll_write_vectored(cx, bufs),
+            Or8::T8(t8) => t8.poll_write_vectored(cx, bufs),
+        }
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        match self {
+            Self::T1(t1) => t1.is_write_vectored(),
+            Self::T2(t2) => t2.is_write_vectored(),
+            Self::T3(t3) => t3.is_write_vectored(),
+            Self::T4(t4) => t4.is_write_vectored(),
+            Self::T5(t5) => t5.is_write_vectored(),
+            Self::T6(t6) => t6.is_write_vectored(),
+            Self::T7(t7) => t7.is_write_vectored(),
+            Self::T8(t8) => t8.is_write_vectored(),
+        }
+    }
+}
+
+/// Seeks the I/O object of the active variant.
+impl<T1, T2, T3, T4, T5, T6, T7, T8> AsyncSeek for Or8<T1, T2, T3, T4, T5, T6, T7, T8>
+where
+    T1: AsyncSeek,
+    T2: AsyncSeek,
+    T3: AsyncSeek,
+    T4: AsyncSeek,
+    T5: AsyncSeek,
+    T6: AsyncSeek,
+    T7: AsyncSeek,
+    T8: AsyncSeek,
+{
+    fn start_seek(self: Pin<&mut Self>, position: io::SeekFrom) -> io::Result<()> {
+        match self.as_pin_mut() {
+            Or8::T1(t1) => t1.start_seek(position),
+            Or8::T2(t2) => t2.start_seek(position),
+            Or8::T3(t3) => t3.start_seek(position),
+            Or8::T4(t4) => t4.start_seek(position),
+            Or8::T5(t5) => t5.start_seek(position),
+            Or8::T6(t6) => t6.start_seek(position),
+            Or8::T7(t7) => t7.start_seek(position),
+            Or8::T8(t8) => t8.start_seek(position),
+        }
+    }
+
+    fn poll_complete(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<u64>> {
+        match self.as_pin_mut() {
+            Or8::T1(t1) => t1.poll_complete(cx),
+            Or8::T2(t2) => t2.poll_complete(cx),
+            Or8::T3(t3) => t3.poll_complete(cx),
+            Or8::T4(t4) => t4.poll_complete(cx),
+            Or8::T5(t5) => t5.poll_complete(cx),
+            Or8::T6(t6) => t6.poll_complete(cx),
+            Or8::T7(t7) => t7.poll_complete(cx),
+            Or8::T8(t8) => t8.poll_complete(cx),
+        }
+    }
+}
+
+/// Reads from the I/O object of the active variant.
+impl<T1, T2, T3, T4, T5, T6, T7, T8, T9> AsyncRead for Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>
+where
+    T1: AsyncRead,
+    T2: AsyncRead,
+    T3: AsyncRead,
+    T4: AsyncRead,
+    T5: AsyncRead,
+    T6: AsyncRead,
+    T7: AsyncRead,
+    T8: AsyncRead,
+    T9: AsyncRead,
+{
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        match self.as_pin_mut() {
+            Or9::T1(t1) => t1.poll_read(cx, buf),
+            Or9::T2(t2) => t2.poll_read(cx, buf),
+            Or9::T3(t3) => t3.poll_read(cx, buf),
+            Or9::T4(t4) => t4.poll_read(cx, buf),
+            Or9::T5(t5) => t5.poll_read(cx, buf),
+            Or9::T6(t6) => t6.poll_read(cx, buf),
+            Or9::T7(t7) => t7.poll_read(cx, buf),
+            Or9::T8(t8) => t8.poll_read(cx, buf),
+            Or9::T9(t9) => t9.poll_read(cx, buf),
+        }
+    }
+}
+
+/// Reads from the buffered I/O object of the active variant.
+impl<T1, T2, T3, T4, T5, T6, T7, T8, T9> AsyncBufRead for Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>
+where
+    T1: AsyncBufRead,
+    T2: AsyncBufRead,
+    T3: AsyncBufRead,
+    T4: AsyncBufRead,
+    T5: AsyncBufRead,
+    T6: AsyncBufRead,
+    T7: AsyncBufRead,
+    T8: AsyncBufRead,
+    T9: AsyncBufRead,
+{
+    fn poll_fill_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+        match self.as_pin_mut() {
+            Or9::T1(t1) => t1.poll_fill_buf(cx),
+            Or9::T2(t2) => t2.poll_fill_buf(cx),
+            Or9::T3(t3) => t3.poll_fill_buf(cx),
+            Or9::T4(t4) => t4.poll_fill_buf(cx),
+            Or9::T5(t5) => t5.poll_fill_buf(cx),
+            Or9::T6(t6) => t6.poll_fill_buf(cx),
+            Or9::T7(t7) => t7.poll_fill_buf(cx),
+            Or9::T8(t8) => t8.poll_fill_buf(cx),
+            Or9::T9(t9) => t9.poll_fill_buf(cx),
+        }
+    }
+
+    fn consume(self: Pin<&mut Self>, amt: usize) {
+        match self.as_pin_mut() {
+            Or9::T1(t1) => t1.consume(amt),
+            Or9::T2(t2) => t2.consume(amt),
+            Or9::T3(t3) => t3.consume(amt),
+            Or9::T4(t4) => t4.consume(amt),
+            Or9::T5(t5) => t5.consume(amt),
+            Or9::T6(t6) => t6.consume(amt),
+            Or9::T7(t7) => t7.consume(amt),
+            Or9::T8(t8) => t8.consume(amt),
+            Or9::T9(t9) => t9.consume(amt),
+        }
+    }
+}
+
+/// Writes to the I/O object of the active variant.
+impl<T1, T2, T3, T4, T5, T6, T7, T8, T9> AsyncWrite for Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>
+where
+    T1: AsyncWrite,
+    T2: AsyncWrite,
+    T3: AsyncWrite,
+    T4: AsyncWrite,
+    T5: AsyncWrite,
+    T6: AsyncWrite,
+    T7: AsyncWrite,
+    T8: AsyncWrite,
+    T9: AsyncWrite,
+{
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        match self.as_pin_mut() {
+            Or9::T1(t1) => t1.poll_write(cx, buf),
+            Or9::T2(t2) => t2.poll_write(cx, buf),
+            Or9::T3(t3) => t3.poll_write(cx, buf),
+            Or9::T4(t4) => t4.poll_write(cx, buf),
+            Or9::T5(t5) => t5.poll_write(cx, buf),
+            Or9::T6(t6) => t6.poll_write(cx, buf),
+            Or9::T7(t7) => t7.poll_write(cx, buf),
+            Or9::T8(t8) => t8.poll_write(cx, buf),
+            Or9::T9(t9) => t9.poll_write(cx, buf),
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match self.as_pin_mut() {
+            Or9::T1(t1) => t1.poll_flush(cx),
+            Or9::T2(t2) => t2.poll_flush(cx),
+            Or9::T3(t3) => t3.poll_flush(cx),
+            Or9::T4(t4) => t4.poll_flush(cx),
+            Or9::T5(t5) => t5.poll_flush(cx),
+            Or9::T6(t6) => t6.poll_flush(cx),
+            Or9::T7(t7) => t7.poll_flush(cx),
+            Or9::T8(t8) => t8.poll_flush(cx),
+            Or9::T9(t9) => t9.poll_flush(cx),
+        }
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match self.as_pin_mut() {
+            Or9::T1(t1) => t1.poll_shutdown(cx),
+            Or9::T2(t2) => t2.poll_shutdown(cx),
+            Or9::T3(t3) => t3.poll_shutdown(cx),
+            Or9::T4(t4) => t4.poll_shutdown(cx),
+            Or9::T5(t5) => t5.poll_shutdown(cx),
+            Or9::T6(t6) => t6.poll_shutdown(cx),
+            Or9::T7(t7) => t7.poll_shutdown(cx),
+            Or9::T8(t8) => t8.poll_shutdown(cx),
+            Or9::T9(t9) => t9.poll_shutdown(cx),
+        }
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[io::IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        match self.as_pin_mut() {
+            Or9::T1(t1) => t1.poll_write_vectored(cx, bufs),
+            Or9::T2(t2) => t2.poll_write_vectored(cx, bufs),
+            Or9::T3(t3) => t3.poll_write_vectored(cx, bufs),
+            Or9::T4(t4) => t4.poll_write_vectored(cx, bufs),
+            Or9::T5(t5) => t5.poll_write_vectored(cx, bufs),
+            Or9::T6(t6) => t6.poll_write_vectored(cx, bufs),
+            Or9::T7(t7) => t7.poll_write_vectored(cx, bufs),
+            Or9::T8(t8) => t8.poll_write_vectored(cx, bufs),
+            Or9::T9(t9) => t9.poll_write_vectored(cx, bufs),
+        }
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        match self {
+            Self::T1(t1) => t1.is_write_vectored(),
+            Self::T2(t2) => t2.is_write_vectored(),
+            Self::T3(t3) => t3.is_write_vectored(),
+            Self::T4(t4) => t4.is_write_vectored(),
+            Self::T5(t5) => t5.is_write_vectored(),
+            Self::T6(t6) => t6.is_write_vectored(),
+            Self::T7(t7) => t7.is_write_vectored(),
+            Self::T8(t8) => t8.is_write_vectored(),
+            Self::T9(t9) => t9.is_write_vectored(),
+        }
+    }
+}
+
+/// Seeks the I/O object of the active variant.
+impl<T1, T2, T3, T4, T5, T6, T7, T8, T9> AsyncSeek for Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>
+where
+    T1: AsyncSeek,
+    T2: AsyncSeek,
+    T3: AsyncSeek,
+    T4: AsyncSeek,
+    T5: AsyncSeek,
+    T6: AsyncSeek,
+    T7: AsyncSeek,
+    T8: AsyncSeek,
+    T9: AsyncSeek,
+{
+    fn start_seek(self: Pin<&mut Self>, position: io::SeekFrom) -> io::Result<()> {
+        match self.as_pin_mut() {
+            Or9::T1(t1) => t1.start_seek(position),
+            Or9::T2(t2) => t2.start_seek(position),
+            Or9::T3(t3) => t3.start_seek(position),
+            Or9::T4(t4) => t4.start_seek(position),
+            Or9::T5(t5) => t5.start_seek(position),
+            Or9::T6(t6) => t6.start_seek(position),
+            Or9::T7(t7) => t7.start_seek(position),
+            Or9::T8(t8) => t8.start_seek(position),
+            Or9::T9(t9) => t9.start_seek(position),
+        }
+    }
+
+    fn poll_complete(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<u64>> {
+        match self.as_pin_mut() {
+            Or9::T1(t1) => t1.poll_complete(cx),
+            Or9::T2(t2) => t2.poll_complete(cx),
+            Or9::T3(t3) => t3.poll_complete(cx),
+            Or9::T4(t4) => t4.poll_complete(cx),
+            Or9::T5(t5) => t5.poll_complete(cx),
+            Or9::T6(t6) => t6.poll_complete(cx),
+            Or9::T7(t7) => t7.poll_complete(cx),
+            Or9::T8(t8) => t8.poll_complete(cx),
+            Or9::T9(t9) => t9.poll_complete(cx),
+        }
+    }
+}
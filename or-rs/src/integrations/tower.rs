@@ -0,0 +1,320 @@
+//! `tower` services of the `Or` types, enabled by the `tower` feature.
+
+use core::task::{Context, Poll};
+
+use tower_service::Service;
+
+use crate::enums::*;
+
+/// Forwards to the service of the active variant, so services sharing the same request,
+/// response and error types can be chosen at runtime and still handed out as a single type.
+impl<Req, T1, T2> Service<Req> for Or2<T1, T2>
+where
+    T1: Service<Req>,
+    T2: Service<Req, Response = T1::Response, Error = T1::Error>,
+{
+    type Response = T1::Response;
+    type Error = T1::Error;
+    type Future = Or2<T1::Future, T2::Future>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        match self {
+            Self::T1(t1) => t1.poll_ready(cx),
+            Self::T2(t2) => t2.poll_ready(cx),
+        }
+    }
+
+    fn call(&mut self, req: Req) -> Self::Future {
+        match self {
+            Self::T1(t1) => Or2::T1(t1.call(req)),
+            Self::T2(t2) => Or2::T2(t2.call(req)),
+        }
+    }
+}
+
+/// Forwards to the service of the active variant, so services sharing the same request,
+/// response and error types can be chosen at runtime and still handed out as a single type.
+impl<Req, T1, T2, T3> Service<Req> for Or3<T1, T2, T3>
+where
+    T1: Service<Req>,
+    T2: Service<Req, Response = T1::Response, Error = T1::Error>,
+    T3: Service<Req, Response = T1::Response, Error = T1::Error>,
+{
+    type Response = T1::Response;
+    type Error = T1::Error;
+    type Future = Or3<T1::Future, T2::Future, T3::Future>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        match self {
+            Self::T1(t1) => t1.poll_ready(cx),
+            Self::T2(t2) => t2.poll_ready(cx),
+            Self::T3(t3) => t3.poll_ready(cx),
+        }
+    }
+
+    fn call(&mut self, req: Req) -> Self::Future {
+        match self {
+            Self::T1(t1) => Or3::T1(t1.call(req)),
+            Self::T2(t2) => Or3::T2(t2.call(req)),
+            Self::T3(t3) => Or3::T3(t3.call(req)),
+        }
+    }
+}
+
+/// Forwards to the service of the active variant, so services sharing the same request,
+/// response and error types can be chosen at runtime and still handed out as a single type.
+impl<Req, T1, T2, T3, T4> Service<Req> for Or4<T1, T2, T3, T4>
+where
+    T1: Service<Req>,
+    T2: Service<Req, Response = T1::Response, Error = T1::Error>,
+    T3: Service<Req, Response = T1::Response, Error = T1::Error>,
+    T4: Service<Req, Response = T1::Response, Error = T1::Error>,
+{
+    type Response = T1::Response;
+    type Error = T1::Error;
+    type Future = Or4<T1::Future, T2::Future, T3::Future, T4::Future>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        match self {
+            Self::T1(t1) => t1.poll_ready(cx),
+            Self::T2(t2) => t2.poll_ready(cx),
+            Self::T3(t3) => t3.poll_ready(cx),
+            Self::T4(t4) => t4.poll_ready(cx),
+        }
+    }
+
+    fn call(&mut self, req: Req) -> Self::Future {
+        match self {
+            Self::T1(t1) => Or4::T1(t1.call(req)),
+            Self::T2(t2) => Or4::T2(t2.call(req)),
+            Self::T3(t3) => Or4::T3(t3.call(req)),
+            Self::T4(t4) => Or4::T4(t4.call(req)),
+        }
+    }
+}
+
+/// Forwards to the service of the active variant, so services sharing the same request,
+/// response and error types can be chosen at runtime and still handed out as a single type.
+impl<Req, T1, T2, T3, T4, T5> Service<Req> for Or5<T1, T2, T3, T4, T5>
+where
+    T1: Service<Req>,
+    T2: Service<Req, Response = T1::Response, Error = T1::Error>,
+    T3: Service<Req, Response = T1::Response, Error = T1::Error>,
+    T4: Service<Req, Response = T1::Response, Error = T1::Error>,
+    T5: Service<Req, Response = T1::Response, Error = T1::Error>,
+{
+    type Response = T1::Response;
+    type Error = T1::Error;
+    type Future = Or5<T1::Future, T2::Future, T3::Future, T4::Future, T5::Future>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        match self {
+            Self::T1(t1) => t1.poll_ready(cx),
+            Self::T2(t2) => t2.poll_ready(cx),
+            Self::T3(t3) => t3.poll_ready(cx),
+            Self::T4(t4) => t4.poll_ready(cx),
+            Self::T5(t5) => t5.poll_ready(cx),
+        }
+    }
+
+    fn call(&mut self, req: Req) -> Self::Future {
+        match self {
+            Self::T1(t1) => Or5::T1(t1.call(req)),
+            Self::T2(t2) => Or5::T2(t2.call(req)),
+            Self::T3(t3) => Or5::T3(t3.call(req)),
+            Self::T4(t4) => Or5::T4(t4.call(req)),
+            Self::T5(t5) => Or5::T5(t5.call(req)),
+        }
+    }
+}
+
+/// Forwards to the service of the active variant, so services sharing the same request,
+/// response and error types can be chosen at runtime and still handed out as a single type.
+impl<Req, T1, T2, T3, T4, T5, T6> Service<Req> for Or6<T1, T2, T3, T4, T5, T6>
+where
+    T1: Service<Req>,
+    T2: Service<Req, Response = T1::Response, Error = T1::Error>,
+    T3: Service<Req, Response = T1::Response, Error = T1::Error>,
+    T4: Service<Req, Response = T1::Response, Error = T1::Error>,
+    T5: Service<Req, Response = T1::Response, Error = T1::Error>,
+    T6: Service<Req, Response = T1::Response, Error = T1::Error>,
+{
+    type Response = T1::Response;
+    type Error = T1::Error;
+    type Future = Or6<T1::Future, T2::Future, T3::Future, T4::Future, T5::Future, T6::Future>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        match self {
+            Self::T1(t1) => t1.poll_ready(cx),
+            Self::T2(t2) => t2.poll_ready(cx),
+            Self::T3(t3) => t3.poll_ready(cx),
+            Self::T4(t4) => t4.poll_ready(cx),
+            Self::T5(t5) => t5.poll_ready(cx),
+            Self::T6(t6) => t6.poll_ready(cx),
+        }
+    }
+
+    fn call(&mut self, req: Req) -> Self::Future {
+        match self {
+            Self::T1(t1) => Or6::T1(t1.call(req)),
+            Self::T2(t2) => Or6::T2(t2.call(req)),
+            Self::T3(t3) => Or6::T3(t3.call(req)),
+            Self::T4(t4) => Or6::T4(t4.call(req)),
+            Self::T5(t5) => Or6::T5(t5.call(req)),
+            Self::T6(t6) => Or6::T6(t6.call(req)),
+        }
+    }
+}
+
+/// Forwards to the service of the active variant, so services sharing the same request,
+/// response and error types can be chosen at runtime and still handed out as a single type.
+impl<Req, T1, T2, T3, T4, T5, T6, T7> Service<Req> for Or7<T1, T2, T3, T4, T5, T6, T7>
+where
+    T1: Service<Req>,
+    T2: Service<Req, Response = T1::Response, Error = T1::Error>,
+    T3: Service<Req, Response = T1::Response, Error = T1::Error>,
+    T4: Service<Req, Response = T1::Response, Error = T1::Error>,
+    T5: Service<Req, Response = T1::Response, Error = T1::Error>,
+    T6: Service<Req, Response = T1::Response, Error = T1::Error>,
+    T7: Service<Req, Response = T1::Response, Error = T1::Error>,
+{
+    type Response = T1::Response;
+    type Error = T1::Error;
+    type Future =
+        Or7<T1::Future, T2::Future, T3::Future, T4::Future, T5::Future, T6::Future, T7::Future>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        match self {
+            Self::T1(t1) => t1.poll_ready(cx),
+            Self::T2(t2) => t2.poll_ready(cx),
+            Self::T3(t3) => t3.poll_ready(cx),
+            Self::T4(t4) => t4.poll_ready(cx),
+            Self::T5(t5) => t5.poll_ready(cx),
+            Self::T6(t6) => t6.poll_ready(cx),
+            Self::T7(t7) => t7.poll_ready(cx),
+        }
+    }
+
+    fn call(&mut self, req: Req) -> Self::Future {
+        match self {
+            Self::T1(t1) => Or7::T1(t1.call(req)),
+            Self::T2(t2) => Or7::T2(t2.call(req)),
+            Self::T3(t3) => Or7::T3(t3.call(req)),
+            Self::T4(t4) => Or7::T4(t4.call(req)),
+            Self::T5(t5) => Or7::T5(t5.call(req)),
+            Self::T6(t6) => Or7::T6(t6.call(req)),
+            Self::T7(t7) => Or7::T7(t7.call(req)),
+        }
+    }
+}
+
+/// Forwards to the service of the active variant, so services sharing the same request,
+/// response and error types can be chosen at runtime and still handed out as a single type.
+impl<Req, T1, T2, T3, T4, T5, T6, T7, T8> Service<Req> for Or8<T1, T2, T3, T4, T5, T6, T7, T8>
+where
+    T1: Service<Req>,
+    T2: Service<Req, Response = T1::Response, Error = T1::Error>,
+    T3: Service<Req, Response = T1::Response, Error = T1::Error>,
+    T4: Service<Req, Response = T1::Response, Error = T1::Error>,
+    T5: Service<Req, Response = T1::Response, Error = T1::Error>,
+    T6: Service<Req, Response = T1::Response, Error = T1::Error>,
+    T7: Service<Req, Response = T1::Response, Error = T1::Error>,
+    T8: Service<Req, Response = T1::Response, Error = T1::Error>,
+{
+    type Response = T1::Response;
+    type Error = T1::Error;
+    type Future = Or8<
+        T1::Future,
+        T2::Future,
+        T3::Future,
+        T4::Future,
+        T5::Future,
+        T6::Future,
+        T7::Future,
+        T8::Future,
+    >;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        match self {
+            Self::T1(t1) => t1.poll_ready(cx),
+            Self::T2(t2) => t2.poll_ready(cx),
+            Self::T3(t3) => t3.poll_ready(cx),
+            Self::T4(t4) => t4.poll_ready(cx),
+            Self::T5(t5) => t5.poll_ready(cx),
+            Self::T6(t6) => t6.poll_ready(cx),
+            Self::T7(t7) => t7.poll_ready(cx),
+            Self::T8(t8) => t8.poll_ready(cx),
+        }
+    }
+
+    fn call(&mut self, req: Req) -> Self::Future {
+        match self {
+            Self::T1(t1) => Or8::T1(t1.call(req)),
+            Self::T2(t2) => Or8::T2(t2.call(req)),
+            Self::T3(t3) => Or8::T3(t3.call(req)),
+            Self::T4(t4) => Or8::T4(t4.call(req)),
+            Self::T5(t5) => Or8::T5(t5.call(req)),
+            Self::T6(t6) => Or8::T6(t6.call(req)),
+            Self::T7(t7) => Or8::T7(t7.call(req)),
+            Self::T8(t8) => Or8::T8(t8.call(req)),
+        }
+    }
+}
+
+/// Forwards to the service of the active variant, so services sharing the same request,
+/// response and error types can be chosen at runtime and still handed out as a single type.
+impl<Req, T1, T2, T3, T4, T5, T6, T7, T8, T9> Service<Req>
+    for Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>
+where
+    T1: Service<Req>,
+    T2: Service<Req, Response = T1::Response, Error = T1::Error>,
+    T3: Service<Req, Response = T1::Response, Error = T1::Error>,
+    T4: Service<Req, Response = T1::Response, Error = T1::Error>,
+    T5: Service<Req, Response = T1::Response, Error = T1::Error>,
+    T6: Service<Req, Response = T1::Response, Error = T1::Error>,
+    T7: Service<Req, Response = T1::Response, Error = T1::Error>,
+    T8: Service<Req, Response = T1::Response, Error = T1::Error>,
+    T9: Service<Req, Response = T1::Response, Error = T1::Error>,
+{
+    type Response = T1::Response;
+    type Error = T1::Error;
+    type Future = Or9<
+        T1::Future,
+        T2::Future,
+        T3::Future,
+        T4::Future,
+        T5::Future,
+        T6::Future,
+        T7::Future,
+        T8::Future,
+        T9::Future,
+    >;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        match self {
+            Self::T1(t1) => t1.poll_ready(cx),
+            Self::T2(t2) => t2.poll_ready(cx),
+            Self::T3(t3) => t3.poll_ready(cx),
+            Self::T4(t4) => t4.poll_ready(cx),
+            Self::T5(t5) => t5.poll_ready(cx),
+            Self::T6(t6) => t6.poll_ready(cx),
+            Self::T7(t7) => t7.poll_ready(cx),
+            Self::T8(t8) => t8.poll_ready(cx),
+            Self::T9(t9) => t9.poll_ready(cx),
+        }
+    }
+
+    fn call(&mut self, req: Req) -> Self::Future {
+        match self {
+            Self::T1(t1) => Or9::T1(t1.call(req)),
+            Self::T2(t2) => Or9::T2(t2.call(req)),
+            Self::T3(t3) => Or9::T3(t3.call(req)),
+            Self::T4(t4) => Or9::T4(t4.call(req)),
+            Self::T5(t5) => Or9::T5(t5.call(req)),
+            Self::T6(t6) => Or9::T6(t6.call(req)),
+            Self::T7(t7) => Or9::T7(t7.call(req)),
+            Self::T8(t8) => Or9::T8(t8.call(req)),
+            Self::T9(t9) => Or9::T9(t9.call(req)),
+        }
+    }
+}
@@ -6,7 +6,79 @@
 //! An implementation of enum exists in the [enums](./enums/index.html) module,
 //! which is automatically code-generated by [code_gen](../code_gen/index.html) crate.
 //! For more information on each `Or` type, please refer to the module documentation.
+//!
+//! The [vec](./vec/index.html) module provides `OrVec` types, struct-of-arrays containers
+//! that store a large number of `Or` values without padding each of them to the largest variant.
+//!
+//! The [concat](./concat/index.html) module merges two `Or` types into a single one, like
+//! `Or2<A, B>` and `Or3<C, D, E>` into `Or5<A, B, C, D, E>`.
+//!
+//! The [poly](./poly/index.html) module maps whichever variant is active by a single mapper
+//! implemented for each payload type.
+//!
+//! The [parse](./parse/index.html) module provides a lenient parser of scalar values of unknown
+//! type, like config values, into an `Or5<i64, f64, bool, String, ()>`.
+//!
+//! With the `serde` feature, the `Or` types are serialized untagged, as the value of the active
+//! variant alone. A value is deserialized into the first of `T1` ... `Tn` that accepts it, so
+//! an `Or2<f64, i32>` never holds an `i32` after a round trip. The [tagged](./tagged/index.html)
+//! module provides a tagged representation, which keeps the variant.
+//!
+//! The crate is `no_std` without the default `std` feature. The `Or` types and the `or_gen!`
+//! expansions only need `core`, while the `alloc` feature adds the methods using `Box` and
+//! `Cow`, like `boxed`, and the [vec](./vec/index.html) module. The `Error` and `io` impls and
+//! the [parse](./parse/index.html) module need `std`.
+//!
+//! With the `codegen` feature, the `enums` and `vec` modules are generated into `OUT_DIR` at
+//! build time instead, and the largest arity can be chosen by the `arity-4`, `arity-6` and
+//! `arity-9` features. The `high-arity` feature generates up to `Or16`.
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(feature = "unstable_feature", feature(core_intrinsics))]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+pub mod concat;
+#[cfg(not(feature = "codegen"))]
 pub mod enums;
+#[cfg(feature = "codegen")]
+#[allow(clippy::type_complexity)]
+pub mod enums {
+    //! A concrete implementation of the type Or, generated at build time.
+    include!(concat!(env!("OUT_DIR"), "/enums.rs"));
+}
+pub mod from_str;
+mod integrations;
+mod macros;
+// `Scalar` is an `Or5`, which is not generated with the `arity-4` feature alone.
+#[cfg(all(
+    feature = "std",
+    any(
+        not(feature = "arity-4"),
+        feature = "arity-6",
+        feature = "arity-9",
+        feature = "high-arity"
+    )
+))]
+pub mod parse;
+pub mod poly;
+#[cfg(feature = "serde")]
+pub mod tagged;
+#[cfg(all(feature = "alloc", not(feature = "codegen")))]
+pub mod vec;
+#[cfg(all(feature = "alloc", feature = "codegen"))]
+#[allow(clippy::type_complexity)]
+pub mod vec {
+    //! Struct-of-arrays containers of the `Or` types, generated at build time.
+    include!(concat!(env!("OUT_DIR"), "/vec.rs"));
+}
+pub mod when;
+
+// re-exported for the expansions of `or_gen!` with `boxed` or `dyn`, since a `no_std` crate
+// can not name `alloc` without declaring it.
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::boxed::Box;
+}
@@ -0,0 +1,482 @@
+//! Declarative macros complementing the `Or` types.
+
+/// Generates `From<Tx>` impls for a concrete `Or` type, so that each payload type can be
+/// converted into the `Or` type with `.into()` or the `?` operator.
+///
+/// A blanket `From<Tx>` impl can not be provided by the `Or` types themselves, since it
+/// conflicts when two positions share the same type. This macro is the opt-in alternative
+/// for `Or` types whose payload types are all distinct.
+///
+/// Note that, due to the orphan rules, the payload types must be defined in the crate
+/// that invokes this macro.
+///
+/// ## Example
+///
+/// ```rust
+/// use or_rs::enums::Or2;
+/// use or_rs::or_from;
+///
+/// struct NotFound;
+/// struct Timeout;
+///
+/// or_from!(Or2<NotFound, Timeout>);
+///
+/// fn fetch() -> Result<(), Or2<NotFound, Timeout>> {
+///     Err(Timeout)?
+/// }
+///
+/// assert!(fetch().unwrap_err().is_t2());
+/// ```
+#[macro_export]
+macro_rules! or_from {
+    ($or:ident < $($typ:ty),+ $(,)? >) => {
+        $crate::or_from!(@impl $or [$($typ),+] [$($typ),+] [T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 T11 T12 T13 T14 T15 T16]);
+    };
+    (@impl $or:ident [$($all:ty),+] [$head:ty $(, $rest:ty)*] [$variant:ident $($variants:ident)*]) => {
+        impl ::core::convert::From<$head> for $crate::enums::$or<$($all),+> {
+            fn from(value: $head) -> Self {
+                Self::$variant(value)
+            }
+        }
+        $crate::or_from!(@impl $or [$($all),+] [$($rest),*] [$($variants)*]);
+    };
+    (@impl $or:ident [$($all:ty),+] [] [$($variants:ident)*]) => {};
+}
+
+/// Generates `PartialEq<Tx>` impls for a concrete `Or` type (and the reverse ones), so that
+/// an `Or` value can be compared against a payload value directly, without extracting it.
+/// The comparison is true only when the `Or` value is of the variant of that type, and the
+/// payloads are equal.
+///
+/// Like [`or_from!`], blanket impls can not be provided by the `Or` types themselves since they
+/// conflict when two positions share the same type, so this macro is the opt-in alternative
+/// for `Or` types whose payload types are all distinct. The payload types must implement
+/// `PartialEq`, and due to the orphan rules, they must be defined in the crate that invokes
+/// this macro.
+///
+/// ## Example
+///
+/// ```rust
+/// use or_rs::enums::Or2;
+/// use or_rs::or_eq;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Celsius(i32);
+/// #[derive(Debug, PartialEq)]
+/// struct Fahrenheit(i32);
+///
+/// or_eq!(Or2<Celsius, Fahrenheit>);
+///
+/// let x: Or2<Celsius, Fahrenheit> = Or2::T1(Celsius(20));
+/// assert!(x == Celsius(20));
+/// assert!(Fahrenheit(20) != x);
+/// ```
+#[macro_export]
+macro_rules! or_eq {
+    ($or:ident < $($typ:ty),+ $(,)? >) => {
+        $crate::or_eq!(@impl $or [$($typ),+] [$($typ),+] [T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 T11 T12 T13 T14 T15 T16]);
+    };
+    (@impl $or:ident [$($all:ty),+] [$head:ty $(, $rest:ty)*] [$variant:ident $($variants:ident)*]) => {
+        impl ::core::cmp::PartialEq<$head> for $crate::enums::$or<$($all),+> {
+            fn eq(&self, other: &$head) -> bool {
+                match self {
+                    Self::$variant(value) => value == other,
+                    _ => false,
+                }
+            }
+        }
+
+        impl ::core::cmp::PartialEq<$crate::enums::$or<$($all),+>> for $head {
+            fn eq(&self, other: &$crate::enums::$or<$($all),+>) -> bool {
+                other == self
+            }
+        }
+        $crate::or_eq!(@impl $or [$($all),+] [$($rest),*] [$($variants)*]);
+    };
+    (@impl $or:ident [$($all:ty),+] [] [$($variants:ident)*]) => {};
+}
+
+/// Generates a named error enum from a concrete `Or` type, with `Display`, `Error`
+/// and `From` impls, plus the conversions from and to the `Or` type.
+///
+/// This bridges the quick usage of `Or` as an ad-hoc error type and a proper error type
+/// exposed in a public API. The variants are named `T1` ... `TX` after the `Or` type, or
+/// can be named explicitly as `Name(Type)`. `Display` and `Error::source` are forwarded
+/// to the error of the active variant, and `Debug` is derived, so it must not be passed
+/// in the attributes.
+///
+/// The `From<Tx>` impls conflict when two variants share the same type, and `Error` is only
+/// implemented with the `std` feature.
+///
+/// ## Example
+///
+/// ```rust
+/// use std::fmt;
+///
+/// use or_rs::enums::Or2;
+/// use or_rs::or_error;
+///
+/// #[derive(Debug)]
+/// struct Timeout;
+///
+/// impl fmt::Display for Timeout {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         write!(f, "timed out")
+///     }
+/// }
+///
+/// impl std::error::Error for Timeout {}
+///
+/// or_error! {
+///     /// The error returned by `fetch`.
+///     pub FetchError = Or2<Parse(std::num::ParseIntError), Timeout(Timeout)>
+/// }
+///
+/// fn fetch() -> Result<i32, FetchError> {
+///     Err(Timeout)?
+/// }
+///
+/// let err = fetch().unwrap_err();
+/// assert_eq!(err.to_string(), "timed out");
+///
+/// let or: Or2<std::num::ParseIntError, Timeout> = err.into();
+/// assert!(or.is_t2());
+/// ```
+#[macro_export]
+macro_rules! or_error {
+    ($(#[$attr:meta])* $vis:vis $name:ident = $or:ident < $($variant:ident ($typ:ty)),+ $(,)? >) => {
+        $crate::or_error!(@pair [$(#[$attr])*] [$vis] $name $or [] [$(($variant ($typ)))+] [T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 T11 T12 T13 T14 T15 T16]);
+    };
+    ($(#[$attr:meta])* $vis:vis $name:ident = $or:ident < $($typ:ty),+ $(,)? >) => {
+        $crate::or_error!(@name [$(#[$attr])*] [$vis] $name $or [] [$($typ),+] [T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 T11 T12 T13 T14 T15 T16]);
+    };
+    // names the variants after the `Or` type.
+    (@name $attrs:tt $vis:tt $name:ident $or:ident [$($done:tt)*] [$head:ty $(, $rest:ty)*] [$tvar:ident $($tvars:ident)*]) => {
+        $crate::or_error!(@name $attrs $vis $name $or [$($done)* ($tvar ($head))] [$($rest),*] [$($tvars)*]);
+    };
+    (@name $attrs:tt $vis:tt $name:ident $or:ident [$($done:tt)*] [] [$($tvars:ident)*]) => {
+        $crate::or_error!(@pair $attrs $vis $name $or [] [$($done)*] [T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 T11 T12 T13 T14 T15 T16]);
+    };
+    // pairs each variant with the corresponding variant of the `Or` type.
+    (@pair $attrs:tt $vis:tt $name:ident $or:ident [$($done:tt)*] [($variant:ident ($typ:ty)) $($rest:tt)*] [$tvar:ident $($tvars:ident)*]) => {
+        $crate::or_error!(@pair $attrs $vis $name $or [$($done)* ($variant $tvar $typ)] [$($rest)*] [$($tvars)*]);
+    };
+    (@pair $attrs:tt $vis:tt $name:ident $or:ident [$($done:tt)*] [] [$($tvars:ident)*]) => {
+        $crate::or_error!(@emit $attrs $vis $name $or [$($done)*]);
+    };
+    (@emit [$(#[$attr:meta])*] [$vis:vis] $name:ident $or:ident [$(($variant:ident $tvar:ident $typ:ty))+]) => {
+        $(#[$attr])*
+        #[derive(Debug)]
+        $vis enum $name {
+            $($variant($typ)),+
+        }
+
+        impl ::core::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    $(Self::$variant(e) => ::core::fmt::Display::fmt(e, f)),+
+                }
+            }
+        }
+
+        $crate::__or_error_impl!($name { $($variant)+ });
+
+        $(
+            impl ::core::convert::From<$typ> for $name {
+                fn from(value: $typ) -> Self {
+                    Self::$variant(value)
+                }
+            }
+        )+
+
+        impl ::core::convert::From<$crate::enums::$or<$($typ),+>> for $name {
+            fn from(value: $crate::enums::$or<$($typ),+>) -> Self {
+                match value {
+                    $($crate::enums::$or::$tvar(e) => Self::$variant(e)),+
+                }
+            }
+        }
+
+        impl ::core::convert::From<$name> for $crate::enums::$or<$($typ),+> {
+            fn from(value: $name) -> Self {
+                match value {
+                    $($name::$variant(e) => Self::$tvar(e)),+
+                }
+            }
+        }
+    };
+}
+
+// implements `Error` for the enum declared by `or_error!`, which is only available with `std`.
+// the feature is checked here rather than in the expansion, where it would be the feature of
+// the calling crate.
+#[cfg(feature = "std")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __or_error_impl {
+    ($name:ident { $($variant:ident)+ }) => {
+        impl ::std::error::Error for $name {
+            fn source(&self) -> ::core::option::Option<&(dyn ::std::error::Error + 'static)> {
+                match self {
+                    $(Self::$variant(e) => ::std::error::Error::source(e)),+
+                }
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "std"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __or_error_impl {
+    ($name:ident { $($variant:ident)+ }) => {};
+}
+
+/// Asserts that an `Or` value is of the given variant, `1` for `T1` and so on.
+///
+/// On failure, the panic message names the asserted variant along with the stringified
+/// expression, and the actual variant and value in its `Debug` form, so the payload types
+/// must implement `Debug`.
+///
+/// ## Example
+///
+/// ```rust
+/// use or_rs::assert_is_t;
+/// use or_rs::enums::Or2;
+///
+/// let x: Or2<i32, f32> = Or2::T2(3.5);
+/// assert_is_t!(x, 2);
+/// ```
+#[macro_export]
+macro_rules! assert_is_t {
+    (@impl $x:expr, $n:literal, $is:ident) => {
+        match &$x {
+            x => {
+                if !x.$is() {
+                    ::core::panic!(
+                        "assertion failed: `{}` is T{}, but it is {}\n  actual: {:?}",
+                        ::core::stringify!($x),
+                        $n,
+                        x.variant_name(),
+                        x,
+                    );
+                }
+            }
+        }
+    };
+    ($x:expr, 1 $(,)?) => {
+        $crate::assert_is_t!(@impl $x, 1, is_t1)
+    };
+    ($x:expr, 2 $(,)?) => {
+        $crate::assert_is_t!(@impl $x, 2, is_t2)
+    };
+    ($x:expr, 3 $(,)?) => {
+        $crate::assert_is_t!(@impl $x, 3, is_t3)
+    };
+    ($x:expr, 4 $(,)?) => {
+        $crate::assert_is_t!(@impl $x, 4, is_t4)
+    };
+    ($x:expr, 5 $(,)?) => {
+        $crate::assert_is_t!(@impl $x, 5, is_t5)
+    };
+    ($x:expr, 6 $(,)?) => {
+        $crate::assert_is_t!(@impl $x, 6, is_t6)
+    };
+    ($x:expr, 7 $(,)?) => {
+        $crate::assert_is_t!(@impl $x, 7, is_t7)
+    };
+    ($x:expr, 8 $(,)?) => {
+        $crate::assert_is_t!(@impl $x, 8, is_t8)
+    };
+    ($x:expr, 9 $(,)?) => {
+        $crate::assert_is_t!(@impl $x, 9, is_t9)
+    };
+    ($x:expr, 10 $(,)?) => {
+        $crate::assert_is_t!(@impl $x, 10, is_t10)
+    };
+    ($x:expr, 11 $(,)?) => {
+        $crate::assert_is_t!(@impl $x, 11, is_t11)
+    };
+    ($x:expr, 12 $(,)?) => {
+        $crate::assert_is_t!(@impl $x, 12, is_t12)
+    };
+    ($x:expr, 13 $(,)?) => {
+        $crate::assert_is_t!(@impl $x, 13, is_t13)
+    };
+    ($x:expr, 14 $(,)?) => {
+        $crate::assert_is_t!(@impl $x, 14, is_t14)
+    };
+    ($x:expr, 15 $(,)?) => {
+        $crate::assert_is_t!(@impl $x, 15, is_t15)
+    };
+    ($x:expr, 16 $(,)?) => {
+        $crate::assert_is_t!(@impl $x, 16, is_t16)
+    };
+}
+
+/// Asserts that an `Or` value is of the given variant and its payload equals to the
+/// expected value, written as `T2 == expected`.
+///
+/// On failure, the panic message includes the actual variant and value, and the expected
+/// one in their `Debug` forms, so the payload types and the expected value must implement
+/// `Debug`.
+///
+/// ## Example
+///
+/// ```rust
+/// use or_rs::assert_or_eq;
+/// use or_rs::enums::Or2;
+///
+/// let x: Or2<i32, f32> = Or2::T2(3.5);
+/// assert_or_eq!(x, T2 == 3.5);
+/// ```
+#[macro_export]
+macro_rules! assert_or_eq {
+    (@impl $x:expr, $variant:ident, $as:ident, $expected:expr) => {
+        match (&$x, &$expected) {
+            (x, expected) => match x.as_ref().$as() {
+                ::core::option::Option::Some(actual) if *actual == *expected => {}
+                ::core::option::Option::Some(actual) => ::core::panic!(
+                    "assertion failed: `{}` is `{} == {}`\n  actual: {}({:?})\nexpected: {}({:?})",
+                    ::core::stringify!($x),
+                    ::core::stringify!($variant),
+                    ::core::stringify!($expected),
+                    ::core::stringify!($variant),
+                    actual,
+                    ::core::stringify!($variant),
+                    expected,
+                ),
+                ::core::option::Option::None => ::core::panic!(
+                    "assertion failed: `{}` is `{} == {}`, but it is {}\n  actual: {:?}\nexpected: {}({:?})",
+                    ::core::stringify!($x),
+                    ::core::stringify!($variant),
+                    ::core::stringify!($expected),
+                    x.variant_name(),
+                    x,
+                    ::core::stringify!($variant),
+                    expected,
+                ),
+            },
+        }
+    };
+    ($x:expr, T1 == $expected:expr $(,)?) => {
+        $crate::assert_or_eq!(@impl $x, T1, as_t1, $expected)
+    };
+    ($x:expr, T2 == $expected:expr $(,)?) => {
+        $crate::assert_or_eq!(@impl $x, T2, as_t2, $expected)
+    };
+    ($x:expr, T3 == $expected:expr $(,)?) => {
+        $crate::assert_or_eq!(@impl $x, T3, as_t3, $expected)
+    };
+    ($x:expr, T4 == $expected:expr $(,)?) => {
+        $crate::assert_or_eq!(@impl $x, T4, as_t4, $expected)
+    };
+    ($x:expr, T5 == $expected:expr $(,)?) => {
+        $crate::assert_or_eq!(@impl $x, T5, as_t5, $expected)
+    };
+    ($x:expr, T6 == $expected:expr $(,)?) => {
+        $crate::assert_or_eq!(@impl $x, T6, as_t6, $expected)
+    };
+    ($x:expr, T7 == $expected:expr $(,)?) => {
+        $crate::assert_or_eq!(@impl $x, T7, as_t7, $expected)
+    };
+    ($x:expr, T8 == $expected:expr $(,)?) => {
+        $crate::assert_or_eq!(@impl $x, T8, as_t8, $expected)
+    };
+    ($x:expr, T9 == $expected:expr $(,)?) => {
+        $crate::assert_or_eq!(@impl $x, T9, as_t9, $expected)
+    };
+    ($x:expr, T10 == $expected:expr $(,)?) => {
+        $crate::assert_or_eq!(@impl $x, T10, as_t10, $expected)
+    };
+    ($x:expr, T11 == $expected:expr $(,)?) => {
+        $crate::assert_or_eq!(@impl $x, T11, as_t11, $expected)
+    };
+    ($x:expr, T12 == $expected:expr $(,)?) => {
+        $crate::assert_or_eq!(@impl $x, T12, as_t12, $expected)
+    };
+    ($x:expr, T13 == $expected:expr $(,)?) => {
+        $crate::assert_or_eq!(@impl $x, T13, as_t13, $expected)
+    };
+    ($x:expr, T14 == $expected:expr $(,)?) => {
+        $crate::assert_or_eq!(@impl $x, T14, as_t14, $expected)
+    };
+    ($x:expr, T15 == $expected:expr $(,)?) => {
+        $crate::assert_or_eq!(@impl $x, T15, as_t15, $expected)
+    };
+    ($x:expr, T16 == $expected:expr $(,)?) => {
+        $crate::assert_or_eq!(@impl $x, T16, as_t16, $expected)
+    };
+}
+
+/// Borrows the payload of the active variant as a trait object, written as
+/// `as_dyn!(x as &dyn Trait)` or `as_dyn!(x as &mut dyn Trait)`.
+///
+/// This is for the common case where every payload type implements the same trait and
+/// the caller just needs dynamic dispatch over it, without writing a forwarding impl.
+/// Additional bounds like `&dyn Display + Send` are supported, while generic parameters
+/// of the enclosing function can not be referred in the trait.
+///
+/// ## Example
+///
+/// ```rust
+/// use std::fmt::{Display, Write};
+///
+/// use or_rs::as_dyn;
+/// use or_rs::enums::Or3;
+///
+/// let x: Or3<i32, String, f64> = Or3::T2("hello".to_string());
+/// let display = as_dyn!(x as &dyn Display);
+/// assert_eq!(display.to_string(), "hello");
+///
+/// let mut out: Or3<String, String, String> = Or3::T1(String::new());
+/// write!(as_dyn!(out as &mut dyn Write), "{}", 42).unwrap();
+/// assert_eq!(out.as_t1().unwrap(), "42");
+/// ```
+#[macro_export]
+macro_rules! as_dyn {
+    // splits the tokens before `as &dyn`, since an `expr` fragment can not be followed by `as`.
+    (@split [$($x:tt)+] as & mut dyn $($bound:tt)+) => {
+        $crate::as_dyn!(@emit [$($x)+] [&'a mut] [$($bound)+])
+    };
+    (@split [$($x:tt)+] as & dyn $($bound:tt)+) => {
+        $crate::as_dyn!(@emit [$($x)+] [&'a] [$($bound)+])
+    };
+    (@split [$($x:tt)*]) => {
+        ::core::compile_error!("expected `as_dyn!(x as &dyn Trait)` or `as_dyn!(x as &mut dyn Trait)`")
+    };
+    (@split [$($x:tt)*] $head:tt $($rest:tt)*) => {
+        $crate::as_dyn!(@split [$($x)* $head] $($rest)*)
+    };
+    // the payload types are bounded by a local trait having the bounds as its supertraits,
+    // which can not be repeated per payload type in a macro.
+    (@emit [$($x:tt)+] [$($reference:tt)+] [$($bound:tt)+]) => {{
+        trait __OrBound: $($bound)+ {}
+        impl<T: ?::core::marker::Sized + $($bound)+> __OrBound for T {}
+        type __OrDyn<'a> = dyn $($bound)+ + 'a;
+        trait __OrAsDyn<'a> {
+            fn __or_as_dyn(self) -> $($reference)+ __OrDyn<'a>;
+        }
+        $crate::__as_dyn_impls!(__OrAsDyn __OrBound __OrDyn [$($reference)+]);
+        ($($x)+).__or_as_dyn()
+    }};
+    ($($tokens:tt)+) => {
+        $crate::as_dyn!(@split [] $($tokens)+)
+    };
+}
+
+// implements the local trait of `as_dyn!` for a reference to an `Or` type, invoked for each
+// of them by `__as_dyn_impls!` in the `enums` module.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __as_dyn_impl {
+    ($or:ident { $($t:ident)+ } $as_dyn:ident $bound:ident $dyn:ident [$($reference:tt)+]) => {
+        impl<'a, $($t: $bound + 'a),+> $as_dyn<'a> for $($reference)+ $crate::enums::$or<$($t),+> {
+            fn __or_as_dyn(self) -> $($reference)+ $dyn<'a> {
+                match self {
+                    $($crate::enums::$or::$t(value) => value,)+
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,160 @@
+//! A lenient parser of scalar values whose type is not known in advance, like the values of
+//! config entries or command line arguments.
+//!
+//! The input is parsed into a [`Scalar`], trying each [`ScalarKind`] in the order of priority
+//! and taking the first one that succeeds. When none of them succeeds, the error of every
+//! attempt is returned in [`ScalarError`].
+//!
+//! ```rust
+//! use or_rs::parse::{parse_scalar, parse_scalar_with, ScalarKind};
+//!
+//! assert_eq!(parse_scalar("42").unwrap().as_t1(), Some(42));
+//! assert_eq!(parse_scalar("4.2").unwrap().as_t2(), Some(4.2));
+//! assert_eq!(parse_scalar("true").unwrap().as_t3(), Some(true));
+//! assert_eq!(parse_scalar("hello").unwrap().as_t4().as_deref(), Some("hello"));
+//! assert!(parse_scalar("null").unwrap().is_t5());
+//!
+//! // keeps the numbers as strings, but still accepts booleans.
+//! let priority = [ScalarKind::Bool, ScalarKind::String];
+//! assert_eq!(parse_scalar_with("42", &priority).unwrap().as_t4().as_deref(), Some("42"));
+//! ```
+
+use std::error::Error;
+use std::fmt;
+use std::num::{ParseFloatError, ParseIntError};
+use std::str::ParseBoolError;
+
+use crate::enums::{Or4, Or5};
+
+/// A scalar value, which is either an integer, a float, a boolean, a string or a unit.
+pub type Scalar = Or5<i64, f64, bool, String, ()>;
+
+/// The failure of parsing the input as one of the kinds of [`Scalar`].
+/// Parsing as a string never fails, so it has no corresponding variant.
+pub type ScalarFailure = Or4<ParseIntError, ParseFloatError, ParseBoolError, ParseUnitError>;
+
+/// The kinds of [`Scalar`], used to specify the priority of parsing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScalarKind {
+    Int,
+    Float,
+    Bool,
+    String,
+    Unit,
+}
+
+/// The default priority used by [`parse_scalar`]. Integers come before floats, so that
+/// `"1"` is parsed as an integer, and strings come last, since they accept any input.
+pub const DEFAULT_PRIORITY: [ScalarKind; 5] = [
+    ScalarKind::Unit,
+    ScalarKind::Bool,
+    ScalarKind::Int,
+    ScalarKind::Float,
+    ScalarKind::String,
+];
+
+/// Parses the input into a [`Scalar`] with the [`DEFAULT_PRIORITY`].
+pub fn parse_scalar(input: &str) -> Result<Scalar, ScalarError> {
+    parse_scalar_with(input, &DEFAULT_PRIORITY)
+}
+
+/// Parses the input into a [`Scalar`], trying the kinds in the order of `priority`.
+/// The kinds not in `priority` are never tried.
+pub fn parse_scalar_with(input: &str, priority: &[ScalarKind]) -> Result<Scalar, ScalarError> {
+    let mut failures = Vec::new();
+
+    for kind in priority {
+        let result = match kind {
+            ScalarKind::Int => input.parse().map(Or5::T1).map_err(Or4::T1),
+            ScalarKind::Float => input.parse().map(Or5::T2).map_err(Or4::T2),
+            ScalarKind::Bool => input.parse().map(Or5::T3).map_err(Or4::T3),
+            ScalarKind::String => Ok(Or5::T4(input.to_string())),
+            ScalarKind::Unit => parse_unit(input).map(Or5::T5).map_err(Or4::T4),
+        };
+        match result {
+            Ok(scalar) => return Ok(scalar),
+            Err(failure) => failures.push(failure),
+        }
+    }
+
+    Err(ScalarError {
+        input: input.to_string(),
+        failures,
+    })
+}
+
+// a unit is written as an empty string or `null`.
+fn parse_unit(input: &str) -> Result<(), ParseUnitError> {
+    match input {
+        "" | "null" => Ok(()),
+        _ => Err(ParseUnitError),
+    }
+}
+
+/// An error which can be returned when parsing a unit, which is written as an empty string
+/// or `null`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseUnitError;
+
+impl fmt::Display for ParseUnitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "provided string was not empty or `null`")
+    }
+}
+
+impl Error for ParseUnitError {}
+
+/// An error returned when the input can not be parsed as any of the kinds tried.
+pub struct ScalarError {
+    input: String,
+    failures: Vec<ScalarFailure>,
+}
+
+impl ScalarError {
+    /// Returns the input that failed to be parsed.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// Returns the failures of the kinds tried, in the order of the priority.
+    pub fn failures(&self) -> &[ScalarFailure] {
+        &self.failures
+    }
+}
+
+// the name of the kind that failed, along with its error.
+fn describe(failure: &ScalarFailure) -> (&'static str, &(dyn Error + 'static)) {
+    match failure {
+        Or4::T1(e) => ("int", e),
+        Or4::T2(e) => ("float", e),
+        Or4::T3(e) => ("bool", e),
+        Or4::T4(e) => ("unit", e),
+    }
+}
+
+impl fmt::Debug for ScalarError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let failures = self
+            .failures
+            .iter()
+            .map(|failure| describe(failure).1)
+            .collect::<Vec<_>>();
+        f.debug_struct("ScalarError")
+            .field("input", &self.input)
+            .field("failures", &failures)
+            .finish()
+    }
+}
+
+impl fmt::Display for ScalarError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed to parse `{}` as a scalar", self.input)?;
+        for failure in &self.failures {
+            let (kind, error) = describe(failure);
+            write!(f, "\n  {}: {}", kind, error)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ScalarError {}
@@ -0,0 +1,49 @@
+//! Polymorphic mapping of `Or` types, by a single mapper implemented for each payload type.
+//!
+//! `map_tx` maps one of the variants, and `fold` takes a function for each of them. A type
+//! implementing [`PolyMap`] for all of the payload types maps whichever variant is active by
+//! `map_poly`, and can be shared between `Or` types of different arities.
+//!
+//! ```rust
+//! use or_rs::enums::Or3;
+//! use or_rs::poly::PolyMap;
+//!
+//! struct Describe;
+//!
+//! impl PolyMap<i32> for Describe {
+//!     type Output = String;
+//!     fn call(self, value: i32) -> String {
+//!         format!("int {}", value)
+//!     }
+//! }
+//!
+//! impl PolyMap<bool> for Describe {
+//!     type Output = String;
+//!     fn call(self, value: bool) -> String {
+//!         format!("bool {}", value)
+//!     }
+//! }
+//!
+//! impl<T> PolyMap<Vec<T>> for Describe {
+//!     type Output = usize;
+//!     fn call(self, value: Vec<T>) -> usize {
+//!         value.len()
+//!     }
+//! }
+//!
+//! let x: Or3<i32, bool, Vec<u8>> = Or3::T2(true);
+//! let y: Or3<String, String, usize> = x.map_poly(Describe);
+//! assert_eq!(y.as_t2(), Some("bool true".to_string()));
+//! ```
+
+/// A mapper of values of type `T`, implemented for each payload type of the `Or` types given to
+/// `map_poly`.
+///
+/// The mapper is consumed by the call, since only the active variant is mapped.
+pub trait PolyMap<T> {
+    /// The type of the mapped value.
+    type Output;
+
+    /// Maps the value.
+    fn call(self, value: T) -> Self::Output;
+}
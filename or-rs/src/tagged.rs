@@ -0,0 +1,36 @@
+//! The adjacently tagged serde representation of the `Or` types, enabled by the `serde` feature.
+//!
+//! The `Or` types are serialized untagged by default, which can not tell the variants apart
+//! when their types accept the same values, like `Or2<i32, f64>` deserializing `1` into
+//! `T1` even if it was serialized from `T2`. Wrapping the value into [`Tagged`] serializes it
+//! along with the name of its variant instead, like `{"variant": "T2", "value": 1.0}`, and
+//! deserializes it into exactly that variant.
+//!
+//! ```rust
+//! use or_rs::enums::Or2;
+//! use or_rs::tagged::Tagged;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Sample {
+//!     value: Tagged<Or2<i32, f64>>,
+//! }
+//! ```
+
+/// A wrapper of an `Or` type, which is serialized as the name of the active variant along with
+/// its value, like `{"variant": "T2", "value": 1.0}`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Tagged<O>(pub O);
+
+impl<O> Tagged<O> {
+    /// Unwraps the `Or` value.
+    pub fn into_inner(self) -> O {
+        self.0
+    }
+}
+
+impl<O> From<O> for Tagged<O> {
+    fn from(value: O) -> Self {
+        Self(value)
+    }
+}
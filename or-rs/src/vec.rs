@@ -0,0 +1,1389 @@
+//! Struct-of-arrays containers of the `Or` types.
+//!
+//! `OrVecX` stores the payloads of each variant in a separate `Vec`, plus the order in which
+//! the values have been pushed. Compared to `Vec<OrX<...>>`, no space is wasted on padding
+//! the payloads to the size of the largest variant, which matters when storing a huge number
+//! of small heterogeneous values.
+
+use alloc::vec::Vec;
+use core::iter::FromIterator;
+use core::slice;
+
+use crate::enums::*;
+
+/// `OrVec2` is a struct-of-arrays container of `Or2` values.
+pub struct OrVec2<T1, T2> {
+    t1: Vec<T1>,
+    t2: Vec<T2>,
+    // the (0-based) variant of each value, in the order of insertion.
+    order: Vec<u8>,
+}
+
+impl<T1, T2> OrVec2<T1, T2> {
+    /// Creates an empty container.
+    pub fn new() -> Self {
+        Self {
+            t1: Vec::new(),
+            t2: Vec::new(),
+            order: Vec::new(),
+        }
+    }
+
+    /// Appends a value to the back of the container.
+    pub fn push(&mut self, value: Or2<T1, T2>) {
+        match value {
+            Or2::T1(t1) => {
+                self.t1.push(t1);
+                self.order.push(0);
+            }
+            Or2::T2(t2) => {
+                self.t2.push(t2);
+                self.order.push(1);
+            }
+        }
+    }
+
+    /// Returns the number of values in the container.
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    /// Returns true if the container contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    /// Returns an iterator over the values in the order of insertion, each borrowed as `Or2`.
+    pub fn iter(&self) -> OrVec2Iter<'_, T1, T2> {
+        OrVec2Iter {
+            t1: self.t1.iter(),
+            t2: self.t2.iter(),
+            order: self.order.iter(),
+        }
+    }
+
+    /// Returns the T1 values in the container, in the order of insertion.
+    pub fn as_t1_slice(&self) -> &[T1] {
+        &self.t1
+    }
+
+    /// Returns the T2 values in the container, in the order of insertion.
+    pub fn as_t2_slice(&self) -> &[T2] {
+        &self.t2
+    }
+}
+
+impl<T1, T2> Default for OrVec2<T1, T2> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T1, T2> Extend<Or2<T1, T2>> for OrVec2<T1, T2> {
+    fn extend<I: IntoIterator<Item = Or2<T1, T2>>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<T1, T2> FromIterator<Or2<T1, T2>> for OrVec2<T1, T2> {
+    fn from_iter<I: IntoIterator<Item = Or2<T1, T2>>>(iter: I) -> Self {
+        let mut vec = Self::new();
+        vec.extend(iter);
+        vec
+    }
+}
+
+impl<'a, T1, T2> IntoIterator for &'a OrVec2<T1, T2> {
+    type Item = Or2<&'a T1, &'a T2>;
+    type IntoIter = OrVec2Iter<'a, T1, T2>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the values of [`OrVec2`], created by [`OrVec2::iter`].
+pub struct OrVec2Iter<'a, T1, T2> {
+    t1: slice::Iter<'a, T1>,
+    t2: slice::Iter<'a, T2>,
+    order: slice::Iter<'a, u8>,
+}
+
+impl<'a, T1, T2> Iterator for OrVec2Iter<'a, T1, T2> {
+    type Item = Or2<&'a T1, &'a T2>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = match self.order.next()? {
+            0 => Or2::T1(self.t1.next()?),
+            1 => Or2::T2(self.t2.next()?),
+            _ => unreachable!("invalid variant index"),
+        };
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.order.size_hint()
+    }
+}
+
+impl<'a, T1, T2> ExactSizeIterator for OrVec2Iter<'a, T1, T2> {}
+
+/// `OrVec3` is a struct-of-arrays container of `Or3` values.
+pub struct OrVec3<T1, T2, T3> {
+    t1: Vec<T1>,
+    t2: Vec<T2>,
+    t3: Vec<T3>,
+    // the (0-based) variant of each value, in the order of insertion.
+    order: Vec<u8>,
+}
+
+impl<T1, T2, T3> OrVec3<T1, T2, T3> {
+    /// Creates an empty container.
+    pub fn new() -> Self {
+        Self {
+            t1: Vec::new(),
+            t2: Vec::new(),
+            t3: Vec::new(),
+            order: Vec::new(),
+        }
+    }
+
+    /// Appends a value to the back of the container.
+    pub fn push(&mut self, value: Or3<T1, T2, T3>) {
+        match value {
+            Or3::T1(t1) => {
+                self.t1.push(t1);
+                self.order.push(0);
+            }
+            Or3::T2(t2) => {
+                self.t2.push(t2);
+                self.order.push(1);
+            }
+            Or3::T3(t3) => {
+                self.t3.push(t3);
+                self.order.push(2);
+            }
+        }
+    }
+
+    /// Returns the number of values in the container.
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    /// Returns true if the container contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    /// Returns an iterator over the values in the order of insertion, each borrowed as `Or3`.
+    pub fn iter(&self) -> OrVec3Iter<'_, T1, T2, T3> {
+        OrVec3Iter {
+            t1: self.t1.iter(),
+            t2: self.t2.iter(),
+            t3: self.t3.iter(),
+            order: self.order.iter(),
+        }
+    }
+
+    /// Returns the T1 values in the container, in the order of insertion.
+    pub fn as_t1_slice(&self) -> &[T1] {
+        &self.t1
+    }
+
+    /// Returns the T2 values in the container, in the order of insertion.
+    pub fn as_t2_slice(&self) -> &[T2] {
+        &self.t2
+    }
+
+    /// Returns the T3 values in the container, in the order of insertion.
+    pub fn as_t3_slice(&self) -> &[T3] {
+        &self.t3
+    }
+}
+
+impl<T1, T2, T3> Default for OrVec3<T1, T2, T3> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T1, T2, T3> Extend<Or3<T1, T2, T3>> for OrVec3<T1, T2, T3> {
+    fn extend<I: IntoIterator<Item = Or3<T1, T2, T3>>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<T1, T2, T3> FromIterator<Or3<T1, T2, T3>> for OrVec3<T1, T2, T3> {
+    fn from_iter<I: IntoIterator<Item = Or3<T1, T2, T3>>>(iter: I) -> Self {
+        let mut vec = Self::new();
+        vec.extend(iter);
+        vec
+    }
+}
+
+impl<'a, T1, T2, T3> IntoIterator for &'a OrVec3<T1, T2, T3> {
+    type Item = Or3<&'a T1, &'a T2, &'a T3>;
+    type IntoIter = OrVec3Iter<'a, T1, T2, T3>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the values of [`OrVec3`], created by [`OrVec3::iter`].
+pub struct OrVec3Iter<'a, T1, T2, T3> {
+    t1: slice::Iter<'a, T1>,
+    t2: slice::Iter<'a, T2>,
+    t3: slice::Iter<'a, T3>,
+    order: slice::Iter<'a, u8>,
+}
+
+impl<'a, T1, T2, T3> Iterator for OrVec3Iter<'a, T1, T2, T3> {
+    type Item = Or3<&'a T1, &'a T2, &'a T3>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = match self.order.next()? {
+            0 => Or3::T1(self.t1.next()?),
+            1 => Or3::T2(self.t2.next()?),
+            2 => Or3::T3(self.t3.next()?),
+            _ => unreachable!("invalid variant index"),
+        };
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.order.size_hint()
+    }
+}
+
+impl<'a, T1, T2, T3> ExactSizeIterator for OrVec3Iter<'a, T1, T2, T3> {}
+
+/// `OrVec4` is a struct-of-arrays container of `Or4` values.
+pub struct OrVec4<T1, T2, T3, T4> {
+    t1: Vec<T1>,
+    t2: Vec<T2>,
+    t3: Vec<T3>,
+    t4: Vec<T4>,
+    // the (0-based) variant of each value, in the order of insertion.
+    order: Vec<u8>,
+}
+
+impl<T1, T2, T3, T4> OrVec4<T1, T2, T3, T4> {
+    /// Creates an empty container.
+    pub fn new() -> Self {
+        Self {
+            t1: Vec::new(),
+            t2: Vec::new(),
+            t3: Vec::new(),
+            t4: Vec::new(),
+            order: Vec::new(),
+        }
+    }
+
+    /// Appends a value to the back of the container.
+    pub fn push(&mut self, value: Or4<T1, T2, T3, T4>) {
+        match value {
+            Or4::T1(t1) => {
+                self.t1.push(t1);
+                self.order.push(0);
+            }
+            Or4::T2(t2) => {
+                self.t2.push(t2);
+                self.order.push(1);
+            }
+            Or4::T3(t3) => {
+                self.t3.push(t3);
+                self.order.push(2);
+            }
+            Or4::T4(t4) => {
+                self.t4.push(t4);
+                self.order.push(3);
+            }
+        }
+    }
+
+    /// Returns the number of values in the container.
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    /// Returns true if the container contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    /// Returns an iterator over the values in the order of insertion, each borrowed as `Or4`.
+    pub fn iter(&self) -> OrVec4Iter<'_, T1, T2, T3, T4> {
+        OrVec4Iter {
+            t1: self.t1.iter(),
+            t2: self.t2.iter(),
+            t3: self.t3.iter(),
+            t4: self.t4.iter(),
+            order: self.order.iter(),
+        }
+    }
+
+    /// Returns the T1 values in the container, in the order of insertion.
+    pub fn as_t1_slice(&self) -> &[T1] {
+        &self.t1
+    }
+
+    /// Returns the T2 values in the container, in the order of insertion.
+    pub fn as_t2_slice(&self) -> &[T2] {
+        &self.t2
+    }
+
+    /// Returns the T3 values in the container, in the order of insertion.
+    pub fn as_t3_slice(&self) -> &[T3] {
+        &self.t3
+    }
+
+    /// Returns the T4 values in the container, in the order of insertion.
+    pub fn as_t4_slice(&self) -> &[T4] {
+        &self.t4
+    }
+}
+
+impl<T1, T2, T3, T4> Default for OrVec4<T1, T2, T3, T4> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T1, T2, T3, T4> Extend<Or4<T1, T2, T3, T4>> for OrVec4<T1, T2, T3, T4> {
+    fn extend<I: IntoIterator<Item = Or4<T1, T2, T3, T4>>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<T1, T2, T3, T4> FromIterator<Or4<T1, T2, T3, T4>> for OrVec4<T1, T2, T3, T4> {
+    fn from_iter<I: IntoIterator<Item = Or4<T1, T2, T3, T4>>>(iter: I) -> Self {
+        let mut vec = Self::new();
+        vec.extend(iter);
+        vec
+    }
+}
+
+impl<'a, T1, T2, T3, T4> IntoIterator for &'a OrVec4<T1, T2, T3, T4> {
+    type Item = Or4<&'a T1, &'a T2, &'a T3, &'a T4>;
+    type IntoIter = OrVec4Iter<'a, T1, T2, T3, T4>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the values of [`OrVec4`], created by [`OrVec4::iter`].
+pub struct OrVec4Iter<'a, T1, T2, T3, T4> {
+    t1: slice::Iter<'a, T1>,
+    t2: slice::Iter<'a, T2>,
+    t3: slice::Iter<'a, T3>,
+    t4: slice::Iter<'a, T4>,
+    order: slice::Iter<'a, u8>,
+}
+
+impl<'a, T1, T2, T3, T4> Iterator for OrVec4Iter<'a, T1, T2, T3, T4> {
+    type Item = Or4<&'a T1, &'a T2, &'a T3, &'a T4>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = match self.order.next()? {
+            0 => Or4::T1(self.t1.next()?),
+            1 => Or4::T2(self.t2.next()?),
+            2 => Or4::T3(self.t3.next()?),
+            3 => Or4::T4(self.t4.next()?),
+            _ => unreachable!("invalid variant index"),
+        };
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.order.size_hint()
+    }
+}
+
+impl<'a, T1, T2, T3, T4> ExactSizeIterator for OrVec4Iter<'a, T1, T2, T3, T4> {}
+
+/// `OrVec5` is a struct-of-arrays container of `Or5` values.
+pub struct OrVec5<T1, T2, T3, T4, T5> {
+    t1: Vec<T1>,
+    t2: Vec<T2>,
+    t3: Vec<T3>,
+    t4: Vec<T4>,
+    t5: Vec<T5>,
+    // the (0-based) variant of each value, in the order of insertion.
+    order: Vec<u8>,
+}
+
+impl<T1, T2, T3, T4, T5> OrVec5<T1, T2, T3, T4, T5> {
+    /// Creates an empty container.
+    pub fn new() -> Self {
+        Self {
+            t1: Vec::new(),
+            t2: Vec::new(),
+            t3: Vec::new(),
+            t4: Vec::new(),
+            t5: Vec::new(),
+            order: Vec::new(),
+        }
+    }
+
+    /// Appends a value to the back of the container.
+    pub fn push(&mut self, value: Or5<T1, T2, T3, T4, T5>) {
+        match value {
+            Or5::T1(t1) => {
+                self.t1.push(t1);
+                self.order.push(0);
+            }
+            Or5::T2(t2) => {
+                self.t2.push(t2);
+                self.order.push(1);
+            }
+            Or5::T3(t3) => {
+                self.t3.push(t3);
+                self.order.push(2);
+            }
+            Or5::T4(t4) => {
+                self.t4.push(t4);
+                self.order.push(3);
+            }
+            Or5::T5(t5) => {
+                self.t5.push(t5);
+                self.order.push(4);
+            }
+        }
+    }
+
+    /// Returns the number of values in the container.
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    /// Returns true if the container contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    /// Returns an iterator over the values in the order of insertion, each borrowed as `Or5`.
+    pub fn iter(&self) -> OrVec5Iter<'_, T1, T2, T3, T4, T5> {
+        OrVec5Iter {
+            t1: self.t1.iter(),
+            t2: self.t2.iter(),
+            t3: self.t3.iter(),
+            t4: self.t4.iter(),
+            t5: self.t5.iter(),
+            order: self.order.iter(),
+        }
+    }
+
+    /// Returns the T1 values in the container, in the order of insertion.
+    pub fn as_t1_slice(&self) -> &[T1] {
+        &self.t1
+    }
+
+    /// Returns the T2 values in the container, in the order of insertion.
+    pub fn as_t2_slice(&self) -> &[T2] {
+        &self.t2
+    }
+
+    /// Returns the T3 values in the container, in the order of insertion.
+    pub fn as_t3_slice(&self) -> &[T3] {
+        &self.t3
+    }
+
+    /// Returns the T4 values in the container, in the order of insertion.
+    pub fn as_t4_slice(&self) -> &[T4] {
+        &self.t4
+    }
+
+    /// Returns the T5 values in the container, in the order of insertion.
+    pub fn as_t5_slice(&self) -> &[T5] {
+        &self.t5
+    }
+}
+
+impl<T1, T2, T3, T4, T5> Default for OrVec5<T1, T2, T3, T4, T5> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T1, T2, T3, T4, T5> Extend<Or5<T1, T2, T3, T4, T5>> for OrVec5<T1, T2, T3, T4, T5> {
+    fn extend<I: IntoIterator<Item = Or5<T1, T2, T3, T4, T5>>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5> FromIterator<Or5<T1, T2, T3, T4, T5>> for OrVec5<T1, T2, T3, T4, T5> {
+    fn from_iter<I: IntoIterator<Item = Or5<T1, T2, T3, T4, T5>>>(iter: I) -> Self {
+        let mut vec = Self::new();
+        vec.extend(iter);
+        vec
+    }
+}
+
+impl<'a, T1, T2, T3, T4, T5> IntoIterator for &'a OrVec5<T1, T2, T3, T4, T5> {
+    type Item = Or5<&'a T1, &'a T2, &'a T3, &'a T4, &'a T5>;
+    type IntoIter = OrVec5Iter<'a, T1, T2, T3, T4, T5>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the values of [`OrVec5`], created by [`OrVec5::iter`].
+pub struct OrVec5Iter<'a, T1, T2, T3, T4, T5> {
+    t1: slice::Iter<'a, T1>,
+    t2: slice::Iter<'a, T2>,
+    t3: slice::Iter<'a, T3>,
+    t4: slice::Iter<'a, T4>,
+    t5: slice::Iter<'a, T5>,
+    order: slice::Iter<'a, u8>,
+}
+
+impl<'a, T1, T2, T3, T4, T5> Iterator for OrVec5Iter<'a, T1, T2, T3, T4, T5> {
+    type Item = Or5<&'a T1, &'a T2, &'a T3, &'a T4, &'a T5>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = match self.order.next()? {
+            0 => Or5::T1(self.t1.next()?),
+            1 => Or5::T2(self.t2.next()?),
+            2 => Or5::T3(self.t3.next()?),
+            3 => Or5::T4(self.t4.next()?),
+            4 => Or5::T5(self.t5.next()?),
+            _ => unreachable!("invalid variant index"),
+        };
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.order.size_hint()
+    }
+}
+
+impl<'a, T1, T2, T3, T4, T5> ExactSizeIterator for OrVec5Iter<'a, T1, T2, T3, T4, T5> {}
+
+/// `OrVec6` is a struct-of-arrays container of `Or6` values.
+pub struct OrVec6<T1, T2, T3, T4, T5, T6> {
+    t1: Vec<T1>,
+    t2: Vec<T2>,
+    t3: Vec<T3>,
+    t4: Vec<T4>,
+    t5: Vec<T5>,
+    t6: Vec<T6>,
+    // the (0-based) variant of each value, in the order of insertion.
+    order: Vec<u8>,
+}
+
+impl<T1, T2, T3, T4, T5, T6> OrVec6<T1, T2, T3, T4, T5, T6> {
+    /// Creates an empty container.
+    pub fn new() -> Self {
+        Self {
+            t1: Vec::new(),
+            t2: Vec::new(),
+            t3: Vec::new(),
+            t4: Vec::new(),
+            t5: Vec::new(),
+            t6: Vec::new(),
+            order: Vec::new(),
+        }
+    }
+
+    /// Appends a value to the back of the container.
+    pub fn push(&mut self, value: Or6<T1, T2, T3, T4, T5, T6>) {
+        match value {
+            Or6::T1(t1) => {
+                self.t1.push(t1);
+                self.order.push(0);
+            }
+            Or6::T2(t2) => {
+                self.t2.push(t2);
+                self.order.push(1);
+            }
+            Or6::T3(t3) => {
+                self.t3.push(t3);
+                self.order.push(2);
+            }
+            Or6::T4(t4) => {
+                self.t4.push(t4);
+                self.order.push(3);
+            }
+            Or6::T5(t5) => {
+                self.t5.push(t5);
+                self.order.push(4);
+            }
+            Or6::T6(t6) => {
+                self.t6.push(t6);
+                self.order.push(5);
+            }
+        }
+    }
+
+    /// Returns the number of values in the container.
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    /// Returns true if the container contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    /// Returns an iterator over the values in the order of insertion, each borrowed as `Or6`.
+    pub fn iter(&self) -> OrVec6Iter<'_, T1, T2, T3, T4, T5, T6> {
+        OrVec6Iter {
+            t1: self.t1.iter(),
+            t2: self.t2.iter(),
+            t3: self.t3.iter(),
+            t4: self.t4.iter(),
+            t5: self.t5.iter(),
+            t6: self.t6.iter(),
+            order: self.order.iter(),
+        }
+    }
+
+    /// Returns the T1 values in the container, in the order of insertion.
+    pub fn as_t1_slice(&self) -> &[T1] {
+        &self.t1
+    }
+
+    /// Returns the T2 values in the container, in the order of insertion.
+    pub fn as_t2_slice(&self) -> &[T2] {
+        &self.t2
+    }
+
+    /// Returns the T3 values in the container, in the order of insertion.
+    pub fn as_t3_slice(&self) -> &[T3] {
+        &self.t3
+    }
+
+    /// Returns the T4 values in the container, in the order of insertion.
+    pub fn as_t4_slice(&self) -> &[T4] {
+        &self.t4
+    }
+
+    /// Returns the T5 values in the container, in the order of insertion.
+    pub fn as_t5_slice(&self) -> &[T5] {
+        &self.t5
+    }
+
+    /// Returns the T6 values in the container, in the order of insertion.
+    pub fn as_t6_slice(&self) -> &[T6] {
+        &self.t6
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6> Default for OrVec6<T1, T2, T3, T4, T5, T6> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6> Extend<Or6<T1, T2, T3, T4, T5, T6>>
+    for OrVec6<T1, T2, T3, T4, T5, T6>
+{
+    fn extend<I: IntoIterator<Item = Or6<T1, T2, T3, T4, T5, T6>>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6> FromIterator<Or6<T1, T2, T3, T4, T5, T6>>
+    for OrVec6<T1, T2, T3, T4, T5, T6>
+{
+    fn from_iter<I: IntoIterator<Item = Or6<T1, T2, T3, T4, T5, T6>>>(iter: I) -> Self {
+        let mut vec = Self::new();
+        vec.extend(iter);
+        vec
+    }
+}
+
+impl<'a, T1, T2, T3, T4, T5, T6> IntoIterator for &'a OrVec6<T1, T2, T3, T4, T5, T6> {
+    type Item = Or6<&'a T1, &'a T2, &'a T3, &'a T4, &'a T5, &'a T6>;
+    type IntoIter = OrVec6Iter<'a, T1, T2, T3, T4, T5, T6>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the values of [`OrVec6`], created by [`OrVec6::iter`].
+pub struct OrVec6Iter<'a, T1, T2, T3, T4, T5, T6> {
+    t1: slice::Iter<'a, T1>,
+    t2: slice::Iter<'a, T2>,
+    t3: slice::Iter<'a, T3>,
+    t4: slice::Iter<'a, T4>,
+    t5: slice::Iter<'a, T5>,
+    t6: slice::Iter<'a, T6>,
+    order: slice::Iter<'a, u8>,
+}
+
+impl<'a, T1, T2, T3, T4, T5, T6> Iterator for OrVec6Iter<'a, T1, T2, T3, T4, T5, T6> {
+    type Item = Or6<&'a T1, &'a T2, &'a T3, &'a T4, &'a T5, &'a T6>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = match self.order.next()? {
+            0 => Or6::T1(self.t1.next()?),
+            1 => Or6::T2(self.t2.next()?),
+            2 => Or6::T3(self.t3.next()?),
+            3 => Or6::T4(self.t4.next()?),
+            4 => Or6::T5(self.t5.next()?),
+            5 => Or6::T6(self.t6.next()?),
+            _ => unreachable!("invalid variant index"),
+        };
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.order.size_hint()
+    }
+}
+
+impl<'a, T1, T2, T3, T4, T5, T6> ExactSizeIterator for OrVec6Iter<'a, T1, T2, T3, T4, T5, T6> {}
+
+/// `OrVec7` is a struct-of-arrays container of `Or7` values.
+pub struct OrVec7<T1, T2, T3, T4, T5, T6, T7> {
+    t1: Vec<T1>,
+    t2: Vec<T2>,
+    t3: Vec<T3>,
+    t4: Vec<T4>,
+    t5: Vec<T5>,
+    t6: Vec<T6>,
+    t7: Vec<T7>,
+    // the (0-based) variant of each value, in the order of insertion.
+    order: Vec<u8>,
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7> OrVec7<T1, T2, T3, T4, T5, T6, T7> {
+    /// Creates an empty container.
+    pub fn new() -> Self {
+        Self {
+            t1: Vec::new(),
+            t2: Vec::new(),
+            t3: Vec::new(),
+            t4: Vec::new(),
+            t5: Vec::new(),
+            t6: Vec::new(),
+            t7: Vec::new(),
+            order: Vec::new(),
+        }
+    }
+
+    /// Appends a value to the back of the container.
+    pub fn push(&mut self, value: Or7<T1, T2, T3, T4, T5, T6, T7>) {
+        match value {
+            Or7::T1(t1) => {
+                self.t1.push(t1);
+                self.order.push(0);
+            }
+            Or7::T2(t2) => {
+                self.t2.push(t2);
+                self.order.push(1);
+            }
+            Or7::T3(t3) => {
+                self.t3.push(t3);
+                self.order.push(2);
+            }
+            Or7::T4(t4) => {
+                self.t4.push(t4);
+                self.order.push(3);
+            }
+            Or7::T5(t5) => {
+                self.t5.push(t5);
+                self.order.push(4);
+            }
+            Or7::T6(t6) => {
+                self.t6.push(t6);
+                self.order.push(5);
+            }
+            Or7::T7(t7) => {
+                self.t7.push(t7);
+                self.order.push(6);
+            }
+        }
+    }
+
+    /// Returns the number of values in the container.
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    /// Returns true if the container contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    /// Returns an iterator over the values in the order of insertion, each borrowed as `Or7`.
+    pub fn iter(&self) -> OrVec7Iter<'_, T1, T2, T3, T4, T5, T6, T7> {
+        OrVec7Iter {
+            t1: self.t1.iter(),
+            t2: self.t2.iter(),
+            t3: self.t3.iter(),
+            t4: self.t4.iter(),
+            t5: self.t5.iter(),
+            t6: self.t6.iter(),
+            t7: self.t7.iter(),
+            order: self.order.iter(),
+        }
+    }
+
+    /// Returns the T1 values in the container, in the order of insertion.
+    pub fn as_t1_slice(&self) -> &[T1] {
+        &self.t1
+    }
+
+    /// Returns the T2 values in the container, in the order of insertion.
+    pub fn as_t2_slice(&self) -> &[T2] {
+        &self.t2
+    }
+
+    /// Returns the T3 values in the container, in the order of insertion.
+    pub fn as_t3_slice(&self) -> &[T3] {
+        &self.t3
+    }
+
+    /// Returns the T4 values in the container, in the order of insertion.
+    pub fn as_t4_slice(&self) -> &[T4] {
+        &self.t4
+    }
+
+    /// Returns the T5 values in the container, in the order of insertion.
+    pub fn as_t5_slice(&self) -> &[T5] {
+        &self.t5
+    }
+
+    /// Returns the T6 values in the container, in the order of insertion.
+    pub fn as_t6_slice(&self) -> &[T6] {
+        &self.t6
+    }
+
+    /// Returns the T7 values in the container, in the order of insertion.
+    pub fn as_t7_slice(&self) -> &[T7] {
+        &self.t7
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7> Default for OrVec7<T1, T2, T3, T4, T5, T6, T7> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7> Extend<Or7<T1, T2, T3, T4, T5, T6, T7>>
+    for OrVec7<T1, T2, T3, T4, T5, T6, T7>
+{
+    fn extend<I: IntoIterator<Item = Or7<T1, T2, T3, T4, T5, T6, T7>>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7> FromIterator<Or7<T1, T2, T3, T4, T5, T6, T7>>
+    for OrVec7<T1, T2, T3, T4, T5, T6, T7>
+{
+    fn from_iter<I: IntoIterator<Item = Or7<T1, T2, T3, T4, T5, T6, T7>>>(iter: I) -> Self {
+        let mut vec = Self::new();
+        vec.extend(iter);
+        vec
+    }
+}
+
+impl<'a, T1, T2, T3, T4, T5, T6, T7> IntoIterator for &'a OrVec7<T1, T2, T3, T4, T5, T6, T7> {
+    type Item = Or7<&'a T1, &'a T2, &'a T3, &'a T4, &'a T5, &'a T6, &'a T7>;
+    type IntoIter = OrVec7Iter<'a, T1, T2, T3, T4, T5, T6, T7>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the values of [`OrVec7`], created by [`OrVec7::iter`].
+pub struct OrVec7Iter<'a, T1, T2, T3, T4, T5, T6, T7> {
+    t1: slice::Iter<'a, T1>,
+    t2: slice::Iter<'a, T2>,
+    t3: slice::Iter<'a, T3>,
+    t4: slice::Iter<'a, T4>,
+    t5: slice::Iter<'a, T5>,
+    t6: slice::Iter<'a, T6>,
+    t7: slice::Iter<'a, T7>,
+    order: slice::Iter<'a, u8>,
+}
+
+impl<'a, T1, T2, T3, T4, T5, T6, T7> Iterator for OrVec7Iter<'a, T1, T2, T3, T4, T5, T6, T7> {
+    type Item = Or7<&'a T1, &'a T2, &'a T3, &'a T4, &'a T5, &'a T6, &'a T7>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = match self.order.next()? {
+            0 => Or7::T1(self.t1.next()?),
+            1 => Or7::T2(self.t2.next()?),
+            2 => Or7::T3(self.t3.next()?),
+            3 => Or7::T4(self.t4.next()?),
+            4 => Or7::T5(self.t5.next()?),
+            5 => Or7::T6(self.t6.next()?),
+            6 => Or7::T7(self.t7.next()?),
+            _ => unreachable!("invalid variant index"),
+        };
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.order.size_hint()
+    }
+}
+
+impl<'a, T1, T2, T3, T4, T5, T6, T7> ExactSizeIterator
+    for OrVec7Iter<'a, T1, T2, T3, T4, T5, T6, T7>
+{
+}
+
+/// `OrVec8` is a struct-of-arrays container of `Or8` values.
+pub struct OrVec8<T1, T2, T3, T4, T5, T6, T7, T8> {
+    t1: Vec<T1>,
+    t2: Vec<T2>,
+    t3: Vec<T3>,
+    t4: Vec<T4>,
+    t5: Vec<T5>,
+    t6: Vec<T6>,
+    t7: Vec<T7>,
+    t8: Vec<T8>,
+    // the (0-based) variant of each value, in the order of insertion.
+    order: Vec<u8>,
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7, T8> OrVec8<T1, T2, T3, T4, T5, T6, T7, T8> {
+    /// Creates an empty container.
+    pub fn new() -> Self {
+        Self {
+            t1: Vec::new(),
+            t2: Vec::new(),
+            t3: Vec::new(),
+            t4: Vec::new(),
+            t5: Vec::new(),
+            t6: Vec::new(),
+            t7: Vec::new(),
+            t8: Vec::new(),
+            order: Vec::new(),
+        }
+    }
+
+    /// Appends a value to the back of the container.
+    pub fn push(&mut self, value: Or8<T1, T2, T3, T4, T5, T6, T7, T8>) {
+        match value {
+            Or8::T1(t1) => {
+                self.t1.push(t1);
+                self.order.push(0);
+            }
+            Or8::T2(t2) => {
+                self.t2.push(t2);
+                self.order.push(1);
+            }
+            Or8::T3(t3) => {
+                self.t3.push(t3);
+                self.order.push(2);
+            }
+            Or8::T4(t4) => {
+                self.t4.push(t4);
+                self.order.push(3);
+            }
+            Or8::T5(t5) => {
+                self.t5.push(t5);
+                self.order.push(4);
+            }
+            Or8::T6(t6) => {
+                self.t6.push(t6);
+                self.order.push(5);
+            }
+            Or8::T7(t7) => {
+                self.t7.push(t7);
+                self.order.push(6);
+            }
+            Or8::T8(t8) => {
+                self.t8.push(t8);
+                self.order.push(7);
+            }
+        }
+    }
+
+    /// Returns the number of values in the container.
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    /// Returns true if the container contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    /// Returns an iterator over the values in the order of insertion, each borrowed as `Or8`.
+    pub fn iter(&self) -> OrVec8Iter<'_, T1, T2, T3, T4, T5, T6, T7, T8> {
+        OrVec8Iter {
+            t1: self.t1.iter(),
+            t2: self.t2.iter(),
+            t3: self.t3.iter(),
+            t4: self.t4.iter(),
+            t5: self.t5.iter(),
+            t6: self.t6.iter(),
+            t7: self.t7.iter(),
+            t8: self.t8.iter(),
+            order: self.order.iter(),
+        }
+    }
+
+    /// Returns the T1 values in the container, in the order of insertion.
+    pub fn as_t1_slice(&self) -> &[T1] {
+        &self.t1
+    }
+
+    /// Returns the T2 values in the container, in the order of insertion.
+    pub fn as_t2_slice(&self) -> &[T2] {
+        &self.t2
+    }
+
+    /// Returns the T3 values in the container, in the order of insertion.
+    pub fn as_t3_slice(&self) -> &[T3] {
+        &self.t3
+    }
+
+    /// Returns the T4 values in the container, in the order of insertion.
+    pub fn as_t4_slice(&self) -> &[T4] {
+        &self.t4
+    }
+
+    /// Returns the T5 values in the container, in the order of insertion.
+    pub fn as_t5_slice(&self) -> &[T5] {
+        &self.t5
+    }
+
+    /// Returns the T6 values in the container, in the order of insertion.
+    pub fn as_t6_slice(&self) -> &[T6] {
+        &self.t6
+    }
+
+    /// Returns the T7 values in the container, in the order of insertion.
+    pub fn as_t7_slice(&self) -> &[T7] {
+        &self.t7
+    }
+
+    /// Returns the T8 values in the container, in the order of insertion.
+    pub fn as_t8_slice(&self) -> &[T8] {
+        &self.t8
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7, T8> Default for OrVec8<T1, T2, T3, T4, T5, T6, T7, T8> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7, T8> Extend<Or8<T1, T2, T3, T4, T5, T6, T7, T8>>
+    for OrVec8<T1, T2, T3, T4, T5, T6, T7, T8>
+{
+    fn extend<I: IntoIterator<Item = Or8<T1, T2, T3, T4, T5, T6, T7, T8>>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7, T8> FromIterator<Or8<T1, T2, T3, T4, T5, T6, T7, T8>>
+    for OrVec8<T1, T2, T3, T4, T5, T6, T7, T8>
+{
+    fn from_iter<I: IntoIterator<Item = Or8<T1, T2, T3, T4, T5, T6, T7, T8>>>(iter: I) -> Self {
+        let mut vec = Self::new();
+        vec.extend(iter);
+        vec
+    }
+}
+
+impl<'a, T1, T2, T3, T4, T5, T6, T7, T8> IntoIterator
+    for &'a OrVec8<T1, T2, T3, T4, T5, T6, T7, T8>
+{
+    type Item = Or8<&'a T1, &'a T2, &'a T3, &'a T4, &'a T5, &'a T6, &'a T7, &'a T8>;
+    type IntoIter = OrVec8Iter<'a, T1, T2, T3, T4, T5, T6, T7, T8>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the values of [`OrVec8`], created by [`OrVec8::iter`].
+pub struct OrVec8Iter<'a, T1, T2, T3, T4, T5, T6, T7, T8> {
+    t1: slice::Iter<'a, T1>,
+    t2: slice::Iter<'a, T2>,
+    t3: slice::Iter<'a, T3>,
+    t4: slice::Iter<'a, T4>,
+    t5: slice::Iter<'a, T5>,
+    t6: slice::Iter<'a, T6>,
+    t7: slice::Iter<'a, T7>,
+    t8: slice::Iter<'a, T8>,
+    order: slice::Iter<'a, u8>,
+}
+
+impl<'a, T1, T2, T3, T4, T5, T6, T7, T8> Iterator
+    for OrVec8Iter<'a, T1, T2, T3, T4, T5, T6, T7, T8>
+{
+    type Item = Or8<&'a T1, &'a T2, &'a T3, &'a T4, &'a T5, &'a T6, &'a T7, &'a T8>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = match self.order.next()? {
+            0 => Or8::T1(self.t1.next()?),
+            1 => Or8::T2(self.t2.next()?),
+            2 => Or8::T3(self.t3.next()?),
+            3 => Or8::T4(self.t4.next()?),
+            4 => Or8::T5(self.t5.next()?),
+            5 => Or8::T6(self.t6.next()?),
+            6 => Or8::T7(self.t7.next()?),
+            7 => Or8::T8(self.t8.next()?),
+            _ => unreachable!("invalid variant index"),
+        };
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.order.size_hint()
+    }
+}
+
+impl<'a, T1, T2, T3, T4, T5, T6, T7, T8> ExactSizeIterator
+    for OrVec8Iter<'a, T1, T2, T3, T4, T5, T6, T7, T8>
+{
+}
+
+/// `OrVec9` is a struct-of-arrays container of `Or9` values.
+pub struct OrVec9<T1, T2, T3, T4, T5, T6, T7, T8, T9> {
+    t1: Vec<T1>,
+    t2: Vec<T2>,
+    t3: Vec<T3>,
+    t4: Vec<T4>,
+    t5: Vec<T5>,
+    t6: Vec<T6>,
+    t7: Vec<T7>,
+    t8: Vec<T8>,
+    t9: Vec<T9>,
+    // the (0-based) variant of each value, in the order of insertion.
+    order: Vec<u8>,
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7, T8, T9> OrVec9<T1, T2, T3, T4, T5, T6, T7, T8, T9> {
+    /// Creates an empty container.
+    pub fn new() -> Self {
+        Self {
+            t1: Vec::new(),
+            t2: Vec::new(),
+            t3: Vec::new(),
+            t4: Vec::new(),
+            t5: Vec::new(),
+            t6: Vec::new(),
+            t7: Vec::new(),
+            t8: Vec::new(),
+            t9: Vec::new(),
+            order: Vec::new(),
+        }
+    }
+
+    /// Appends a value to the back of the container.
+    pub fn push(&mut self, value: Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>) {
+        match value {
+            Or9::T1(t1) => {
+                self.t1.push(t1);
+                self.order.push(0);
+            }
+            Or9::T2(t2) => {
+                self.t2.push(t2);
+                self.order.push(1);
+            }
+            Or9::T3(t3) => {
+                self.t3.push(t3);
+                self.order.push(2);
+            }
+            Or9::T4(t4) => {
+                self.t4.push(t4);
+                self.order.push(3);
+            }
+            Or9::T5(t5) => {
+                self.t5.push(t5);
+                self.order.push(4);
+            }
+            Or9::T6(t6) => {
+                self.t6.push(t6);
+                self.order.push(5);
+            }
+            Or9::T7(t7) => {
+                self.t7.push(t7);
+                self.order.push(6);
+            }
+            Or9::T8(t8) => {
+                self.t8.push(t8);
+                self.order.push(7);
+            }
+            Or9::T9(t9) => {
+                self.t9.push(t9);
+                self.order.push(8);
+            }
+        }
+    }
+
+    /// Returns the number of values in the container.
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    /// Returns true if the container contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    /// Returns an iterator over the values in the order of insertion, each borrowed as `Or9`.
+    pub fn iter(&self) -> OrVec9Iter<'_, T1, T2, T3, T4, T5, T6, T7, T8, T9> {
+        OrVec9Iter {
+            t1: self.t1.iter(),
+            t2: self.t2.iter(),
+            t3: self.t3.iter(),
+            t4: self.t4.iter(),
+            t5: self.t5.iter(),
+            t6: self.t6.iter(),
+            t7: self.t7.iter(),
+            t8: self.t8.iter(),
+            t9: self.t9.iter(),
+            order: self.order.iter(),
+        }
+    }
+
+    /// Returns the T1 values in the container, in the order of insertion.
+    pub fn as_t1_slice(&self) -> &[T1] {
+        &self.t1
+    }
+
+    /// Returns the T2 values in the container, in the order of insertion.
+    pub fn as_t2_slice(&self) -> &[T2] {
+        &self.t2
+    }
+
+    /// Returns the T3 values in the container, in the order of insertion.
+    pub fn as_t3_slice(&self) -> &[T3] {
+        &self.t3
+    }
+
+    /// Returns the T4 values in the container, in the order of insertion.
+    pub fn as_t4_slice(&self) -> &[T4] {
+        &self.t4
+    }
+
+    /// Returns the T5 values in the container, in the order of insertion.
+    pub fn as_t5_slice(&self) -> &[T5] {
+        &self.t5
+    }
+
+    /// Returns the T6 values in the container, in the order of insertion.
+    pub fn as_t6_slice(&self) -> &[T6] {
+        &self.t6
+    }
+
+    /// Returns the T7 values in the container, in the order of insertion.
+    pub fn as_t7_slice(&self) -> &[T7] {
+        &self.t7
+    }
+
+    /// Returns the T8 values in the container, in the order of insertion.
+    pub fn as_t8_slice(&self) -> &[T8] {
+        &self.t8
+    }
+
+    /// Returns the T9 values in the container, in the order of insertion.
+    pub fn as_t9_slice(&self) -> &[T9] {
+        &self.t9
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7, T8, T9> Default for OrVec9<T1, T2, T3, T4, T5, T6, T7, T8, T9> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7, T8, T9> Extend<Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>>
+    for OrVec9<T1, T2, T3, T4, T5, T6, T7, T8, T9>
+{
+    fn extend<I: IntoIterator<Item = Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<T1, T2, T3, T4, T5, T6, T7, T8, T9> FromIterator<Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>>
+    for OrVec9<T1, T2, T3, T4, T5, T6, T7, T8, T9>
+{
+    fn from_iter<I: IntoIterator<Item = Or9<T1, T2, T3, T4, T5, T6, T7, T8, T9>>>(iter: I) -> Self {
+        let mut vec = Self::new();
+        vec.extend(iter);
+        vec
+    }
+}
+
+impl<'a, T1, T2, T3, T4, T5, T6, T7, T8, T9> IntoIterator
+    for &'a OrVec9<T1, T2, T3, T4, T5, T6, T7, T8, T9>
+{
+    type Item = Or9<&'a T1, &'a T2, &'a T3, &'a T4, &'a T5, &'a T6, &'a T7, &'a T8, &'a T9>;
+    type IntoIter = OrVec9Iter<'a, T1, T2, T3, T4, T5, T6, T7, T8, T9>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the values of [`OrVec9`], created by [`OrVec9::iter`].
+pub struct OrVec9Iter<'a, T1, T2, T3, T4, T5, T6, T7, T8, T9> {
+    t1: slice::Iter<'a, T1>,
+    t2: slice::Iter<'a, T2>,
+    t3: slice::Iter<'a, T3>,
+    t4: slice::Iter<'a, T4>,
+    t5: slice::Iter<'a, T5>,
+    t6: slice::Iter<'a, T6>,
+    t7: slice::Iter<'a, T7>,
+    t8: slice::Iter<'a, T8>,
+    t9: slice::Iter<'a, T9>,
+    order: slice::Iter<'a, u8>,
+}
+
+impl<'a, T1, T2, T3, T4, T5, T6, T7, T8, T9> Iterator
+    for OrVec9Iter<'a, T1, T2, T3, T4, T5, T6, T7, T8, T9>
+{
+    type Item = Or9<&'a T1, &'a T2, &'a T3, &'a T4, &'a T5, &'a T6, &'a T7, &'a T8, &'a T9>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = match self.order.next()? {
+            0 => Or9::T1(self.t1.next()?),
+            1 => Or9::T2(self.t2.next()?),
+            2 => Or9::T3(self.t3.next()?),
+            3 => Or9::T4(self.t4.next()?),
+            4 => Or9::T5(self.t5.next()?),
+            5 => Or9::T6(self.t6.next()?),
+            6 => Or9::T7(self.t7.next()?),
+            7 => Or9::T8(self.t8.next()?),
+            8 => Or9::T9(self.t9.next()?),
+            _ => unreachable!("invalid variant index"),
+        };
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.order.size_hint()
+    }
+}
+
+impl<'a, T1, T2, T3, T4, T5, T6, T7, T8, T9> ExactSizeIterator
+    for OrVec9Iter<'a, T1, T2, T3, T4, T5, T6, T7, T8, T9>
+{
+}
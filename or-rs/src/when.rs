@@ -0,0 +1,50 @@
+//! A fluent builder to handle the variants of `Or` types one by one.
+//!
+//! Each `when_tx` call handles the variant `Tx`, and replaces it with [`Infallible`](std::convert::Infallible)
+//! in the type of the remaining `Or`. Once every variant is handled, the result can be taken by
+//! `finish`, otherwise a default value has to be provided by [`When::otherwise`].
+//!
+//! ```rust
+//! use or_rs::enums::Or3;
+//!
+//! let x: Or3<i32, f32, String> = Or3::T2(3.2);
+//!
+//! let s = x
+//!     .when_t1(|a| format!("i32: {}", a))
+//!     .when_t2(|b| format!("f32: {}", b))
+//!     .when_t3(|c| format!("String: {}", c))
+//!     .finish();
+//! assert_eq!(s, "f32: 3.2");
+//! ```
+
+/// The state of a `when_tx` chain, holding either the result of the handled variant
+/// or the `Or` value whose variant is not handled yet.
+pub struct When<R, O> {
+    state: Result<R, O>,
+}
+
+impl<R, O> When<R, O> {
+    pub(crate) fn handled(result: R) -> Self {
+        When { state: Ok(result) }
+    }
+
+    pub(crate) fn pending(or: O) -> Self {
+        When { state: Err(or) }
+    }
+
+    pub(crate) fn into_state(self) -> Result<R, O> {
+        self.state
+    }
+
+    /// Finishes the chain, calling `f` to produce a default value if the active variant
+    /// has not been handled.
+    pub fn otherwise<F>(self, f: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        match self.state {
+            Ok(result) => result,
+            Err(_) => f(),
+        }
+    }
+}
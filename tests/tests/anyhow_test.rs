@@ -0,0 +1,30 @@
+#![cfg(feature = "anyhow")]
+
+use std::num::{ParseFloatError, ParseIntError};
+
+use or_rs::enums::*;
+
+#[test]
+fn test_into_anyhow() {
+    let int_err = "x".parse::<i32>().unwrap_err();
+    let x = Or2::<ParseIntError, ParseFloatError>::T1(int_err.clone());
+
+    // the error of the active variant is moved as is, so it is downcast to the variant.
+    let err = x.into_anyhow();
+    assert_eq!(err.to_string(), int_err.to_string());
+    assert_eq!(err.downcast::<ParseIntError>().unwrap(), int_err);
+}
+
+#[test]
+fn test_context() {
+    let float_err = "x".parse::<f64>().unwrap_err();
+    let x = Or2::<ParseIntError, ParseFloatError>::T2(float_err.clone());
+
+    let err = x.context("failed to read the config");
+    assert_eq!(err.to_string(), "failed to read the config");
+    assert_eq!(
+        format!("{:#}", err),
+        format!("failed to read the config: {}", float_err)
+    );
+    assert_eq!(err.downcast_ref::<ParseFloatError>(), Some(&float_err));
+}
@@ -0,0 +1,60 @@
+#![cfg(feature = "async-graphql")]
+
+use async_graphql::{EmptyMutation, EmptySubscription, Object, Schema, SimpleObject};
+use futures::executor::block_on;
+use or_rs::enums::*;
+
+#[derive(SimpleObject)]
+struct User {
+    name: String,
+}
+
+#[derive(SimpleObject)]
+struct Post {
+    title: String,
+}
+
+struct Query;
+
+#[Object]
+impl Query {
+    async fn search(&self, user: bool) -> Or2<User, Post> {
+        if user {
+            Or2::T1(User {
+                name: "alice".to_string(),
+            })
+        } else {
+            Or2::T2(Post {
+                title: "hello".to_string(),
+            })
+        }
+    }
+}
+
+fn schema() -> Schema<Query, EmptyMutation, EmptySubscription> {
+    Schema::new(Query, EmptyMutation, EmptySubscription)
+}
+
+#[test]
+fn test_union_type() {
+    // the union is named after its members.
+    let sdl = schema().sdl();
+    assert!(sdl.contains("union UserOrPost = User | Post"), "{}", sdl);
+}
+
+#[test]
+fn test_union_resolve() {
+    let query = "{
+        user: search(user: true) { __typename ... on User { name } ... on Post { title } }
+        post: search(user: false) { __typename ... on User { name } ... on Post { title } }
+    }";
+    let response = block_on(schema().execute(query));
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+        response.data.into_json().unwrap(),
+        serde_json::json!({
+            "user": { "__typename": "User", "name": "alice" },
+            "post": { "__typename": "Post", "title": "hello" },
+        })
+    );
+}
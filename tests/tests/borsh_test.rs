@@ -0,0 +1,28 @@
+#![cfg(feature = "borsh")]
+
+use borsh::io::ErrorKind;
+use or_rs::enums::*;
+
+#[test]
+fn test_round_trip() {
+    let x = Or3::<u8, String, bool>::T2("hello".to_string());
+    let bytes = borsh::to_vec(&x).unwrap();
+    // the 0-based index of the variant, followed by the payload.
+    assert_eq!(bytes, [&[1, 5, 0, 0, 0][..], b"hello"].concat());
+    assert_eq!(
+        borsh::from_slice::<Or3<u8, String, bool>>(&bytes).unwrap(),
+        x
+    );
+
+    let x = Or2::<u32, bool>::T1(7);
+    let bytes = borsh::to_vec(&x).unwrap();
+    assert_eq!(bytes, [0, 7, 0, 0, 0]);
+    assert_eq!(borsh::from_slice::<Or2<u32, bool>>(&bytes).unwrap(), x);
+}
+
+#[test]
+fn test_invalid_tag() {
+    let err = borsh::from_slice::<Or2<u32, bool>>(&[2, 7, 0, 0, 0]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert_eq!(err.to_string(), "invalid Or2 variant tag: 2");
+}
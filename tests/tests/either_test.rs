@@ -0,0 +1,38 @@
+#![cfg(feature = "either")]
+
+use either::Either;
+use or_rs::enums::*;
+
+#[test]
+fn test_or2_either() {
+    let x = Or2::<i32, String>::T2("hello".to_string());
+    assert_eq!(x.into_either(), Either::Right("hello".to_string()));
+
+    let x: Or2<i32, String> = Either::Left(3).into();
+    assert_eq!(x, Or2::T1(3));
+    assert_eq!(Either::from(x), Either::Left(3));
+}
+
+#[test]
+fn test_larger_or_either() {
+    // the types after the first are nested into the right side.
+    let x = Or3::<i32, String, bool>::T3(true);
+    let either = x.into_either();
+    assert_eq!(either, Either::Right(Or2::T2(true)));
+
+    let x: Or3<i32, String, bool> = either.into();
+    assert_eq!(x, Or3::T3(true));
+
+    let x: Or3<i32, String, bool> = Either::Left(3).into();
+    assert_eq!(x, Or3::T1(3));
+}
+
+#[test]
+fn test_fold_either() {
+    // the closures are taken the same way as `Either::either`.
+    let x = Or2::<i32, String>::T1(3);
+    assert_eq!(x.fold_either(|n| n * 2, |s| s.len() as i32), 6);
+
+    let x = Or2::<i32, String>::T2("hello".to_string());
+    assert_eq!(x.fold_either(|n| n * 2, |s| s.len() as i32), 5);
+}
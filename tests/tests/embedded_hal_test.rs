@@ -0,0 +1,42 @@
+#![cfg(feature = "embedded-hal")]
+
+use embedded_hal::i2c::I2c;
+use embedded_hal::spi::SpiBus;
+use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTransaction};
+use embedded_hal_mock::eh1::spi::{Mock as SpiMock, Transaction as SpiTransaction};
+use or_rs::enums::*;
+
+#[test]
+fn test_i2c() {
+    let expectations = [I2cTransaction::write_read(
+        0x48,
+        vec![0x00],
+        vec![0x12, 0x34],
+    )];
+    let mut active = I2cMock::new(&expectations);
+
+    // the transaction goes to the driver of the active variant.
+    let mut i2c = Or2::<&mut I2cMock, &mut I2cMock>::T2(&mut active);
+    let mut read = [0; 2];
+    i2c.write_read(0x48, &[0x00], &mut read).unwrap();
+    assert_eq!(read, [0x12, 0x34]);
+
+    active.done();
+}
+
+#[test]
+fn test_spi() {
+    let expectations = [
+        SpiTransaction::transfer_in_place(vec![0x9f, 0x00], vec![0x9f, 0xef]),
+        SpiTransaction::flush(),
+    ];
+    let mut active = SpiMock::new(&expectations);
+
+    let mut spi = Or2::<&mut SpiMock<u8>, &mut SpiMock<u8>>::T1(&mut active);
+    let mut words = [0x9f, 0x00];
+    spi.transfer_in_place(&mut words).unwrap();
+    spi.flush().unwrap();
+    assert_eq!(words, [0x9f, 0xef]);
+
+    active.done();
+}
@@ -0,0 +1,1119 @@
+use or_rs::enums::*;
+
+#[test]
+fn test_from_str() {
+    let x: Or3<i32, f64, String> = "42".parse().unwrap();
+    assert_eq!(x.as_t1().unwrap(), 42);
+
+    let x: Or3<i32, f64, String> = "4.2".parse().unwrap();
+    assert_eq!(x.as_t2().unwrap(), 4.2);
+
+    let x: Or3<i32, f64, String> = "hello".parse().unwrap();
+    assert_eq!(x.as_t3().unwrap(), "hello");
+
+    // every type fails, so all errors are returned.
+    let err = "hello".parse::<Or2<i32, bool>>().unwrap_err();
+    assert_eq!(err.errors().0, "hello".parse::<i32>().unwrap_err());
+    assert_eq!(err.errors().1, "hello".parse::<bool>().unwrap_err());
+    assert_eq!(
+        err.to_string(),
+        "no type could parse the string: invalid digit found in string, provided string was not `true` or `false`"
+    );
+    assert_eq!(
+        format!("{:?}", err),
+        "ParseOrError(ParseIntError { kind: InvalidDigit }, ParseBoolError)"
+    );
+}
+
+#[test]
+fn test_from_any() {
+    use std::any::Any;
+
+    let value: Box<dyn Any> = Box::new("hello".to_string());
+    let x = Or3::<i32, String, f32>::from_any(value).unwrap();
+    assert_eq!(x.as_t2().unwrap(), "hello");
+
+    let value: Box<dyn Any> = Box::new(3.0_f32);
+    let x: Or3<i32, String, f32> = value.try_into().unwrap();
+    assert_eq!(x.as_t3().unwrap(), 3.0);
+
+    // a value of none of the types is handed back.
+    let value: Box<dyn Any> = Box::new(42_u8);
+    let value = Or2::<i32, String>::from_any(value).err().unwrap();
+    assert_eq!(*value.downcast::<u8>().unwrap(), 42);
+}
+
+#[test]
+fn test_into_any() {
+    let x: Or2<i32, String> = Or2::T2("hello".to_string());
+    assert_eq!(x.as_any().downcast_ref::<String>().unwrap(), "hello");
+
+    let value = x.into_any();
+    assert!(value.is::<String>());
+    let x = Or2::<i32, String>::from_any(value).unwrap();
+    assert_eq!(x.as_t2().unwrap(), "hello");
+}
+
+#[test]
+fn test_compact() {
+    use std::convert::Infallible;
+
+    let x: Or3<i32, Infallible, String> = Or3::T3("hello".to_string());
+    let y: Or2<i32, String> = x.compact_t2();
+    assert_eq!(y.as_t2().unwrap(), "hello");
+
+    let x: Or2<Infallible, f32> = Or2::T2(3.0);
+    assert_eq!(x.compact_t1(), 3.0);
+}
+
+#[test]
+fn test_or_from() {
+    use or_rs::or_from;
+
+    #[derive(Debug, PartialEq)]
+    struct NotFound;
+    #[derive(Debug, PartialEq)]
+    struct Timeout(u64);
+
+    or_from!(Or2<NotFound, Timeout>);
+
+    fn fetch(timeout: bool) -> Result<(), Or2<NotFound, Timeout>> {
+        if timeout {
+            Err(Timeout(30))?
+        }
+        Err(NotFound.into())
+    }
+
+    assert_eq!(fetch(true).unwrap_err().as_t2().unwrap(), Timeout(30));
+    assert_eq!(fetch(false).unwrap_err().as_t1().unwrap(), NotFound);
+}
+
+#[test]
+fn test_or_eq() {
+    use or_rs::or_eq;
+
+    #[derive(Debug, PartialEq)]
+    struct Id(u32);
+    #[derive(Debug, PartialEq)]
+    struct Name(&'static str);
+
+    or_eq!(Or2<Id, Name>);
+
+    let x: Or2<Id, Name> = Or2::T2(Name("hello"));
+    assert!(x == Name("hello"));
+    assert!(x != Name("world"));
+    assert!(x != Id(1));
+    assert!(Name("hello") == x);
+}
+
+#[test]
+fn test_when() {
+    let x: Or3<i32, f32, String> = Or3::T3("hello".to_string());
+    let s = x
+        .when_t2(|b| format!("f32: {}", b))
+        .when_t3(|c| format!("String: {}", c))
+        .when_t1(|a| format!("i32: {}", a))
+        .finish();
+    assert_eq!(s, "String: hello");
+
+    // unhandled variants fall back to `otherwise`.
+    let x: Or3<i32, f32, String> = Or3::T1(3);
+    let n = x.when_t2(|b| b as i64).otherwise(|| -1);
+    assert_eq!(n, -1);
+}
+
+// polls a future to completion, which is enough for the futures in these tests.
+fn block_on<F: std::future::Future>(fut: F) -> F::Output {
+    use std::task::{Context, Poll, Waker};
+
+    let mut fut = std::pin::pin!(fut);
+    let mut cx = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = fut.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
+#[test]
+fn test_fold() {
+    let x: Or3<i32, f32, String> = Or3::T3("hello".to_string());
+    let n = x.fold(|a| a as usize, |b| b as usize, |c| c.len());
+    assert_eq!(n, 5);
+}
+
+#[test]
+fn test_fold_async() {
+    async fn len(s: String) -> usize {
+        s.len()
+    }
+
+    let x: Or3<i32, f32, String> = Or3::T3("hello".to_string());
+    let n = block_on(x.fold_async(
+        |a| async move { a as usize },
+        |b| async move { b as usize },
+        len,
+    ));
+    assert_eq!(n, 5);
+}
+
+#[test]
+fn test_map_async() {
+    async fn lookup(id: u32) -> String {
+        format!("user-{}", id)
+    }
+
+    let x: Or2<u32, f32> = Or2::T1(3);
+    let x = block_on(x.map_t1_async(lookup));
+    assert_eq!(x.as_t1().unwrap(), "user-3");
+
+    // other variants are kept as is.
+    let x: Or2<u32, f32> = Or2::T2(3.2);
+    let x = block_on(x.map_t1_async(lookup));
+    assert_eq!(x.as_t2().unwrap(), 3.2);
+}
+
+#[test]
+fn test_into_options() {
+    let x: Or3<i32, f32, String> = Or3::T2(3.2);
+    assert_eq!(x.as_options(), (None, Some(&3.2), None));
+    assert_eq!(x.into_options(), (None, Some(3.2), None));
+}
+
+#[test]
+fn test_try_from_options() {
+    let x = Or3::<i32, f32, String>::try_from_options((None, None, Some("hello".to_string())));
+    assert_eq!(x.unwrap().as_t3().unwrap(), "hello");
+
+    // none or more than one `Some` is an error.
+    let options = (Some(1), Some(3.2), None::<String>);
+    assert_eq!(Or3::try_from_options(options.clone()).err().unwrap(), options);
+    let options = (None::<i32>, None::<f32>);
+    assert_eq!(Or2::try_from_options(options).err().unwrap(), options);
+}
+
+#[test]
+fn test_or_vec() {
+    use or_rs::vec::OrVec3;
+
+    let mut vec: OrVec3<u8, f64, String> = vec![Or3::T1(1), Or3::T3("hello".to_string())]
+        .into_iter()
+        .collect();
+    vec.push(Or3::T2(3.2));
+    vec.push(Or3::T1(2));
+
+    assert_eq!(vec.len(), 4);
+    assert_eq!(vec.as_t1_slice(), &[1, 2]);
+
+    let items = vec
+        .iter()
+        .map(|x| x.fold(|a| a.to_string(), |b| b.to_string(), |c| c.clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(items, vec!["1", "hello", "3.2", "2"]);
+}
+
+#[test]
+fn test_fmt_write() {
+    use std::fmt::{self, Write};
+
+    // a sink counting the bytes written, instead of keeping them.
+    struct Counter(usize);
+    impl Write for Counter {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            self.0 += s.len();
+            Ok(())
+        }
+    }
+
+    let mut x: Or2<String, Counter> = Or2::T1(String::new());
+    write!(x, "{}-{}", 1, 2).unwrap();
+    x.write_char('!').unwrap();
+    assert_eq!(x.as_t1().unwrap(), "1-2!");
+
+    let mut x: Or2<String, Counter> = Or2::T2(Counter(0));
+    write!(x, "{}-{}", 1, 2).unwrap();
+    assert_eq!(x.as_t2().unwrap().0, 3);
+}
+
+#[test]
+fn test_into_boxed_error() {
+    use std::error::Error;
+    use std::num::ParseIntError;
+
+    fn parse(s: &str) -> Result<i32, Box<dyn Error + Send + Sync>> {
+        let x: Or2<ParseIntError, std::fmt::Error> = Or2::T1(s.parse::<i32>().unwrap_err());
+        Err(x.into_boxed_error())
+    }
+
+    let err = parse("x").unwrap_err();
+    assert!(err.downcast_ref::<ParseIntError>().is_some());
+}
+
+#[test]
+fn test_or_error() {
+    use or_rs::or_error;
+    use std::error::Error;
+
+    or_error!(ParseError = Or2<std::num::ParseIntError, std::num::ParseFloatError>);
+
+    fn parse(s: &str) -> Result<f64, ParseError> {
+        if s.contains('.') {
+            Ok(s.parse::<f64>()?)
+        } else {
+            Ok(s.parse::<i32>()? as f64)
+        }
+    }
+
+    let err = parse("x").unwrap_err();
+    assert!(matches!(err, ParseError::T1(_)));
+    assert_eq!(err.to_string(), "invalid digit found in string");
+    assert!(err.source().is_none());
+
+    let or: Or2<_, _> = parse("x.").unwrap_err().into();
+    assert!(or.is_t2());
+    assert!(matches!(ParseError::from(or), ParseError::T2(_)));
+}
+
+#[test]
+fn test_as_ref() {
+    let mut x: Or3<i32, f64, String> = Or3::T3("hello".to_string());
+    assert_eq!(x.as_ref().as_t3().map(|s| s.len()), Some(5));
+
+    if let Or3::T3(s) = x.as_mut() {
+        s.push('!');
+    }
+    assert_eq!(x.as_t3().unwrap(), "hello!");
+}
+
+#[test]
+fn test_assert_macros() {
+    use or_rs::{assert_is_t, assert_or_eq};
+
+    let mut x: Or3<i32, f64, String> = Or3::T2(3.5);
+    assert_is_t!(x, 2);
+    assert_or_eq!(x, T2 == 3.5);
+
+    if let Or3::T2(v) = x.as_mut() {
+        *v += 1.0;
+    }
+    assert_or_eq!(x, T2 == 4.5);
+
+    let message = std::panic::catch_unwind(|| assert_or_eq!(Or2::<i32, f64>::T2(3.0), T2 == 3.5))
+        .unwrap_err()
+        .downcast::<String>()
+        .unwrap();
+    assert_eq!(
+        *message,
+        "assertion failed: `Or2::<i32, f64>::T2(3.0)` is `T2 == 3.5`\n  actual: T2(3.0)\nexpected: T2(3.5)"
+    );
+}
+
+#[test]
+#[should_panic(expected = "assertion failed: `x` is T2, but it is T1\n  actual: T1(5)")]
+fn test_assert_is_t_other_variant() {
+    let x: Or3<i32, f64, String> = Or3::T1(5);
+    or_rs::assert_is_t!(x, 2);
+}
+
+#[test]
+#[should_panic(
+    expected = "assertion failed: `x` is `T2 == 3.5`, but it is T1\n  actual: T1(3)\nexpected: T2(3.5)"
+)]
+fn test_assert_or_eq_other_variant() {
+    let x: Or2<i32, f64> = Or2::T1(3);
+    or_rs::assert_or_eq!(x, T2 == 3.5);
+}
+
+#[test]
+fn test_extend_tuple() {
+    use std::collections::HashSet;
+
+    let mut buffers: (Vec<i32>, String, HashSet<char>) = Default::default();
+    buffers.extend(vec![Or3::<i32, char, char>::T1(1), Or3::T2('a'), Or3::T3('b')]);
+    buffers.extend(vec![Or3::<i32, char, char>::T3('b'), Or3::T1(2)]);
+
+    assert_eq!(buffers.0, vec![1, 2]);
+    assert_eq!(buffers.1, "a");
+    assert_eq!(buffers.2, HashSet::from(['b']));
+}
+
+#[test]
+fn test_items() {
+    let x: Or2<Vec<u32>, Vec<String>> = Or2::T2(vec!["a".to_string(), "b".to_string()]);
+    let items = x
+        .iter_items()
+        .map(|item| item.fold(|n| n.to_string(), |s| s.clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(items, vec!["a", "b"]);
+
+    let x: Or2<Vec<u32>, Vec<String>> = Or2::T1(vec![1, 2]);
+    assert_eq!(x.iter_items().count(), 2);
+    let items = x.into_items().map(|item| item.as_t1().unwrap()).collect::<Vec<_>>();
+    assert_eq!(items, vec![1, 2]);
+}
+
+#[test]
+fn test_cow() {
+    use std::borrow::Cow;
+
+    fn parse(input: &str) -> Or2<Cow<'_, str>, Cow<'_, [u8]>> {
+        match input.strip_prefix("b:") {
+            Some(bytes) => Or2::T2(Cow::Borrowed(bytes.as_bytes())),
+            None => Or2::T1(Cow::Borrowed(input)),
+        }
+    }
+
+    let owned: Or2<String, Vec<u8>> = {
+        let input = "hello".to_string();
+        parse(&input).into_owned()
+    };
+    assert_eq!(owned.as_t1().unwrap(), "hello");
+
+    let mut x = parse("b:ab");
+    if let Or2::T2(bytes) = x.to_mut() {
+        bytes.push(b'c');
+    }
+    assert_eq!(x.as_t2().unwrap().as_ref(), b"abc");
+}
+
+#[test]
+fn test_boxed() {
+    let x: Or3<[u8; 1024], i32, String> = Or3::T1([1; 1024]);
+    let boxed = x.boxed();
+    assert!(std::mem::size_of_val(&boxed) < 1024);
+    assert_eq!(boxed.unboxed().as_t1().unwrap()[0], 1);
+
+    let x = Or2::<i32, String>::T2("hello".to_string()).boxed().unboxed();
+    assert_eq!(x.as_t2().unwrap(), "hello");
+}
+
+#[test]
+fn test_as_dyn() {
+    use or_rs::as_dyn;
+    use std::fmt::{self, Display};
+
+    let values: Vec<Or3<i32, String, f64>> =
+        vec![Or3::T1(1), Or3::T2("two".to_string()), Or3::T3(3.5)];
+    let joined = values
+        .iter()
+        .map(|x| as_dyn!(x as &dyn Display + Sync).to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(joined, vec!["1", "two", "3.5"]);
+
+    struct Upper(String);
+    impl fmt::Write for Upper {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            self.0.push_str(&s.to_uppercase());
+            Ok(())
+        }
+    }
+
+    let mut out: Or2<String, Upper> = Or2::T2(Upper(String::new()));
+    as_dyn!(out as &mut dyn fmt::Write).write_str("hello").unwrap();
+    assert_eq!(out.as_t2().unwrap().0, "HELLO");
+}
+
+#[test]
+fn test_discriminant() {
+    use std::collections::HashSet;
+
+    let values: Vec<Or3<i32, String, f64>> = vec![Or3::T1(1), Or3::T3(2.0), Or3::T1(3)];
+    let kinds = values
+        .iter()
+        .map(Or3::discriminant)
+        .collect::<HashSet<_>>();
+    assert_eq!(kinds.len(), 2);
+    assert!(kinds.contains(&Or3Discriminant::T1));
+    assert!(!kinds.contains(&Or3Discriminant::T2));
+
+    let missing = Or3Discriminant::iter_all()
+        .filter(|kind| !kinds.contains(kind))
+        .collect::<Vec<_>>();
+    assert_eq!(missing, vec![Or3Discriminant::T2]);
+
+    assert!(Or3Discriminant::T1 < Or3Discriminant::T3);
+    assert_eq!(Or4Discriminant::iter_all().len(), 4);
+    assert_eq!(Or2Discriminant::iter_all().next_back(), Some(Or2Discriminant::T2));
+}
+
+#[test]
+fn test_index() {
+    let mut x: Or2<Vec<u8>, [u8; 4]> = Or2::T1(vec![1, 2, 3]);
+    assert_eq!(x[1], 2);
+    assert_eq!(&x[1..], &[2, 3]);
+    x[0] = 10;
+    assert_eq!(x.as_t1().unwrap(), vec![10, 2, 3]);
+
+    let mut x: Or2<Vec<u8>, [u8; 4]> = Or2::T2([1, 2, 3, 4]);
+    x[3] = 40;
+    assert_eq!(x[..], [1, 2, 3, 40]);
+}
+
+#[test]
+fn test_fmt_traits() {
+    let x: Or2<u8, u32> = Or2::T2(255);
+    assert_eq!(format!("{:x}", x), "ff");
+    assert_eq!(format!("{:#X}", x), "0xFF");
+    assert_eq!(format!("{:o}", x), "377");
+    assert_eq!(format!("{:08b}", x), "11111111");
+
+    let x: Or2<f32, f64> = Or2::T1(1500.0);
+    assert_eq!(format!("{:e}", x), "1.5e3");
+    assert_eq!(format!("{:E}", x), "1.5E3");
+
+    let value = 3;
+    let x: Or2<&i32, Box<i32>> = Or2::T1(&value);
+    assert_eq!(format!("{:p}", x), format!("{:p}", &value));
+}
+
+#[test]
+fn test_variant_sizes() {
+    type Large = Or3<u8, [u8; 1024], u32>;
+    assert_eq!(Large::variant_sizes(), [1, 1024, 4]);
+    assert_eq!(Large::variant_aligns(), [1, 1, 4]);
+    assert_eq!(Large::max_variant_size(), 1024);
+    assert!(std::mem::size_of::<Large>() >= Large::max_variant_size());
+
+    const MAX: usize = Or2::<u16, u64>::max_variant_size();
+    assert_eq!(MAX, 8);
+}
+
+#[test]
+fn test_variant_index() {
+    let xs: [Or3<i32, f64, String>; 3] = [Or3::T1(1), Or3::T2(2.0), Or3::T3("3".to_string())];
+    let mut counts = [0; Or3::<i32, f64, String>::VARIANT_COUNT];
+    for x in &xs {
+        counts[x.variant_index()] += 1;
+    }
+    assert_eq!(counts, [1, 1, 1]);
+    assert_eq!(xs[2].variant_index(), 2);
+
+    const INDEX: usize = Or2::<u8, u16>::T2(1).variant_index();
+    assert_eq!(INDEX, 1);
+    assert_eq!(Or9::<u8, u8, u8, u8, u8, u8, u8, u8, u8>::VARIANT_COUNT, 9);
+}
+
+#[test]
+fn test_variant_name() {
+    let x: Or3<i32, f64, String> = Or3::T2(4.2);
+    assert_eq!(x.variant_name(), "T2");
+    assert_eq!(x.type_name(), "f64");
+
+    let x: Or3<i32, f64, String> = Or3::T3("hello".to_string());
+    assert_eq!(x.variant_name(), "T3");
+    assert!(x.type_name().ends_with("String"));
+
+    const NAME: &str = Or2::<u8, u16>::T1(1).variant_name();
+    assert_eq!(NAME, "T1");
+}
+
+#[test]
+fn test_unwrap() {
+    let x: Or3<i32, f64, String> = Or3::T2(4.2);
+    assert_eq!(x.unwrap_t2(), 4.2);
+
+    let x: Or3<i32, f64, String> = Or3::T3("hello".to_string());
+    assert_eq!(x.expect_t3("not a string"), "hello");
+}
+
+#[test]
+#[should_panic(expected = "called `Or3::unwrap_t1()` on a `T2` value")]
+fn test_unwrap_panic() {
+    let x: Or3<i32, f64, String> = Or3::T2(4.2);
+    x.unwrap_t1();
+}
+
+#[test]
+#[should_panic(expected = "not a string")]
+fn test_expect_panic() {
+    let x: Or3<i32, f64, String> = Or3::T1(42);
+    x.expect_t3("not a string");
+}
+
+#[test]
+fn test_unwrap_location() {
+    use std::panic::{self, Location};
+    use std::sync::{Arc, Mutex};
+
+    // the hook is global, so only the panic of this test is recorded.
+    let location = Arc::new(Mutex::new(None));
+    let recorded = Arc::clone(&location);
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        let message = info.payload().downcast_ref::<String>();
+        if message.is_some_and(|m| m.contains("Or2::unwrap_t2()")) {
+            *recorded.lock().unwrap() = info.location().map(|l| (l.file().to_string(), l.line()));
+        }
+    }));
+
+    let x: Or2<i32, f64> = Or2::T1(42);
+    let caller = Location::caller();
+    let result = panic::catch_unwind(|| x.unwrap_t2());
+    panic::set_hook(default_hook);
+
+    assert!(result.is_err());
+    assert_eq!(
+        *location.lock().unwrap(),
+        Some((caller.file().to_string(), caller.line() + 1))
+    );
+}
+
+#[test]
+fn test_reborrow() {
+    fn push(mut out: Or2<&mut Vec<u8>, &mut String>, byte: u8) {
+        match out.reborrow() {
+            Or2::T1(v) => v.push(byte),
+            Or2::T2(s) => s.push(byte as char),
+        }
+    }
+
+    let mut v = Vec::new();
+    let mut out: Or2<&mut Vec<u8>, &mut String> = Or2::T1(&mut v);
+    for byte in b"abc" {
+        push(out.reborrow(), *byte);
+    }
+    push(out, b'd');
+    assert_eq!(v, b"abcd");
+
+    let mut s = String::new();
+    let mut out: Or2<&mut Vec<u8>, &mut String> = Or2::T2(&mut s);
+    push(out.reborrow(), b'x');
+    push(out.reborrow(), b'y');
+    assert_eq!(s, "xy");
+}
+
+#[test]
+fn test_unify() {
+    let x: Or3<u8, u16, u32> = Or3::T2(300);
+    assert_eq!(x.unify::<u64>(), 300);
+
+    let x: Or3<u8, i16, u32> = Or3::T2(-1);
+    assert_eq!(x.unify::<i64>(), -1);
+
+    let x: Or3<u8, i16, u64> = Or3::T3(300);
+    assert_eq!(x.try_unify::<u16>().ok(), Some(300));
+    let x: Or3<u8, i16, u64> = Or3::T2(-1);
+    assert!(x.try_unify::<u16>().err().unwrap().is_t2());
+    let x: Or3<u8, i16, u64> = Or3::T3(u64::MAX);
+    let err = x.try_unify::<u16>().err().unwrap();
+    assert_eq!(
+        err.as_t3().unwrap().to_string(),
+        "out of range integral type conversion attempted"
+    );
+}
+
+#[test]
+fn test_downcast() {
+    // generic code can extract the payload by its type.
+    fn take<T: 'static>(x: Or3<i32, String, f64>) -> Option<T> {
+        x.downcast::<T>().ok()
+    }
+
+    let x: Or3<i32, String, f64> = Or3::T2("hello".to_string());
+    assert_eq!(take::<String>(x).unwrap(), "hello");
+
+    let x: Or3<i32, String, f64> = Or3::T3(4.2);
+    let x = x.downcast::<String>().err().unwrap();
+    assert_eq!(x.downcast::<f64>().ok(), Some(4.2));
+
+    // any variant of the type can be taken when the types are the same.
+    let x: Or2<u8, u8> = Or2::T2(1);
+    assert_eq!(x.downcast::<u8>().ok(), Some(1));
+}
+
+#[test]
+fn test_concat() {
+    use or_rs::concat::{Concat, Concatenated};
+
+    type Lhs = Or2<u8, char>;
+    type Rhs = Or3<i32, String, bool>;
+
+    let x: Lhs = Or2::T2('a');
+    let y: Concatenated<Lhs, Rhs> = x.concat_left::<Rhs>();
+    assert_eq!(y.as_t2(), Some('a'));
+
+    let x: Rhs = Or3::T3(true);
+    let y: Or5<u8, char, i32, String, bool> = x.concat_right::<Lhs>();
+    assert_eq!(y.as_t5(), Some(true));
+
+    let y = <Lhs as Concat<Rhs>>::right(Or3::T1(42));
+    assert_eq!(y.as_t3(), Some(42));
+}
+
+#[test]
+fn test_deref() {
+    // the methods of `str` are called through auto-deref.
+    let x: Or2<Box<str>, String> = Or2::T1("hello".into());
+    assert_eq!(x.len(), 5);
+    assert!(x.starts_with("he"));
+
+    let x: Or2<Box<str>, String> = Or2::T2("world".to_string());
+    assert_eq!(x.to_uppercase(), "WORLD");
+    assert_eq!(&*x, "world");
+
+    let mut x: Or2<Vec<u8>, Box<[u8]>> = Or2::T2(vec![3, 1, 2].into_boxed_slice());
+    x.sort();
+    assert_eq!(&*x, &[1, 2, 3]);
+}
+
+#[test]
+fn test_try_fold() {
+    fn parse(x: Or3<&str, i64, f64>) -> Result<i32, String> {
+        x.try_fold(
+            |s| s.parse().map_err(|e| format!("{}: {}", s, e)),
+            |n| i32::try_from(n).map_err(|e| e.to_string()),
+            |f| {
+                if f.fract() != 0.0 {
+                    return Err(format!("{} is not an integer", f));
+                }
+                Ok(f as i32)
+            },
+        )
+    }
+
+    assert_eq!(parse(Or3::T1("42")), Ok(42));
+    assert_eq!(parse(Or3::T2(7)), Ok(7));
+    assert_eq!(parse(Or3::T3(3.0)), Ok(3));
+    assert!(parse(Or3::T1("x")).unwrap_err().starts_with("x: "));
+    assert!(parse(Or3::T2(i64::MAX)).is_err());
+    assert_eq!(parse(Or3::T3(3.5)).unwrap_err(), "3.5 is not an integer");
+}
+
+#[test]
+fn test_as_tx_ref_mut() {
+    let mut x: Or3<String, i32, Vec<u8>> = Or3::T1("hello".to_string());
+    assert_eq!(x.as_t1_ref().map(String::as_str), Some("hello"));
+    assert_eq!(x.as_t2_ref(), None);
+    assert_eq!(x.as_t3_ref(), None);
+
+    if let Some(s) = x.as_t1_mut() {
+        s.push_str(", world");
+    }
+    assert!(x.as_t3_mut().is_none());
+    assert_eq!(x.as_t1(), Some("hello, world".to_string()));
+
+    let mut y: Or2<i32, u8> = Or2::T2(1);
+    *y.as_t2_mut().unwrap() += 1;
+    assert_eq!(y.as_t2_ref(), Some(&2));
+}
+
+#[test]
+fn test_map_poly() {
+    use or_rs::poly::PolyMap;
+
+    struct Double;
+
+    impl PolyMap<i32> for Double {
+        type Output = i32;
+        fn call(self, value: i32) -> i32 {
+            value * 2
+        }
+    }
+
+    impl PolyMap<String> for Double {
+        type Output = String;
+        fn call(self, value: String) -> String {
+            value.repeat(2)
+        }
+    }
+
+    impl<T: Clone> PolyMap<Vec<T>> for Double {
+        type Output = Vec<T>;
+        fn call(self, value: Vec<T>) -> Vec<T> {
+            value.iter().chain(value.iter()).cloned().collect()
+        }
+    }
+
+    let x: Or2<i32, String> = Or2::T1(21);
+    assert_eq!(x.map_poly(Double).as_t1(), Some(42));
+
+    let x: Or3<i32, String, Vec<u8>> = Or3::T2("ab".to_string());
+    assert_eq!(x.map_poly(Double).as_t2(), Some("abab".to_string()));
+
+    let x: Or3<i32, String, Vec<u8>> = Or3::T3(vec![1, 2]);
+    assert_eq!(x.map_poly(Double).as_t3(), Some(vec![1, 2, 1, 2]));
+
+    // a mapper borrowing its state, to an `Or` of the same output types.
+    struct Offset<'a>(&'a i32);
+
+    impl PolyMap<i32> for Offset<'_> {
+        type Output = i64;
+        fn call(self, value: i32) -> i64 {
+            i64::from(value + self.0)
+        }
+    }
+
+    impl PolyMap<u8> for Offset<'_> {
+        type Output = i64;
+        fn call(self, value: u8) -> i64 {
+            i64::from(value) + i64::from(*self.0)
+        }
+    }
+
+    let offset = 10;
+    let x: Or2<i32, u8> = Or2::T2(5);
+    assert_eq!(x.map_poly(Offset(&offset)).fold(|a| a, |b| b), 15);
+}
+
+#[test]
+fn test_debug() {
+    let x: Or2<i32, &str> = Or2::T2("a");
+    assert_eq!(format!("{:?}", x), r#"T2("a")"#);
+    assert_eq!(format!("{:#?}", x), "T2(\n    \"a\",\n)");
+
+    let x: Or5<u8, u8, u8, Vec<i32>, u8> = Or5::T4(vec![1, 2]);
+    assert_eq!(format!("{:?}", x), "T4([1, 2])");
+
+    let x: Or9<(), (), (), (), (), (), (), (), Option<bool>> = Or9::T9(Some(true));
+    assert_eq!(format!("{:?}", x), "T9(Some(true))");
+    let _ = dbg!(x);
+}
+
+#[test]
+fn test_display() {
+    let values: Vec<Or3<i32, f32, String>> =
+        vec![Or3::T1(-3), Or3::T2(0.5), Or3::T3("hello".to_string())];
+    let printed = values.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+    assert_eq!(printed, ["-3", "0.5", "hello"]);
+
+    // the flags are forwarded along with the value.
+    let x: Or3<i32, f32, String> = Or3::T2(1.0 / 3.0);
+    assert_eq!(format!("{:>8.3}", x), "   0.333");
+    let x: Or3<i32, f32, String> = Or3::T3("ab".to_string());
+    assert_eq!(format!("[{:-^6}]", x), "[--ab--]");
+}
+
+#[test]
+fn test_clone_copy() {
+    fn duplicate<T: Copy>(x: T) -> (T, T) {
+        (x, x)
+    }
+
+    let (a, b) = duplicate(Or2::<u8, char>::T2('x'));
+    assert_eq!((a.as_t2(), b.as_t2()), (Some('x'), Some('x')));
+
+    let (a, _) = duplicate(Or9::<(), (), (), (), (), (), (), (), i32>::T9(9));
+    assert_eq!(a.as_t9(), Some(9));
+
+    // `Clone` only requires the payloads to be `Clone`.
+    let x: Or4<String, Vec<u8>, i32, bool> = Or4::T1("hello".to_string());
+    let y = x.clone();
+    assert_eq!(x.as_t1(), y.as_t1());
+}
+
+#[test]
+fn test_hash() {
+    use std::collections::{HashMap, HashSet};
+
+    let mut counts: HashMap<Or2<i32, String>, usize> = HashMap::new();
+    for key in [
+        Or2::T1(1),
+        Or2::T2("a".to_string()),
+        Or2::T1(1),
+        Or2::T2("a".to_string()),
+        Or2::T1(2),
+    ] {
+        *counts.entry(key).or_default() += 1;
+    }
+    assert_eq!(counts[&Or2::T1(1)], 2);
+    assert_eq!(counts[&Or2::T2("a".to_string())], 2);
+    assert_eq!(counts[&Or2::T1(2)], 1);
+
+    // the same value in different variants are different keys.
+    let set: HashSet<Or3<u8, u8, u8>> = [Or3::T1(0), Or3::T2(0), Or3::T3(0), Or3::T1(0)]
+        .into_iter()
+        .collect();
+    assert_eq!(set.len(), 3);
+    assert_ne!(Or3::<u8, u8, u8>::T1(0), Or3::T2(0));
+}
+
+#[test]
+fn test_ord() {
+    use std::collections::BTreeMap;
+
+    let mut values: Vec<Or3<i32, &str, i32>> = vec![
+        Or3::T3(-1),
+        Or3::T2("b"),
+        Or3::T1(5),
+        Or3::T2("a"),
+        Or3::T1(-5),
+    ];
+    values.sort();
+    assert_eq!(
+        values,
+        [
+            Or3::T1(-5),
+            Or3::T1(5),
+            Or3::T2("a"),
+            Or3::T2("b"),
+            Or3::T3(-1)
+        ]
+    );
+
+    // the variant position comes first, so a value of an earlier variant is always smaller.
+    assert!(Or2::<i32, i32>::T1(i32::MAX) < Or2::T2(i32::MIN));
+    assert!(Or2::<f64, f64>::T1(f64::NAN)
+        .partial_cmp(&Or2::T1(0.0))
+        .is_none());
+
+    let map: BTreeMap<Or2<char, u8>, &str> = [(Or2::T2(1), "one"), (Or2::T1('a'), "a")]
+        .into_iter()
+        .collect();
+    assert_eq!(map.into_values().collect::<Vec<_>>(), ["a", "one"]);
+}
+
+#[test]
+fn test_error_into_box_dyn_error() {
+    use std::error::Error;
+    use std::fmt;
+    use std::io;
+    use std::num::ParseIntError;
+
+    #[derive(Debug)]
+    struct MyError;
+    impl fmt::Display for MyError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "my error")
+        }
+    }
+    impl Error for MyError {}
+
+    fn check(n: i32) -> Result<i32, Or3<io::Error, ParseIntError, MyError>> {
+        match n {
+            0 => Err(Or3::T1(io::Error::other("io"))),
+            1 => Err(Or3::T2("x".parse::<i32>().unwrap_err())),
+            2 => Err(Or3::T3(MyError)),
+            n => Ok(n),
+        }
+    }
+
+    fn run(n: i32) -> Result<i32, Box<dyn Error>> {
+        Ok(check(n)?)
+    }
+
+    assert_eq!(run(0).unwrap_err().to_string(), "io");
+    assert_eq!(
+        run(1).unwrap_err().to_string(),
+        "invalid digit found in string"
+    );
+    assert_eq!(run(2).unwrap_err().to_string(), "my error");
+    assert!(run(2).unwrap_err().source().is_none());
+    assert_eq!(run(3).unwrap(), 3);
+}
+
+#[test]
+fn test_error() {
+    use std::error::Error;
+    use std::fmt;
+
+    #[derive(Debug)]
+    struct Outer(std::num::ParseIntError);
+    impl fmt::Display for Outer {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "outer")
+        }
+    }
+    impl Error for Outer {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            Some(&self.0)
+        }
+    }
+
+    let x: Or2<fmt::Error, Outer> = Or2::T2(Outer("x".parse::<i32>().unwrap_err()));
+    assert_eq!(x.to_string(), "outer");
+    assert_eq!(
+        x.source().unwrap().to_string(),
+        "invalid digit found in string"
+    );
+
+    let err: Box<dyn Error> = Or2::<fmt::Error, Outer>::T1(fmt::Error).into();
+    assert_eq!(err.to_string(), fmt::Error.to_string());
+}
+
+#[test]
+fn test_result_conversions() {
+    let x: Or2<i32, String> = Ok(1).into();
+    assert_eq!(x, Or2::T1(1));
+    let x: Or2<i32, String> = Or2::from(Err("e".to_string()));
+    assert_eq!(x.clone().into_result(), Err("e".to_string()));
+    assert_eq!(Or2::from(x.clone().into_result()), x);
+
+    // the remaining positions become an `Or` of one less arity.
+    let x: Or3<i32, String, bool> = Or3::T3(true);
+    assert_eq!(x.clone().into_result(), Err(Or2::T2(true)));
+    assert_eq!(Or3::from(x.clone().into_result()), x);
+    assert_eq!(Or3::<i32, String, bool>::T1(5).into_result(), Ok(5));
+
+    // or are converted into a single error type.
+    #[derive(Debug, PartialEq)]
+    struct Error(String);
+    impl From<String> for Error {
+        fn from(s: String) -> Self {
+            Error(s)
+        }
+    }
+    impl From<bool> for Error {
+        fn from(b: bool) -> Self {
+            Error(b.to_string())
+        }
+    }
+    assert_eq!(x.try_into_result::<Error>(), Err(Error("true".to_string())));
+    let x: Or3<i32, String, bool> = Or3::T2("e".to_string());
+    assert_eq!(x.try_into_result::<Error>(), Err(Error("e".to_string())));
+}
+
+#[test]
+fn test_option_conversions() {
+    let x: Or2<i32, ()> = Some(1).into();
+    assert_eq!(x, Or2::T1(1));
+    assert_eq!(x.into_option(), Some(1));
+    let x: Or2<i32, ()> = None.into();
+    assert_eq!(x, Or2::T2(()));
+    assert_eq!(x.into_option(), None);
+
+    let x: Or3<i32, String, ()> = Or3::T2("a".to_string());
+    assert_eq!(x.clone().into_option(), Some(Or2::T2("a".to_string())));
+    assert_eq!(Or3::from(x.clone().into_option()), x);
+    assert_eq!(Or3::<i32, String, ()>::T3(()).into_option(), None);
+}
+
+#[test]
+fn test_iterator() {
+    fn evens(
+        v: &[i32],
+        reverse: bool,
+    ) -> Or2<impl Iterator<Item = i32> + '_, std::vec::IntoIter<i32>> {
+        if reverse {
+            let mut evens = v.iter().copied().filter(|x| x % 2 == 0).collect::<Vec<_>>();
+            evens.reverse();
+            Or2::T2(evens.into_iter())
+        } else {
+            Or2::T1(v.iter().copied().filter(|x| x % 2 == 0))
+        }
+    }
+
+    let v = [1, 2, 3, 4, 6];
+    assert_eq!(evens(&v, false).collect::<Vec<_>>(), [2, 4, 6]);
+    assert_eq!(evens(&v, true).collect::<Vec<_>>(), [6, 4, 2]);
+    assert_eq!(evens(&v, true).size_hint(), (3, Some(3)));
+    assert_eq!(evens(&v, false).nth(1), Some(4));
+    assert_eq!(evens(&v, true).count(), 3);
+    assert_eq!(evens(&v, true).last(), Some(2));
+    assert_eq!(Iterator::fold(evens(&v, false), 0, |a, b| a + b), 12);
+
+    let mut it: Or3<std::iter::Once<u8>, std::ops::Range<u8>, std::iter::Empty<u8>> = Or3::T2(0..3);
+    assert_eq!(it.next(), Some(0));
+    assert_eq!(it.map(|x| x * 10).collect::<Vec<_>>(), [10, 20]);
+}
+
+#[test]
+fn test_iterator_double_ended_exact_size() {
+    type Iter = Or2<std::ops::Range<u8>, std::vec::IntoIter<u8>>;
+
+    let it: Iter = Or2::T1(0..4);
+    assert_eq!(it.len(), 4);
+    assert_eq!(it.rev().collect::<Vec<_>>(), [3, 2, 1, 0]);
+
+    let mut it: Iter = Or2::T2(vec![1, 2, 3, 4, 5].into_iter());
+    assert_eq!(it.next_back(), Some(5));
+    assert_eq!(it.nth_back(1), Some(3));
+    assert_eq!(it.len(), 2);
+    assert_eq!(it.rfold(0, |acc, x| acc * 10 + x), 21);
+
+    // the capacity is taken from the exact size.
+    let v = Iter::T1(0..100).collect::<Vec<_>>();
+    assert!(v.capacity() >= 100);
+
+    fn assert_fused<I: std::iter::FusedIterator>(_: &I) {}
+    let mut it: Iter = Or2::T1(0..1);
+    assert_fused(&it);
+    assert_eq!((it.next(), it.next(), it.next()), (Some(0), None, None));
+}
+
+#[test]
+fn test_future() {
+    use std::future::{ready, Future, Ready};
+    use std::pin::Pin;
+
+    async fn fetch(id: u32) -> String {
+        format!("user-{}", id)
+    }
+
+    fn lookup(id: u32) -> Or2<Ready<String>, impl Future<Output = String>> {
+        if id == 0 {
+            Or2::T1(ready("anonymous".to_string()))
+        } else {
+            Or2::T2(fetch(id))
+        }
+    }
+
+    assert_eq!(block_on(lookup(0)), "anonymous");
+    assert_eq!(block_on(lookup(3)), "user-3");
+
+    // the payloads are projected as pinned, which polls the futures not being `Unpin`.
+    let mut fut = std::pin::pin!(lookup(5));
+    assert!(fut.as_mut().as_pin_mut().is_t2());
+    assert!(fut.as_ref().as_pin_ref().is_t2());
+    assert_eq!(block_on(fut), "user-5");
+
+    let boxed: Or2<Pin<Box<dyn Future<Output = i32>>>, Ready<i32>> = Or2::T1(Box::pin(async { 1 }));
+    assert_eq!(block_on(boxed), 1);
+}
+
+#[test]
+fn test_io() {
+    use std::io::{BufRead, BufReader, Cursor, Read, Seek, SeekFrom, Write};
+
+    type Reader = Or2<Cursor<Vec<u8>>, Cursor<&'static [u8]>>;
+
+    let mut r: Reader = Or2::T1(Cursor::new(b"hello\nworld\n".to_vec()));
+    let mut buf = [0; 5];
+    r.read_exact(&mut buf).unwrap();
+    assert_eq!(&buf, b"hello");
+    assert_eq!(r.seek(SeekFrom::Start(6)).unwrap(), 6);
+    let mut rest = String::new();
+    r.read_to_string(&mut rest).unwrap();
+    assert_eq!(rest, "world\n");
+
+    let r: Reader = Or2::T2(Cursor::new(b"a\nb\n"));
+    assert_eq!(r.lines().map(Result::unwrap).collect::<Vec<_>>(), ["a", "b"]);
+
+    let r: Or2<BufReader<&[u8]>, &[u8]> = Or2::T1(BufReader::new(b"x,y"));
+    let fields = r.split(b',').map(Result::unwrap).collect::<Vec<_>>();
+    assert_eq!(fields, [b"x".to_vec(), b"y".to_vec()]);
+
+    let mut w: Or2<Vec<u8>, std::io::Sink> = Or2::T1(Vec::new());
+    write!(w, "{}-{}", 1, 2).unwrap();
+    w.write_all(b"!").unwrap();
+    w.flush().unwrap();
+    assert_eq!(w.as_t1().unwrap(), b"1-2!");
+
+    let mut w: Or2<Cursor<Vec<u8>>, Cursor<[u8; 4]>> = Or2::T1(Cursor::new(Vec::new()));
+    w.write_all(b"abc").unwrap();
+    assert_eq!(w.stream_position().unwrap(), 3);
+    w.rewind().unwrap();
+    w.write_all(b"x").unwrap();
+    assert_eq!(w.as_t1().unwrap().into_inner(), b"xbc");
+}
+
+#[test]
+fn test_as_ref_forwarding() {
+    use std::path::{Path, PathBuf};
+
+    fn file_name<P: AsRef<Path>>(path: P) -> String {
+        path.as_ref().file_name().unwrap().to_string_lossy().into_owned()
+    }
+
+    let path: Or3<&str, String, PathBuf> = Or3::T3(PathBuf::from("/tmp/a.txt"));
+    assert_eq!(file_name(&path), "a.txt");
+    let path: Or3<&str, String, PathBuf> = Or3::T1("b.txt");
+    assert_eq!(file_name(path), "b.txt");
+
+    let s: Or2<String, &str> = Or2::T2("hello");
+    assert_eq!(AsRef::<str>::as_ref(&s), "hello");
+    assert_eq!(AsRef::<[u8]>::as_ref(&s), b"hello");
+
+    let mut v: Or2<Vec<u8>, [u8; 3]> = Or2::T2([1, 2, 3]);
+    AsMut::<[u8]>::as_mut(&mut v)[0] = 10;
+    assert_eq!(AsRef::<[u8]>::as_ref(&v), [10, 2, 3]);
+}
@@ -0,0 +1,28 @@
+#![cfg(feature = "frunk")]
+
+use frunk_core::coproduct::Coproduct;
+use frunk_core::Coprod;
+use or_rs::enums::*;
+
+type I32OrStringOrBool = Coprod!(i32, String, bool);
+
+#[test]
+fn test_into_coproduct() {
+    // the value is kept at the same position.
+    let x = Or3::<i32, String, bool>::T2("hello".to_string());
+    let coproduct = x.into_coproduct();
+    assert_eq!(coproduct, I32OrStringOrBool::inject("hello".to_string()));
+    assert_eq!(coproduct.get::<String, _>().unwrap(), "hello");
+
+    let coproduct: I32OrStringOrBool = Or3::T3(true).into();
+    assert_eq!(coproduct, Coproduct::inject(true));
+}
+
+#[test]
+fn test_from_coproduct() {
+    let x: Or3<i32, String, bool> = I32OrStringOrBool::inject(3).into();
+    assert_eq!(x, Or3::T1(3));
+
+    let x: Or3<i32, String, bool> = I32OrStringOrBool::inject(false).into();
+    assert_eq!(x, Or3::T3(false));
+}
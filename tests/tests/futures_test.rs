@@ -0,0 +1,33 @@
+#![cfg(feature = "futures")]
+
+use futures::executor::block_on;
+use futures::stream::{self, FusedStream, Stream, StreamExt};
+use or_rs::enums::*;
+
+fn numbers(from_vec: bool) -> Or2<impl Stream<Item = i32>, impl Stream<Item = i32>> {
+    if from_vec {
+        Or2::T1(stream::iter(vec![1, 2, 3]))
+    } else {
+        Or2::T2(stream::once(async { 4 }))
+    }
+}
+
+#[test]
+fn test_stream() {
+    // the items come from the stream of the active variant.
+    let items = block_on(numbers(true).collect::<Vec<_>>());
+    assert_eq!(items, [1, 2, 3]);
+    assert_eq!(numbers(true).size_hint(), (3, Some(3)));
+
+    let items = block_on(numbers(false).collect::<Vec<_>>());
+    assert_eq!(items, [4]);
+}
+
+#[test]
+fn test_fused_stream() {
+    let mut x = Or2::<_, stream::Fuse<stream::Empty<i32>>>::T1(stream::iter(vec![1]).fuse());
+    assert!(!x.is_terminated());
+    assert_eq!(block_on(x.next()), Some(1));
+    assert_eq!(block_on(x.next()), None);
+    assert!(x.is_terminated());
+}
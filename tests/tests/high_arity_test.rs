@@ -0,0 +1,26 @@
+#![cfg(feature = "high-arity")]
+
+use or_rs::enums::*;
+
+type Wide =
+    Or16<u8, u16, u32, u64, i8, i16, i32, i64, f32, f64, bool, char, i128, String, usize, isize>;
+
+#[test]
+fn test_or16() {
+    let x = Wide::T16(-3);
+    assert!(x.is_t16());
+    assert_eq!(x.clone().as_t16(), Some(-3));
+    assert_eq!(x.map_t16(|v| v * 2).as_t16(), Some(-6));
+
+    let x = Wide::T14("hello".to_string());
+    assert!(!x.is_t16());
+    assert_eq!(x.to_string(), "hello");
+    assert_eq!(x.as_t14().unwrap(), "hello");
+}
+
+#[test]
+fn test_or10_from_str() {
+    // the first type parsing the string wins, also past `Or9`.
+    let x: Or10<bool, bool, bool, bool, bool, bool, bool, bool, bool, i32> = "7".parse().unwrap();
+    assert_eq!(x.as_t10(), Some(7));
+}
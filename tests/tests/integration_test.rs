@@ -1,4 +1,5 @@
 #![feature(proc_macro_hygiene)] // for now, you have to add this unstable feature flag
+#![allow(clippy::no_effect)]
 
 use or_rs::enums::*;
 use or_rs_macros::or_gen;
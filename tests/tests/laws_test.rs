@@ -0,0 +1,725 @@
+//! Property-based tests of the laws of the `Or` types, generated by `code_gen`.
+//! Do not edit this file by hand, run `cargo run --bin code_gen -- --laws` instead.
+
+use or_rs::enums::*;
+use proptest::prelude::*;
+
+fn f(x: i32) -> i32 {
+    x.wrapping_mul(3)
+}
+
+fn g(x: i32) -> i32 {
+    x.wrapping_add(7)
+}
+
+fn or2(k: usize, x: i32) -> Or2<i32, i32> {
+    match k {
+        1 => Or2::T1(x),
+        _ => Or2::T2(x),
+    }
+}
+
+proptest! {
+    #[test]
+    fn or2_map_identity(k in 1..=2usize, x: i32) {
+        prop_assert_eq!(or2(k, x).map_t1(|x| x).into_options(), or2(k, x).into_options());
+        prop_assert_eq!(or2(k, x).map_t2(|x| x).into_options(), or2(k, x).into_options());
+    }
+
+    #[test]
+    fn or2_map_composition(k in 1..=2usize, x: i32) {
+        prop_assert_eq!(
+            or2(k, x).map_t1(f).map_t1(g).into_options(),
+            or2(k, x).map_t1(|x| g(f(x))).into_options()
+        );
+        prop_assert_eq!(
+            or2(k, x).map_t2(f).map_t2(g).into_options(),
+            or2(k, x).map_t2(|x| g(f(x))).into_options()
+        );
+    }
+
+    #[test]
+    fn or2_fold_map(k in 1..=2usize, x: i32) {
+        prop_assert_eq!(
+            or2(k, x).map_t1(f).fold(|x| x, |x| x),
+            or2(k, x).fold(f, |x| x)
+        );
+        prop_assert_eq!(
+            or2(k, x).map_t2(f).fold(|x| x, |x| x),
+            or2(k, x).fold(|x| x, f)
+        );
+    }
+
+    #[test]
+    fn or2_as_is(k in 1..=2usize, x: i32) {
+        prop_assert_eq!(or2(k, x).is_t1(), k == 1);
+        prop_assert_eq!(or2(k, x).as_t1(), if k == 1 { Some(x) } else { None });
+        prop_assert_eq!(or2(k, x).is_t2(), k == 2);
+        prop_assert_eq!(or2(k, x).as_t2(), if k == 2 { Some(x) } else { None });
+    }
+}
+
+fn or3(k: usize, x: i32) -> Or3<i32, i32, i32> {
+    match k {
+        1 => Or3::T1(x),
+        2 => Or3::T2(x),
+        _ => Or3::T3(x),
+    }
+}
+
+proptest! {
+    #[test]
+    fn or3_map_identity(k in 1..=3usize, x: i32) {
+        prop_assert_eq!(or3(k, x).map_t1(|x| x).into_options(), or3(k, x).into_options());
+        prop_assert_eq!(or3(k, x).map_t2(|x| x).into_options(), or3(k, x).into_options());
+        prop_assert_eq!(or3(k, x).map_t3(|x| x).into_options(), or3(k, x).into_options());
+    }
+
+    #[test]
+    fn or3_map_composition(k in 1..=3usize, x: i32) {
+        prop_assert_eq!(
+            or3(k, x).map_t1(f).map_t1(g).into_options(),
+            or3(k, x).map_t1(|x| g(f(x))).into_options()
+        );
+        prop_assert_eq!(
+            or3(k, x).map_t2(f).map_t2(g).into_options(),
+            or3(k, x).map_t2(|x| g(f(x))).into_options()
+        );
+        prop_assert_eq!(
+            or3(k, x).map_t3(f).map_t3(g).into_options(),
+            or3(k, x).map_t3(|x| g(f(x))).into_options()
+        );
+    }
+
+    #[test]
+    fn or3_fold_map(k in 1..=3usize, x: i32) {
+        prop_assert_eq!(
+            or3(k, x).map_t1(f).fold(|x| x, |x| x, |x| x),
+            or3(k, x).fold(f, |x| x, |x| x)
+        );
+        prop_assert_eq!(
+            or3(k, x).map_t2(f).fold(|x| x, |x| x, |x| x),
+            or3(k, x).fold(|x| x, f, |x| x)
+        );
+        prop_assert_eq!(
+            or3(k, x).map_t3(f).fold(|x| x, |x| x, |x| x),
+            or3(k, x).fold(|x| x, |x| x, f)
+        );
+    }
+
+    #[test]
+    fn or3_as_is(k in 1..=3usize, x: i32) {
+        prop_assert_eq!(or3(k, x).is_t1(), k == 1);
+        prop_assert_eq!(or3(k, x).as_t1(), if k == 1 { Some(x) } else { None });
+        prop_assert_eq!(or3(k, x).is_t2(), k == 2);
+        prop_assert_eq!(or3(k, x).as_t2(), if k == 2 { Some(x) } else { None });
+        prop_assert_eq!(or3(k, x).is_t3(), k == 3);
+        prop_assert_eq!(or3(k, x).as_t3(), if k == 3 { Some(x) } else { None });
+    }
+}
+
+fn or4(k: usize, x: i32) -> Or4<i32, i32, i32, i32> {
+    match k {
+        1 => Or4::T1(x),
+        2 => Or4::T2(x),
+        3 => Or4::T3(x),
+        _ => Or4::T4(x),
+    }
+}
+
+proptest! {
+    #[test]
+    fn or4_map_identity(k in 1..=4usize, x: i32) {
+        prop_assert_eq!(or4(k, x).map_t1(|x| x).into_options(), or4(k, x).into_options());
+        prop_assert_eq!(or4(k, x).map_t2(|x| x).into_options(), or4(k, x).into_options());
+        prop_assert_eq!(or4(k, x).map_t3(|x| x).into_options(), or4(k, x).into_options());
+        prop_assert_eq!(or4(k, x).map_t4(|x| x).into_options(), or4(k, x).into_options());
+    }
+
+    #[test]
+    fn or4_map_composition(k in 1..=4usize, x: i32) {
+        prop_assert_eq!(
+            or4(k, x).map_t1(f).map_t1(g).into_options(),
+            or4(k, x).map_t1(|x| g(f(x))).into_options()
+        );
+        prop_assert_eq!(
+            or4(k, x).map_t2(f).map_t2(g).into_options(),
+            or4(k, x).map_t2(|x| g(f(x))).into_options()
+        );
+        prop_assert_eq!(
+            or4(k, x).map_t3(f).map_t3(g).into_options(),
+            or4(k, x).map_t3(|x| g(f(x))).into_options()
+        );
+        prop_assert_eq!(
+            or4(k, x).map_t4(f).map_t4(g).into_options(),
+            or4(k, x).map_t4(|x| g(f(x))).into_options()
+        );
+    }
+
+    #[test]
+    fn or4_fold_map(k in 1..=4usize, x: i32) {
+        prop_assert_eq!(
+            or4(k, x).map_t1(f).fold(|x| x, |x| x, |x| x, |x| x),
+            or4(k, x).fold(f, |x| x, |x| x, |x| x)
+        );
+        prop_assert_eq!(
+            or4(k, x).map_t2(f).fold(|x| x, |x| x, |x| x, |x| x),
+            or4(k, x).fold(|x| x, f, |x| x, |x| x)
+        );
+        prop_assert_eq!(
+            or4(k, x).map_t3(f).fold(|x| x, |x| x, |x| x, |x| x),
+            or4(k, x).fold(|x| x, |x| x, f, |x| x)
+        );
+        prop_assert_eq!(
+            or4(k, x).map_t4(f).fold(|x| x, |x| x, |x| x, |x| x),
+            or4(k, x).fold(|x| x, |x| x, |x| x, f)
+        );
+    }
+
+    #[test]
+    fn or4_as_is(k in 1..=4usize, x: i32) {
+        prop_assert_eq!(or4(k, x).is_t1(), k == 1);
+        prop_assert_eq!(or4(k, x).as_t1(), if k == 1 { Some(x) } else { None });
+        prop_assert_eq!(or4(k, x).is_t2(), k == 2);
+        prop_assert_eq!(or4(k, x).as_t2(), if k == 2 { Some(x) } else { None });
+        prop_assert_eq!(or4(k, x).is_t3(), k == 3);
+        prop_assert_eq!(or4(k, x).as_t3(), if k == 3 { Some(x) } else { None });
+        prop_assert_eq!(or4(k, x).is_t4(), k == 4);
+        prop_assert_eq!(or4(k, x).as_t4(), if k == 4 { Some(x) } else { None });
+    }
+}
+
+fn or5(k: usize, x: i32) -> Or5<i32, i32, i32, i32, i32> {
+    match k {
+        1 => Or5::T1(x),
+        2 => Or5::T2(x),
+        3 => Or5::T3(x),
+        4 => Or5::T4(x),
+        _ => Or5::T5(x),
+    }
+}
+
+proptest! {
+    #[test]
+    fn or5_map_identity(k in 1..=5usize, x: i32) {
+        prop_assert_eq!(or5(k, x).map_t1(|x| x).into_options(), or5(k, x).into_options());
+        prop_assert_eq!(or5(k, x).map_t2(|x| x).into_options(), or5(k, x).into_options());
+        prop_assert_eq!(or5(k, x).map_t3(|x| x).into_options(), or5(k, x).into_options());
+        prop_assert_eq!(or5(k, x).map_t4(|x| x).into_options(), or5(k, x).into_options());
+        prop_assert_eq!(or5(k, x).map_t5(|x| x).into_options(), or5(k, x).into_options());
+    }
+
+    #[test]
+    fn or5_map_composition(k in 1..=5usize, x: i32) {
+        prop_assert_eq!(
+            or5(k, x).map_t1(f).map_t1(g).into_options(),
+            or5(k, x).map_t1(|x| g(f(x))).into_options()
+        );
+        prop_assert_eq!(
+            or5(k, x).map_t2(f).map_t2(g).into_options(),
+            or5(k, x).map_t2(|x| g(f(x))).into_options()
+        );
+        prop_assert_eq!(
+            or5(k, x).map_t3(f).map_t3(g).into_options(),
+            or5(k, x).map_t3(|x| g(f(x))).into_options()
+        );
+        prop_assert_eq!(
+            or5(k, x).map_t4(f).map_t4(g).into_options(),
+            or5(k, x).map_t4(|x| g(f(x))).into_options()
+        );
+        prop_assert_eq!(
+            or5(k, x).map_t5(f).map_t5(g).into_options(),
+            or5(k, x).map_t5(|x| g(f(x))).into_options()
+        );
+    }
+
+    #[test]
+    fn or5_fold_map(k in 1..=5usize, x: i32) {
+        prop_assert_eq!(
+            or5(k, x).map_t1(f).fold(|x| x, |x| x, |x| x, |x| x, |x| x),
+            or5(k, x).fold(f, |x| x, |x| x, |x| x, |x| x)
+        );
+        prop_assert_eq!(
+            or5(k, x).map_t2(f).fold(|x| x, |x| x, |x| x, |x| x, |x| x),
+            or5(k, x).fold(|x| x, f, |x| x, |x| x, |x| x)
+        );
+        prop_assert_eq!(
+            or5(k, x).map_t3(f).fold(|x| x, |x| x, |x| x, |x| x, |x| x),
+            or5(k, x).fold(|x| x, |x| x, f, |x| x, |x| x)
+        );
+        prop_assert_eq!(
+            or5(k, x).map_t4(f).fold(|x| x, |x| x, |x| x, |x| x, |x| x),
+            or5(k, x).fold(|x| x, |x| x, |x| x, f, |x| x)
+        );
+        prop_assert_eq!(
+            or5(k, x).map_t5(f).fold(|x| x, |x| x, |x| x, |x| x, |x| x),
+            or5(k, x).fold(|x| x, |x| x, |x| x, |x| x, f)
+        );
+    }
+
+    #[test]
+    fn or5_as_is(k in 1..=5usize, x: i32) {
+        prop_assert_eq!(or5(k, x).is_t1(), k == 1);
+        prop_assert_eq!(or5(k, x).as_t1(), if k == 1 { Some(x) } else { None });
+        prop_assert_eq!(or5(k, x).is_t2(), k == 2);
+        prop_assert_eq!(or5(k, x).as_t2(), if k == 2 { Some(x) } else { None });
+        prop_assert_eq!(or5(k, x).is_t3(), k == 3);
+        prop_assert_eq!(or5(k, x).as_t3(), if k == 3 { Some(x) } else { None });
+        prop_assert_eq!(or5(k, x).is_t4(), k == 4);
+        prop_assert_eq!(or5(k, x).as_t4(), if k == 4 { Some(x) } else { None });
+        prop_assert_eq!(or5(k, x).is_t5(), k == 5);
+        prop_assert_eq!(or5(k, x).as_t5(), if k == 5 { Some(x) } else { None });
+    }
+}
+
+fn or6(k: usize, x: i32) -> Or6<i32, i32, i32, i32, i32, i32> {
+    match k {
+        1 => Or6::T1(x),
+        2 => Or6::T2(x),
+        3 => Or6::T3(x),
+        4 => Or6::T4(x),
+        5 => Or6::T5(x),
+        _ => Or6::T6(x),
+    }
+}
+
+proptest! {
+    #[test]
+    fn or6_map_identity(k in 1..=6usize, x: i32) {
+        prop_assert_eq!(or6(k, x).map_t1(|x| x).into_options(), or6(k, x).into_options());
+        prop_assert_eq!(or6(k, x).map_t2(|x| x).into_options(), or6(k, x).into_options());
+        prop_assert_eq!(or6(k, x).map_t3(|x| x).into_options(), or6(k, x).into_options());
+        prop_assert_eq!(or6(k, x).map_t4(|x| x).into_options(), or6(k, x).into_options());
+        prop_assert_eq!(or6(k, x).map_t5(|x| x).into_options(), or6(k, x).into_options());
+        prop_assert_eq!(or6(k, x).map_t6(|x| x).into_options(), or6(k, x).into_options());
+    }
+
+    #[test]
+    fn or6_map_composition(k in 1..=6usize, x: i32) {
+        prop_assert_eq!(
+            or6(k, x).map_t1(f).map_t1(g).into_options(),
+            or6(k, x).map_t1(|x| g(f(x))).into_options()
+        );
+        prop_assert_eq!(
+            or6(k, x).map_t2(f).map_t2(g).into_options(),
+            or6(k, x).map_t2(|x| g(f(x))).into_options()
+        );
+        prop_assert_eq!(
+            or6(k, x).map_t3(f).map_t3(g).into_options(),
+            or6(k, x).map_t3(|x| g(f(x))).into_options()
+        );
+        prop_assert_eq!(
+            or6(k, x).map_t4(f).map_t4(g).into_options(),
+            or6(k, x).map_t4(|x| g(f(x))).into_options()
+        );
+        prop_assert_eq!(
+            or6(k, x).map_t5(f).map_t5(g).into_options(),
+            or6(k, x).map_t5(|x| g(f(x))).into_options()
+        );
+        prop_assert_eq!(
+            or6(k, x).map_t6(f).map_t6(g).into_options(),
+            or6(k, x).map_t6(|x| g(f(x))).into_options()
+        );
+    }
+
+    #[test]
+    fn or6_fold_map(k in 1..=6usize, x: i32) {
+        prop_assert_eq!(
+            or6(k, x).map_t1(f).fold(|x| x, |x| x, |x| x, |x| x, |x| x, |x| x),
+            or6(k, x).fold(f, |x| x, |x| x, |x| x, |x| x, |x| x)
+        );
+        prop_assert_eq!(
+            or6(k, x).map_t2(f).fold(|x| x, |x| x, |x| x, |x| x, |x| x, |x| x),
+            or6(k, x).fold(|x| x, f, |x| x, |x| x, |x| x, |x| x)
+        );
+        prop_assert_eq!(
+            or6(k, x).map_t3(f).fold(|x| x, |x| x, |x| x, |x| x, |x| x, |x| x),
+            or6(k, x).fold(|x| x, |x| x, f, |x| x, |x| x, |x| x)
+        );
+        prop_assert_eq!(
+            or6(k, x).map_t4(f).fold(|x| x, |x| x, |x| x, |x| x, |x| x, |x| x),
+            or6(k, x).fold(|x| x, |x| x, |x| x, f, |x| x, |x| x)
+        );
+        prop_assert_eq!(
+            or6(k, x).map_t5(f).fold(|x| x, |x| x, |x| x, |x| x, |x| x, |x| x),
+            or6(k, x).fold(|x| x, |x| x, |x| x, |x| x, f, |x| x)
+        );
+        prop_assert_eq!(
+            or6(k, x).map_t6(f).fold(|x| x, |x| x, |x| x, |x| x, |x| x, |x| x),
+            or6(k, x).fold(|x| x, |x| x, |x| x, |x| x, |x| x, f)
+        );
+    }
+
+    #[test]
+    fn or6_as_is(k in 1..=6usize, x: i32) {
+        prop_assert_eq!(or6(k, x).is_t1(), k == 1);
+        prop_assert_eq!(or6(k, x).as_t1(), if k == 1 { Some(x) } else { None });
+        prop_assert_eq!(or6(k, x).is_t2(), k == 2);
+        prop_assert_eq!(or6(k, x).as_t2(), if k == 2 { Some(x) } else { None });
+        prop_assert_eq!(or6(k, x).is_t3(), k == 3);
+        prop_assert_eq!(or6(k, x).as_t3(), if k == 3 { Some(x) } else { None });
+        prop_assert_eq!(or6(k, x).is_t4(), k == 4);
+        prop_assert_eq!(or6(k, x).as_t4(), if k == 4 { Some(x) } else { None });
+        prop_assert_eq!(or6(k, x).is_t5(), k == 5);
+        prop_assert_eq!(or6(k, x).as_t5(), if k == 5 { Some(x) } else { None });
+        prop_assert_eq!(or6(k, x).is_t6(), k == 6);
+        prop_assert_eq!(or6(k, x).as_t6(), if k == 6 { Some(x) } else { None });
+    }
+}
+
+fn or7(k: usize, x: i32) -> Or7<i32, i32, i32, i32, i32, i32, i32> {
+    match k {
+        1 => Or7::T1(x),
+        2 => Or7::T2(x),
+        3 => Or7::T3(x),
+        4 => Or7::T4(x),
+        5 => Or7::T5(x),
+        6 => Or7::T6(x),
+        _ => Or7::T7(x),
+    }
+}
+
+proptest! {
+    #[test]
+    fn or7_map_identity(k in 1..=7usize, x: i32) {
+        prop_assert_eq!(or7(k, x).map_t1(|x| x).into_options(), or7(k, x).into_options());
+        prop_assert_eq!(or7(k, x).map_t2(|x| x).into_options(), or7(k, x).into_options());
+        prop_assert_eq!(or7(k, x).map_t3(|x| x).into_options(), or7(k, x).into_options());
+        prop_assert_eq!(or7(k, x).map_t4(|x| x).into_options(), or7(k, x).into_options());
+        prop_assert_eq!(or7(k, x).map_t5(|x| x).into_options(), or7(k, x).into_options());
+        prop_assert_eq!(or7(k, x).map_t6(|x| x).into_options(), or7(k, x).into_options());
+        prop_assert_eq!(or7(k, x).map_t7(|x| x).into_options(), or7(k, x).into_options());
+    }
+
+    #[test]
+    fn or7_map_composition(k in 1..=7usize, x: i32) {
+        prop_assert_eq!(
+            or7(k, x).map_t1(f).map_t1(g).into_options(),
+            or7(k, x).map_t1(|x| g(f(x))).into_options()
+        );
+        prop_assert_eq!(
+            or7(k, x).map_t2(f).map_t2(g).into_options(),
+            or7(k, x).map_t2(|x| g(f(x))).into_options()
+        );
+        prop_assert_eq!(
+            or7(k, x).map_t3(f).map_t3(g).into_options(),
+            or7(k, x).map_t3(|x| g(f(x))).into_options()
+        );
+        prop_assert_eq!(
+            or7(k, x).map_t4(f).map_t4(g).into_options(),
+            or7(k, x).map_t4(|x| g(f(x))).into_options()
+        );
+        prop_assert_eq!(
+            or7(k, x).map_t5(f).map_t5(g).into_options(),
+            or7(k, x).map_t5(|x| g(f(x))).into_options()
+        );
+        prop_assert_eq!(
+            or7(k, x).map_t6(f).map_t6(g).into_options(),
+            or7(k, x).map_t6(|x| g(f(x))).into_options()
+        );
+        prop_assert_eq!(
+            or7(k, x).map_t7(f).map_t7(g).into_options(),
+            or7(k, x).map_t7(|x| g(f(x))).into_options()
+        );
+    }
+
+    #[test]
+    fn or7_fold_map(k in 1..=7usize, x: i32) {
+        prop_assert_eq!(
+            or7(k, x).map_t1(f).fold(|x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x),
+            or7(k, x).fold(f, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x)
+        );
+        prop_assert_eq!(
+            or7(k, x).map_t2(f).fold(|x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x),
+            or7(k, x).fold(|x| x, f, |x| x, |x| x, |x| x, |x| x, |x| x)
+        );
+        prop_assert_eq!(
+            or7(k, x).map_t3(f).fold(|x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x),
+            or7(k, x).fold(|x| x, |x| x, f, |x| x, |x| x, |x| x, |x| x)
+        );
+        prop_assert_eq!(
+            or7(k, x).map_t4(f).fold(|x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x),
+            or7(k, x).fold(|x| x, |x| x, |x| x, f, |x| x, |x| x, |x| x)
+        );
+        prop_assert_eq!(
+            or7(k, x).map_t5(f).fold(|x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x),
+            or7(k, x).fold(|x| x, |x| x, |x| x, |x| x, f, |x| x, |x| x)
+        );
+        prop_assert_eq!(
+            or7(k, x).map_t6(f).fold(|x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x),
+            or7(k, x).fold(|x| x, |x| x, |x| x, |x| x, |x| x, f, |x| x)
+        );
+        prop_assert_eq!(
+            or7(k, x).map_t7(f).fold(|x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x),
+            or7(k, x).fold(|x| x, |x| x, |x| x, |x| x, |x| x, |x| x, f)
+        );
+    }
+
+    #[test]
+    fn or7_as_is(k in 1..=7usize, x: i32) {
+        prop_assert_eq!(or7(k, x).is_t1(), k == 1);
+        prop_assert_eq!(or7(k, x).as_t1(), if k == 1 { Some(x) } else { None });
+        prop_assert_eq!(or7(k, x).is_t2(), k == 2);
+        prop_assert_eq!(or7(k, x).as_t2(), if k == 2 { Some(x) } else { None });
+        prop_assert_eq!(or7(k, x).is_t3(), k == 3);
+        prop_assert_eq!(or7(k, x).as_t3(), if k == 3 { Some(x) } else { None });
+        prop_assert_eq!(or7(k, x).is_t4(), k == 4);
+        prop_assert_eq!(or7(k, x).as_t4(), if k == 4 { Some(x) } else { None });
+        prop_assert_eq!(or7(k, x).is_t5(), k == 5);
+        prop_assert_eq!(or7(k, x).as_t5(), if k == 5 { Some(x) } else { None });
+        prop_assert_eq!(or7(k, x).is_t6(), k == 6);
+        prop_assert_eq!(or7(k, x).as_t6(), if k == 6 { Some(x) } else { None });
+        prop_assert_eq!(or7(k, x).is_t7(), k == 7);
+        prop_assert_eq!(or7(k, x).as_t7(), if k == 7 { Some(x) } else { None });
+    }
+}
+
+fn or8(k: usize, x: i32) -> Or8<i32, i32, i32, i32, i32, i32, i32, i32> {
+    match k {
+        1 => Or8::T1(x),
+        2 => Or8::T2(x),
+        3 => Or8::T3(x),
+        4 => Or8::T4(x),
+        5 => Or8::T5(x),
+        6 => Or8::T6(x),
+        7 => Or8::T7(x),
+        _ => Or8::T8(x),
+    }
+}
+
+proptest! {
+    #[test]
+    fn or8_map_identity(k in 1..=8usize, x: i32) {
+        prop_assert_eq!(or8(k, x).map_t1(|x| x).into_options(), or8(k, x).into_options());
+        prop_assert_eq!(or8(k, x).map_t2(|x| x).into_options(), or8(k, x).into_options());
+        prop_assert_eq!(or8(k, x).map_t3(|x| x).into_options(), or8(k, x).into_options());
+        prop_assert_eq!(or8(k, x).map_t4(|x| x).into_options(), or8(k, x).into_options());
+        prop_assert_eq!(or8(k, x).map_t5(|x| x).into_options(), or8(k, x).into_options());
+        prop_assert_eq!(or8(k, x).map_t6(|x| x).into_options(), or8(k, x).into_options());
+        prop_assert_eq!(or8(k, x).map_t7(|x| x).into_options(), or8(k, x).into_options());
+        prop_assert_eq!(or8(k, x).map_t8(|x| x).into_options(), or8(k, x).into_options());
+    }
+
+    #[test]
+    fn or8_map_composition(k in 1..=8usize, x: i32) {
+        prop_assert_eq!(
+            or8(k, x).map_t1(f).map_t1(g).into_options(),
+            or8(k, x).map_t1(|x| g(f(x))).into_options()
+        );
+        prop_assert_eq!(
+            or8(k, x).map_t2(f).map_t2(g).into_options(),
+            or8(k, x).map_t2(|x| g(f(x))).into_options()
+        );
+        prop_assert_eq!(
+            or8(k, x).map_t3(f).map_t3(g).into_options(),
+            or8(k, x).map_t3(|x| g(f(x))).into_options()
+        );
+        prop_assert_eq!(
+            or8(k, x).map_t4(f).map_t4(g).into_options(),
+            or8(k, x).map_t4(|x| g(f(x))).into_options()
+        );
+        prop_assert_eq!(
+            or8(k, x).map_t5(f).map_t5(g).into_options(),
+            or8(k, x).map_t5(|x| g(f(x))).into_options()
+        );
+        prop_assert_eq!(
+            or8(k, x).map_t6(f).map_t6(g).into_options(),
+            or8(k, x).map_t6(|x| g(f(x))).into_options()
+        );
+        prop_assert_eq!(
+            or8(k, x).map_t7(f).map_t7(g).into_options(),
+            or8(k, x).map_t7(|x| g(f(x))).into_options()
+        );
+        prop_assert_eq!(
+            or8(k, x).map_t8(f).map_t8(g).into_options(),
+            or8(k, x).map_t8(|x| g(f(x))).into_options()
+        );
+    }
+
+    #[test]
+    fn or8_fold_map(k in 1..=8usize, x: i32) {
+        prop_assert_eq!(
+            or8(k, x).map_t1(f).fold(|x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x),
+            or8(k, x).fold(f, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x)
+        );
+        prop_assert_eq!(
+            or8(k, x).map_t2(f).fold(|x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x),
+            or8(k, x).fold(|x| x, f, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x)
+        );
+        prop_assert_eq!(
+            or8(k, x).map_t3(f).fold(|x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x),
+            or8(k, x).fold(|x| x, |x| x, f, |x| x, |x| x, |x| x, |x| x, |x| x)
+        );
+        prop_assert_eq!(
+            or8(k, x).map_t4(f).fold(|x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x),
+            or8(k, x).fold(|x| x, |x| x, |x| x, f, |x| x, |x| x, |x| x, |x| x)
+        );
+        prop_assert_eq!(
+            or8(k, x).map_t5(f).fold(|x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x),
+            or8(k, x).fold(|x| x, |x| x, |x| x, |x| x, f, |x| x, |x| x, |x| x)
+        );
+        prop_assert_eq!(
+            or8(k, x).map_t6(f).fold(|x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x),
+            or8(k, x).fold(|x| x, |x| x, |x| x, |x| x, |x| x, f, |x| x, |x| x)
+        );
+        prop_assert_eq!(
+            or8(k, x).map_t7(f).fold(|x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x),
+            or8(k, x).fold(|x| x, |x| x, |x| x, |x| x, |x| x, |x| x, f, |x| x)
+        );
+        prop_assert_eq!(
+            or8(k, x).map_t8(f).fold(|x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x),
+            or8(k, x).fold(|x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x, f)
+        );
+    }
+
+    #[test]
+    fn or8_as_is(k in 1..=8usize, x: i32) {
+        prop_assert_eq!(or8(k, x).is_t1(), k == 1);
+        prop_assert_eq!(or8(k, x).as_t1(), if k == 1 { Some(x) } else { None });
+        prop_assert_eq!(or8(k, x).is_t2(), k == 2);
+        prop_assert_eq!(or8(k, x).as_t2(), if k == 2 { Some(x) } else { None });
+        prop_assert_eq!(or8(k, x).is_t3(), k == 3);
+        prop_assert_eq!(or8(k, x).as_t3(), if k == 3 { Some(x) } else { None });
+        prop_assert_eq!(or8(k, x).is_t4(), k == 4);
+        prop_assert_eq!(or8(k, x).as_t4(), if k == 4 { Some(x) } else { None });
+        prop_assert_eq!(or8(k, x).is_t5(), k == 5);
+        prop_assert_eq!(or8(k, x).as_t5(), if k == 5 { Some(x) } else { None });
+        prop_assert_eq!(or8(k, x).is_t6(), k == 6);
+        prop_assert_eq!(or8(k, x).as_t6(), if k == 6 { Some(x) } else { None });
+        prop_assert_eq!(or8(k, x).is_t7(), k == 7);
+        prop_assert_eq!(or8(k, x).as_t7(), if k == 7 { Some(x) } else { None });
+        prop_assert_eq!(or8(k, x).is_t8(), k == 8);
+        prop_assert_eq!(or8(k, x).as_t8(), if k == 8 { Some(x) } else { None });
+    }
+}
+
+fn or9(k: usize, x: i32) -> Or9<i32, i32, i32, i32, i32, i32, i32, i32, i32> {
+    match k {
+        1 => Or9::T1(x),
+        2 => Or9::T2(x),
+        3 => Or9::T3(x),
+        4 => Or9::T4(x),
+        5 => Or9::T5(x),
+        6 => Or9::T6(x),
+        7 => Or9::T7(x),
+        8 => Or9::T8(x),
+        _ => Or9::T9(x),
+    }
+}
+
+proptest! {
+    #[test]
+    fn or9_map_identity(k in 1..=9usize, x: i32) {
+        prop_assert_eq!(or9(k, x).map_t1(|x| x).into_options(), or9(k, x).into_options());
+        prop_assert_eq!(or9(k, x).map_t2(|x| x).into_options(), or9(k, x).into_options());
+        prop_assert_eq!(or9(k, x).map_t3(|x| x).into_options(), or9(k, x).into_options());
+        prop_assert_eq!(or9(k, x).map_t4(|x| x).into_options(), or9(k, x).into_options());
+        prop_assert_eq!(or9(k, x).map_t5(|x| x).into_options(), or9(k, x).into_options());
+        prop_assert_eq!(or9(k, x).map_t6(|x| x).into_options(), or9(k, x).into_options());
+        prop_assert_eq!(or9(k, x).map_t7(|x| x).into_options(), or9(k, x).into_options());
+        prop_assert_eq!(or9(k, x).map_t8(|x| x).into_options(), or9(k, x).into_options());
+        prop_assert_eq!(or9(k, x).map_t9(|x| x).into_options(), or9(k, x).into_options());
+    }
+
+    #[test]
+    fn or9_map_composition(k in 1..=9usize, x: i32) {
+        prop_assert_eq!(
+            or9(k, x).map_t1(f).map_t1(g).into_options(),
+            or9(k, x).map_t1(|x| g(f(x))).into_options()
+        );
+        prop_assert_eq!(
+            or9(k, x).map_t2(f).map_t2(g).into_options(),
+            or9(k, x).map_t2(|x| g(f(x))).into_options()
+        );
+        prop_assert_eq!(
+            or9(k, x).map_t3(f).map_t3(g).into_options(),
+            or9(k, x).map_t3(|x| g(f(x))).into_options()
+        );
+        prop_assert_eq!(
+            or9(k, x).map_t4(f).map_t4(g).into_options(),
+            or9(k, x).map_t4(|x| g(f(x))).into_options()
+        );
+        prop_assert_eq!(
+            or9(k, x).map_t5(f).map_t5(g).into_options(),
+            or9(k, x).map_t5(|x| g(f(x))).into_options()
+        );
+        prop_assert_eq!(
+            or9(k, x).map_t6(f).map_t6(g).into_options(),
+            or9(k, x).map_t6(|x| g(f(x))).into_options()
+        );
+        prop_assert_eq!(
+            or9(k, x).map_t7(f).map_t7(g).into_options(),
+            or9(k, x).map_t7(|x| g(f(x))).into_options()
+        );
+        prop_assert_eq!(
+            or9(k, x).map_t8(f).map_t8(g).into_options(),
+            or9(k, x).map_t8(|x| g(f(x))).into_options()
+        );
+        prop_assert_eq!(
+            or9(k, x).map_t9(f).map_t9(g).into_options(),
+            or9(k, x).map_t9(|x| g(f(x))).into_options()
+        );
+    }
+
+    #[test]
+    fn or9_fold_map(k in 1..=9usize, x: i32) {
+        prop_assert_eq!(
+            or9(k, x).map_t1(f).fold(|x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x),
+            or9(k, x).fold(f, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x)
+        );
+        prop_assert_eq!(
+            or9(k, x).map_t2(f).fold(|x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x),
+            or9(k, x).fold(|x| x, f, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x)
+        );
+        prop_assert_eq!(
+            or9(k, x).map_t3(f).fold(|x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x),
+            or9(k, x).fold(|x| x, |x| x, f, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x)
+        );
+        prop_assert_eq!(
+            or9(k, x).map_t4(f).fold(|x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x),
+            or9(k, x).fold(|x| x, |x| x, |x| x, f, |x| x, |x| x, |x| x, |x| x, |x| x)
+        );
+        prop_assert_eq!(
+            or9(k, x).map_t5(f).fold(|x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x),
+            or9(k, x).fold(|x| x, |x| x, |x| x, |x| x, f, |x| x, |x| x, |x| x, |x| x)
+        );
+        prop_assert_eq!(
+            or9(k, x).map_t6(f).fold(|x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x),
+            or9(k, x).fold(|x| x, |x| x, |x| x, |x| x, |x| x, f, |x| x, |x| x, |x| x)
+        );
+        prop_assert_eq!(
+            or9(k, x).map_t7(f).fold(|x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x),
+            or9(k, x).fold(|x| x, |x| x, |x| x, |x| x, |x| x, |x| x, f, |x| x, |x| x)
+        );
+        prop_assert_eq!(
+            or9(k, x).map_t8(f).fold(|x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x),
+            or9(k, x).fold(|x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x, f, |x| x)
+        );
+        prop_assert_eq!(
+            or9(k, x).map_t9(f).fold(|x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x),
+            or9(k, x).fold(|x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x, |x| x, f)
+        );
+    }
+
+    #[test]
+    fn or9_as_is(k in 1..=9usize, x: i32) {
+        prop_assert_eq!(or9(k, x).is_t1(), k == 1);
+        prop_assert_eq!(or9(k, x).as_t1(), if k == 1 { Some(x) } else { None });
+        prop_assert_eq!(or9(k, x).is_t2(), k == 2);
+        prop_assert_eq!(or9(k, x).as_t2(), if k == 2 { Some(x) } else { None });
+        prop_assert_eq!(or9(k, x).is_t3(), k == 3);
+        prop_assert_eq!(or9(k, x).as_t3(), if k == 3 { Some(x) } else { None });
+        prop_assert_eq!(or9(k, x).is_t4(), k == 4);
+        prop_assert_eq!(or9(k, x).as_t4(), if k == 4 { Some(x) } else { None });
+        prop_assert_eq!(or9(k, x).is_t5(), k == 5);
+        prop_assert_eq!(or9(k, x).as_t5(), if k == 5 { Some(x) } else { None });
+        prop_assert_eq!(or9(k, x).is_t6(), k == 6);
+        prop_assert_eq!(or9(k, x).as_t6(), if k == 6 { Some(x) } else { None });
+        prop_assert_eq!(or9(k, x).is_t7(), k == 7);
+        prop_assert_eq!(or9(k, x).as_t7(), if k == 7 { Some(x) } else { None });
+        prop_assert_eq!(or9(k, x).is_t8(), k == 8);
+        prop_assert_eq!(or9(k, x).as_t8(), if k == 8 { Some(x) } else { None });
+        prop_assert_eq!(or9(k, x).is_t9(), k == 9);
+        prop_assert_eq!(or9(k, x).as_t9(), if k == 9 { Some(x) } else { None });
+    }
+}
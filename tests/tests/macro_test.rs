@@ -1,7 +1,5 @@
-#![feature(proc_macro_hygiene)]
-#![allow(unused_variables)]
-
-use or_rs_macros::or_gen;
+#![feature(proc_macro_hygiene, stmt_expr_attributes, try_blocks)]
+#![allow(unused_variables, clippy::no_effect, clippy::needless_ifs)]
 
 #[test]
 fn test_compile() {
@@ -56,3 +54,832 @@ fn test_compile() {
         _ => "hello".to_string(),
     };
 }
+
+#[test]
+fn test_coerce() {
+    use or_rs::enums::*;
+    use or_rs_macros::or_gen;
+
+    let b = false;
+
+    #[or_gen(coerce)]
+    let x: Or3<i64, String, f64> = if b {
+        3
+    } else if !b {
+        "hello"
+    } else {
+        3.0_f32
+    };
+    assert_eq!(x.as_t2().unwrap(), "hello");
+
+    #[or_gen(coerce)]
+    let x: Or2<i64, String> = match 3 {
+        3 => 3_u8,
+        _ => 'c'.to_string(),
+    };
+    assert_eq!(x.as_t1().unwrap(), 3);
+}
+
+#[test]
+fn test_boxed() {
+    use or_rs::enums::*;
+    use or_rs_macros::or_gen;
+
+    #[allow(dead_code)]
+    struct Large([u64; 64]);
+
+    #[or_gen(boxed)]
+    let x: Or2<Box<Large>, Box<i32>> = if false { Large([0; 64]) } else { 3 };
+    assert_eq!(std::mem::size_of_val(&x), 16);
+    assert_eq!(*x.as_t2().unwrap(), 3);
+
+    #[or_gen(boxed, coerce)]
+    let x: Or2<Box<String>, Box<i64>> = match 3 {
+        1 => "hello",
+        _ => 3,
+    };
+    assert_eq!(*x.as_t2().unwrap(), 3);
+}
+
+#[test]
+fn test_flatten() {
+    use or_rs::enums::*;
+    use or_rs_macros::or_gen;
+
+    let f = |n: i32| {
+        #[or_gen(flatten)]
+        let x: Or4<i32, String, f32, bool> = if n < 10 {
+            let m = n * 2;
+            if m < 10 {
+                m
+            } else {
+                m.to_string()
+            }
+        } else {
+            match n {
+                10 => 1.0,
+                _ => n.is_positive(),
+            }
+        };
+        x
+    };
+
+    assert_eq!(f(2).as_t1().unwrap(), 4);
+    assert_eq!(f(6).as_t2().unwrap(), "12");
+    assert_eq!(f(10).as_t3().unwrap(), 1.0);
+    assert!(f(11).as_t4().unwrap());
+}
+
+#[test]
+fn test_expr_position() {
+    use or_rs::enums::*;
+    use or_rs_macros::or_gen;
+
+    let n = 3;
+    let x: Or3<i32, f32, String> = #[or_gen]
+    match n {
+        1 => 33,
+        3 => 3.2,
+        _ => "hello".to_string(),
+    };
+    assert_eq!(x.as_t2().unwrap(), 3.2);
+
+    fn describe(x: Or2<i32, String>) -> String {
+        x.fold(|a| a.to_string(), |b| b)
+    }
+    let s = describe(
+        #[or_gen(coerce)]
+        if n > 0 { n } else { "negative" },
+    );
+    assert_eq!(s, "3");
+}
+
+#[test]
+fn test_crate_path() {
+    use or_rs_macros::or_gen;
+
+    // stands in for `or_rs` under another path, like a re-export of another crate.
+    mod custom {
+        pub mod enums {
+            #[derive(Debug, PartialEq)]
+            pub enum Or2<T1, T2> {
+                T1(T1),
+                T2(T2),
+            }
+        }
+
+        pub mod __private {
+            #[derive(Debug, PartialEq)]
+            pub struct Box<T>(pub T);
+
+            impl<T> Box<T> {
+                pub fn new(value: T) -> Self {
+                    Box(value)
+                }
+            }
+        }
+    }
+    use custom::__private::Box;
+
+    let n = 3;
+    let x = #[or_gen(crate = custom)]
+    if n > 0 { n } else { "negative" };
+    assert_eq!(x, custom::enums::Or2::T1(3));
+
+    #[or_gen(boxed, crate = custom)]
+    let x: custom::enums::Or2<Box<i32>, Box<&str>> = if n > 0 { n } else { "negative" };
+    assert_eq!(x, custom::enums::Or2::T1(Box(3)));
+
+    // the boxed trait objects need the real `Box`, so `or_rs` is re-exported as is.
+    mod reexport {
+        pub use or_rs::*;
+    }
+    #[or_gen(dyn std::fmt::Debug, crate = reexport)]
+    let x = if n > 0 { n } else { "negative" };
+    assert_eq!(format!("{:?}", x.fold(|a| a, |b| b)), "3");
+}
+
+#[test]
+fn test_trace() {
+    use or_rs::enums::*;
+    use or_rs_macros::or_gen;
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    // records the fields of the events as `name=value`.
+    #[derive(Clone, Default)]
+    struct Recorder(Arc<Mutex<Vec<String>>>);
+
+    impl Visit for Recorder {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            let field = format!("{}={:?}", field.name(), value);
+            self.0.lock().unwrap().push(field);
+        }
+    }
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+        fn new_span(&self, _: &Attributes<'_>) -> Id {
+            Id::from_u64(1)
+        }
+        fn record(&self, _: &Id, _: &Record<'_>) {}
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+        fn event(&self, event: &Event<'_>) {
+            event.record(&mut self.clone());
+        }
+        fn enter(&self, _: &Id) {}
+        fn exit(&self, _: &Id) {}
+    }
+
+    let recorder = Recorder::default();
+    tracing::subscriber::with_default(recorder.clone(), || {
+        #[or_gen(trace)]
+        let x: Or2<i32, String> = if false { 3 } else { "hello".to_string() };
+        assert!(x.is_t2());
+    });
+
+    assert_eq!(
+        *recorder.0.lock().unwrap(),
+        [
+            "message=or_gen branch taken",
+            "variant=\"T2\"",
+            "index=2",
+            "type_name=\"alloc::string::String\"",
+        ]
+    );
+}
+
+#[test]
+fn test_generic() {
+    use or_rs_macros::or_gen;
+
+    fn f<T: Default + Clone>(n: i32, t: T) -> or_rs::enums::Or3<T, String, Vec<T>> {
+        #[or_gen]
+        let x: or_rs::enums::Or3<T, String, Vec<T>> = match n {
+            0 => t,
+            1 => format!("{}", n),
+            _ => vec![t, T::default()],
+        };
+        x
+    }
+
+    assert_eq!(f(0, 3.5).as_t1().unwrap(), 3.5);
+    assert_eq!(f(1, 3.5).as_t2().unwrap(), "1");
+    assert_eq!(f(2, 3.5).as_t3().unwrap(), vec![3.5, 0.0]);
+}
+
+#[test]
+fn test_reference() {
+    use or_rs::enums::*;
+    use or_rs_macros::or_gen;
+
+    struct Record {
+        name: String,
+        bytes: Vec<u8>,
+    }
+
+    impl Record {
+        fn key<'a>(&'a self, by_name: bool) -> Or2<&'a str, &'a [u8]> {
+            #[or_gen]
+            let x: Or2<&'a str, &'a [u8]> = if by_name { &self.name } else { &self.bytes };
+            x
+        }
+    }
+
+    let record = Record {
+        name: "name".to_string(),
+        bytes: vec![1, 2],
+    };
+    assert_eq!(record.key(true).as_t1().unwrap(), "name");
+    assert_eq!(record.key(false).as_t2().unwrap(), &[1, 2]);
+
+    #[or_gen]
+    let x: Or2<&str, &[u8]> = match record.bytes.len() {
+        0 => &record.name,
+        _ => &record.bytes[..1],
+    };
+    assert_eq!(x.as_t2().unwrap(), &[1]);
+}
+
+#[test]
+fn test_or_alias() {
+    use or_rs::enums::Or2;
+    use or_rs_macros::or_alias;
+
+    or_alias! {
+        #[derive(Debug, PartialEq)]
+        pub Key = Or2<name: &'static str, raw_bytes: Vec<u8>>
+    }
+
+    let key = Key::from(Or2::<&str, Vec<u8>>::T2(vec![1, 2]));
+    assert_eq!(key, Key::RawBytes(vec![1, 2]));
+    assert!(key.is_raw_bytes());
+    assert!(!key.is_name());
+    assert_eq!(key.as_raw_bytes(), Some(vec![1, 2]));
+
+    let or: Or2<&str, Vec<u8>> = Key::Name("name").into();
+    assert_eq!(or.as_t1(), Some("name"));
+}
+
+#[test]
+fn test_cfg() {
+    use or_rs::enums::*;
+    use or_rs_macros::or_gen;
+
+    let f = |n: i32| {
+        #[or_gen]
+        let x: Or3<i32, f32, String> = match n {
+            1 => 22,
+            #[cfg(any())]
+            2 => 3.2,
+            #[cfg(not(any()))]
+            _ => "hello".to_string(),
+        };
+        x
+    };
+    assert_eq!(f(1).as_t1().unwrap(), 22);
+    // the disabled arm still takes `T2`, so the enabled one is `T3`.
+    assert_eq!(f(2).as_t3().unwrap(), "hello");
+}
+
+#[test]
+fn test_attributes() {
+    use or_rs::enums::*;
+    use or_rs_macros::or_gen;
+
+    let f = |n: i32| {
+        #[or_gen]
+        let x: Or3<i32, f32, String> = match n {
+            m if m < 0 => 1,
+            #[allow(clippy::manual_range_patterns)]
+            0 | 1 | 2 => {
+                #[allow(clippy::approx_constant)]
+                let pi = 3.14;
+                pi
+            }
+            _ =>
+            {
+                #[allow(unused_parens)]
+                ("hello".to_string())
+            }
+        };
+        x
+    };
+    assert_eq!(f(-1).as_t1().unwrap(), 1);
+    assert!(f(1).is_t2());
+    assert_eq!(f(3).as_t3().unwrap(), "hello");
+}
+
+#[test]
+fn test_nested() {
+    use or_rs::enums::*;
+    use or_rs_macros::or_gen;
+
+    let f = |n: i32| {
+        #[or_gen]
+        let x: Option<Or2<i32, String>> = if n == 0 {
+            None
+        } else if n > 0 {
+            Some(n)
+        } else {
+            Option::Some("negative".to_string())
+        };
+        x
+    };
+    assert!(f(0).is_none());
+    assert_eq!(f(1).unwrap().as_t1().unwrap(), 1);
+    assert_eq!(f(-1).unwrap().as_t2().unwrap(), "negative");
+
+    #[or_gen]
+    let x: Result<Or2<i32, f32>, String> = match 3 {
+        1 => Ok(1),
+        2 => Err("two".to_string()),
+        _ => Ok(3.0),
+    };
+    assert_eq!(x.unwrap().as_t2().unwrap(), 3.0);
+}
+
+#[test]
+fn test_variant() {
+    use or_rs::enums::*;
+    use or_rs_macros::or_gen;
+
+    let f = |n: i32| {
+        #[or_gen]
+        let x: Or3<i32, String, f32> = match n {
+            0 => 0,
+            #[variant(T2)]
+            1 => "one".to_string(),
+            #[or_gen::variant(T2)]
+            2 => "two".to_string(),
+            3 => "three".to_string(),
+            _ => 3.0,
+        };
+        x
+    };
+    assert_eq!(f(0).as_t1().unwrap(), 0);
+    assert_eq!(f(1).as_t2().unwrap(), "one");
+    assert_eq!(f(2).as_t2().unwrap(), "two");
+    assert_eq!(f(3).as_t2().unwrap(), "three");
+    assert_eq!(f(4).as_t3().unwrap(), 3.0);
+
+    // the `Or` type is named after the largest variant in the expression position.
+    let x: Or3<i32, bool, String> = #[or_gen]
+    if false {
+        1
+    } else {
+        #[variant(T3)]
+        "hello".to_string()
+    };
+    assert_eq!(x.as_t3().unwrap(), "hello");
+}
+
+#[test]
+fn test_passthrough() {
+    use or_rs::enums::*;
+    use or_rs_macros::or_gen;
+
+    fn parse(s: &str) -> Or3<i32, f32, String> {
+        if let Ok(n) = s.parse() {
+            Or3::T1(n)
+        } else if let Ok(n) = s.parse() {
+            Or3::T2(n)
+        } else {
+            Or3::T3(s.to_string())
+        }
+    }
+
+    // a branch passed through still takes its position.
+    let f = |n: i32| {
+        #[or_gen]
+        let x: Or3<i32, f32, String> = match n {
+            0 => Or3::T3("zero".to_string()),
+            #[passthrough]
+            1 => parse("1.5"),
+            _ => "other".to_string(),
+        };
+        x
+    };
+    assert_eq!(f(0).as_t3().unwrap(), "zero");
+    assert_eq!(f(1).as_t2().unwrap(), 1.5);
+    assert_eq!(f(2).as_t3().unwrap(), "other");
+
+    let f = |n: i32| {
+        #[or_gen]
+        let x: Or3<i32, f32, String> = if n == 0 {
+            0
+        } else if n == 1 {
+            or_rs::enums::Or3::<i32, f32, String>::T1(1)
+        } else {
+            #[or_gen::passthrough]
+            parse("two")
+        };
+        x
+    };
+    assert_eq!(f(0).as_t1().unwrap(), 0);
+    assert_eq!(f(1).as_t1().unwrap(), 1);
+    assert_eq!(f(2).as_t3().unwrap(), "two");
+
+    // the `Or` type is named after the constructor in the expression position.
+    let x: Or3<i32, bool, String> = #[or_gen]
+    if false {
+        1
+    } else {
+        Or3::T3("hello".to_string())
+    };
+    assert_eq!(x.as_t3().unwrap(), "hello");
+
+    // the whole value is passed through inside an `Option`.
+    fn lookup(n: i32) -> Option<Or2<i32, String>> {
+        (n == 1).then(|| Or2::T2("one".to_string()))
+    }
+    let f = |n: i32| {
+        #[or_gen]
+        let x: Option<Or2<i32, String>> = match n {
+            0 => Some(0),
+            #[passthrough]
+            1 => lookup(n),
+            _ => None,
+        };
+        x
+    };
+    assert_eq!(f(0).unwrap().as_t1().unwrap(), 0);
+    assert_eq!(f(1).unwrap().as_t2().unwrap(), "one");
+    assert!(f(2).is_none());
+}
+
+#[test]
+fn test_type_in_attribute() {
+    use or_rs::enums::*;
+    use or_rs_macros::or_gen;
+
+    let f = |n: i32| {
+        #[or_gen(Or3<i32, f32, String>)]
+        let x = match n {
+            0 => 0,
+            1 => 1.0,
+            _ => "other".to_string(),
+        };
+        x.is_t1()
+    };
+    assert!(f(0));
+    assert!(!f(1));
+
+    #[or_gen(Or2<i32, String>)]
+    let x = match n_is_zero(2) {
+        true => 0,
+        false => "other".to_string(),
+    };
+    assert_eq!(x.as_t2().unwrap(), "other");
+
+    // the options follow the type, and the binding can be `_`.
+    #[or_gen(or_rs::enums::Or2<i64, String>, coerce)]
+    let _ = if n_is_zero(0) { 3 } else { "hello" };
+
+    #[or_gen(Option<Or2<i32, String>>, allow_unused_variants)]
+    let y = if n_is_zero(0) { Some(1) } else { None };
+    assert_eq!(y.unwrap().as_t1().unwrap(), 1);
+
+    let x = #[or_gen(Or2<u8, String>)]
+    if n_is_zero(1) {
+        1
+    } else {
+        "hello".to_string()
+    };
+    assert_eq!(x.as_t2().unwrap(), "hello");
+}
+
+fn n_is_zero(n: i32) -> bool {
+    n == 0
+}
+
+#[test]
+fn test_tail() {
+    use or_rs::enums::*;
+    use or_rs_macros::or_gen;
+
+    // only the tail of the branch is wrapped, not the tails of closures and blocks in it.
+    let f = |n: i32| {
+        #[or_gen]
+        let x: Or3<i32, String, ()> = match n {
+            0 => {
+                let add = |x: i32| {
+                    let y = x + 1;
+                    y * 2
+                };
+                add(1)
+            }
+            1 => {
+                let s = {
+                    let s = "one";
+                    s.to_string()
+                };
+                let len = match s.len() {
+                    3 => 3,
+                    _ => 0,
+                };
+                format!("{}{}", s, len)
+            }
+            _ => {
+                let _ = n;
+            }
+        };
+        x
+    };
+    assert_eq!(f(0).as_t1().unwrap(), 4);
+    assert_eq!(f(1).as_t2().unwrap(), "one3");
+    assert!(f(2).is_t3());
+
+    // a diverging branch takes no variant.
+    let f = |n: i32| -> Option<Or2<i32, String>> {
+        #[or_gen]
+        let x: Or2<i32, String> = match n {
+            0 => return None,
+            1 => {
+                let _ = n;
+                return None;
+            }
+            2 => 2,
+            _ => {
+                format!("other{}", n)
+            }
+        };
+        Some(x)
+    };
+    assert!(f(0).is_none());
+    assert!(f(1).is_none());
+    assert_eq!(f(2).unwrap().as_t1().unwrap(), 2);
+    assert_eq!(f(3).unwrap().as_t2().unwrap(), "other3");
+}
+
+#[test]
+fn test_wrapped_initializer() {
+    use or_rs::enums::*;
+    use or_rs_macros::or_gen;
+
+    #[or_gen]
+    #[allow(unused_parens)]
+    let x: Or2<i32, f32> = (if n_is_zero(1) { 1 } else { 2.0 });
+    assert_eq!(x.as_t2().unwrap(), 2.0);
+
+    // with the `Or` type in the attribute, the branches can be wrapped in a method call.
+    #[or_gen(Or2<i32, String>)]
+    let s = match 42 {
+        0 => 0,
+        n => format!("n = {}", n),
+    }
+    .fold(|n| n.to_string(), |s| s);
+    assert_eq!(s, "n = 42");
+
+    fn parse(n: i32) -> Result<Or2<i32, f32>, String> {
+        #[or_gen(Result<Or2<i32, f32>, String>)]
+        let x = match n {
+            0 => Ok(0),
+            1 => Ok(1.0),
+            _ => Err(format!("unexpected {}", n)),
+        }?;
+        Ok(x)
+    }
+    assert_eq!(parse(0).unwrap().as_t1().unwrap(), 0);
+    assert_eq!(parse(1).unwrap().as_t2().unwrap(), 1.0);
+    assert_eq!(parse(2).err().unwrap(), "unexpected 2");
+}
+
+#[test]
+fn test_cast_tail() {
+    use or_rs::enums::*;
+    use or_rs_macros::or_gen;
+
+    let f = |n: i64| {
+        #[or_gen]
+        let x: Or3<i32, f64, usize> = match n {
+            0 => n as i32,
+            1 => {
+                let half = n as f32 / 2.0;
+                half as f64
+            }
+            _ => n as usize,
+        };
+        x
+    };
+    assert_eq!(f(0).as_t1().unwrap(), 0);
+    assert_eq!(f(1).as_t2().unwrap(), 0.5);
+    assert_eq!(f(7).as_t3().unwrap(), 7);
+
+    let value = 3u8;
+    let ptr = &value as *const u8;
+    let x: Or2<usize, u32> = #[or_gen]
+    if n_is_zero(0) {
+        ptr as usize
+    } else {
+        value as u32
+    };
+    assert_eq!(x.as_t1().unwrap(), ptr as usize);
+}
+
+#[test]
+fn test_await_scrutinee() {
+    use or_rs::enums::*;
+    use or_rs_macros::or_gen;
+
+    async fn fetch(url: &str) -> Result<String, String> {
+        match url.strip_prefix("https://") {
+            Some(host) => Ok(host.to_string()),
+            None => Err(format!("unsupported url {}", url)),
+        }
+    }
+
+    async fn get(url: &str) -> Result<Or2<usize, String>, String> {
+        #[or_gen]
+        let x: Or2<usize, String> = match fetch(url).await?.as_str() {
+            "" => 0,
+            host => host.to_uppercase(),
+        };
+        Ok(x)
+    }
+
+    async fn status(url: &str) -> Or2<String, String> {
+        #[or_gen]
+        let x: Or2<String, String> = match fetch(url).await {
+            Ok(host) => host,
+            #[variant(T2)]
+            Err(e) => e,
+        };
+        x
+    }
+
+    assert_eq!(
+        block_on(get("https://example.com"))
+            .unwrap()
+            .as_t2()
+            .unwrap(),
+        "EXAMPLE.COM"
+    );
+    assert_eq!(block_on(get("https://")).unwrap().as_t1().unwrap(), 0);
+    assert!(block_on(get("ftp://example.com")).is_err());
+    assert!(block_on(status("ftp://example.com")).is_t2());
+}
+
+#[test]
+fn test_tuple_annotation() {
+    use or_rs::enums::*;
+    use or_rs_macros::or_gen;
+
+    let n = 3;
+    #[or_gen]
+    let (x, count): (Or2<i32, String>, usize) = (
+        if n_is_zero(n) {
+            0
+        } else {
+            format!("n = {}", n)
+        },
+        n as usize,
+    );
+    assert_eq!(x.as_t2().unwrap(), "n = 3");
+    assert_eq!(count, 3);
+
+    // every element which is an `if` or `match` expression takes its own `Or` type.
+    #[or_gen]
+    let (x, y, z): (Or2<i32, f32>, Option<Or2<u8, bool>>, &str) = (
+        match n {
+            0 => 0,
+            _ => 1.5,
+        },
+        match n {
+            0 => None,
+            1 => Some(1),
+            _ => Some(true),
+        },
+        "z",
+    );
+    assert_eq!(x.as_t2().unwrap(), 1.5);
+    assert!(y.unwrap().as_t2().unwrap());
+    assert_eq!(z, "z");
+}
+
+#[test]
+fn test_dyn_trait() {
+    use or_rs::as_dyn;
+    use or_rs::enums::*;
+    use or_rs_macros::or_gen;
+
+    type BoxedIter = Box<dyn Iterator<Item = u32>>;
+
+    fn evens_or_odds(n: u32, evens: bool) -> Or2<BoxedIter, BoxedIter> {
+        #[or_gen(dyn Iterator<Item = u32>)]
+        if evens {
+            (0..n).filter(|x| x % 2 == 0)
+        } else {
+            (0..n).filter(|x| x % 2 == 1).map(|x| x * 10)
+        }
+    }
+    let it = evens_or_odds(5, true);
+    assert!(it.is_t1());
+    assert_eq!(it.fold(|it| it, |it| it).collect::<Vec<_>>(), vec![0, 2, 4]);
+    let mut it = evens_or_odds(5, false);
+    assert_eq!(
+        as_dyn!(it as &mut dyn Iterator<Item = u32>).collect::<Vec<_>>(),
+        vec![10, 30]
+    );
+
+    let offset = 3;
+    #[or_gen(dyn Fn(i32) -> i32)]
+    let f = match offset {
+        0 => |x| x,
+        1 => move |x| x + offset,
+        _ => move |x| x * offset,
+    };
+    assert!(f.is_t3());
+    assert_eq!(f.fold(|f| f, |f| f, |f| f)(2), 6);
+}
+
+#[test]
+fn test_allow_unused_variants() {
+    use or_rs::enums::*;
+    use or_rs_macros::or_gen;
+
+    // `T3` is reserved for a branch added later.
+    #[or_gen(allow_unused_variants)]
+    let x: Or3<i32, String, f32> = match 42 {
+        0 => 0,
+        n => format!("n = {}", n),
+    };
+    assert_eq!(x.as_t2().unwrap(), "n = 42");
+
+    #[or_gen(Or4<u8, char, bool, ()>, allow_unused_variants)]
+    let y = if n_is_zero(0) { 1 } else { 'a' };
+    assert!(y.is_t1());
+}
+
+#[test]
+fn test_try_block() {
+    use or_rs::enums::*;
+    use or_rs_macros::or_gen;
+
+    fn parse(s: &str) -> Result<Or2<i32, f64>, std::num::ParseFloatError> {
+        #[or_gen]
+        let x: Result<Or2<i32, f64>, std::num::ParseFloatError> = try {
+            let f: f64 = s.parse()?;
+            if f.fract() == 0.0 {
+                f as i32
+            } else {
+                f
+            }
+        };
+        x
+    }
+    assert_eq!(parse("3").unwrap().as_t1().unwrap(), 3);
+    assert_eq!(parse("3.5").unwrap().as_t2().unwrap(), 3.5);
+    assert!(parse("x").is_err());
+
+    let v = [1, 2, 3];
+    #[or_gen]
+    let y: Option<Or2<u8, char>> = try {
+        match v.get(1)? {
+            2 => 2,
+            _ => 'x',
+        }
+    };
+    assert_eq!(y.unwrap().as_t1().unwrap(), 2);
+}
+
+// polls a future to completion, which is enough for the futures in these tests.
+fn block_on<F: std::future::Future>(fut: F) -> F::Output {
+    use std::task::{Context, Poll, Waker};
+
+    let mut fut = std::pin::pin!(fut);
+    let mut cx = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = fut.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
+#[test]
+fn test_unit_branch() {
+    use or_rs::enums::*;
+    use or_rs_macros::or_gen;
+
+    fn check(v: &[i32]) -> Or2<i32, ()> {
+        #[or_gen]
+        let x: Or2<i32, ()> = if let Some(first) = v.first() {
+            *first
+        };
+        x
+    }
+    assert_eq!(check(&[3]).into_option(), Some(3));
+    assert_eq!(check(&[]).into_option(), None);
+
+    #[or_gen]
+    let x: Or3<i32, String, ()> = match check(&[]).into_option() {
+        Some(n) if n > 0 => n,
+        Some(n) => n.to_string(),
+        None => {}
+    };
+    assert_eq!(x.into_option(), None);
+}
@@ -0,0 +1,29 @@
+use or_rs::enums::Or4;
+use or_rs::parse::{parse_scalar, parse_scalar_with, ScalarKind};
+
+#[test]
+fn test_parse_scalar() {
+    assert_eq!(parse_scalar("-3").unwrap().as_t1(), Some(-3));
+    assert_eq!(parse_scalar("1e3").unwrap().as_t2(), Some(1000.0));
+    assert_eq!(parse_scalar("false").unwrap().as_t3(), Some(false));
+    assert_eq!(parse_scalar("1.2.3").unwrap().as_t4().unwrap(), "1.2.3");
+    assert!(parse_scalar("").unwrap().is_t5());
+}
+
+#[test]
+fn test_parse_scalar_with() {
+    // floats take priority over integers.
+    let priority = [ScalarKind::Float, ScalarKind::Int];
+    assert_eq!(parse_scalar_with("1", &priority).unwrap().as_t2(), Some(1.0));
+
+    // every kind fails, so all failures are returned in the order of the priority.
+    let err = parse_scalar_with("x", &priority).err().unwrap();
+    assert_eq!(err.input(), "x");
+    assert_eq!(err.failures().len(), 2);
+    assert!(matches!(err.failures()[0], Or4::T2(_)));
+    assert!(matches!(err.failures()[1], Or4::T1(_)));
+    assert_eq!(
+        err.to_string(),
+        "failed to parse `x` as a scalar\n  float: invalid float literal\n  int: invalid digit found in string"
+    );
+}
@@ -0,0 +1,46 @@
+#![cfg(feature = "pyo3")]
+
+use or_rs::enums::*;
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+
+#[test]
+fn test_into_py() {
+    Python::with_gil(|py| {
+        let obj = Or2::<i32, String>::T1(3).into_py(py);
+        assert_eq!(obj.extract::<i32>(py).unwrap(), 3);
+
+        let obj = Or3::<i32, String, bool>::T2("hello".to_string()).into_py(py);
+        assert_eq!(obj.extract::<String>(py).unwrap(), "hello");
+    });
+}
+
+#[test]
+fn test_from_py_object() {
+    Python::with_gil(|py| {
+        // the first type which can be extracted wins.
+        let obj = 3_i32.into_py(py);
+        let x = obj.extract::<Or2<String, i32>>(py).unwrap();
+        assert_eq!(x.as_t2(), Some(3));
+
+        let obj: PyObject = "hello".into_py(py);
+        let x = obj.extract::<Or3<i32, String, bool>>(py).unwrap();
+        assert_eq!(x.as_t2().unwrap(), "hello");
+    });
+}
+
+#[test]
+fn test_from_py_object_error() {
+    Python::with_gil(|py| {
+        // every type fails, so a `TypeError` describing all the failures is raised.
+        let obj = 1.5_f64.into_py(py);
+        let err = obj.extract::<Or2<i32, String>>(py).err().unwrap();
+        assert!(err.is_instance_of::<PyTypeError>(py));
+        assert_eq!(
+            err.to_string(),
+            "TypeError: failed to extract Or2 (\
+             T1: TypeError: 'float' object cannot be interpreted as an integer, \
+             T2: TypeError: 'float' object cannot be converted to 'PyString')"
+        );
+    });
+}
@@ -0,0 +1,52 @@
+#![cfg(any(feature = "repr-c", feature = "repr-u8"))]
+
+use std::mem::size_of;
+
+use or_rs::enums::*;
+
+// reads the tag in front of the payload, as a C caller would.
+#[cfg(feature = "repr-u8")]
+fn tag<O>(x: &O) -> usize {
+    unsafe { *(x as *const O as *const u8) as usize }
+}
+
+#[cfg(not(feature = "repr-u8"))]
+fn tag<O>(x: &O) -> usize {
+    unsafe { *(x as *const O as *const std::os::raw::c_int) as usize }
+}
+
+#[test]
+fn test_discriminant() {
+    // the tag is the 0-based index of the variant.
+    assert_eq!(tag(&Or3::<u8, u16, u32>::T1(7)), 0);
+    assert_eq!(tag(&Or3::<u8, u16, u32>::T2(7)), 1);
+    assert_eq!(tag(&Or3::<u8, u16, u32>::T3(7)), 2);
+    assert_eq!(tag(&Or2::<bool, u8>::T2(7)), 1);
+}
+
+#[test]
+fn test_payload_offset() {
+    // the payload follows the tag, aligned to the largest payload type.
+    let x = Or2::<u8, u32>::T2(0x1234_5678);
+    let payload = unsafe { *(&x as *const Or2<u8, u32> as *const u32).add(1) };
+    assert_eq!(payload, 0x1234_5678);
+}
+
+#[cfg(feature = "repr-u8")]
+#[test]
+fn test_size_of_repr_u8() {
+    // a `u8` tag, and no niche is taken from the payload.
+    assert_eq!(size_of::<Or2<u8, u8>>(), 2);
+    assert_eq!(size_of::<Or2<u8, u32>>(), 8);
+    assert_eq!(size_of::<Or2<bool, ()>>(), 2);
+}
+
+#[cfg(not(feature = "repr-u8"))]
+#[test]
+fn test_size_of_repr_c() {
+    // a tag of the size of a C enum, which is `int` on the supported targets.
+    let tag = size_of::<std::os::raw::c_int>();
+    assert_eq!(size_of::<Or2<u8, u8>>(), 2 * tag);
+    assert_eq!(size_of::<Or2<u8, u32>>(), tag + 4);
+    assert_eq!(size_of::<Or2<bool, ()>>(), 2 * tag);
+}
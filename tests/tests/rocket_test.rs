@@ -0,0 +1,28 @@
+#![cfg(feature = "rocket")]
+
+use or_rs::enums::*;
+use rocket::http::Status;
+use rocket::local::blocking::Client;
+use rocket::{get, routes};
+
+#[get("/<id>")]
+fn find(id: u32) -> Or2<String, Status> {
+    if id == 1 {
+        Or2::T1("alice".to_string())
+    } else {
+        Or2::T2(Status::NotFound)
+    }
+}
+
+#[test]
+fn test_responder() {
+    let client = Client::tracked(rocket::build().mount("/", routes![find])).unwrap();
+
+    // the response is made by the responder of the active variant.
+    let response = client.get("/1").dispatch();
+    assert_eq!(response.status(), Status::Ok);
+    assert_eq!(response.into_string().unwrap(), "alice");
+
+    let response = client.get("/2").dispatch();
+    assert_eq!(response.status(), Status::NotFound);
+}
@@ -0,0 +1,48 @@
+#![cfg(feature = "serde")]
+
+use or_rs::enums::*;
+use serde_json::json;
+
+#[test]
+fn test_serialize_untagged() {
+    // the value of the active variant is serialized as is.
+    let x = Or3::<i32, String, Vec<bool>>::T2("hello".to_string());
+    assert_eq!(serde_json::to_value(&x).unwrap(), json!("hello"));
+
+    let x = Or3::<i32, String, Vec<bool>>::T3(vec![true, false]);
+    assert_eq!(serde_json::to_value(&x).unwrap(), json!([true, false]));
+}
+
+#[test]
+fn test_deserialize_untagged() {
+    // the first type which accepts the value wins.
+    let x: Or3<i32, String, Vec<bool>> = serde_json::from_value(json!([true])).unwrap();
+    assert_eq!(x, Or3::T3(vec![true]));
+
+    let x: Or2<i32, f64> = serde_json::from_value(json!(1)).unwrap();
+    assert_eq!(x, Or2::T1(1));
+
+    assert!(serde_json::from_value::<Or2<i32, String>>(json!(true)).is_err());
+}
+
+#[test]
+fn test_tagged() {
+    use or_rs::tagged::Tagged;
+
+    // the name of the variant is kept, so `T2` is not read back as `T1`.
+    let x = Tagged(Or2::<i32, f64>::T2(1.0));
+    let value = serde_json::to_value(x).unwrap();
+    assert_eq!(value, json!({ "variant": "T2", "value": 1.0 }));
+    assert_eq!(
+        serde_json::from_value::<Tagged<Or2<i32, f64>>>(value).unwrap(),
+        x
+    );
+
+    let value = json!({ "variant": "T1", "value": 1 });
+    let x: Tagged<Or2<i32, f64>> = serde_json::from_value(value).unwrap();
+    assert_eq!(x.into_inner(), Or2::T1(1));
+
+    // an unknown variant is rejected.
+    let value = json!({ "variant": "T3", "value": 1 });
+    assert!(serde_json::from_value::<Tagged<Or2<i32, f64>>>(value).is_err());
+}
@@ -0,0 +1,37 @@
+#![cfg(feature = "tokio")]
+
+use std::io::{Cursor, SeekFrom};
+
+use or_rs::enums::*;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
+
+#[tokio::test]
+async fn test_async_read() {
+    // the bytes come from the reader of the active variant.
+    let mut x = Or2::<&[u8], Cursor<Vec<u8>>>::T1(b"hello");
+    let mut buf = String::new();
+    x.read_to_string(&mut buf).await.unwrap();
+    assert_eq!(buf, "hello");
+
+    let mut x = Or2::<&[u8], Cursor<Vec<u8>>>::T2(Cursor::new(b"first\nsecond".to_vec()));
+    let mut line = String::new();
+    x.read_line(&mut line).await.unwrap();
+    assert_eq!(line, "first\n");
+}
+
+#[tokio::test]
+async fn test_async_write() {
+    let mut x = Or2::<Vec<u8>, Cursor<Vec<u8>>>::T1(Vec::new());
+    x.write_all(b"hello").await.unwrap();
+    x.flush().await.unwrap();
+    assert_eq!(x.as_t1().unwrap(), b"hello");
+}
+
+#[tokio::test]
+async fn test_async_seek() {
+    let mut x = Or2::<Cursor<Vec<u8>>, Cursor<&[u8]>>::T2(Cursor::new(b"hello"));
+    assert_eq!(x.seek(SeekFrom::Start(3)).await.unwrap(), 3);
+    let mut buf = String::new();
+    x.read_to_string(&mut buf).await.unwrap();
+    assert_eq!(buf, "lo");
+}
@@ -0,0 +1,58 @@
+#![cfg(feature = "tower")]
+
+use std::convert::Infallible;
+use std::task::{Context, Poll};
+
+use futures::executor::block_on;
+use futures::future::{poll_fn, ready, Ready};
+use or_rs::enums::*;
+use tower_service::Service;
+
+struct Double;
+
+impl Service<i32> for Double {
+    type Response = i32;
+    type Error = Infallible;
+    type Future = Ready<Result<i32, Infallible>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: i32) -> Self::Future {
+        ready(Ok(req * 2))
+    }
+}
+
+struct Negate;
+
+impl Service<i32> for Negate {
+    type Response = i32;
+    type Error = Infallible;
+    type Future = Ready<Result<i32, Infallible>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: i32) -> Self::Future {
+        ready(Ok(-req))
+    }
+}
+
+fn call<S: Service<i32>>(service: &mut S, req: i32) -> Result<S::Response, S::Error> {
+    block_on(async {
+        poll_fn(|cx| service.poll_ready(cx)).await?;
+        service.call(req).await
+    })
+}
+
+#[test]
+fn test_service() {
+    // the request is handled by the service of the active variant.
+    let mut service = Or2::<Double, Negate>::T1(Double);
+    assert_eq!(call(&mut service, 3), Ok(6));
+
+    let mut service = Or2::<Double, Negate>::T2(Negate);
+    assert_eq!(call(&mut service, 3), Ok(-3));
+}
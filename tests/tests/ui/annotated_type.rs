@@ -0,0 +1,18 @@
+#![feature(proc_macro_hygiene)]
+
+use or_rs::enums::Or2;
+use or_rs_macros::or_gen;
+
+fn main() {
+    // the annotated type is not the type of the `if` expression wrapped in a method call.
+    #[or_gen]
+    let x: Or2<i32, f32> = if true { Or2::T1(1) } else { Or2::T2(2.0) }.clone();
+
+    // the annotated type is not a path to the `Or` type.
+    #[or_gen]
+    let y: &Or2<i32, f32> = if true { &Or2::T1(1) } else { &Or2::T2(2.0) };
+
+    // the annotated type has no generic arguments.
+    #[or_gen]
+    let z: Or2 = if true { 1 } else { 2.0 };
+}
@@ -0,0 +1,15 @@
+#![feature(proc_macro_hygiene, stmt_expr_attributes)]
+
+use or_rs::enums::Or2;
+use or_rs_macros::or_gen;
+
+fn main() {
+    // the value would be removed along with the `let` binding it is moved onto.
+    #[or_gen]
+    let x: Or2<i32, f32> = if true {
+        #[cfg(any())]
+        1
+    } else {
+        2.0
+    };
+}
@@ -0,0 +1,13 @@
+#![feature(proc_macro_hygiene)]
+
+use or_rs::enums::Or2;
+use or_rs_macros::or_gen;
+
+fn main() {
+    // there is no `if` or `match` expression to generate the branches of.
+    #[or_gen]
+    let x: Or2<i32, f32>;
+
+    #[or_gen(dyn std::fmt::Display)]
+    let y;
+}
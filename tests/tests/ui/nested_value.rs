@@ -0,0 +1,14 @@
+#![feature(proc_macro_hygiene)]
+
+use or_rs::enums::Or2;
+use or_rs_macros::or_gen;
+
+fn main() {
+    // the branches of an `Or` type inside an `Option` need to be `Some(...)` or `None`.
+    #[or_gen]
+    let x: Option<Or2<i32, f32>> = if true { Some(1) } else { 2.0 };
+
+    // the same for `Ok(...)` or `Err(...)` inside a `Result`.
+    #[or_gen]
+    let y: Result<Or2<i32, f32>, ()> = if true { Ok(1) } else { 2.0 };
+}
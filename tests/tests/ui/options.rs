@@ -0,0 +1,30 @@
+#![feature(proc_macro_hygiene)]
+
+use or_rs::enums::Or2;
+use or_rs_macros::or_gen;
+
+fn main() {
+    // unknown option.
+    #[or_gen(coerced)]
+    let a: Or2<i32, f32> = if true { Or2::T1(1) } else { Or2::T2(2.0) };
+
+    // the `Or` type is given twice.
+    #[or_gen(Or2<i32, f32>, Or2<i32, f32>)]
+    let b = if true { 1 } else { 2 };
+
+    // the trait object is given twice.
+    #[or_gen(dyn std::fmt::Debug, dyn std::fmt::Display)]
+    let c = if true { 1 } else { 2 };
+
+    // the `Or` type can not be given along with a trait object.
+    #[or_gen(Or2<i32, f32>, dyn std::fmt::Debug)]
+    let d = if true { 1 } else { 2 };
+
+    // the path to `or_rs` is given twice.
+    #[or_gen(crate = or_rs, crate = ::or_rs)]
+    let e: Or2<i32, f32> = if true { Or2::T1(1) } else { Or2::T2(2.0) };
+
+    // the arguments are not types.
+    #[or_gen(1 + 2)]
+    let f: Or2<i32, f32> = if true { Or2::T1(1) } else { Or2::T2(2.0) };
+}
@@ -0,0 +1,16 @@
+#![feature(proc_macro_hygiene, try_blocks)]
+
+use or_rs::enums::Or2;
+use or_rs_macros::or_gen;
+
+fn main() {
+    // the `Or` type is not inside a `Result` or an `Option`.
+    #[or_gen]
+    let x: Or2<i32, f32> = try { if true { 1 } else { 2 } };
+
+    // the try block has no tail expression.
+    #[or_gen]
+    let y: Option<()> = try {
+        None::<i32>?;
+    };
+}
@@ -0,0 +1,14 @@
+#![feature(proc_macro_hygiene)]
+
+use or_rs::enums::Or2;
+use or_rs_macros::or_gen;
+
+fn main() {
+    // the tuple expression is shorter than the annotation.
+    #[or_gen]
+    let (x, n): (Or2<i32, f32>, usize) = (if true { Or2::T1(1) } else { Or2::T2(2.0) },);
+
+    // none of the elements is an `if` or `match` expression.
+    #[or_gen]
+    let (y, m): (Or2<i32, f32>, usize) = (Or2::T1(1), 2);
+}
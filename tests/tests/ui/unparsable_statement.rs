@@ -0,0 +1,11 @@
+#![feature(proc_macro_hygiene, stmt_expr_attributes, yeet_expr)]
+
+use or_rs_macros::or_gen;
+
+// `do yeet` is not parsed by `syn`, so the statement is reported and emitted as is.
+fn f() -> Option<i32> {
+    #[or_gen]
+    do yeet;
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+#![feature(proc_macro_hygiene, stmt_expr_attributes)]
+
+use or_rs::enums::Or2;
+use or_rs_macros::or_gen;
+
+fn main() {
+    // neither a `let` statement nor an expression.
+    #[or_gen]
+    fn g() {}
+
+    // not an `if` or `match` expression.
+    #[or_gen]
+    let x: Or2<i32, f32> = Or2::T1(3);
+
+    // the `Or` type is not annotated.
+    #[or_gen]
+    let y = if true { 3 } else { 4 };
+}
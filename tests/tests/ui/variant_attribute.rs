@@ -0,0 +1,30 @@
+#![feature(proc_macro_hygiene, stmt_expr_attributes)]
+
+use or_rs::enums::Or2;
+use or_rs_macros::or_gen;
+
+fn main() {
+    // `#[variant(...)]` needs a variant like `T2`.
+    #[or_gen]
+    let x: Or2<i32, f32> = match 3 {
+        #[variant(Two)]
+        1 => 1,
+        _ => 2.0,
+    };
+
+    // `#[passthrough]` takes no arguments.
+    #[or_gen]
+    let y: Or2<i32, f32> = match 3 {
+        #[passthrough(T1)]
+        1 => Or2::T1(1),
+        _ => 2.0,
+    };
+
+    // the attributes are not supported on an arm whose body has branches of its own.
+    #[or_gen]
+    let z: Or2<i32, f32> = match 3 {
+        #[variant(T2)]
+        1 => if true { 1.0 } else { 2.0 },
+        _ => 1,
+    };
+}
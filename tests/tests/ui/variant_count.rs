@@ -0,0 +1,18 @@
+#![feature(proc_macro_hygiene)]
+
+use or_rs::enums::{Or2, Or3};
+use or_rs_macros::or_gen;
+
+fn main() {
+    // 3 branches do not fit in `Or2`.
+    #[or_gen]
+    let x: Or2<i32, f32> = match 3 {
+        1 => 1,
+        2 => 2.0,
+        _ => "three",
+    };
+
+    // `T3` is not used by any branch.
+    #[or_gen]
+    let y: Or3<i32, f32, &str> = if true { 1 } else { 2.0 };
+}